@@ -0,0 +1,154 @@
+use std::str::FromStr;
+
+use crate::CliError;
+
+/// Options that don't take a value
+const FLAGS: &[&str] = &["json"];
+
+/// Command line arguments of a subcommand
+///
+/// Options are written "--name value" or "--name=value", every other argument is positional.
+/// Each option can only be given once.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Args {
+    options: Vec<(String, String)>,
+    flags: Vec<String>,
+    positional: Vec<String>,
+}
+
+impl Args {
+    /// Initializes an Args struct from the arguments after the subcommand
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Args, CliError> {
+        let mut parsed = Args::default();
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let Some(option) = arg.strip_prefix("--") else {
+                parsed.positional.push(arg);
+                continue;
+            };
+
+            let (name, value) = match option.split_once('=') {
+                Some((name, value)) => (name.to_string(), Some(value.to_string())),
+                None => (option.to_string(), None),
+            };
+            if parsed.has(&name) {
+                return Err(CliError::Usage(format!("--{name} is given more than once")));
+            }
+
+            if FLAGS.contains(&name.as_str()) {
+                if value.is_some() {
+                    return Err(CliError::Usage(format!("--{name} doesn't take a value")));
+                }
+                parsed.flags.push(name);
+            } else {
+                let value = value
+                    .or_else(|| args.next())
+                    .ok_or_else(|| CliError::Usage(format!("--{name} needs a value")))?;
+                parsed.options.push((name, value));
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    fn has(&self, name: &str) -> bool {
+        self.flags.iter().any(|flag| flag == name)
+            || self.options.iter().any(|(option, _)| option == name)
+    }
+
+    /// Returns true if the flag is set
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.iter().any(|flag| flag == name)
+    }
+
+    /// Returns the value of an option
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .find(|(option, _)| option == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the value of an option that has to be given
+    pub fn required(&self, name: &str) -> Result<&str, CliError> {
+        self.value(name)
+            .ok_or_else(|| CliError::Usage(format!("--{name} is required")))
+    }
+
+    /// Parses the value of an option with `parse`, which returns None for invalid values
+    pub fn parsed<T, F>(&self, name: &str, parse: F) -> Result<Option<T>, CliError>
+    where
+        F: FnOnce(&str) -> Option<T>,
+    {
+        self.value(name)
+            .map(|value| {
+                parse(value).ok_or_else(|| CliError::Usage(format!("invalid --{name} {value:?}")))
+            })
+            .transpose()
+    }
+
+    /// Parses the value of an option with `FromStr`
+    pub fn number<T: FromStr>(&self, name: &str) -> Result<Option<T>, CliError> {
+        self.parsed(name, |value| value.parse().ok())
+    }
+
+    /// Returns the positional arguments
+    pub fn positional(&self) -> &[String] {
+        &self.positional
+    }
+
+    /// Fails on options the subcommand doesn't know
+    pub fn check_known(&self, known: &[&str]) -> Result<(), CliError> {
+        let unknown = self
+            .flags
+            .iter()
+            .chain(self.options.iter().map(|(name, _)| name))
+            .find(|name| !known.contains(&name.as_str()));
+
+        match unknown {
+            Some(name) => Err(CliError::Usage(format!("unknown option --{name}"))),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Args;
+    use crate::CliError;
+
+    fn parse(args: &[&str]) -> Result<Args, CliError> {
+        Args::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_args() {
+        let args = parse(&["--username", "bob", "--difficulty=8", "--json", "proof"]).unwrap();
+
+        assert_eq!(Some("bob"), args.value("username"));
+        assert_eq!(Ok(Some(8usize)), args.number("difficulty"));
+        assert_eq!(Ok(None::<usize>), args.number("threads"));
+        assert!(args.flag("json"));
+        assert_eq!(["proof"], args.positional());
+        assert!(args
+            .check_known(&["username", "difficulty", "json"])
+            .is_ok());
+        assert!(args.check_known(&["username", "json"]).is_err());
+        assert!(args.required("address").is_err());
+    }
+
+    #[test]
+    fn test_args_invalid() {
+        for args in [
+            &["--username"][..],
+            &["--json=yes"],
+            &["--username", "bob", "--username=alice"],
+        ] {
+            assert!(matches!(parse(args), Err(CliError::Usage(_))), "{args:?}");
+        }
+
+        let args = parse(&["--difficulty", "eight"]).unwrap();
+        assert!(args.number::<usize>("difficulty").is_err());
+    }
+}
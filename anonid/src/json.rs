@@ -0,0 +1,89 @@
+//! Minimal JSON writer for the machine-readable output
+
+/// Value of a field in the JSON output
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Integer(u64),
+    /// Written as null when it isn't finite
+    Float(f64),
+    String(String),
+    /// Already serialized JSON, written as is
+    Raw(String),
+}
+
+fn write_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Writes a value for the "name: value" output, strings without quotes
+pub fn write_text(value: &JsonValue) -> String {
+    match value {
+        JsonValue::Null => "-".to_string(),
+        JsonValue::Bool(value) => value.to_string(),
+        JsonValue::Integer(value) => value.to_string(),
+        JsonValue::Float(value) => value.to_string(),
+        JsonValue::String(value) | JsonValue::Raw(value) => value.clone(),
+    }
+}
+
+/// Writes a JSON object with the fields in order
+pub fn write_object(fields: &[(&str, JsonValue)]) -> String {
+    let mut out = String::from("{");
+
+    for (index, (name, value)) in fields.iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        write_string(&mut out, name);
+        out.push(':');
+
+        match value {
+            JsonValue::Null => out.push_str("null"),
+            JsonValue::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+            JsonValue::Integer(value) => out.push_str(&value.to_string()),
+            JsonValue::Float(value) if value.is_finite() => out.push_str(&value.to_string()),
+            JsonValue::Float(_) => out.push_str("null"),
+            JsonValue::String(value) => write_string(&mut out, value),
+            JsonValue::Raw(value) => out.push_str(value),
+        }
+    }
+
+    out.push('}');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write_object, JsonValue};
+
+    #[test]
+    fn test_write_object() {
+        assert_eq!("{}", write_object(&[]));
+        assert_eq!(
+            r#"{"valid":true,"error":null,"nonce":42,"seconds":1.5,"eta":null,"name":"a\"b\\c\n\u0001","proof":{}}"#,
+            write_object(&[
+                ("valid", JsonValue::Bool(true)),
+                ("error", JsonValue::Null),
+                ("nonce", JsonValue::Integer(42)),
+                ("seconds", JsonValue::Float(1.5)),
+                ("eta", JsonValue::Float(f64::INFINITY)),
+                ("name", JsonValue::String("a\"b\\c\n\u{1}".to_string())),
+                ("proof", JsonValue::Raw("{}".to_string())),
+            ])
+        );
+    }
+}
@@ -0,0 +1,663 @@
+//! `anonid`, mines and verifies AnonID proofs of work
+//!
+//! Every subcommand prints "name: value" lines, or a single JSON object with `--json`. The
+//! exit code is 0 on success, 1 for a proof that doesn't verify, 2 for invalid arguments,
+//! and 3 when the work itself fails.
+
+mod args;
+mod json;
+
+use std::fmt;
+use std::io::Read;
+use std::num::NonZeroUsize;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use anonid_pow::{
+    benchmark, calibrate, DifficultyCurve, LengthMetric, PoW, PoWAlgo, Proof, ProofError,
+    SignedProof, TargetMode, UserData,
+};
+
+use args::Args;
+use json::JsonValue;
+
+const USAGE: &str = "\
+usage: anonid <command> [options] [--json]
+
+commands:
+  mine        --username NAME --address ADDRESS --difficulty BITS [--threads N]
+  verify      [STAMP | JSON | SIGNED_JSON | -] [--min-difficulty BITS] [--target MODE]
+              [--metric METRIC] [--policy POLICY]
+  estimate    --username NAME --difficulty BITS [--hash-rate H/S | --calibrate-ms MS]
+              [--threads N]
+  bench       [--algo ALGO] [--duration-ms MS]
+  difficulty  --username NAME --difficulty BITS
+
+mine, estimate, and difficulty also take [--algo ALGO] [--target MODE] [--metric METRIC]
+[--policy POLICY] [--namespace NAMESPACE], named like in proofs. verify reads standard
+input without a proof or with \"-\", and checks the signature of JSON proofs with a
+\"signature\" field. It only accepts proofs with the given target mode, metric, and
+policy, the legacy ones by default, and --min-difficulty is the number of bits of work the
+proof has to match under that metric and policy.";
+
+/// Options every subcommand that builds a PoW takes
+const POW_OPTIONS: &[&str] = &[
+    "username",
+    "address",
+    "namespace",
+    "difficulty",
+    "algo",
+    "target",
+    "metric",
+    "policy",
+    "json",
+];
+
+/// Reasons the tool exits unsuccessfully
+#[derive(Debug, PartialEq, Eq)]
+pub enum CliError {
+    /// The proof is malformed or doesn't verify
+    Invalid(String),
+    /// The arguments are invalid
+    Usage(String),
+    /// The work failed, e.g. the nonce space is exhausted or standard input can't be read
+    Failed(String),
+}
+
+impl CliError {
+    fn exit_code(&self) -> u8 {
+        match self {
+            CliError::Invalid(_) => 1,
+            CliError::Usage(_) => 2,
+            CliError::Failed(_) => 3,
+        }
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CliError::Invalid(reason) => write!(f, "invalid proof: {reason}"),
+            CliError::Usage(reason) => write!(f, "{reason}"),
+            CliError::Failed(reason) => write!(f, "{reason}"),
+        }
+    }
+}
+
+type Report = Vec<(&'static str, JsonValue)>;
+
+/// Returns the seconds of an optional duration, null if it can't be represented
+fn seconds(duration: Option<Duration>) -> JsonValue {
+    duration.map_or(JsonValue::Null, |duration| {
+        JsonValue::Float(duration.as_secs_f64())
+    })
+}
+
+fn threads(args: &Args) -> Result<Option<NonZeroUsize>, CliError> {
+    args.number("threads")
+}
+
+fn algo(args: &Args) -> Result<PoWAlgo, CliError> {
+    let algo = args
+        .parsed("algo", PoWAlgo::from_name)?
+        .unwrap_or(PoWAlgo::Sha256);
+    algo.validate()
+        .map_err(|error| CliError::Usage(error.to_string()))?;
+
+    Ok(algo)
+}
+
+/// Returns the target mode, length metric, and difficulty policy of the options, the legacy
+/// ones by default
+fn modes(args: &Args) -> Result<(TargetMode, LengthMetric, DifficultyCurve), CliError> {
+    Ok((
+        args.parsed("target", TargetMode::from_name)?
+            .unwrap_or_default(),
+        args.parsed("metric", LengthMetric::from_name)?
+            .unwrap_or_default(),
+        args.parsed("policy", DifficultyCurve::from_name)?
+            .unwrap_or_default(),
+    ))
+}
+
+/// Builds the PoW described by the shared options, the address can be left out when only
+/// the difficulty matters
+fn pow(args: &Args, address_required: bool) -> Result<PoW, CliError> {
+    let address = match address_required {
+        true => args.required("address")?,
+        false => args.value("address").unwrap_or_default(),
+    };
+    let mut userdata = UserData::new(args.required("username")?.to_string(), address.to_string());
+    if let Some(namespace) = args.value("namespace") {
+        userdata = userdata.with_namespace(namespace.to_string());
+    }
+
+    let difficulty = args
+        .number("difficulty")?
+        .ok_or_else(|| CliError::Usage("--difficulty is required".to_string()))?;
+    let pow = PoW::new(userdata, difficulty, algo(args)?)
+        .map_err(|error| CliError::Usage(error.to_string()))?;
+    let (target_mode, length_metric, difficulty_policy) = modes(args)?;
+
+    Ok(pow
+        .with_target_mode(target_mode)
+        .with_length_metric(length_metric)
+        .with_difficulty_policy(difficulty_policy))
+}
+
+fn mine(args: &Args) -> Result<Report, CliError> {
+    args.check_known(&[POW_OPTIONS, &["threads"]].concat())?;
+    let pow = pow(args, true)?;
+
+    let started = Instant::now();
+    let proof = pow.proof(
+        pow.calculate_pow_parallel(threads(args)?)
+            .map_err(|error| CliError::Failed(error.to_string()))?,
+    );
+    let elapsed = started.elapsed();
+
+    Ok(vec![
+        (
+            "stamp",
+            proof.to_stamp().map_or(JsonValue::Null, JsonValue::String),
+        ),
+        ("hash", JsonValue::String(proof.hash.clone())),
+        ("nonce", JsonValue::Integer(proof.nonce as u64)),
+        ("seconds", JsonValue::Float(elapsed.as_secs_f64())),
+        ("proof", JsonValue::Raw(proof.to_json())),
+    ])
+}
+
+fn verify(args: &Args) -> Result<Report, CliError> {
+    args.check_known(&["min-difficulty", "target", "metric", "policy", "json"])?;
+    let (target_mode, length_metric, difficulty_policy) = modes(args)?;
+    let min_difficulty = args.number::<usize>("min-difficulty")?;
+
+    let input = match args.positional() {
+        [] => "-".to_string(),
+        [proof] => proof.clone(),
+        _ => {
+            return Err(CliError::Usage(
+                "only one proof can be verified".to_string(),
+            ))
+        }
+    };
+    let input = if input == "-" {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|error| CliError::Failed(format!("can't read standard input: {error}")))?;
+        input
+    } else {
+        input
+    };
+    let input = input.trim();
+
+    // JSON proofs with a "signature" field are signed proofs, their signature has to verify
+    let (proof, signed) = if input.starts_with('{') {
+        match SignedProof::from_json(input) {
+            Ok(signed_proof) => {
+                signed_proof
+                    .verify_signature()
+                    .map_err(|error| CliError::Invalid(error.to_string()))?;
+                (signed_proof.proof, true)
+            }
+            Err(ProofError::MissingField("signature")) => (
+                Proof::from_json(input).map_err(|error| CliError::Invalid(error.to_string()))?,
+                false,
+            ),
+            Err(error) => return Err(CliError::Invalid(error.to_string())),
+        }
+    } else {
+        let proof =
+            Proof::from_stamp(input).map_err(|error| CliError::Invalid(error.to_string()))?;
+        (proof, false)
+    };
+
+    // The proof names its own target mode, metric, and policy, which decide how much work
+    // its difficulty stands for, so only the ones the verifier chose are accepted
+    if proof.target_mode != target_mode {
+        return Err(CliError::Invalid(format!(
+            "target mode {} isn't accepted, expected {target_mode}",
+            proof.target_mode
+        )));
+    }
+    if proof.length_metric != length_metric {
+        return Err(CliError::Invalid(format!(
+            "length metric {} isn't accepted, expected {length_metric}",
+            proof.length_metric
+        )));
+    }
+    if proof.difficulty_policy != difficulty_policy {
+        return Err(CliError::Invalid(format!(
+            "difficulty policy {} isn't accepted, expected {difficulty_policy}",
+            proof.difficulty_policy
+        )));
+    }
+
+    let pow = proof
+        .pow()
+        .map_err(|error| CliError::Invalid(error.to_string()))?;
+    if let Some(min_difficulty) = min_difficulty {
+        // Compares the probability of an attempt meeting the target, the work the proof
+        // actually required, with that of as many leading zero bits as the username's
+        // adjusted minimum difficulty, so numeric targets are held to it too
+        let required = PoW::new(proof.userdata.clone(), min_difficulty, proof.algo.clone())
+            .map_err(|error| CliError::Usage(error.to_string()))?
+            .with_target_mode(TargetMode::LeadingZeroBits)
+            .with_length_metric(length_metric)
+            .with_difficulty_policy(difficulty_policy);
+        if pow.estimate().probability > required.estimate().probability {
+            return Err(CliError::Invalid(format!(
+                "the proof required less work than difficulty {min_difficulty}"
+            )));
+        }
+    }
+    // Parsed stamps carry no hash, the one recalculated here is reported
+    let Some(hash) = pow
+        .verify_nonce(proof.nonce)
+        .filter(|hash| proof.hash.is_empty() || hash.eq_ignore_ascii_case(&proof.hash))
+    else {
+        return Err(CliError::Invalid(
+            "the hash doesn't meet the target".to_string(),
+        ));
+    };
+
+    Ok(vec![
+        ("valid", JsonValue::Bool(true)),
+        ("signed", JsonValue::Bool(signed)),
+        (
+            "username",
+            JsonValue::String(proof.userdata.username().to_string()),
+        ),
+        (
+            "auth_address",
+            JsonValue::String(proof.userdata.auth_address().to_string()),
+        ),
+        ("algo", JsonValue::String(proof.algo.to_string())),
+        ("difficulty", JsonValue::Integer(proof.difficulty as u64)),
+        (
+            "adjusted_difficulty",
+            JsonValue::Integer(pow.adjusted_difficulty() as u64),
+        ),
+        ("hash", JsonValue::String(hash)),
+    ])
+}
+
+fn estimate(args: &Args) -> Result<Report, CliError> {
+    args.check_known(&[POW_OPTIONS, &["hash-rate", "calibrate-ms", "threads"]].concat())?;
+    let pow = pow(args, false)?;
+
+    let hash_rate = match args.number::<f64>("hash-rate")? {
+        Some(hash_rate) if hash_rate > 0.0 && hash_rate.is_finite() => hash_rate,
+        Some(_) => {
+            return Err(CliError::Usage(
+                "--hash-rate has to be positive".to_string(),
+            ))
+        }
+        None => {
+            let duration = Duration::from_millis(args.number("calibrate-ms")?.unwrap_or(1000));
+            let threads = threads(args)?
+                .or_else(|| std::thread::available_parallelism().ok())
+                .map_or(1, NonZeroUsize::get);
+
+            calibrate(&algo(args)?, duration) * threads as f64
+        }
+    };
+
+    let estimate = pow.estimate();
+    let attempts = |percentile| {
+        estimate
+            .percentile_attempts(percentile)
+            .map_or(JsonValue::Null, JsonValue::Float)
+    };
+
+    Ok(vec![
+        (
+            "adjusted_difficulty",
+            JsonValue::Integer(pow.adjusted_difficulty() as u64),
+        ),
+        ("probability", JsonValue::Float(estimate.probability)),
+        (
+            "expected_attempts",
+            JsonValue::Float(estimate.expected_attempts()),
+        ),
+        ("median_attempts", attempts(0.5)),
+        ("p95_attempts", attempts(0.95)),
+        ("hash_rate", JsonValue::Float(hash_rate)),
+        (
+            "expected_seconds",
+            seconds(estimate.expected_time(hash_rate)),
+        ),
+        (
+            "median_seconds",
+            seconds(estimate.percentile_time(0.5, hash_rate)),
+        ),
+        (
+            "p95_seconds",
+            seconds(estimate.percentile_time(0.95, hash_rate)),
+        ),
+    ])
+}
+
+fn bench(args: &Args) -> Result<Report, CliError> {
+    args.check_known(&["algo", "duration-ms", "json"])?;
+
+    let duration = Duration::from_millis(args.number("duration-ms")?.unwrap_or(1000));
+    let report = benchmark(&algo(args)?, duration);
+
+    Ok(vec![
+        ("algo", JsonValue::String(report.algo)),
+        ("hashes", JsonValue::Integer(report.hashes)),
+        ("seconds", JsonValue::Float(report.elapsed.as_secs_f64())),
+        ("hash_rate", JsonValue::Float(report.hash_rate)),
+    ])
+}
+
+fn difficulty(args: &Args) -> Result<Report, CliError> {
+    args.check_known(POW_OPTIONS)?;
+    let pow = pow(args, false)?;
+
+    let metric = args
+        .parsed("metric", LengthMetric::from_name)?
+        .unwrap_or_default();
+    let username = args.required("username")?;
+
+    Ok(vec![
+        (
+            "username_length",
+            JsonValue::Integer(metric.length(username) as u64),
+        ),
+        (
+            "adjusted_difficulty",
+            JsonValue::Integer(pow.adjusted_difficulty() as u64),
+        ),
+    ])
+}
+
+/// Runs a subcommand and returns its report
+fn run(command: &str, args: &Args) -> Result<Report, CliError> {
+    match command {
+        "mine" => mine(args),
+        "verify" => verify(args),
+        "estimate" => estimate(args),
+        "bench" => bench(args),
+        "difficulty" => difficulty(args),
+        _ => Err(CliError::Usage(format!("unknown command {command:?}"))),
+    }
+}
+
+fn main() -> ExitCode {
+    let mut arguments = std::env::args().skip(1);
+    let command = match arguments.next() {
+        Some(command) if !matches!(command.as_str(), "help" | "-h" | "--help") => command,
+        _ => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+    };
+
+    let args = Args::parse(arguments);
+    let json = args.as_ref().is_ok_and(|args| args.flag("json"));
+
+    match args.and_then(|args| run(&command, &args)) {
+        Ok(report) if json => println!("{}", json::write_object(&report)),
+        Ok(report) => {
+            for (name, value) in report {
+                println!("{name}: {}", json::write_text(&value));
+            }
+        }
+        Err(error) => {
+            if json {
+                println!(
+                    "{}",
+                    json::write_object(&[
+                        ("error", JsonValue::String(error.to_string())),
+                        ("exit_code", JsonValue::Integer(error.exit_code() as u64)),
+                    ])
+                );
+            } else {
+                eprintln!("anonid: {error}");
+                if matches!(error, CliError::Usage(_)) {
+                    eprintln!("\n{USAGE}");
+                }
+            }
+            return ExitCode::from(error.exit_code());
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run, Args, CliError, JsonValue};
+    use anonid_pow::{DifficultyCurve, PoW, PoWAlgo, TargetMode, UserData};
+
+    fn run_with(command: &str, args: &[&str]) -> Result<Vec<(&'static str, JsonValue)>, CliError> {
+        run(
+            command,
+            &Args::parse(args.iter().map(|arg| arg.to_string())).unwrap(),
+        )
+    }
+
+    fn field(report: &[(&'static str, JsonValue)], name: &str) -> JsonValue {
+        report
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, value)| value.clone())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_mine_verify() {
+        let report = run_with(
+            "mine",
+            &[
+                "--username",
+                "zeronet_user",
+                "--address",
+                "1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX",
+                "--difficulty",
+                "16",
+                "--threads",
+                "2",
+            ],
+        )
+        .unwrap();
+
+        let JsonValue::String(stamp) = field(&report, "stamp") else {
+            panic!("no stamp in {report:?}");
+        };
+        assert_eq!(
+            "anonid:1:sha256:16:1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user:9356",
+            stamp
+        );
+        let JsonValue::Raw(proof) = field(&report, "proof") else {
+            panic!("no proof in {report:?}");
+        };
+
+        for proof in [&stamp, &proof] {
+            let report = run_with("verify", &[proof]).unwrap();
+            assert_eq!(JsonValue::Bool(true), field(&report, "valid"));
+            assert_eq!(
+                JsonValue::Integer(16),
+                field(&report, "adjusted_difficulty")
+            );
+        }
+
+        let wrong_nonce = stamp.replace(":9356", ":9357");
+        assert!(matches!(
+            run_with("verify", &[&wrong_nonce]),
+            Err(CliError::Invalid(_))
+        ));
+        assert!(matches!(
+            run_with("verify", &[&stamp, "--min-difficulty", "32"]),
+            Err(CliError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_signed() {
+        let signed_proof = concat!(
+            r#"{"version":1,"algo":"sha256","target_mode":"prefix","difficulty":8,"#,
+            r#""auth_address":"19pTScE8LZfwRNasdjXrgFWkVqMRcU99GK","username":"zeronet_user","#,
+            r#""nonce":31,"hash":"3ef97ad5f95984cff3c3b99c32000bc45677435d52b4fd0fd61af61192ddb955","#,
+            r#""signature":"Hzrd4vVuqF0zuYEZO/uMJKmyqd1IkEXmzMwEdB9Yt+i+PTHRULvKHRgphDXF4UTeAlJbYoFWrHshxM/Li2/1O28="}"#
+        );
+
+        let report = run_with("verify", &[signed_proof]).unwrap();
+        assert_eq!(JsonValue::Bool(true), field(&report, "signed"));
+
+        let unsigned = signed_proof.split(r#","signature""#).next().unwrap();
+        for tampered in [
+            signed_proof.replace("Hzrd4", "Hzrd5"),
+            signed_proof.replace("vVuq", "vVur"),
+            format!(r#"{unsigned},"signature":1}}"#),
+        ] {
+            assert!(
+                matches!(run_with("verify", &[&tampered]), Err(CliError::Invalid(_))),
+                "{tampered}"
+            );
+        }
+
+        let unsigned = format!("{unsigned}}}");
+        let report = run_with("verify", &[&unsigned]).unwrap();
+        assert_eq!(JsonValue::Bool(false), field(&report, "signed"));
+    }
+
+    #[test]
+    fn test_estimate_difficulty() {
+        let report = run_with(
+            "estimate",
+            &[
+                "--username",
+                "bob",
+                "--difficulty",
+                "16",
+                "--hash-rate",
+                "1024",
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            JsonValue::Float(65536.0),
+            field(&report, "expected_attempts")
+        );
+        assert_eq!(JsonValue::Float(64.0), field(&report, "expected_seconds"));
+
+        let report = run_with(
+            "difficulty",
+            &[
+                "--username",
+                "zeronet_user",
+                "--difficulty",
+                "24",
+                "--policy",
+                "linear-2-8",
+            ],
+        )
+        .unwrap();
+        assert_eq!(JsonValue::Integer(12), field(&report, "username_length"));
+        assert_eq!(
+            JsonValue::Integer(19),
+            field(&report, "adjusted_difficulty")
+        );
+    }
+
+    #[test]
+    fn test_verify_min_difficulty() {
+        let userdata =
+            UserData::from_merged("1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user".to_string())
+                .unwrap();
+        let numeric_target = format!("numeric-{}", "f".repeat(64));
+
+        // Zero-work proofs that claim a high difficulty with a target or policy of their choice
+        let numeric_forgery = format!(
+            concat!(
+                r#"{{"version":1,"algo":"sha256","target_mode":"{}","difficulty":256,"#,
+                r#""auth_address":"1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX","username":"zeronet_user","#,
+                r#""nonce":0,"hash":""}}"#
+            ),
+            numeric_target
+        );
+        let policy_forgery = PoW::new(userdata.clone(), 256, PoWAlgo::Sha256)
+            .unwrap()
+            .with_target_mode(TargetMode::LeadingZeroBits)
+            .with_difficulty_policy(DifficultyCurve::Table(vec![2]))
+            .prove()
+            .unwrap()
+            .to_json();
+
+        for (forgery, args) in [
+            (&numeric_forgery, vec![]),
+            (&numeric_forgery, vec!["--min-difficulty", "200"]),
+            (
+                &numeric_forgery,
+                vec!["--target", &numeric_target, "--min-difficulty", "200"],
+            ),
+            (&policy_forgery, vec!["--min-difficulty", "200"]),
+            (
+                &policy_forgery,
+                vec!["--target", "zeros", "--min-difficulty", "200"],
+            ),
+        ] {
+            assert!(matches!(
+                run_with("verify", &[&[forgery.as_str()], &args[..]].concat()),
+                Err(CliError::Invalid(_))
+            ));
+        }
+
+        // The target and policy are accepted when the verifier chooses them
+        assert!(run_with("verify", &[&numeric_forgery, "--target", &numeric_target]).is_ok());
+        assert!(run_with(
+            "verify",
+            &[&policy_forgery, "--target", "zeros", "--policy", "table-2"]
+        )
+        .is_ok());
+
+        let proof = PoW::new(userdata, 16, PoWAlgo::Sha256)
+            .unwrap()
+            .with_target_mode(TargetMode::LeadingZeroBits)
+            .prove()
+            .unwrap()
+            .to_json();
+        for (min_difficulty, valid) in [("16", true), ("17", false)] {
+            let report = run_with(
+                "verify",
+                &[
+                    &proof,
+                    "--target",
+                    "zeros",
+                    "--min-difficulty",
+                    min_difficulty,
+                ],
+            );
+            assert_eq!(valid, report.is_ok(), "{min_difficulty}");
+        }
+    }
+
+    #[test]
+    fn test_usage_errors() {
+        for (command, args) in [
+            ("mine", &["--username", "bob", "--difficulty", "8"][..]),
+            ("difficulty", &["--username", "bob", "--difficulty", "1"]),
+            (
+                "difficulty",
+                &["--username", "bob", "--difficulty", "8", "--algo", "md5"],
+            ),
+            (
+                "difficulty",
+                &["--username", "bob", "--difficulty", "8", "--threads", "2"],
+            ),
+            (
+                "estimate",
+                &["--username", "bob", "--difficulty", "8", "--hash-rate", "0"],
+            ),
+            ("forge", &[]),
+        ] {
+            let error = run_with(command, args).unwrap_err();
+            assert_eq!(2, error.exit_code(), "{command} {args:?}: {error}");
+        }
+    }
+}
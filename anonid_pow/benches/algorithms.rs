@@ -0,0 +1,125 @@
+//! Reproducible numbers for tuning the difficulties of the PoW algorithms: the hash rate of
+//! `PoWAlgo::calculate` and of the prepared hasher, `calculate_pow` at several difficulties,
+//! and `verify_pow` one by one and in a batch
+//!
+//! The userdata and therefore the nonces found are fixed, so runs only differ in speed.
+//! Run with `cargo bench -p anonid_pow --bench algorithms --all-features`
+
+use anonid_pow::{
+    DifficultyCurve, LengthMetric, PoW, PoWAlgo, TargetMode, UserData, VerifyRequest,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::hint::black_box;
+
+const USERDATA: &str = "1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user";
+const BATCH_SIZE: usize = 1000;
+
+/// Returns the algorithms to benchmark with the difficulties `calculate_pow` is run at
+fn algos() -> Vec<(PoWAlgo, Vec<usize>)> {
+    vec![
+        (PoWAlgo::Sha256, vec![8, 12, 16, 20]),
+        #[cfg(feature = "blake3")]
+        (PoWAlgo::Blake3, vec![8, 12, 16, 20]),
+        #[cfg(feature = "sha3")]
+        (PoWAlgo::Sha3_256, vec![8, 12, 16, 20]),
+        (
+            PoWAlgo::Argon2id {
+                memory_cost: 4096,
+                time_cost: 1,
+                parallelism: 1,
+            },
+            vec![4, 8],
+        ),
+    ]
+}
+
+/// An empty difficulty table keeps the full difficulty for every username
+fn pow(algo: &PoWAlgo, difficulty: usize) -> PoW {
+    let userdata = UserData::from_merged(USERDATA.to_string()).unwrap();
+
+    PoW::new(userdata, difficulty, algo.clone())
+        .unwrap()
+        .with_target_mode(TargetMode::LeadingZeroBits)
+        .with_difficulty_policy(DifficultyCurve::Table(Vec::new()))
+}
+
+fn hashing(c: &mut Criterion) {
+    for (algo, _) in algos() {
+        let mut group = c.benchmark_group(format!("{algo}/hash"));
+        group.throughput(Throughput::Elements(1));
+
+        let mut nonce = 0;
+        group.bench_function("calculate", |b| {
+            b.iter(|| {
+                nonce += 1;
+                algo.calculate(USERDATA, black_box(nonce)).unwrap()
+            })
+        });
+
+        let prepared = algo.prepare(USERDATA).unwrap();
+        group.bench_function("prepared", |b| {
+            b.iter(|| {
+                nonce += 1;
+                prepared.digest(black_box(nonce))
+            })
+        });
+
+        group.finish();
+    }
+}
+
+fn calculate_pow(c: &mut Criterion) {
+    for (algo, difficulties) in algos() {
+        let mut group = c.benchmark_group(format!("{algo}/calculate_pow"));
+        group.sample_size(10);
+
+        for difficulty in difficulties {
+            let pow = pow(&algo, difficulty);
+
+            // The nonce found is the number of attempts minus one
+            let (_, nonce) = pow.calculate_pow().unwrap();
+            group.throughput(Throughput::Elements(nonce as u64 + 1));
+            group.bench_with_input(BenchmarkId::from_parameter(difficulty), &pow, |b, pow| {
+                b.iter(|| pow.calculate_pow().unwrap())
+            });
+        }
+
+        group.finish();
+    }
+}
+
+fn verify(c: &mut Criterion) {
+    for (algo, _) in algos() {
+        let mut group = c.benchmark_group(format!("{algo}/verify"));
+
+        let pow = pow(&algo, 8);
+        let (hash, nonce) = pow.calculate_pow().unwrap();
+        group.throughput(Throughput::Elements(1));
+        group.bench_function("verify_pow", |b| {
+            b.iter(|| pow.verify_pow(black_box((hash.clone(), nonce))))
+        });
+
+        let requests = vec![
+            VerifyRequest {
+                merged_userdata: USERDATA.to_string(),
+                difficulty: 8,
+                algo: algo.clone(),
+                target_mode: TargetMode::LeadingZeroBits,
+                length_metric: LengthMetric::Bytes,
+                difficulty_policy: DifficultyCurve::Table(Vec::new()),
+                nonce,
+                hash: Some(hash),
+            };
+            BATCH_SIZE
+        ];
+        group.throughput(Throughput::Elements(BATCH_SIZE as u64));
+        group.bench_function(format!("verify_batch {BATCH_SIZE}"), |b| {
+            b.iter(|| PoW::verify_batch(black_box(&requests), None))
+        });
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, hashing, calculate_pow, verify);
+criterion_main!(benches);
@@ -0,0 +1,41 @@
+//! Compares the per-attempt cost of hashing the whole "userdata:nonce" string with the
+//! prepared hasher that reuses the SHA-256 midstate of the userdata
+//!
+//! Run with `cargo bench -p anonid_pow --bench calculate`
+
+use anonid_pow::PoWAlgo;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use sha2::{Digest, Sha256};
+use std::hint::black_box;
+
+const USERDATA: &str = "1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user";
+
+fn calculate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sha256");
+    group.throughput(Throughput::Elements(1));
+
+    let mut nonce = 0;
+    group.bench_function("full rehash + hex", |b| {
+        b.iter(|| {
+            nonce += 1;
+            let mut hasher = Sha256::new();
+            hasher.update(USERDATA.as_bytes());
+            hasher.update(format!(":{}", black_box(nonce)).as_bytes());
+
+            format!("{:x}", hasher.finalize()).as_bytes()[0]
+        })
+    });
+
+    let prepared = PoWAlgo::Sha256.prepare(USERDATA).unwrap();
+    group.bench_function("midstate + raw digest", |b| {
+        b.iter(|| {
+            nonce += 1;
+            prepared.digest(black_box(nonce))[0]
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, calculate);
+criterion_main!(benches);
@@ -0,0 +1,179 @@
+#!/usr/bin/env python3
+"""Generates src/unicode_tables.rs from Python's unicodedata module and the UTS #39 data.
+
+Usage: python3 scripts/unicode_tables.py confusables.txt > src/unicode_tables.rs
+
+confusables.txt is the UTS #39 data file from https://www.unicode.org/Public/security/.
+"""
+
+import sys
+import unicodedata
+
+HANGUL_FIRST = 0xAC00
+HANGUL_LAST = 0xD7A3
+
+
+def code_points():
+    for code_point in range(0x110000):
+        if 0xD800 <= code_point <= 0xDFFF:
+            continue
+        if HANGUL_FIRST <= code_point <= HANGUL_LAST:
+            continue
+        yield code_point
+
+
+def combining_class_ranges():
+    ranges = []
+    for code_point in code_points():
+        combining_class = unicodedata.combining(chr(code_point))
+        if combining_class == 0:
+            continue
+        if ranges and ranges[-1][1] == code_point - 1 and ranges[-1][2] == combining_class:
+            ranges[-1][1] = code_point
+        else:
+            ranges.append([code_point, code_point, combining_class])
+    return ranges
+
+
+def grapheme_extend_ranges():
+    """Characters that never start a grapheme cluster.
+
+    Combining and spacing marks, the zero width joiners, emoji modifiers, halfwidth katakana
+    voiced sound marks, and tags, which approximates the Extend and SpacingMark classes of
+    UAX #29.
+    """
+    extra = {0x200C, 0x200D, 0xFF9E, 0xFF9F}
+    extra.update(range(0x1F3FB, 0x1F400))
+    extra.update(range(0xE0020, 0xE0080))
+
+    ranges = []
+    for code_point in range(0x110000):
+        if unicodedata.category(chr(code_point)) not in ("Mn", "Me", "Mc") and code_point not in extra:
+            continue
+        if ranges and ranges[-1][1] == code_point - 1:
+            ranges[-1][1] = code_point
+        else:
+            ranges.append([code_point, code_point])
+    return ranges
+
+
+def mappings(function):
+    """Code points whose mapping differs from themselves, with the mapped code points."""
+    result = []
+    for code_point in code_points():
+        character = chr(code_point)
+        mapped = function(character)
+        if mapped != character:
+            result.append((code_point, [ord(mapped_character) for mapped_character in mapped]))
+    return result
+
+
+def compositions():
+    result = []
+    for code_point in code_points():
+        decomposition = unicodedata.decomposition(chr(code_point))
+        if not decomposition or decomposition.startswith("<"):
+            continue
+        parts = [int(part, 16) for part in decomposition.split()]
+        if len(parts) != 2:
+            continue
+        # Composition exclusions and non-starter decompositions don't recompose
+        if unicodedata.normalize("NFC", chr(parts[0]) + chr(parts[1])) != chr(code_point):
+            continue
+        result.append((parts[0], parts[1], code_point))
+    return sorted(result)
+
+
+def confusables(path):
+    """Version of the UTS #39 data and the prototypes of confusable characters.
+
+    The prototypes are stored in NFD, so the skeleton of a decomposed string only needs the
+    combining marks reordered after mapping.
+    """
+    version = None
+    result = []
+    with open(path, encoding="utf-8-sig") as lines:
+        for line in lines:
+            if line.startswith("# Version: "):
+                version = line.split(":")[1].strip()
+            line = line.split("#")[0].strip()
+            if not line:
+                continue
+            source, target, _ = [field.strip() for field in line.split(";")]
+            prototype = "".join(chr(int(code_point, 16)) for code_point in target.split())
+            mapped = unicodedata.normalize("NFD", prototype)
+            result.append((int(source, 16), [ord(mapped_character) for mapped_character in mapped]))
+    assert version is not None
+    return version, sorted(result)
+
+
+def write_rows(out, items, per_line):
+    for start in range(0, len(items), per_line):
+        out.write("    " + " ".join(items[start:start + per_line]) + "\n")
+
+
+def write_mapping_table(out, name, doc, entries):
+    data = []
+    index = []
+    for code_point, mapped in entries:
+        index.append((code_point, len(data), len(mapped)))
+        data.extend(mapped)
+
+    assert len(data) < 1 << 16
+    out.write(f"/// {doc}\n")
+    out.write(f"///\n/// Each entry is a code point with the offset and length of its mapping in `{name}_DATA`.\n")
+    out.write(f"pub(crate) const {name}: &[(u32, u16, u8)] = &[\n")
+    write_rows(out, [f"(0x{code_point:x}, {offset}, {length})," for code_point, offset, length in index], 5)
+    out.write("];\n\n")
+
+    out.write(f"pub(crate) const {name}_DATA: &[u32] = &[\n")
+    write_rows(out, [f"0x{code_point:x}," for code_point in data], 10)
+    out.write("];\n\n")
+
+
+def main():
+    out = sys.stdout
+    confusables_version, confusable_prototypes = confusables(sys.argv[1])
+
+    out.write(
+        f"//! Unicode {unicodedata.unidata_version} data and UTS #39 {confusables_version} confusables "
+        "generated by scripts/unicode_tables.py, do not edit\n\n"
+    )
+
+    out.write("/// Canonical combining classes other than 0 as inclusive code point ranges\n")
+    out.write("pub(crate) const COMBINING_CLASSES: &[(u32, u32, u8)] = &[\n")
+    write_rows(out, [f"(0x{start:x}, 0x{end:x}, {combining_class})," for start, end, combining_class in combining_class_ranges()], 4)
+    out.write("];\n\n")
+
+    write_mapping_table(
+        out,
+        "DECOMPOSITIONS",
+        "Full compatibility decompositions except the algorithmic Hangul ones",
+        mappings(lambda character: unicodedata.normalize("NFKD", character)),
+    )
+    write_mapping_table(
+        out,
+        "CASE_FOLDINGS",
+        "Full case foldings",
+        mappings(str.casefold),
+    )
+
+    write_mapping_table(
+        out,
+        "CONFUSABLES",
+        "Prototypes of confusable characters in NFD",
+        confusable_prototypes,
+    )
+
+    out.write("/// Characters that extend the preceding grapheme cluster as inclusive code point ranges\n")
+    out.write("pub(crate) const GRAPHEME_EXTEND: &[(u32, u32)] = &[\n")
+    write_rows(out, [f"(0x{start:x}, 0x{end:x})," for start, end in grapheme_extend_ranges()], 5)
+    out.write("];\n\n")
+
+    out.write("/// Primary composites by their canonical decomposition pair\n")
+    out.write("pub(crate) const COMPOSITIONS: &[(u32, u32, u32)] = &[\n")
+    write_rows(out, [f"(0x{first:x}, 0x{second:x}, 0x{composite:x})," for first, second, composite in compositions()], 4)
+    out.write("];\n")
+
+
+main()
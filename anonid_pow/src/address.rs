@@ -0,0 +1,378 @@
+use std::fmt;
+
+use sha2::{Digest, Sha256};
+
+use crate::{AddressError, UserData};
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BECH32_ALPHABET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Version byte of mainnet pay-to-public-key-hash addresses
+const P2PKH_VERSION: u8 = 0;
+/// Human readable part of mainnet segwit addresses
+const BECH32_HRP: &str = "bc";
+
+/// Kind of a Bitcoin address, both commit to the hash160 of a public key
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddressKind {
+    /// Base58check address starting with '1', the kind ZeroNet uses
+    P2pkh,
+    /// Bech32 segwit version 0 address starting with "bc1q"
+    P2wpkh,
+}
+
+/// A decoded and checked auth_address
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AuthAddress {
+    kind: AddressKind,
+    hash160: [u8; 20],
+}
+
+impl AuthAddress {
+    /// Initializes an AuthAddress from a public key hash
+    pub fn new(kind: AddressKind, hash160: [u8; 20]) -> AuthAddress {
+        AuthAddress { kind, hash160 }
+    }
+
+    /// Decodes a mainnet P2PKH or P2WPKH address and checks its checksum and version
+    pub fn parse(address: &str) -> Result<AuthAddress, AddressError> {
+        let is_bech32 = address
+            .get(..BECH32_HRP.len() + 1)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("bc1"));
+
+        if is_bech32 {
+            decode_bech32(address)
+        } else {
+            decode_base58check(address)
+        }
+    }
+
+    /// Returns the kind of the address
+    pub fn kind(&self) -> AddressKind {
+        self.kind
+    }
+
+    /// Returns the RIPEMD-160 of the SHA-256 of the public key the address commits to
+    pub fn hash160(&self) -> [u8; 20] {
+        self.hash160
+    }
+}
+
+impl fmt::Display for AuthAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            AddressKind::P2pkh => {
+                let payload = [&[P2PKH_VERSION][..], &self.hash160].concat();
+                write!(f, "{}", base58check_encode(&payload))
+            }
+            AddressKind::P2wpkh => write!(f, "{}", encode_bech32(&self.hash160)),
+        }
+    }
+}
+
+impl UserData {
+    /// Decodes the auth_address, so malformed addresses can be rejected before verifying a
+    /// proof
+    pub fn validate_auth_address(&self) -> Result<AuthAddress, AddressError> {
+        AuthAddress::parse(self.auth_address())
+    }
+}
+
+fn checksum(payload: &[u8]) -> [u8; 4] {
+    let digest = Sha256::digest(Sha256::digest(payload));
+    [digest[0], digest[1], digest[2], digest[3]]
+}
+
+/// Decodes base58check and returns the payload, which has to have one of the lengths
+pub(crate) fn base58check_decode(
+    encoded: &str,
+    payload_lengths: &[usize],
+) -> Result<Vec<u8>, AddressError> {
+    let max_length = payload_lengths.iter().max().unwrap_or(&0) + 4;
+
+    let mut bytes: Vec<u8> = Vec::new();
+    for character in encoded.chars() {
+        let mut carry = BASE58_ALPHABET
+            .iter()
+            .position(|symbol| *symbol as char == character)
+            .ok_or(AddressError::InvalidCharacter(character))? as u32;
+
+        // bytes is little-endian while decoding
+        for byte in bytes.iter_mut() {
+            carry += *byte as u32 * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+        if bytes.len() > max_length {
+            return Err(AddressError::InvalidLength(encoded.len()));
+        }
+    }
+
+    let leading_zeros = encoded.chars().take_while(|c| *c == '1').count();
+    bytes.extend(std::iter::repeat_n(0, leading_zeros));
+    bytes.reverse();
+
+    if bytes.len() < 4 || !payload_lengths.contains(&(bytes.len() - 4)) {
+        return Err(AddressError::InvalidLength(encoded.len()));
+    }
+    let checksum_start = bytes.len() - 4;
+    if checksum(&bytes[..checksum_start]) != bytes[checksum_start..] {
+        return Err(AddressError::InvalidChecksum);
+    }
+
+    bytes.truncate(checksum_start);
+    Ok(bytes)
+}
+
+/// Encodes the payload followed by its checksum in base58
+pub(crate) fn base58check_encode(payload: &[u8]) -> String {
+    let mut payload = payload.to_vec();
+    payload.extend(checksum(&payload));
+
+    // digits is little-endian while encoding
+    let mut digits: Vec<u8> = Vec::new();
+    for byte in &payload {
+        let mut carry = *byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let leading_zeros = payload.iter().take_while(|byte| **byte == 0).count();
+    std::iter::repeat_n('1', leading_zeros)
+        .chain(
+            digits
+                .iter()
+                .rev()
+                .map(|digit| BASE58_ALPHABET[*digit as usize] as char),
+        )
+        .collect()
+}
+
+fn decode_base58check(address: &str) -> Result<AuthAddress, AddressError> {
+    let payload = base58check_decode(address, &[21])?;
+    if payload[0] != P2PKH_VERSION {
+        return Err(AddressError::UnsupportedVersion(payload[0]));
+    }
+
+    Ok(AuthAddress::new(
+        AddressKind::P2pkh,
+        payload[1..].try_into().unwrap(),
+    ))
+}
+
+fn bech32_polymod(values: impl Iterator<Item = u8>) -> u32 {
+    const GENERATORS: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+    let mut checksum = 1;
+    for value in values {
+        let top = checksum >> 25;
+        checksum = (checksum & 0x1ffffff) << 5 ^ value as u32;
+        for (bit, generator) in GENERATORS.iter().enumerate() {
+            if top >> bit & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum
+}
+
+fn bech32_hrp_values() -> impl Iterator<Item = u8> {
+    BECH32_HRP
+        .bytes()
+        .map(|byte| byte >> 5)
+        .chain([0])
+        .chain(BECH32_HRP.bytes().map(|byte| byte & 0x1f))
+}
+
+/// Regroups bits, padding the last group with zeros if `pad` is set
+fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Option<Vec<u8>> {
+    let mut accumulator = 0;
+    let mut bits = 0;
+    let mut converted = Vec::new();
+    for value in data {
+        accumulator = accumulator << from | *value as u32;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            converted.push((accumulator >> bits & ((1 << to) - 1)) as u8);
+        }
+    }
+
+    if pad && bits > 0 {
+        converted.push((accumulator << (to - bits) & ((1 << to) - 1)) as u8);
+    } else if !pad && (bits >= from || accumulator & ((1 << bits) - 1) != 0) {
+        return None;
+    }
+
+    Some(converted)
+}
+
+fn decode_bech32(address: &str) -> Result<AuthAddress, AddressError> {
+    if address.len() > 90 {
+        return Err(AddressError::InvalidLength(address.len()));
+    }
+    let has_lowercase = address.bytes().any(|byte| byte.is_ascii_lowercase());
+    if let Some(character) = address
+        .chars()
+        .find(|character| has_lowercase && character.is_ascii_uppercase())
+    {
+        return Err(AddressError::InvalidCharacter(character));
+    }
+
+    let address = address.to_ascii_lowercase();
+    let data = &address[BECH32_HRP.len() + 1..];
+    let values = data
+        .chars()
+        .map(|character| {
+            BECH32_ALPHABET
+                .iter()
+                .position(|symbol| *symbol as char == character)
+                .map(|value| value as u8)
+                .ok_or(AddressError::InvalidCharacter(character))
+        })
+        .collect::<Result<Vec<u8>, AddressError>>()?;
+
+    if values.len() < 7 {
+        return Err(AddressError::InvalidLength(address.len()));
+    }
+    // Bech32m, used by segwit version 1 and later, uses a different constant
+    if bech32_polymod(bech32_hrp_values().chain(values.iter().copied())) != 1 {
+        return Err(AddressError::InvalidChecksum);
+    }
+
+    let (witness_version, program) = (values[0], &values[1..values.len() - 6]);
+    if witness_version != 0 {
+        return Err(AddressError::UnsupportedVersion(witness_version));
+    }
+    let program = convert_bits(program, 5, 8, false).ok_or(AddressError::InvalidChecksum)?;
+    let hash160 = program
+        .try_into()
+        .map_err(|_| AddressError::InvalidLength(address.len()))?;
+
+    Ok(AuthAddress::new(AddressKind::P2wpkh, hash160))
+}
+
+fn encode_bech32(hash160: &[u8; 20]) -> String {
+    let mut values = vec![0];
+    values.extend(convert_bits(hash160, 8, 5, true).unwrap());
+
+    let polymod = bech32_polymod(
+        bech32_hrp_values()
+            .chain(values.iter().copied())
+            .chain([0; 6]),
+    ) ^ 1;
+    values.extend((0..6).map(|index| (polymod >> (5 * (5 - index)) & 0x1f) as u8));
+
+    let data: String = values
+        .iter()
+        .map(|value| BECH32_ALPHABET[*value as usize] as char)
+        .collect();
+    format!("{BECH32_HRP}1{data}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AddressKind, AuthAddress};
+    use crate::{AddressError, UserData};
+
+    #[test]
+    fn test_p2pkh_address() {
+        let address = AuthAddress::parse("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2").unwrap();
+        assert_eq!(AddressKind::P2pkh, address.kind());
+        assert_eq!(
+            "77bff20c60e522dfaa3350c39b030a5d004e839a",
+            address
+                .hash160()
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<String>()
+        );
+        assert_eq!("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2", address.to_string());
+
+        let zero = AuthAddress::new(AddressKind::P2pkh, [0; 20]);
+        assert_eq!("1111111111111111111114oLvT2", zero.to_string());
+        assert_eq!(Ok(zero), AuthAddress::parse("1111111111111111111114oLvT2"));
+
+        let userdata = UserData::new(
+            "zeronet_user".to_string(),
+            "1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX".to_string(),
+        );
+        assert!(userdata.validate_auth_address().is_ok());
+
+        for (address, error) in [
+            (
+                "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3",
+                AddressError::InvalidChecksum,
+            ),
+            (
+                "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN0",
+                AddressError::InvalidCharacter('0'),
+            ),
+            // P2SH
+            (
+                "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy",
+                AddressError::UnsupportedVersion(5),
+            ),
+            ("1FBbx", AddressError::InvalidLength(5)),
+            ("", AddressError::InvalidLength(0)),
+        ] {
+            assert_eq!(Err(error), AuthAddress::parse(address), "{address}");
+        }
+    }
+
+    #[test]
+    fn test_p2wpkh_address() {
+        let address = AuthAddress::parse("BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4").unwrap();
+        assert_eq!(AddressKind::P2wpkh, address.kind());
+        assert_eq!(
+            "751e76e8199196d454941c45d1b3a323f1433bd6",
+            address
+                .hash160()
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<String>()
+        );
+        assert_eq!(
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+            address.to_string()
+        );
+
+        for (address, error) in [
+            (
+                "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5",
+                AddressError::InvalidChecksum,
+            ),
+            (
+                "bc1qw508d6qejxtdg4y5r3zarvaRy0c5xw7kv8f3t4",
+                AddressError::InvalidCharacter('R'),
+            ),
+            (
+                "bc1qw508d6qejxtdg4y5r3zarvarb0c5xw7kv8f3t4",
+                AddressError::InvalidCharacter('b'),
+            ),
+            // P2WSH
+            (
+                "bc1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3qccfmv3",
+                AddressError::InvalidLength(62),
+            ),
+            // Taproot, segwit version 1
+            (
+                "bc1p5d7rjq7g6rdk2yhzks9smlaqtedr4dekq08ge8ztwac72sfr9rusxg3297",
+                AddressError::InvalidChecksum,
+            ),
+        ] {
+            assert_eq!(Err(error), AuthAddress::parse(address), "{address}");
+        }
+    }
+}
@@ -0,0 +1,487 @@
+#[cfg(feature = "blake3")]
+use crate::blake3;
+#[cfg(feature = "sha3")]
+use crate::sha3;
+use crate::{argon2, AnonIdPowError};
+use sha2::{Digest, Sha256};
+use std::fmt;
+
+/// Hash function a PoW can be calculated with
+///
+/// The hash covers the merged userdata followed by ":nonce", with the nonce in decimal as
+/// written by `NonceBuffer`. `prepare` absorbs the userdata once and `hash` finishes the
+/// hash for a single nonce, so the work on the userdata is shared by every attempt.
+pub trait PoWHash: Sync {
+    /// Hasher state with the userdata absorbed
+    type State: Sync;
+
+    /// Identifier of the algorithm and its parameters, it must not contain ':'
+    fn algo_id(&self) -> String;
+
+    /// Length of the digest in bytes
+    fn output_len(&self) -> usize;
+
+    /// Checks the algorithm's parameters
+    fn validate(&self) -> Result<(), AnonIdPowError> {
+        Ok(())
+    }
+
+    /// Absorbs the merged userdata
+    fn prepare(&self, userdata: &[u8]) -> Self::State;
+
+    /// Writes the digest of the userdata followed by ":nonce" to `out`, which is
+    /// `output_len` bytes long
+    fn hash(&self, state: &Self::State, nonce: usize, out: &mut [u8]);
+}
+
+/// Contains the PoW algorithm
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PoWAlgo {
+    Sha256,
+    #[cfg(feature = "blake3")]
+    Blake3,
+    #[cfg(feature = "sha3")]
+    Sha3_256,
+    /// Memory-hard Argon2id (RFC 9106) with a 32 byte tag
+    ///
+    /// "userdata:nonce" is the password and `ARGON2ID_SALT` the salt.
+    Argon2id {
+        /// Memory in KiB, between `8 * parallelism` and `MAX_ARGON2ID_MEMORY_KIB`
+        memory_cost: u32,
+        /// Number of passes over the memory, between 1 and `MAX_ARGON2ID_TIME_COST`
+        time_cost: u32,
+        /// Number of lanes, between 1 and `MAX_ARGON2ID_PARALLELISM`
+        parallelism: u32,
+    },
+}
+
+/// Salt used by `PoWAlgo::Argon2id`, the userdata already makes every password unique
+pub const ARGON2ID_SALT: &[u8] = b"anonid_pow";
+
+/// Highest Argon2id memory cost `PoWAlgo::validate` accepts, 1 GiB
+///
+/// The parameters of a proof come from its submitter, the caps bound the memory and time a
+/// single verification can take.
+pub const MAX_ARGON2ID_MEMORY_KIB: u32 = 1 << 20;
+/// Highest Argon2id time cost `PoWAlgo::validate` accepts
+pub const MAX_ARGON2ID_TIME_COST: u32 = 16;
+/// Highest Argon2id parallelism `PoWAlgo::validate` accepts
+pub const MAX_ARGON2ID_PARALLELISM: u32 = 64;
+
+impl PoWAlgo {
+    /// Initializes a PoWAlgo from its name
+    ///
+    /// Argon2id carries its parameters in the name, "argon2id,m=19456,t=2,p=1".
+    pub fn from_name(name: &str) -> Option<PoWAlgo> {
+        match name {
+            "sha256" => Some(PoWAlgo::Sha256),
+            #[cfg(feature = "blake3")]
+            "blake3" => Some(PoWAlgo::Blake3),
+            #[cfg(feature = "sha3")]
+            "sha3-256" => Some(PoWAlgo::Sha3_256),
+            _ => {
+                let mut params = name.strip_prefix("argon2id,")?.split(',');
+
+                let mut param = |key: &str| -> Option<u32> {
+                    params
+                        .next()?
+                        .strip_prefix(key)?
+                        .strip_prefix('=')?
+                        .parse()
+                        .ok()
+                };
+                let algo = PoWAlgo::Argon2id {
+                    memory_cost: param("m")?,
+                    time_cost: param("t")?,
+                    parallelism: param("p")?,
+                };
+
+                params.next().is_none().then_some(algo)
+            }
+        }
+    }
+
+    /// Checks the algorithm's parameters
+    ///
+    /// Argon2id parameters have to be valid for RFC 9106 and within the `MAX_ARGON2ID_*` caps.
+    pub fn validate(&self) -> Result<(), AnonIdPowError> {
+        match *self {
+            PoWAlgo::Argon2id {
+                memory_cost,
+                time_cost,
+                parallelism,
+            } => {
+                let within_caps = memory_cost <= MAX_ARGON2ID_MEMORY_KIB
+                    && time_cost <= MAX_ARGON2ID_TIME_COST
+                    && parallelism <= MAX_ARGON2ID_PARALLELISM;
+
+                if within_caps && self.argon2_params().is_some_and(|params| params.is_valid()) {
+                    Ok(())
+                } else {
+                    Err(AnonIdPowError::InvalidAlgoParameters(self.to_string()))
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn argon2_params(&self) -> Option<argon2::Params> {
+        match *self {
+            PoWAlgo::Argon2id {
+                memory_cost,
+                time_cost,
+                parallelism,
+            } => Some(argon2::Params {
+                memory_cost,
+                time_cost,
+                parallelism,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Calculates the Hash based on the algorithm
+    ///
+    /// Fails if the algorithm's parameters are invalid, see `validate`.
+    pub fn calculate(&self, userdata: &str, nonce: usize) -> Result<String, AnonIdPowError> {
+        Ok(to_hex(&self.prepare(userdata)?.digest(nonce)))
+    }
+
+    /// Absorbs the userdata once so the hash can be calculated for many nonces
+    ///
+    /// Fails if the algorithm's parameters are invalid, see `validate`.
+    pub fn prepare(&self, userdata: &str) -> Result<PreparedAlgo, AnonIdPowError> {
+        self.validate()?;
+
+        Ok(PoWHash::prepare(self, userdata.as_bytes()))
+    }
+}
+
+impl PoWHash for PoWAlgo {
+    type State = PreparedAlgo;
+
+    fn algo_id(&self) -> String {
+        self.to_string()
+    }
+
+    fn output_len(&self) -> usize {
+        32
+    }
+
+    fn validate(&self) -> Result<(), AnonIdPowError> {
+        PoWAlgo::validate(self)
+    }
+
+    /// Panics if the parameters are invalid, `PoW::new` rejects those before preparing
+    fn prepare(&self, userdata: &[u8]) -> PreparedAlgo {
+        if let Err(error) = self.validate() {
+            panic!("{error}");
+        }
+
+        let state = match self {
+            PoWAlgo::Sha256 => {
+                let mut hasher = Sha256::new();
+                hasher.update(userdata);
+
+                PreparedState::Sha256(hasher)
+            }
+            #[cfg(feature = "blake3")]
+            PoWAlgo::Blake3 => {
+                let mut hasher = blake3::Blake3::new();
+                hasher.update(userdata);
+
+                PreparedState::Blake3(hasher)
+            }
+            #[cfg(feature = "sha3")]
+            PoWAlgo::Sha3_256 => {
+                let mut hasher = sha3::Sha3_256::new();
+                hasher.update(userdata);
+
+                PreparedState::Sha3_256(Box::new(hasher))
+            }
+            PoWAlgo::Argon2id { .. } => PreparedState::Argon2id {
+                params: self.argon2_params().unwrap(),
+                userdata: userdata.to_vec(),
+            },
+        };
+
+        PreparedAlgo { state }
+    }
+
+    fn hash(&self, state: &PreparedAlgo, nonce: usize, out: &mut [u8]) {
+        out.copy_from_slice(&state.digest(nonce));
+    }
+}
+
+impl fmt::Display for PoWAlgo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PoWAlgo::Sha256 => write!(f, "sha256"),
+            #[cfg(feature = "blake3")]
+            PoWAlgo::Blake3 => write!(f, "blake3"),
+            #[cfg(feature = "sha3")]
+            PoWAlgo::Sha3_256 => write!(f, "sha3-256"),
+            PoWAlgo::Argon2id {
+                memory_cost,
+                time_cost,
+                parallelism,
+            } => write!(f, "argon2id,m={memory_cost},t={time_cost},p={parallelism}"),
+        }
+    }
+}
+
+/// Hasher state of a PoW algorithm with the userdata already absorbed
+#[derive(Clone)]
+pub struct PreparedAlgo {
+    state: PreparedState,
+}
+
+/// Argon2id has no state to share between nonces, only the userdata is kept
+#[derive(Clone)]
+enum PreparedState {
+    Sha256(Sha256),
+    #[cfg(feature = "blake3")]
+    Blake3(blake3::Blake3),
+    #[cfg(feature = "sha3")]
+    Sha3_256(Box<sha3::Sha3_256>),
+    Argon2id {
+        params: argon2::Params,
+        userdata: Vec<u8>,
+    },
+}
+
+impl PreparedAlgo {
+    /// Calculates the raw digest of "userdata:nonce"
+    pub fn digest(&self, nonce: usize) -> [u8; 32] {
+        let mut buffer = NonceBuffer::new();
+        let nonce = buffer.format(nonce);
+
+        match &self.state {
+            PreparedState::Sha256(hasher) => {
+                let mut hasher = hasher.clone();
+                hasher.update(nonce);
+
+                hasher.finalize().into()
+            }
+            #[cfg(feature = "blake3")]
+            PreparedState::Blake3(hasher) => {
+                let mut hasher = hasher.clone();
+                hasher.update(nonce);
+
+                hasher.finalize()
+            }
+            #[cfg(feature = "sha3")]
+            PreparedState::Sha3_256(hasher) => {
+                let mut hasher = sha3::Sha3_256::clone(hasher);
+                hasher.update(nonce);
+
+                hasher.finalize()
+            }
+            PreparedState::Argon2id { params, userdata } => {
+                let mut password = Vec::with_capacity(userdata.len() + nonce.len());
+                password.extend_from_slice(userdata);
+                password.extend_from_slice(nonce);
+
+                let mut digest = [0; 32];
+                argon2::argon2id(*params, &password, ARGON2ID_SALT, &[], &[], &mut digest);
+
+                digest
+            }
+        }
+    }
+}
+
+/// Stack buffer holding ":nonce" in decimal, large enough for `usize::MAX`
+pub struct NonceBuffer {
+    bytes: [u8; 21],
+}
+
+impl NonceBuffer {
+    /// Initializes an empty NonceBuffer
+    pub fn new() -> NonceBuffer {
+        NonceBuffer { bytes: [0; 21] }
+    }
+
+    /// Writes ":nonce" to the buffer and returns it
+    pub fn format(&mut self, mut nonce: usize) -> &[u8] {
+        let mut start = self.bytes.len();
+        loop {
+            start -= 1;
+            self.bytes[start] = b'0' + (nonce % 10) as u8;
+            nonce /= 10;
+
+            if nonce == 0 {
+                break;
+            }
+        }
+
+        start -= 1;
+        self.bytes[start] = b':';
+
+        &self.bytes[start..]
+    }
+}
+
+impl Default for NonceBuffer {
+    fn default() -> NonceBuffer {
+        NonceBuffer::new()
+    }
+}
+
+/// Formats a raw digest as lowercase hex
+pub(crate) fn to_hex(digest: &[u8]) -> String {
+    digest.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NonceBuffer, PoWAlgo, PoWHash};
+    use crate::{AnonIdPowError, PoW, UserData};
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn test_pow_algo() {
+        let userdata = "1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user";
+        let nonce = 666;
+
+        let hash = "0729afa04e84848b8535f35df9dab0bad39b1e4c56a2d82443e2ecd89aca1483";
+
+        let pow_algo = PoWAlgo::Sha256;
+        let computed_hash = pow_algo.calculate(userdata, nonce).unwrap();
+
+        assert_eq!(hash, computed_hash);
+
+        let prepared = pow_algo.prepare(userdata).unwrap();
+        for nonce in [0, 9, 10, 666, 6589658, usize::MAX] {
+            let mut hasher = Sha256::new();
+            hasher.update(format!("{userdata}:{nonce}").as_bytes());
+
+            assert_eq!(<[u8; 32]>::from(hasher.finalize()), prepared.digest(nonce));
+        }
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn test_pow_algo_blake3() {
+        let userdata = "1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user";
+        let nonce = 666;
+
+        let hash = "89333ed6e32a2674932097cb5a9ba1fd80fb0dedb7cec69e6b1447af4de934ca";
+
+        let pow_algo = PoWAlgo::Blake3;
+        assert_eq!(hash, pow_algo.calculate(userdata, nonce).unwrap());
+        assert_eq!(Some(PoWAlgo::Blake3), PoWAlgo::from_name("blake3"));
+    }
+
+    #[cfg(feature = "sha3")]
+    #[test]
+    fn test_pow_algo_sha3_256() {
+        let userdata = "1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user";
+        let nonce = 666;
+
+        let hash = "0445fe486cb879170afeda80f4fede4ed5255319e3cfdc0358daa02a1caea92a";
+
+        let pow_algo = PoWAlgo::Sha3_256;
+        assert_eq!(hash, pow_algo.calculate(userdata, nonce).unwrap());
+        assert_eq!(Some(PoWAlgo::Sha3_256), PoWAlgo::from_name("sha3-256"));
+    }
+
+    #[test]
+    fn test_pow_algo_argon2id() {
+        let userdata = "1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user";
+
+        let pow_algo = PoWAlgo::Argon2id {
+            memory_cost: 64,
+            time_cost: 2,
+            parallelism: 2,
+        };
+        assert_eq!("argon2id,m=64,t=2,p=2", pow_algo.to_string());
+        assert_eq!(
+            Some(pow_algo.clone()),
+            PoWAlgo::from_name("argon2id,m=64,t=2,p=2")
+        );
+        assert_eq!(None, PoWAlgo::from_name("argon2id,m=64,t=2"));
+        assert_eq!(None, PoWAlgo::from_name("argon2id,m=64,t=2,p=2,x=1"));
+
+        let hash = "328f576a7a2f4f441b2a81ae3c8ff9462d883e68894f2cac4cba00c782e4c0fc";
+        assert_eq!(hash, pow_algo.calculate(userdata, 666).unwrap());
+
+        let userdata = UserData::from_merged(userdata.to_string()).unwrap();
+        let pow = PoW::new(userdata.clone(), 6, pow_algo).unwrap();
+        let (hash, nonce) = pow.calculate_pow().unwrap();
+        assert!(pow.verify_pow((hash, nonce)));
+
+        // Invalid parameters are rejected before any memory is allocated or divided by the
+        // number of lanes
+        for invalid_algo in [
+            PoWAlgo::Argon2id {
+                memory_cost: 64,
+                time_cost: 1,
+                parallelism: 0,
+            },
+            PoWAlgo::Argon2id {
+                memory_cost: u32::MAX,
+                time_cost: 1,
+                parallelism: 1,
+            },
+        ] {
+            let error = Err(AnonIdPowError::InvalidAlgoParameters(
+                invalid_algo.to_string(),
+            ));
+            assert_eq!(error, invalid_algo.calculate(userdata.merge().as_str(), 0));
+            assert!(invalid_algo.prepare("").is_err());
+        }
+
+        let invalid_algo = PoWAlgo::Argon2id {
+            memory_cost: 8,
+            time_cost: 1,
+            parallelism: 2,
+        };
+        assert_eq!(
+            Some(AnonIdPowError::InvalidAlgoParameters(
+                "argon2id,m=8,t=1,p=2".to_string()
+            )),
+            PoW::new(userdata, 6, invalid_algo).err()
+        );
+
+        for name in [
+            "argon2id,m=1048576,t=16,p=64",
+            "argon2id,m=1048577,t=1,p=1",
+            "argon2id,m=64,t=17,p=1",
+            "argon2id,m=1024,t=1,p=65",
+        ] {
+            let algo = PoWAlgo::from_name(name).unwrap();
+            assert_eq!(
+                name == "argon2id,m=1048576,t=16,p=64",
+                algo.validate().is_ok()
+            );
+        }
+    }
+
+    #[test]
+    fn test_nonce_buffer() {
+        let mut buffer = NonceBuffer::new();
+
+        assert_eq!(b":0", buffer.format(0));
+        assert_eq!(b":6589658", buffer.format(6589658));
+        assert_eq!(
+            format!(":{}", usize::MAX).as_bytes(),
+            buffer.format(usize::MAX)
+        );
+    }
+
+    #[test]
+    fn test_pow_hash_trait() {
+        let userdata = "1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user";
+
+        let state = PoWHash::prepare(&PoWAlgo::Sha256, userdata.as_bytes());
+        let mut digest = [0; 32];
+        PoWAlgo::Sha256.hash(&state, 666, &mut digest);
+
+        assert_eq!("sha256", PoWAlgo::Sha256.algo_id());
+        assert_eq!(
+            PoWAlgo::Sha256.calculate(userdata, 666).unwrap(),
+            super::to_hex(&digest)
+        );
+    }
+}
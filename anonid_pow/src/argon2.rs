@@ -0,0 +1,520 @@
+//! Argon2id (RFC 9106) and the BLAKE2b hash it is built on
+
+/// Size of an Argon2 memory block in 64-bit words
+const BLOCK_WORDS: usize = 128;
+
+/// Number of slices each lane is split into, lanes synchronize at the end of every slice
+const SYNC_POINTS: usize = 4;
+
+/// Argon2 version 1.3
+const VERSION: u32 = 0x13;
+
+/// Argon2 type identifier of Argon2id
+const ARGON2ID: u32 = 2;
+
+const BLAKE2B_IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+const BLAKE2B_SIGMA: [[usize; 16]; 12] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+];
+
+/// Unkeyed BLAKE2b with an output length between 1 and 64 bytes
+struct Blake2b {
+    state: [u64; 8],
+    buffer: [u8; 128],
+    buffer_len: usize,
+    counter: u128,
+    out_len: usize,
+}
+
+impl Blake2b {
+    fn new(out_len: usize) -> Blake2b {
+        debug_assert!((1..=64).contains(&out_len));
+
+        let mut state = BLAKE2B_IV;
+        state[0] ^= 0x0101_0000 ^ out_len as u64;
+
+        Blake2b {
+            state,
+            buffer: [0; 128],
+            buffer_len: 0,
+            counter: 0,
+            out_len,
+        }
+    }
+
+    fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            // The last block is only compressed in `finalize`, so a full buffer is kept
+            // until more data arrives
+            if self.buffer_len == 128 {
+                self.counter += 128;
+                let block = self.buffer;
+                self.compress(&block, false);
+                self.buffer_len = 0;
+            }
+
+            let take = data.len().min(128 - self.buffer_len);
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&data[..take]);
+            self.buffer_len += take;
+            data = &data[take..];
+        }
+    }
+
+    fn finalize(mut self, out: &mut [u8]) {
+        self.counter += self.buffer_len as u128;
+        self.buffer[self.buffer_len..].fill(0);
+        let block = self.buffer;
+        self.compress(&block, true);
+
+        let mut bytes = [0; 64];
+        for (chunk, word) in bytes.chunks_exact_mut(8).zip(self.state) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        out.copy_from_slice(&bytes[..self.out_len]);
+    }
+
+    fn compress(&mut self, block: &[u8; 128], last: bool) {
+        let mut message = [0u64; 16];
+        for (word, chunk) in message.iter_mut().zip(block.chunks_exact(8)) {
+            *word = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+
+        let mut v = [0u64; 16];
+        v[..8].copy_from_slice(&self.state);
+        v[8..].copy_from_slice(&BLAKE2B_IV);
+        v[12] ^= self.counter as u64;
+        v[13] ^= (self.counter >> 64) as u64;
+        if last {
+            v[14] = !v[14];
+        }
+
+        for sigma in BLAKE2B_SIGMA {
+            let mut mix = |a: usize, b: usize, c: usize, d: usize, x: u64, y: u64| {
+                v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+                v[d] = (v[d] ^ v[a]).rotate_right(32);
+                v[c] = v[c].wrapping_add(v[d]);
+                v[b] = (v[b] ^ v[c]).rotate_right(24);
+                v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+                v[d] = (v[d] ^ v[a]).rotate_right(16);
+                v[c] = v[c].wrapping_add(v[d]);
+                v[b] = (v[b] ^ v[c]).rotate_right(63);
+            };
+
+            mix(0, 4, 8, 12, message[sigma[0]], message[sigma[1]]);
+            mix(1, 5, 9, 13, message[sigma[2]], message[sigma[3]]);
+            mix(2, 6, 10, 14, message[sigma[4]], message[sigma[5]]);
+            mix(3, 7, 11, 15, message[sigma[6]], message[sigma[7]]);
+            mix(0, 5, 10, 15, message[sigma[8]], message[sigma[9]]);
+            mix(1, 6, 11, 12, message[sigma[10]], message[sigma[11]]);
+            mix(2, 7, 8, 13, message[sigma[12]], message[sigma[13]]);
+            mix(3, 4, 9, 14, message[sigma[14]], message[sigma[15]]);
+        }
+
+        for (index, word) in self.state.iter_mut().enumerate() {
+            *word ^= v[index] ^ v[index + 8];
+        }
+    }
+}
+
+/// Variable-length hash H' built from BLAKE2b
+fn blake2b_long(inputs: &[&[u8]], out: &mut [u8]) {
+    let out_len_bytes = (out.len() as u32).to_le_bytes();
+
+    if out.len() <= 64 {
+        let mut hasher = Blake2b::new(out.len());
+        hasher.update(&out_len_bytes);
+        for input in inputs {
+            hasher.update(input);
+        }
+        hasher.finalize(out);
+        return;
+    }
+
+    let mut hasher = Blake2b::new(64);
+    hasher.update(&out_len_bytes);
+    for input in inputs {
+        hasher.update(input);
+    }
+    let mut previous = [0; 64];
+    hasher.finalize(&mut previous);
+
+    let mut written = 0;
+    while out.len() - written > 64 {
+        out[written..written + 32].copy_from_slice(&previous[..32]);
+        written += 32;
+
+        let remaining = out.len() - written;
+        let mut hasher = Blake2b::new(remaining.min(64));
+        hasher.update(&previous);
+        if remaining <= 64 {
+            hasher.finalize(&mut out[written..]);
+            return;
+        }
+        hasher.finalize(&mut previous);
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Block([u64; BLOCK_WORDS]);
+
+impl Block {
+    const ZERO: Block = Block([0; BLOCK_WORDS]);
+
+    fn from_bytes(bytes: &[u8]) -> Block {
+        let mut block = Block::ZERO;
+        for (word, chunk) in block.0.iter_mut().zip(bytes.chunks_exact(8)) {
+            *word = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        block
+    }
+
+    fn to_bytes(self) -> [u8; BLOCK_WORDS * 8] {
+        let mut bytes = [0; BLOCK_WORDS * 8];
+        for (chunk, word) in bytes.chunks_exact_mut(8).zip(self.0) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        bytes
+    }
+
+    fn xor(&mut self, other: &Block) {
+        for (word, other) in self.0.iter_mut().zip(other.0) {
+            *word ^= other;
+        }
+    }
+}
+
+/// Compression function G, returns `P(x ^ y) ^ x ^ y`
+fn compress(x: &Block, y: &Block) -> Block {
+    let mut r = *x;
+    r.xor(y);
+
+    let mut z = r;
+    for row in 0..8 {
+        let mut indices = [0; 16];
+        for (index, word) in indices.iter_mut().enumerate() {
+            *word = row * 16 + index;
+        }
+        permute(&mut z, indices);
+    }
+    for column in 0..8 {
+        let mut indices = [0; 16];
+        for (index, word) in indices.iter_mut().enumerate() {
+            *word = column * 2 + (index / 2) * 16 + index % 2;
+        }
+        permute(&mut z, indices);
+    }
+
+    z.xor(&r);
+    z
+}
+
+/// Permutation P applied to the 16 words of a block at `indices`
+fn permute(block: &mut Block, indices: [usize; 16]) {
+    let mut v = [0u64; 16];
+    for (word, index) in v.iter_mut().zip(indices) {
+        *word = block.0[index];
+    }
+
+    let mut mix = |a: usize, b: usize, c: usize, d: usize| {
+        let multiply = |x: u64, y: u64| {
+            2u64.wrapping_mul(x & 0xffff_ffff)
+                .wrapping_mul(y & 0xffff_ffff)
+        };
+
+        v[a] = v[a].wrapping_add(v[b]).wrapping_add(multiply(v[a], v[b]));
+        v[d] = (v[d] ^ v[a]).rotate_right(32);
+        v[c] = v[c].wrapping_add(v[d]).wrapping_add(multiply(v[c], v[d]));
+        v[b] = (v[b] ^ v[c]).rotate_right(24);
+        v[a] = v[a].wrapping_add(v[b]).wrapping_add(multiply(v[a], v[b]));
+        v[d] = (v[d] ^ v[a]).rotate_right(16);
+        v[c] = v[c].wrapping_add(v[d]).wrapping_add(multiply(v[c], v[d]));
+        v[b] = (v[b] ^ v[c]).rotate_right(63);
+    };
+
+    mix(0, 4, 8, 12);
+    mix(1, 5, 9, 13);
+    mix(2, 6, 10, 14);
+    mix(3, 7, 11, 15);
+    mix(0, 5, 10, 15);
+    mix(1, 6, 11, 12);
+    mix(2, 7, 8, 13);
+    mix(3, 4, 9, 14);
+
+    for (word, index) in v.into_iter().zip(indices) {
+        block.0[index] = word;
+    }
+}
+
+/// Cost parameters of Argon2id
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct Params {
+    /// Memory in KiB, at least `8 * parallelism`
+    pub(crate) memory_cost: u32,
+    /// Number of passes over the memory, at least 1
+    pub(crate) time_cost: u32,
+    /// Number of lanes, between 1 and `2^24 - 1`
+    pub(crate) parallelism: u32,
+}
+
+impl Params {
+    pub(crate) fn is_valid(&self) -> bool {
+        (1..1 << 24).contains(&self.parallelism)
+            && self.time_cost >= 1
+            && self.memory_cost as u64 >= 8 * self.parallelism as u64
+    }
+}
+
+/// Calculates the Argon2id tag of `password` and writes it to `out`
+///
+/// The parameters have to be valid, see `Params::is_valid`.
+pub(crate) fn argon2id(
+    params: Params,
+    password: &[u8],
+    salt: &[u8],
+    secret: &[u8],
+    associated_data: &[u8],
+    out: &mut [u8],
+) {
+    assert!(params.is_valid(), "invalid Argon2id parameters");
+
+    let lanes = params.parallelism as usize;
+    let passes = params.time_cost as usize;
+    let segment_length = params.memory_cost as usize / (SYNC_POINTS * lanes);
+    let lane_length = segment_length * SYNC_POINTS;
+    let memory_blocks = lane_length * lanes;
+
+    let mut h0 = [0; 72];
+    let mut hasher = Blake2b::new(64);
+    for value in [
+        params.parallelism,
+        out.len() as u32,
+        params.memory_cost,
+        params.time_cost,
+        VERSION,
+        ARGON2ID,
+    ] {
+        hasher.update(&value.to_le_bytes());
+    }
+    for input in [password, salt, secret, associated_data] {
+        hasher.update(&(input.len() as u32).to_le_bytes());
+        hasher.update(input);
+    }
+    hasher.finalize(&mut h0[..64]);
+
+    let mut memory = vec![Block::ZERO; memory_blocks];
+    for lane in 0..lanes {
+        h0[68..].copy_from_slice(&(lane as u32).to_le_bytes());
+        for column in 0..2 {
+            h0[64..68].copy_from_slice(&(column as u32).to_le_bytes());
+
+            let mut bytes = [0; BLOCK_WORDS * 8];
+            blake2b_long(&[&h0], &mut bytes);
+            memory[lane * lane_length + column] = Block::from_bytes(&bytes);
+        }
+    }
+
+    for pass in 0..passes {
+        for slice in 0..SYNC_POINTS {
+            for lane in 0..lanes {
+                let data_independent = pass == 0 && slice < SYNC_POINTS / 2;
+
+                let mut input_block = Block::ZERO;
+                let mut address_block = Block::ZERO;
+                if data_independent {
+                    input_block.0[..6].copy_from_slice(&[
+                        pass as u64,
+                        lane as u64,
+                        slice as u64,
+                        memory_blocks as u64,
+                        passes as u64,
+                        ARGON2ID as u64,
+                    ]);
+                }
+                let next_addresses = |input_block: &mut Block| {
+                    input_block.0[6] += 1;
+                    compress(&Block::ZERO, &compress(&Block::ZERO, input_block))
+                };
+
+                let starting_index = if pass == 0 && slice == 0 { 2 } else { 0 };
+                if data_independent && starting_index != 0 {
+                    address_block = next_addresses(&mut input_block);
+                }
+
+                for index in starting_index..segment_length {
+                    let current = lane * lane_length + slice * segment_length + index;
+                    let previous = if current.is_multiple_of(lane_length) {
+                        current + lane_length - 1
+                    } else {
+                        current - 1
+                    };
+
+                    let pseudo_random = if data_independent {
+                        if index % BLOCK_WORDS == 0 {
+                            address_block = next_addresses(&mut input_block);
+                        }
+                        address_block.0[index % BLOCK_WORDS]
+                    } else {
+                        memory[previous].0[0]
+                    };
+
+                    let reference_lane = if pass == 0 && slice == 0 {
+                        lane
+                    } else {
+                        (pseudo_random >> 32) as usize % lanes
+                    };
+                    let same_lane = reference_lane == lane;
+
+                    let reference_area_size = if pass == 0 && slice == 0 {
+                        index - 1
+                    } else {
+                        let finished = if pass == 0 {
+                            slice * segment_length
+                        } else {
+                            lane_length - segment_length
+                        };
+
+                        if same_lane {
+                            finished + index - 1
+                        } else if index == 0 {
+                            finished - 1
+                        } else {
+                            finished
+                        }
+                    } as u64;
+
+                    let relative_position = pseudo_random & 0xffff_ffff;
+                    let relative_position = (relative_position * relative_position) >> 32;
+                    let relative_position =
+                        reference_area_size - 1 - ((reference_area_size * relative_position) >> 32);
+
+                    let start_position = if pass != 0 && slice != SYNC_POINTS - 1 {
+                        (slice + 1) * segment_length
+                    } else {
+                        0
+                    };
+                    let reference_index =
+                        (start_position + relative_position as usize) % lane_length;
+
+                    let mut block = compress(
+                        &memory[previous],
+                        &memory[reference_lane * lane_length + reference_index],
+                    );
+                    if pass != 0 {
+                        block.xor(&memory[current]);
+                    }
+                    memory[current] = block;
+                }
+            }
+        }
+    }
+
+    let mut last = memory[lane_length - 1];
+    for lane in 1..lanes {
+        last.xor(&memory[lane * lane_length + lane_length - 1]);
+    }
+
+    blake2b_long(&[&last.to_bytes()], out);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{argon2id, blake2b_long, Blake2b, Params};
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    #[test]
+    fn test_blake2b() {
+        let mut out = [0; 64];
+        let mut hasher = Blake2b::new(64);
+        hasher.update(b"abc");
+        hasher.finalize(&mut out);
+
+        assert_eq!(
+            "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1\
+             7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923",
+            to_hex(&out)
+        );
+
+        let mut out = [0; 32];
+        let mut hasher = Blake2b::new(32);
+        hasher.update(&[0x61; 200]);
+        hasher.finalize(&mut out);
+        let mut split_out = [0; 32];
+        let mut hasher = Blake2b::new(32);
+        hasher.update(&[0x61; 128]);
+        hasher.update(&[0x61; 72]);
+        hasher.finalize(&mut split_out);
+
+        assert_eq!(out, split_out);
+
+        let mut out = [0; 100];
+        blake2b_long(&[b"abc"], &mut out);
+        assert_eq!(
+            &out[..32],
+            &{
+                let mut first = [0; 64];
+                let mut hasher = Blake2b::new(64);
+                hasher.update(&100u32.to_le_bytes());
+                hasher.update(b"abc");
+                hasher.finalize(&mut first);
+                first
+            }[..32]
+        );
+    }
+
+    #[test]
+    fn test_argon2id_rfc9106() {
+        let params = Params {
+            memory_cost: 32,
+            time_cost: 3,
+            parallelism: 4,
+        };
+
+        let mut tag = [0; 32];
+        argon2id(params, &[1; 32], &[2; 16], &[3; 8], &[4; 12], &mut tag);
+
+        assert_eq!(
+            "0d640df58d78766c08c037a34a8b53c9d01ef0452d75b65eb52520e96b01e659",
+            to_hex(&tag)
+        );
+    }
+
+    #[test]
+    fn test_argon2id_params() {
+        let params = |memory_cost, time_cost, parallelism| Params {
+            memory_cost,
+            time_cost,
+            parallelism,
+        };
+
+        assert!(params(8, 1, 1).is_valid());
+        assert!(params(19456, 2, 1).is_valid());
+        assert!(!params(7, 1, 1).is_valid());
+        assert!(!params(31, 1, 4).is_valid());
+        assert!(!params(64, 0, 1).is_valid());
+        assert!(!params(64, 1, 0).is_valid());
+    }
+}
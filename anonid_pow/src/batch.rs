@@ -0,0 +1,196 @@
+use std::num::NonZeroUsize;
+
+use crate::algo::to_hex;
+use crate::{
+    DifficultyCurve, LengthMetric, PoW, PoWAlgo, PoWHash, PreparedAlgo, TargetMode, UserData,
+    VerifyError,
+};
+
+/// A proof to check with `PoW::verify_batch`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifyRequest {
+    /// "auth_address:username"
+    pub merged_userdata: String,
+    pub difficulty: usize,
+    pub algo: PoWAlgo,
+    pub target_mode: TargetMode,
+    pub length_metric: LengthMetric,
+    pub difficulty_policy: DifficultyCurve,
+    pub nonce: usize,
+    /// Hash claimed by the submitter, checked ignoring case when present
+    pub hash: Option<String>,
+}
+
+impl VerifyRequest {
+    fn verify(
+        &self,
+        prepared: &mut Option<(PoWAlgo, String, PreparedAlgo)>,
+    ) -> Result<String, VerifyError> {
+        let userdata = UserData::from_merged(self.merged_userdata.clone())
+            .filter(|userdata| userdata.merge() == self.merged_userdata)
+            .ok_or(VerifyError::MalformedUserData)?;
+
+        let pow = PoW::new(userdata, self.difficulty, self.algo.clone())
+            .map_err(VerifyError::InvalidParameters)?
+            .with_target_mode(self.target_mode)
+            .with_length_metric(self.length_metric)
+            .with_difficulty_policy(self.difficulty_policy.clone());
+
+        let is_cached = prepared.as_ref().is_some_and(|(algo, merged_userdata, _)| {
+            *algo == self.algo && *merged_userdata == self.merged_userdata
+        });
+        if !is_cached {
+            *prepared = Some((
+                self.algo.clone(),
+                self.merged_userdata.clone(),
+                PoWHash::prepare(&self.algo, self.merged_userdata.as_bytes()),
+            ));
+        }
+        let (_, _, state) = prepared.as_ref().unwrap();
+
+        let digest = state.digest(self.nonce);
+        if !pow.target().is_met(&digest) {
+            return Err(VerifyError::TargetNotMet);
+        }
+
+        let hash = to_hex(&digest);
+        match &self.hash {
+            Some(input_hash) if !input_hash.eq_ignore_ascii_case(&hash) => {
+                Err(VerifyError::WrongHash)
+            }
+            _ => Ok(hash),
+        }
+    }
+}
+
+impl PoW {
+    /// Verifies many proofs on multiple threads and returns the hash or the reason of the
+    /// failure for each of them, in the order of `requests`
+    ///
+    /// Requests are grouped by algorithm and userdata first, so proofs for the same userdata
+    /// share the absorbed userdata state. When `threads` is `None` the available parallelism
+    /// of the machine is used.
+    pub fn verify_batch(
+        requests: &[VerifyRequest],
+        threads: Option<NonZeroUsize>,
+    ) -> Vec<Result<String, VerifyError>> {
+        let threads = threads
+            .or_else(|| std::thread::available_parallelism().ok())
+            .map_or(1, NonZeroUsize::get);
+
+        let keys: Vec<(String, &str)> = requests
+            .iter()
+            .map(|request| (request.algo.to_string(), request.merged_userdata.as_str()))
+            .collect();
+        let mut order: Vec<usize> = (0..requests.len()).collect();
+        order.sort_by(|a, b| keys[*a].cmp(&keys[*b]));
+
+        let chunk_size = order.len().div_ceil(threads).max(1);
+
+        let mut results = vec![Err(VerifyError::TargetNotMet); requests.len()];
+        std::thread::scope(|scope| {
+            let workers: Vec<_> = order
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        let mut prepared = None;
+
+                        chunk
+                            .iter()
+                            .map(|index| (*index, requests[*index].verify(&mut prepared)))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            for worker in workers {
+                let verified = worker.join().expect("verification worker thread panicked");
+                for (index, result) in verified {
+                    results[index] = result;
+                }
+            }
+        });
+
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VerifyRequest;
+    use crate::{
+        AnonIdPowError, DifficultyCurve, LengthMetric, PoW, PoWAlgo, TargetMode, UserData,
+        VerifyError,
+    };
+    use std::num::NonZeroUsize;
+
+    const HASH: &str = "ffffff419e9de8f5a3b958da92eb19ed8b6cc6da591de7fec0a2e7250c804047";
+
+    fn request(merged_userdata: &str, difficulty: usize, nonce: usize) -> VerifyRequest {
+        VerifyRequest {
+            merged_userdata: merged_userdata.to_string(),
+            difficulty,
+            algo: PoWAlgo::Sha256,
+            target_mode: TargetMode::HexPrefix,
+            length_metric: LengthMetric::Bytes,
+            difficulty_policy: DifficultyCurve::Legacy,
+            nonce,
+            hash: None,
+        }
+    }
+
+    #[test]
+    fn test_verify_batch() {
+        let merged_userdata = "1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user";
+
+        let mut requests = vec![
+            request(merged_userdata, 24, 6589658),
+            request(merged_userdata, 24, 6589659),
+            VerifyRequest {
+                hash: Some(HASH.to_uppercase()),
+                ..request(merged_userdata, 24, 6589658)
+            },
+            VerifyRequest {
+                hash: Some(HASH.replace('4', "5")),
+                ..request(merged_userdata, 24, 6589658)
+            },
+            request("1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX", 24, 6589658),
+            request("1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zero:net", 24, 6589658),
+            request(merged_userdata, 1, 6589658),
+        ];
+
+        let pow = PoW::new(
+            UserData::new("other".to_string(), "1FBbx".to_string()),
+            8,
+            PoWAlgo::Sha256,
+        )
+        .unwrap();
+        let (hash, nonce) = pow.calculate_pow().unwrap();
+        requests.push(request("1FBbx:other", 8, nonce));
+
+        let expected = vec![
+            Ok(HASH.to_string()),
+            Err(VerifyError::TargetNotMet),
+            Ok(HASH.to_string()),
+            Err(VerifyError::WrongHash),
+            Err(VerifyError::MalformedUserData),
+            Err(VerifyError::MalformedUserData),
+            Err(VerifyError::InvalidParameters(
+                AnonIdPowError::DifficultyTooLow {
+                    difficulty: 1,
+                    min: 2,
+                },
+            )),
+            Ok(hash),
+        ];
+
+        for threads in [1, 3, 16] {
+            assert_eq!(
+                expected,
+                PoW::verify_batch(&requests, NonZeroUsize::new(threads))
+            );
+        }
+        assert_eq!(expected, PoW::verify_batch(&requests, None));
+        assert!(PoW::verify_batch(&[], None).is_empty());
+    }
+}
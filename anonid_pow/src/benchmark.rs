@@ -0,0 +1,94 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::PoWHash;
+
+/// Userdata hashed by `benchmark`, the hash rate doesn't depend on its content
+const BENCHMARK_USERDATA: &[u8] = b"1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user";
+
+/// Result of `benchmark`
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchmarkReport {
+    /// `PoWHash::algo_id` of the benchmarked algorithm
+    pub algo: String,
+    /// Number of hashes computed
+    pub hashes: u64,
+    /// Time spent computing them
+    pub elapsed: Duration,
+    /// Hashes per second
+    pub hash_rate: f64,
+}
+
+impl fmt::Display for BenchmarkReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: {:.0} H/s ({} hashes in {:.3}s)",
+            self.algo,
+            self.hash_rate,
+            self.hashes,
+            self.elapsed.as_secs_f64()
+        )
+    }
+}
+
+/// Measures how many hashes per second a single thread computes with `algo`
+///
+/// Hashes are computed for about `duration`, but at least one is. Every hash uses the
+/// prepared state of fixed userdata and a new nonce, like `calculate_pow` does.
+pub fn benchmark<A: PoWHash>(algo: &A, duration: Duration) -> BenchmarkReport {
+    let state = algo.prepare(BENCHMARK_USERDATA);
+    let mut digest = vec![0; algo.output_len()];
+
+    let started = Instant::now();
+    let mut hashes: u64 = 0;
+    // Batches double while they are short, so slow algorithms don't overshoot the duration
+    // and fast ones don't spend their time reading the clock
+    let mut batch: u64 = 1;
+    loop {
+        let batch_started = Instant::now();
+        for nonce in hashes..hashes + batch {
+            algo.hash(&state, nonce as usize, &mut digest);
+        }
+        hashes += batch;
+
+        let elapsed = started.elapsed();
+        if elapsed >= duration {
+            return BenchmarkReport {
+                algo: algo.algo_id(),
+                hashes,
+                elapsed,
+                hash_rate: hashes as f64 / elapsed.as_secs_f64(),
+            };
+        }
+        if batch_started.elapsed() * 16 < duration {
+            batch *= 2;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::benchmark;
+    use crate::PoWAlgo;
+    use std::time::Duration;
+
+    #[test]
+    fn test_benchmark() {
+        let duration = Duration::from_millis(20);
+        let report = benchmark(&PoWAlgo::Sha256, duration);
+
+        assert_eq!("sha256", report.algo);
+        assert!(report.elapsed >= duration);
+        assert!(report.hashes > 1);
+        assert!(report.hash_rate > 0.0 && report.hash_rate.is_finite());
+        assert!(report.to_string().starts_with("sha256: "));
+
+        let slow_algo = PoWAlgo::Argon2id {
+            memory_cost: 1024,
+            time_cost: 1,
+            parallelism: 1,
+        };
+        assert_eq!(1, benchmark(&slow_algo, Duration::ZERO).hashes);
+    }
+}
@@ -0,0 +1,306 @@
+//! BLAKE3 in its default hashing mode with a 32 byte output
+
+const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const MESSAGE_PERMUTATION: [usize; 16] = [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8];
+
+const BLOCK_LEN: usize = 64;
+const CHUNK_LEN: usize = 1024;
+
+const CHUNK_START: u32 = 1 << 0;
+const CHUNK_END: u32 = 1 << 1;
+const PARENT: u32 = 1 << 2;
+const ROOT: u32 = 1 << 3;
+
+fn compress(
+    chaining_value: &[u32; 8],
+    block_words: &[u32; 16],
+    counter: u64,
+    block_len: u32,
+    flags: u32,
+) -> [u32; 16] {
+    let mut state = [
+        chaining_value[0],
+        chaining_value[1],
+        chaining_value[2],
+        chaining_value[3],
+        chaining_value[4],
+        chaining_value[5],
+        chaining_value[6],
+        chaining_value[7],
+        IV[0],
+        IV[1],
+        IV[2],
+        IV[3],
+        counter as u32,
+        (counter >> 32) as u32,
+        block_len,
+        flags,
+    ];
+    let mut message = *block_words;
+
+    for round in 0..7 {
+        let mut mix = |a: usize, b: usize, c: usize, d: usize, x: u32, y: u32| {
+            state[a] = state[a].wrapping_add(state[b]).wrapping_add(x);
+            state[d] = (state[d] ^ state[a]).rotate_right(16);
+            state[c] = state[c].wrapping_add(state[d]);
+            state[b] = (state[b] ^ state[c]).rotate_right(12);
+            state[a] = state[a].wrapping_add(state[b]).wrapping_add(y);
+            state[d] = (state[d] ^ state[a]).rotate_right(8);
+            state[c] = state[c].wrapping_add(state[d]);
+            state[b] = (state[b] ^ state[c]).rotate_right(7);
+        };
+
+        mix(0, 4, 8, 12, message[0], message[1]);
+        mix(1, 5, 9, 13, message[2], message[3]);
+        mix(2, 6, 10, 14, message[4], message[5]);
+        mix(3, 7, 11, 15, message[6], message[7]);
+        mix(0, 5, 10, 15, message[8], message[9]);
+        mix(1, 6, 11, 12, message[10], message[11]);
+        mix(2, 7, 8, 13, message[12], message[13]);
+        mix(3, 4, 9, 14, message[14], message[15]);
+
+        if round < 6 {
+            let previous = message;
+            for (word, index) in message.iter_mut().zip(MESSAGE_PERMUTATION) {
+                *word = previous[index];
+            }
+        }
+    }
+
+    for index in 0..8 {
+        state[index] ^= state[index + 8];
+        state[index + 8] ^= chaining_value[index];
+    }
+    state
+}
+
+fn first_8_words(words: [u32; 16]) -> [u32; 8] {
+    words[..8].try_into().unwrap()
+}
+
+fn words_from_le_bytes(bytes: &[u8; BLOCK_LEN]) -> [u32; 16] {
+    let mut words = [0; 16];
+    for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+        *word = u32::from_le_bytes(chunk.try_into().unwrap());
+    }
+    words
+}
+
+/// Input of the last compression of a node, either a chaining value or the root hash
+struct Output {
+    input_chaining_value: [u32; 8],
+    block_words: [u32; 16],
+    counter: u64,
+    block_len: u32,
+    flags: u32,
+}
+
+impl Output {
+    fn chaining_value(&self) -> [u32; 8] {
+        first_8_words(compress(
+            &self.input_chaining_value,
+            &self.block_words,
+            self.counter,
+            self.block_len,
+            self.flags,
+        ))
+    }
+
+    fn root_hash(&self) -> [u8; 32] {
+        let words = compress(
+            &self.input_chaining_value,
+            &self.block_words,
+            0,
+            self.block_len,
+            self.flags | ROOT,
+        );
+
+        let mut hash = [0; 32];
+        for (chunk, word) in hash.chunks_exact_mut(4).zip(words) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        hash
+    }
+}
+
+#[derive(Clone)]
+struct ChunkState {
+    chaining_value: [u32; 8],
+    chunk_counter: u64,
+    block: [u8; BLOCK_LEN],
+    block_len: usize,
+    blocks_compressed: usize,
+}
+
+impl ChunkState {
+    fn new(chunk_counter: u64) -> ChunkState {
+        ChunkState {
+            chaining_value: IV,
+            chunk_counter,
+            block: [0; BLOCK_LEN],
+            block_len: 0,
+            blocks_compressed: 0,
+        }
+    }
+
+    fn len(&self) -> usize {
+        BLOCK_LEN * self.blocks_compressed + self.block_len
+    }
+
+    fn start_flag(&self) -> u32 {
+        if self.blocks_compressed == 0 {
+            CHUNK_START
+        } else {
+            0
+        }
+    }
+
+    fn update(&mut self, mut input: &[u8]) {
+        while !input.is_empty() {
+            // The last block of a chunk is compressed by `output` with the CHUNK_END flag,
+            // so a full block is only compressed once more input arrives
+            if self.block_len == BLOCK_LEN {
+                self.chaining_value = first_8_words(compress(
+                    &self.chaining_value,
+                    &words_from_le_bytes(&self.block),
+                    self.chunk_counter,
+                    BLOCK_LEN as u32,
+                    self.start_flag(),
+                ));
+                self.blocks_compressed += 1;
+                self.block = [0; BLOCK_LEN];
+                self.block_len = 0;
+            }
+
+            let take = (BLOCK_LEN - self.block_len).min(input.len());
+            self.block[self.block_len..self.block_len + take].copy_from_slice(&input[..take]);
+            self.block_len += take;
+            input = &input[take..];
+        }
+    }
+
+    fn output(&self) -> Output {
+        Output {
+            input_chaining_value: self.chaining_value,
+            block_words: words_from_le_bytes(&self.block),
+            counter: self.chunk_counter,
+            block_len: self.block_len as u32,
+            flags: self.start_flag() | CHUNK_END,
+        }
+    }
+}
+
+fn parent_output(left: [u32; 8], right: [u32; 8]) -> Output {
+    let mut block_words = [0; 16];
+    block_words[..8].copy_from_slice(&left);
+    block_words[8..].copy_from_slice(&right);
+
+    Output {
+        input_chaining_value: IV,
+        block_words,
+        counter: 0,
+        block_len: BLOCK_LEN as u32,
+        flags: PARENT,
+    }
+}
+
+/// Incremental BLAKE3 hasher, cloning it after absorbing a prefix reuses that work
+#[derive(Clone)]
+pub(crate) struct Blake3 {
+    chunk_state: ChunkState,
+    chaining_value_stack: Vec<[u32; 8]>,
+}
+
+impl Blake3 {
+    pub(crate) fn new() -> Blake3 {
+        Blake3 {
+            chunk_state: ChunkState::new(0),
+            chaining_value_stack: Vec::new(),
+        }
+    }
+
+    /// Merges the completed subtrees, the number of trailing zero bits of the chunk count is
+    /// the number of subtrees the new chunk completes
+    fn add_chunk_chaining_value(&mut self, mut chaining_value: [u32; 8], mut total_chunks: u64) {
+        while total_chunks & 1 == 0 {
+            let left = self.chaining_value_stack.pop().unwrap();
+            chaining_value = parent_output(left, chaining_value).chaining_value();
+            total_chunks >>= 1;
+        }
+        self.chaining_value_stack.push(chaining_value);
+    }
+
+    pub(crate) fn update(&mut self, mut input: &[u8]) {
+        while !input.is_empty() {
+            if self.chunk_state.len() == CHUNK_LEN {
+                let chaining_value = self.chunk_state.output().chaining_value();
+                let total_chunks = self.chunk_state.chunk_counter + 1;
+                self.add_chunk_chaining_value(chaining_value, total_chunks);
+                self.chunk_state = ChunkState::new(total_chunks);
+            }
+
+            let take = (CHUNK_LEN - self.chunk_state.len()).min(input.len());
+            self.chunk_state.update(&input[..take]);
+            input = &input[take..];
+        }
+    }
+
+    pub(crate) fn finalize(&self) -> [u8; 32] {
+        let mut output = self.chunk_state.output();
+        for left in self.chaining_value_stack.iter().rev() {
+            output = parent_output(*left, output.chaining_value());
+        }
+        output.root_hash()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Blake3;
+
+    fn blake3(data: &[u8]) -> String {
+        let mut hasher = Blake3::new();
+        hasher.update(data);
+        hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    #[test]
+    fn test_blake3() {
+        assert_eq!(
+            "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262",
+            blake3(b"")
+        );
+        assert_eq!(
+            "2d3adedff11b61f14c886e35afa036736dcd87a74d27b5c1510225d0f592e213",
+            blake3(&[0])
+        );
+        assert_eq!(
+            "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85",
+            blake3(b"abc")
+        );
+    }
+
+    #[test]
+    fn test_blake3_incremental() {
+        // Input from the official test vectors, long enough to build a tree of chunks
+        let input: Vec<u8> = (0..5000).map(|index| (index % 251) as u8).collect();
+
+        let mut hasher = Blake3::new();
+        for part in input.chunks(333) {
+            hasher.update(part);
+        }
+
+        assert_eq!(blake3(&input), hex(&hasher.finalize()));
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+}
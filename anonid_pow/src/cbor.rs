@@ -0,0 +1,386 @@
+//! Reader and writer for the flat CBOR (RFC 8949) maps proofs are stored as
+//!
+//! Only maps with text keys whose values are unsigned integers, byte strings, or text strings
+//! are read, values of other types are skipped. Maps follow the core deterministic encoding
+//! of RFC 8949 section 4.2.1: every item has to use definite lengths and the shortest
+//! encoding of its argument, and the keys of every map have to be sorted in the bytewise
+//! lexicographic order of their encodings, so each map has exactly one encoding.
+
+const UNSIGNED: u8 = 0;
+const NEGATIVE: u8 = 1;
+const BYTES: u8 = 2;
+const TEXT: u8 = 3;
+const ARRAY: u8 = 4;
+const MAP: u8 = 5;
+const TAG: u8 = 6;
+const SIMPLE: u8 = 7;
+
+/// Nesting depth up to which values of other types are skipped
+const MAX_DEPTH: usize = 16;
+
+/// Value of a field in a flat CBOR map
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum CborValue {
+    Unsigned(u64),
+    Bytes(Vec<u8>),
+    Text(String),
+    /// A value of another type, skipped by `read_map` and never written
+    Other,
+}
+
+fn write_head(cbor: &mut Vec<u8>, major_type: u8, argument: u64) {
+    let major_type = major_type << 5;
+
+    match argument {
+        0..=23 => cbor.push(major_type | argument as u8),
+        24..=0xff => cbor.extend([major_type | 24, argument as u8]),
+        0x100..=0xffff => {
+            cbor.push(major_type | 25);
+            cbor.extend((argument as u16).to_be_bytes());
+        }
+        0x1_0000..=0xffff_ffff => {
+            cbor.push(major_type | 26);
+            cbor.extend((argument as u32).to_be_bytes());
+        }
+        _ => {
+            cbor.push(major_type | 27);
+            cbor.extend(argument.to_be_bytes());
+        }
+    }
+}
+
+/// Writes a flat CBOR map with the keys sorted in the bytewise lexicographic order of their
+/// encodings
+pub(crate) fn write_map(fields: &[(&str, CborValue)]) -> Vec<u8> {
+    let mut entries: Vec<(Vec<u8>, &CborValue)> = fields
+        .iter()
+        .map(|(key, value)| {
+            let mut encoded_key = Vec::new();
+            write_head(&mut encoded_key, TEXT, key.len() as u64);
+            encoded_key.extend(key.as_bytes());
+            (encoded_key, value)
+        })
+        .collect();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut cbor = Vec::new();
+    write_head(&mut cbor, MAP, entries.len() as u64);
+    for (encoded_key, value) in entries {
+        cbor.extend(encoded_key);
+
+        match value {
+            CborValue::Unsigned(value) => write_head(&mut cbor, UNSIGNED, *value),
+            CborValue::Bytes(value) => {
+                write_head(&mut cbor, BYTES, value.len() as u64);
+                cbor.extend(value);
+            }
+            CborValue::Text(value) => {
+                write_head(&mut cbor, TEXT, value.len() as u64);
+                cbor.extend(value.as_bytes());
+            }
+            CborValue::Other => unreachable!("values of other types are never written"),
+        }
+    }
+
+    cbor
+}
+
+/// Reads a flat CBOR map, returns None if the CBOR is malformed or not canonical, a key isn't
+/// text, or a key appears twice
+///
+/// Values of other types are returned as `CborValue::Other`.
+pub(crate) fn read_map(cbor: &[u8]) -> Option<Vec<(String, CborValue)>> {
+    let mut reader = Reader {
+        input: cbor,
+        position: 0,
+    };
+
+    let (major_type, length) = reader.head()?;
+    if major_type != MAP {
+        return None;
+    }
+
+    let mut fields = Vec::new();
+    let mut previous_key: Option<&[u8]> = None;
+    for _ in 0..length {
+        let key_start = reader.position;
+        let (major_type, key_length) = reader.head()?;
+        if major_type != TEXT {
+            return None;
+        }
+        let key = reader.text(key_length)?;
+
+        // Sorted keys also rule out duplicates
+        let encoded_key = &cbor[key_start..reader.position];
+        if previous_key.is_some_and(|previous| previous >= encoded_key) {
+            return None;
+        }
+        previous_key = Some(encoded_key);
+
+        let value = match reader.head()? {
+            (UNSIGNED, value) => CborValue::Unsigned(value),
+            (BYTES, length) => CborValue::Bytes(reader.bytes(length)?.to_vec()),
+            (TEXT, length) => CborValue::Text(reader.text(length)?),
+            (major_type, argument) => {
+                reader.skip(major_type, argument, 1)?;
+                CborValue::Other
+            }
+        };
+        fields.push((key, value));
+    }
+
+    (reader.position == reader.input.len()).then_some(fields)
+}
+
+struct Reader<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl Reader<'_> {
+    fn bytes(&mut self, length: u64) -> Option<&[u8]> {
+        let end = self.position.checked_add(usize::try_from(length).ok()?)?;
+        let bytes = self.input.get(self.position..end)?;
+        self.position = end;
+
+        Some(bytes)
+    }
+
+    fn text(&mut self, length: u64) -> Option<String> {
+        let bytes = self.bytes(length)?;
+
+        String::from_utf8(bytes.to_vec()).ok()
+    }
+
+    /// Reads the major type and argument of the next item
+    fn head(&mut self) -> Option<(u8, u64)> {
+        let initial_byte = self.bytes(1)?[0];
+        let major_type = initial_byte >> 5;
+        let additional = initial_byte & 0x1f;
+
+        let (argument, minimum) = match additional {
+            additional @ 0..=23 => return Some((major_type, additional as u64)),
+            24 => (self.bytes(1)?[0] as u64, 24),
+            25 => (
+                u16::from_be_bytes(self.bytes(2)?.try_into().ok()?) as u64,
+                0x100,
+            ),
+            26 => (
+                u32::from_be_bytes(self.bytes(4)?.try_into().ok()?) as u64,
+                0x1_0000,
+            ),
+            27 => (
+                u64::from_be_bytes(self.bytes(8)?.try_into().ok()?),
+                0x1_0000_0000,
+            ),
+            // Reserved values and indefinite lengths
+            _ => return None,
+        };
+
+        let shortest = match (major_type, additional) {
+            // Simple values below 32 have to use the head without a following byte
+            (SIMPLE, 24) => argument >= 32,
+            // Floats have to lose precision in the next smaller size
+            (SIMPLE, 25) => true,
+            (SIMPLE, 26) => !fits_half(f32::from_bits(argument as u32)),
+            (SIMPLE, 27) => {
+                let value = f64::from_bits(argument);
+                !value.is_nan() && value as f32 as f64 != value
+            }
+            _ => argument >= minimum,
+        };
+
+        shortest.then_some((major_type, argument))
+    }
+
+    /// Skips the content of an item whose head has been read, checking it's well-formed and
+    /// canonical
+    fn skip(&mut self, major_type: u8, argument: u64, depth: usize) -> Option<()> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+
+        match major_type {
+            BYTES => {
+                self.bytes(argument)?;
+            }
+            TEXT => {
+                self.text(argument)?;
+            }
+            ARRAY => {
+                for _ in 0..argument {
+                    self.item(depth + 1)?;
+                }
+            }
+            MAP => {
+                let input = self.input;
+                let mut previous_key: Option<&[u8]> = None;
+                for _ in 0..argument {
+                    let key_start = self.position;
+                    self.item(depth + 1)?;
+                    let encoded_key = &input[key_start..self.position];
+                    if previous_key.is_some_and(|previous| previous >= encoded_key) {
+                        return None;
+                    }
+                    previous_key = Some(encoded_key);
+
+                    self.item(depth + 1)?;
+                }
+            }
+            TAG => self.item(depth + 1)?,
+            // Integers, simple values, and floats are only a head
+            UNSIGNED | NEGATIVE | SIMPLE => {}
+            _ => unreachable!("major types have three bits"),
+        }
+
+        Some(())
+    }
+
+    /// Skips the next item
+    fn item(&mut self, depth: usize) -> Option<()> {
+        let (major_type, argument) = self.head()?;
+
+        self.skip(major_type, argument, depth)
+    }
+}
+
+/// Returns whether a float can be encoded as a half precision float without losing precision
+fn fits_half(value: f32) -> bool {
+    if !value.is_finite() || value == 0.0 {
+        return true;
+    }
+
+    let bits = value.to_bits();
+    let exponent = ((bits >> 23) & 0xff) as i32 - 127;
+    let mantissa = bits & 0x7f_ffff;
+    match exponent {
+        // Normal half floats keep 10 of the 23 bits of the mantissa
+        -14..=15 => mantissa & 0x1fff == 0,
+        // Subnormal half floats are multiples of 2^-24
+        -24..=-15 => mantissa & ((1 << (-1 - exponent)) - 1) == 0,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_map, write_map, CborValue};
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    fn unhex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_cbor() {
+        // Encodings from the examples in RFC 8949 appendix A
+        for (value, expected) in [
+            (CborValue::Unsigned(0), "00"),
+            (CborValue::Unsigned(23), "17"),
+            (CborValue::Unsigned(24), "1818"),
+            (CborValue::Unsigned(1000), "1903e8"),
+            (CborValue::Unsigned(1000000), "1a000f4240"),
+            (CborValue::Unsigned(1000000000000), "1b000000e8d4a51000"),
+            (CborValue::Bytes(vec![1, 2, 3, 4]), "4401020304"),
+            (CborValue::Text("ü".to_string()), "62c3bc"),
+        ] {
+            let cbor = write_map(&[("a", value.clone())]);
+            assert_eq!(format!("a16161{expected}"), hex(&cbor));
+            assert_eq!(Some(vec![("a".to_string(), value)]), read_map(&cbor));
+        }
+
+        assert_eq!(Some(vec![]), read_map(&[0xa0]));
+    }
+
+    #[test]
+    fn test_cbor_key_order() {
+        // Shorter keys sort first since the length is part of the encoding
+        let cbor = write_map(&[
+            ("bb", CborValue::Unsigned(1)),
+            ("c", CborValue::Unsigned(2)),
+            ("a", CborValue::Unsigned(3)),
+        ]);
+        assert_eq!("a361610361630262626201", hex(&cbor));
+        assert_eq!(
+            Some(vec![
+                ("a".to_string(), CborValue::Unsigned(3)),
+                ("c".to_string(), CborValue::Unsigned(2)),
+                ("bb".to_string(), CborValue::Unsigned(1)),
+            ]),
+            read_map(&cbor)
+        );
+
+        for unsorted in ["a2616302616103", "a262626201616103"] {
+            assert_eq!(None, read_map(&unhex(unsorted)), "{unsorted}");
+        }
+    }
+
+    #[test]
+    fn test_cbor_other_values() {
+        for value in [
+            "20",
+            "3903e7",
+            "f4",
+            "f6",
+            "f820",
+            "820180",
+            "a2616101616202",
+            "c11a514b67b0",
+            "f93c00",
+            "fa47c35000",
+            "fb3ff199999999999a",
+        ] {
+            assert_eq!(
+                Some(vec![("a".to_string(), CborValue::Other)]),
+                read_map(&unhex(&format!("a16161{value}"))),
+                "{value}"
+            );
+        }
+
+        // Other values have to be canonical too
+        for value in [
+            "3800",
+            "f818",
+            "fa3f800000",
+            "fb3ff0000000000000",
+            "fb7ff8000000000000",
+            "9f01ff",
+            "a2616201616101",
+            "a2616101616102",
+            "c1",
+            "8161ff",
+            &format!("{}00", "81".repeat(20)),
+        ] {
+            assert_eq!(None, read_map(&unhex(&format!("a16161{value}"))), "{value}");
+        }
+    }
+
+    #[test]
+    fn test_cbor_malformed() {
+        for cbor in [
+            &[][..],
+            &[0x00],
+            &[0xa1, 0x61, 0x61],
+            &[0xa1, 0x61, 0x61, 0x01, 0x00],
+            &[0xa1, 0x01, 0x01],
+            &[0xa1, 0x61, 0x61, 0x18, 0x17],
+            &[0xa1, 0x61, 0x61, 0x19, 0x00, 0xff],
+            &[0xa1, 0x61, 0x61, 0x5f, 0xff],
+            &[0xa1, 0x61, 0x61, 0x62, 0xff, 0xfe],
+            &[0xa1, 0x61, 0x61, 0x1c],
+            &[0xbf, 0xff],
+            &[0xa2, 0x61, 0x61, 0x01, 0x61, 0x61, 0x02],
+            &[
+                0xa1, 0x61, 0x61, 0x5b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            ],
+        ] {
+            assert_eq!(None, read_map(cbor), "{cbor:02x?}");
+        }
+    }
+}
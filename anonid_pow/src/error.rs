@@ -0,0 +1,198 @@
+use std::fmt;
+
+/// Errors returned by the PoW functions instead of panicking on invalid parameters
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnonIdPowError {
+    /// The difficulty is lower than the smallest one `adjust_difficulty` can divide by
+    DifficultyTooLow { difficulty: usize, min: usize },
+    /// The difficulty asks for more bits than the hash has
+    DifficultyTooHigh { difficulty: usize, max: usize },
+    /// The PoW algorithm's parameters are out of range
+    InvalidAlgoParameters(String),
+    /// Every nonce has been tried without finding a solution
+    NonceSpaceExhausted,
+}
+
+impl fmt::Display for AnonIdPowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnonIdPowError::DifficultyTooLow { difficulty, min } => {
+                write!(
+                    f,
+                    "difficulty {difficulty} is lower than the minimum of {min}"
+                )
+            }
+            AnonIdPowError::DifficultyTooHigh { difficulty, max } => {
+                write!(
+                    f,
+                    "difficulty {difficulty} is higher than the maximum of {max}"
+                )
+            }
+            AnonIdPowError::InvalidAlgoParameters(algo) => {
+                write!(f, "invalid parameters for PoW algorithm {algo}")
+            }
+            AnonIdPowError::NonceSpaceExhausted => {
+                write!(f, "every nonce has been tried without finding a solution")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnonIdPowError {}
+
+/// Errors returned when a serialized proof can't be decoded
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProofError {
+    /// The input is not in the expected format
+    Malformed,
+    /// A required field is missing
+    MissingField(&'static str),
+    /// A field has a value of the wrong type or out of range
+    InvalidField(&'static str),
+    /// The proof was written by a newer or unknown format version
+    UnsupportedVersion(u64),
+}
+
+impl fmt::Display for ProofError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProofError::Malformed => write!(f, "malformed proof"),
+            ProofError::MissingField(field) => write!(f, "missing proof field {field}"),
+            ProofError::InvalidField(field) => write!(f, "invalid proof field {field}"),
+            ProofError::UnsupportedVersion(version) => {
+                write!(f, "unsupported proof format version {version}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ProofError {}
+
+/// Reasons a proof fails `PoW::verify_batch`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VerifyError {
+    /// The merged userdata can't be split into auth_address and username
+    MalformedUserData,
+    /// The difficulty or the algorithm's parameters are invalid
+    InvalidParameters(AnonIdPowError),
+    /// The digest of the userdata and nonce doesn't meet the target
+    TargetNotMet,
+    /// The supplied hash differs from the recalculated one
+    WrongHash,
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VerifyError::MalformedUserData => write!(f, "malformed userdata"),
+            VerifyError::InvalidParameters(error) => write!(f, "invalid parameters: {error}"),
+            VerifyError::TargetNotMet => write!(f, "the hash doesn't meet the target"),
+            VerifyError::WrongHash => write!(f, "the hash doesn't match the nonce"),
+        }
+    }
+}
+
+impl std::error::Error for VerifyError {}
+
+/// Reasons a username is rejected by a `UsernamePolicy`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UsernameError {
+    /// The username has fewer characters than the policy's minimum
+    TooShort { length: usize, min: usize },
+    /// The username has more characters than the policy's maximum
+    TooLong { length: usize, max: usize },
+    /// The username contains a character outside of the allowed classes and symbols
+    ForbiddenCharacter(char),
+    /// The username starts or ends with a character that is only allowed inside it
+    ForbiddenEdge(char),
+    /// The username is on the policy's reserved list
+    Reserved(String),
+    /// The policy requires canonical usernames and the username is not in canonical form
+    NotCanonical,
+}
+
+impl fmt::Display for UsernameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UsernameError::TooShort { length, min } => {
+                write!(f, "username length {length} is shorter than {min}")
+            }
+            UsernameError::TooLong { length, max } => {
+                write!(f, "username length {length} is longer than {max}")
+            }
+            UsernameError::ForbiddenCharacter(character) => {
+                write!(f, "username contains forbidden character {character:?}")
+            }
+            UsernameError::ForbiddenEdge(character) => {
+                write!(f, "username can't start or end with {character:?}")
+            }
+            UsernameError::Reserved(name) => write!(f, "username {name} is reserved"),
+            UsernameError::NotCanonical => write!(f, "username is not in canonical form"),
+        }
+    }
+}
+
+impl std::error::Error for UsernameError {}
+
+/// Reasons an auth_address is rejected by `AuthAddress::parse`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AddressError {
+    /// The address contains a character outside of its alphabet, or mixes cases in bech32
+    InvalidCharacter(char),
+    /// The address doesn't decode to a 20 byte public key hash, contains the address length
+    InvalidLength(usize),
+    /// The checksum doesn't match the payload
+    InvalidChecksum,
+    /// The version byte or segwit version is not the one of a public key hash
+    UnsupportedVersion(u8),
+}
+
+impl fmt::Display for AddressError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AddressError::InvalidCharacter(character) => {
+                write!(f, "address contains invalid character {character:?}")
+            }
+            AddressError::InvalidLength(length) => {
+                write!(f, "address length {length} doesn't match a public key hash")
+            }
+            AddressError::InvalidChecksum => write!(f, "invalid address checksum"),
+            AddressError::UnsupportedVersion(version) => {
+                write!(f, "unsupported address version {version}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AddressError {}
+
+/// Reasons signing or verifying a signed message fails
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignatureError {
+    /// The signature is not a base64 encoded 65 byte recoverable signature
+    Malformed,
+    /// The secret key is out of range or not a valid WIF string
+    InvalidKey,
+    /// The auth_address can't be decoded
+    InvalidAddress(AddressError),
+    /// The signature was made by a key that doesn't belong to the auth_address
+    AddressMismatch,
+    /// The signature is valid but the signed proof doesn't verify
+    InvalidProof,
+}
+
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SignatureError::Malformed => write!(f, "malformed signature"),
+            SignatureError::InvalidKey => write!(f, "invalid secret key"),
+            SignatureError::InvalidAddress(error) => write!(f, "invalid auth_address: {error}"),
+            SignatureError::AddressMismatch => {
+                write!(f, "the key doesn't belong to the auth_address")
+            }
+            SignatureError::InvalidProof => write!(f, "the signed proof doesn't verify"),
+        }
+    }
+}
+
+impl std::error::Error for SignatureError {}
@@ -0,0 +1,126 @@
+//! Expected work and time-to-solve of a PoW
+//!
+//! Every attempt hashes a different nonce and succeeds independently with the same
+//! probability, so the number of attempts until a solution is found follows a geometric
+//! distribution.
+
+use std::time::Duration;
+
+use crate::{benchmark, DifficultyPolicy, PoW, PoWHash};
+
+/// Expected work of a PoW, see `PoW::estimate`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Estimate {
+    /// Probability that a single attempt finds a solution
+    pub probability: f64,
+}
+
+impl Estimate {
+    /// Returns the mean number of attempts until a solution is found, infinite if no digest
+    /// can meet the target
+    pub fn expected_attempts(&self) -> f64 {
+        1.0 / self.probability
+    }
+
+    /// Returns the number of attempts after which a solution has been found with the given
+    /// probability, e.g. 0.5 for the median
+    ///
+    /// None is returned if `percentile` isn't in `0.0..1.0`.
+    pub fn percentile_attempts(&self, percentile: f64) -> Option<f64> {
+        if !(0.0..1.0).contains(&percentile) {
+            return None;
+        }
+
+        // Smallest n with 1 - (1 - p)^n >= percentile
+        let attempts = ((-percentile).ln_1p() / (-self.probability).ln_1p()).ceil();
+
+        Some(attempts.max(1.0))
+    }
+
+    /// Returns the mean time until a solution is found at `hash_rate` hashes per second
+    ///
+    /// None is returned if it can't be represented as a Duration, which includes targets
+    /// no digest can meet and hash rates that aren't positive.
+    pub fn expected_time(&self, hash_rate: f64) -> Option<Duration> {
+        Duration::try_from_secs_f64(self.expected_attempts() / hash_rate).ok()
+    }
+
+    /// Returns the time after which a solution has been found with the given probability
+    /// at `hash_rate` hashes per second
+    ///
+    /// None is returned under the conditions of `percentile_attempts` and `expected_time`.
+    pub fn percentile_time(&self, percentile: f64, hash_rate: f64) -> Option<Duration> {
+        Duration::try_from_secs_f64(self.percentile_attempts(percentile)? / hash_rate).ok()
+    }
+}
+
+impl<A: PoWHash, D: DifficultyPolicy> PoW<A, D> {
+    /// Estimates the work needed to find a solution
+    ///
+    /// The digests are assumed to be uniformly distributed, which holds for every built-in
+    /// algorithm.
+    pub fn estimate(&self) -> Estimate {
+        Estimate {
+            probability: self.target().probability(self.algo.output_len()),
+        }
+    }
+}
+
+/// Measures how many hashes per second a single thread computes with `algo`
+///
+/// Hashes are computed for about `duration`, see `benchmark` for the details.
+/// `calculate_pow_parallel` reaches about this rate times the number of threads on otherwise
+/// idle cores.
+pub fn calibrate<A: PoWHash>(algo: &A, duration: Duration) -> f64 {
+    benchmark(algo, duration).hash_rate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{calibrate, Estimate};
+    use crate::{NumericTarget, PoW, PoWAlgo, TargetMode, UserData};
+    use std::time::Duration;
+
+    fn pow(difficulty: usize, target_mode: TargetMode) -> PoW {
+        let userdata =
+            UserData::from_merged("1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:bob".to_string()).unwrap();
+
+        PoW::new(userdata, difficulty, PoWAlgo::Sha256)
+            .unwrap()
+            .with_target_mode(target_mode)
+    }
+
+    #[test]
+    fn test_estimate() {
+        // "bob" keeps the full difficulty, the hex prefix "3fff" has 4 nibbles to match
+        let estimate = pow(14, TargetMode::HexPrefix).estimate();
+        assert_eq!(65536.0, estimate.expected_attempts());
+
+        let estimate = pow(14, TargetMode::LeadingZeroBits).estimate();
+        assert_eq!(16384.0, estimate.expected_attempts());
+        assert_eq!(Some(11357.0), estimate.percentile_attempts(0.5));
+        assert_eq!(Some(1.0), estimate.percentile_attempts(0.0));
+        assert_eq!(None, estimate.percentile_attempts(1.0));
+        assert_eq!(
+            Some(Duration::from_millis(16384)),
+            estimate.expected_time(1000.0)
+        );
+        assert_eq!(None, estimate.expected_time(0.0));
+
+        let target = NumericTarget::from_difficulty(14);
+        assert_eq!(estimate, pow(14, TargetMode::Numeric(target)).estimate());
+
+        let impossible = Estimate { probability: 0.0 };
+        assert_eq!(f64::INFINITY, impossible.expected_attempts());
+        assert_eq!(None, impossible.percentile_time(0.5, 1000.0));
+
+        let certain = Estimate { probability: 1.0 };
+        assert_eq!(Some(1.0), certain.percentile_attempts(0.99));
+    }
+
+    #[test]
+    fn test_calibrate() {
+        let hash_rate = calibrate(&PoWAlgo::Sha256, Duration::from_millis(20));
+        assert!(hash_rate > 0.0 && hash_rate.is_finite());
+    }
+}
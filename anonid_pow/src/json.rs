@@ -0,0 +1,407 @@
+//! Reader and writer for the flat JSON objects proofs are stored as
+//!
+//! Proofs only use strings and unsigned integers, but the reader accepts values of any type
+//! so unknown fields can be skipped. Other numbers are read as floats and arrays and objects
+//! are kept as their JSON text, so the writer takes them too.
+
+use std::fmt::Write;
+
+/// Deepest nesting of arrays and objects the reader accepts
+const MAX_DEPTH: usize = 32;
+
+/// Value of a field in a flat JSON object
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum JsonValue {
+    String(String),
+    Number(u64),
+    Null,
+    Bool(bool),
+    /// Written as null when it isn't finite
+    Float(f64),
+    /// Already serialized JSON, written as is
+    Raw(String),
+}
+
+/// Writes a flat JSON object with the fields in the given order
+pub(crate) fn write_json_object(fields: &[(&str, JsonValue)]) -> String {
+    let mut json = String::from("{");
+
+    for (index, (key, value)) in fields.iter().enumerate() {
+        if index > 0 {
+            json.push(',');
+        }
+
+        write_string(&mut json, key);
+        json.push(':');
+        match value {
+            JsonValue::String(value) => write_string(&mut json, value),
+            JsonValue::Number(value) => write!(json, "{value}").unwrap(),
+            JsonValue::Null => json.push_str("null"),
+            JsonValue::Bool(value) => write!(json, "{value}").unwrap(),
+            JsonValue::Float(value) if value.is_finite() => write!(json, "{value}").unwrap(),
+            JsonValue::Float(_) => json.push_str("null"),
+            JsonValue::Raw(value) => json.push_str(value),
+        }
+    }
+
+    json.push('}');
+    json
+}
+
+fn write_string(json: &mut String, value: &str) {
+    json.push('"');
+    for character in value.chars() {
+        match character {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            character if (character as u32) < 0x20 => {
+                write!(json, "\\u{:04x}", character as u32).unwrap()
+            }
+            character => json.push(character),
+        }
+    }
+    json.push('"');
+}
+
+/// Reads a JSON object, returns None if the JSON is malformed, nested too deeply, or a key
+/// appears twice
+pub(crate) fn read_object(json: &str) -> Option<Vec<(String, JsonValue)>> {
+    let mut reader = Reader {
+        input: json.as_bytes(),
+        position: 0,
+    };
+
+    let mut fields: Vec<(String, JsonValue)> = Vec::new();
+
+    reader.expect(b'{')?;
+    if !reader.consume(b'}') {
+        loop {
+            let key = reader.string()?;
+            reader.expect(b':')?;
+            let value = reader.value(0)?;
+
+            if fields.iter().any(|(existing, _)| *existing == key) {
+                return None;
+            }
+            fields.push((key, value));
+
+            if reader.consume(b'}') {
+                break;
+            }
+            reader.expect(b',')?;
+        }
+    }
+
+    reader.skip_whitespace();
+    (reader.position == reader.input.len()).then_some(fields)
+}
+
+struct Reader<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl Reader<'_> {
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.input.get(self.position) {
+            self.position += 1;
+        }
+    }
+
+    fn consume(&mut self, byte: u8) -> bool {
+        self.skip_whitespace();
+        if self.input.get(self.position) == Some(&byte) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        self.consume(byte).then_some(())
+    }
+
+    fn value(&mut self, depth: usize) -> Option<JsonValue> {
+        self.skip_whitespace();
+        let start = self.position;
+
+        match self.input.get(self.position)? {
+            b'"' => self.string().map(JsonValue::String),
+            b'-' | b'0'..=b'9' => self.number(),
+            b't' => self.literal("true").map(|_| JsonValue::Bool(true)),
+            b'f' => self.literal("false").map(|_| JsonValue::Bool(false)),
+            b'n' => self.literal("null").map(|_| JsonValue::Null),
+            b'[' | b'{' if depth < MAX_DEPTH => {
+                self.skip_container(depth)?;
+                let text = std::str::from_utf8(&self.input[start..self.position]).ok()?;
+                Some(JsonValue::Raw(text.to_string()))
+            }
+            _ => None,
+        }
+    }
+
+    fn literal(&mut self, literal: &str) -> Option<()> {
+        let end = self.position + literal.len();
+        if self.input.get(self.position..end)? != literal.as_bytes() {
+            return None;
+        }
+        self.position = end;
+        Some(())
+    }
+
+    /// Skips an array or object, the reader is at its opening bracket
+    fn skip_container(&mut self, depth: usize) -> Option<()> {
+        let (is_object, close) = match self.input[self.position] {
+            b'{' => (true, b'}'),
+            _ => (false, b']'),
+        };
+        self.position += 1;
+
+        if self.consume(close) {
+            return Some(());
+        }
+        loop {
+            if is_object {
+                self.skip_whitespace();
+                self.string()?;
+                self.expect(b':')?;
+            }
+            self.value(depth + 1)?;
+
+            if self.consume(close) {
+                return Some(());
+            }
+            self.expect(b',')?;
+        }
+    }
+
+    /// Reads a number, unsigned integers that fit a u64 are kept exact
+    fn number(&mut self) -> Option<JsonValue> {
+        let start = self.position;
+        let digits = |reader: &mut Reader| {
+            let start = reader.position;
+            while let Some(b'0'..=b'9') = reader.input.get(reader.position) {
+                reader.position += 1;
+            }
+            reader.position > start
+        };
+
+        let negative = self.consume_byte(b'-');
+        let integer_start = self.position;
+        if !digits(self) {
+            return None;
+        }
+        if self.position - integer_start > 1 && self.input[integer_start] == b'0' {
+            return None;
+        }
+        let mut is_integer = !negative;
+        if self.consume_byte(b'.') {
+            is_integer = false;
+            if !digits(self) {
+                return None;
+            }
+        }
+        if self.consume_byte(b'e') || self.consume_byte(b'E') {
+            is_integer = false;
+            if !self.consume_byte(b'+') {
+                self.consume_byte(b'-');
+            }
+            if !digits(self) {
+                return None;
+            }
+        }
+
+        let number = std::str::from_utf8(&self.input[start..self.position]).ok()?;
+        match number.parse() {
+            Ok(number) if is_integer => Some(JsonValue::Number(number)),
+            _ => number.parse().ok().map(JsonValue::Float),
+        }
+    }
+
+    /// Consumes the byte if it comes next, without skipping whitespace
+    fn consume_byte(&mut self, byte: u8) -> bool {
+        if self.input.get(self.position) == Some(&byte) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn string(&mut self) -> Option<String> {
+        self.expect(b'"')?;
+
+        let mut value = String::new();
+        loop {
+            let start = self.position;
+            while let Some(byte) = self.input.get(self.position) {
+                if *byte == b'"' || *byte == b'\\' || *byte < 0x20 {
+                    break;
+                }
+                self.position += 1;
+            }
+            value.push_str(std::str::from_utf8(&self.input[start..self.position]).ok()?);
+
+            match self.input.get(self.position)? {
+                b'"' => {
+                    self.position += 1;
+                    return Some(value);
+                }
+                b'\\' => {
+                    self.position += 1;
+                    value.push(self.escape()?);
+                }
+                _ => return None,
+            }
+        }
+    }
+
+    fn escape(&mut self) -> Option<char> {
+        let byte = *self.input.get(self.position)?;
+        self.position += 1;
+
+        let character = match byte {
+            b'"' => '"',
+            b'\\' => '\\',
+            b'/' => '/',
+            b'b' => '\u{8}',
+            b'f' => '\u{c}',
+            b'n' => '\n',
+            b'r' => '\r',
+            b't' => '\t',
+            b'u' => {
+                let high = self.hex4()?;
+                if (0xd800..0xdc00).contains(&high) {
+                    if self.input.get(self.position..self.position + 2)? != b"\\u" {
+                        return None;
+                    }
+                    self.position += 2;
+
+                    let low = self.hex4()?;
+                    if !(0xdc00..0xe000).contains(&low) {
+                        return None;
+                    }
+                    char::from_u32(0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00))?
+                } else {
+                    char::from_u32(high)?
+                }
+            }
+            _ => return None,
+        };
+
+        Some(character)
+    }
+
+    fn hex4(&mut self) -> Option<u32> {
+        let digits = self.input.get(self.position..self.position + 4)?;
+        self.position += 4;
+
+        u32::from_str_radix(std::str::from_utf8(digits).ok()?, 16).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_object, write_json_object, JsonValue};
+
+    #[test]
+    fn test_json_round_trip() {
+        let fields = vec![
+            (
+                "name".to_string(),
+                JsonValue::String("a \"b\"\\\n\u{1}ü😀".to_string()),
+            ),
+            ("nonce".to_string(), JsonValue::Number(u64::MAX)),
+        ];
+        let borrowed: Vec<_> = fields
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.clone()))
+            .collect();
+
+        let json = write_json_object(&borrowed);
+        assert_eq!(
+            r#"{"name":"a \"b\"\\\n\u0001ü😀","nonce":18446744073709551615}"#,
+            json
+        );
+        assert_eq!(Some(fields), read_object(&json));
+
+        assert_eq!(
+            Some(vec![(
+                "a".to_string(),
+                JsonValue::String("é😀/".to_string())
+            )]),
+            read_object(" { \"a\" : \"\\u00e9\\ud83d\\ude00\\/\" } ")
+        );
+        assert_eq!(Some(vec![]), read_object("{}"));
+    }
+
+    #[test]
+    fn test_write_json_object() {
+        assert_eq!("{}", write_json_object(&[]));
+        assert_eq!(
+            r#"{"valid":true,"error":null,"seconds":1.5,"eta":null,"proof":{"nonce":1}}"#,
+            write_json_object(&[
+                ("valid", JsonValue::Bool(true)),
+                ("error", JsonValue::Null),
+                ("seconds", JsonValue::Float(1.5)),
+                ("eta", JsonValue::Float(f64::INFINITY)),
+                ("proof", JsonValue::Raw(r#"{"nonce":1}"#.to_string())),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_json_other_values() {
+        let json = concat!(
+            r#"{"a":true,"b":false,"c":null,"d":-1,"e":1.5e3,"f":18446744073709551616,"#,
+            r#""g":[1, {"h": [null, "]"]}],"i":{}}"#
+        );
+        assert_eq!(
+            Some(vec![
+                ("a".to_string(), JsonValue::Bool(true)),
+                ("b".to_string(), JsonValue::Bool(false)),
+                ("c".to_string(), JsonValue::Null),
+                ("d".to_string(), JsonValue::Float(-1.0)),
+                ("e".to_string(), JsonValue::Float(1500.0)),
+                ("f".to_string(), JsonValue::Float(18446744073709551616.0)),
+                (
+                    "g".to_string(),
+                    JsonValue::Raw(r#"[1, {"h": [null, "]"]}]"#.to_string())
+                ),
+                ("i".to_string(), JsonValue::Raw("{}".to_string())),
+            ]),
+            read_object(json)
+        );
+    }
+
+    #[test]
+    fn test_json_malformed() {
+        let too_deep = format!("{{\"a\":{}{}}}", "[".repeat(40), "]".repeat(40));
+
+        for json in [
+            "",
+            "{",
+            "{\"a\":1,}",
+            "{\"a\":1} x",
+            "{\"a\":01}",
+            "{\"a\":-}",
+            "{\"a\":1.}",
+            "{\"a\":1e}",
+            "{\"a\":+1}",
+            "{\"a\":tru}",
+            "{\"a\":[1,]}",
+            "{\"a\":{1:2}}",
+            "{\"a\":[}",
+            "{\"a\":1,\"a\":2}",
+            "{\"a\":\"\\ud83d\"}",
+            "{\"a\":\"\n\"}",
+            &too_deep,
+        ] {
+            assert_eq!(None, read_object(json), "{json}");
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::fmt;
+
+use crate::unicode::grapheme_count;
+use crate::UserData;
+
+/// Contains the way a username's length is counted for the difficulty adjustment
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LengthMetric {
+    /// UTF-8 bytes, the original AnonID metric
+    ///
+    /// Names in scripts outside of ASCII count two to four times as long as they look.
+    #[default]
+    Bytes,
+    /// Unicode scalar values
+    Scalars,
+    /// Grapheme clusters, the characters a user perceives
+    Graphemes,
+}
+
+impl LengthMetric {
+    /// Initializes a LengthMetric from its name
+    pub fn from_name(name: &str) -> Option<LengthMetric> {
+        match name {
+            "bytes" => Some(LengthMetric::Bytes),
+            "scalars" => Some(LengthMetric::Scalars),
+            "graphemes" => Some(LengthMetric::Graphemes),
+            _ => None,
+        }
+    }
+
+    /// Returns the length of the string
+    pub fn length(&self, string: &str) -> usize {
+        match self {
+            LengthMetric::Bytes => string.len(),
+            LengthMetric::Scalars => string.chars().count(),
+            LengthMetric::Graphemes => grapheme_count(string),
+        }
+    }
+}
+
+impl fmt::Display for LengthMetric {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LengthMetric::Bytes => write!(f, "bytes"),
+            LengthMetric::Scalars => write!(f, "scalars"),
+            LengthMetric::Graphemes => write!(f, "graphemes"),
+        }
+    }
+}
+
+impl UserData {
+    /// Returns username's length counted with the given metric
+    pub fn username_length_in(&self, metric: LengthMetric) -> usize {
+        metric.length(self.username())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LengthMetric;
+    use crate::{PoW, PoWAlgo, UserData};
+
+    #[test]
+    fn test_length_metric() {
+        let userdata = UserData::new("名前".to_string(), "1FBbx".to_string());
+
+        assert_eq!(6, userdata.username_length());
+        assert_eq!(6, userdata.username_length_in(LengthMetric::Bytes));
+        assert_eq!(2, userdata.username_length_in(LengthMetric::Scalars));
+        assert_eq!(2, userdata.username_length_in(LengthMetric::Graphemes));
+
+        assert_eq!(2, LengthMetric::Scalars.length("e\u{301}"));
+        assert_eq!(1, LengthMetric::Graphemes.length("e\u{301}"));
+
+        for metric in [
+            LengthMetric::Bytes,
+            LengthMetric::Scalars,
+            LengthMetric::Graphemes,
+        ] {
+            assert_eq!(Some(metric), LengthMetric::from_name(&metric.to_string()));
+        }
+        assert_eq!(None, LengthMetric::from_name("words"));
+    }
+
+    #[test]
+    fn test_pow_length_metric() {
+        // 4 bytes or 2 graphemes, the adjusted difficulty is 2 or 4
+        let userdata = UserData::new("éé".to_string(), "1FBbx".to_string());
+
+        let pow = PoW::new(userdata, 4, PoWAlgo::Sha256).unwrap();
+        let (hash, nonce) = pow.calculate_pow().unwrap();
+        assert!(hash.starts_with('3'));
+
+        let grapheme_pow = pow.with_length_metric(LengthMetric::Graphemes);
+        let (grapheme_hash, grapheme_nonce) = grapheme_pow.calculate_pow().unwrap();
+        assert!(grapheme_hash.starts_with('f'));
+        assert!(grapheme_pow.verify_pow((grapheme_hash, grapheme_nonce)));
+        assert!(!grapheme_pow.verify_pow((hash, nonce)));
+    }
+}
@@ -1,10 +1,74 @@
-use sha2::{Digest, Sha256};
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+mod address;
+mod algo;
+mod argon2;
+mod batch;
+mod benchmark;
+#[cfg(feature = "blake3")]
+mod blake3;
+#[cfg(feature = "cbor")]
+mod cbor;
+mod error;
+mod estimate;
+mod json;
+mod length;
+mod merge;
+mod policy;
+mod proof;
+mod ripemd160;
+mod secp256k1;
+#[cfg(feature = "sha3")]
+mod sha3;
+mod signature;
+mod solver;
+mod stamp;
+mod target;
+mod unicode;
+#[rustfmt::skip]
+mod unicode_tables;
+mod username;
+mod wire;
+
+pub use address::{AddressKind, AuthAddress};
+pub use algo::{
+    NonceBuffer, PoWAlgo, PoWHash, PreparedAlgo, ARGON2ID_SALT, MAX_ARGON2ID_MEMORY_KIB,
+    MAX_ARGON2ID_PARALLELISM, MAX_ARGON2ID_TIME_COST,
+};
+pub use batch::VerifyRequest;
+pub use benchmark::{benchmark, BenchmarkReport};
+pub use error::{
+    AddressError, AnonIdPowError, ProofError, SignatureError, UsernameError, VerifyError,
+};
+pub use estimate::{calibrate, Estimate};
+pub use length::LengthMetric;
+pub use merge::MergeFormat;
+pub use policy::{DifficultyCurve, DifficultyPolicy};
+pub use proof::{Proof, PROOF_VERSION};
+pub use signature::SignedProof;
+#[cfg(feature = "sign")]
+pub use signature::SigningKey;
+pub use solver::{CancellationToken, Checkpoint, Progress, SolveOptions, SolveOutcome};
+pub use stamp::STAMP_VERSION;
+pub use target::{NumericTarget, TargetMode};
+pub use unicode::{canonicalize_username, username_skeleton};
+pub use username::{CharacterClass, UsernamePolicy};
+pub use wire::WIRE_VERSION;
+
+use algo::to_hex;
+use target::Target;
 
 /// Contains username and user's public key
-#[derive(Clone)]
+///
+/// The userdata is merged in the legacy format unless another `MergeFormat` or a namespace is
+/// set, so proofs made before the versioned format still verify.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UserData {
     username: String,
     auth_address: String,
+    namespace: Option<String>,
+    merge_format: MergeFormat,
 }
 
 impl UserData {
@@ -13,138 +77,343 @@ impl UserData {
         UserData {
             username,
             auth_address,
+            namespace: None,
+            merge_format: MergeFormat::Legacy,
+        }
+    }
+
+    /// Initialize a UserData struct with the canonical form of the username
+    ///
+    /// The username is NFKC normalized and case folded, see `canonicalize_username`, so
+    /// proofs bind to the canonical form and differently written names share one proof.
+    pub fn new_canonical(username: String, auth_address: String) -> UserData {
+        UserData::new(canonicalize_username(&username), auth_address)
+    }
+
+    /// Sets the format used by `merge`
+    ///
+    /// Switching to `MergeFormat::Legacy` drops the namespace, the legacy format can't carry
+    /// it.
+    pub fn with_merge_format(mut self, merge_format: MergeFormat) -> UserData {
+        if merge_format == MergeFormat::Legacy {
+            self.namespace = None;
         }
+        self.merge_format = merge_format;
+        self
+    }
+
+    /// Sets the namespace, the domain the username is registered in, and switches to
+    /// `MergeFormat::V1`
+    pub fn with_namespace(mut self, namespace: String) -> UserData {
+        self.namespace = Some(namespace);
+        self.merge_format = MergeFormat::V1;
+        self
     }
 
     /// Initialize a UserData struct from a merged userdata string
+    ///
+    /// Strings whose netstrings parse as `MergeFormat::V1` are read as such and anything else
+    /// as "auth_address:username". Strings with missing or trailing fields are rejected, so
+    /// legacy usernames can't contain a ':'. That keeps the formats apart: a V1 string has at
+    /// least three ':', so a legacy string with the auth_address "v1" is never read as V1.
     pub fn from_merged(merged_userdata: String) -> Option<UserData> {
-        let userdata: Vec<&str> = merged_userdata.split(":").collect();
+        if let Some((auth_address, username, namespace)) = merge::split_v1(&merged_userdata) {
+            return Some(UserData {
+                username: username.to_string(),
+                auth_address: auth_address.to_string(),
+                namespace: namespace.map(str::to_string),
+                merge_format: MergeFormat::V1,
+            });
+        }
 
-        let auth_address = userdata.get(0)?;
-        let username = userdata.get(1)?;
+        let (auth_address, username) = merged_userdata.split_once(':')?;
+        if username.contains(':') {
+            return None;
+        }
 
-        Some(UserData {
-            username: username.to_string(),
-            auth_address: auth_address.to_string(),
-        })
+        Some(UserData::new(
+            username.to_string(),
+            auth_address.to_string(),
+        ))
     }
 
     /// Merges the username and public key to a string
     ///
-    /// "auth_address:username"
+    /// "auth_address:username" in the legacy format, see `MergeFormat` for the others
     ///
     pub fn merge(&self) -> String {
-        format!("{}:{}", self.auth_address, self.username)
+        match self.merge_format {
+            MergeFormat::Legacy => format!("{}:{}", self.auth_address, self.username),
+            MergeFormat::V1 => merge::merge_v1(
+                &self.auth_address,
+                &self.username,
+                self.namespace.as_deref(),
+            ),
+        }
     }
 
-    /// Returns username's length
-    pub fn username_length(&self) -> usize {
-        self.username.len()
+    /// Returns the username
+    pub fn username(&self) -> &str {
+        &self.username
     }
-}
 
-/// Contains the PoW algorithm
-#[derive(Clone)]
-pub enum PoWAlgo {
-    Sha256,
-}
+    /// Returns the user's public key
+    pub fn auth_address(&self) -> &str {
+        &self.auth_address
+    }
 
-impl PoWAlgo {
-    /// Calculates the Hash based on the algorithm
-    pub fn calculate(&self, userdata: &str, nonce: usize) -> String {
-        match self {
-            PoWAlgo::Sha256 => {
-                let mut hasher = Sha256::new();
-                hasher.update(userdata.as_bytes());
+    /// Returns the namespace, if any
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
 
-                hasher.update(format!(":{nonce}").as_bytes());
+    /// Returns the format used by `merge`
+    pub fn merge_format(&self) -> MergeFormat {
+        self.merge_format
+    }
 
-                let final_hash = hasher.finalize();
+    /// Returns whether the username is in its canonical form
+    pub fn is_canonical(&self) -> bool {
+        canonicalize_username(&self.username) == self.username
+    }
 
-                format!("{:x}", final_hash)
-            }
-        }
+    /// Returns the confusable skeleton of the username, see `username_skeleton`
+    pub fn skeleton(&self) -> String {
+        username_skeleton(&self.username)
+    }
+
+    /// Returns username's length
+    pub fn username_length(&self) -> usize {
+        self.username.len()
     }
 }
 
+/// Lowest difficulty accepted by `PoW::new`, `adjust_difficulty` divides by half of it
+pub const MIN_DIFFICULTY: usize = 2;
+
+/// Highest difficulty accepted by `PoW::new` for the built-in algorithms, the number of bits
+/// in a 32 byte digest
+pub const MAX_DIFFICULTY: usize = 256;
+
 /// Contains PoW parameters like difficulty, userdata, and PoW algorithm
+///
+/// The algorithm is one of the built-in `PoWAlgo`s unless another `PoWHash` is used, and the
+/// difficulty policy one of the `DifficultyCurve`s unless another `DifficultyPolicy` is.
 #[derive(Clone)]
-pub struct PoW {
+pub struct PoW<A = PoWAlgo, D = DifficultyCurve> {
     userdata: UserData,
     difficulty: usize,
-    algo: PoWAlgo,
+    algo: A,
+    target_mode: TargetMode,
+    length_metric: LengthMetric,
+    difficulty_policy: D,
+}
+
+fn validate_difficulty(difficulty: usize, max: usize) -> Result<(), AnonIdPowError> {
+    if difficulty < MIN_DIFFICULTY {
+        return Err(AnonIdPowError::DifficultyTooLow {
+            difficulty,
+            min: MIN_DIFFICULTY,
+        });
+    }
+
+    if difficulty > max {
+        return Err(AnonIdPowError::DifficultyTooHigh { difficulty, max });
+    }
+
+    Ok(())
 }
 
 impl PoW {
+    /// Adjusts the difficulty based on the username's length
+    ///
+    /// As the username's length gets shorter the higher the difficulty will get. This is
+    /// `DifficultyCurve::Legacy`, see `with_difficulty_policy` for the others.
+    pub fn adjust_difficulty(
+        username_length: usize,
+        difficulty: usize,
+    ) -> Result<usize, AnonIdPowError> {
+        validate_difficulty(difficulty, MAX_DIFFICULTY)?;
+
+        Ok(DifficultyCurve::Legacy.adjust(username_length, difficulty))
+    }
+}
+
+impl<A: PoWHash> PoW<A> {
     /// Initializes a PoW struct from userdata, difficulty, and PoW algorthm
-    pub fn new(userdata: UserData, difficulty: usize, algo: PoWAlgo) -> PoW {
-        PoW {
+    ///
+    /// The difficulty has to be between `MIN_DIFFICULTY` and the number of bits in the
+    /// algorithm's digest. The legacy hex prefix target is used, see `with_target_mode` to
+    /// change it. The username's length is counted in bytes, see `with_length_metric`, and
+    /// adjusted with the legacy curve, see `with_difficulty_policy`.
+    pub fn new(userdata: UserData, difficulty: usize, algo: A) -> Result<PoW<A>, AnonIdPowError> {
+        validate_difficulty(difficulty, algo.output_len() * 8)?;
+        algo.validate()?;
+
+        Ok(PoW {
             userdata,
             difficulty,
             algo,
-        }
+            target_mode: TargetMode::default(),
+            length_metric: LengthMetric::default(),
+            difficulty_policy: DifficultyCurve::default(),
+        })
     }
+}
 
-    /// Adjusts the difficulty based on the username's length
-    ///
-    /// As the username's length gets shorter the higher the difficulty will get
-    pub fn adjust_difficulty(username_length: usize, difficulty: usize) -> usize {
-        let half_difficulty = difficulty / 2;
-
-        let divisor = std::cmp::max(1, username_length / half_difficulty);
+impl<A: PoWHash, D: DifficultyPolicy> PoW<A, D> {
+    /// Sets the way the adjusted difficulty is turned into a target
+    pub fn with_target_mode(mut self, target_mode: TargetMode) -> PoW<A, D> {
+        self.target_mode = target_mode;
+        self
+    }
 
-        difficulty / divisor
+    /// Sets the way the username's length is counted for the difficulty adjustment
+    pub fn with_length_metric(mut self, length_metric: LengthMetric) -> PoW<A, D> {
+        self.length_metric = length_metric;
+        self
     }
 
-    fn calculate_target(adjusted_difficulty: usize) -> String {
-        format!("{:x}", usize::pow(2, adjusted_difficulty as u32) - 1)
+    /// Sets the policy that adjusts the difficulty to the username's length
+    pub fn with_difficulty_policy<E: DifficultyPolicy>(self, difficulty_policy: E) -> PoW<A, E> {
+        PoW {
+            userdata: self.userdata,
+            difficulty: self.difficulty,
+            algo: self.algo,
+            target_mode: self.target_mode,
+            length_metric: self.length_metric,
+            difficulty_policy,
+        }
     }
 
-    /// Calculates the actual PoW and returns the hash and the nonce as the result
-    pub fn calculate_pow(&self) -> (String, usize) {
-        let userdata = self.userdata.merge();
-        let username_length = self.userdata.username_length();
+    /// Returns the difficulty after the difficulty policy adjusted it to the username's
+    /// length
+    pub fn adjusted_difficulty(&self) -> usize {
+        let username_length = self.userdata.username_length_in(self.length_metric);
 
-        let adjusted_difficulty = Self::adjust_difficulty(username_length, self.difficulty);
+        self.difficulty_policy
+            .adjust(username_length, self.difficulty)
+            .min(self.difficulty)
+    }
 
-        let target = Self::calculate_target(adjusted_difficulty);
+    fn target(&self) -> Target {
+        Target::new(
+            self.target_mode,
+            self.difficulty,
+            self.adjusted_difficulty(),
+        )
+    }
 
+    /// Calculates the actual PoW and returns the hash and the nonce as the result
+    pub fn calculate_pow(&self) -> Result<(String, usize), AnonIdPowError> {
+        let state = self.algo.prepare(self.userdata.merge().as_bytes());
+        let target = self.target();
+
+        let mut digest = vec![0; self.algo.output_len()];
         let mut nonce = 0;
         loop {
-            let hash = self.algo.calculate(&userdata, nonce);
+            self.algo.hash(&state, nonce, &mut digest);
 
-            if hash[..target.len()] == target {
-                return (hash, nonce);
+            if target.is_met(&digest) {
+                return Ok((to_hex(&digest), nonce));
             } else {
-                nonce += 1;
+                nonce = nonce
+                    .checked_add(1)
+                    .ok_or(AnonIdPowError::NonceSpaceExhausted)?;
             }
         }
     }
 
-    /// Verify the PoW from userdata, hash, and nonce
-    pub fn verify_pow(&self, pow_value: (String, usize)) -> bool {
-        let userdata = self.userdata.merge();
-        let username_length = self.userdata.username_length();
+    /// Calculates the PoW on multiple threads and returns the hash and the nonce as the result
+    ///
+    /// The nonce space is interleaved between the workers, worker `i` tries the nonces
+    /// `i, i + threads, i + 2 * threads, ...`. A worker stops as soon as its next nonce is
+    /// greater than the smallest solution found so far, so every nonce below the returned
+    /// one has been tried and the result is always the smallest valid nonce, the same one
+    /// `calculate_pow` returns.
+    ///
+    /// When `threads` is `None` the available parallelism of the machine is used.
+    pub fn calculate_pow_parallel(
+        &self,
+        threads: Option<NonZeroUsize>,
+    ) -> Result<(String, usize), AnonIdPowError> {
+        let threads = threads
+            .or_else(|| std::thread::available_parallelism().ok())
+            .map_or(1, NonZeroUsize::get);
+
+        let state = self.algo.prepare(self.userdata.merge().as_bytes());
+        let target = self.target();
+
+        let best_nonce = AtomicUsize::new(usize::MAX);
+
+        let results: Vec<Option<(String, usize)>> = std::thread::scope(|scope| {
+            let workers: Vec<_> = (0..threads)
+                .map(|worker| {
+                    let state = &state;
+                    let target = &target;
+                    let best_nonce = &best_nonce;
+
+                    scope.spawn(move || {
+                        let mut digest = vec![0; self.algo.output_len()];
+                        let mut nonce = worker;
+                        while nonce < best_nonce.load(Ordering::Relaxed) {
+                            self.algo.hash(state, nonce, &mut digest);
+
+                            if target.is_met(&digest) {
+                                best_nonce.fetch_min(nonce, Ordering::Relaxed);
+                                return Some((to_hex(&digest), nonce));
+                            }
+
+                            nonce = nonce.checked_add(threads)?;
+                        }
+                        None
+                    })
+                })
+                .collect();
+
+            workers
+                .into_iter()
+                .map(|worker| worker.join().expect("PoW worker thread panicked"))
+                .collect()
+        });
+
+        results
+            .into_iter()
+            .flatten()
+            .min_by_key(|(_, nonce)| *nonce)
+            .ok_or(AnonIdPowError::NonceSpaceExhausted)
+    }
 
-        let adjusted_difficulty = Self::adjust_difficulty(username_length, self.difficulty);
+    /// Verifies the PoW from the userdata and nonce alone and returns the hash if the target
+    /// is met
+    pub fn verify_nonce(&self, nonce: usize) -> Option<String> {
+        let state = self.algo.prepare(self.userdata.merge().as_bytes());
 
-        let target = Self::calculate_target(adjusted_difficulty);
+        let mut digest = vec![0; self.algo.output_len()];
+        self.algo.hash(&state, nonce, &mut digest);
 
-        let (input_hash, nonce) = pow_value;
+        self.target().is_met(&digest).then(|| to_hex(&digest))
+    }
 
-        let computed_hash = self.algo.calculate(&userdata, nonce);
+    /// Verify the PoW from userdata, hash, and nonce
+    ///
+    /// The hash is recalculated by `verify_nonce`, the given one only has to match it
+    /// ignoring case and can be left empty.
+    pub fn verify_pow(&self, pow_value: (String, usize)) -> bool {
+        let (input_hash, nonce) = pow_value;
 
-        if computed_hash[..target.len()] == target && computed_hash == input_hash {
-            true
-        } else {
-            false
-        }
+        self.verify_nonce(nonce)
+            .is_some_and(|hash| input_hash.is_empty() || hash.eq_ignore_ascii_case(&input_hash))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{PoW, PoWAlgo, UserData};
+    use super::{
+        AnonIdPowError, NonceBuffer, NumericTarget, PoW, PoWAlgo, PoWHash, TargetMode, UserData,
+    };
+    use sha2::{Digest, Sha512};
+    use std::num::NonZeroUsize;
 
     #[test]
     fn test_userdata_merge() {
@@ -163,32 +432,56 @@ mod tests {
     fn test_userdata_from_merge() {
         let correct_merged_userdata = "1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user".to_string();
         let incorrect_merged_userdata = "1FBbx487PoajzgnA4yY6TnoLFhQQteT8UXzeronetuser".to_string();
+        let trailing_merged_userdata =
+            "1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user:extra".to_string();
 
         assert!(UserData::from_merged(correct_merged_userdata).is_some());
         assert!(UserData::from_merged(incorrect_merged_userdata).is_none());
+        assert!(UserData::from_merged(trailing_merged_userdata).is_none());
     }
 
     #[test]
-    fn test_pow_algo() {
-        let userdata = "1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user";
-        let nonce = 666;
-
-        let hash = "0729afa04e84848b8535f35df9dab0bad39b1e4c56a2d82443e2ecd89aca1483";
-
-        let pow_algo = PoWAlgo::Sha256;
-        let computed_hash = pow_algo.calculate(userdata, nonce);
+    fn test_pow_adjust_difficulty() {
+        let difficulty = 6;
 
-        assert_eq!(hash, computed_hash);
+        assert_eq!(Ok(6), PoW::adjust_difficulty(2, difficulty));
+        assert_eq!(Ok(3), PoW::adjust_difficulty(7, difficulty));
+        assert_eq!(Ok(2), PoW::adjust_difficulty(10, difficulty));
+        assert_eq!(Ok(1), PoW::adjust_difficulty(18, difficulty));
     }
 
     #[test]
-    fn test_pow_adjust_difficulty() {
-        let difficulty = 6;
+    fn test_pow_invalid_difficulty() {
+        let userdata =
+            UserData::from_merged("1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user".to_string())
+                .unwrap();
 
-        assert_eq!(6, PoW::adjust_difficulty(2, difficulty));
-        assert_eq!(3, PoW::adjust_difficulty(7, difficulty));
-        assert_eq!(2, PoW::adjust_difficulty(10, difficulty));
-        assert_eq!(1, PoW::adjust_difficulty(18, difficulty));
+        for difficulty in [0, 1] {
+            let error = AnonIdPowError::DifficultyTooLow { difficulty, min: 2 };
+
+            assert_eq!(Err(error.clone()), PoW::adjust_difficulty(4, difficulty));
+            assert_eq!(
+                Some(error),
+                PoW::new(userdata.clone(), difficulty, PoWAlgo::Sha256).err()
+            );
+        }
+
+        for difficulty in [257, usize::MAX] {
+            let error = AnonIdPowError::DifficultyTooHigh {
+                difficulty,
+                max: 256,
+            };
+
+            assert_eq!(Err(error.clone()), PoW::adjust_difficulty(4, difficulty));
+            assert_eq!(
+                Some(error),
+                PoW::new(userdata.clone(), difficulty, PoWAlgo::Sha256).err()
+            );
+        }
+
+        let pow = PoW::new(userdata, 256, PoWAlgo::Sha256).unwrap();
+        assert!(!pow.verify_pow(("f".repeat(64), 0)));
+        assert!(!pow.verify_pow((String::new(), 0)));
     }
 
     #[test]
@@ -198,14 +491,154 @@ mod tests {
         let userdata =
             UserData::from_merged("1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user".to_string())
                 .unwrap();
-        let pow = PoW::new(userdata, difficulty, PoWAlgo::Sha256);
+        let pow = PoW::new(userdata, difficulty, PoWAlgo::Sha256).unwrap();
 
         let expected_result = (
             "ffffff419e9de8f5a3b958da92eb19ed8b6cc6da591de7fec0a2e7250c804047".to_string(),
             6589658_usize,
         );
 
-        assert_eq!(expected_result, pow.calculate_pow());
+        assert_eq!(expected_result, pow.calculate_pow().unwrap());
+    }
+
+    #[test]
+    fn test_pow_calculate_parallel() {
+        let difficulty = 16;
+
+        let userdata =
+            UserData::from_merged("1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user".to_string())
+                .unwrap();
+        let pow = PoW::new(userdata, difficulty, PoWAlgo::Sha256).unwrap();
+
+        let expected_result = pow.calculate_pow().unwrap();
+
+        for threads in [1, 3, 8] {
+            let threads = NonZeroUsize::new(threads);
+            assert_eq!(
+                expected_result,
+                pow.calculate_pow_parallel(threads).unwrap()
+            );
+        }
+        assert_eq!(expected_result, pow.calculate_pow_parallel(None).unwrap());
+    }
+
+    #[test]
+    fn test_pow_leading_zero_bits() {
+        let userdata =
+            UserData::from_merged("1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user".to_string())
+                .unwrap();
+
+        for difficulty in [17, 18] {
+            let pow = PoW::new(userdata.clone(), difficulty, PoWAlgo::Sha256)
+                .unwrap()
+                .with_target_mode(TargetMode::LeadingZeroBits);
+
+            let (hash, nonce) = pow.calculate_pow().unwrap();
+            let leading_zero_bits = u128::from_str_radix(&hash[..32], 16)
+                .unwrap()
+                .leading_zeros() as usize;
+
+            assert!(leading_zero_bits >= difficulty);
+            assert!(pow.verify_pow((hash.clone(), nonce)));
+
+            let legacy_pow = PoW::new(userdata.clone(), difficulty, PoWAlgo::Sha256).unwrap();
+            assert!(!legacy_pow.verify_pow((hash, nonce)));
+        }
+    }
+
+    #[test]
+    fn test_pow_numeric_target() {
+        let userdata =
+            UserData::from_merged("1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user".to_string())
+                .unwrap();
+
+        // 2^-16.5, halfway between two whole bit difficulties
+        let target = NumericTarget::from_compact(0x1f00b504).unwrap();
+        let pow = PoW::new(userdata.clone(), 16, PoWAlgo::Sha256)
+            .unwrap()
+            .with_target_mode(TargetMode::Numeric(target));
+
+        let (hash, nonce) = pow.calculate_pow().unwrap();
+        assert!(hash <= target.to_string());
+        assert!(pow.verify_pow((hash.clone(), nonce)));
+
+        let harder_target = NumericTarget::from_compact(0x1f000001).unwrap();
+        let harder_pow = PoW::new(userdata, 16, PoWAlgo::Sha256)
+            .unwrap()
+            .with_target_mode(TargetMode::Numeric(harder_target));
+        assert!(!harder_pow.verify_pow((hash, nonce)));
+    }
+
+    #[derive(Clone)]
+    struct Sha512Algo;
+
+    impl PoWHash for Sha512Algo {
+        type State = Sha512;
+
+        fn algo_id(&self) -> String {
+            "sha512".to_string()
+        }
+
+        fn output_len(&self) -> usize {
+            64
+        }
+
+        fn prepare(&self, userdata: &[u8]) -> Sha512 {
+            let mut hasher = Sha512::new();
+            hasher.update(userdata);
+            hasher
+        }
+
+        fn hash(&self, state: &Sha512, nonce: usize, out: &mut [u8]) {
+            let mut hasher = state.clone();
+            hasher.update(NonceBuffer::new().format(nonce));
+            out.copy_from_slice(&hasher.finalize());
+        }
+    }
+
+    #[test]
+    fn test_pow_custom_hash() {
+        let userdata =
+            UserData::from_merged("1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user".to_string())
+                .unwrap();
+
+        let pow = PoW::new(userdata.clone(), 12, Sha512Algo).unwrap();
+        let (hash, nonce) = pow.calculate_pow().unwrap();
+
+        let mut hasher = Sha512::new();
+        hasher.update(format!("{}:{nonce}", userdata.merge()).as_bytes());
+        assert_eq!(format!("{:x}", hasher.finalize()), hash);
+        assert!(hash.starts_with("3f"));
+        assert!(pow.verify_pow((hash, nonce)));
+
+        assert!(PoW::new(userdata.clone(), 512, Sha512Algo).is_ok());
+        assert_eq!(
+            Some(AnonIdPowError::DifficultyTooHigh {
+                difficulty: 257,
+                max: 256
+            }),
+            PoW::new(userdata, 257, PoWAlgo::Sha256).err()
+        );
+    }
+
+    #[test]
+    fn test_pow_custom_hash_numeric_target() {
+        let userdata =
+            UserData::from_merged("1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user".to_string())
+                .unwrap();
+
+        // 12 characters ease the 12 bit target to 6 bits, the 64 byte digest is compared by
+        // its first 32 bytes
+        let target = NumericTarget::from_difficulty(12);
+        let pow = PoW::new(userdata, 12, Sha512Algo)
+            .unwrap()
+            .with_target_mode(TargetMode::Numeric(target));
+        assert_eq!(64.0, pow.estimate().expected_attempts());
+
+        let (hash, nonce) = pow.calculate_pow().unwrap();
+        assert_eq!(128, hash.len());
+        assert!(u8::from_str_radix(&hash[..2], 16).unwrap() < 4);
+        assert!(pow.verify_pow((hash, nonce)));
     }
 
     #[test]
@@ -215,13 +648,19 @@ mod tests {
         let userdata =
             UserData::from_merged("1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user".to_string())
                 .unwrap();
-        let pow = PoW::new(userdata, difficulty, PoWAlgo::Sha256);
+        let pow = PoW::new(userdata, difficulty, PoWAlgo::Sha256).unwrap();
 
         let computed_pow = (
             "ffffff419e9de8f5a3b958da92eb19ed8b6cc6da591de7fec0a2e7250c804047".to_string(),
             6589658_usize,
         );
 
-        assert!(pow.verify_pow(computed_pow));
+        assert!(pow.verify_pow(computed_pow.clone()));
+        assert!(pow.verify_pow((computed_pow.0.to_uppercase(), computed_pow.1)));
+        assert!(pow.verify_pow((String::new(), computed_pow.1)));
+        assert!(!pow.verify_pow((computed_pow.0.replace('4', "5"), computed_pow.1)));
+
+        assert_eq!(Some(computed_pow.0), pow.verify_nonce(computed_pow.1));
+        assert_eq!(None, pow.verify_nonce(computed_pow.1 + 1));
     }
 }
@@ -0,0 +1,169 @@
+use std::fmt;
+
+/// Prefix of the userdata merged with `MergeFormat::V1`
+const V1_PREFIX: &str = "v1:";
+
+/// Contains the way `UserData::merge` joins the userdata fields before hashing
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MergeFormat {
+    /// "auth_address:username", the original AnonID format
+    ///
+    /// It can't carry a namespace and usernames containing a ':' don't round-trip.
+    #[default]
+    Legacy,
+    /// "v1:" followed by the auth_address, the username, and the optional namespace as
+    /// netstrings
+    ///
+    /// A netstring is the length of the field in bytes, a ':', the field, and a ',', for
+    /// example "v1:5:1FBbx,12:zeronet_user,". Any username and namespace round-trip.
+    V1,
+}
+
+impl MergeFormat {
+    /// Initializes a MergeFormat from its name
+    pub fn from_name(name: &str) -> Option<MergeFormat> {
+        match name {
+            "legacy" => Some(MergeFormat::Legacy),
+            "v1" => Some(MergeFormat::V1),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for MergeFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MergeFormat::Legacy => write!(f, "legacy"),
+            MergeFormat::V1 => write!(f, "v1"),
+        }
+    }
+}
+
+/// Joins the fields in the `MergeFormat::V1` format
+pub(crate) fn merge_v1(auth_address: &str, username: &str, namespace: Option<&str>) -> String {
+    let mut merged = V1_PREFIX.to_string();
+    for field in [Some(auth_address), Some(username), namespace]
+        .into_iter()
+        .flatten()
+    {
+        merged.push_str(&format!("{}:{field},", field.len()));
+    }
+    merged
+}
+
+/// Splits a `MergeFormat::V1` string into auth_address, username, and namespace
+///
+/// Returns None if the string has no "v1:" prefix, if a length has a sign or a leading zero,
+/// or if anything follows the namespace.
+pub(crate) fn split_v1(merged_userdata: &str) -> Option<(&str, &str, Option<&str>)> {
+    let mut rest = merged_userdata.strip_prefix(V1_PREFIX)?;
+
+    let mut fields = Vec::new();
+    while !rest.is_empty() && fields.len() < 3 {
+        let (length, tail) = rest.split_once(':')?;
+        if !length.bytes().all(|byte| byte.is_ascii_digit())
+            || length.is_empty()
+            || (length.len() > 1 && length.starts_with('0'))
+        {
+            return None;
+        }
+        let length: usize = length.parse().ok()?;
+
+        let field = tail.get(..length)?;
+        rest = tail[length..].strip_prefix(',')?;
+        fields.push(field);
+    }
+
+    match fields[..] {
+        [auth_address, username] if rest.is_empty() => Some((auth_address, username, None)),
+        [auth_address, username, namespace] if rest.is_empty() => {
+            Some((auth_address, username, Some(namespace)))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{merge_v1, split_v1, MergeFormat};
+    use crate::UserData;
+
+    #[test]
+    fn test_merge_v1() {
+        assert_eq!(
+            "v1:5:1FBbx,12:zeronet_user,",
+            merge_v1("1FBbx", "zeronet_user", None)
+        );
+        assert_eq!(
+            "v1:5:1FBbx,8:zero:net,9:zeronet.9,",
+            merge_v1("1FBbx", "zero:net", Some("zeronet.9"))
+        );
+        assert_eq!("v1:0:,3:名,0:,", merge_v1("", "名", Some("")));
+
+        for (auth_address, username, namespace) in [
+            ("1FBbx", "zeronet_user", None),
+            ("1FBbx", "zero:net,", Some("a,b:c")),
+            ("", "名前", Some("")),
+        ] {
+            let merged = merge_v1(auth_address, username, namespace);
+            assert_eq!(Some((auth_address, username, namespace)), split_v1(&merged));
+        }
+
+        for merged in [
+            "1FBbx:zeronet_user",
+            "v1:",
+            "v1:5:1FBbx,",
+            "v1:5:1FBbx,12:zeronet_user",
+            "v1:5:1FBbx,12:zeronet_user,extra",
+            "v1:5:1FBbx,12:zeronet_user,0:,0:,",
+            "v1:05:1FBbx,12:zeronet_user,",
+            "v1:+5:1FBbx,12:zeronet_user,",
+            "v1:6:1FBbx,12:zeronet_user,",
+            "v1:2:名,0:,",
+        ] {
+            assert_eq!(None, split_v1(merged), "{merged}");
+        }
+    }
+
+    #[test]
+    fn test_userdata_merge_format() {
+        let legacy = UserData::new("zeronet_user".to_string(), "1FBbx".to_string());
+        let v1 = legacy.clone().with_merge_format(MergeFormat::V1);
+        let namespaced = legacy.clone().with_namespace("zeronet".to_string());
+
+        assert_eq!("1FBbx:zeronet_user", legacy.merge());
+        assert_eq!("v1:5:1FBbx,12:zeronet_user,", v1.merge());
+        assert_eq!("v1:5:1FBbx,12:zeronet_user,7:zeronet,", namespaced.merge());
+        assert_eq!(MergeFormat::V1, namespaced.merge_format());
+        assert_eq!(Some("zeronet"), namespaced.namespace());
+
+        for userdata in [&legacy, &v1, &namespaced] {
+            assert_eq!(
+                Some(userdata),
+                UserData::from_merged(userdata.merge()).as_ref()
+            );
+        }
+
+        // A legacy auth_address "v1" isn't mistaken for the V1 prefix
+        let v1_address = UserData::new("zeronet_user".to_string(), "v1".to_string());
+        assert_eq!("v1:zeronet_user", v1_address.merge());
+        assert_eq!(
+            Some(&v1_address),
+            UserData::from_merged(v1_address.merge()).as_ref()
+        );
+        for merged in ["v1:5:1FBbx,", "v1:5:1FBbx,12:zeronet_user,extra"] {
+            assert_eq!(None, UserData::from_merged(merged.to_string()), "{merged}");
+        }
+
+        assert_eq!(
+            legacy,
+            namespaced.with_merge_format(MergeFormat::Legacy),
+            "the legacy format drops the namespace"
+        );
+
+        for format in [MergeFormat::Legacy, MergeFormat::V1] {
+            assert_eq!(Some(format), MergeFormat::from_name(&format.to_string()));
+        }
+        assert_eq!(None, MergeFormat::from_name("v2"));
+    }
+}
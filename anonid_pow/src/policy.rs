@@ -0,0 +1,251 @@
+use std::fmt;
+
+/// Maps the length of a username to the difficulty its PoW needs
+///
+/// `PoW` consults its policy for the adjusted difficulty, the built-in policies are the
+/// `DifficultyCurve`s.
+pub trait DifficultyPolicy: Sync {
+    /// Returns the adjusted difficulty for a username of `username_length` at the full
+    /// `difficulty`, `PoW` caps it at `difficulty`
+    fn adjust(&self, username_length: usize, difficulty: usize) -> usize;
+}
+
+/// Contains the built-in difficulty policies
+///
+/// Lengths are counted from the first character, a username of length 1 always needs the
+/// full difficulty except with `Table`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum DifficultyCurve {
+    /// `difficulty / max(1, length / (difficulty / 2))`, the original AnonID curve
+    ///
+    /// The difficulty only changes when `length / (difficulty / 2)` does, which leaves wide
+    /// plateaus for long usernames.
+    #[default]
+    Legacy,
+    /// One bit less for every `step` characters after the first, down to `min`
+    Linear { step: usize, min: usize },
+    /// Halves every `half_life` characters after the first, interpolated linearly in
+    /// between, down to `min`
+    Exponential { half_life: usize, min: usize },
+    /// The difficulty for a username of length `n` is the entry `n - 1`, longer usernames
+    /// use the last entry and an empty table keeps the full difficulty
+    Table(Vec<usize>),
+}
+
+/// Parses a decimal number without sign or leading zeros
+fn parse_number(number: &str) -> Option<usize> {
+    number
+        .parse()
+        .ok()
+        .filter(|parsed: &usize| parsed.to_string() == number)
+}
+
+impl DifficultyCurve {
+    /// Initializes a DifficultyCurve from its name
+    ///
+    /// The names are "legacy", "linear-step-min", "exponential-half_life-min", and
+    /// "table-" followed by the comma separated entries. Steps and half-lives of 0 are
+    /// rejected.
+    pub fn from_name(name: &str) -> Option<DifficultyCurve> {
+        if name == "legacy" {
+            return Some(DifficultyCurve::Legacy);
+        }
+        if let Some(entries) = name.strip_prefix("table-") {
+            if entries.is_empty() {
+                return Some(DifficultyCurve::Table(Vec::new()));
+            }
+            return entries
+                .split(',')
+                .map(parse_number)
+                .collect::<Option<Vec<usize>>>()
+                .map(DifficultyCurve::Table);
+        }
+
+        let (curve, parameters) = name.split_once('-')?;
+        let (first, min) = parameters.split_once('-')?;
+        let (first, min) = (parse_number(first)?, parse_number(min)?);
+        if first == 0 {
+            return None;
+        }
+
+        match curve {
+            "linear" => Some(DifficultyCurve::Linear { step: first, min }),
+            "exponential" => Some(DifficultyCurve::Exponential {
+                half_life: first,
+                min,
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl DifficultyPolicy for DifficultyCurve {
+    fn adjust(&self, username_length: usize, difficulty: usize) -> usize {
+        let extra_length = username_length.saturating_sub(1);
+
+        match self {
+            DifficultyCurve::Legacy => {
+                let half_difficulty = std::cmp::max(1, difficulty / 2);
+
+                difficulty / std::cmp::max(1, username_length / half_difficulty)
+            }
+            DifficultyCurve::Linear { step, min } => difficulty
+                .saturating_sub(extra_length / std::cmp::max(1, *step))
+                .max(*min),
+            DifficultyCurve::Exponential { half_life, min } => {
+                let half_life = std::cmp::max(1, *half_life) as u128;
+                let halvings = extra_length as u128 / half_life;
+                let fraction = extra_length as u128 % half_life;
+
+                // difficulty * 2^-halvings * (1 - fraction / (2 * half_life)), rounded
+                let numerator = (difficulty as u128).saturating_mul(2 * half_life - fraction);
+                let adjusted = match u32::try_from(halvings)
+                    .ok()
+                    .and_then(|halvings| (2 * half_life).checked_shl(halvings))
+                    .filter(|denominator| denominator.leading_zeros() > 0)
+                {
+                    Some(denominator) => numerator.saturating_add(denominator / 2) / denominator,
+                    None => 0,
+                };
+
+                (adjusted as usize).max(*min)
+            }
+            DifficultyCurve::Table(table) => table
+                .get(extra_length)
+                .or(table.last())
+                .copied()
+                .unwrap_or(difficulty),
+        }
+    }
+}
+
+impl fmt::Display for DifficultyCurve {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DifficultyCurve::Legacy => write!(f, "legacy"),
+            DifficultyCurve::Linear { step, min } => write!(f, "linear-{step}-{min}"),
+            DifficultyCurve::Exponential { half_life, min } => {
+                write!(f, "exponential-{half_life}-{min}")
+            }
+            DifficultyCurve::Table(table) => {
+                let entries: Vec<String> = table.iter().map(usize::to_string).collect();
+                write!(f, "table-{}", entries.join(","))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DifficultyCurve, DifficultyPolicy};
+    use crate::{PoW, PoWAlgo, UserData};
+
+    #[test]
+    fn test_difficulty_curves() {
+        let adjusted = |curve: &DifficultyCurve| -> Vec<usize> {
+            (1..=12).map(|length| curve.adjust(length, 24)).collect()
+        };
+
+        assert_eq!(
+            vec![6, 6, 6, 6, 6, 3, 3, 3, 2, 2, 2, 1],
+            (1..=12)
+                .map(|length| DifficultyCurve::Legacy.adjust(length, 6))
+                .collect::<Vec<usize>>()
+        );
+        assert_eq!(
+            vec![24, 24, 23, 23, 22, 22, 21, 21, 20, 20, 19, 19],
+            adjusted(&DifficultyCurve::Linear { step: 2, min: 0 })
+        );
+        assert_eq!(
+            vec![24, 21, 18, 15, 12, 11, 9, 8, 6, 6, 6, 6],
+            adjusted(&DifficultyCurve::Exponential {
+                half_life: 4,
+                min: 6
+            })
+        );
+        assert_eq!(
+            vec![28, 20, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16],
+            adjusted(&DifficultyCurve::Table(vec![28, 20, 16]))
+        );
+        assert_eq!(24, DifficultyCurve::Table(Vec::new()).adjust(3, 24));
+
+        let exponential = DifficultyCurve::Exponential {
+            half_life: 1,
+            min: 0,
+        };
+        assert_eq!(0, exponential.adjust(usize::MAX, usize::MAX));
+        assert_eq!(
+            0,
+            DifficultyCurve::Linear { step: 0, min: 0 }.adjust(100, 24)
+        );
+    }
+
+    #[test]
+    fn test_difficulty_curve_names() {
+        for curve in [
+            DifficultyCurve::Legacy,
+            DifficultyCurve::Linear { step: 2, min: 8 },
+            DifficultyCurve::Exponential {
+                half_life: 4,
+                min: 0,
+            },
+            DifficultyCurve::Table(vec![28, 20, 16]),
+            DifficultyCurve::Table(Vec::new()),
+        ] {
+            assert_eq!(
+                Some(curve.clone()),
+                DifficultyCurve::from_name(&curve.to_string())
+            );
+        }
+
+        assert_eq!(
+            "exponential-4-0",
+            DifficultyCurve::Exponential {
+                half_life: 4,
+                min: 0
+            }
+            .to_string()
+        );
+        for name in [
+            "",
+            "linear",
+            "linear-0-8",
+            "linear-2",
+            "linear-02-8",
+            "linear-2-8-1",
+            "cubic-2-8",
+            "table-1,,2",
+            "table-+1",
+        ] {
+            assert_eq!(None, DifficultyCurve::from_name(name), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_pow_difficulty_policy() {
+        let userdata = UserData::new("zeronet_user".to_string(), "1FBbx".to_string());
+
+        // The legacy curve gives 12 characters at difficulty 8 only 2 bits
+        let pow = PoW::new(userdata, 8, PoWAlgo::Sha256)
+            .unwrap()
+            .with_difficulty_policy(DifficultyCurve::Table(vec![12]));
+        assert_eq!(8, pow.adjusted_difficulty());
+        let (hash, nonce) = pow.calculate_pow().unwrap();
+        assert!(hash.starts_with("ff"), "capped at the full difficulty");
+        assert!(pow.verify_pow((hash, nonce)));
+
+        struct Constant(usize);
+
+        impl DifficultyPolicy for Constant {
+            fn adjust(&self, _: usize, _: usize) -> usize {
+                self.0
+            }
+        }
+
+        let pow = pow.with_difficulty_policy(Constant(4));
+        assert_eq!(4, pow.adjusted_difficulty());
+        let (hash, nonce) = pow.calculate_pow().unwrap();
+        assert!(hash.starts_with('f'));
+        assert!(pow.verify_pow((hash, nonce)));
+    }
+}
@@ -0,0 +1,546 @@
+use crate::json::{self, JsonValue};
+use crate::{
+    AnonIdPowError, DifficultyCurve, LengthMetric, MergeFormat, PoW, PoWAlgo, ProofError,
+    TargetMode, UserData,
+};
+
+/// Version of the proof format written by `Proof::to_json`
+pub const PROOF_VERSION: u64 = 1;
+
+/// A solved PoW together with everything needed to verify it
+///
+/// The JSON form is a flat object with the fields "version", "algo", "target_mode",
+/// "difficulty", "auth_address", "username", "nonce", and "hash", the algorithm and target
+/// mode use their names from `from_name`. A "length_metric" field is added when the
+/// username's length isn't counted in bytes, a "difficulty_policy" field when it isn't
+/// adjusted with the legacy curve, and "merge_format" and "namespace" fields when the
+/// userdata isn't merged in the legacy format.
+///
+/// With the `serde` feature Proof implements `Serialize` and `Deserialize` using the same
+/// fields, so serializing it with serde_json gives the same object as `to_json`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proof {
+    pub version: u64,
+    pub userdata: UserData,
+    pub difficulty: usize,
+    pub algo: PoWAlgo,
+    pub target_mode: TargetMode,
+    pub length_metric: LengthMetric,
+    pub difficulty_policy: DifficultyCurve,
+    pub hash: String,
+    pub nonce: usize,
+}
+
+impl Proof {
+    /// Returns the PoW the proof was made for
+    pub fn pow(&self) -> Result<PoW, AnonIdPowError> {
+        let pow = PoW::new(self.userdata.clone(), self.difficulty, self.algo.clone())?;
+
+        Ok(pow
+            .with_target_mode(self.target_mode)
+            .with_length_metric(self.length_metric)
+            .with_difficulty_policy(self.difficulty_policy.clone()))
+    }
+
+    /// Verifies the proof against its own parameters
+    ///
+    /// The parameters were chosen by whoever made the proof, so an easy target mode or
+    /// difficulty policy passes as well as a hard one. This alone proves nothing about how
+    /// much work was done, check proofs from others with `verify_against`.
+    pub fn verify(&self) -> bool {
+        self.pow()
+            .is_ok_and(|pow| pow.verify_pow((self.hash.clone(), self.nonce)))
+    }
+
+    /// Verifies the proof against the PoW the verifier requires
+    ///
+    /// The proof has to be for the PoW's userdata and use its algorithm, target mode, length
+    /// metric, and difficulty policy. Its difficulty can be higher than the PoW's as long as
+    /// its target is no easier to meet.
+    pub fn verify_against(&self, pow: &PoW) -> bool {
+        if self.userdata != pow.userdata
+            || self.algo != pow.algo
+            || self.target_mode != pow.target_mode
+            || self.length_metric != pow.length_metric
+            || self.difficulty_policy != pow.difficulty_policy
+        {
+            return false;
+        }
+
+        self.pow().is_ok_and(|proof_pow| {
+            proof_pow.estimate().probability <= pow.estimate().probability
+                && proof_pow.verify_pow((self.hash.clone(), self.nonce))
+        })
+    }
+
+    /// Serializes the proof to JSON
+    pub fn to_json(&self) -> String {
+        json::write_json_object(&self.json_fields())
+    }
+
+    /// Returns the fields written by `to_json`, in order
+    pub(crate) fn json_fields(&self) -> Vec<(&'static str, JsonValue)> {
+        let mut fields = vec![
+            ("version", JsonValue::Number(self.version)),
+            ("algo", JsonValue::String(self.algo.to_string())),
+            (
+                "target_mode",
+                JsonValue::String(self.target_mode.to_string()),
+            ),
+            ("difficulty", JsonValue::Number(self.difficulty as u64)),
+            (
+                "auth_address",
+                JsonValue::String(self.userdata.auth_address().to_string()),
+            ),
+            (
+                "username",
+                JsonValue::String(self.userdata.username().to_string()),
+            ),
+            ("nonce", JsonValue::Number(self.nonce as u64)),
+            ("hash", JsonValue::String(self.hash.clone())),
+        ];
+
+        if self.length_metric != LengthMetric::Bytes {
+            fields.push((
+                "length_metric",
+                JsonValue::String(self.length_metric.to_string()),
+            ));
+        }
+        if self.difficulty_policy != DifficultyCurve::Legacy {
+            fields.push((
+                "difficulty_policy",
+                JsonValue::String(self.difficulty_policy.to_string()),
+            ));
+        }
+
+        if self.userdata.merge_format() != MergeFormat::Legacy {
+            fields.push((
+                "merge_format",
+                JsonValue::String(self.userdata.merge_format().to_string()),
+            ));
+        }
+        if let Some(namespace) = self.userdata.namespace() {
+            fields.push(("namespace", JsonValue::String(namespace.to_string())));
+        }
+
+        fields
+    }
+
+    /// Initializes a Proof struct from its JSON form
+    ///
+    /// Unknown fields are ignored so newer writers can add optional ones.
+    pub fn from_json(proof: &str) -> Result<Proof, ProofError> {
+        let fields = json::read_object(proof).ok_or(ProofError::Malformed)?;
+
+        Proof::from_fields(&fields)
+    }
+
+    /// Initializes a Proof struct from the fields of its JSON form
+    fn from_fields(fields: &[(String, JsonValue)]) -> Result<Proof, ProofError> {
+        let field = |name: &'static str| {
+            fields
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value)
+                .ok_or(ProofError::MissingField(name))
+        };
+        let string = |name: &'static str| match field(name)? {
+            JsonValue::String(value) => Ok(value.as_str()),
+            _ => Err(ProofError::InvalidField(name)),
+        };
+        let number = |name: &'static str| match field(name)? {
+            JsonValue::Number(value) => {
+                usize::try_from(*value).map_err(|_| ProofError::InvalidField(name))
+            }
+            _ => Err(ProofError::InvalidField(name)),
+        };
+
+        let version = number("version")? as u64;
+        if version != PROOF_VERSION {
+            return Err(ProofError::UnsupportedVersion(version));
+        }
+
+        let length_metric = match field("length_metric") {
+            Ok(JsonValue::String(name)) => {
+                LengthMetric::from_name(name).ok_or(ProofError::InvalidField("length_metric"))?
+            }
+            Ok(_) => return Err(ProofError::InvalidField("length_metric")),
+            Err(_) => LengthMetric::Bytes,
+        };
+        let difficulty_policy = match field("difficulty_policy") {
+            Ok(JsonValue::String(name)) => DifficultyCurve::from_name(name)
+                .ok_or(ProofError::InvalidField("difficulty_policy"))?,
+            Ok(_) => return Err(ProofError::InvalidField("difficulty_policy")),
+            Err(_) => DifficultyCurve::Legacy,
+        };
+
+        let merge_format = match field("merge_format") {
+            Ok(JsonValue::String(name)) => {
+                MergeFormat::from_name(name).ok_or(ProofError::InvalidField("merge_format"))?
+            }
+            Ok(_) => return Err(ProofError::InvalidField("merge_format")),
+            Err(_) => MergeFormat::Legacy,
+        };
+        let mut userdata = UserData::new(
+            string("username")?.to_string(),
+            string("auth_address")?.to_string(),
+        )
+        .with_merge_format(merge_format);
+        match field("namespace") {
+            Ok(JsonValue::String(namespace)) if merge_format != MergeFormat::Legacy => {
+                userdata = userdata.with_namespace(namespace.clone());
+            }
+            Ok(_) => return Err(ProofError::InvalidField("namespace")),
+            Err(_) => {}
+        }
+
+        Ok(Proof {
+            version,
+            userdata,
+            difficulty: number("difficulty")?,
+            algo: PoWAlgo::from_name(string("algo")?)
+                .filter(|algo| algo.validate().is_ok())
+                .ok_or(ProofError::InvalidField("algo"))?,
+            target_mode: TargetMode::from_name(string("target_mode")?)
+                .ok_or(ProofError::InvalidField("target_mode"))?,
+            length_metric,
+            difficulty_policy,
+            hash: string("hash")?.to_string(),
+            nonce: number("nonce")?,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Proof {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let fields = self.json_fields();
+        let mut map = serializer.serialize_map(Some(fields.len()))?;
+        for (key, value) in &fields {
+            match value {
+                JsonValue::String(value) => map.serialize_entry(key, value)?,
+                JsonValue::Number(value) => map.serialize_entry(key, value)?,
+                _ => unreachable!("proof fields are strings or numbers"),
+            }
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Proof {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Proof, D::Error> {
+        deserializer.deserialize_map(ProofVisitor)
+    }
+}
+
+/// Collects the fields of a serialized proof for `Proof::from_fields`
+#[cfg(feature = "serde")]
+struct ProofVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for ProofVisitor {
+    type Value = Proof;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "a proof object")
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Proof, A::Error> {
+        let mut fields: Vec<(String, JsonValue)> = Vec::new();
+        while let Some(key) = map.next_key::<String>()? {
+            if fields.iter().any(|(name, _)| *name == key) {
+                return Err(serde::de::Error::custom(ProofError::Malformed));
+            }
+            let FieldValue(value) = map.next_value()?;
+            fields.push((key, value));
+        }
+
+        Proof::from_fields(&fields).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Value of a serialized proof field, strings and unsigned integers are kept and every other
+/// value is skipped so `from_fields` can reject or ignore it like the JSON reader does
+#[cfg(feature = "serde")]
+struct FieldValue(JsonValue);
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FieldValue {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<FieldValue, D::Error> {
+        deserializer.deserialize_any(FieldValueVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct FieldValueVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for FieldValueVisitor {
+    type Value = FieldValue;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "any value")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<FieldValue, E> {
+        Ok(FieldValue(JsonValue::String(value.to_string())))
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<FieldValue, E> {
+        Ok(FieldValue(JsonValue::Number(value)))
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<FieldValue, E> {
+        match u64::try_from(value) {
+            Ok(value) => self.visit_u64(value),
+            Err(_) => Ok(FieldValue(JsonValue::Float(value as f64))),
+        }
+    }
+
+    fn visit_f64<E: serde::de::Error>(self, value: f64) -> Result<FieldValue, E> {
+        Ok(FieldValue(JsonValue::Float(value)))
+    }
+
+    fn visit_bool<E: serde::de::Error>(self, value: bool) -> Result<FieldValue, E> {
+        Ok(FieldValue(JsonValue::Bool(value)))
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<FieldValue, E> {
+        Ok(FieldValue(JsonValue::Null))
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<FieldValue, E> {
+        Ok(FieldValue(JsonValue::Null))
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, _: &[u8]) -> Result<FieldValue, E> {
+        Ok(FieldValue(JsonValue::Raw(String::new())))
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<FieldValue, A::Error> {
+        while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+        Ok(FieldValue(JsonValue::Raw(String::new())))
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<FieldValue, A::Error> {
+        while map
+            .next_entry::<serde::de::IgnoredAny, serde::de::IgnoredAny>()?
+            .is_some()
+        {}
+        Ok(FieldValue(JsonValue::Raw(String::new())))
+    }
+}
+
+impl PoW {
+    /// Wraps a hash and nonce returned by `calculate_pow` into a Proof with the PoW's
+    /// parameters
+    pub fn proof(&self, pow_value: (String, usize)) -> Proof {
+        let (hash, nonce) = pow_value;
+
+        Proof {
+            version: PROOF_VERSION,
+            userdata: self.userdata.clone(),
+            difficulty: self.difficulty,
+            algo: self.algo.clone(),
+            target_mode: self.target_mode,
+            length_metric: self.length_metric,
+            difficulty_policy: self.difficulty_policy.clone(),
+            hash,
+            nonce,
+        }
+    }
+
+    /// Calculates the PoW and returns it as a Proof
+    pub fn prove(&self) -> Result<Proof, AnonIdPowError> {
+        Ok(self.proof(self.calculate_pow()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Proof, PROOF_VERSION};
+    use crate::{DifficultyCurve, LengthMetric, PoW, PoWAlgo, ProofError, TargetMode, UserData};
+
+    fn proof() -> Proof {
+        let userdata =
+            UserData::from_merged("1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user".to_string())
+                .unwrap();
+
+        PoW::new(userdata, 12, PoWAlgo::Sha256)
+            .unwrap()
+            .with_target_mode(TargetMode::LeadingZeroBits)
+            .prove()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_proof_json() {
+        let proof = proof();
+        assert!(proof.verify());
+
+        let json = proof.to_json();
+        assert_eq!(
+            format!(
+                concat!(
+                    r#"{{"version":1,"algo":"sha256","target_mode":"zeros","difficulty":12,"#,
+                    r#""auth_address":"1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX","#,
+                    r#""username":"zeronet_user","nonce":{},"hash":"{}"}}"#
+                ),
+                proof.nonce, proof.hash
+            ),
+            json
+        );
+        assert_eq!(Ok(proof.clone()), Proof::from_json(&json));
+
+        // Unknown fields are skipped whatever their type
+        let with_unknown = json.replace('}', r#","x":true,"y":[1,{"z":null}],"w":-1.5}"#);
+        assert_eq!(Ok(proof.clone()), Proof::from_json(&with_unknown));
+
+        let proof = Proof {
+            length_metric: LengthMetric::Graphemes,
+            difficulty_policy: DifficultyCurve::Table(vec![16, 12]),
+            ..proof
+        };
+        let json = proof.to_json();
+        assert!(
+            json.ends_with(r#","length_metric":"graphemes","difficulty_policy":"table-16,12"}"#)
+        );
+        assert_eq!(Ok(proof.clone()), Proof::from_json(&json));
+
+        let proof = Proof {
+            userdata: proof.userdata.with_namespace("zeronet".to_string()),
+            ..proof
+        };
+        let json = proof.to_json();
+        assert!(json.ends_with(r#","merge_format":"v1","namespace":"zeronet"}"#));
+        assert_eq!(Ok(proof), Proof::from_json(&json));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_proof_serde() {
+        let proof = Proof {
+            length_metric: LengthMetric::Graphemes,
+            ..proof()
+        };
+
+        let json = serde_json::to_string(&proof).unwrap();
+        assert_eq!(proof.to_json(), json);
+        assert_eq!(proof, serde_json::from_str::<Proof>(&json).unwrap());
+
+        let with_unknown = json.replace('}', r#","x":true,"y":[1,{"z":null}],"w":-1.5}"#);
+        assert_eq!(proof, serde_json::from_str::<Proof>(&with_unknown).unwrap());
+
+        for invalid in [
+            json.replace(r#""difficulty":12"#, r#""difficulty":true"#),
+            json.replace(r#""algo":"sha256""#, r#""algo":"md5""#),
+            json.replace(r#""version":1"#, r#""version":1,"version":1"#),
+            json.replace(r#""version":1,"#, ""),
+        ] {
+            assert!(
+                serde_json::from_str::<Proof>(&invalid).is_err(),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_proof_verify() {
+        let mut proof = proof();
+
+        proof.difficulty = 24;
+        assert!(!proof.verify());
+
+        proof.difficulty = 1;
+        assert!(!proof.verify());
+    }
+
+    #[test]
+    fn test_proof_verify_against() {
+        let proof = proof();
+        let pow = proof.pow().unwrap();
+        assert!(proof.verify_against(&pow));
+
+        // The verifier's parameters count, not the ones the proof claims
+        let harder = PoW::new(proof.userdata.clone(), 16, PoWAlgo::Sha256)
+            .unwrap()
+            .with_target_mode(TargetMode::LeadingZeroBits);
+        assert!(!proof.verify_against(&harder));
+        let easier = PoW::new(proof.userdata.clone(), 8, PoWAlgo::Sha256)
+            .unwrap()
+            .with_target_mode(TargetMode::LeadingZeroBits);
+        assert!(proof.verify_against(&easier));
+
+        for forged in [
+            Proof {
+                difficulty_policy: DifficultyCurve::Table(vec![2]),
+                ..proof.clone()
+            },
+            Proof {
+                target_mode: TargetMode::HexPrefix,
+                ..proof.clone()
+            },
+            Proof {
+                length_metric: LengthMetric::Graphemes,
+                ..proof.clone()
+            },
+            Proof {
+                userdata: UserData::new("zeronet_user".to_string(), "1FBbx".to_string()),
+                ..proof.clone()
+            },
+        ] {
+            assert!(!forged.verify_against(&pow), "{forged:?}");
+        }
+    }
+
+    #[test]
+    fn test_proof_invalid_json() {
+        let json = proof().to_json();
+
+        assert_eq!(Err(ProofError::Malformed), Proof::from_json("{"));
+        assert_eq!(
+            Err(ProofError::MissingField("hash")),
+            Proof::from_json(&json.replace("\"hash\"", "\"digest\""))
+        );
+        assert_eq!(
+            Err(ProofError::InvalidField("algo")),
+            Proof::from_json(&json.replace("sha256", "md5"))
+        );
+        assert_eq!(
+            Err(ProofError::InvalidField("algo")),
+            Proof::from_json(&json.replace("sha256", "argon2id,m=4000000000,t=1,p=1"))
+        );
+        assert_eq!(
+            Err(ProofError::InvalidField("difficulty")),
+            Proof::from_json(&json.replace(":12,", ":\"12\","))
+        );
+        assert_eq!(
+            Err(ProofError::InvalidField("difficulty")),
+            Proof::from_json(&json.replace(":12,", ":true,"))
+        );
+        assert_eq!(
+            Err(ProofError::InvalidField("difficulty")),
+            Proof::from_json(&json.replace(":12,", ":-12,"))
+        );
+        assert_eq!(
+            Err(ProofError::InvalidField("length_metric")),
+            Proof::from_json(&json.replace("}", r#","length_metric":"words"}"#))
+        );
+        assert_eq!(
+            Err(ProofError::InvalidField("difficulty_policy")),
+            Proof::from_json(&json.replace("}", r#","difficulty_policy":"linear-0-8"}"#))
+        );
+        assert_eq!(
+            Err(ProofError::InvalidField("namespace")),
+            Proof::from_json(&json.replace("}", r#","namespace":"zeronet"}"#))
+        );
+        assert_eq!(
+            Err(ProofError::UnsupportedVersion(PROOF_VERSION + 1)),
+            Proof::from_json(&json.replace("\"version\":1", "\"version\":2"))
+        );
+    }
+}
@@ -0,0 +1,438 @@
+//! Public key recovery from ECDSA signatures over secp256k1, as used to verify Bitcoin
+//! signed messages
+//!
+//! Numbers are 256-bit little-endian arrays of 64-bit limbs. Nothing here runs in constant
+//! time, which is fine since only public data is handled. Signing is left to wallets.
+
+type U256 = [u64; 4];
+
+/// A modulus `2^256 - c` with a small `c`, both the field prime and the group order are one
+struct Modulus {
+    m: U256,
+    c: U256,
+}
+
+/// The field prime `2^256 - 2^32 - 977`
+const P: Modulus = Modulus {
+    m: [
+        0xfffffffefffffc2f,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ],
+    c: [0x00000001000003d1, 0, 0, 0],
+};
+
+/// The order of the generator
+const N: Modulus = Modulus {
+    m: [
+        0xbfd25e8cd0364141,
+        0xbaaedce6af48a03b,
+        0xfffffffffffffffe,
+        0xffffffffffffffff,
+    ],
+    c: [0x402da1732fc9bebf, 0x4551231950b75fc4, 1, 0],
+};
+
+const GENERATOR: Point = Point {
+    x: [
+        0x59f2815b16f81798,
+        0x029bfcdb2dce28d9,
+        0x55a06295ce870b07,
+        0x79be667ef9dcbbac,
+    ],
+    y: [
+        0x9c47d08ffb10d4b8,
+        0xfd17b448a6855419,
+        0x5da4fbfc0e1108a8,
+        0x483ada7726a3c465,
+    ],
+    z: [1, 0, 0, 0],
+};
+
+const ZERO: U256 = [0; 4];
+const ONE: U256 = [1, 0, 0, 0];
+
+fn from_be_bytes(bytes: &[u8; 32]) -> U256 {
+    let mut value = ZERO;
+    for (limb, chunk) in value.iter_mut().rev().zip(bytes.chunks_exact(8)) {
+        *limb = u64::from_be_bytes(chunk.try_into().unwrap());
+    }
+    value
+}
+
+fn to_be_bytes(value: &U256) -> [u8; 32] {
+    let mut bytes = [0; 32];
+    for (chunk, limb) in bytes.chunks_exact_mut(8).zip(value.iter().rev()) {
+        chunk.copy_from_slice(&limb.to_be_bytes());
+    }
+    bytes
+}
+
+fn is_less(a: &U256, b: &U256) -> bool {
+    a.iter().rev().cmp(b.iter().rev()).is_lt()
+}
+
+fn add(a: &U256, b: &U256) -> (U256, bool) {
+    let mut sum = ZERO;
+    let mut carry = false;
+    for index in 0..4 {
+        let (limb, carry_a) = a[index].overflowing_add(b[index]);
+        let (limb, carry_b) = limb.overflowing_add(carry as u64);
+        sum[index] = limb;
+        carry = carry_a || carry_b;
+    }
+    (sum, carry)
+}
+
+fn sub(a: &U256, b: &U256) -> (U256, bool) {
+    let mut difference = ZERO;
+    let mut borrow = false;
+    for index in 0..4 {
+        let (limb, borrow_a) = a[index].overflowing_sub(b[index]);
+        let (limb, borrow_b) = limb.overflowing_sub(borrow as u64);
+        difference[index] = limb;
+        borrow = borrow_a || borrow_b;
+    }
+    (difference, borrow)
+}
+
+fn mul_wide(a: &U256, b: &U256) -> [u64; 8] {
+    let mut product = [0; 8];
+    for (i, a_limb) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, b_limb) in b.iter().enumerate() {
+            let value = *a_limb as u128 * *b_limb as u128 + product[i + j] as u128 + carry as u128;
+            product[i + j] = value as u64;
+            carry = (value >> 64) as u64;
+        }
+        product[i + 4] = carry;
+    }
+    product
+}
+
+impl Modulus {
+    /// Reduces a 512-bit number by folding the high half, `2^256 = c` modulo `m`
+    fn reduce(&self, mut wide: [u64; 8]) -> U256 {
+        while wide[4..] != ZERO {
+            let high: U256 = wide[4..].try_into().unwrap();
+            let mut folded = mul_wide(&high, &self.c);
+
+            let mut carry = 0;
+            for (index, limb) in folded.iter_mut().enumerate() {
+                let low = if index < 4 { wide[index] } else { 0 };
+                let value = *limb as u128 + low as u128 + carry as u128;
+                *limb = value as u64;
+                carry = (value >> 64) as u64;
+            }
+            wide = folded;
+        }
+
+        let mut value: U256 = wide[..4].try_into().unwrap();
+        while !is_less(&value, &self.m) {
+            value = sub(&value, &self.m).0;
+        }
+        value
+    }
+
+    fn add(&self, a: &U256, b: &U256) -> U256 {
+        let (sum, carry) = add(a, b);
+        if carry || !is_less(&sum, &self.m) {
+            sub(&sum, &self.m).0
+        } else {
+            sum
+        }
+    }
+
+    fn sub(&self, a: &U256, b: &U256) -> U256 {
+        let (difference, borrow) = sub(a, b);
+        if borrow {
+            add(&difference, &self.m).0
+        } else {
+            difference
+        }
+    }
+
+    fn mul(&self, a: &U256, b: &U256) -> U256 {
+        self.reduce(mul_wide(a, b))
+    }
+
+    fn pow(&self, base: &U256, exponent: &U256) -> U256 {
+        let mut result = ONE;
+        for bit in (0..256).rev() {
+            result = self.mul(&result, &result);
+            if exponent[bit / 64] >> (bit % 64) & 1 == 1 {
+                result = self.mul(&result, base);
+            }
+        }
+        result
+    }
+
+    /// Inverts a non-zero number with Fermat's little theorem
+    fn inv(&self, value: &U256) -> U256 {
+        self.pow(value, &sub(&self.m, &[2, 0, 0, 0]).0)
+    }
+}
+
+/// A point in Jacobian coordinates, the point at infinity has `z = 0`
+#[derive(Clone, Copy, Debug)]
+struct Point {
+    x: U256,
+    y: U256,
+    z: U256,
+}
+
+impl Point {
+    const INFINITY: Point = Point {
+        x: ONE,
+        y: ONE,
+        z: ZERO,
+    };
+
+    fn is_infinity(&self) -> bool {
+        self.z == ZERO
+    }
+
+    fn double(&self) -> Point {
+        if self.is_infinity() || self.y == ZERO {
+            return Point::INFINITY;
+        }
+
+        let a = P.mul(&self.x, &self.x);
+        let b = P.mul(&self.y, &self.y);
+        let c = P.mul(&b, &b);
+        let x_plus_b = P.add(&self.x, &b);
+        let d = P.sub(&P.sub(&P.mul(&x_plus_b, &x_plus_b), &a), &c);
+        let d = P.add(&d, &d);
+        let e = P.add(&P.add(&a, &a), &a);
+        let f = P.mul(&e, &e);
+
+        let x = P.sub(&f, &P.add(&d, &d));
+        let c8 = P.add(&c, &c);
+        let c8 = P.add(&c8, &c8);
+        let c8 = P.add(&c8, &c8);
+        let y = P.sub(&P.mul(&e, &P.sub(&d, &x)), &c8);
+        let z = P.mul(&P.add(&self.y, &self.y), &self.z);
+
+        Point { x, y, z }
+    }
+
+    fn add(&self, other: &Point) -> Point {
+        if self.is_infinity() {
+            return *other;
+        }
+        if other.is_infinity() {
+            return *self;
+        }
+
+        let z1_squared = P.mul(&self.z, &self.z);
+        let z2_squared = P.mul(&other.z, &other.z);
+        let u1 = P.mul(&self.x, &z2_squared);
+        let u2 = P.mul(&other.x, &z1_squared);
+        let s1 = P.mul(&self.y, &P.mul(&z2_squared, &other.z));
+        let s2 = P.mul(&other.y, &P.mul(&z1_squared, &self.z));
+
+        if u1 == u2 {
+            return if s1 == s2 {
+                self.double()
+            } else {
+                Point::INFINITY
+            };
+        }
+
+        let h = P.sub(&u2, &u1);
+        let r = P.sub(&s2, &s1);
+        let h_squared = P.mul(&h, &h);
+        let h_cubed = P.mul(&h_squared, &h);
+        let u1_h_squared = P.mul(&u1, &h_squared);
+
+        let x = P.sub(
+            &P.sub(&P.mul(&r, &r), &h_cubed),
+            &P.add(&u1_h_squared, &u1_h_squared),
+        );
+        let y = P.sub(&P.mul(&r, &P.sub(&u1_h_squared, &x)), &P.mul(&s1, &h_cubed));
+        let z = P.mul(&h, &P.mul(&self.z, &other.z));
+
+        Point { x, y, z }
+    }
+
+    fn mul(&self, scalar: &U256) -> Point {
+        let mut result = Point::INFINITY;
+        for bit in (0..256).rev() {
+            result = result.double();
+            if scalar[bit / 64] >> (bit % 64) & 1 == 1 {
+                result = result.add(self);
+            }
+        }
+        result
+    }
+
+    /// Returns the affine coordinates, None for the point at infinity
+    fn to_affine(self) -> Option<(U256, U256)> {
+        if self.is_infinity() {
+            return None;
+        }
+
+        let z_inverse = P.inv(&self.z);
+        let z_inverse_squared = P.mul(&z_inverse, &z_inverse);
+        let x = P.mul(&self.x, &z_inverse_squared);
+        let y = P.mul(&self.y, &P.mul(&z_inverse_squared, &z_inverse));
+        Some((x, y))
+    }
+
+    /// Finds the point with the x coordinate and the parity of y, if there is one
+    fn from_x(x: &U256, is_odd: bool) -> Option<Point> {
+        let x_cubed = P.mul(&P.mul(x, x), x);
+        let y_squared = P.add(&x_cubed, &[7, 0, 0, 0]);
+
+        // P is 3 mod 4, so the square root is y_squared^((P + 1) / 4)
+        let exponent = [
+            0xffffffffbfffff0c,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0x3fffffffffffffff,
+        ];
+        let mut y = P.pow(&y_squared, &exponent);
+        if P.mul(&y, &y) != y_squared {
+            return None;
+        }
+        if (y[0] & 1 == 1) != is_odd {
+            y = P.sub(&ZERO, &y);
+        }
+
+        Some(Point { x: *x, y, z: ONE })
+    }
+}
+
+/// Serializes a public key in the 33 byte compressed or the 65 byte uncompressed form
+fn serialize_public_key(point: &Point, compressed: bool) -> Option<Vec<u8>> {
+    let (x, y) = point.to_affine()?;
+
+    let mut bytes = if compressed {
+        vec![2 | (y[0] & 1) as u8]
+    } else {
+        vec![4]
+    };
+    bytes.extend(to_be_bytes(&x));
+    if !compressed {
+        bytes.extend(to_be_bytes(&y));
+    }
+    Some(bytes)
+}
+
+/// Reduces a hash to a scalar, the hash is never more than twice the group order
+fn scalar_from_hash(hash: &[u8; 32]) -> U256 {
+    N.reduce([from_be_bytes(hash), ZERO].concat().try_into().unwrap())
+}
+
+/// Recovers the public key that made the signature of the message hash
+pub(crate) fn recover(
+    hash: &[u8; 32],
+    r: &[u8; 32],
+    s: &[u8; 32],
+    recovery_id: u8,
+    compressed: bool,
+) -> Option<Vec<u8>> {
+    let r = from_be_bytes(r);
+    let s = from_be_bytes(s);
+    for value in [&r, &s] {
+        if *value == ZERO || !is_less(value, &N.m) {
+            return None;
+        }
+    }
+
+    let x = if recovery_id & 2 == 0 {
+        r
+    } else {
+        let (x, carry) = add(&r, &N.m);
+        if carry || !is_less(&x, &P.m) {
+            return None;
+        }
+        x
+    };
+    let big_r = Point::from_x(&x, recovery_id & 1 == 1)?;
+
+    // Q = r^-1 (sR - zG)
+    let r_inverse = N.inv(&r);
+    let z = scalar_from_hash(hash);
+    let u1 = N.mul(&N.sub(&ZERO, &z), &r_inverse);
+    let u2 = N.mul(&s, &r_inverse);
+    let public_key = GENERATOR.mul(&u1).add(&big_r.mul(&u2));
+
+    serialize_public_key(&public_key, compressed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::recover;
+    use crate::to_hex;
+    use sha2::{Digest, Sha256};
+
+    fn bytes(hex: &str) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[index * 2..index * 2 + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_recover() {
+        let hash: [u8; 32] = Sha256::digest(b"sample").into();
+
+        // Signatures of the hash by the secret keys 0101...01, 7f7f...7f, and fe ff...ff
+        for (r, s, recovery_id, public_key) in [
+            (
+                "d5dedac97504ed218aac714f53bc009776036453c8081db7f5494684496c73a7",
+                "704aaa069615e793e26b274857f581b2c679dcb1a425523ef1ae52f6a80480ff",
+                0,
+                concat!(
+                    "1b84c5567b126440995d3ed5aaba0565d71e1834604819ff9c17f5e9d5dd078f",
+                    "70beaf8f588b541507fed6a642c5ab42dfdf8120a7f639de5122d47a69a8e8d1"
+                ),
+            ),
+            (
+                "8c5ac2d911b5fe4a2cec485f4c337d030f043258c0a3ae3bb98d5e0fd9b08969",
+                "19e45f6e6330d670da48532fb490b06576cbab19c418dc4e99c59dcfd0956b93",
+                0,
+                concat!(
+                    "142715675faf8da1ecc4d51e0b9e539fa0d52fdd96ed60dbe99adb15d6b05ad9",
+                    "0bb325eaeeb09c99bc600eacef8a1b50f39a7c85dbb5d0ec7dde9a2f6458e32f"
+                ),
+            ),
+            (
+                "1e736d30affefef41a80e7af135860ace5aa2bc3066dfece5ac8890dddde4b0a",
+                "6a56a97139948c4dc41e9121879282033f384375c926ce86acdb0ee60bdf90be",
+                1,
+                concat!(
+                    "5c33ffee092ee0c712cac13fb17b8a599b16cfc4b324c7d0a9497e0ecad7c2a4",
+                    "2701a1c0cb3df706681e890856d8ab48d6a51d8698244aec38b756cd928eaaa8"
+                ),
+            ),
+        ] {
+            let (r, s) = (bytes(r), bytes(s));
+            let y_is_odd = public_key.ends_with(['1', '3', '5', '7', '9', 'b', 'd', 'f']);
+            let compressed = format!(
+                "{}{}",
+                if y_is_odd { "03" } else { "02" },
+                &public_key[..64]
+            );
+
+            assert_eq!(
+                Some(format!("04{public_key}")),
+                recover(&hash, &r, &s, recovery_id, false).map(|key| to_hex(&key))
+            );
+            assert_eq!(
+                Some(compressed.clone()),
+                recover(&hash, &r, &s, recovery_id, true).map(|key| to_hex(&key))
+            );
+            assert_ne!(
+                Some(compressed),
+                recover(&hash, &r, &s, recovery_id ^ 1, true).map(|key| to_hex(&key))
+            );
+        }
+
+        assert_eq!(None, recover(&hash, &[0; 32], &[1; 32], 0, true));
+        assert_eq!(None, recover(&hash, &[1; 32], &[0xff; 32], 0, true));
+    }
+}
@@ -0,0 +1,165 @@
+//! SHA3-256 (FIPS 202)
+
+/// Number of bytes absorbed per Keccak-f[1600] permutation for a 256-bit output
+const RATE: usize = 136;
+
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// Rotation offsets of the rho step, in the lane order visited by the pi step
+const RHO_OFFSETS: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+/// Lane indices visited by the pi step
+const PI_LANES: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+fn keccak_f(state: &mut [u64; 25]) {
+    for round_constant in ROUND_CONSTANTS {
+        // theta
+        let mut columns = [0u64; 5];
+        for (x, column) in columns.iter_mut().enumerate() {
+            *column = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+        for x in 0..5 {
+            let d = columns[(x + 4) % 5] ^ columns[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                state[x + 5 * y] ^= d;
+            }
+        }
+
+        // rho and pi
+        let mut current = state[1];
+        for (lane, offset) in PI_LANES.into_iter().zip(RHO_OFFSETS) {
+            let next = state[lane];
+            state[lane] = current.rotate_left(offset);
+            current = next;
+        }
+
+        // chi
+        for y in 0..5 {
+            let row = [
+                state[5 * y],
+                state[5 * y + 1],
+                state[5 * y + 2],
+                state[5 * y + 3],
+                state[5 * y + 4],
+            ];
+            for x in 0..5 {
+                state[5 * y + x] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+            }
+        }
+
+        // iota
+        state[0] ^= round_constant;
+    }
+}
+
+/// Incremental SHA3-256 hasher, cloning it after absorbing a prefix reuses that work
+#[derive(Clone)]
+pub(crate) struct Sha3_256 {
+    state: [u64; 25],
+    buffer: [u8; RATE],
+    buffer_len: usize,
+}
+
+impl Sha3_256 {
+    pub(crate) fn new() -> Sha3_256 {
+        Sha3_256 {
+            state: [0; 25],
+            buffer: [0; RATE],
+            buffer_len: 0,
+        }
+    }
+
+    pub(crate) fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let take = data.len().min(RATE - self.buffer_len);
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&data[..take]);
+            self.buffer_len += take;
+            data = &data[take..];
+
+            if self.buffer_len == RATE {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    pub(crate) fn finalize(mut self) -> [u8; 32] {
+        self.buffer[self.buffer_len..].fill(0);
+        self.buffer[self.buffer_len] ^= 0x06;
+        self.buffer[RATE - 1] ^= 0x80;
+        self.absorb_buffer();
+
+        let mut digest = [0; 32];
+        for (chunk, lane) in digest.chunks_exact_mut(8).zip(self.state) {
+            chunk.copy_from_slice(&lane.to_le_bytes());
+        }
+        digest
+    }
+
+    fn absorb_buffer(&mut self) {
+        for (lane, chunk) in self.state.iter_mut().zip(self.buffer.chunks_exact(8)) {
+            *lane ^= u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        keccak_f(&mut self.state);
+        self.buffer_len = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sha3_256;
+
+    fn sha3_256(data: &[u8]) -> String {
+        let mut hasher = Sha3_256::new();
+        hasher.update(data);
+        hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    #[test]
+    fn test_sha3_256() {
+        assert_eq!(
+            "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a",
+            sha3_256(b"")
+        );
+        assert_eq!(
+            "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532",
+            sha3_256(b"abc")
+        );
+        assert_eq!(
+            "8f3934e6f7a15698fe0f396b95d8c4440929a8fa6eae140171c068b4549fbf81",
+            sha3_256(&[b'a'; 1000])
+        );
+    }
+}
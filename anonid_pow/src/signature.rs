@@ -0,0 +1,459 @@
+use sha2::{Digest, Sha256};
+
+#[cfg(feature = "sign")]
+use crate::address::base58check_decode;
+use crate::json::{self, JsonValue};
+use crate::ripemd160::ripemd160;
+#[cfg(feature = "sign")]
+use crate::AddressKind;
+use crate::{secp256k1, AuthAddress, Proof, ProofError, SignatureError};
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Version byte of mainnet WIF secret keys
+#[cfg(feature = "sign")]
+const WIF_VERSION: u8 = 0x80;
+
+/// Prefix of the messages signed for a `SignedProof`, so they can't be mistaken for other
+/// messages signed with the same key
+const PROOF_MESSAGE_PREFIX: &str = "anonid-proof:";
+
+#[cfg(feature = "sign")]
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::new();
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0, |group, (index, byte)| {
+            group | (*byte as u32) << (16 - 8 * index)
+        });
+        for index in 0..4 {
+            if index <= chunk.len() {
+                encoded.push(BASE64_ALPHABET[(group >> (18 - 6 * index) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Decodes padded base64, None if the input isn't in the canonical encoding
+fn base64_decode(encoded: &str) -> Option<Vec<u8>> {
+    if !encoded.len().is_multiple_of(4) {
+        return None;
+    }
+
+    let chunks = encoded.len() / 4;
+    let mut bytes = Vec::new();
+    for (index, chunk) in encoded.as_bytes().chunks(4).enumerate() {
+        let padding = chunk.iter().rev().take_while(|byte| **byte == b'=').count();
+        if padding > 2 || (padding > 0 && index + 1 != chunks) {
+            return None;
+        }
+
+        let mut group = 0;
+        for byte in &chunk[..4 - padding] {
+            let value = BASE64_ALPHABET.iter().position(|symbol| symbol == byte)?;
+            group = group << 6 | value as u32;
+        }
+        group <<= 6 * padding;
+
+        // The unused bits before the padding have to be zero
+        let decoded = [(group >> 16) as u8, (group >> 8) as u8, group as u8];
+        if decoded[3 - padding..].iter().any(|byte| *byte != 0) {
+            return None;
+        }
+        bytes.extend(&decoded[..3 - padding]);
+    }
+    Some(bytes)
+}
+
+/// Hashes a message the way Bitcoin's signmessage does
+fn message_hash(message: &str) -> [u8; 32] {
+    let mut data = b"\x18Bitcoin Signed Message:\n".to_vec();
+    let length = message.len() as u64;
+    match length {
+        0..=0xfc => data.push(length as u8),
+        0xfd..=0xffff => {
+            data.push(0xfd);
+            data.extend((length as u16).to_le_bytes());
+        }
+        0x10000..=0xffffffff => {
+            data.push(0xfe);
+            data.extend((length as u32).to_le_bytes());
+        }
+        _ => {
+            data.push(0xff);
+            data.extend(length.to_le_bytes());
+        }
+    }
+    data.extend(message.as_bytes());
+
+    Sha256::digest(Sha256::digest(data)).into()
+}
+
+fn hash160(public_key: &[u8]) -> [u8; 20] {
+    ripemd160(&Sha256::digest(public_key))
+}
+
+/// A secp256k1 secret key, the key behind an auth_address
+///
+/// Signing uses k256's constant time implementation with RFC 6979 nonces and low s values,
+/// so signatures match the ones Bitcoin Core's signmessage makes. The secret is zeroed when
+/// the key is dropped.
+#[cfg(feature = "sign")]
+#[derive(Clone)]
+pub struct SigningKey {
+    key: k256::ecdsa::SigningKey,
+    compressed: bool,
+}
+
+#[cfg(feature = "sign")]
+impl SigningKey {
+    /// Initializes a SigningKey from its 32 big-endian bytes
+    ///
+    /// `compressed` selects the public key form the address is derived from, ZeroNet uses
+    /// both.
+    pub fn from_bytes(secret: [u8; 32], compressed: bool) -> Result<SigningKey, SignatureError> {
+        let key =
+            k256::ecdsa::SigningKey::from_slice(&secret).map_err(|_| SignatureError::InvalidKey)?;
+
+        Ok(SigningKey { key, compressed })
+    }
+
+    /// Initializes a SigningKey from a mainnet WIF string, the format ZeroNet stores keys in
+    pub fn from_wif(wif: &str) -> Result<SigningKey, SignatureError> {
+        let payload = base58check_decode(wif, &[33, 34]).map_err(|_| SignatureError::InvalidKey)?;
+        if payload[0] != WIF_VERSION || payload.get(33).is_some_and(|flag| *flag != 1) {
+            return Err(SignatureError::InvalidKey);
+        }
+
+        SigningKey::from_bytes(payload[1..33].try_into().unwrap(), payload.len() == 34)
+    }
+
+    /// Returns the P2PKH address of the key
+    pub fn auth_address(&self) -> AuthAddress {
+        let public_key = self.key.verifying_key().to_encoded_point(self.compressed);
+
+        AuthAddress::new(AddressKind::P2pkh, hash160(public_key.as_bytes()))
+    }
+
+    /// Signs a message with the Bitcoin signed message scheme and returns the base64
+    /// signature
+    pub fn sign_message(&self, message: &str) -> String {
+        let (signature, recovery_id) = self
+            .key
+            .sign_prehash_recoverable(&message_hash(message))
+            .expect("a 32 byte digest can always be signed");
+
+        let header = 27 + recovery_id.to_byte() + if self.compressed { 4 } else { 0 };
+        let signature = [&[header][..], &signature.to_bytes()].concat();
+        base64_encode(&signature)
+    }
+}
+
+impl AuthAddress {
+    /// Checks a Bitcoin signed message signature against the address
+    ///
+    /// The public key is recovered from the signature and its hash160 has to match the
+    /// address, signatures for P2WPKH addresses have to use a compressed key.
+    pub fn verify_message(&self, message: &str, signature: &str) -> Result<(), SignatureError> {
+        let signature: [u8; 65] = base64_decode(signature)
+            .and_then(|signature| signature.try_into().ok())
+            .ok_or(SignatureError::Malformed)?;
+
+        let header = signature[0];
+        if !(27..=42).contains(&header) {
+            return Err(SignatureError::Malformed);
+        }
+        let recovery_id = (header - 27) % 4;
+        let compressed = header >= 31;
+
+        let public_key = secp256k1::recover(
+            &message_hash(message),
+            signature[1..33].try_into().unwrap(),
+            signature[33..].try_into().unwrap(),
+            recovery_id,
+            compressed,
+        )
+        .ok_or(SignatureError::Malformed)?;
+
+        if hash160(&public_key) != self.hash160() {
+            return Err(SignatureError::AddressMismatch);
+        }
+
+        Ok(())
+    }
+}
+
+/// A proof signed with the key behind its auth_address
+///
+/// The signature covers "anonid-proof:" followed by the proof's JSON, the message returned
+/// by `Proof::signing_message`, so the proof can't be claimed by someone who only copied it.
+/// The JSON form is the proof's JSON with an extra "signature" field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignedProof {
+    pub proof: Proof,
+    /// Base64 Bitcoin signed message signature
+    pub signature: String,
+}
+
+impl SignedProof {
+    /// Checks the signature, that it was made by the key behind the proof's auth_address, and
+    /// the proof itself
+    ///
+    /// Like `Proof::verify` the proof is checked against its own parameters, use
+    /// `verify_signature` and `Proof::verify_against` to require a PoW.
+    pub fn verify(&self) -> Result<(), SignatureError> {
+        self.verify_signature()?;
+
+        if !self.proof.verify() {
+            return Err(SignatureError::InvalidProof);
+        }
+
+        Ok(())
+    }
+
+    /// Checks only the signature, for callers that verify the proof themselves
+    pub fn verify_signature(&self) -> Result<(), SignatureError> {
+        let auth_address = self
+            .proof
+            .userdata
+            .validate_auth_address()
+            .map_err(SignatureError::InvalidAddress)?;
+
+        auth_address.verify_message(&self.proof.signing_message(), &self.signature)
+    }
+
+    /// Serializes the signed proof to JSON
+    pub fn to_json(&self) -> String {
+        let mut fields = self.proof.json_fields();
+        fields.push(("signature", JsonValue::String(self.signature.clone())));
+
+        json::write_json_object(&fields)
+    }
+
+    /// Initializes a SignedProof struct from its JSON form
+    pub fn from_json(signed_proof: &str) -> Result<SignedProof, ProofError> {
+        let proof = Proof::from_json(signed_proof)?;
+
+        let fields = json::read_object(signed_proof).ok_or(ProofError::Malformed)?;
+        let signature = match fields.into_iter().find(|(key, _)| key == "signature") {
+            Some((_, JsonValue::String(signature))) => signature,
+            Some(_) => return Err(ProofError::InvalidField("signature")),
+            None => return Err(ProofError::MissingField("signature")),
+        };
+
+        Ok(SignedProof { proof, signature })
+    }
+}
+
+impl Proof {
+    /// Returns the message the owner signs for a `SignedProof`
+    ///
+    /// It is signed with the Bitcoin signed message scheme, by `Proof::sign` or by the wallet
+    /// that holds the key behind the auth_address.
+    pub fn signing_message(&self) -> String {
+        format!("{PROOF_MESSAGE_PREFIX}{}", self.to_json())
+    }
+
+    /// Signs the proof with the key behind its auth_address
+    ///
+    /// Fails if the auth_address is not a valid address or belongs to another key.
+    #[cfg(feature = "sign")]
+    pub fn sign(&self, key: &SigningKey) -> Result<SignedProof, SignatureError> {
+        let auth_address = self
+            .userdata
+            .validate_auth_address()
+            .map_err(SignatureError::InvalidAddress)?;
+        if auth_address.hash160() != key.auth_address().hash160() {
+            return Err(SignatureError::AddressMismatch);
+        }
+
+        Ok(SignedProof {
+            proof: self.clone(),
+            signature: key.sign_message(&self.signing_message()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::base64_decode;
+    #[cfg(feature = "sign")]
+    use super::{base64_encode, SigningKey};
+    use crate::{AddressKind, AuthAddress, SignatureError, SignedProof, UserData};
+
+    // Bitcoin Core's signmessage test key, whose compressed address is ADDRESS and
+    // uncompressed address is UNCOMPRESSED_ADDRESS
+    #[cfg(feature = "sign")]
+    const SECRET: [u8; 32] = [
+        0xd2, 0xb8, 0xa0, 0x11, 0x6d, 0x64, 0x1f, 0xe7, 0xd3, 0x03, 0x6f, 0x84, 0x64, 0x62, 0x8f,
+        0xb5, 0x95, 0xb4, 0x80, 0x41, 0x4c, 0x13, 0xa3, 0x01, 0xb3, 0xd4, 0x03, 0x8c, 0x81, 0x1c,
+        0x28, 0xb0,
+    ];
+    const ADDRESS: &str = "19pTScE8LZfwRNasdjXrgFWkVqMRcU99GK";
+    const UNCOMPRESSED_ADDRESS: &str = "1CnXtvgj7uMsdiJasfWCVZ9i5kZLYnmWZi";
+    const MESSAGE: &str = "This is just a test message";
+
+    /// A proof for ADDRESS signed with the test key
+    const SIGNED_PROOF: &str = concat!(
+        r#"{"version":1,"algo":"sha256","target_mode":"prefix","difficulty":8,"#,
+        r#""auth_address":"19pTScE8LZfwRNasdjXrgFWkVqMRcU99GK","username":"zeronet_user","#,
+        r#""nonce":31,"hash":"3ef97ad5f95984cff3c3b99c32000bc45677435d52b4fd0fd61af61192ddb955","#,
+        r#""signature":"Hzrd4vVuqF0zuYEZO/uMJKmyqd1IkEXmzMwEdB9Yt+i+PTHRULvKHRgphDXF4UTeAlJbYoFWrHshxM/Li2/1O28="}"#
+    );
+
+    /// The same proof copied to another address and signed with that address' key, [0x7f; 32]
+    const STOLEN_PROOF: &str = concat!(
+        r#"{"version":1,"algo":"sha256","target_mode":"prefix","difficulty":8,"#,
+        r#""auth_address":"114zeswED9T4khX92nQiXt1beMx5vhisGX","username":"zeronet_user","#,
+        r#""nonce":31,"hash":"3ef97ad5f95984cff3c3b99c32000bc45677435d52b4fd0fd61af61192ddb955","#,
+        r#""signature":"H1fzpBqj7/5X3KzcP0CeTb37ufrfmNdrhWgY/zwnJApgNfqlCtBQ4PSLu7CImt7gHOxNmT1iYU4DxU6s/RSyR5Y="}"#
+    );
+
+    #[test]
+    fn test_base64() {
+        for (bytes, encoded) in [
+            (&b""[..], ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foob", "Zm9vYg=="),
+        ] {
+            #[cfg(feature = "sign")]
+            assert_eq!(encoded, base64_encode(bytes));
+            assert_eq!(Some(bytes.to_vec()), base64_decode(encoded));
+        }
+
+        for encoded in ["Zg=", "Zh==", "Zg==Zg==", "Z===", "Zm9v!"] {
+            assert_eq!(None, base64_decode(encoded), "{encoded}");
+        }
+    }
+
+    #[test]
+    fn test_verify_message() {
+        let signature =
+            "INbVnW4e6PeRmsv2Qgu8NuopvrVjkcxob+sX8OcZG0SALhWybUjzMLPdAsXI46YZGb0KQTRii+wWIQzRpG/U+S0=";
+
+        let address = AuthAddress::parse(ADDRESS).unwrap();
+        assert_eq!(Ok(()), address.verify_message(MESSAGE, signature));
+        assert_eq!(
+            Err(SignatureError::AddressMismatch),
+            address.verify_message("This is just a test message!", signature)
+        );
+        assert_eq!(
+            Err(SignatureError::Malformed),
+            address.verify_message(MESSAGE, &signature[4..])
+        );
+
+        let segwit_address = AuthAddress::new(AddressKind::P2wpkh, address.hash160());
+        assert_eq!(Ok(()), segwit_address.verify_message(MESSAGE, signature));
+
+        // The same signature with the header of an uncompressed key
+        let uncompressed_signature =
+            "HNbVnW4e6PeRmsv2Qgu8NuopvrVjkcxob+sX8OcZG0SALhWybUjzMLPdAsXI46YZGb0KQTRii+wWIQzRpG/U+S0=";
+        let uncompressed_address = AuthAddress::parse(UNCOMPRESSED_ADDRESS).unwrap();
+        assert_eq!(
+            Ok(()),
+            uncompressed_address.verify_message(MESSAGE, uncompressed_signature)
+        );
+        assert_eq!(
+            Err(SignatureError::AddressMismatch),
+            address.verify_message(MESSAGE, uncompressed_signature)
+        );
+    }
+
+    #[test]
+    fn test_signed_proof() {
+        let signed_proof = SignedProof::from_json(SIGNED_PROOF).unwrap();
+        assert_eq!(Ok(()), signed_proof.verify());
+        assert_eq!(SIGNED_PROOF, signed_proof.to_json());
+        assert!(signed_proof
+            .proof
+            .signing_message()
+            .starts_with("anonid-proof:{\"version\":1,"));
+        assert!(SignedProof::from_json(&signed_proof.proof.to_json()).is_err());
+
+        // A copied proof claimed for another address
+        let mut stolen = signed_proof.clone();
+        stolen.proof.userdata = UserData::new(
+            "zeronet_user".to_string(),
+            "114zeswED9T4khX92nQiXt1beMx5vhisGX".to_string(),
+        );
+        assert_eq!(Err(SignatureError::AddressMismatch), stolen.verify());
+        let stolen = SignedProof::from_json(STOLEN_PROOF).unwrap();
+        assert_eq!(Err(SignatureError::InvalidProof), stolen.verify());
+
+        let mut tampered = signed_proof;
+        tampered.proof.nonce += 1;
+        assert_eq!(Err(SignatureError::AddressMismatch), tampered.verify());
+    }
+
+    #[cfg(feature = "sign")]
+    #[test]
+    fn test_sign() {
+        // Bitcoin Core's signmessage vectors, RFC 6979 nonces make the signatures reproducible
+        let key = SigningKey::from_bytes(SECRET, true).unwrap();
+        assert_eq!(ADDRESS, key.auth_address().to_string());
+        assert_eq!(
+            "INbVnW4e6PeRmsv2Qgu8NuopvrVjkcxob+sX8OcZG0SALhWybUjzMLPdAsXI46YZGb0KQTRii+wWIQzRpG/U+S0=",
+            key.sign_message(MESSAGE)
+        );
+        let uncompressed_key = SigningKey::from_bytes(SECRET, false).unwrap();
+        assert_eq!(
+            UNCOMPRESSED_ADDRESS,
+            uncompressed_key.auth_address().to_string()
+        );
+        assert_eq!(
+            "HNbVnW4e6PeRmsv2Qgu8NuopvrVjkcxob+sX8OcZG0SALhWybUjzMLPdAsXI46YZGb0KQTRii+wWIQzRpG/U+S0=",
+            uncompressed_key.sign_message(MESSAGE)
+        );
+
+        let signed_proof = SignedProof::from_json(SIGNED_PROOF).unwrap();
+        assert_eq!(Ok(signed_proof.clone()), signed_proof.proof.sign(&key));
+
+        let other_key = SigningKey::from_bytes([0x7f; 32], true).unwrap();
+        assert_eq!(
+            Err(SignatureError::AddressMismatch),
+            signed_proof.proof.sign(&other_key).map(|_| ())
+        );
+        let mut stolen = signed_proof.proof;
+        stolen.userdata = UserData::new(
+            "zeronet_user".to_string(),
+            other_key.auth_address().to_string(),
+        );
+        assert_eq!(
+            Ok(SignedProof::from_json(STOLEN_PROOF).unwrap()),
+            stolen.sign(&other_key)
+        );
+    }
+
+    #[cfg(feature = "sign")]
+    #[test]
+    fn test_signing_key_wif() {
+        for (wif, address) in [
+            (
+                "5HpHagT65TZzG1PH3CSu63k8DbpvD8s5ip4nEB3kEsreAnchuDf",
+                "1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm",
+            ),
+            (
+                "KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn",
+                "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH",
+            ),
+        ] {
+            let key = SigningKey::from_wif(wif).unwrap();
+            assert_eq!(address, key.auth_address().to_string());
+        }
+
+        for wif in [
+            "",
+            "5HpHagT65TZzG1PH3CSu63k8DbpvD8s5ip4nEB3kEsreAnchuDg",
+            "cUeKHd5orzT3mz8P9pxyREHfsWtVfgsfDjiZZBcjUBAaGk1BTj7N",
+            "1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm",
+        ] {
+            assert!(SigningKey::from_wif(wif).is_err(), "{wif}");
+        }
+        assert!(SigningKey::from_bytes([0; 32], true).is_err());
+    }
+}
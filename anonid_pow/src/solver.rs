@@ -0,0 +1,482 @@
+use crate::{
+    to_hex, AnonIdPowError, DifficultyCurve, DifficultyPolicy, LengthMetric, PoW, PoWAlgo, PoWHash,
+    TargetMode, UserData,
+};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Time aimed for between two checks of the cancellation token and the budget
+const CHECK_PERIOD: Duration = Duration::from_millis(10);
+/// Most nonces tried between two checks, so fast algorithms don't read the clock per hash
+const MAX_CHECK_INTERVAL: u64 = 1024;
+
+/// Shared flag used to stop a running PoW search from another thread
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Initializes a token that is not cancelled yet
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    /// Requests every search holding a clone of this token to stop
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if `cancel` has been called on this token or one of its clones
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Contains the limits and the reporting interval of a PoW search
+#[derive(Clone)]
+pub struct SolveOptions {
+    /// Token that stops the search when cancelled
+    pub cancellation: Option<CancellationToken>,
+    /// Point in time after which the search gives up
+    pub deadline: Option<Instant>,
+    /// Maximum number of nonces to try
+    pub max_attempts: Option<u64>,
+    /// Minimum time between two progress reports
+    pub progress_interval: Duration,
+    /// First nonce to try, used to resume a search from a checkpoint
+    pub start_nonce: usize,
+}
+
+impl Default for SolveOptions {
+    fn default() -> SolveOptions {
+        SolveOptions {
+            cancellation: None,
+            deadline: None,
+            max_attempts: None,
+            progress_interval: Duration::from_secs(1),
+            start_nonce: 0,
+        }
+    }
+}
+
+/// Progress of a running PoW search
+#[derive(Clone, Copy, Debug)]
+pub struct Progress {
+    /// Number of nonces tried so far
+    pub attempts: u64,
+    /// Time spent searching so far
+    pub elapsed: Duration,
+    /// Average number of hashes per second since the search started
+    pub hash_rate: f64,
+    /// Next nonce that will be tried, every nonce before it has already been tried
+    pub next_nonce: usize,
+}
+
+impl Progress {
+    fn new(attempts: u64, next_nonce: usize, started: Instant) -> Progress {
+        let elapsed = started.elapsed();
+        let seconds = elapsed.as_secs_f64();
+        let hash_rate = if seconds > 0.0 {
+            attempts as f64 / seconds
+        } else {
+            0.0
+        };
+
+        Progress {
+            attempts,
+            elapsed,
+            hash_rate,
+            next_nonce,
+        }
+    }
+}
+
+/// Result of a PoW search that can be stopped before finding a solution
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SolveOutcome {
+    /// A valid hash and nonce have been found
+    Found { hash: String, nonce: usize },
+    /// The cancellation token has been cancelled
+    Cancelled { attempts: u64, next_nonce: usize },
+    /// The deadline has passed or the attempt budget has been used up
+    BudgetExhausted { attempts: u64, next_nonce: usize },
+}
+
+/// Saved state of an unfinished PoW search
+///
+/// The string form is "algo:target_mode:difficulty:next_nonce:auth_address:username", the
+/// merged userdata goes last so it can be split off as a whole. A length metric other than
+/// bytes and a difficulty policy other than the legacy curve are written after the target
+/// mode, in that order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Checkpoint {
+    pub userdata: UserData,
+    pub difficulty: usize,
+    pub algo: PoWAlgo,
+    pub target_mode: TargetMode,
+    pub length_metric: LengthMetric,
+    pub difficulty_policy: DifficultyCurve,
+    pub next_nonce: usize,
+}
+
+impl Checkpoint {
+    /// Initializes a Checkpoint struct from a checkpoint string
+    pub fn parse(checkpoint: &str) -> Option<Checkpoint> {
+        let (algo, rest) = checkpoint.split_once(':')?;
+        let algo = PoWAlgo::from_name(algo)?;
+        let (target_mode, rest) = rest.split_once(':')?;
+        let target_mode = TargetMode::from_name(target_mode)?;
+        let (length_metric, rest) = rest
+            .split_once(':')
+            .and_then(|(name, tail)| Some((LengthMetric::from_name(name)?, tail)))
+            .unwrap_or((LengthMetric::Bytes, rest));
+        let (difficulty_policy, rest) = rest
+            .split_once(':')
+            .and_then(|(name, tail)| Some((DifficultyCurve::from_name(name)?, tail)))
+            .unwrap_or((DifficultyCurve::Legacy, rest));
+
+        let mut fields = rest.splitn(3, ':');
+        let difficulty = fields.next()?.parse().ok()?;
+        let next_nonce = fields.next()?.parse().ok()?;
+        let userdata = UserData::from_merged(fields.next()?.to_string())?;
+
+        Some(Checkpoint {
+            userdata,
+            difficulty,
+            algo,
+            target_mode,
+            length_metric,
+            difficulty_policy,
+            next_nonce,
+        })
+    }
+
+    /// Returns the PoW the checkpoint was taken from
+    pub fn pow(&self) -> Result<PoW, AnonIdPowError> {
+        let pow = PoW::new(self.userdata.clone(), self.difficulty, self.algo.clone())?;
+
+        Ok(pow
+            .with_target_mode(self.target_mode)
+            .with_length_metric(self.length_metric)
+            .with_difficulty_policy(self.difficulty_policy.clone()))
+    }
+
+    /// Continues the search from the checkpoint's next nonce
+    pub fn resume<F>(
+        &self,
+        options: &SolveOptions,
+        progress: F,
+    ) -> Result<SolveOutcome, AnonIdPowError>
+    where
+        F: FnMut(&Progress),
+    {
+        let options = SolveOptions {
+            start_nonce: self.next_nonce,
+            ..options.clone()
+        };
+
+        Ok(self.pow()?.solve(&options, progress))
+    }
+}
+
+impl fmt::Display for Checkpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}:", self.algo, self.target_mode)?;
+        if self.length_metric != LengthMetric::Bytes {
+            write!(f, "{}:", self.length_metric)?;
+        }
+        if self.difficulty_policy != DifficultyCurve::Legacy {
+            write!(f, "{}:", self.difficulty_policy)?;
+        }
+        write!(
+            f,
+            "{}:{}:{}",
+            self.difficulty,
+            self.next_nonce,
+            self.userdata.merge()
+        )
+    }
+}
+
+impl<A: PoWHash, D: DifficultyPolicy> PoW<A, D> {
+    /// Calculates the PoW until a solution is found, the search is cancelled, or the budget
+    /// in `options` runs out
+    ///
+    /// `progress` is called at most once per `options.progress_interval` with the number of
+    /// nonces tried and the current hash rate. Its `next_nonce`, or the one carried by an
+    /// unsuccessful outcome, can be turned into a `Checkpoint` with `PoW::checkpoint` to
+    /// resume the search later.
+    pub fn solve<F>(&self, options: &SolveOptions, mut progress: F) -> SolveOutcome
+    where
+        F: FnMut(&Progress),
+    {
+        let state = self.algo.prepare(self.userdata.merge().as_bytes());
+        let target = self.target();
+        let mut digest = vec![0; self.algo.output_len()];
+
+        let started = Instant::now();
+        let mut last_report = started;
+        let mut last_check = started;
+
+        // The interval between two checks adapts to the cost of a hash, so memory-hard
+        // algorithms still stop within about `CHECK_PERIOD`
+        let mut check_interval: u64 = 1;
+        let mut next_check: u64 = 0;
+
+        let mut attempts: u64 = 0;
+        let mut nonce = options.start_nonce;
+        loop {
+            if attempts == next_check {
+                if options
+                    .cancellation
+                    .as_ref()
+                    .is_some_and(CancellationToken::is_cancelled)
+                {
+                    return SolveOutcome::Cancelled {
+                        attempts,
+                        next_nonce: nonce,
+                    };
+                }
+
+                if options
+                    .deadline
+                    .is_some_and(|deadline| Instant::now() >= deadline)
+                {
+                    return SolveOutcome::BudgetExhausted {
+                        attempts,
+                        next_nonce: nonce,
+                    };
+                }
+
+                if last_report.elapsed() >= options.progress_interval {
+                    progress(&Progress::new(attempts, nonce, started));
+                    last_report = Instant::now();
+                }
+
+                let now = Instant::now();
+                let since_last_check = now - last_check;
+                if since_last_check < CHECK_PERIOD / 2 {
+                    check_interval = (check_interval * 2).min(MAX_CHECK_INTERVAL);
+                } else if since_last_check > CHECK_PERIOD {
+                    check_interval = (check_interval / 2).max(1);
+                }
+                last_check = now;
+                next_check = attempts + check_interval;
+            }
+
+            if options.max_attempts.is_some_and(|max| attempts >= max) {
+                return SolveOutcome::BudgetExhausted {
+                    attempts,
+                    next_nonce: nonce,
+                };
+            }
+
+            self.algo.hash(&state, nonce, &mut digest);
+            attempts += 1;
+
+            if target.is_met(&digest) {
+                return SolveOutcome::Found {
+                    hash: to_hex(&digest),
+                    nonce,
+                };
+            }
+
+            nonce = match nonce.checked_add(1) {
+                Some(next_nonce) => next_nonce,
+                None => {
+                    return SolveOutcome::BudgetExhausted {
+                        attempts,
+                        next_nonce: nonce,
+                    }
+                }
+            };
+        }
+    }
+}
+
+impl PoW {
+    /// Saves the state of a search that has tried every nonce before `next_nonce`
+    pub fn checkpoint(&self, next_nonce: usize) -> Checkpoint {
+        Checkpoint {
+            userdata: self.userdata.clone(),
+            difficulty: self.difficulty,
+            algo: self.algo.clone(),
+            target_mode: self.target_mode,
+            length_metric: self.length_metric,
+            difficulty_policy: self.difficulty_policy.clone(),
+            next_nonce,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CancellationToken, Checkpoint, SolveOptions, SolveOutcome};
+    use crate::{DifficultyCurve, LengthMetric, PoW, PoWAlgo, PoWHash, UserData};
+    use std::time::{Duration, Instant};
+
+    fn test_pow(difficulty: usize) -> PoW {
+        let userdata =
+            UserData::from_merged("1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user".to_string())
+                .unwrap();
+        PoW::new(userdata, difficulty, PoWAlgo::Sha256).unwrap()
+    }
+
+    #[test]
+    fn test_solve_found() {
+        let pow = test_pow(16);
+        let (hash, nonce) = pow.calculate_pow().unwrap();
+
+        let outcome = pow.solve(&SolveOptions::default(), |_| {});
+
+        assert_eq!(SolveOutcome::Found { hash, nonce }, outcome);
+    }
+
+    #[test]
+    fn test_solve_cancelled() {
+        let pow = test_pow(48);
+
+        let cancellation = CancellationToken::new();
+        cancellation.cancel();
+
+        let options = SolveOptions {
+            cancellation: Some(cancellation),
+            ..SolveOptions::default()
+        };
+
+        assert_eq!(
+            SolveOutcome::Cancelled {
+                attempts: 0,
+                next_nonce: 0
+            },
+            pow.solve(&options, |_| {})
+        );
+    }
+
+    #[test]
+    fn test_solve_budget_exhausted() {
+        let pow = test_pow(48);
+
+        let options = SolveOptions {
+            max_attempts: Some(5000),
+            ..SolveOptions::default()
+        };
+        assert_eq!(
+            SolveOutcome::BudgetExhausted {
+                attempts: 5000,
+                next_nonce: 5000
+            },
+            pow.solve(&options, |_| {})
+        );
+
+        let options = SolveOptions {
+            deadline: Some(Instant::now() + Duration::from_millis(50)),
+            progress_interval: Duration::ZERO,
+            ..SolveOptions::default()
+        };
+        let mut reports = 0;
+        let outcome = pow.solve(&options, |progress| {
+            assert!(progress.hash_rate >= 0.0);
+            reports += 1;
+        });
+
+        assert!(matches!(outcome, SolveOutcome::BudgetExhausted { .. }));
+        assert!(reports > 0);
+    }
+
+    /// Takes 5 ms per hash and never meets a hex prefix target
+    struct SlowAlgo;
+
+    impl PoWHash for SlowAlgo {
+        type State = ();
+
+        fn algo_id(&self) -> String {
+            "slow".to_string()
+        }
+
+        fn output_len(&self) -> usize {
+            32
+        }
+
+        fn prepare(&self, _: &[u8]) {}
+
+        fn hash(&self, _: &(), _: usize, out: &mut [u8]) {
+            std::thread::sleep(Duration::from_millis(5));
+            out.fill(0);
+        }
+    }
+
+    #[test]
+    fn test_solve_slow_algo_deadline() {
+        let userdata = UserData::new("zeronet_user".to_string(), "1FBbx".to_string());
+        let pow = PoW::new(userdata, 8, SlowAlgo).unwrap();
+
+        let started = Instant::now();
+        let options = SolveOptions {
+            deadline: Some(started + Duration::from_millis(50)),
+            ..SolveOptions::default()
+        };
+        let outcome = pow.solve(&options, |_| {});
+
+        assert!(matches!(outcome, SolveOutcome::BudgetExhausted { .. }));
+        // Checking every 1024 attempts would take more than 5 seconds
+        assert!(started.elapsed() < Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_checkpoint_resume() {
+        let pow = test_pow(16);
+        let (hash, nonce) = pow.calculate_pow().unwrap();
+
+        let options = SolveOptions {
+            max_attempts: Some(nonce as u64 / 2),
+            ..SolveOptions::default()
+        };
+        let next_nonce = match pow.solve(&options, |_| {}) {
+            SolveOutcome::BudgetExhausted { next_nonce, .. } => next_nonce,
+            outcome => panic!("unexpected outcome {outcome:?}"),
+        };
+
+        let checkpoint = pow.checkpoint(next_nonce).to_string();
+        assert_eq!(
+            format!(
+                "sha256:prefix:16:{next_nonce}:1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user"
+            ),
+            checkpoint
+        );
+
+        let checkpoint = Checkpoint::parse(&checkpoint).unwrap();
+        assert_eq!(pow.checkpoint(next_nonce), checkpoint);
+
+        let outcome = checkpoint.resume(&SolveOptions::default(), |_| {}).unwrap();
+        assert_eq!(SolveOutcome::Found { hash, nonce }, outcome);
+
+        let graphemes = pow.clone().with_length_metric(LengthMetric::Graphemes);
+        let checkpoint = graphemes.checkpoint(0).to_string();
+        assert_eq!(
+            "sha256:prefix:graphemes:16:0:1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user",
+            checkpoint
+        );
+        assert_eq!(
+            Some(graphemes.checkpoint(0)),
+            Checkpoint::parse(&checkpoint)
+        );
+
+        let linear = graphemes.with_difficulty_policy(DifficultyCurve::Linear { step: 2, min: 8 });
+        let checkpoint = linear.checkpoint(0).to_string();
+        assert_eq!(
+            "sha256:prefix:graphemes:linear-2-8:16:0:1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user",
+            checkpoint
+        );
+        assert_eq!(Some(linear.checkpoint(0)), Checkpoint::parse(&checkpoint));
+
+        assert!(Checkpoint::parse("sha256:prefix:16:1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX").is_none());
+        assert!(Checkpoint::parse("sha256:16:0:1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:user").is_none());
+        assert!(
+            Checkpoint::parse("md5:prefix:16:0:1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:user").is_none()
+        );
+    }
+}
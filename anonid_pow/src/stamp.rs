@@ -0,0 +1,259 @@
+use crate::{
+    DifficultyCurve, LengthMetric, MergeFormat, PoWAlgo, Proof, ProofError, TargetMode, UserData,
+    PROOF_VERSION,
+};
+
+/// Version of the stamp format written by `Proof::to_stamp`
+pub const STAMP_VERSION: u64 = 1;
+
+const STAMP_PREFIX: &str = "anonid";
+
+/// Parses a decimal number without sign or leading zeros
+fn parse_decimal<T: std::str::FromStr>(
+    field: Option<&str>,
+    name: &'static str,
+) -> Result<T, ProofError> {
+    let field = field.ok_or(ProofError::MissingField(name))?;
+
+    if field.is_empty()
+        || !field.bytes().all(|byte| byte.is_ascii_digit())
+        || (field.len() > 1 && field.starts_with('0'))
+    {
+        return Err(ProofError::InvalidField(name));
+    }
+
+    field.parse().map_err(|_| ProofError::InvalidField(name))
+}
+
+impl Proof {
+    /// Encodes the proof as a single line stamp
+    ///
+    /// "anonid:1:algo:difficulty:auth_address:username:nonce"
+    ///
+    /// The hash is left out since it can be recalculated from the other fields, the userdata
+    /// is written by `UserData::merge`. Stamps always use the hex prefix target, count the
+    /// username's length in bytes, and adjust the difficulty with the legacy curve, None is
+    /// returned for other proofs and for legacy userdata whose username contains a ':'.
+    pub fn to_stamp(&self) -> Option<String> {
+        if self.target_mode != TargetMode::HexPrefix
+            || self.length_metric != LengthMetric::Bytes
+            || self.difficulty_policy != DifficultyCurve::Legacy
+            || (self.userdata.merge_format() == MergeFormat::Legacy
+                && self.userdata.username().contains(':'))
+        {
+            return None;
+        }
+
+        Some(format!(
+            "{STAMP_PREFIX}:{STAMP_VERSION}:{}:{}:{}:{}",
+            self.algo,
+            self.difficulty,
+            self.userdata.merge(),
+            self.nonce
+        ))
+    }
+
+    /// Initializes a Proof struct from a stamp
+    ///
+    /// The parser is strict, numbers can't have signs or leading zeros and legacy usernames
+    /// can't contain a ':'. The hash is left empty, so parsing never runs the PoW algorithm,
+    /// `verify` recalculates it when the stamp is checked.
+    pub fn from_stamp(stamp: &str) -> Result<Proof, ProofError> {
+        let mut fields = stamp.splitn(5, ':');
+
+        if fields.next() != Some(STAMP_PREFIX) {
+            return Err(ProofError::Malformed);
+        }
+
+        let version = parse_decimal(fields.next(), "version")?;
+        if version != STAMP_VERSION {
+            return Err(ProofError::UnsupportedVersion(version));
+        }
+
+        let algo = fields.next().ok_or(ProofError::MissingField("algo"))?;
+        let algo = PoWAlgo::from_name(algo)
+            .filter(|algo| algo.validate().is_ok())
+            .ok_or(ProofError::InvalidField("algo"))?;
+
+        let difficulty = parse_decimal(fields.next(), "difficulty")?;
+
+        let (merged_userdata, nonce) = fields
+            .next()
+            .ok_or(ProofError::MissingField("auth_address"))?
+            .rsplit_once(':')
+            .ok_or(ProofError::MissingField("nonce"))?;
+        let nonce = parse_decimal(Some(nonce), "nonce")?;
+
+        let userdata = match UserData::from_merged(merged_userdata.to_string()) {
+            Some(userdata) if userdata.merge() == merged_userdata => userdata,
+            None if !merged_userdata.contains(':') => {
+                return Err(ProofError::MissingField("username"))
+            }
+            _ => return Err(ProofError::InvalidField("username")),
+        };
+
+        Ok(Proof {
+            version: PROOF_VERSION,
+            hash: String::new(),
+            userdata,
+            difficulty,
+            algo,
+            target_mode: TargetMode::HexPrefix,
+            length_metric: LengthMetric::Bytes,
+            difficulty_policy: DifficultyCurve::Legacy,
+            nonce,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        DifficultyCurve, LengthMetric, PoW, PoWAlgo, Proof, ProofError, TargetMode, UserData,
+    };
+
+    fn userdata() -> UserData {
+        UserData::from_merged("1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user".to_string())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_stamp() {
+        let proof = PoW::new(userdata(), 24, PoWAlgo::Sha256).unwrap().proof((
+            "ffffff419e9de8f5a3b958da92eb19ed8b6cc6da591de7fec0a2e7250c804047".to_string(),
+            6589658,
+        ));
+
+        let stamp = proof.to_stamp().unwrap();
+        assert_eq!(
+            "anonid:1:sha256:24:1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user:6589658",
+            stamp
+        );
+
+        let parsed = Proof::from_stamp(&stamp).unwrap();
+        assert_eq!(
+            Proof {
+                hash: String::new(),
+                ..proof
+            },
+            parsed
+        );
+        assert!(parsed.verify());
+
+        let forged = Proof::from_stamp(&stamp.replace("6589658", "6589659")).unwrap();
+        assert!(!forged.verify());
+
+        let userdata = UserData::new("zero:net".to_string(), "1FBbx".to_string())
+            .with_namespace("zeronet".to_string());
+        let proof = PoW::new(userdata, 8, PoWAlgo::Sha256)
+            .unwrap()
+            .prove()
+            .unwrap();
+        let stamp = proof.to_stamp().unwrap();
+        assert_eq!(
+            format!(
+                "anonid:1:sha256:8:v1:5:1FBbx,8:zero:net,7:zeronet,:{}",
+                proof.nonce
+            ),
+            stamp
+        );
+        assert_eq!(
+            Ok(Proof {
+                hash: String::new(),
+                ..proof
+            }),
+            Proof::from_stamp(&stamp)
+        );
+    }
+
+    #[test]
+    fn test_stamp_unsupported_proof() {
+        let mut proof = PoW::new(userdata(), 8, PoWAlgo::Sha256)
+            .unwrap()
+            .with_target_mode(TargetMode::LeadingZeroBits)
+            .prove()
+            .unwrap();
+        assert_eq!(None, proof.to_stamp());
+
+        proof.target_mode = TargetMode::HexPrefix;
+        proof.length_metric = LengthMetric::Scalars;
+        assert_eq!(None, proof.to_stamp());
+
+        proof.length_metric = LengthMetric::Bytes;
+        proof.difficulty_policy = DifficultyCurve::Linear { step: 1, min: 1 };
+        assert_eq!(None, proof.to_stamp());
+
+        proof.difficulty_policy = DifficultyCurve::Legacy;
+        proof.userdata = UserData::new("zero:net".to_string(), "1FBbx".to_string());
+        assert_eq!(None, proof.to_stamp());
+    }
+
+    #[test]
+    fn test_stamp_invalid() {
+        for (stamp, error) in [
+            ("", ProofError::Malformed),
+            (
+                "hashcash:1:sha256:24:1FBbx:zeronet_user:0",
+                ProofError::Malformed,
+            ),
+            ("anonid", ProofError::MissingField("version")),
+            (
+                "anonid:2:sha256:24:1FBbx:zeronet_user:0",
+                ProofError::UnsupportedVersion(2),
+            ),
+            (
+                "anonid:01:sha256:24:1FBbx:zeronet_user:0",
+                ProofError::InvalidField("version"),
+            ),
+            ("anonid:1", ProofError::MissingField("algo")),
+            (
+                "anonid:1:md5:24:1FBbx:zeronet_user:0",
+                ProofError::InvalidField("algo"),
+            ),
+            (
+                "anonid:1:argon2id,m=1,t=1,p=1:24:1FBbx:zeronet_user:0",
+                ProofError::InvalidField("algo"),
+            ),
+            // Above MAX_ARGON2ID_MEMORY_KIB, rejected before anything is allocated
+            (
+                "anonid:1:argon2id,m=4000000000,t=1,p=1:24:1FBbx:bob:0",
+                ProofError::InvalidField("algo"),
+            ),
+            (
+                "anonid:1:argon2id,m=64,t=4000000000,p=1:24:1FBbx:bob:0",
+                ProofError::InvalidField("algo"),
+            ),
+            ("anonid:1:sha256", ProofError::MissingField("difficulty")),
+            (
+                "anonid:1:sha256:+24:1FBbx:zeronet_user:0",
+                ProofError::InvalidField("difficulty"),
+            ),
+            (
+                "anonid:1:sha256:24",
+                ProofError::MissingField("auth_address"),
+            ),
+            (
+                "anonid:1:sha256:24:1FBbx",
+                ProofError::MissingField("nonce"),
+            ),
+            (
+                "anonid:1:sha256:24:1FBbx:0",
+                ProofError::MissingField("username"),
+            ),
+            (
+                "anonid:1:sha256:24:1FBbx:zeronet_user:",
+                ProofError::InvalidField("nonce"),
+            ),
+            (
+                "anonid:1:sha256:24:1FBbx:zeronet_user:-1",
+                ProofError::InvalidField("nonce"),
+            ),
+            (
+                "anonid:1:sha256:24:1FBbx:zero:net:0",
+                ProofError::InvalidField("username"),
+            ),
+        ] {
+            assert_eq!(Err(error), Proof::from_stamp(stamp), "{stamp}");
+        }
+    }
+}
@@ -0,0 +1,457 @@
+use std::fmt;
+
+/// Contains the way the adjusted difficulty is turned into a target
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TargetMode {
+    /// The hex hash has to start with the hex representation of `2^difficulty - 1`
+    ///
+    /// This is the original AnonID target, the difficulty only has an effect in 4-bit steps.
+    #[default]
+    HexPrefix,
+    /// The raw digest has to start with at least `difficulty` zero bits
+    LeadingZeroBits,
+    /// The raw digest read as a 256-bit big-endian integer has to be at most the target
+    ///
+    /// The target is the one required at the full difficulty, every bit the username's
+    /// length takes off the adjusted difficulty doubles it.
+    Numeric(NumericTarget),
+}
+
+impl TargetMode {
+    /// Initializes a TargetMode from its name
+    pub fn from_name(name: &str) -> Option<TargetMode> {
+        match name {
+            "prefix" => Some(TargetMode::HexPrefix),
+            "zeros" => Some(TargetMode::LeadingZeroBits),
+            _ => {
+                let target = name.strip_prefix("numeric-")?;
+                NumericTarget::from_hex(target).map(TargetMode::Numeric)
+            }
+        }
+    }
+}
+
+impl fmt::Display for TargetMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TargetMode::HexPrefix => write!(f, "prefix"),
+            TargetMode::LeadingZeroBits => write!(f, "zeros"),
+            TargetMode::Numeric(target) => write!(f, "numeric-{target}"),
+        }
+    }
+}
+
+/// 256-bit big-endian target a digest has to be less than or equal to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NumericTarget([u8; 32]);
+
+impl NumericTarget {
+    /// The easiest target, every digest meets it
+    pub const MAX: NumericTarget = NumericTarget([0xff; 32]);
+
+    /// Initializes a NumericTarget from its big-endian bytes
+    pub fn from_bytes(bytes: [u8; 32]) -> NumericTarget {
+        NumericTarget(bytes)
+    }
+
+    /// Returns the big-endian bytes of the target
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+
+    /// Initializes a NumericTarget from 64 hex characters
+    pub fn from_hex(target: &str) -> Option<NumericTarget> {
+        if target.len() != 64 || !target.is_ascii() {
+            return None;
+        }
+
+        let mut bytes = [0; 32];
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&target[index * 2..index * 2 + 2], 16).ok()?;
+        }
+
+        Some(NumericTarget(bytes))
+    }
+
+    /// Calculates `2^(256 - difficulty) - 1`, the target met by digests starting with at
+    /// least `difficulty` zero bits
+    pub fn from_difficulty(difficulty: usize) -> NumericTarget {
+        NumericTarget::MAX.shift_right(difficulty)
+    }
+
+    /// Initializes a NumericTarget from Bitcoin's compact "nBits" representation
+    ///
+    /// The highest byte is the length of the target in bytes and the lower three bytes are
+    /// its most significant bytes. Negative and overflowing targets are rejected.
+    pub fn from_compact(bits: u32) -> Option<NumericTarget> {
+        let size = (bits >> 24) as usize;
+        let mantissa = bits & 0x007f_ffff;
+
+        if bits & 0x0080_0000 != 0 && mantissa != 0 {
+            return None;
+        }
+
+        let mut bytes = [0; 32];
+        for (index, byte) in mantissa.to_be_bytes()[1..].iter().enumerate() {
+            // Position of the byte counted from the least significant end of the target,
+            // bytes that end up below it are shifted out
+            let Some(position) = (size + 2).checked_sub(3 + index) else {
+                continue;
+            };
+
+            match position {
+                0..=31 => bytes[31 - position] = *byte,
+                _ if *byte == 0 => {}
+                _ => return None,
+            }
+        }
+
+        Some(NumericTarget(bytes))
+    }
+
+    /// Encodes the target in Bitcoin's compact "nBits" representation
+    ///
+    /// Only the three most significant bytes are kept, so the result can be slightly lower
+    /// than the target itself.
+    pub fn to_compact(&self) -> u32 {
+        let mut size = 32 - self.0.iter().take_while(|byte| **byte == 0).count();
+
+        let mut mantissa = self.0[32 - size..]
+            .iter()
+            .take(3)
+            .fold(0u32, |mantissa, byte| mantissa << 8 | *byte as u32);
+        if size < 3 {
+            mantissa <<= 8 * (3 - size) as u32;
+        }
+
+        if mantissa & 0x0080_0000 != 0 {
+            mantissa >>= 8;
+            size += 1;
+        }
+
+        (size as u32) << 24 | mantissa
+    }
+
+    /// Returns the difficulty in bits, `256 - log2(target + 1)`, which can be fractional
+    pub fn difficulty(&self) -> f64 {
+        let leading_zero_bytes = self.0.iter().take_while(|byte| **byte == 0).count();
+
+        let significant = self.0[leading_zero_bytes..]
+            .iter()
+            .take(8)
+            .fold(0.0, |value, byte| value * 256.0 + *byte as f64);
+        let significant_bits = 8 * (32 - leading_zero_bytes).min(8);
+        let remaining_bits = 8 * (32 - leading_zero_bytes) - significant_bits;
+
+        let log2 = (significant + 1.0).log2() + remaining_bits as f64;
+
+        (256.0 - log2).max(0.0)
+    }
+
+    /// Returns true if the big-endian digest is less than or equal to the target
+    ///
+    /// Digests of other lengths than 32 bytes are compared as fractions of their range, so
+    /// they meet the target with about the same probability. Longer digests are compared by
+    /// their first 32 bytes, shorter ones as if they were padded with zeros.
+    pub fn is_met(&self, digest: &[u8]) -> bool {
+        let compared = digest.len().min(32);
+
+        digest[..compared] <= self.0[..compared]
+    }
+
+    fn shift_right(&self, bits: usize) -> NumericTarget {
+        if bits >= 256 {
+            return NumericTarget([0; 32]);
+        }
+
+        let (bytes, bits) = (bits / 8, bits % 8);
+        let mut shifted = [0; 32];
+        for (index, shifted_byte) in shifted.iter_mut().enumerate().skip(bytes) {
+            let byte = self.0[index - bytes];
+            let carry = if index > bytes && bits != 0 {
+                self.0[index - bytes - 1] << (8 - bits)
+            } else {
+                0
+            };
+
+            *shifted_byte = byte >> bits | carry;
+        }
+
+        NumericTarget(shifted)
+    }
+
+    /// Multiplies the success probability `(target + 1) / 2^256` by `2^bits`, saturating at
+    /// `NumericTarget::MAX`
+    fn ease(&self, bits: usize) -> NumericTarget {
+        if bits == 0 {
+            return *self;
+        }
+
+        let leading_zero_bits = leading_zero_bits(&self.0);
+        if bits > leading_zero_bits {
+            return NumericTarget::MAX;
+        }
+
+        let (bytes, bits) = (bits / 8, bits % 8);
+        let mut shifted = [0; 32];
+        for (index, shifted_byte) in shifted.iter_mut().enumerate().take(32 - bytes) {
+            let byte = self.0[index + bytes];
+            let carry = if index + bytes < 31 && bits != 0 {
+                self.0[index + bytes + 1] >> (8 - bits)
+            } else {
+                0
+            };
+
+            *shifted_byte = byte << bits | carry;
+        }
+
+        // The bits shifted in from the right are filled with ones, which is the `- 1` of
+        // `(target + 1) * 2^bits - 1`
+        let mut eased = NumericTarget(shifted);
+        let fill = bytes * 8 + bits;
+        for bit in 0..fill {
+            eased.0[31 - bit / 8] |= 1 << (bit % 8);
+        }
+
+        eased
+    }
+}
+
+impl fmt::Display for NumericTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Condition a raw digest has to meet, checked without hex-formatting the digest
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Target {
+    /// Hex prefix kept as nibbles
+    HexPrefix(Vec<u8>),
+    /// Number of leading zero bits
+    LeadingZeroBits(usize),
+    /// Highest accepted digest
+    Numeric(NumericTarget),
+}
+
+impl Target {
+    /// Calculates the target for the difficulty and the adjusted difficulty
+    pub(crate) fn new(
+        target_mode: TargetMode,
+        difficulty: usize,
+        adjusted_difficulty: usize,
+    ) -> Target {
+        match target_mode {
+            TargetMode::HexPrefix => Self::hex_prefix(adjusted_difficulty),
+            TargetMode::LeadingZeroBits => Target::LeadingZeroBits(adjusted_difficulty),
+            TargetMode::Numeric(target) => {
+                Target::Numeric(target.ease(difficulty.saturating_sub(adjusted_difficulty)))
+            }
+        }
+    }
+
+    /// The prefix is the hex representation of `2^difficulty - 1`, so it is a single nibble
+    /// holding the `difficulty % 4` low bits followed by `difficulty / 4` "f" nibbles.
+    fn hex_prefix(adjusted_difficulty: usize) -> Target {
+        let mut nibbles = Vec::with_capacity(adjusted_difficulty / 4 + 1);
+
+        let leading_bits = adjusted_difficulty % 4;
+        if leading_bits != 0 || adjusted_difficulty == 0 {
+            nibbles.push((1 << leading_bits) - 1);
+        }
+        nibbles.resize(nibbles.len() + adjusted_difficulty / 4, 0xf);
+
+        Target::HexPrefix(nibbles)
+    }
+
+    /// Returns the probability that a uniformly random digest of `digest_len` bytes meets
+    /// the target
+    pub(crate) fn probability(&self, digest_len: usize) -> f64 {
+        match self {
+            Target::HexPrefix(nibbles) if nibbles.len() <= digest_len * 2 => {
+                (-4.0 * nibbles.len() as f64).exp2()
+            }
+            Target::LeadingZeroBits(bits) if *bits <= digest_len * 8 => (-(*bits as f64)).exp2(),
+            // (target + 1) / 2^256, shorter digests only reach the target's first bytes
+            Target::Numeric(target) => target.0[..digest_len.min(32)]
+                .iter()
+                .rev()
+                .fold(1.0, |probability, byte| {
+                    (probability + *byte as f64) / 256.0
+                }),
+            _ => 0.0,
+        }
+    }
+
+    /// Returns true if the raw digest meets the target
+    pub(crate) fn is_met(&self, digest: &[u8]) -> bool {
+        match self {
+            Target::HexPrefix(nibbles) => {
+                if nibbles.len() > digest.len() * 2 {
+                    return false;
+                }
+
+                nibbles.iter().enumerate().all(|(index, nibble)| {
+                    let byte = digest[index / 2];
+                    let digest_nibble = if index % 2 == 0 {
+                        byte >> 4
+                    } else {
+                        byte & 0xf
+                    };
+
+                    digest_nibble == *nibble
+                })
+            }
+            Target::LeadingZeroBits(bits) => leading_zero_bits(digest) >= *bits,
+            Target::Numeric(target) => target.is_met(digest),
+        }
+    }
+}
+
+/// Counts the zero bits at the start of a big-endian digest
+fn leading_zero_bits(digest: &[u8]) -> usize {
+    let mut bits = 0;
+    for byte in digest {
+        bits += byte.leading_zeros() as usize;
+
+        if *byte != 0 {
+            break;
+        }
+    }
+    bits
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Target::HexPrefix(nibbles) => {
+                for nibble in nibbles {
+                    write!(f, "{nibble:x}")?;
+                }
+                Ok(())
+            }
+            Target::LeadingZeroBits(bits) => write!(f, "{bits} leading zero bits"),
+            Target::Numeric(target) => write!(f, "<= {target}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NumericTarget, Target, TargetMode};
+
+    #[test]
+    fn test_target_from_difficulty() {
+        let target =
+            |difficulty| Target::new(TargetMode::HexPrefix, difficulty, difficulty).to_string();
+
+        assert_eq!("0", target(0));
+        for difficulty in 1..64 {
+            let expected = format!("{:x}", u64::MAX >> (64 - difficulty));
+
+            assert_eq!(expected, target(difficulty));
+        }
+        assert_eq!("f".repeat(64), target(256));
+    }
+
+    #[test]
+    fn test_target_is_met() {
+        let target = Target::new(TargetMode::HexPrefix, 6, 6);
+
+        assert!(target.is_met(&[0x3f, 0x00]));
+        assert!(target.is_met(&[0x3f]));
+        assert!(!target.is_met(&[0x3e, 0xff]));
+        assert!(!target.is_met(&[0x7f, 0xff]));
+        assert!(!Target::new(TargetMode::HexPrefix, 12, 12).is_met(&[0xff]));
+    }
+
+    #[test]
+    fn test_target_leading_zero_bits() {
+        let target = |difficulty| Target::new(TargetMode::LeadingZeroBits, difficulty, difficulty);
+
+        assert!(target(0).is_met(&[0xff, 0xff]));
+        assert!(target(9).is_met(&[0x00, 0x7f]));
+        assert!(!target(10).is_met(&[0x00, 0x7f]));
+        assert!(target(16).is_met(&[0x00, 0x00]));
+        assert!(!target(17).is_met(&[0x00, 0x00]));
+        assert!(target(3).is_met(&[0x10]));
+        assert!(!target(4).is_met(&[0x10]));
+    }
+
+    #[test]
+    fn test_numeric_target_compact() {
+        // Bitcoin's genesis block target
+        let genesis = NumericTarget::from_compact(0x1d00ffff).unwrap();
+        assert_eq!(
+            "00000000ffff0000000000000000000000000000000000000000000000000000",
+            genesis.to_string()
+        );
+        assert_eq!(0x1d00ffff, genesis.to_compact());
+        assert!((genesis.difficulty() - 32.0).abs() < 0.001);
+
+        assert_eq!(
+            NumericTarget::from_hex(&format!("{:064x}", 0x12u8)),
+            NumericTarget::from_compact(0x01120000)
+        );
+        assert_eq!(
+            0x01120000,
+            NumericTarget::from_compact(0x01120000)
+                .unwrap()
+                .to_compact()
+        );
+        assert_eq!(
+            0x02008000,
+            NumericTarget::from_compact(0x02008000)
+                .unwrap()
+                .to_compact()
+        );
+
+        assert!(NumericTarget::from_compact(0x04923456).is_none());
+        assert!(NumericTarget::from_compact(0xff123456).is_none());
+        assert_eq!(
+            Some(NumericTarget::from_bytes([0; 32])),
+            NumericTarget::from_compact(0x00800000)
+        );
+    }
+
+    #[test]
+    fn test_numeric_target_from_difficulty() {
+        for difficulty in [0, 1, 7, 8, 9, 24, 255, 256] {
+            let target = NumericTarget::from_difficulty(difficulty);
+            let zeros = Target::new(TargetMode::LeadingZeroBits, difficulty, difficulty);
+
+            let mut digest = target.to_bytes();
+            assert!(target.is_met(&digest));
+            assert_eq!(zeros.is_met(&digest), target.is_met(&digest));
+
+            if let Some(byte) = digest.iter_mut().rev().find(|byte| **byte != 0xff) {
+                *byte += 1;
+                assert!(!target.is_met(&digest));
+                assert_eq!(zeros.is_met(&digest), target.is_met(&digest));
+            }
+        }
+
+        // Other digest lengths are compared as fractions of their range
+        let target = NumericTarget::from_difficulty(12);
+        assert!(target.is_met(&[0x00, 0x0f, 0xff]));
+        assert!(!target.is_met(&[0x00, 0x10]));
+        assert!(target.is_met(&[[0x00, 0x0f].as_slice(), &[0xff; 62]].concat()));
+        assert!(!target.is_met(&[[0x00, 0x10].as_slice(), &[0x00; 62]].concat()));
+
+        let target = NumericTarget::from_difficulty(20);
+        let mode = TargetMode::Numeric(target);
+        assert_eq!(Some(mode), TargetMode::from_name(&mode.to_string()));
+
+        assert_eq!(
+            Target::Numeric(NumericTarget::from_difficulty(12)),
+            Target::new(mode, 20, 12)
+        );
+        assert_eq!(
+            Target::Numeric(NumericTarget::MAX),
+            Target::new(mode, 300, 0)
+        );
+    }
+}
@@ -0,0 +1,457 @@
+//! NFKC normalization, case folding, grapheme clusters, and confusable skeletons of usernames
+
+use crate::unicode_tables::{
+    CASE_FOLDINGS, CASE_FOLDINGS_DATA, COMBINING_CLASSES, COMPOSITIONS, CONFUSABLES,
+    CONFUSABLES_DATA, DECOMPOSITIONS, DECOMPOSITIONS_DATA, GRAPHEME_EXTEND,
+};
+
+const HANGUL_S_BASE: u32 = 0xac00;
+const HANGUL_L_BASE: u32 = 0x1100;
+const HANGUL_V_BASE: u32 = 0x1161;
+const HANGUL_T_BASE: u32 = 0x11a7;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_COUNT: u32 = 28;
+const HANGUL_N_COUNT: u32 = HANGUL_V_COUNT * HANGUL_T_COUNT;
+const HANGUL_S_COUNT: u32 = 19 * HANGUL_N_COUNT;
+
+/// Finds the inclusive range containing the character
+fn find_range<T>(ranges: &[T], character: char, bounds: impl Fn(&T) -> (u32, u32)) -> Option<&T> {
+    let code_point = character as u32;
+
+    let index = ranges
+        .binary_search_by(|range| {
+            let (start, end) = bounds(range);
+            if end < code_point {
+                std::cmp::Ordering::Less
+            } else if start > code_point {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .ok()?;
+
+    Some(&ranges[index])
+}
+
+fn combining_class(character: char) -> u8 {
+    find_range(COMBINING_CLASSES, character, |(start, end, _)| {
+        (*start, *end)
+    })
+    .map_or(0, |(_, _, class)| *class)
+}
+
+fn lookup<'a>(
+    table: &[(u32, u16, u8)],
+    data: &'a [u32],
+    character: char,
+) -> Option<impl Iterator<Item = char> + 'a> {
+    let index = table
+        .binary_search_by_key(&(character as u32), |(code_point, _, _)| *code_point)
+        .ok()?;
+    let (_, offset, length) = table[index];
+
+    Some(
+        data[offset as usize..offset as usize + length as usize]
+            .iter()
+            .map(|code_point| char::from_u32(*code_point).unwrap()),
+    )
+}
+
+/// Returns the compatibility decomposition (NFKD) of the string
+fn decompose(string: &str) -> Vec<char> {
+    let mut decomposed = Vec::with_capacity(string.len());
+
+    for character in string.chars() {
+        let syllable_index = (character as u32).wrapping_sub(HANGUL_S_BASE);
+
+        if syllable_index < HANGUL_S_COUNT {
+            let l = HANGUL_L_BASE + syllable_index / HANGUL_N_COUNT;
+            let v = HANGUL_V_BASE + syllable_index % HANGUL_N_COUNT / HANGUL_T_COUNT;
+            let t = HANGUL_T_BASE + syllable_index % HANGUL_T_COUNT;
+
+            decomposed.extend([l, v].map(|jamo| char::from_u32(jamo).unwrap()));
+            if t != HANGUL_T_BASE {
+                decomposed.push(char::from_u32(t).unwrap());
+            }
+        } else if let Some(mapping) = lookup(DECOMPOSITIONS, DECOMPOSITIONS_DATA, character) {
+            decomposed.extend(mapping);
+        } else {
+            decomposed.push(character);
+        }
+    }
+
+    reorder(&mut decomposed);
+
+    decomposed
+}
+
+/// Puts a decomposed string in canonical order, runs of combining marks are sorted by their
+/// combining class
+fn reorder(decomposed: &mut [char]) {
+    let mut start = 0;
+    while start < decomposed.len() {
+        if combining_class(decomposed[start]) == 0 {
+            start += 1;
+            continue;
+        }
+
+        let end = decomposed[start..]
+            .iter()
+            .position(|character| combining_class(*character) == 0)
+            .map_or(decomposed.len(), |length| start + length);
+        decomposed[start..end].sort_by_key(|character| combining_class(*character));
+        start = end;
+    }
+}
+
+fn compose_pair(first: char, second: char) -> Option<char> {
+    let (first, second) = (first as u32, second as u32);
+
+    let l_index = first.wrapping_sub(HANGUL_L_BASE);
+    let v_index = second.wrapping_sub(HANGUL_V_BASE);
+    if l_index < 19 && v_index < HANGUL_V_COUNT {
+        return char::from_u32(
+            HANGUL_S_BASE + (l_index * HANGUL_V_COUNT + v_index) * HANGUL_T_COUNT,
+        );
+    }
+
+    let s_index = first.wrapping_sub(HANGUL_S_BASE);
+    let t_index = second.wrapping_sub(HANGUL_T_BASE);
+    if s_index < HANGUL_S_COUNT
+        && s_index % HANGUL_T_COUNT == 0
+        && (1..HANGUL_T_COUNT).contains(&t_index)
+    {
+        return char::from_u32(first + t_index);
+    }
+
+    let index = COMPOSITIONS
+        .binary_search_by_key(&(first, second), |(first, second, _)| (*first, *second))
+        .ok()?;
+    char::from_u32(COMPOSITIONS[index].2)
+}
+
+/// Canonically composes a decomposed string
+fn compose(decomposed: Vec<char>) -> String {
+    let mut composed: Vec<char> = Vec::with_capacity(decomposed.len());
+    let mut starter = None;
+    // Highest combining class between the starter and the current character, None while
+    // they are adjacent
+    let mut last_class = None;
+
+    for character in decomposed {
+        let class = combining_class(character);
+
+        if let Some(starter) = starter {
+            let is_blocked = last_class.is_some_and(|last_class| last_class >= class);
+
+            if !is_blocked {
+                if let Some(pair) = compose_pair(composed[starter], character) {
+                    composed[starter] = pair;
+                    continue;
+                }
+            }
+        }
+
+        composed.push(character);
+        if class == 0 {
+            starter = Some(composed.len() - 1);
+            last_class = None;
+        } else {
+            last_class = Some(class);
+        }
+    }
+
+    composed.into_iter().collect()
+}
+
+/// Applies NFKC normalization
+pub(crate) fn nfkc(string: &str) -> String {
+    compose(decompose(string))
+}
+
+/// Applies full Unicode case folding
+pub(crate) fn case_fold(string: &str) -> String {
+    let mut folded = String::with_capacity(string.len());
+
+    for character in string.chars() {
+        match lookup(CASE_FOLDINGS, CASE_FOLDINGS_DATA, character) {
+            Some(mapping) => folded.extend(mapping),
+            None => folded.push(character),
+        }
+    }
+
+    folded
+}
+
+const ZERO_WIDTH_JOINER: char = '\u{200d}';
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum HangulType {
+    Leading,
+    Vowel,
+    Trailing,
+    LeadingVowel,
+    LeadingVowelTrailing,
+}
+
+fn hangul_type(character: char) -> Option<HangulType> {
+    match character as u32 {
+        0x1100..=0x115f | 0xa960..=0xa97c => Some(HangulType::Leading),
+        0x1160..=0x11a7 | 0xd7b0..=0xd7c6 => Some(HangulType::Vowel),
+        0x11a8..=0x11ff | 0xd7cb..=0xd7fb => Some(HangulType::Trailing),
+        code_point if code_point.wrapping_sub(HANGUL_S_BASE) < HANGUL_S_COUNT => {
+            if (code_point - HANGUL_S_BASE).is_multiple_of(HANGUL_T_COUNT) {
+                Some(HangulType::LeadingVowel)
+            } else {
+                Some(HangulType::LeadingVowelTrailing)
+            }
+        }
+        _ => None,
+    }
+}
+
+fn is_grapheme_extend(character: char) -> bool {
+    find_range(GRAPHEME_EXTEND, character, |range| *range).is_some()
+}
+
+fn is_regional_indicator(character: char) -> bool {
+    ('\u{1f1e6}'..='\u{1f1ff}').contains(&character)
+}
+
+/// Approximates the Extended_Pictographic property with the blocks emoji are taken from
+fn is_extended_pictographic(character: char) -> bool {
+    matches!(
+        character as u32,
+        0xa9 | 0xae
+            | 0x203c
+            | 0x2049
+            | 0x2122
+            | 0x2139
+            | 0x2194..=0x21aa
+            | 0x231a..=0x23ff
+            | 0x24c2
+            | 0x25aa..=0x27bf
+            | 0x2934..=0x2935
+            | 0x2b05..=0x2b55
+            | 0x3030
+            | 0x303d
+            | 0x3297
+            | 0x3299
+            | 0x1f000..=0x1faff
+    )
+}
+
+fn is_control(character: char) -> bool {
+    character.is_control() || character == '\u{2028}' || character == '\u{2029}'
+}
+
+/// Returns the number of grapheme clusters in the string
+///
+/// Follows the extended grapheme cluster rules of UAX #29 except for prepended
+/// characters, with the Extend, SpacingMark, and Extended_Pictographic properties
+/// approximated from the general categories.
+pub(crate) fn grapheme_count(string: &str) -> usize {
+    let mut count = 0;
+    let mut previous: Option<char> = None;
+    // Number of regional indicators directly before the current character
+    let mut regional_indicators = 0;
+    // Whether the characters before the current one are a pictograph followed by extenders,
+    // and whether that was the case before the last zero width joiner
+    let mut pictographic = false;
+    let mut pictographic_before_joiner = false;
+
+    for character in string.chars() {
+        let is_boundary = match previous {
+            None => true,
+            Some('\r') if character == '\n' => false,
+            Some(previous) if is_control(previous) || is_control(character) => true,
+            Some(previous) => {
+                let is_hangul_sequence = matches!(
+                    (hangul_type(previous), hangul_type(character)),
+                    (
+                        Some(HangulType::Leading),
+                        Some(
+                            HangulType::Leading
+                                | HangulType::Vowel
+                                | HangulType::LeadingVowel
+                                | HangulType::LeadingVowelTrailing
+                        )
+                    ) | (
+                        Some(HangulType::LeadingVowel | HangulType::Vowel),
+                        Some(HangulType::Vowel | HangulType::Trailing)
+                    ) | (
+                        Some(HangulType::LeadingVowelTrailing | HangulType::Trailing),
+                        Some(HangulType::Trailing)
+                    )
+                );
+                let is_emoji_sequence = previous == ZERO_WIDTH_JOINER
+                    && pictographic_before_joiner
+                    && is_extended_pictographic(character);
+                let is_flag = is_regional_indicator(character) && regional_indicators % 2 == 1;
+
+                !(is_hangul_sequence
+                    || is_grapheme_extend(character)
+                    || is_emoji_sequence
+                    || is_flag)
+            }
+        };
+
+        if is_boundary {
+            count += 1;
+        }
+
+        if is_regional_indicator(character) {
+            regional_indicators += 1;
+        } else {
+            regional_indicators = 0;
+        }
+
+        if character == ZERO_WIDTH_JOINER {
+            pictographic_before_joiner = pictographic;
+            pictographic = false;
+        } else if is_extended_pictographic(character) {
+            pictographic = true;
+        } else if !is_grapheme_extend(character) {
+            pictographic = false;
+        }
+
+        previous = Some(character);
+    }
+
+    count
+}
+
+/// Returns the UTS #39 skeleton of a string whose NFD and NFKD are the same
+///
+/// Each character of the decomposed string is replaced by its prototype from the confusables
+/// data, which is stored in NFD, and the result is put in canonical order again.
+fn skeleton(string: &str) -> Vec<char> {
+    let mut mapped = Vec::with_capacity(string.len());
+
+    for character in decompose(string) {
+        match lookup(CONFUSABLES, CONFUSABLES_DATA, character) {
+            Some(prototype) => mapped.extend(prototype),
+            None => mapped.push(character),
+        }
+    }
+    reorder(&mut mapped);
+
+    mapped
+}
+
+/// Returns the canonical form of a username, its NFKC case folding
+///
+/// The string is normalized again after case folding, since folding can produce
+/// characters that compose.
+pub fn canonicalize_username(username: &str) -> String {
+    nfkc(&case_fold(&nfkc(username)))
+}
+
+/// Returns the confusable skeleton of a username, the UTS #39 skeleton of its canonical form
+///
+/// Two usernames with the same skeleton are visually confusable, so registries can use it as
+/// the uniqueness key of canonical usernames. Uppercase letters can be confused with other
+/// characters than their case folding, like 'I' with 'l', so the confusables are mapped
+/// before canonicalizing too. The skeleton is only meant for comparisons, it isn't a readable
+/// form of the username.
+pub fn username_skeleton(username: &str) -> String {
+    let mapped: String = skeleton(username).into_iter().collect();
+
+    skeleton(&canonicalize_username(&mapped))
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{canonicalize_username, case_fold, grapheme_count, nfkc, username_skeleton};
+
+    #[test]
+    fn test_nfkc() {
+        for (input, expected) in [
+            // Composition and canonical ordering of combining marks
+            ("e\u{301}", "é"),
+            ("a\u{323}\u{302}", "ậ"),
+            ("a\u{302}\u{323}", "ậ"),
+            ("\u{1e0b}\u{323}", "\u{1e0d}\u{307}"),
+            // Compatibility characters
+            ("ﬁ", "fi"),
+            ("①", "1"),
+            ("ｐａｙｐａｌ", "paypal"),
+            ("x²", "x2"),
+            ("ẛ\u{323}", "ṩ"),
+            // Composition exclusions and singletons
+            ("\u{958}", "\u{915}\u{93c}"),
+            ("\u{212b}", "\u{c5}"),
+            // Hangul
+            ("\u{1100}\u{1161}\u{11a8}", "각"),
+            ("각", "각"),
+        ] {
+            assert_eq!(expected, nfkc(input), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_case_fold() {
+        assert_eq!("paypal", case_fold("PayPal"));
+        assert_eq!("strasse", case_fold("Straße"));
+        assert_eq!("σσσ", case_fold("Σσς"));
+        assert_eq!("ǰ", nfkc(&case_fold("ǰ")));
+    }
+
+    #[test]
+    fn test_canonicalize_username() {
+        assert_eq!("paypal", canonicalize_username("ＰａｙＰａｌ"));
+        assert_eq!("strasse", canonicalize_username("STRAẞE"));
+        assert_eq!("café", canonicalize_username("CAFE\u{301}"));
+        assert_eq!(
+            canonicalize_username("café"),
+            canonicalize_username("cafe\u{301}")
+        );
+    }
+
+    #[test]
+    fn test_grapheme_count() {
+        for (string, expected) in [
+            ("", 0),
+            ("zeronet", 7),
+            ("e\u{301}", 1),
+            ("\r\n", 1),
+            ("\n\r", 2),
+            ("名前です", 4),
+            ("\u{1100}\u{1161}\u{11a8}한국", 3),
+            ("नमस्ते", 4),
+            ("👍🏽", 1),
+            ("👨\u{200d}👩\u{200d}👧", 1),
+            ("a\u{200d}👧", 2),
+            ("🇩🇪🇫🇷🇮", 3),
+        ] {
+            assert_eq!(expected, grapheme_count(string), "{string}");
+        }
+    }
+
+    #[test]
+    fn test_username_skeleton() {
+        let skeleton = username_skeleton("paypal");
+        for username in [
+            "раypal",
+            "PAYPAL",
+            "paypaI",
+            "рау\u{440}а\u{4c0}",
+            "ｐａｙｐａｌ",
+            "ρaypa1",
+        ] {
+            assert_eq!(skeleton, username_skeleton(username), "{username}");
+        }
+
+        assert_eq!(username_skeleton("modern"), username_skeleton("rnodern"));
+        assert_eq!(username_skeleton("ǉ"), username_skeleton("lj"));
+        assert_ne!(username_skeleton("paypal"), username_skeleton("paypa"));
+        assert_ne!(username_skeleton("café"), username_skeleton("cafe"));
+
+        // Skeletons are in NFD and stable
+        assert_eq!(username_skeleton("cafe\u{301}"), username_skeleton("café"));
+        assert_eq!(skeleton, username_skeleton(&skeleton));
+    }
+}
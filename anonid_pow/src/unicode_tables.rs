@@ -0,0 +1,5139 @@
+//! Unicode 14.0.0 data and UTS #39 15.0.0 confusables generated by scripts/unicode_tables.py, do not edit
+
+/// Canonical combining classes other than 0 as inclusive code point ranges
+pub(crate) const COMBINING_CLASSES: &[(u32, u32, u8)] = &[
+    (0x300, 0x314, 230), (0x315, 0x315, 232), (0x316, 0x319, 220), (0x31a, 0x31a, 232),
+    (0x31b, 0x31b, 216), (0x31c, 0x320, 220), (0x321, 0x322, 202), (0x323, 0x326, 220),
+    (0x327, 0x328, 202), (0x329, 0x333, 220), (0x334, 0x338, 1), (0x339, 0x33c, 220),
+    (0x33d, 0x344, 230), (0x345, 0x345, 240), (0x346, 0x346, 230), (0x347, 0x349, 220),
+    (0x34a, 0x34c, 230), (0x34d, 0x34e, 220), (0x350, 0x352, 230), (0x353, 0x356, 220),
+    (0x357, 0x357, 230), (0x358, 0x358, 232), (0x359, 0x35a, 220), (0x35b, 0x35b, 230),
+    (0x35c, 0x35c, 233), (0x35d, 0x35e, 234), (0x35f, 0x35f, 233), (0x360, 0x361, 234),
+    (0x362, 0x362, 233), (0x363, 0x36f, 230), (0x483, 0x487, 230), (0x591, 0x591, 220),
+    (0x592, 0x595, 230), (0x596, 0x596, 220), (0x597, 0x599, 230), (0x59a, 0x59a, 222),
+    (0x59b, 0x59b, 220), (0x59c, 0x5a1, 230), (0x5a2, 0x5a7, 220), (0x5a8, 0x5a9, 230),
+    (0x5aa, 0x5aa, 220), (0x5ab, 0x5ac, 230), (0x5ad, 0x5ad, 222), (0x5ae, 0x5ae, 228),
+    (0x5af, 0x5af, 230), (0x5b0, 0x5b0, 10), (0x5b1, 0x5b1, 11), (0x5b2, 0x5b2, 12),
+    (0x5b3, 0x5b3, 13), (0x5b4, 0x5b4, 14), (0x5b5, 0x5b5, 15), (0x5b6, 0x5b6, 16),
+    (0x5b7, 0x5b7, 17), (0x5b8, 0x5b8, 18), (0x5b9, 0x5ba, 19), (0x5bb, 0x5bb, 20),
+    (0x5bc, 0x5bc, 21), (0x5bd, 0x5bd, 22), (0x5bf, 0x5bf, 23), (0x5c1, 0x5c1, 24),
+    (0x5c2, 0x5c2, 25), (0x5c4, 0x5c4, 230), (0x5c5, 0x5c5, 220), (0x5c7, 0x5c7, 18),
+    (0x610, 0x617, 230), (0x618, 0x618, 30), (0x619, 0x619, 31), (0x61a, 0x61a, 32),
+    (0x64b, 0x64b, 27), (0x64c, 0x64c, 28), (0x64d, 0x64d, 29), (0x64e, 0x64e, 30),
+    (0x64f, 0x64f, 31), (0x650, 0x650, 32), (0x651, 0x651, 33), (0x652, 0x652, 34),
+    (0x653, 0x654, 230), (0x655, 0x656, 220), (0x657, 0x65b, 230), (0x65c, 0x65c, 220),
+    (0x65d, 0x65e, 230), (0x65f, 0x65f, 220), (0x670, 0x670, 35), (0x6d6, 0x6dc, 230),
+    (0x6df, 0x6e2, 230), (0x6e3, 0x6e3, 220), (0x6e4, 0x6e4, 230), (0x6e7, 0x6e8, 230),
+    (0x6ea, 0x6ea, 220), (0x6eb, 0x6ec, 230), (0x6ed, 0x6ed, 220), (0x711, 0x711, 36),
+    (0x730, 0x730, 230), (0x731, 0x731, 220), (0x732, 0x733, 230), (0x734, 0x734, 220),
+    (0x735, 0x736, 230), (0x737, 0x739, 220), (0x73a, 0x73a, 230), (0x73b, 0x73c, 220),
+    (0x73d, 0x73d, 230), (0x73e, 0x73e, 220), (0x73f, 0x741, 230), (0x742, 0x742, 220),
+    (0x743, 0x743, 230), (0x744, 0x744, 220), (0x745, 0x745, 230), (0x746, 0x746, 220),
+    (0x747, 0x747, 230), (0x748, 0x748, 220), (0x749, 0x74a, 230), (0x7eb, 0x7f1, 230),
+    (0x7f2, 0x7f2, 220), (0x7f3, 0x7f3, 230), (0x7fd, 0x7fd, 220), (0x816, 0x819, 230),
+    (0x81b, 0x823, 230), (0x825, 0x827, 230), (0x829, 0x82d, 230), (0x859, 0x85b, 220),
+    (0x898, 0x898, 230), (0x899, 0x89b, 220), (0x89c, 0x89f, 230), (0x8ca, 0x8ce, 230),
+    (0x8cf, 0x8d3, 220), (0x8d4, 0x8e1, 230), (0x8e3, 0x8e3, 220), (0x8e4, 0x8e5, 230),
+    (0x8e6, 0x8e6, 220), (0x8e7, 0x8e8, 230), (0x8e9, 0x8e9, 220), (0x8ea, 0x8ec, 230),
+    (0x8ed, 0x8ef, 220), (0x8f0, 0x8f0, 27), (0x8f1, 0x8f1, 28), (0x8f2, 0x8f2, 29),
+    (0x8f3, 0x8f5, 230), (0x8f6, 0x8f6, 220), (0x8f7, 0x8f8, 230), (0x8f9, 0x8fa, 220),
+    (0x8fb, 0x8ff, 230), (0x93c, 0x93c, 7), (0x94d, 0x94d, 9), (0x951, 0x951, 230),
+    (0x952, 0x952, 220), (0x953, 0x954, 230), (0x9bc, 0x9bc, 7), (0x9cd, 0x9cd, 9),
+    (0x9fe, 0x9fe, 230), (0xa3c, 0xa3c, 7), (0xa4d, 0xa4d, 9), (0xabc, 0xabc, 7),
+    (0xacd, 0xacd, 9), (0xb3c, 0xb3c, 7), (0xb4d, 0xb4d, 9), (0xbcd, 0xbcd, 9),
+    (0xc3c, 0xc3c, 7), (0xc4d, 0xc4d, 9), (0xc55, 0xc55, 84), (0xc56, 0xc56, 91),
+    (0xcbc, 0xcbc, 7), (0xccd, 0xccd, 9), (0xd3b, 0xd3c, 9), (0xd4d, 0xd4d, 9),
+    (0xdca, 0xdca, 9), (0xe38, 0xe39, 103), (0xe3a, 0xe3a, 9), (0xe48, 0xe4b, 107),
+    (0xeb8, 0xeb9, 118), (0xeba, 0xeba, 9), (0xec8, 0xecb, 122), (0xf18, 0xf19, 220),
+    (0xf35, 0xf35, 220), (0xf37, 0xf37, 220), (0xf39, 0xf39, 216), (0xf71, 0xf71, 129),
+    (0xf72, 0xf72, 130), (0xf74, 0xf74, 132), (0xf7a, 0xf7d, 130), (0xf80, 0xf80, 130),
+    (0xf82, 0xf83, 230), (0xf84, 0xf84, 9), (0xf86, 0xf87, 230), (0xfc6, 0xfc6, 220),
+    (0x1037, 0x1037, 7), (0x1039, 0x103a, 9), (0x108d, 0x108d, 220), (0x135d, 0x135f, 230),
+    (0x1714, 0x1715, 9), (0x1734, 0x1734, 9), (0x17d2, 0x17d2, 9), (0x17dd, 0x17dd, 230),
+    (0x18a9, 0x18a9, 228), (0x1939, 0x1939, 222), (0x193a, 0x193a, 230), (0x193b, 0x193b, 220),
+    (0x1a17, 0x1a17, 230), (0x1a18, 0x1a18, 220), (0x1a60, 0x1a60, 9), (0x1a75, 0x1a7c, 230),
+    (0x1a7f, 0x1a7f, 220), (0x1ab0, 0x1ab4, 230), (0x1ab5, 0x1aba, 220), (0x1abb, 0x1abc, 230),
+    (0x1abd, 0x1abd, 220), (0x1abf, 0x1ac0, 220), (0x1ac1, 0x1ac2, 230), (0x1ac3, 0x1ac4, 220),
+    (0x1ac5, 0x1ac9, 230), (0x1aca, 0x1aca, 220), (0x1acb, 0x1ace, 230), (0x1b34, 0x1b34, 7),
+    (0x1b44, 0x1b44, 9), (0x1b6b, 0x1b6b, 230), (0x1b6c, 0x1b6c, 220), (0x1b6d, 0x1b73, 230),
+    (0x1baa, 0x1bab, 9), (0x1be6, 0x1be6, 7), (0x1bf2, 0x1bf3, 9), (0x1c37, 0x1c37, 7),
+    (0x1cd0, 0x1cd2, 230), (0x1cd4, 0x1cd4, 1), (0x1cd5, 0x1cd9, 220), (0x1cda, 0x1cdb, 230),
+    (0x1cdc, 0x1cdf, 220), (0x1ce0, 0x1ce0, 230), (0x1ce2, 0x1ce8, 1), (0x1ced, 0x1ced, 220),
+    (0x1cf4, 0x1cf4, 230), (0x1cf8, 0x1cf9, 230), (0x1dc0, 0x1dc1, 230), (0x1dc2, 0x1dc2, 220),
+    (0x1dc3, 0x1dc9, 230), (0x1dca, 0x1dca, 220), (0x1dcb, 0x1dcc, 230), (0x1dcd, 0x1dcd, 234),
+    (0x1dce, 0x1dce, 214), (0x1dcf, 0x1dcf, 220), (0x1dd0, 0x1dd0, 202), (0x1dd1, 0x1df5, 230),
+    (0x1df6, 0x1df6, 232), (0x1df7, 0x1df8, 228), (0x1df9, 0x1df9, 220), (0x1dfa, 0x1dfa, 218),
+    (0x1dfb, 0x1dfb, 230), (0x1dfc, 0x1dfc, 233), (0x1dfd, 0x1dfd, 220), (0x1dfe, 0x1dfe, 230),
+    (0x1dff, 0x1dff, 220), (0x20d0, 0x20d1, 230), (0x20d2, 0x20d3, 1), (0x20d4, 0x20d7, 230),
+    (0x20d8, 0x20da, 1), (0x20db, 0x20dc, 230), (0x20e1, 0x20e1, 230), (0x20e5, 0x20e6, 1),
+    (0x20e7, 0x20e7, 230), (0x20e8, 0x20e8, 220), (0x20e9, 0x20e9, 230), (0x20ea, 0x20eb, 1),
+    (0x20ec, 0x20ef, 220), (0x20f0, 0x20f0, 230), (0x2cef, 0x2cf1, 230), (0x2d7f, 0x2d7f, 9),
+    (0x2de0, 0x2dff, 230), (0x302a, 0x302a, 218), (0x302b, 0x302b, 228), (0x302c, 0x302c, 232),
+    (0x302d, 0x302d, 222), (0x302e, 0x302f, 224), (0x3099, 0x309a, 8), (0xa66f, 0xa66f, 230),
+    (0xa674, 0xa67d, 230), (0xa69e, 0xa69f, 230), (0xa6f0, 0xa6f1, 230), (0xa806, 0xa806, 9),
+    (0xa82c, 0xa82c, 9), (0xa8c4, 0xa8c4, 9), (0xa8e0, 0xa8f1, 230), (0xa92b, 0xa92d, 220),
+    (0xa953, 0xa953, 9), (0xa9b3, 0xa9b3, 7), (0xa9c0, 0xa9c0, 9), (0xaab0, 0xaab0, 230),
+    (0xaab2, 0xaab3, 230), (0xaab4, 0xaab4, 220), (0xaab7, 0xaab8, 230), (0xaabe, 0xaabf, 230),
+    (0xaac1, 0xaac1, 230), (0xaaf6, 0xaaf6, 9), (0xabed, 0xabed, 9), (0xfb1e, 0xfb1e, 26),
+    (0xfe20, 0xfe26, 230), (0xfe27, 0xfe2d, 220), (0xfe2e, 0xfe2f, 230), (0x101fd, 0x101fd, 220),
+    (0x102e0, 0x102e0, 220), (0x10376, 0x1037a, 230), (0x10a0d, 0x10a0d, 220), (0x10a0f, 0x10a0f, 230),
+    (0x10a38, 0x10a38, 230), (0x10a39, 0x10a39, 1), (0x10a3a, 0x10a3a, 220), (0x10a3f, 0x10a3f, 9),
+    (0x10ae5, 0x10ae5, 230), (0x10ae6, 0x10ae6, 220), (0x10d24, 0x10d27, 230), (0x10eab, 0x10eac, 230),
+    (0x10f46, 0x10f47, 220), (0x10f48, 0x10f4a, 230), (0x10f4b, 0x10f4b, 220), (0x10f4c, 0x10f4c, 230),
+    (0x10f4d, 0x10f50, 220), (0x10f82, 0x10f82, 230), (0x10f83, 0x10f83, 220), (0x10f84, 0x10f84, 230),
+    (0x10f85, 0x10f85, 220), (0x11046, 0x11046, 9), (0x11070, 0x11070, 9), (0x1107f, 0x1107f, 9),
+    (0x110b9, 0x110b9, 9), (0x110ba, 0x110ba, 7), (0x11100, 0x11102, 230), (0x11133, 0x11134, 9),
+    (0x11173, 0x11173, 7), (0x111c0, 0x111c0, 9), (0x111ca, 0x111ca, 7), (0x11235, 0x11235, 9),
+    (0x11236, 0x11236, 7), (0x112e9, 0x112e9, 7), (0x112ea, 0x112ea, 9), (0x1133b, 0x1133c, 7),
+    (0x1134d, 0x1134d, 9), (0x11366, 0x1136c, 230), (0x11370, 0x11374, 230), (0x11442, 0x11442, 9),
+    (0x11446, 0x11446, 7), (0x1145e, 0x1145e, 230), (0x114c2, 0x114c2, 9), (0x114c3, 0x114c3, 7),
+    (0x115bf, 0x115bf, 9), (0x115c0, 0x115c0, 7), (0x1163f, 0x1163f, 9), (0x116b6, 0x116b6, 9),
+    (0x116b7, 0x116b7, 7), (0x1172b, 0x1172b, 9), (0x11839, 0x11839, 9), (0x1183a, 0x1183a, 7),
+    (0x1193d, 0x1193e, 9), (0x11943, 0x11943, 7), (0x119e0, 0x119e0, 9), (0x11a34, 0x11a34, 9),
+    (0x11a47, 0x11a47, 9), (0x11a99, 0x11a99, 9), (0x11c3f, 0x11c3f, 9), (0x11d42, 0x11d42, 7),
+    (0x11d44, 0x11d45, 9), (0x11d97, 0x11d97, 9), (0x16af0, 0x16af4, 1), (0x16b30, 0x16b36, 230),
+    (0x16ff0, 0x16ff1, 6), (0x1bc9e, 0x1bc9e, 1), (0x1d165, 0x1d166, 216), (0x1d167, 0x1d169, 1),
+    (0x1d16d, 0x1d16d, 226), (0x1d16e, 0x1d172, 216), (0x1d17b, 0x1d182, 220), (0x1d185, 0x1d189, 230),
+    (0x1d18a, 0x1d18b, 220), (0x1d1aa, 0x1d1ad, 230), (0x1d242, 0x1d244, 230), (0x1e000, 0x1e006, 230),
+    (0x1e008, 0x1e018, 230), (0x1e01b, 0x1e021, 230), (0x1e023, 0x1e024, 230), (0x1e026, 0x1e02a, 230),
+    (0x1e130, 0x1e136, 230), (0x1e2ae, 0x1e2ae, 230), (0x1e2ec, 0x1e2ef, 230), (0x1e8d0, 0x1e8d6, 220),
+    (0x1e944, 0x1e949, 230), (0x1e94a, 0x1e94a, 7),
+];
+
+/// Full compatibility decompositions except the algorithmic Hangul ones
+///
+/// Each entry is a code point with the offset and length of its mapping in `DECOMPOSITIONS_DATA`.
+pub(crate) const DECOMPOSITIONS: &[(u32, u16, u8)] = &[
+    (0xa0, 0, 1), (0xa8, 1, 2), (0xaa, 3, 1), (0xaf, 4, 2), (0xb2, 6, 1),
+    (0xb3, 7, 1), (0xb4, 8, 2), (0xb5, 10, 1), (0xb8, 11, 2), (0xb9, 13, 1),
+    (0xba, 14, 1), (0xbc, 15, 3), (0xbd, 18, 3), (0xbe, 21, 3), (0xc0, 24, 2),
+    (0xc1, 26, 2), (0xc2, 28, 2), (0xc3, 30, 2), (0xc4, 32, 2), (0xc5, 34, 2),
+    (0xc7, 36, 2), (0xc8, 38, 2), (0xc9, 40, 2), (0xca, 42, 2), (0xcb, 44, 2),
+    (0xcc, 46, 2), (0xcd, 48, 2), (0xce, 50, 2), (0xcf, 52, 2), (0xd1, 54, 2),
+    (0xd2, 56, 2), (0xd3, 58, 2), (0xd4, 60, 2), (0xd5, 62, 2), (0xd6, 64, 2),
+    (0xd9, 66, 2), (0xda, 68, 2), (0xdb, 70, 2), (0xdc, 72, 2), (0xdd, 74, 2),
+    (0xe0, 76, 2), (0xe1, 78, 2), (0xe2, 80, 2), (0xe3, 82, 2), (0xe4, 84, 2),
+    (0xe5, 86, 2), (0xe7, 88, 2), (0xe8, 90, 2), (0xe9, 92, 2), (0xea, 94, 2),
+    (0xeb, 96, 2), (0xec, 98, 2), (0xed, 100, 2), (0xee, 102, 2), (0xef, 104, 2),
+    (0xf1, 106, 2), (0xf2, 108, 2), (0xf3, 110, 2), (0xf4, 112, 2), (0xf5, 114, 2),
+    (0xf6, 116, 2), (0xf9, 118, 2), (0xfa, 120, 2), (0xfb, 122, 2), (0xfc, 124, 2),
+    (0xfd, 126, 2), (0xff, 128, 2), (0x100, 130, 2), (0x101, 132, 2), (0x102, 134, 2),
+    (0x103, 136, 2), (0x104, 138, 2), (0x105, 140, 2), (0x106, 142, 2), (0x107, 144, 2),
+    (0x108, 146, 2), (0x109, 148, 2), (0x10a, 150, 2), (0x10b, 152, 2), (0x10c, 154, 2),
+    (0x10d, 156, 2), (0x10e, 158, 2), (0x10f, 160, 2), (0x112, 162, 2), (0x113, 164, 2),
+    (0x114, 166, 2), (0x115, 168, 2), (0x116, 170, 2), (0x117, 172, 2), (0x118, 174, 2),
+    (0x119, 176, 2), (0x11a, 178, 2), (0x11b, 180, 2), (0x11c, 182, 2), (0x11d, 184, 2),
+    (0x11e, 186, 2), (0x11f, 188, 2), (0x120, 190, 2), (0x121, 192, 2), (0x122, 194, 2),
+    (0x123, 196, 2), (0x124, 198, 2), (0x125, 200, 2), (0x128, 202, 2), (0x129, 204, 2),
+    (0x12a, 206, 2), (0x12b, 208, 2), (0x12c, 210, 2), (0x12d, 212, 2), (0x12e, 214, 2),
+    (0x12f, 216, 2), (0x130, 218, 2), (0x132, 220, 2), (0x133, 222, 2), (0x134, 224, 2),
+    (0x135, 226, 2), (0x136, 228, 2), (0x137, 230, 2), (0x139, 232, 2), (0x13a, 234, 2),
+    (0x13b, 236, 2), (0x13c, 238, 2), (0x13d, 240, 2), (0x13e, 242, 2), (0x13f, 244, 2),
+    (0x140, 246, 2), (0x143, 248, 2), (0x144, 250, 2), (0x145, 252, 2), (0x146, 254, 2),
+    (0x147, 256, 2), (0x148, 258, 2), (0x149, 260, 2), (0x14c, 262, 2), (0x14d, 264, 2),
+    (0x14e, 266, 2), (0x14f, 268, 2), (0x150, 270, 2), (0x151, 272, 2), (0x154, 274, 2),
+    (0x155, 276, 2), (0x156, 278, 2), (0x157, 280, 2), (0x158, 282, 2), (0x159, 284, 2),
+    (0x15a, 286, 2), (0x15b, 288, 2), (0x15c, 290, 2), (0x15d, 292, 2), (0x15e, 294, 2),
+    (0x15f, 296, 2), (0x160, 298, 2), (0x161, 300, 2), (0x162, 302, 2), (0x163, 304, 2),
+    (0x164, 306, 2), (0x165, 308, 2), (0x168, 310, 2), (0x169, 312, 2), (0x16a, 314, 2),
+    (0x16b, 316, 2), (0x16c, 318, 2), (0x16d, 320, 2), (0x16e, 322, 2), (0x16f, 324, 2),
+    (0x170, 326, 2), (0x171, 328, 2), (0x172, 330, 2), (0x173, 332, 2), (0x174, 334, 2),
+    (0x175, 336, 2), (0x176, 338, 2), (0x177, 340, 2), (0x178, 342, 2), (0x179, 344, 2),
+    (0x17a, 346, 2), (0x17b, 348, 2), (0x17c, 350, 2), (0x17d, 352, 2), (0x17e, 354, 2),
+    (0x17f, 356, 1), (0x1a0, 357, 2), (0x1a1, 359, 2), (0x1af, 361, 2), (0x1b0, 363, 2),
+    (0x1c4, 365, 3), (0x1c5, 368, 3), (0x1c6, 371, 3), (0x1c7, 374, 2), (0x1c8, 376, 2),
+    (0x1c9, 378, 2), (0x1ca, 380, 2), (0x1cb, 382, 2), (0x1cc, 384, 2), (0x1cd, 386, 2),
+    (0x1ce, 388, 2), (0x1cf, 390, 2), (0x1d0, 392, 2), (0x1d1, 394, 2), (0x1d2, 396, 2),
+    (0x1d3, 398, 2), (0x1d4, 400, 2), (0x1d5, 402, 3), (0x1d6, 405, 3), (0x1d7, 408, 3),
+    (0x1d8, 411, 3), (0x1d9, 414, 3), (0x1da, 417, 3), (0x1db, 420, 3), (0x1dc, 423, 3),
+    (0x1de, 426, 3), (0x1df, 429, 3), (0x1e0, 432, 3), (0x1e1, 435, 3), (0x1e2, 438, 2),
+    (0x1e3, 440, 2), (0x1e6, 442, 2), (0x1e7, 444, 2), (0x1e8, 446, 2), (0x1e9, 448, 2),
+    (0x1ea, 450, 2), (0x1eb, 452, 2), (0x1ec, 454, 3), (0x1ed, 457, 3), (0x1ee, 460, 2),
+    (0x1ef, 462, 2), (0x1f0, 464, 2), (0x1f1, 466, 2), (0x1f2, 468, 2), (0x1f3, 470, 2),
+    (0x1f4, 472, 2), (0x1f5, 474, 2), (0x1f8, 476, 2), (0x1f9, 478, 2), (0x1fa, 480, 3),
+    (0x1fb, 483, 3), (0x1fc, 486, 2), (0x1fd, 488, 2), (0x1fe, 490, 2), (0x1ff, 492, 2),
+    (0x200, 494, 2), (0x201, 496, 2), (0x202, 498, 2), (0x203, 500, 2), (0x204, 502, 2),
+    (0x205, 504, 2), (0x206, 506, 2), (0x207, 508, 2), (0x208, 510, 2), (0x209, 512, 2),
+    (0x20a, 514, 2), (0x20b, 516, 2), (0x20c, 518, 2), (0x20d, 520, 2), (0x20e, 522, 2),
+    (0x20f, 524, 2), (0x210, 526, 2), (0x211, 528, 2), (0x212, 530, 2), (0x213, 532, 2),
+    (0x214, 534, 2), (0x215, 536, 2), (0x216, 538, 2), (0x217, 540, 2), (0x218, 542, 2),
+    (0x219, 544, 2), (0x21a, 546, 2), (0x21b, 548, 2), (0x21e, 550, 2), (0x21f, 552, 2),
+    (0x226, 554, 2), (0x227, 556, 2), (0x228, 558, 2), (0x229, 560, 2), (0x22a, 562, 3),
+    (0x22b, 565, 3), (0x22c, 568, 3), (0x22d, 571, 3), (0x22e, 574, 2), (0x22f, 576, 2),
+    (0x230, 578, 3), (0x231, 581, 3), (0x232, 584, 2), (0x233, 586, 2), (0x2b0, 588, 1),
+    (0x2b1, 589, 1), (0x2b2, 590, 1), (0x2b3, 591, 1), (0x2b4, 592, 1), (0x2b5, 593, 1),
+    (0x2b6, 594, 1), (0x2b7, 595, 1), (0x2b8, 596, 1), (0x2d8, 597, 2), (0x2d9, 599, 2),
+    (0x2da, 601, 2), (0x2db, 603, 2), (0x2dc, 605, 2), (0x2dd, 607, 2), (0x2e0, 609, 1),
+    (0x2e1, 610, 1), (0x2e2, 611, 1), (0x2e3, 612, 1), (0x2e4, 613, 1), (0x340, 614, 1),
+    (0x341, 615, 1), (0x343, 616, 1), (0x344, 617, 2), (0x374, 619, 1), (0x37a, 620, 2),
+    (0x37e, 622, 1), (0x384, 623, 2), (0x385, 625, 3), (0x386, 628, 2), (0x387, 630, 1),
+    (0x388, 631, 2), (0x389, 633, 2), (0x38a, 635, 2), (0x38c, 637, 2), (0x38e, 639, 2),
+    (0x38f, 641, 2), (0x390, 643, 3), (0x3aa, 646, 2), (0x3ab, 648, 2), (0x3ac, 650, 2),
+    (0x3ad, 652, 2), (0x3ae, 654, 2), (0x3af, 656, 2), (0x3b0, 658, 3), (0x3ca, 661, 2),
+    (0x3cb, 663, 2), (0x3cc, 665, 2), (0x3cd, 667, 2), (0x3ce, 669, 2), (0x3d0, 671, 1),
+    (0x3d1, 672, 1), (0x3d2, 673, 1), (0x3d3, 674, 2), (0x3d4, 676, 2), (0x3d5, 678, 1),
+    (0x3d6, 679, 1), (0x3f0, 680, 1), (0x3f1, 681, 1), (0x3f2, 682, 1), (0x3f4, 683, 1),
+    (0x3f5, 684, 1), (0x3f9, 685, 1), (0x400, 686, 2), (0x401, 688, 2), (0x403, 690, 2),
+    (0x407, 692, 2), (0x40c, 694, 2), (0x40d, 696, 2), (0x40e, 698, 2), (0x419, 700, 2),
+    (0x439, 702, 2), (0x450, 704, 2), (0x451, 706, 2), (0x453, 708, 2), (0x457, 710, 2),
+    (0x45c, 712, 2), (0x45d, 714, 2), (0x45e, 716, 2), (0x476, 718, 2), (0x477, 720, 2),
+    (0x4c1, 722, 2), (0x4c2, 724, 2), (0x4d0, 726, 2), (0x4d1, 728, 2), (0x4d2, 730, 2),
+    (0x4d3, 732, 2), (0x4d6, 734, 2), (0x4d7, 736, 2), (0x4da, 738, 2), (0x4db, 740, 2),
+    (0x4dc, 742, 2), (0x4dd, 744, 2), (0x4de, 746, 2), (0x4df, 748, 2), (0x4e2, 750, 2),
+    (0x4e3, 752, 2), (0x4e4, 754, 2), (0x4e5, 756, 2), (0x4e6, 758, 2), (0x4e7, 760, 2),
+    (0x4ea, 762, 2), (0x4eb, 764, 2), (0x4ec, 766, 2), (0x4ed, 768, 2), (0x4ee, 770, 2),
+    (0x4ef, 772, 2), (0x4f0, 774, 2), (0x4f1, 776, 2), (0x4f2, 778, 2), (0x4f3, 780, 2),
+    (0x4f4, 782, 2), (0x4f5, 784, 2), (0x4f8, 786, 2), (0x4f9, 788, 2), (0x587, 790, 2),
+    (0x622, 792, 2), (0x623, 794, 2), (0x624, 796, 2), (0x625, 798, 2), (0x626, 800, 2),
+    (0x675, 802, 2), (0x676, 804, 2), (0x677, 806, 2), (0x678, 808, 2), (0x6c0, 810, 2),
+    (0x6c2, 812, 2), (0x6d3, 814, 2), (0x929, 816, 2), (0x931, 818, 2), (0x934, 820, 2),
+    (0x958, 822, 2), (0x959, 824, 2), (0x95a, 826, 2), (0x95b, 828, 2), (0x95c, 830, 2),
+    (0x95d, 832, 2), (0x95e, 834, 2), (0x95f, 836, 2), (0x9cb, 838, 2), (0x9cc, 840, 2),
+    (0x9dc, 842, 2), (0x9dd, 844, 2), (0x9df, 846, 2), (0xa33, 848, 2), (0xa36, 850, 2),
+    (0xa59, 852, 2), (0xa5a, 854, 2), (0xa5b, 856, 2), (0xa5e, 858, 2), (0xb48, 860, 2),
+    (0xb4b, 862, 2), (0xb4c, 864, 2), (0xb5c, 866, 2), (0xb5d, 868, 2), (0xb94, 870, 2),
+    (0xbca, 872, 2), (0xbcb, 874, 2), (0xbcc, 876, 2), (0xc48, 878, 2), (0xcc0, 880, 2),
+    (0xcc7, 882, 2), (0xcc8, 884, 2), (0xcca, 886, 2), (0xccb, 888, 3), (0xd4a, 891, 2),
+    (0xd4b, 893, 2), (0xd4c, 895, 2), (0xdda, 897, 2), (0xddc, 899, 2), (0xddd, 901, 3),
+    (0xdde, 904, 2), (0xe33, 906, 2), (0xeb3, 908, 2), (0xedc, 910, 2), (0xedd, 912, 2),
+    (0xf0c, 914, 1), (0xf43, 915, 2), (0xf4d, 917, 2), (0xf52, 919, 2), (0xf57, 921, 2),
+    (0xf5c, 923, 2), (0xf69, 925, 2), (0xf73, 927, 2), (0xf75, 929, 2), (0xf76, 931, 2),
+    (0xf77, 933, 3), (0xf78, 936, 2), (0xf79, 938, 3), (0xf81, 941, 2), (0xf93, 943, 2),
+    (0xf9d, 945, 2), (0xfa2, 947, 2), (0xfa7, 949, 2), (0xfac, 951, 2), (0xfb9, 953, 2),
+    (0x1026, 955, 2), (0x10fc, 957, 1), (0x1b06, 958, 2), (0x1b08, 960, 2), (0x1b0a, 962, 2),
+    (0x1b0c, 964, 2), (0x1b0e, 966, 2), (0x1b12, 968, 2), (0x1b3b, 970, 2), (0x1b3d, 972, 2),
+    (0x1b40, 974, 2), (0x1b41, 976, 2), (0x1b43, 978, 2), (0x1d2c, 980, 1), (0x1d2d, 981, 1),
+    (0x1d2e, 982, 1), (0x1d30, 983, 1), (0x1d31, 984, 1), (0x1d32, 985, 1), (0x1d33, 986, 1),
+    (0x1d34, 987, 1), (0x1d35, 988, 1), (0x1d36, 989, 1), (0x1d37, 990, 1), (0x1d38, 991, 1),
+    (0x1d39, 992, 1), (0x1d3a, 993, 1), (0x1d3c, 994, 1), (0x1d3d, 995, 1), (0x1d3e, 996, 1),
+    (0x1d3f, 997, 1), (0x1d40, 998, 1), (0x1d41, 999, 1), (0x1d42, 1000, 1), (0x1d43, 1001, 1),
+    (0x1d44, 1002, 1), (0x1d45, 1003, 1), (0x1d46, 1004, 1), (0x1d47, 1005, 1), (0x1d48, 1006, 1),
+    (0x1d49, 1007, 1), (0x1d4a, 1008, 1), (0x1d4b, 1009, 1), (0x1d4c, 1010, 1), (0x1d4d, 1011, 1),
+    (0x1d4f, 1012, 1), (0x1d50, 1013, 1), (0x1d51, 1014, 1), (0x1d52, 1015, 1), (0x1d53, 1016, 1),
+    (0x1d54, 1017, 1), (0x1d55, 1018, 1), (0x1d56, 1019, 1), (0x1d57, 1020, 1), (0x1d58, 1021, 1),
+    (0x1d59, 1022, 1), (0x1d5a, 1023, 1), (0x1d5b, 1024, 1), (0x1d5c, 1025, 1), (0x1d5d, 1026, 1),
+    (0x1d5e, 1027, 1), (0x1d5f, 1028, 1), (0x1d60, 1029, 1), (0x1d61, 1030, 1), (0x1d62, 1031, 1),
+    (0x1d63, 1032, 1), (0x1d64, 1033, 1), (0x1d65, 1034, 1), (0x1d66, 1035, 1), (0x1d67, 1036, 1),
+    (0x1d68, 1037, 1), (0x1d69, 1038, 1), (0x1d6a, 1039, 1), (0x1d78, 1040, 1), (0x1d9b, 1041, 1),
+    (0x1d9c, 1042, 1), (0x1d9d, 1043, 1), (0x1d9e, 1044, 1), (0x1d9f, 1045, 1), (0x1da0, 1046, 1),
+    (0x1da1, 1047, 1), (0x1da2, 1048, 1), (0x1da3, 1049, 1), (0x1da4, 1050, 1), (0x1da5, 1051, 1),
+    (0x1da6, 1052, 1), (0x1da7, 1053, 1), (0x1da8, 1054, 1), (0x1da9, 1055, 1), (0x1daa, 1056, 1),
+    (0x1dab, 1057, 1), (0x1dac, 1058, 1), (0x1dad, 1059, 1), (0x1dae, 1060, 1), (0x1daf, 1061, 1),
+    (0x1db0, 1062, 1), (0x1db1, 1063, 1), (0x1db2, 1064, 1), (0x1db3, 1065, 1), (0x1db4, 1066, 1),
+    (0x1db5, 1067, 1), (0x1db6, 1068, 1), (0x1db7, 1069, 1), (0x1db8, 1070, 1), (0x1db9, 1071, 1),
+    (0x1dba, 1072, 1), (0x1dbb, 1073, 1), (0x1dbc, 1074, 1), (0x1dbd, 1075, 1), (0x1dbe, 1076, 1),
+    (0x1dbf, 1077, 1), (0x1e00, 1078, 2), (0x1e01, 1080, 2), (0x1e02, 1082, 2), (0x1e03, 1084, 2),
+    (0x1e04, 1086, 2), (0x1e05, 1088, 2), (0x1e06, 1090, 2), (0x1e07, 1092, 2), (0x1e08, 1094, 3),
+    (0x1e09, 1097, 3), (0x1e0a, 1100, 2), (0x1e0b, 1102, 2), (0x1e0c, 1104, 2), (0x1e0d, 1106, 2),
+    (0x1e0e, 1108, 2), (0x1e0f, 1110, 2), (0x1e10, 1112, 2), (0x1e11, 1114, 2), (0x1e12, 1116, 2),
+    (0x1e13, 1118, 2), (0x1e14, 1120, 3), (0x1e15, 1123, 3), (0x1e16, 1126, 3), (0x1e17, 1129, 3),
+    (0x1e18, 1132, 2), (0x1e19, 1134, 2), (0x1e1a, 1136, 2), (0x1e1b, 1138, 2), (0x1e1c, 1140, 3),
+    (0x1e1d, 1143, 3), (0x1e1e, 1146, 2), (0x1e1f, 1148, 2), (0x1e20, 1150, 2), (0x1e21, 1152, 2),
+    (0x1e22, 1154, 2), (0x1e23, 1156, 2), (0x1e24, 1158, 2), (0x1e25, 1160, 2), (0x1e26, 1162, 2),
+    (0x1e27, 1164, 2), (0x1e28, 1166, 2), (0x1e29, 1168, 2), (0x1e2a, 1170, 2), (0x1e2b, 1172, 2),
+    (0x1e2c, 1174, 2), (0x1e2d, 1176, 2), (0x1e2e, 1178, 3), (0x1e2f, 1181, 3), (0x1e30, 1184, 2),
+    (0x1e31, 1186, 2), (0x1e32, 1188, 2), (0x1e33, 1190, 2), (0x1e34, 1192, 2), (0x1e35, 1194, 2),
+    (0x1e36, 1196, 2), (0x1e37, 1198, 2), (0x1e38, 1200, 3), (0x1e39, 1203, 3), (0x1e3a, 1206, 2),
+    (0x1e3b, 1208, 2), (0x1e3c, 1210, 2), (0x1e3d, 1212, 2), (0x1e3e, 1214, 2), (0x1e3f, 1216, 2),
+    (0x1e40, 1218, 2), (0x1e41, 1220, 2), (0x1e42, 1222, 2), (0x1e43, 1224, 2), (0x1e44, 1226, 2),
+    (0x1e45, 1228, 2), (0x1e46, 1230, 2), (0x1e47, 1232, 2), (0x1e48, 1234, 2), (0x1e49, 1236, 2),
+    (0x1e4a, 1238, 2), (0x1e4b, 1240, 2), (0x1e4c, 1242, 3), (0x1e4d, 1245, 3), (0x1e4e, 1248, 3),
+    (0x1e4f, 1251, 3), (0x1e50, 1254, 3), (0x1e51, 1257, 3), (0x1e52, 1260, 3), (0x1e53, 1263, 3),
+    (0x1e54, 1266, 2), (0x1e55, 1268, 2), (0x1e56, 1270, 2), (0x1e57, 1272, 2), (0x1e58, 1274, 2),
+    (0x1e59, 1276, 2), (0x1e5a, 1278, 2), (0x1e5b, 1280, 2), (0x1e5c, 1282, 3), (0x1e5d, 1285, 3),
+    (0x1e5e, 1288, 2), (0x1e5f, 1290, 2), (0x1e60, 1292, 2), (0x1e61, 1294, 2), (0x1e62, 1296, 2),
+    (0x1e63, 1298, 2), (0x1e64, 1300, 3), (0x1e65, 1303, 3), (0x1e66, 1306, 3), (0x1e67, 1309, 3),
+    (0x1e68, 1312, 3), (0x1e69, 1315, 3), (0x1e6a, 1318, 2), (0x1e6b, 1320, 2), (0x1e6c, 1322, 2),
+    (0x1e6d, 1324, 2), (0x1e6e, 1326, 2), (0x1e6f, 1328, 2), (0x1e70, 1330, 2), (0x1e71, 1332, 2),
+    (0x1e72, 1334, 2), (0x1e73, 1336, 2), (0x1e74, 1338, 2), (0x1e75, 1340, 2), (0x1e76, 1342, 2),
+    (0x1e77, 1344, 2), (0x1e78, 1346, 3), (0x1e79, 1349, 3), (0x1e7a, 1352, 3), (0x1e7b, 1355, 3),
+    (0x1e7c, 1358, 2), (0x1e7d, 1360, 2), (0x1e7e, 1362, 2), (0x1e7f, 1364, 2), (0x1e80, 1366, 2),
+    (0x1e81, 1368, 2), (0x1e82, 1370, 2), (0x1e83, 1372, 2), (0x1e84, 1374, 2), (0x1e85, 1376, 2),
+    (0x1e86, 1378, 2), (0x1e87, 1380, 2), (0x1e88, 1382, 2), (0x1e89, 1384, 2), (0x1e8a, 1386, 2),
+    (0x1e8b, 1388, 2), (0x1e8c, 1390, 2), (0x1e8d, 1392, 2), (0x1e8e, 1394, 2), (0x1e8f, 1396, 2),
+    (0x1e90, 1398, 2), (0x1e91, 1400, 2), (0x1e92, 1402, 2), (0x1e93, 1404, 2), (0x1e94, 1406, 2),
+    (0x1e95, 1408, 2), (0x1e96, 1410, 2), (0x1e97, 1412, 2), (0x1e98, 1414, 2), (0x1e99, 1416, 2),
+    (0x1e9a, 1418, 2), (0x1e9b, 1420, 2), (0x1ea0, 1422, 2), (0x1ea1, 1424, 2), (0x1ea2, 1426, 2),
+    (0x1ea3, 1428, 2), (0x1ea4, 1430, 3), (0x1ea5, 1433, 3), (0x1ea6, 1436, 3), (0x1ea7, 1439, 3),
+    (0x1ea8, 1442, 3), (0x1ea9, 1445, 3), (0x1eaa, 1448, 3), (0x1eab, 1451, 3), (0x1eac, 1454, 3),
+    (0x1ead, 1457, 3), (0x1eae, 1460, 3), (0x1eaf, 1463, 3), (0x1eb0, 1466, 3), (0x1eb1, 1469, 3),
+    (0x1eb2, 1472, 3), (0x1eb3, 1475, 3), (0x1eb4, 1478, 3), (0x1eb5, 1481, 3), (0x1eb6, 1484, 3),
+    (0x1eb7, 1487, 3), (0x1eb8, 1490, 2), (0x1eb9, 1492, 2), (0x1eba, 1494, 2), (0x1ebb, 1496, 2),
+    (0x1ebc, 1498, 2), (0x1ebd, 1500, 2), (0x1ebe, 1502, 3), (0x1ebf, 1505, 3), (0x1ec0, 1508, 3),
+    (0x1ec1, 1511, 3), (0x1ec2, 1514, 3), (0x1ec3, 1517, 3), (0x1ec4, 1520, 3), (0x1ec5, 1523, 3),
+    (0x1ec6, 1526, 3), (0x1ec7, 1529, 3), (0x1ec8, 1532, 2), (0x1ec9, 1534, 2), (0x1eca, 1536, 2),
+    (0x1ecb, 1538, 2), (0x1ecc, 1540, 2), (0x1ecd, 1542, 2), (0x1ece, 1544, 2), (0x1ecf, 1546, 2),
+    (0x1ed0, 1548, 3), (0x1ed1, 1551, 3), (0x1ed2, 1554, 3), (0x1ed3, 1557, 3), (0x1ed4, 1560, 3),
+    (0x1ed5, 1563, 3), (0x1ed6, 1566, 3), (0x1ed7, 1569, 3), (0x1ed8, 1572, 3), (0x1ed9, 1575, 3),
+    (0x1eda, 1578, 3), (0x1edb, 1581, 3), (0x1edc, 1584, 3), (0x1edd, 1587, 3), (0x1ede, 1590, 3),
+    (0x1edf, 1593, 3), (0x1ee0, 1596, 3), (0x1ee1, 1599, 3), (0x1ee2, 1602, 3), (0x1ee3, 1605, 3),
+    (0x1ee4, 1608, 2), (0x1ee5, 1610, 2), (0x1ee6, 1612, 2), (0x1ee7, 1614, 2), (0x1ee8, 1616, 3),
+    (0x1ee9, 1619, 3), (0x1eea, 1622, 3), (0x1eeb, 1625, 3), (0x1eec, 1628, 3), (0x1eed, 1631, 3),
+    (0x1eee, 1634, 3), (0x1eef, 1637, 3), (0x1ef0, 1640, 3), (0x1ef1, 1643, 3), (0x1ef2, 1646, 2),
+    (0x1ef3, 1648, 2), (0x1ef4, 1650, 2), (0x1ef5, 1652, 2), (0x1ef6, 1654, 2), (0x1ef7, 1656, 2),
+    (0x1ef8, 1658, 2), (0x1ef9, 1660, 2), (0x1f00, 1662, 2), (0x1f01, 1664, 2), (0x1f02, 1666, 3),
+    (0x1f03, 1669, 3), (0x1f04, 1672, 3), (0x1f05, 1675, 3), (0x1f06, 1678, 3), (0x1f07, 1681, 3),
+    (0x1f08, 1684, 2), (0x1f09, 1686, 2), (0x1f0a, 1688, 3), (0x1f0b, 1691, 3), (0x1f0c, 1694, 3),
+    (0x1f0d, 1697, 3), (0x1f0e, 1700, 3), (0x1f0f, 1703, 3), (0x1f10, 1706, 2), (0x1f11, 1708, 2),
+    (0x1f12, 1710, 3), (0x1f13, 1713, 3), (0x1f14, 1716, 3), (0x1f15, 1719, 3), (0x1f18, 1722, 2),
+    (0x1f19, 1724, 2), (0x1f1a, 1726, 3), (0x1f1b, 1729, 3), (0x1f1c, 1732, 3), (0x1f1d, 1735, 3),
+    (0x1f20, 1738, 2), (0x1f21, 1740, 2), (0x1f22, 1742, 3), (0x1f23, 1745, 3), (0x1f24, 1748, 3),
+    (0x1f25, 1751, 3), (0x1f26, 1754, 3), (0x1f27, 1757, 3), (0x1f28, 1760, 2), (0x1f29, 1762, 2),
+    (0x1f2a, 1764, 3), (0x1f2b, 1767, 3), (0x1f2c, 1770, 3), (0x1f2d, 1773, 3), (0x1f2e, 1776, 3),
+    (0x1f2f, 1779, 3), (0x1f30, 1782, 2), (0x1f31, 1784, 2), (0x1f32, 1786, 3), (0x1f33, 1789, 3),
+    (0x1f34, 1792, 3), (0x1f35, 1795, 3), (0x1f36, 1798, 3), (0x1f37, 1801, 3), (0x1f38, 1804, 2),
+    (0x1f39, 1806, 2), (0x1f3a, 1808, 3), (0x1f3b, 1811, 3), (0x1f3c, 1814, 3), (0x1f3d, 1817, 3),
+    (0x1f3e, 1820, 3), (0x1f3f, 1823, 3), (0x1f40, 1826, 2), (0x1f41, 1828, 2), (0x1f42, 1830, 3),
+    (0x1f43, 1833, 3), (0x1f44, 1836, 3), (0x1f45, 1839, 3), (0x1f48, 1842, 2), (0x1f49, 1844, 2),
+    (0x1f4a, 1846, 3), (0x1f4b, 1849, 3), (0x1f4c, 1852, 3), (0x1f4d, 1855, 3), (0x1f50, 1858, 2),
+    (0x1f51, 1860, 2), (0x1f52, 1862, 3), (0x1f53, 1865, 3), (0x1f54, 1868, 3), (0x1f55, 1871, 3),
+    (0x1f56, 1874, 3), (0x1f57, 1877, 3), (0x1f59, 1880, 2), (0x1f5b, 1882, 3), (0x1f5d, 1885, 3),
+    (0x1f5f, 1888, 3), (0x1f60, 1891, 2), (0x1f61, 1893, 2), (0x1f62, 1895, 3), (0x1f63, 1898, 3),
+    (0x1f64, 1901, 3), (0x1f65, 1904, 3), (0x1f66, 1907, 3), (0x1f67, 1910, 3), (0x1f68, 1913, 2),
+    (0x1f69, 1915, 2), (0x1f6a, 1917, 3), (0x1f6b, 1920, 3), (0x1f6c, 1923, 3), (0x1f6d, 1926, 3),
+    (0x1f6e, 1929, 3), (0x1f6f, 1932, 3), (0x1f70, 1935, 2), (0x1f71, 1937, 2), (0x1f72, 1939, 2),
+    (0x1f73, 1941, 2), (0x1f74, 1943, 2), (0x1f75, 1945, 2), (0x1f76, 1947, 2), (0x1f77, 1949, 2),
+    (0x1f78, 1951, 2), (0x1f79, 1953, 2), (0x1f7a, 1955, 2), (0x1f7b, 1957, 2), (0x1f7c, 1959, 2),
+    (0x1f7d, 1961, 2), (0x1f80, 1963, 3), (0x1f81, 1966, 3), (0x1f82, 1969, 4), (0x1f83, 1973, 4),
+    (0x1f84, 1977, 4), (0x1f85, 1981, 4), (0x1f86, 1985, 4), (0x1f87, 1989, 4), (0x1f88, 1993, 3),
+    (0x1f89, 1996, 3), (0x1f8a, 1999, 4), (0x1f8b, 2003, 4), (0x1f8c, 2007, 4), (0x1f8d, 2011, 4),
+    (0x1f8e, 2015, 4), (0x1f8f, 2019, 4), (0x1f90, 2023, 3), (0x1f91, 2026, 3), (0x1f92, 2029, 4),
+    (0x1f93, 2033, 4), (0x1f94, 2037, 4), (0x1f95, 2041, 4), (0x1f96, 2045, 4), (0x1f97, 2049, 4),
+    (0x1f98, 2053, 3), (0x1f99, 2056, 3), (0x1f9a, 2059, 4), (0x1f9b, 2063, 4), (0x1f9c, 2067, 4),
+    (0x1f9d, 2071, 4), (0x1f9e, 2075, 4), (0x1f9f, 2079, 4), (0x1fa0, 2083, 3), (0x1fa1, 2086, 3),
+    (0x1fa2, 2089, 4), (0x1fa3, 2093, 4), (0x1fa4, 2097, 4), (0x1fa5, 2101, 4), (0x1fa6, 2105, 4),
+    (0x1fa7, 2109, 4), (0x1fa8, 2113, 3), (0x1fa9, 2116, 3), (0x1faa, 2119, 4), (0x1fab, 2123, 4),
+    (0x1fac, 2127, 4), (0x1fad, 2131, 4), (0x1fae, 2135, 4), (0x1faf, 2139, 4), (0x1fb0, 2143, 2),
+    (0x1fb1, 2145, 2), (0x1fb2, 2147, 3), (0x1fb3, 2150, 2), (0x1fb4, 2152, 3), (0x1fb6, 2155, 2),
+    (0x1fb7, 2157, 3), (0x1fb8, 2160, 2), (0x1fb9, 2162, 2), (0x1fba, 2164, 2), (0x1fbb, 2166, 2),
+    (0x1fbc, 2168, 2), (0x1fbd, 2170, 2), (0x1fbe, 2172, 1), (0x1fbf, 2173, 2), (0x1fc0, 2175, 2),
+    (0x1fc1, 2177, 3), (0x1fc2, 2180, 3), (0x1fc3, 2183, 2), (0x1fc4, 2185, 3), (0x1fc6, 2188, 2),
+    (0x1fc7, 2190, 3), (0x1fc8, 2193, 2), (0x1fc9, 2195, 2), (0x1fca, 2197, 2), (0x1fcb, 2199, 2),
+    (0x1fcc, 2201, 2), (0x1fcd, 2203, 3), (0x1fce, 2206, 3), (0x1fcf, 2209, 3), (0x1fd0, 2212, 2),
+    (0x1fd1, 2214, 2), (0x1fd2, 2216, 3), (0x1fd3, 2219, 3), (0x1fd6, 2222, 2), (0x1fd7, 2224, 3),
+    (0x1fd8, 2227, 2), (0x1fd9, 2229, 2), (0x1fda, 2231, 2), (0x1fdb, 2233, 2), (0x1fdd, 2235, 3),
+    (0x1fde, 2238, 3), (0x1fdf, 2241, 3), (0x1fe0, 2244, 2), (0x1fe1, 2246, 2), (0x1fe2, 2248, 3),
+    (0x1fe3, 2251, 3), (0x1fe4, 2254, 2), (0x1fe5, 2256, 2), (0x1fe6, 2258, 2), (0x1fe7, 2260, 3),
+    (0x1fe8, 2263, 2), (0x1fe9, 2265, 2), (0x1fea, 2267, 2), (0x1feb, 2269, 2), (0x1fec, 2271, 2),
+    (0x1fed, 2273, 3), (0x1fee, 2276, 3), (0x1fef, 2279, 1), (0x1ff2, 2280, 3), (0x1ff3, 2283, 2),
+    (0x1ff4, 2285, 3), (0x1ff6, 2288, 2), (0x1ff7, 2290, 3), (0x1ff8, 2293, 2), (0x1ff9, 2295, 2),
+    (0x1ffa, 2297, 2), (0x1ffb, 2299, 2), (0x1ffc, 2301, 2), (0x1ffd, 2303, 2), (0x1ffe, 2305, 2),
+    (0x2000, 2307, 1), (0x2001, 2308, 1), (0x2002, 2309, 1), (0x2003, 2310, 1), (0x2004, 2311, 1),
+    (0x2005, 2312, 1), (0x2006, 2313, 1), (0x2007, 2314, 1), (0x2008, 2315, 1), (0x2009, 2316, 1),
+    (0x200a, 2317, 1), (0x2011, 2318, 1), (0x2017, 2319, 2), (0x2024, 2321, 1), (0x2025, 2322, 2),
+    (0x2026, 2324, 3), (0x202f, 2327, 1), (0x2033, 2328, 2), (0x2034, 2330, 3), (0x2036, 2333, 2),
+    (0x2037, 2335, 3), (0x203c, 2338, 2), (0x203e, 2340, 2), (0x2047, 2342, 2), (0x2048, 2344, 2),
+    (0x2049, 2346, 2), (0x2057, 2348, 4), (0x205f, 2352, 1), (0x2070, 2353, 1), (0x2071, 2354, 1),
+    (0x2074, 2355, 1), (0x2075, 2356, 1), (0x2076, 2357, 1), (0x2077, 2358, 1), (0x2078, 2359, 1),
+    (0x2079, 2360, 1), (0x207a, 2361, 1), (0x207b, 2362, 1), (0x207c, 2363, 1), (0x207d, 2364, 1),
+    (0x207e, 2365, 1), (0x207f, 2366, 1), (0x2080, 2367, 1), (0x2081, 2368, 1), (0x2082, 2369, 1),
+    (0x2083, 2370, 1), (0x2084, 2371, 1), (0x2085, 2372, 1), (0x2086, 2373, 1), (0x2087, 2374, 1),
+    (0x2088, 2375, 1), (0x2089, 2376, 1), (0x208a, 2377, 1), (0x208b, 2378, 1), (0x208c, 2379, 1),
+    (0x208d, 2380, 1), (0x208e, 2381, 1), (0x2090, 2382, 1), (0x2091, 2383, 1), (0x2092, 2384, 1),
+    (0x2093, 2385, 1), (0x2094, 2386, 1), (0x2095, 2387, 1), (0x2096, 2388, 1), (0x2097, 2389, 1),
+    (0x2098, 2390, 1), (0x2099, 2391, 1), (0x209a, 2392, 1), (0x209b, 2393, 1), (0x209c, 2394, 1),
+    (0x20a8, 2395, 2), (0x2100, 2397, 3), (0x2101, 2400, 3), (0x2102, 2403, 1), (0x2103, 2404, 2),
+    (0x2105, 2406, 3), (0x2106, 2409, 3), (0x2107, 2412, 1), (0x2109, 2413, 2), (0x210a, 2415, 1),
+    (0x210b, 2416, 1), (0x210c, 2417, 1), (0x210d, 2418, 1), (0x210e, 2419, 1), (0x210f, 2420, 1),
+    (0x2110, 2421, 1), (0x2111, 2422, 1), (0x2112, 2423, 1), (0x2113, 2424, 1), (0x2115, 2425, 1),
+    (0x2116, 2426, 2), (0x2119, 2428, 1), (0x211a, 2429, 1), (0x211b, 2430, 1), (0x211c, 2431, 1),
+    (0x211d, 2432, 1), (0x2120, 2433, 2), (0x2121, 2435, 3), (0x2122, 2438, 2), (0x2124, 2440, 1),
+    (0x2126, 2441, 1), (0x2128, 2442, 1), (0x212a, 2443, 1), (0x212b, 2444, 2), (0x212c, 2446, 1),
+    (0x212d, 2447, 1), (0x212f, 2448, 1), (0x2130, 2449, 1), (0x2131, 2450, 1), (0x2133, 2451, 1),
+    (0x2134, 2452, 1), (0x2135, 2453, 1), (0x2136, 2454, 1), (0x2137, 2455, 1), (0x2138, 2456, 1),
+    (0x2139, 2457, 1), (0x213b, 2458, 3), (0x213c, 2461, 1), (0x213d, 2462, 1), (0x213e, 2463, 1),
+    (0x213f, 2464, 1), (0x2140, 2465, 1), (0x2145, 2466, 1), (0x2146, 2467, 1), (0x2147, 2468, 1),
+    (0x2148, 2469, 1), (0x2149, 2470, 1), (0x2150, 2471, 3), (0x2151, 2474, 3), (0x2152, 2477, 4),
+    (0x2153, 2481, 3), (0x2154, 2484, 3), (0x2155, 2487, 3), (0x2156, 2490, 3), (0x2157, 2493, 3),
+    (0x2158, 2496, 3), (0x2159, 2499, 3), (0x215a, 2502, 3), (0x215b, 2505, 3), (0x215c, 2508, 3),
+    (0x215d, 2511, 3), (0x215e, 2514, 3), (0x215f, 2517, 2), (0x2160, 2519, 1), (0x2161, 2520, 2),
+    (0x2162, 2522, 3), (0x2163, 2525, 2), (0x2164, 2527, 1), (0x2165, 2528, 2), (0x2166, 2530, 3),
+    (0x2167, 2533, 4), (0x2168, 2537, 2), (0x2169, 2539, 1), (0x216a, 2540, 2), (0x216b, 2542, 3),
+    (0x216c, 2545, 1), (0x216d, 2546, 1), (0x216e, 2547, 1), (0x216f, 2548, 1), (0x2170, 2549, 1),
+    (0x2171, 2550, 2), (0x2172, 2552, 3), (0x2173, 2555, 2), (0x2174, 2557, 1), (0x2175, 2558, 2),
+    (0x2176, 2560, 3), (0x2177, 2563, 4), (0x2178, 2567, 2), (0x2179, 2569, 1), (0x217a, 2570, 2),
+    (0x217b, 2572, 3), (0x217c, 2575, 1), (0x217d, 2576, 1), (0x217e, 2577, 1), (0x217f, 2578, 1),
+    (0x2189, 2579, 3), (0x219a, 2582, 2), (0x219b, 2584, 2), (0x21ae, 2586, 2), (0x21cd, 2588, 2),
+    (0x21ce, 2590, 2), (0x21cf, 2592, 2), (0x2204, 2594, 2), (0x2209, 2596, 2), (0x220c, 2598, 2),
+    (0x2224, 2600, 2), (0x2226, 2602, 2), (0x222c, 2604, 2), (0x222d, 2606, 3), (0x222f, 2609, 2),
+    (0x2230, 2611, 3), (0x2241, 2614, 2), (0x2244, 2616, 2), (0x2247, 2618, 2), (0x2249, 2620, 2),
+    (0x2260, 2622, 2), (0x2262, 2624, 2), (0x226d, 2626, 2), (0x226e, 2628, 2), (0x226f, 2630, 2),
+    (0x2270, 2632, 2), (0x2271, 2634, 2), (0x2274, 2636, 2), (0x2275, 2638, 2), (0x2278, 2640, 2),
+    (0x2279, 2642, 2), (0x2280, 2644, 2), (0x2281, 2646, 2), (0x2284, 2648, 2), (0x2285, 2650, 2),
+    (0x2288, 2652, 2), (0x2289, 2654, 2), (0x22ac, 2656, 2), (0x22ad, 2658, 2), (0x22ae, 2660, 2),
+    (0x22af, 2662, 2), (0x22e0, 2664, 2), (0x22e1, 2666, 2), (0x22e2, 2668, 2), (0x22e3, 2670, 2),
+    (0x22ea, 2672, 2), (0x22eb, 2674, 2), (0x22ec, 2676, 2), (0x22ed, 2678, 2), (0x2329, 2680, 1),
+    (0x232a, 2681, 1), (0x2460, 2682, 1), (0x2461, 2683, 1), (0x2462, 2684, 1), (0x2463, 2685, 1),
+    (0x2464, 2686, 1), (0x2465, 2687, 1), (0x2466, 2688, 1), (0x2467, 2689, 1), (0x2468, 2690, 1),
+    (0x2469, 2691, 2), (0x246a, 2693, 2), (0x246b, 2695, 2), (0x246c, 2697, 2), (0x246d, 2699, 2),
+    (0x246e, 2701, 2), (0x246f, 2703, 2), (0x2470, 2705, 2), (0x2471, 2707, 2), (0x2472, 2709, 2),
+    (0x2473, 2711, 2), (0x2474, 2713, 3), (0x2475, 2716, 3), (0x2476, 2719, 3), (0x2477, 2722, 3),
+    (0x2478, 2725, 3), (0x2479, 2728, 3), (0x247a, 2731, 3), (0x247b, 2734, 3), (0x247c, 2737, 3),
+    (0x247d, 2740, 4), (0x247e, 2744, 4), (0x247f, 2748, 4), (0x2480, 2752, 4), (0x2481, 2756, 4),
+    (0x2482, 2760, 4), (0x2483, 2764, 4), (0x2484, 2768, 4), (0x2485, 2772, 4), (0x2486, 2776, 4),
+    (0x2487, 2780, 4), (0x2488, 2784, 2), (0x2489, 2786, 2), (0x248a, 2788, 2), (0x248b, 2790, 2),
+    (0x248c, 2792, 2), (0x248d, 2794, 2), (0x248e, 2796, 2), (0x248f, 2798, 2), (0x2490, 2800, 2),
+    (0x2491, 2802, 3), (0x2492, 2805, 3), (0x2493, 2808, 3), (0x2494, 2811, 3), (0x2495, 2814, 3),
+    (0x2496, 2817, 3), (0x2497, 2820, 3), (0x2498, 2823, 3), (0x2499, 2826, 3), (0x249a, 2829, 3),
+    (0x249b, 2832, 3), (0x249c, 2835, 3), (0x249d, 2838, 3), (0x249e, 2841, 3), (0x249f, 2844, 3),
+    (0x24a0, 2847, 3), (0x24a1, 2850, 3), (0x24a2, 2853, 3), (0x24a3, 2856, 3), (0x24a4, 2859, 3),
+    (0x24a5, 2862, 3), (0x24a6, 2865, 3), (0x24a7, 2868, 3), (0x24a8, 2871, 3), (0x24a9, 2874, 3),
+    (0x24aa, 2877, 3), (0x24ab, 2880, 3), (0x24ac, 2883, 3), (0x24ad, 2886, 3), (0x24ae, 2889, 3),
+    (0x24af, 2892, 3), (0x24b0, 2895, 3), (0x24b1, 2898, 3), (0x24b2, 2901, 3), (0x24b3, 2904, 3),
+    (0x24b4, 2907, 3), (0x24b5, 2910, 3), (0x24b6, 2913, 1), (0x24b7, 2914, 1), (0x24b8, 2915, 1),
+    (0x24b9, 2916, 1), (0x24ba, 2917, 1), (0x24bb, 2918, 1), (0x24bc, 2919, 1), (0x24bd, 2920, 1),
+    (0x24be, 2921, 1), (0x24bf, 2922, 1), (0x24c0, 2923, 1), (0x24c1, 2924, 1), (0x24c2, 2925, 1),
+    (0x24c3, 2926, 1), (0x24c4, 2927, 1), (0x24c5, 2928, 1), (0x24c6, 2929, 1), (0x24c7, 2930, 1),
+    (0x24c8, 2931, 1), (0x24c9, 2932, 1), (0x24ca, 2933, 1), (0x24cb, 2934, 1), (0x24cc, 2935, 1),
+    (0x24cd, 2936, 1), (0x24ce, 2937, 1), (0x24cf, 2938, 1), (0x24d0, 2939, 1), (0x24d1, 2940, 1),
+    (0x24d2, 2941, 1), (0x24d3, 2942, 1), (0x24d4, 2943, 1), (0x24d5, 2944, 1), (0x24d6, 2945, 1),
+    (0x24d7, 2946, 1), (0x24d8, 2947, 1), (0x24d9, 2948, 1), (0x24da, 2949, 1), (0x24db, 2950, 1),
+    (0x24dc, 2951, 1), (0x24dd, 2952, 1), (0x24de, 2953, 1), (0x24df, 2954, 1), (0x24e0, 2955, 1),
+    (0x24e1, 2956, 1), (0x24e2, 2957, 1), (0x24e3, 2958, 1), (0x24e4, 2959, 1), (0x24e5, 2960, 1),
+    (0x24e6, 2961, 1), (0x24e7, 2962, 1), (0x24e8, 2963, 1), (0x24e9, 2964, 1), (0x24ea, 2965, 1),
+    (0x2a0c, 2966, 4), (0x2a74, 2970, 3), (0x2a75, 2973, 2), (0x2a76, 2975, 3), (0x2adc, 2978, 2),
+    (0x2c7c, 2980, 1), (0x2c7d, 2981, 1), (0x2d6f, 2982, 1), (0x2e9f, 2983, 1), (0x2ef3, 2984, 1),
+    (0x2f00, 2985, 1), (0x2f01, 2986, 1), (0x2f02, 2987, 1), (0x2f03, 2988, 1), (0x2f04, 2989, 1),
+    (0x2f05, 2990, 1), (0x2f06, 2991, 1), (0x2f07, 2992, 1), (0x2f08, 2993, 1), (0x2f09, 2994, 1),
+    (0x2f0a, 2995, 1), (0x2f0b, 2996, 1), (0x2f0c, 2997, 1), (0x2f0d, 2998, 1), (0x2f0e, 2999, 1),
+    (0x2f0f, 3000, 1), (0x2f10, 3001, 1), (0x2f11, 3002, 1), (0x2f12, 3003, 1), (0x2f13, 3004, 1),
+    (0x2f14, 3005, 1), (0x2f15, 3006, 1), (0x2f16, 3007, 1), (0x2f17, 3008, 1), (0x2f18, 3009, 1),
+    (0x2f19, 3010, 1), (0x2f1a, 3011, 1), (0x2f1b, 3012, 1), (0x2f1c, 3013, 1), (0x2f1d, 3014, 1),
+    (0x2f1e, 3015, 1), (0x2f1f, 3016, 1), (0x2f20, 3017, 1), (0x2f21, 3018, 1), (0x2f22, 3019, 1),
+    (0x2f23, 3020, 1), (0x2f24, 3021, 1), (0x2f25, 3022, 1), (0x2f26, 3023, 1), (0x2f27, 3024, 1),
+    (0x2f28, 3025, 1), (0x2f29, 3026, 1), (0x2f2a, 3027, 1), (0x2f2b, 3028, 1), (0x2f2c, 3029, 1),
+    (0x2f2d, 3030, 1), (0x2f2e, 3031, 1), (0x2f2f, 3032, 1), (0x2f30, 3033, 1), (0x2f31, 3034, 1),
+    (0x2f32, 3035, 1), (0x2f33, 3036, 1), (0x2f34, 3037, 1), (0x2f35, 3038, 1), (0x2f36, 3039, 1),
+    (0x2f37, 3040, 1), (0x2f38, 3041, 1), (0x2f39, 3042, 1), (0x2f3a, 3043, 1), (0x2f3b, 3044, 1),
+    (0x2f3c, 3045, 1), (0x2f3d, 3046, 1), (0x2f3e, 3047, 1), (0x2f3f, 3048, 1), (0x2f40, 3049, 1),
+    (0x2f41, 3050, 1), (0x2f42, 3051, 1), (0x2f43, 3052, 1), (0x2f44, 3053, 1), (0x2f45, 3054, 1),
+    (0x2f46, 3055, 1), (0x2f47, 3056, 1), (0x2f48, 3057, 1), (0x2f49, 3058, 1), (0x2f4a, 3059, 1),
+    (0x2f4b, 3060, 1), (0x2f4c, 3061, 1), (0x2f4d, 3062, 1), (0x2f4e, 3063, 1), (0x2f4f, 3064, 1),
+    (0x2f50, 3065, 1), (0x2f51, 3066, 1), (0x2f52, 3067, 1), (0x2f53, 3068, 1), (0x2f54, 3069, 1),
+    (0x2f55, 3070, 1), (0x2f56, 3071, 1), (0x2f57, 3072, 1), (0x2f58, 3073, 1), (0x2f59, 3074, 1),
+    (0x2f5a, 3075, 1), (0x2f5b, 3076, 1), (0x2f5c, 3077, 1), (0x2f5d, 3078, 1), (0x2f5e, 3079, 1),
+    (0x2f5f, 3080, 1), (0x2f60, 3081, 1), (0x2f61, 3082, 1), (0x2f62, 3083, 1), (0x2f63, 3084, 1),
+    (0x2f64, 3085, 1), (0x2f65, 3086, 1), (0x2f66, 3087, 1), (0x2f67, 3088, 1), (0x2f68, 3089, 1),
+    (0x2f69, 3090, 1), (0x2f6a, 3091, 1), (0x2f6b, 3092, 1), (0x2f6c, 3093, 1), (0x2f6d, 3094, 1),
+    (0x2f6e, 3095, 1), (0x2f6f, 3096, 1), (0x2f70, 3097, 1), (0x2f71, 3098, 1), (0x2f72, 3099, 1),
+    (0x2f73, 3100, 1), (0x2f74, 3101, 1), (0x2f75, 3102, 1), (0x2f76, 3103, 1), (0x2f77, 3104, 1),
+    (0x2f78, 3105, 1), (0x2f79, 3106, 1), (0x2f7a, 3107, 1), (0x2f7b, 3108, 1), (0x2f7c, 3109, 1),
+    (0x2f7d, 3110, 1), (0x2f7e, 3111, 1), (0x2f7f, 3112, 1), (0x2f80, 3113, 1), (0x2f81, 3114, 1),
+    (0x2f82, 3115, 1), (0x2f83, 3116, 1), (0x2f84, 3117, 1), (0x2f85, 3118, 1), (0x2f86, 3119, 1),
+    (0x2f87, 3120, 1), (0x2f88, 3121, 1), (0x2f89, 3122, 1), (0x2f8a, 3123, 1), (0x2f8b, 3124, 1),
+    (0x2f8c, 3125, 1), (0x2f8d, 3126, 1), (0x2f8e, 3127, 1), (0x2f8f, 3128, 1), (0x2f90, 3129, 1),
+    (0x2f91, 3130, 1), (0x2f92, 3131, 1), (0x2f93, 3132, 1), (0x2f94, 3133, 1), (0x2f95, 3134, 1),
+    (0x2f96, 3135, 1), (0x2f97, 3136, 1), (0x2f98, 3137, 1), (0x2f99, 3138, 1), (0x2f9a, 3139, 1),
+    (0x2f9b, 3140, 1), (0x2f9c, 3141, 1), (0x2f9d, 3142, 1), (0x2f9e, 3143, 1), (0x2f9f, 3144, 1),
+    (0x2fa0, 3145, 1), (0x2fa1, 3146, 1), (0x2fa2, 3147, 1), (0x2fa3, 3148, 1), (0x2fa4, 3149, 1),
+    (0x2fa5, 3150, 1), (0x2fa6, 3151, 1), (0x2fa7, 3152, 1), (0x2fa8, 3153, 1), (0x2fa9, 3154, 1),
+    (0x2faa, 3155, 1), (0x2fab, 3156, 1), (0x2fac, 3157, 1), (0x2fad, 3158, 1), (0x2fae, 3159, 1),
+    (0x2faf, 3160, 1), (0x2fb0, 3161, 1), (0x2fb1, 3162, 1), (0x2fb2, 3163, 1), (0x2fb3, 3164, 1),
+    (0x2fb4, 3165, 1), (0x2fb5, 3166, 1), (0x2fb6, 3167, 1), (0x2fb7, 3168, 1), (0x2fb8, 3169, 1),
+    (0x2fb9, 3170, 1), (0x2fba, 3171, 1), (0x2fbb, 3172, 1), (0x2fbc, 3173, 1), (0x2fbd, 3174, 1),
+    (0x2fbe, 3175, 1), (0x2fbf, 3176, 1), (0x2fc0, 3177, 1), (0x2fc1, 3178, 1), (0x2fc2, 3179, 1),
+    (0x2fc3, 3180, 1), (0x2fc4, 3181, 1), (0x2fc5, 3182, 1), (0x2fc6, 3183, 1), (0x2fc7, 3184, 1),
+    (0x2fc8, 3185, 1), (0x2fc9, 3186, 1), (0x2fca, 3187, 1), (0x2fcb, 3188, 1), (0x2fcc, 3189, 1),
+    (0x2fcd, 3190, 1), (0x2fce, 3191, 1), (0x2fcf, 3192, 1), (0x2fd0, 3193, 1), (0x2fd1, 3194, 1),
+    (0x2fd2, 3195, 1), (0x2fd3, 3196, 1), (0x2fd4, 3197, 1), (0x2fd5, 3198, 1), (0x3000, 3199, 1),
+    (0x3036, 3200, 1), (0x3038, 3201, 1), (0x3039, 3202, 1), (0x303a, 3203, 1), (0x304c, 3204, 2),
+    (0x304e, 3206, 2), (0x3050, 3208, 2), (0x3052, 3210, 2), (0x3054, 3212, 2), (0x3056, 3214, 2),
+    (0x3058, 3216, 2), (0x305a, 3218, 2), (0x305c, 3220, 2), (0x305e, 3222, 2), (0x3060, 3224, 2),
+    (0x3062, 3226, 2), (0x3065, 3228, 2), (0x3067, 3230, 2), (0x3069, 3232, 2), (0x3070, 3234, 2),
+    (0x3071, 3236, 2), (0x3073, 3238, 2), (0x3074, 3240, 2), (0x3076, 3242, 2), (0x3077, 3244, 2),
+    (0x3079, 3246, 2), (0x307a, 3248, 2), (0x307c, 3250, 2), (0x307d, 3252, 2), (0x3094, 3254, 2),
+    (0x309b, 3256, 2), (0x309c, 3258, 2), (0x309e, 3260, 2), (0x309f, 3262, 2), (0x30ac, 3264, 2),
+    (0x30ae, 3266, 2), (0x30b0, 3268, 2), (0x30b2, 3270, 2), (0x30b4, 3272, 2), (0x30b6, 3274, 2),
+    (0x30b8, 3276, 2), (0x30ba, 3278, 2), (0x30bc, 3280, 2), (0x30be, 3282, 2), (0x30c0, 3284, 2),
+    (0x30c2, 3286, 2), (0x30c5, 3288, 2), (0x30c7, 3290, 2), (0x30c9, 3292, 2), (0x30d0, 3294, 2),
+    (0x30d1, 3296, 2), (0x30d3, 3298, 2), (0x30d4, 3300, 2), (0x30d6, 3302, 2), (0x30d7, 3304, 2),
+    (0x30d9, 3306, 2), (0x30da, 3308, 2), (0x30dc, 3310, 2), (0x30dd, 3312, 2), (0x30f4, 3314, 2),
+    (0x30f7, 3316, 2), (0x30f8, 3318, 2), (0x30f9, 3320, 2), (0x30fa, 3322, 2), (0x30fe, 3324, 2),
+    (0x30ff, 3326, 2), (0x3131, 3328, 1), (0x3132, 3329, 1), (0x3133, 3330, 1), (0x3134, 3331, 1),
+    (0x3135, 3332, 1), (0x3136, 3333, 1), (0x3137, 3334, 1), (0x3138, 3335, 1), (0x3139, 3336, 1),
+    (0x313a, 3337, 1), (0x313b, 3338, 1), (0x313c, 3339, 1), (0x313d, 3340, 1), (0x313e, 3341, 1),
+    (0x313f, 3342, 1), (0x3140, 3343, 1), (0x3141, 3344, 1), (0x3142, 3345, 1), (0x3143, 3346, 1),
+    (0x3144, 3347, 1), (0x3145, 3348, 1), (0x3146, 3349, 1), (0x3147, 3350, 1), (0x3148, 3351, 1),
+    (0x3149, 3352, 1), (0x314a, 3353, 1), (0x314b, 3354, 1), (0x314c, 3355, 1), (0x314d, 3356, 1),
+    (0x314e, 3357, 1), (0x314f, 3358, 1), (0x3150, 3359, 1), (0x3151, 3360, 1), (0x3152, 3361, 1),
+    (0x3153, 3362, 1), (0x3154, 3363, 1), (0x3155, 3364, 1), (0x3156, 3365, 1), (0x3157, 3366, 1),
+    (0x3158, 3367, 1), (0x3159, 3368, 1), (0x315a, 3369, 1), (0x315b, 3370, 1), (0x315c, 3371, 1),
+    (0x315d, 3372, 1), (0x315e, 3373, 1), (0x315f, 3374, 1), (0x3160, 3375, 1), (0x3161, 3376, 1),
+    (0x3162, 3377, 1), (0x3163, 3378, 1), (0x3164, 3379, 1), (0x3165, 3380, 1), (0x3166, 3381, 1),
+    (0x3167, 3382, 1), (0x3168, 3383, 1), (0x3169, 3384, 1), (0x316a, 3385, 1), (0x316b, 3386, 1),
+    (0x316c, 3387, 1), (0x316d, 3388, 1), (0x316e, 3389, 1), (0x316f, 3390, 1), (0x3170, 3391, 1),
+    (0x3171, 3392, 1), (0x3172, 3393, 1), (0x3173, 3394, 1), (0x3174, 3395, 1), (0x3175, 3396, 1),
+    (0x3176, 3397, 1), (0x3177, 3398, 1), (0x3178, 3399, 1), (0x3179, 3400, 1), (0x317a, 3401, 1),
+    (0x317b, 3402, 1), (0x317c, 3403, 1), (0x317d, 3404, 1), (0x317e, 3405, 1), (0x317f, 3406, 1),
+    (0x3180, 3407, 1), (0x3181, 3408, 1), (0x3182, 3409, 1), (0x3183, 3410, 1), (0x3184, 3411, 1),
+    (0x3185, 3412, 1), (0x3186, 3413, 1), (0x3187, 3414, 1), (0x3188, 3415, 1), (0x3189, 3416, 1),
+    (0x318a, 3417, 1), (0x318b, 3418, 1), (0x318c, 3419, 1), (0x318d, 3420, 1), (0x318e, 3421, 1),
+    (0x3192, 3422, 1), (0x3193, 3423, 1), (0x3194, 3424, 1), (0x3195, 3425, 1), (0x3196, 3426, 1),
+    (0x3197, 3427, 1), (0x3198, 3428, 1), (0x3199, 3429, 1), (0x319a, 3430, 1), (0x319b, 3431, 1),
+    (0x319c, 3432, 1), (0x319d, 3433, 1), (0x319e, 3434, 1), (0x319f, 3435, 1), (0x3200, 3436, 3),
+    (0x3201, 3439, 3), (0x3202, 3442, 3), (0x3203, 3445, 3), (0x3204, 3448, 3), (0x3205, 3451, 3),
+    (0x3206, 3454, 3), (0x3207, 3457, 3), (0x3208, 3460, 3), (0x3209, 3463, 3), (0x320a, 3466, 3),
+    (0x320b, 3469, 3), (0x320c, 3472, 3), (0x320d, 3475, 3), (0x320e, 3478, 4), (0x320f, 3482, 4),
+    (0x3210, 3486, 4), (0x3211, 3490, 4), (0x3212, 3494, 4), (0x3213, 3498, 4), (0x3214, 3502, 4),
+    (0x3215, 3506, 4), (0x3216, 3510, 4), (0x3217, 3514, 4), (0x3218, 3518, 4), (0x3219, 3522, 4),
+    (0x321a, 3526, 4), (0x321b, 3530, 4), (0x321c, 3534, 4), (0x321d, 3538, 7), (0x321e, 3545, 6),
+    (0x3220, 3551, 3), (0x3221, 3554, 3), (0x3222, 3557, 3), (0x3223, 3560, 3), (0x3224, 3563, 3),
+    (0x3225, 3566, 3), (0x3226, 3569, 3), (0x3227, 3572, 3), (0x3228, 3575, 3), (0x3229, 3578, 3),
+    (0x322a, 3581, 3), (0x322b, 3584, 3), (0x322c, 3587, 3), (0x322d, 3590, 3), (0x322e, 3593, 3),
+    (0x322f, 3596, 3), (0x3230, 3599, 3), (0x3231, 3602, 3), (0x3232, 3605, 3), (0x3233, 3608, 3),
+    (0x3234, 3611, 3), (0x3235, 3614, 3), (0x3236, 3617, 3), (0x3237, 3620, 3), (0x3238, 3623, 3),
+    (0x3239, 3626, 3), (0x323a, 3629, 3), (0x323b, 3632, 3), (0x323c, 3635, 3), (0x323d, 3638, 3),
+    (0x323e, 3641, 3), (0x323f, 3644, 3), (0x3240, 3647, 3), (0x3241, 3650, 3), (0x3242, 3653, 3),
+    (0x3243, 3656, 3), (0x3244, 3659, 1), (0x3245, 3660, 1), (0x3246, 3661, 1), (0x3247, 3662, 1),
+    (0x3250, 3663, 3), (0x3251, 3666, 2), (0x3252, 3668, 2), (0x3253, 3670, 2), (0x3254, 3672, 2),
+    (0x3255, 3674, 2), (0x3256, 3676, 2), (0x3257, 3678, 2), (0x3258, 3680, 2), (0x3259, 3682, 2),
+    (0x325a, 3684, 2), (0x325b, 3686, 2), (0x325c, 3688, 2), (0x325d, 3690, 2), (0x325e, 3692, 2),
+    (0x325f, 3694, 2), (0x3260, 3696, 1), (0x3261, 3697, 1), (0x3262, 3698, 1), (0x3263, 3699, 1),
+    (0x3264, 3700, 1), (0x3265, 3701, 1), (0x3266, 3702, 1), (0x3267, 3703, 1), (0x3268, 3704, 1),
+    (0x3269, 3705, 1), (0x326a, 3706, 1), (0x326b, 3707, 1), (0x326c, 3708, 1), (0x326d, 3709, 1),
+    (0x326e, 3710, 2), (0x326f, 3712, 2), (0x3270, 3714, 2), (0x3271, 3716, 2), (0x3272, 3718, 2),
+    (0x3273, 3720, 2), (0x3274, 3722, 2), (0x3275, 3724, 2), (0x3276, 3726, 2), (0x3277, 3728, 2),
+    (0x3278, 3730, 2), (0x3279, 3732, 2), (0x327a, 3734, 2), (0x327b, 3736, 2), (0x327c, 3738, 5),
+    (0x327d, 3743, 4), (0x327e, 3747, 2), (0x3280, 3749, 1), (0x3281, 3750, 1), (0x3282, 3751, 1),
+    (0x3283, 3752, 1), (0x3284, 3753, 1), (0x3285, 3754, 1), (0x3286, 3755, 1), (0x3287, 3756, 1),
+    (0x3288, 3757, 1), (0x3289, 3758, 1), (0x328a, 3759, 1), (0x328b, 3760, 1), (0x328c, 3761, 1),
+    (0x328d, 3762, 1), (0x328e, 3763, 1), (0x328f, 3764, 1), (0x3290, 3765, 1), (0x3291, 3766, 1),
+    (0x3292, 3767, 1), (0x3293, 3768, 1), (0x3294, 3769, 1), (0x3295, 3770, 1), (0x3296, 3771, 1),
+    (0x3297, 3772, 1), (0x3298, 3773, 1), (0x3299, 3774, 1), (0x329a, 3775, 1), (0x329b, 3776, 1),
+    (0x329c, 3777, 1), (0x329d, 3778, 1), (0x329e, 3779, 1), (0x329f, 3780, 1), (0x32a0, 3781, 1),
+    (0x32a1, 3782, 1), (0x32a2, 3783, 1), (0x32a3, 3784, 1), (0x32a4, 3785, 1), (0x32a5, 3786, 1),
+    (0x32a6, 3787, 1), (0x32a7, 3788, 1), (0x32a8, 3789, 1), (0x32a9, 3790, 1), (0x32aa, 3791, 1),
+    (0x32ab, 3792, 1), (0x32ac, 3793, 1), (0x32ad, 3794, 1), (0x32ae, 3795, 1), (0x32af, 3796, 1),
+    (0x32b0, 3797, 1), (0x32b1, 3798, 2), (0x32b2, 3800, 2), (0x32b3, 3802, 2), (0x32b4, 3804, 2),
+    (0x32b5, 3806, 2), (0x32b6, 3808, 2), (0x32b7, 3810, 2), (0x32b8, 3812, 2), (0x32b9, 3814, 2),
+    (0x32ba, 3816, 2), (0x32bb, 3818, 2), (0x32bc, 3820, 2), (0x32bd, 3822, 2), (0x32be, 3824, 2),
+    (0x32bf, 3826, 2), (0x32c0, 3828, 2), (0x32c1, 3830, 2), (0x32c2, 3832, 2), (0x32c3, 3834, 2),
+    (0x32c4, 3836, 2), (0x32c5, 3838, 2), (0x32c6, 3840, 2), (0x32c7, 3842, 2), (0x32c8, 3844, 2),
+    (0x32c9, 3846, 3), (0x32ca, 3849, 3), (0x32cb, 3852, 3), (0x32cc, 3855, 2), (0x32cd, 3857, 3),
+    (0x32ce, 3860, 2), (0x32cf, 3862, 3), (0x32d0, 3865, 1), (0x32d1, 3866, 1), (0x32d2, 3867, 1),
+    (0x32d3, 3868, 1), (0x32d4, 3869, 1), (0x32d5, 3870, 1), (0x32d6, 3871, 1), (0x32d7, 3872, 1),
+    (0x32d8, 3873, 1), (0x32d9, 3874, 1), (0x32da, 3875, 1), (0x32db, 3876, 1), (0x32dc, 3877, 1),
+    (0x32dd, 3878, 1), (0x32de, 3879, 1), (0x32df, 3880, 1), (0x32e0, 3881, 1), (0x32e1, 3882, 1),
+    (0x32e2, 3883, 1), (0x32e3, 3884, 1), (0x32e4, 3885, 1), (0x32e5, 3886, 1), (0x32e6, 3887, 1),
+    (0x32e7, 3888, 1), (0x32e8, 3889, 1), (0x32e9, 3890, 1), (0x32ea, 3891, 1), (0x32eb, 3892, 1),
+    (0x32ec, 3893, 1), (0x32ed, 3894, 1), (0x32ee, 3895, 1), (0x32ef, 3896, 1), (0x32f0, 3897, 1),
+    (0x32f1, 3898, 1), (0x32f2, 3899, 1), (0x32f3, 3900, 1), (0x32f4, 3901, 1), (0x32f5, 3902, 1),
+    (0x32f6, 3903, 1), (0x32f7, 3904, 1), (0x32f8, 3905, 1), (0x32f9, 3906, 1), (0x32fa, 3907, 1),
+    (0x32fb, 3908, 1), (0x32fc, 3909, 1), (0x32fd, 3910, 1), (0x32fe, 3911, 1), (0x32ff, 3912, 2),
+    (0x3300, 3914, 5), (0x3301, 3919, 4), (0x3302, 3923, 5), (0x3303, 3928, 3), (0x3304, 3931, 5),
+    (0x3305, 3936, 3), (0x3306, 3939, 3), (0x3307, 3942, 6), (0x3308, 3948, 4), (0x3309, 3952, 3),
+    (0x330a, 3955, 3), (0x330b, 3958, 3), (0x330c, 3961, 4), (0x330d, 3965, 4), (0x330e, 3969, 4),
+    (0x330f, 3973, 4), (0x3310, 3977, 4), (0x3311, 3981, 4), (0x3312, 3985, 4), (0x3313, 3989, 6),
+    (0x3314, 3995, 2), (0x3315, 3997, 6), (0x3316, 4003, 6), (0x3317, 4009, 5), (0x3318, 4014, 4),
+    (0x3319, 4018, 6), (0x331a, 4024, 6), (0x331b, 4030, 4), (0x331c, 4034, 3), (0x331d, 4037, 3),
+    (0x331e, 4040, 4), (0x331f, 4044, 4), (0x3320, 4048, 5), (0x3321, 4053, 5), (0x3322, 4058, 3),
+    (0x3323, 4061, 3), (0x3324, 4064, 4), (0x3325, 4068, 3), (0x3326, 4071, 3), (0x3327, 4074, 2),
+    (0x3328, 4076, 2), (0x3329, 4078, 3), (0x332a, 4081, 3), (0x332b, 4084, 6), (0x332c, 4090, 4),
+    (0x332d, 4094, 5), (0x332e, 4099, 6), (0x332f, 4105, 4), (0x3330, 4109, 3), (0x3331, 4112, 3),
+    (0x3332, 4115, 6), (0x3333, 4121, 4), (0x3334, 4125, 6), (0x3335, 4131, 3), (0x3336, 4134, 5),
+    (0x3337, 4139, 3), (0x3338, 4142, 4), (0x3339, 4146, 3), (0x333a, 4149, 4), (0x333b, 4153, 5),
+    (0x333c, 4158, 4), (0x333d, 4162, 5), (0x333e, 4167, 4), (0x333f, 4171, 2), (0x3340, 4173, 5),
+    (0x3341, 4178, 3), (0x3342, 4181, 3), (0x3343, 4184, 4), (0x3344, 4188, 3), (0x3345, 4191, 3),
+    (0x3346, 4194, 3), (0x3347, 4197, 5), (0x3348, 4202, 4), (0x3349, 4206, 2), (0x334a, 4208, 6),
+    (0x334b, 4214, 3), (0x334c, 4217, 5), (0x334d, 4222, 4), (0x334e, 4226, 4), (0x334f, 4230, 3),
+    (0x3350, 4233, 3), (0x3351, 4236, 4), (0x3352, 4240, 2), (0x3353, 4242, 4), (0x3354, 4246, 5),
+    (0x3355, 4251, 2), (0x3356, 4253, 6), (0x3357, 4259, 3), (0x3358, 4262, 2), (0x3359, 4264, 2),
+    (0x335a, 4266, 2), (0x335b, 4268, 2), (0x335c, 4270, 2), (0x335d, 4272, 2), (0x335e, 4274, 2),
+    (0x335f, 4276, 2), (0x3360, 4278, 2), (0x3361, 4280, 2), (0x3362, 4282, 3), (0x3363, 4285, 3),
+    (0x3364, 4288, 3), (0x3365, 4291, 3), (0x3366, 4294, 3), (0x3367, 4297, 3), (0x3368, 4300, 3),
+    (0x3369, 4303, 3), (0x336a, 4306, 3), (0x336b, 4309, 3), (0x336c, 4312, 3), (0x336d, 4315, 3),
+    (0x336e, 4318, 3), (0x336f, 4321, 3), (0x3370, 4324, 3), (0x3371, 4327, 3), (0x3372, 4330, 2),
+    (0x3373, 4332, 2), (0x3374, 4334, 3), (0x3375, 4337, 2), (0x3376, 4339, 2), (0x3377, 4341, 2),
+    (0x3378, 4343, 3), (0x3379, 4346, 3), (0x337a, 4349, 2), (0x337b, 4351, 2), (0x337c, 4353, 2),
+    (0x337d, 4355, 2), (0x337e, 4357, 2), (0x337f, 4359, 4), (0x3380, 4363, 2), (0x3381, 4365, 2),
+    (0x3382, 4367, 2), (0x3383, 4369, 2), (0x3384, 4371, 2), (0x3385, 4373, 2), (0x3386, 4375, 2),
+    (0x3387, 4377, 2), (0x3388, 4379, 3), (0x3389, 4382, 4), (0x338a, 4386, 2), (0x338b, 4388, 2),
+    (0x338c, 4390, 2), (0x338d, 4392, 2), (0x338e, 4394, 2), (0x338f, 4396, 2), (0x3390, 4398, 2),
+    (0x3391, 4400, 3), (0x3392, 4403, 3), (0x3393, 4406, 3), (0x3394, 4409, 3), (0x3395, 4412, 2),
+    (0x3396, 4414, 2), (0x3397, 4416, 2), (0x3398, 4418, 2), (0x3399, 4420, 2), (0x339a, 4422, 2),
+    (0x339b, 4424, 2), (0x339c, 4426, 2), (0x339d, 4428, 2), (0x339e, 4430, 2), (0x339f, 4432, 3),
+    (0x33a0, 4435, 3), (0x33a1, 4438, 2), (0x33a2, 4440, 3), (0x33a3, 4443, 3), (0x33a4, 4446, 3),
+    (0x33a5, 4449, 2), (0x33a6, 4451, 3), (0x33a7, 4454, 3), (0x33a8, 4457, 4), (0x33a9, 4461, 2),
+    (0x33aa, 4463, 3), (0x33ab, 4466, 3), (0x33ac, 4469, 3), (0x33ad, 4472, 3), (0x33ae, 4475, 5),
+    (0x33af, 4480, 6), (0x33b0, 4486, 2), (0x33b1, 4488, 2), (0x33b2, 4490, 2), (0x33b3, 4492, 2),
+    (0x33b4, 4494, 2), (0x33b5, 4496, 2), (0x33b6, 4498, 2), (0x33b7, 4500, 2), (0x33b8, 4502, 2),
+    (0x33b9, 4504, 2), (0x33ba, 4506, 2), (0x33bb, 4508, 2), (0x33bc, 4510, 2), (0x33bd, 4512, 2),
+    (0x33be, 4514, 2), (0x33bf, 4516, 2), (0x33c0, 4518, 2), (0x33c1, 4520, 2), (0x33c2, 4522, 4),
+    (0x33c3, 4526, 2), (0x33c4, 4528, 2), (0x33c5, 4530, 2), (0x33c6, 4532, 4), (0x33c7, 4536, 3),
+    (0x33c8, 4539, 2), (0x33c9, 4541, 2), (0x33ca, 4543, 2), (0x33cb, 4545, 2), (0x33cc, 4547, 2),
+    (0x33cd, 4549, 2), (0x33ce, 4551, 2), (0x33cf, 4553, 2), (0x33d0, 4555, 2), (0x33d1, 4557, 2),
+    (0x33d2, 4559, 3), (0x33d3, 4562, 2), (0x33d4, 4564, 2), (0x33d5, 4566, 3), (0x33d6, 4569, 3),
+    (0x33d7, 4572, 2), (0x33d8, 4574, 4), (0x33d9, 4578, 3), (0x33da, 4581, 2), (0x33db, 4583, 2),
+    (0x33dc, 4585, 2), (0x33dd, 4587, 2), (0x33de, 4589, 3), (0x33df, 4592, 3), (0x33e0, 4595, 2),
+    (0x33e1, 4597, 2), (0x33e2, 4599, 2), (0x33e3, 4601, 2), (0x33e4, 4603, 2), (0x33e5, 4605, 2),
+    (0x33e6, 4607, 2), (0x33e7, 4609, 2), (0x33e8, 4611, 2), (0x33e9, 4613, 3), (0x33ea, 4616, 3),
+    (0x33eb, 4619, 3), (0x33ec, 4622, 3), (0x33ed, 4625, 3), (0x33ee, 4628, 3), (0x33ef, 4631, 3),
+    (0x33f0, 4634, 3), (0x33f1, 4637, 3), (0x33f2, 4640, 3), (0x33f3, 4643, 3), (0x33f4, 4646, 3),
+    (0x33f5, 4649, 3), (0x33f6, 4652, 3), (0x33f7, 4655, 3), (0x33f8, 4658, 3), (0x33f9, 4661, 3),
+    (0x33fa, 4664, 3), (0x33fb, 4667, 3), (0x33fc, 4670, 3), (0x33fd, 4673, 3), (0x33fe, 4676, 3),
+    (0x33ff, 4679, 3), (0xa69c, 4682, 1), (0xa69d, 4683, 1), (0xa770, 4684, 1), (0xa7f2, 4685, 1),
+    (0xa7f3, 4686, 1), (0xa7f4, 4687, 1), (0xa7f8, 4688, 1), (0xa7f9, 4689, 1), (0xab5c, 4690, 1),
+    (0xab5d, 4691, 1), (0xab5e, 4692, 1), (0xab5f, 4693, 1), (0xab69, 4694, 1), (0xf900, 4695, 1),
+    (0xf901, 4696, 1), (0xf902, 4697, 1), (0xf903, 4698, 1), (0xf904, 4699, 1), (0xf905, 4700, 1),
+    (0xf906, 4701, 1), (0xf907, 4702, 1), (0xf908, 4703, 1), (0xf909, 4704, 1), (0xf90a, 4705, 1),
+    (0xf90b, 4706, 1), (0xf90c, 4707, 1), (0xf90d, 4708, 1), (0xf90e, 4709, 1), (0xf90f, 4710, 1),
+    (0xf910, 4711, 1), (0xf911, 4712, 1), (0xf912, 4713, 1), (0xf913, 4714, 1), (0xf914, 4715, 1),
+    (0xf915, 4716, 1), (0xf916, 4717, 1), (0xf917, 4718, 1), (0xf918, 4719, 1), (0xf919, 4720, 1),
+    (0xf91a, 4721, 1), (0xf91b, 4722, 1), (0xf91c, 4723, 1), (0xf91d, 4724, 1), (0xf91e, 4725, 1),
+    (0xf91f, 4726, 1), (0xf920, 4727, 1), (0xf921, 4728, 1), (0xf922, 4729, 1), (0xf923, 4730, 1),
+    (0xf924, 4731, 1), (0xf925, 4732, 1), (0xf926, 4733, 1), (0xf927, 4734, 1), (0xf928, 4735, 1),
+    (0xf929, 4736, 1), (0xf92a, 4737, 1), (0xf92b, 4738, 1), (0xf92c, 4739, 1), (0xf92d, 4740, 1),
+    (0xf92e, 4741, 1), (0xf92f, 4742, 1), (0xf930, 4743, 1), (0xf931, 4744, 1), (0xf932, 4745, 1),
+    (0xf933, 4746, 1), (0xf934, 4747, 1), (0xf935, 4748, 1), (0xf936, 4749, 1), (0xf937, 4750, 1),
+    (0xf938, 4751, 1), (0xf939, 4752, 1), (0xf93a, 4753, 1), (0xf93b, 4754, 1), (0xf93c, 4755, 1),
+    (0xf93d, 4756, 1), (0xf93e, 4757, 1), (0xf93f, 4758, 1), (0xf940, 4759, 1), (0xf941, 4760, 1),
+    (0xf942, 4761, 1), (0xf943, 4762, 1), (0xf944, 4763, 1), (0xf945, 4764, 1), (0xf946, 4765, 1),
+    (0xf947, 4766, 1), (0xf948, 4767, 1), (0xf949, 4768, 1), (0xf94a, 4769, 1), (0xf94b, 4770, 1),
+    (0xf94c, 4771, 1), (0xf94d, 4772, 1), (0xf94e, 4773, 1), (0xf94f, 4774, 1), (0xf950, 4775, 1),
+    (0xf951, 4776, 1), (0xf952, 4777, 1), (0xf953, 4778, 1), (0xf954, 4779, 1), (0xf955, 4780, 1),
+    (0xf956, 4781, 1), (0xf957, 4782, 1), (0xf958, 4783, 1), (0xf959, 4784, 1), (0xf95a, 4785, 1),
+    (0xf95b, 4786, 1), (0xf95c, 4787, 1), (0xf95d, 4788, 1), (0xf95e, 4789, 1), (0xf95f, 4790, 1),
+    (0xf960, 4791, 1), (0xf961, 4792, 1), (0xf962, 4793, 1), (0xf963, 4794, 1), (0xf964, 4795, 1),
+    (0xf965, 4796, 1), (0xf966, 4797, 1), (0xf967, 4798, 1), (0xf968, 4799, 1), (0xf969, 4800, 1),
+    (0xf96a, 4801, 1), (0xf96b, 4802, 1), (0xf96c, 4803, 1), (0xf96d, 4804, 1), (0xf96e, 4805, 1),
+    (0xf96f, 4806, 1), (0xf970, 4807, 1), (0xf971, 4808, 1), (0xf972, 4809, 1), (0xf973, 4810, 1),
+    (0xf974, 4811, 1), (0xf975, 4812, 1), (0xf976, 4813, 1), (0xf977, 4814, 1), (0xf978, 4815, 1),
+    (0xf979, 4816, 1), (0xf97a, 4817, 1), (0xf97b, 4818, 1), (0xf97c, 4819, 1), (0xf97d, 4820, 1),
+    (0xf97e, 4821, 1), (0xf97f, 4822, 1), (0xf980, 4823, 1), (0xf981, 4824, 1), (0xf982, 4825, 1),
+    (0xf983, 4826, 1), (0xf984, 4827, 1), (0xf985, 4828, 1), (0xf986, 4829, 1), (0xf987, 4830, 1),
+    (0xf988, 4831, 1), (0xf989, 4832, 1), (0xf98a, 4833, 1), (0xf98b, 4834, 1), (0xf98c, 4835, 1),
+    (0xf98d, 4836, 1), (0xf98e, 4837, 1), (0xf98f, 4838, 1), (0xf990, 4839, 1), (0xf991, 4840, 1),
+    (0xf992, 4841, 1), (0xf993, 4842, 1), (0xf994, 4843, 1), (0xf995, 4844, 1), (0xf996, 4845, 1),
+    (0xf997, 4846, 1), (0xf998, 4847, 1), (0xf999, 4848, 1), (0xf99a, 4849, 1), (0xf99b, 4850, 1),
+    (0xf99c, 4851, 1), (0xf99d, 4852, 1), (0xf99e, 4853, 1), (0xf99f, 4854, 1), (0xf9a0, 4855, 1),
+    (0xf9a1, 4856, 1), (0xf9a2, 4857, 1), (0xf9a3, 4858, 1), (0xf9a4, 4859, 1), (0xf9a5, 4860, 1),
+    (0xf9a6, 4861, 1), (0xf9a7, 4862, 1), (0xf9a8, 4863, 1), (0xf9a9, 4864, 1), (0xf9aa, 4865, 1),
+    (0xf9ab, 4866, 1), (0xf9ac, 4867, 1), (0xf9ad, 4868, 1), (0xf9ae, 4869, 1), (0xf9af, 4870, 1),
+    (0xf9b0, 4871, 1), (0xf9b1, 4872, 1), (0xf9b2, 4873, 1), (0xf9b3, 4874, 1), (0xf9b4, 4875, 1),
+    (0xf9b5, 4876, 1), (0xf9b6, 4877, 1), (0xf9b7, 4878, 1), (0xf9b8, 4879, 1), (0xf9b9, 4880, 1),
+    (0xf9ba, 4881, 1), (0xf9bb, 4882, 1), (0xf9bc, 4883, 1), (0xf9bd, 4884, 1), (0xf9be, 4885, 1),
+    (0xf9bf, 4886, 1), (0xf9c0, 4887, 1), (0xf9c1, 4888, 1), (0xf9c2, 4889, 1), (0xf9c3, 4890, 1),
+    (0xf9c4, 4891, 1), (0xf9c5, 4892, 1), (0xf9c6, 4893, 1), (0xf9c7, 4894, 1), (0xf9c8, 4895, 1),
+    (0xf9c9, 4896, 1), (0xf9ca, 4897, 1), (0xf9cb, 4898, 1), (0xf9cc, 4899, 1), (0xf9cd, 4900, 1),
+    (0xf9ce, 4901, 1), (0xf9cf, 4902, 1), (0xf9d0, 4903, 1), (0xf9d1, 4904, 1), (0xf9d2, 4905, 1),
+    (0xf9d3, 4906, 1), (0xf9d4, 4907, 1), (0xf9d5, 4908, 1), (0xf9d6, 4909, 1), (0xf9d7, 4910, 1),
+    (0xf9d8, 4911, 1), (0xf9d9, 4912, 1), (0xf9da, 4913, 1), (0xf9db, 4914, 1), (0xf9dc, 4915, 1),
+    (0xf9dd, 4916, 1), (0xf9de, 4917, 1), (0xf9df, 4918, 1), (0xf9e0, 4919, 1), (0xf9e1, 4920, 1),
+    (0xf9e2, 4921, 1), (0xf9e3, 4922, 1), (0xf9e4, 4923, 1), (0xf9e5, 4924, 1), (0xf9e6, 4925, 1),
+    (0xf9e7, 4926, 1), (0xf9e8, 4927, 1), (0xf9e9, 4928, 1), (0xf9ea, 4929, 1), (0xf9eb, 4930, 1),
+    (0xf9ec, 4931, 1), (0xf9ed, 4932, 1), (0xf9ee, 4933, 1), (0xf9ef, 4934, 1), (0xf9f0, 4935, 1),
+    (0xf9f1, 4936, 1), (0xf9f2, 4937, 1), (0xf9f3, 4938, 1), (0xf9f4, 4939, 1), (0xf9f5, 4940, 1),
+    (0xf9f6, 4941, 1), (0xf9f7, 4942, 1), (0xf9f8, 4943, 1), (0xf9f9, 4944, 1), (0xf9fa, 4945, 1),
+    (0xf9fb, 4946, 1), (0xf9fc, 4947, 1), (0xf9fd, 4948, 1), (0xf9fe, 4949, 1), (0xf9ff, 4950, 1),
+    (0xfa00, 4951, 1), (0xfa01, 4952, 1), (0xfa02, 4953, 1), (0xfa03, 4954, 1), (0xfa04, 4955, 1),
+    (0xfa05, 4956, 1), (0xfa06, 4957, 1), (0xfa07, 4958, 1), (0xfa08, 4959, 1), (0xfa09, 4960, 1),
+    (0xfa0a, 4961, 1), (0xfa0b, 4962, 1), (0xfa0c, 4963, 1), (0xfa0d, 4964, 1), (0xfa10, 4965, 1),
+    (0xfa12, 4966, 1), (0xfa15, 4967, 1), (0xfa16, 4968, 1), (0xfa17, 4969, 1), (0xfa18, 4970, 1),
+    (0xfa19, 4971, 1), (0xfa1a, 4972, 1), (0xfa1b, 4973, 1), (0xfa1c, 4974, 1), (0xfa1d, 4975, 1),
+    (0xfa1e, 4976, 1), (0xfa20, 4977, 1), (0xfa22, 4978, 1), (0xfa25, 4979, 1), (0xfa26, 4980, 1),
+    (0xfa2a, 4981, 1), (0xfa2b, 4982, 1), (0xfa2c, 4983, 1), (0xfa2d, 4984, 1), (0xfa2e, 4985, 1),
+    (0xfa2f, 4986, 1), (0xfa30, 4987, 1), (0xfa31, 4988, 1), (0xfa32, 4989, 1), (0xfa33, 4990, 1),
+    (0xfa34, 4991, 1), (0xfa35, 4992, 1), (0xfa36, 4993, 1), (0xfa37, 4994, 1), (0xfa38, 4995, 1),
+    (0xfa39, 4996, 1), (0xfa3a, 4997, 1), (0xfa3b, 4998, 1), (0xfa3c, 4999, 1), (0xfa3d, 5000, 1),
+    (0xfa3e, 5001, 1), (0xfa3f, 5002, 1), (0xfa40, 5003, 1), (0xfa41, 5004, 1), (0xfa42, 5005, 1),
+    (0xfa43, 5006, 1), (0xfa44, 5007, 1), (0xfa45, 5008, 1), (0xfa46, 5009, 1), (0xfa47, 5010, 1),
+    (0xfa48, 5011, 1), (0xfa49, 5012, 1), (0xfa4a, 5013, 1), (0xfa4b, 5014, 1), (0xfa4c, 5015, 1),
+    (0xfa4d, 5016, 1), (0xfa4e, 5017, 1), (0xfa4f, 5018, 1), (0xfa50, 5019, 1), (0xfa51, 5020, 1),
+    (0xfa52, 5021, 1), (0xfa53, 5022, 1), (0xfa54, 5023, 1), (0xfa55, 5024, 1), (0xfa56, 5025, 1),
+    (0xfa57, 5026, 1), (0xfa58, 5027, 1), (0xfa59, 5028, 1), (0xfa5a, 5029, 1), (0xfa5b, 5030, 1),
+    (0xfa5c, 5031, 1), (0xfa5d, 5032, 1), (0xfa5e, 5033, 1), (0xfa5f, 5034, 1), (0xfa60, 5035, 1),
+    (0xfa61, 5036, 1), (0xfa62, 5037, 1), (0xfa63, 5038, 1), (0xfa64, 5039, 1), (0xfa65, 5040, 1),
+    (0xfa66, 5041, 1), (0xfa67, 5042, 1), (0xfa68, 5043, 1), (0xfa69, 5044, 1), (0xfa6a, 5045, 1),
+    (0xfa6b, 5046, 1), (0xfa6c, 5047, 1), (0xfa6d, 5048, 1), (0xfa70, 5049, 1), (0xfa71, 5050, 1),
+    (0xfa72, 5051, 1), (0xfa73, 5052, 1), (0xfa74, 5053, 1), (0xfa75, 5054, 1), (0xfa76, 5055, 1),
+    (0xfa77, 5056, 1), (0xfa78, 5057, 1), (0xfa79, 5058, 1), (0xfa7a, 5059, 1), (0xfa7b, 5060, 1),
+    (0xfa7c, 5061, 1), (0xfa7d, 5062, 1), (0xfa7e, 5063, 1), (0xfa7f, 5064, 1), (0xfa80, 5065, 1),
+    (0xfa81, 5066, 1), (0xfa82, 5067, 1), (0xfa83, 5068, 1), (0xfa84, 5069, 1), (0xfa85, 5070, 1),
+    (0xfa86, 5071, 1), (0xfa87, 5072, 1), (0xfa88, 5073, 1), (0xfa89, 5074, 1), (0xfa8a, 5075, 1),
+    (0xfa8b, 5076, 1), (0xfa8c, 5077, 1), (0xfa8d, 5078, 1), (0xfa8e, 5079, 1), (0xfa8f, 5080, 1),
+    (0xfa90, 5081, 1), (0xfa91, 5082, 1), (0xfa92, 5083, 1), (0xfa93, 5084, 1), (0xfa94, 5085, 1),
+    (0xfa95, 5086, 1), (0xfa96, 5087, 1), (0xfa97, 5088, 1), (0xfa98, 5089, 1), (0xfa99, 5090, 1),
+    (0xfa9a, 5091, 1), (0xfa9b, 5092, 1), (0xfa9c, 5093, 1), (0xfa9d, 5094, 1), (0xfa9e, 5095, 1),
+    (0xfa9f, 5096, 1), (0xfaa0, 5097, 1), (0xfaa1, 5098, 1), (0xfaa2, 5099, 1), (0xfaa3, 5100, 1),
+    (0xfaa4, 5101, 1), (0xfaa5, 5102, 1), (0xfaa6, 5103, 1), (0xfaa7, 5104, 1), (0xfaa8, 5105, 1),
+    (0xfaa9, 5106, 1), (0xfaaa, 5107, 1), (0xfaab, 5108, 1), (0xfaac, 5109, 1), (0xfaad, 5110, 1),
+    (0xfaae, 5111, 1), (0xfaaf, 5112, 1), (0xfab0, 5113, 1), (0xfab1, 5114, 1), (0xfab2, 5115, 1),
+    (0xfab3, 5116, 1), (0xfab4, 5117, 1), (0xfab5, 5118, 1), (0xfab6, 5119, 1), (0xfab7, 5120, 1),
+    (0xfab8, 5121, 1), (0xfab9, 5122, 1), (0xfaba, 5123, 1), (0xfabb, 5124, 1), (0xfabc, 5125, 1),
+    (0xfabd, 5126, 1), (0xfabe, 5127, 1), (0xfabf, 5128, 1), (0xfac0, 5129, 1), (0xfac1, 5130, 1),
+    (0xfac2, 5131, 1), (0xfac3, 5132, 1), (0xfac4, 5133, 1), (0xfac5, 5134, 1), (0xfac6, 5135, 1),
+    (0xfac7, 5136, 1), (0xfac8, 5137, 1), (0xfac9, 5138, 1), (0xfaca, 5139, 1), (0xfacb, 5140, 1),
+    (0xfacc, 5141, 1), (0xfacd, 5142, 1), (0xface, 5143, 1), (0xfacf, 5144, 1), (0xfad0, 5145, 1),
+    (0xfad1, 5146, 1), (0xfad2, 5147, 1), (0xfad3, 5148, 1), (0xfad4, 5149, 1), (0xfad5, 5150, 1),
+    (0xfad6, 5151, 1), (0xfad7, 5152, 1), (0xfad8, 5153, 1), (0xfad9, 5154, 1), (0xfb00, 5155, 2),
+    (0xfb01, 5157, 2), (0xfb02, 5159, 2), (0xfb03, 5161, 3), (0xfb04, 5164, 3), (0xfb05, 5167, 2),
+    (0xfb06, 5169, 2), (0xfb13, 5171, 2), (0xfb14, 5173, 2), (0xfb15, 5175, 2), (0xfb16, 5177, 2),
+    (0xfb17, 5179, 2), (0xfb1d, 5181, 2), (0xfb1f, 5183, 2), (0xfb20, 5185, 1), (0xfb21, 5186, 1),
+    (0xfb22, 5187, 1), (0xfb23, 5188, 1), (0xfb24, 5189, 1), (0xfb25, 5190, 1), (0xfb26, 5191, 1),
+    (0xfb27, 5192, 1), (0xfb28, 5193, 1), (0xfb29, 5194, 1), (0xfb2a, 5195, 2), (0xfb2b, 5197, 2),
+    (0xfb2c, 5199, 3), (0xfb2d, 5202, 3), (0xfb2e, 5205, 2), (0xfb2f, 5207, 2), (0xfb30, 5209, 2),
+    (0xfb31, 5211, 2), (0xfb32, 5213, 2), (0xfb33, 5215, 2), (0xfb34, 5217, 2), (0xfb35, 5219, 2),
+    (0xfb36, 5221, 2), (0xfb38, 5223, 2), (0xfb39, 5225, 2), (0xfb3a, 5227, 2), (0xfb3b, 5229, 2),
+    (0xfb3c, 5231, 2), (0xfb3e, 5233, 2), (0xfb40, 5235, 2), (0xfb41, 5237, 2), (0xfb43, 5239, 2),
+    (0xfb44, 5241, 2), (0xfb46, 5243, 2), (0xfb47, 5245, 2), (0xfb48, 5247, 2), (0xfb49, 5249, 2),
+    (0xfb4a, 5251, 2), (0xfb4b, 5253, 2), (0xfb4c, 5255, 2), (0xfb4d, 5257, 2), (0xfb4e, 5259, 2),
+    (0xfb4f, 5261, 2), (0xfb50, 5263, 1), (0xfb51, 5264, 1), (0xfb52, 5265, 1), (0xfb53, 5266, 1),
+    (0xfb54, 5267, 1), (0xfb55, 5268, 1), (0xfb56, 5269, 1), (0xfb57, 5270, 1), (0xfb58, 5271, 1),
+    (0xfb59, 5272, 1), (0xfb5a, 5273, 1), (0xfb5b, 5274, 1), (0xfb5c, 5275, 1), (0xfb5d, 5276, 1),
+    (0xfb5e, 5277, 1), (0xfb5f, 5278, 1), (0xfb60, 5279, 1), (0xfb61, 5280, 1), (0xfb62, 5281, 1),
+    (0xfb63, 5282, 1), (0xfb64, 5283, 1), (0xfb65, 5284, 1), (0xfb66, 5285, 1), (0xfb67, 5286, 1),
+    (0xfb68, 5287, 1), (0xfb69, 5288, 1), (0xfb6a, 5289, 1), (0xfb6b, 5290, 1), (0xfb6c, 5291, 1),
+    (0xfb6d, 5292, 1), (0xfb6e, 5293, 1), (0xfb6f, 5294, 1), (0xfb70, 5295, 1), (0xfb71, 5296, 1),
+    (0xfb72, 5297, 1), (0xfb73, 5298, 1), (0xfb74, 5299, 1), (0xfb75, 5300, 1), (0xfb76, 5301, 1),
+    (0xfb77, 5302, 1), (0xfb78, 5303, 1), (0xfb79, 5304, 1), (0xfb7a, 5305, 1), (0xfb7b, 5306, 1),
+    (0xfb7c, 5307, 1), (0xfb7d, 5308, 1), (0xfb7e, 5309, 1), (0xfb7f, 5310, 1), (0xfb80, 5311, 1),
+    (0xfb81, 5312, 1), (0xfb82, 5313, 1), (0xfb83, 5314, 1), (0xfb84, 5315, 1), (0xfb85, 5316, 1),
+    (0xfb86, 5317, 1), (0xfb87, 5318, 1), (0xfb88, 5319, 1), (0xfb89, 5320, 1), (0xfb8a, 5321, 1),
+    (0xfb8b, 5322, 1), (0xfb8c, 5323, 1), (0xfb8d, 5324, 1), (0xfb8e, 5325, 1), (0xfb8f, 5326, 1),
+    (0xfb90, 5327, 1), (0xfb91, 5328, 1), (0xfb92, 5329, 1), (0xfb93, 5330, 1), (0xfb94, 5331, 1),
+    (0xfb95, 5332, 1), (0xfb96, 5333, 1), (0xfb97, 5334, 1), (0xfb98, 5335, 1), (0xfb99, 5336, 1),
+    (0xfb9a, 5337, 1), (0xfb9b, 5338, 1), (0xfb9c, 5339, 1), (0xfb9d, 5340, 1), (0xfb9e, 5341, 1),
+    (0xfb9f, 5342, 1), (0xfba0, 5343, 1), (0xfba1, 5344, 1), (0xfba2, 5345, 1), (0xfba3, 5346, 1),
+    (0xfba4, 5347, 2), (0xfba5, 5349, 2), (0xfba6, 5351, 1), (0xfba7, 5352, 1), (0xfba8, 5353, 1),
+    (0xfba9, 5354, 1), (0xfbaa, 5355, 1), (0xfbab, 5356, 1), (0xfbac, 5357, 1), (0xfbad, 5358, 1),
+    (0xfbae, 5359, 1), (0xfbaf, 5360, 1), (0xfbb0, 5361, 2), (0xfbb1, 5363, 2), (0xfbd3, 5365, 1),
+    (0xfbd4, 5366, 1), (0xfbd5, 5367, 1), (0xfbd6, 5368, 1), (0xfbd7, 5369, 1), (0xfbd8, 5370, 1),
+    (0xfbd9, 5371, 1), (0xfbda, 5372, 1), (0xfbdb, 5373, 1), (0xfbdc, 5374, 1), (0xfbdd, 5375, 2),
+    (0xfbde, 5377, 1), (0xfbdf, 5378, 1), (0xfbe0, 5379, 1), (0xfbe1, 5380, 1), (0xfbe2, 5381, 1),
+    (0xfbe3, 5382, 1), (0xfbe4, 5383, 1), (0xfbe5, 5384, 1), (0xfbe6, 5385, 1), (0xfbe7, 5386, 1),
+    (0xfbe8, 5387, 1), (0xfbe9, 5388, 1), (0xfbea, 5389, 3), (0xfbeb, 5392, 3), (0xfbec, 5395, 3),
+    (0xfbed, 5398, 3), (0xfbee, 5401, 3), (0xfbef, 5404, 3), (0xfbf0, 5407, 3), (0xfbf1, 5410, 3),
+    (0xfbf2, 5413, 3), (0xfbf3, 5416, 3), (0xfbf4, 5419, 3), (0xfbf5, 5422, 3), (0xfbf6, 5425, 3),
+    (0xfbf7, 5428, 3), (0xfbf8, 5431, 3), (0xfbf9, 5434, 3), (0xfbfa, 5437, 3), (0xfbfb, 5440, 3),
+    (0xfbfc, 5443, 1), (0xfbfd, 5444, 1), (0xfbfe, 5445, 1), (0xfbff, 5446, 1), (0xfc00, 5447, 3),
+    (0xfc01, 5450, 3), (0xfc02, 5453, 3), (0xfc03, 5456, 3), (0xfc04, 5459, 3), (0xfc05, 5462, 2),
+    (0xfc06, 5464, 2), (0xfc07, 5466, 2), (0xfc08, 5468, 2), (0xfc09, 5470, 2), (0xfc0a, 5472, 2),
+    (0xfc0b, 5474, 2), (0xfc0c, 5476, 2), (0xfc0d, 5478, 2), (0xfc0e, 5480, 2), (0xfc0f, 5482, 2),
+    (0xfc10, 5484, 2), (0xfc11, 5486, 2), (0xfc12, 5488, 2), (0xfc13, 5490, 2), (0xfc14, 5492, 2),
+    (0xfc15, 5494, 2), (0xfc16, 5496, 2), (0xfc17, 5498, 2), (0xfc18, 5500, 2), (0xfc19, 5502, 2),
+    (0xfc1a, 5504, 2), (0xfc1b, 5506, 2), (0xfc1c, 5508, 2), (0xfc1d, 5510, 2), (0xfc1e, 5512, 2),
+    (0xfc1f, 5514, 2), (0xfc20, 5516, 2), (0xfc21, 5518, 2), (0xfc22, 5520, 2), (0xfc23, 5522, 2),
+    (0xfc24, 5524, 2), (0xfc25, 5526, 2), (0xfc26, 5528, 2), (0xfc27, 5530, 2), (0xfc28, 5532, 2),
+    (0xfc29, 5534, 2), (0xfc2a, 5536, 2), (0xfc2b, 5538, 2), (0xfc2c, 5540, 2), (0xfc2d, 5542, 2),
+    (0xfc2e, 5544, 2), (0xfc2f, 5546, 2), (0xfc30, 5548, 2), (0xfc31, 5550, 2), (0xfc32, 5552, 2),
+    (0xfc33, 5554, 2), (0xfc34, 5556, 2), (0xfc35, 5558, 2), (0xfc36, 5560, 2), (0xfc37, 5562, 2),
+    (0xfc38, 5564, 2), (0xfc39, 5566, 2), (0xfc3a, 5568, 2), (0xfc3b, 5570, 2), (0xfc3c, 5572, 2),
+    (0xfc3d, 5574, 2), (0xfc3e, 5576, 2), (0xfc3f, 5578, 2), (0xfc40, 5580, 2), (0xfc41, 5582, 2),
+    (0xfc42, 5584, 2), (0xfc43, 5586, 2), (0xfc44, 5588, 2), (0xfc45, 5590, 2), (0xfc46, 5592, 2),
+    (0xfc47, 5594, 2), (0xfc48, 5596, 2), (0xfc49, 5598, 2), (0xfc4a, 5600, 2), (0xfc4b, 5602, 2),
+    (0xfc4c, 5604, 2), (0xfc4d, 5606, 2), (0xfc4e, 5608, 2), (0xfc4f, 5610, 2), (0xfc50, 5612, 2),
+    (0xfc51, 5614, 2), (0xfc52, 5616, 2), (0xfc53, 5618, 2), (0xfc54, 5620, 2), (0xfc55, 5622, 2),
+    (0xfc56, 5624, 2), (0xfc57, 5626, 2), (0xfc58, 5628, 2), (0xfc59, 5630, 2), (0xfc5a, 5632, 2),
+    (0xfc5b, 5634, 2), (0xfc5c, 5636, 2), (0xfc5d, 5638, 2), (0xfc5e, 5640, 3), (0xfc5f, 5643, 3),
+    (0xfc60, 5646, 3), (0xfc61, 5649, 3), (0xfc62, 5652, 3), (0xfc63, 5655, 3), (0xfc64, 5658, 3),
+    (0xfc65, 5661, 3), (0xfc66, 5664, 3), (0xfc67, 5667, 3), (0xfc68, 5670, 3), (0xfc69, 5673, 3),
+    (0xfc6a, 5676, 2), (0xfc6b, 5678, 2), (0xfc6c, 5680, 2), (0xfc6d, 5682, 2), (0xfc6e, 5684, 2),
+    (0xfc6f, 5686, 2), (0xfc70, 5688, 2), (0xfc71, 5690, 2), (0xfc72, 5692, 2), (0xfc73, 5694, 2),
+    (0xfc74, 5696, 2), (0xfc75, 5698, 2), (0xfc76, 5700, 2), (0xfc77, 5702, 2), (0xfc78, 5704, 2),
+    (0xfc79, 5706, 2), (0xfc7a, 5708, 2), (0xfc7b, 5710, 2), (0xfc7c, 5712, 2), (0xfc7d, 5714, 2),
+    (0xfc7e, 5716, 2), (0xfc7f, 5718, 2), (0xfc80, 5720, 2), (0xfc81, 5722, 2), (0xfc82, 5724, 2),
+    (0xfc83, 5726, 2), (0xfc84, 5728, 2), (0xfc85, 5730, 2), (0xfc86, 5732, 2), (0xfc87, 5734, 2),
+    (0xfc88, 5736, 2), (0xfc89, 5738, 2), (0xfc8a, 5740, 2), (0xfc8b, 5742, 2), (0xfc8c, 5744, 2),
+    (0xfc8d, 5746, 2), (0xfc8e, 5748, 2), (0xfc8f, 5750, 2), (0xfc90, 5752, 2), (0xfc91, 5754, 2),
+    (0xfc92, 5756, 2), (0xfc93, 5758, 2), (0xfc94, 5760, 2), (0xfc95, 5762, 2), (0xfc96, 5764, 2),
+    (0xfc97, 5766, 3), (0xfc98, 5769, 3), (0xfc99, 5772, 3), (0xfc9a, 5775, 3), (0xfc9b, 5778, 3),
+    (0xfc9c, 5781, 2), (0xfc9d, 5783, 2), (0xfc9e, 5785, 2), (0xfc9f, 5787, 2), (0xfca0, 5789, 2),
+    (0xfca1, 5791, 2), (0xfca2, 5793, 2), (0xfca3, 5795, 2), (0xfca4, 5797, 2), (0xfca5, 5799, 2),
+    (0xfca6, 5801, 2), (0xfca7, 5803, 2), (0xfca8, 5805, 2), (0xfca9, 5807, 2), (0xfcaa, 5809, 2),
+    (0xfcab, 5811, 2), (0xfcac, 5813, 2), (0xfcad, 5815, 2), (0xfcae, 5817, 2), (0xfcaf, 5819, 2),
+    (0xfcb0, 5821, 2), (0xfcb1, 5823, 2), (0xfcb2, 5825, 2), (0xfcb3, 5827, 2), (0xfcb4, 5829, 2),
+    (0xfcb5, 5831, 2), (0xfcb6, 5833, 2), (0xfcb7, 5835, 2), (0xfcb8, 5837, 2), (0xfcb9, 5839, 2),
+    (0xfcba, 5841, 2), (0xfcbb, 5843, 2), (0xfcbc, 5845, 2), (0xfcbd, 5847, 2), (0xfcbe, 5849, 2),
+    (0xfcbf, 5851, 2), (0xfcc0, 5853, 2), (0xfcc1, 5855, 2), (0xfcc2, 5857, 2), (0xfcc3, 5859, 2),
+    (0xfcc4, 5861, 2), (0xfcc5, 5863, 2), (0xfcc6, 5865, 2), (0xfcc7, 5867, 2), (0xfcc8, 5869, 2),
+    (0xfcc9, 5871, 2), (0xfcca, 5873, 2), (0xfccb, 5875, 2), (0xfccc, 5877, 2), (0xfccd, 5879, 2),
+    (0xfcce, 5881, 2), (0xfccf, 5883, 2), (0xfcd0, 5885, 2), (0xfcd1, 5887, 2), (0xfcd2, 5889, 2),
+    (0xfcd3, 5891, 2), (0xfcd4, 5893, 2), (0xfcd5, 5895, 2), (0xfcd6, 5897, 2), (0xfcd7, 5899, 2),
+    (0xfcd8, 5901, 2), (0xfcd9, 5903, 2), (0xfcda, 5905, 2), (0xfcdb, 5907, 2), (0xfcdc, 5909, 2),
+    (0xfcdd, 5911, 2), (0xfcde, 5913, 2), (0xfcdf, 5915, 3), (0xfce0, 5918, 3), (0xfce1, 5921, 2),
+    (0xfce2, 5923, 2), (0xfce3, 5925, 2), (0xfce4, 5927, 2), (0xfce5, 5929, 2), (0xfce6, 5931, 2),
+    (0xfce7, 5933, 2), (0xfce8, 5935, 2), (0xfce9, 5937, 2), (0xfcea, 5939, 2), (0xfceb, 5941, 2),
+    (0xfcec, 5943, 2), (0xfced, 5945, 2), (0xfcee, 5947, 2), (0xfcef, 5949, 2), (0xfcf0, 5951, 2),
+    (0xfcf1, 5953, 2), (0xfcf2, 5955, 3), (0xfcf3, 5958, 3), (0xfcf4, 5961, 3), (0xfcf5, 5964, 2),
+    (0xfcf6, 5966, 2), (0xfcf7, 5968, 2), (0xfcf8, 5970, 2), (0xfcf9, 5972, 2), (0xfcfa, 5974, 2),
+    (0xfcfb, 5976, 2), (0xfcfc, 5978, 2), (0xfcfd, 5980, 2), (0xfcfe, 5982, 2), (0xfcff, 5984, 2),
+    (0xfd00, 5986, 2), (0xfd01, 5988, 2), (0xfd02, 5990, 2), (0xfd03, 5992, 2), (0xfd04, 5994, 2),
+    (0xfd05, 5996, 2), (0xfd06, 5998, 2), (0xfd07, 6000, 2), (0xfd08, 6002, 2), (0xfd09, 6004, 2),
+    (0xfd0a, 6006, 2), (0xfd0b, 6008, 2), (0xfd0c, 6010, 2), (0xfd0d, 6012, 2), (0xfd0e, 6014, 2),
+    (0xfd0f, 6016, 2), (0xfd10, 6018, 2), (0xfd11, 6020, 2), (0xfd12, 6022, 2), (0xfd13, 6024, 2),
+    (0xfd14, 6026, 2), (0xfd15, 6028, 2), (0xfd16, 6030, 2), (0xfd17, 6032, 2), (0xfd18, 6034, 2),
+    (0xfd19, 6036, 2), (0xfd1a, 6038, 2), (0xfd1b, 6040, 2), (0xfd1c, 6042, 2), (0xfd1d, 6044, 2),
+    (0xfd1e, 6046, 2), (0xfd1f, 6048, 2), (0xfd20, 6050, 2), (0xfd21, 6052, 2), (0xfd22, 6054, 2),
+    (0xfd23, 6056, 2), (0xfd24, 6058, 2), (0xfd25, 6060, 2), (0xfd26, 6062, 2), (0xfd27, 6064, 2),
+    (0xfd28, 6066, 2), (0xfd29, 6068, 2), (0xfd2a, 6070, 2), (0xfd2b, 6072, 2), (0xfd2c, 6074, 2),
+    (0xfd2d, 6076, 2), (0xfd2e, 6078, 2), (0xfd2f, 6080, 2), (0xfd30, 6082, 2), (0xfd31, 6084, 2),
+    (0xfd32, 6086, 2), (0xfd33, 6088, 2), (0xfd34, 6090, 2), (0xfd35, 6092, 2), (0xfd36, 6094, 2),
+    (0xfd37, 6096, 2), (0xfd38, 6098, 2), (0xfd39, 6100, 2), (0xfd3a, 6102, 2), (0xfd3b, 6104, 2),
+    (0xfd3c, 6106, 2), (0xfd3d, 6108, 2), (0xfd50, 6110, 3), (0xfd51, 6113, 3), (0xfd52, 6116, 3),
+    (0xfd53, 6119, 3), (0xfd54, 6122, 3), (0xfd55, 6125, 3), (0xfd56, 6128, 3), (0xfd57, 6131, 3),
+    (0xfd58, 6134, 3), (0xfd59, 6137, 3), (0xfd5a, 6140, 3), (0xfd5b, 6143, 3), (0xfd5c, 6146, 3),
+    (0xfd5d, 6149, 3), (0xfd5e, 6152, 3), (0xfd5f, 6155, 3), (0xfd60, 6158, 3), (0xfd61, 6161, 3),
+    (0xfd62, 6164, 3), (0xfd63, 6167, 3), (0xfd64, 6170, 3), (0xfd65, 6173, 3), (0xfd66, 6176, 3),
+    (0xfd67, 6179, 3), (0xfd68, 6182, 3), (0xfd69, 6185, 3), (0xfd6a, 6188, 3), (0xfd6b, 6191, 3),
+    (0xfd6c, 6194, 3), (0xfd6d, 6197, 3), (0xfd6e, 6200, 3), (0xfd6f, 6203, 3), (0xfd70, 6206, 3),
+    (0xfd71, 6209, 3), (0xfd72, 6212, 3), (0xfd73, 6215, 3), (0xfd74, 6218, 3), (0xfd75, 6221, 3),
+    (0xfd76, 6224, 3), (0xfd77, 6227, 3), (0xfd78, 6230, 3), (0xfd79, 6233, 3), (0xfd7a, 6236, 3),
+    (0xfd7b, 6239, 3), (0xfd7c, 6242, 3), (0xfd7d, 6245, 3), (0xfd7e, 6248, 3), (0xfd7f, 6251, 3),
+    (0xfd80, 6254, 3), (0xfd81, 6257, 3), (0xfd82, 6260, 3), (0xfd83, 6263, 3), (0xfd84, 6266, 3),
+    (0xfd85, 6269, 3), (0xfd86, 6272, 3), (0xfd87, 6275, 3), (0xfd88, 6278, 3), (0xfd89, 6281, 3),
+    (0xfd8a, 6284, 3), (0xfd8b, 6287, 3), (0xfd8c, 6290, 3), (0xfd8d, 6293, 3), (0xfd8e, 6296, 3),
+    (0xfd8f, 6299, 3), (0xfd92, 6302, 3), (0xfd93, 6305, 3), (0xfd94, 6308, 3), (0xfd95, 6311, 3),
+    (0xfd96, 6314, 3), (0xfd97, 6317, 3), (0xfd98, 6320, 3), (0xfd99, 6323, 3), (0xfd9a, 6326, 3),
+    (0xfd9b, 6329, 3), (0xfd9c, 6332, 3), (0xfd9d, 6335, 3), (0xfd9e, 6338, 3), (0xfd9f, 6341, 3),
+    (0xfda0, 6344, 3), (0xfda1, 6347, 3), (0xfda2, 6350, 3), (0xfda3, 6353, 3), (0xfda4, 6356, 3),
+    (0xfda5, 6359, 3), (0xfda6, 6362, 3), (0xfda7, 6365, 3), (0xfda8, 6368, 3), (0xfda9, 6371, 3),
+    (0xfdaa, 6374, 3), (0xfdab, 6377, 3), (0xfdac, 6380, 3), (0xfdad, 6383, 3), (0xfdae, 6386, 3),
+    (0xfdaf, 6389, 3), (0xfdb0, 6392, 3), (0xfdb1, 6395, 3), (0xfdb2, 6398, 3), (0xfdb3, 6401, 3),
+    (0xfdb4, 6404, 3), (0xfdb5, 6407, 3), (0xfdb6, 6410, 3), (0xfdb7, 6413, 3), (0xfdb8, 6416, 3),
+    (0xfdb9, 6419, 3), (0xfdba, 6422, 3), (0xfdbb, 6425, 3), (0xfdbc, 6428, 3), (0xfdbd, 6431, 3),
+    (0xfdbe, 6434, 3), (0xfdbf, 6437, 3), (0xfdc0, 6440, 3), (0xfdc1, 6443, 3), (0xfdc2, 6446, 3),
+    (0xfdc3, 6449, 3), (0xfdc4, 6452, 3), (0xfdc5, 6455, 3), (0xfdc6, 6458, 3), (0xfdc7, 6461, 3),
+    (0xfdf0, 6464, 3), (0xfdf1, 6467, 3), (0xfdf2, 6470, 4), (0xfdf3, 6474, 4), (0xfdf4, 6478, 4),
+    (0xfdf5, 6482, 4), (0xfdf6, 6486, 4), (0xfdf7, 6490, 4), (0xfdf8, 6494, 4), (0xfdf9, 6498, 3),
+    (0xfdfa, 6501, 18), (0xfdfb, 6519, 8), (0xfdfc, 6527, 4), (0xfe10, 6531, 1), (0xfe11, 6532, 1),
+    (0xfe12, 6533, 1), (0xfe13, 6534, 1), (0xfe14, 6535, 1), (0xfe15, 6536, 1), (0xfe16, 6537, 1),
+    (0xfe17, 6538, 1), (0xfe18, 6539, 1), (0xfe19, 6540, 3), (0xfe30, 6543, 2), (0xfe31, 6545, 1),
+    (0xfe32, 6546, 1), (0xfe33, 6547, 1), (0xfe34, 6548, 1), (0xfe35, 6549, 1), (0xfe36, 6550, 1),
+    (0xfe37, 6551, 1), (0xfe38, 6552, 1), (0xfe39, 6553, 1), (0xfe3a, 6554, 1), (0xfe3b, 6555, 1),
+    (0xfe3c, 6556, 1), (0xfe3d, 6557, 1), (0xfe3e, 6558, 1), (0xfe3f, 6559, 1), (0xfe40, 6560, 1),
+    (0xfe41, 6561, 1), (0xfe42, 6562, 1), (0xfe43, 6563, 1), (0xfe44, 6564, 1), (0xfe47, 6565, 1),
+    (0xfe48, 6566, 1), (0xfe49, 6567, 2), (0xfe4a, 6569, 2), (0xfe4b, 6571, 2), (0xfe4c, 6573, 2),
+    (0xfe4d, 6575, 1), (0xfe4e, 6576, 1), (0xfe4f, 6577, 1), (0xfe50, 6578, 1), (0xfe51, 6579, 1),
+    (0xfe52, 6580, 1), (0xfe54, 6581, 1), (0xfe55, 6582, 1), (0xfe56, 6583, 1), (0xfe57, 6584, 1),
+    (0xfe58, 6585, 1), (0xfe59, 6586, 1), (0xfe5a, 6587, 1), (0xfe5b, 6588, 1), (0xfe5c, 6589, 1),
+    (0xfe5d, 6590, 1), (0xfe5e, 6591, 1), (0xfe5f, 6592, 1), (0xfe60, 6593, 1), (0xfe61, 6594, 1),
+    (0xfe62, 6595, 1), (0xfe63, 6596, 1), (0xfe64, 6597, 1), (0xfe65, 6598, 1), (0xfe66, 6599, 1),
+    (0xfe68, 6600, 1), (0xfe69, 6601, 1), (0xfe6a, 6602, 1), (0xfe6b, 6603, 1), (0xfe70, 6604, 2),
+    (0xfe71, 6606, 2), (0xfe72, 6608, 2), (0xfe74, 6610, 2), (0xfe76, 6612, 2), (0xfe77, 6614, 2),
+    (0xfe78, 6616, 2), (0xfe79, 6618, 2), (0xfe7a, 6620, 2), (0xfe7b, 6622, 2), (0xfe7c, 6624, 2),
+    (0xfe7d, 6626, 2), (0xfe7e, 6628, 2), (0xfe7f, 6630, 2), (0xfe80, 6632, 1), (0xfe81, 6633, 2),
+    (0xfe82, 6635, 2), (0xfe83, 6637, 2), (0xfe84, 6639, 2), (0xfe85, 6641, 2), (0xfe86, 6643, 2),
+    (0xfe87, 6645, 2), (0xfe88, 6647, 2), (0xfe89, 6649, 2), (0xfe8a, 6651, 2), (0xfe8b, 6653, 2),
+    (0xfe8c, 6655, 2), (0xfe8d, 6657, 1), (0xfe8e, 6658, 1), (0xfe8f, 6659, 1), (0xfe90, 6660, 1),
+    (0xfe91, 6661, 1), (0xfe92, 6662, 1), (0xfe93, 6663, 1), (0xfe94, 6664, 1), (0xfe95, 6665, 1),
+    (0xfe96, 6666, 1), (0xfe97, 6667, 1), (0xfe98, 6668, 1), (0xfe99, 6669, 1), (0xfe9a, 6670, 1),
+    (0xfe9b, 6671, 1), (0xfe9c, 6672, 1), (0xfe9d, 6673, 1), (0xfe9e, 6674, 1), (0xfe9f, 6675, 1),
+    (0xfea0, 6676, 1), (0xfea1, 6677, 1), (0xfea2, 6678, 1), (0xfea3, 6679, 1), (0xfea4, 6680, 1),
+    (0xfea5, 6681, 1), (0xfea6, 6682, 1), (0xfea7, 6683, 1), (0xfea8, 6684, 1), (0xfea9, 6685, 1),
+    (0xfeaa, 6686, 1), (0xfeab, 6687, 1), (0xfeac, 6688, 1), (0xfead, 6689, 1), (0xfeae, 6690, 1),
+    (0xfeaf, 6691, 1), (0xfeb0, 6692, 1), (0xfeb1, 6693, 1), (0xfeb2, 6694, 1), (0xfeb3, 6695, 1),
+    (0xfeb4, 6696, 1), (0xfeb5, 6697, 1), (0xfeb6, 6698, 1), (0xfeb7, 6699, 1), (0xfeb8, 6700, 1),
+    (0xfeb9, 6701, 1), (0xfeba, 6702, 1), (0xfebb, 6703, 1), (0xfebc, 6704, 1), (0xfebd, 6705, 1),
+    (0xfebe, 6706, 1), (0xfebf, 6707, 1), (0xfec0, 6708, 1), (0xfec1, 6709, 1), (0xfec2, 6710, 1),
+    (0xfec3, 6711, 1), (0xfec4, 6712, 1), (0xfec5, 6713, 1), (0xfec6, 6714, 1), (0xfec7, 6715, 1),
+    (0xfec8, 6716, 1), (0xfec9, 6717, 1), (0xfeca, 6718, 1), (0xfecb, 6719, 1), (0xfecc, 6720, 1),
+    (0xfecd, 6721, 1), (0xfece, 6722, 1), (0xfecf, 6723, 1), (0xfed0, 6724, 1), (0xfed1, 6725, 1),
+    (0xfed2, 6726, 1), (0xfed3, 6727, 1), (0xfed4, 6728, 1), (0xfed5, 6729, 1), (0xfed6, 6730, 1),
+    (0xfed7, 6731, 1), (0xfed8, 6732, 1), (0xfed9, 6733, 1), (0xfeda, 6734, 1), (0xfedb, 6735, 1),
+    (0xfedc, 6736, 1), (0xfedd, 6737, 1), (0xfede, 6738, 1), (0xfedf, 6739, 1), (0xfee0, 6740, 1),
+    (0xfee1, 6741, 1), (0xfee2, 6742, 1), (0xfee3, 6743, 1), (0xfee4, 6744, 1), (0xfee5, 6745, 1),
+    (0xfee6, 6746, 1), (0xfee7, 6747, 1), (0xfee8, 6748, 1), (0xfee9, 6749, 1), (0xfeea, 6750, 1),
+    (0xfeeb, 6751, 1), (0xfeec, 6752, 1), (0xfeed, 6753, 1), (0xfeee, 6754, 1), (0xfeef, 6755, 1),
+    (0xfef0, 6756, 1), (0xfef1, 6757, 1), (0xfef2, 6758, 1), (0xfef3, 6759, 1), (0xfef4, 6760, 1),
+    (0xfef5, 6761, 3), (0xfef6, 6764, 3), (0xfef7, 6767, 3), (0xfef8, 6770, 3), (0xfef9, 6773, 3),
+    (0xfefa, 6776, 3), (0xfefb, 6779, 2), (0xfefc, 6781, 2), (0xff01, 6783, 1), (0xff02, 6784, 1),
+    (0xff03, 6785, 1), (0xff04, 6786, 1), (0xff05, 6787, 1), (0xff06, 6788, 1), (0xff07, 6789, 1),
+    (0xff08, 6790, 1), (0xff09, 6791, 1), (0xff0a, 6792, 1), (0xff0b, 6793, 1), (0xff0c, 6794, 1),
+    (0xff0d, 6795, 1), (0xff0e, 6796, 1), (0xff0f, 6797, 1), (0xff10, 6798, 1), (0xff11, 6799, 1),
+    (0xff12, 6800, 1), (0xff13, 6801, 1), (0xff14, 6802, 1), (0xff15, 6803, 1), (0xff16, 6804, 1),
+    (0xff17, 6805, 1), (0xff18, 6806, 1), (0xff19, 6807, 1), (0xff1a, 6808, 1), (0xff1b, 6809, 1),
+    (0xff1c, 6810, 1), (0xff1d, 6811, 1), (0xff1e, 6812, 1), (0xff1f, 6813, 1), (0xff20, 6814, 1),
+    (0xff21, 6815, 1), (0xff22, 6816, 1), (0xff23, 6817, 1), (0xff24, 6818, 1), (0xff25, 6819, 1),
+    (0xff26, 6820, 1), (0xff27, 6821, 1), (0xff28, 6822, 1), (0xff29, 6823, 1), (0xff2a, 6824, 1),
+    (0xff2b, 6825, 1), (0xff2c, 6826, 1), (0xff2d, 6827, 1), (0xff2e, 6828, 1), (0xff2f, 6829, 1),
+    (0xff30, 6830, 1), (0xff31, 6831, 1), (0xff32, 6832, 1), (0xff33, 6833, 1), (0xff34, 6834, 1),
+    (0xff35, 6835, 1), (0xff36, 6836, 1), (0xff37, 6837, 1), (0xff38, 6838, 1), (0xff39, 6839, 1),
+    (0xff3a, 6840, 1), (0xff3b, 6841, 1), (0xff3c, 6842, 1), (0xff3d, 6843, 1), (0xff3e, 6844, 1),
+    (0xff3f, 6845, 1), (0xff40, 6846, 1), (0xff41, 6847, 1), (0xff42, 6848, 1), (0xff43, 6849, 1),
+    (0xff44, 6850, 1), (0xff45, 6851, 1), (0xff46, 6852, 1), (0xff47, 6853, 1), (0xff48, 6854, 1),
+    (0xff49, 6855, 1), (0xff4a, 6856, 1), (0xff4b, 6857, 1), (0xff4c, 6858, 1), (0xff4d, 6859, 1),
+    (0xff4e, 6860, 1), (0xff4f, 6861, 1), (0xff50, 6862, 1), (0xff51, 6863, 1), (0xff52, 6864, 1),
+    (0xff53, 6865, 1), (0xff54, 6866, 1), (0xff55, 6867, 1), (0xff56, 6868, 1), (0xff57, 6869, 1),
+    (0xff58, 6870, 1), (0xff59, 6871, 1), (0xff5a, 6872, 1), (0xff5b, 6873, 1), (0xff5c, 6874, 1),
+    (0xff5d, 6875, 1), (0xff5e, 6876, 1), (0xff5f, 6877, 1), (0xff60, 6878, 1), (0xff61, 6879, 1),
+    (0xff62, 6880, 1), (0xff63, 6881, 1), (0xff64, 6882, 1), (0xff65, 6883, 1), (0xff66, 6884, 1),
+    (0xff67, 6885, 1), (0xff68, 6886, 1), (0xff69, 6887, 1), (0xff6a, 6888, 1), (0xff6b, 6889, 1),
+    (0xff6c, 6890, 1), (0xff6d, 6891, 1), (0xff6e, 6892, 1), (0xff6f, 6893, 1), (0xff70, 6894, 1),
+    (0xff71, 6895, 1), (0xff72, 6896, 1), (0xff73, 6897, 1), (0xff74, 6898, 1), (0xff75, 6899, 1),
+    (0xff76, 6900, 1), (0xff77, 6901, 1), (0xff78, 6902, 1), (0xff79, 6903, 1), (0xff7a, 6904, 1),
+    (0xff7b, 6905, 1), (0xff7c, 6906, 1), (0xff7d, 6907, 1), (0xff7e, 6908, 1), (0xff7f, 6909, 1),
+    (0xff80, 6910, 1), (0xff81, 6911, 1), (0xff82, 6912, 1), (0xff83, 6913, 1), (0xff84, 6914, 1),
+    (0xff85, 6915, 1), (0xff86, 6916, 1), (0xff87, 6917, 1), (0xff88, 6918, 1), (0xff89, 6919, 1),
+    (0xff8a, 6920, 1), (0xff8b, 6921, 1), (0xff8c, 6922, 1), (0xff8d, 6923, 1), (0xff8e, 6924, 1),
+    (0xff8f, 6925, 1), (0xff90, 6926, 1), (0xff91, 6927, 1), (0xff92, 6928, 1), (0xff93, 6929, 1),
+    (0xff94, 6930, 1), (0xff95, 6931, 1), (0xff96, 6932, 1), (0xff97, 6933, 1), (0xff98, 6934, 1),
+    (0xff99, 6935, 1), (0xff9a, 6936, 1), (0xff9b, 6937, 1), (0xff9c, 6938, 1), (0xff9d, 6939, 1),
+    (0xff9e, 6940, 1), (0xff9f, 6941, 1), (0xffa0, 6942, 1), (0xffa1, 6943, 1), (0xffa2, 6944, 1),
+    (0xffa3, 6945, 1), (0xffa4, 6946, 1), (0xffa5, 6947, 1), (0xffa6, 6948, 1), (0xffa7, 6949, 1),
+    (0xffa8, 6950, 1), (0xffa9, 6951, 1), (0xffaa, 6952, 1), (0xffab, 6953, 1), (0xffac, 6954, 1),
+    (0xffad, 6955, 1), (0xffae, 6956, 1), (0xffaf, 6957, 1), (0xffb0, 6958, 1), (0xffb1, 6959, 1),
+    (0xffb2, 6960, 1), (0xffb3, 6961, 1), (0xffb4, 6962, 1), (0xffb5, 6963, 1), (0xffb6, 6964, 1),
+    (0xffb7, 6965, 1), (0xffb8, 6966, 1), (0xffb9, 6967, 1), (0xffba, 6968, 1), (0xffbb, 6969, 1),
+    (0xffbc, 6970, 1), (0xffbd, 6971, 1), (0xffbe, 6972, 1), (0xffc2, 6973, 1), (0xffc3, 6974, 1),
+    (0xffc4, 6975, 1), (0xffc5, 6976, 1), (0xffc6, 6977, 1), (0xffc7, 6978, 1), (0xffca, 6979, 1),
+    (0xffcb, 6980, 1), (0xffcc, 6981, 1), (0xffcd, 6982, 1), (0xffce, 6983, 1), (0xffcf, 6984, 1),
+    (0xffd2, 6985, 1), (0xffd3, 6986, 1), (0xffd4, 6987, 1), (0xffd5, 6988, 1), (0xffd6, 6989, 1),
+    (0xffd7, 6990, 1), (0xffda, 6991, 1), (0xffdb, 6992, 1), (0xffdc, 6993, 1), (0xffe0, 6994, 1),
+    (0xffe1, 6995, 1), (0xffe2, 6996, 1), (0xffe3, 6997, 2), (0xffe4, 6999, 1), (0xffe5, 7000, 1),
+    (0xffe6, 7001, 1), (0xffe8, 7002, 1), (0xffe9, 7003, 1), (0xffea, 7004, 1), (0xffeb, 7005, 1),
+    (0xffec, 7006, 1), (0xffed, 7007, 1), (0xffee, 7008, 1), (0x10781, 7009, 1), (0x10782, 7010, 1),
+    (0x10783, 7011, 1), (0x10784, 7012, 1), (0x10785, 7013, 1), (0x10787, 7014, 1), (0x10788, 7015, 1),
+    (0x10789, 7016, 1), (0x1078a, 7017, 1), (0x1078b, 7018, 1), (0x1078c, 7019, 1), (0x1078d, 7020, 1),
+    (0x1078e, 7021, 1), (0x1078f, 7022, 1), (0x10790, 7023, 1), (0x10791, 7024, 1), (0x10792, 7025, 1),
+    (0x10793, 7026, 1), (0x10794, 7027, 1), (0x10795, 7028, 1), (0x10796, 7029, 1), (0x10797, 7030, 1),
+    (0x10798, 7031, 1), (0x10799, 7032, 1), (0x1079a, 7033, 1), (0x1079b, 7034, 1), (0x1079c, 7035, 1),
+    (0x1079d, 7036, 1), (0x1079e, 7037, 1), (0x1079f, 7038, 1), (0x107a0, 7039, 1), (0x107a1, 7040, 1),
+    (0x107a2, 7041, 1), (0x107a3, 7042, 1), (0x107a4, 7043, 1), (0x107a5, 7044, 1), (0x107a6, 7045, 1),
+    (0x107a7, 7046, 1), (0x107a8, 7047, 1), (0x107a9, 7048, 1), (0x107aa, 7049, 1), (0x107ab, 7050, 1),
+    (0x107ac, 7051, 1), (0x107ad, 7052, 1), (0x107ae, 7053, 1), (0x107af, 7054, 1), (0x107b0, 7055, 1),
+    (0x107b2, 7056, 1), (0x107b3, 7057, 1), (0x107b4, 7058, 1), (0x107b5, 7059, 1), (0x107b6, 7060, 1),
+    (0x107b7, 7061, 1), (0x107b8, 7062, 1), (0x107b9, 7063, 1), (0x107ba, 7064, 1), (0x1109a, 7065, 2),
+    (0x1109c, 7067, 2), (0x110ab, 7069, 2), (0x1112e, 7071, 2), (0x1112f, 7073, 2), (0x1134b, 7075, 2),
+    (0x1134c, 7077, 2), (0x114bb, 7079, 2), (0x114bc, 7081, 2), (0x114be, 7083, 2), (0x115ba, 7085, 2),
+    (0x115bb, 7087, 2), (0x11938, 7089, 2), (0x1d15e, 7091, 2), (0x1d15f, 7093, 2), (0x1d160, 7095, 3),
+    (0x1d161, 7098, 3), (0x1d162, 7101, 3), (0x1d163, 7104, 3), (0x1d164, 7107, 3), (0x1d1bb, 7110, 2),
+    (0x1d1bc, 7112, 2), (0x1d1bd, 7114, 3), (0x1d1be, 7117, 3), (0x1d1bf, 7120, 3), (0x1d1c0, 7123, 3),
+    (0x1d400, 7126, 1), (0x1d401, 7127, 1), (0x1d402, 7128, 1), (0x1d403, 7129, 1), (0x1d404, 7130, 1),
+    (0x1d405, 7131, 1), (0x1d406, 7132, 1), (0x1d407, 7133, 1), (0x1d408, 7134, 1), (0x1d409, 7135, 1),
+    (0x1d40a, 7136, 1), (0x1d40b, 7137, 1), (0x1d40c, 7138, 1), (0x1d40d, 7139, 1), (0x1d40e, 7140, 1),
+    (0x1d40f, 7141, 1), (0x1d410, 7142, 1), (0x1d411, 7143, 1), (0x1d412, 7144, 1), (0x1d413, 7145, 1),
+    (0x1d414, 7146, 1), (0x1d415, 7147, 1), (0x1d416, 7148, 1), (0x1d417, 7149, 1), (0x1d418, 7150, 1),
+    (0x1d419, 7151, 1), (0x1d41a, 7152, 1), (0x1d41b, 7153, 1), (0x1d41c, 7154, 1), (0x1d41d, 7155, 1),
+    (0x1d41e, 7156, 1), (0x1d41f, 7157, 1), (0x1d420, 7158, 1), (0x1d421, 7159, 1), (0x1d422, 7160, 1),
+    (0x1d423, 7161, 1), (0x1d424, 7162, 1), (0x1d425, 7163, 1), (0x1d426, 7164, 1), (0x1d427, 7165, 1),
+    (0x1d428, 7166, 1), (0x1d429, 7167, 1), (0x1d42a, 7168, 1), (0x1d42b, 7169, 1), (0x1d42c, 7170, 1),
+    (0x1d42d, 7171, 1), (0x1d42e, 7172, 1), (0x1d42f, 7173, 1), (0x1d430, 7174, 1), (0x1d431, 7175, 1),
+    (0x1d432, 7176, 1), (0x1d433, 7177, 1), (0x1d434, 7178, 1), (0x1d435, 7179, 1), (0x1d436, 7180, 1),
+    (0x1d437, 7181, 1), (0x1d438, 7182, 1), (0x1d439, 7183, 1), (0x1d43a, 7184, 1), (0x1d43b, 7185, 1),
+    (0x1d43c, 7186, 1), (0x1d43d, 7187, 1), (0x1d43e, 7188, 1), (0x1d43f, 7189, 1), (0x1d440, 7190, 1),
+    (0x1d441, 7191, 1), (0x1d442, 7192, 1), (0x1d443, 7193, 1), (0x1d444, 7194, 1), (0x1d445, 7195, 1),
+    (0x1d446, 7196, 1), (0x1d447, 7197, 1), (0x1d448, 7198, 1), (0x1d449, 7199, 1), (0x1d44a, 7200, 1),
+    (0x1d44b, 7201, 1), (0x1d44c, 7202, 1), (0x1d44d, 7203, 1), (0x1d44e, 7204, 1), (0x1d44f, 7205, 1),
+    (0x1d450, 7206, 1), (0x1d451, 7207, 1), (0x1d452, 7208, 1), (0x1d453, 7209, 1), (0x1d454, 7210, 1),
+    (0x1d456, 7211, 1), (0x1d457, 7212, 1), (0x1d458, 7213, 1), (0x1d459, 7214, 1), (0x1d45a, 7215, 1),
+    (0x1d45b, 7216, 1), (0x1d45c, 7217, 1), (0x1d45d, 7218, 1), (0x1d45e, 7219, 1), (0x1d45f, 7220, 1),
+    (0x1d460, 7221, 1), (0x1d461, 7222, 1), (0x1d462, 7223, 1), (0x1d463, 7224, 1), (0x1d464, 7225, 1),
+    (0x1d465, 7226, 1), (0x1d466, 7227, 1), (0x1d467, 7228, 1), (0x1d468, 7229, 1), (0x1d469, 7230, 1),
+    (0x1d46a, 7231, 1), (0x1d46b, 7232, 1), (0x1d46c, 7233, 1), (0x1d46d, 7234, 1), (0x1d46e, 7235, 1),
+    (0x1d46f, 7236, 1), (0x1d470, 7237, 1), (0x1d471, 7238, 1), (0x1d472, 7239, 1), (0x1d473, 7240, 1),
+    (0x1d474, 7241, 1), (0x1d475, 7242, 1), (0x1d476, 7243, 1), (0x1d477, 7244, 1), (0x1d478, 7245, 1),
+    (0x1d479, 7246, 1), (0x1d47a, 7247, 1), (0x1d47b, 7248, 1), (0x1d47c, 7249, 1), (0x1d47d, 7250, 1),
+    (0x1d47e, 7251, 1), (0x1d47f, 7252, 1), (0x1d480, 7253, 1), (0x1d481, 7254, 1), (0x1d482, 7255, 1),
+    (0x1d483, 7256, 1), (0x1d484, 7257, 1), (0x1d485, 7258, 1), (0x1d486, 7259, 1), (0x1d487, 7260, 1),
+    (0x1d488, 7261, 1), (0x1d489, 7262, 1), (0x1d48a, 7263, 1), (0x1d48b, 7264, 1), (0x1d48c, 7265, 1),
+    (0x1d48d, 7266, 1), (0x1d48e, 7267, 1), (0x1d48f, 7268, 1), (0x1d490, 7269, 1), (0x1d491, 7270, 1),
+    (0x1d492, 7271, 1), (0x1d493, 7272, 1), (0x1d494, 7273, 1), (0x1d495, 7274, 1), (0x1d496, 7275, 1),
+    (0x1d497, 7276, 1), (0x1d498, 7277, 1), (0x1d499, 7278, 1), (0x1d49a, 7279, 1), (0x1d49b, 7280, 1),
+    (0x1d49c, 7281, 1), (0x1d49e, 7282, 1), (0x1d49f, 7283, 1), (0x1d4a2, 7284, 1), (0x1d4a5, 7285, 1),
+    (0x1d4a6, 7286, 1), (0x1d4a9, 7287, 1), (0x1d4aa, 7288, 1), (0x1d4ab, 7289, 1), (0x1d4ac, 7290, 1),
+    (0x1d4ae, 7291, 1), (0x1d4af, 7292, 1), (0x1d4b0, 7293, 1), (0x1d4b1, 7294, 1), (0x1d4b2, 7295, 1),
+    (0x1d4b3, 7296, 1), (0x1d4b4, 7297, 1), (0x1d4b5, 7298, 1), (0x1d4b6, 7299, 1), (0x1d4b7, 7300, 1),
+    (0x1d4b8, 7301, 1), (0x1d4b9, 7302, 1), (0x1d4bb, 7303, 1), (0x1d4bd, 7304, 1), (0x1d4be, 7305, 1),
+    (0x1d4bf, 7306, 1), (0x1d4c0, 7307, 1), (0x1d4c1, 7308, 1), (0x1d4c2, 7309, 1), (0x1d4c3, 7310, 1),
+    (0x1d4c5, 7311, 1), (0x1d4c6, 7312, 1), (0x1d4c7, 7313, 1), (0x1d4c8, 7314, 1), (0x1d4c9, 7315, 1),
+    (0x1d4ca, 7316, 1), (0x1d4cb, 7317, 1), (0x1d4cc, 7318, 1), (0x1d4cd, 7319, 1), (0x1d4ce, 7320, 1),
+    (0x1d4cf, 7321, 1), (0x1d4d0, 7322, 1), (0x1d4d1, 7323, 1), (0x1d4d2, 7324, 1), (0x1d4d3, 7325, 1),
+    (0x1d4d4, 7326, 1), (0x1d4d5, 7327, 1), (0x1d4d6, 7328, 1), (0x1d4d7, 7329, 1), (0x1d4d8, 7330, 1),
+    (0x1d4d9, 7331, 1), (0x1d4da, 7332, 1), (0x1d4db, 7333, 1), (0x1d4dc, 7334, 1), (0x1d4dd, 7335, 1),
+    (0x1d4de, 7336, 1), (0x1d4df, 7337, 1), (0x1d4e0, 7338, 1), (0x1d4e1, 7339, 1), (0x1d4e2, 7340, 1),
+    (0x1d4e3, 7341, 1), (0x1d4e4, 7342, 1), (0x1d4e5, 7343, 1), (0x1d4e6, 7344, 1), (0x1d4e7, 7345, 1),
+    (0x1d4e8, 7346, 1), (0x1d4e9, 7347, 1), (0x1d4ea, 7348, 1), (0x1d4eb, 7349, 1), (0x1d4ec, 7350, 1),
+    (0x1d4ed, 7351, 1), (0x1d4ee, 7352, 1), (0x1d4ef, 7353, 1), (0x1d4f0, 7354, 1), (0x1d4f1, 7355, 1),
+    (0x1d4f2, 7356, 1), (0x1d4f3, 7357, 1), (0x1d4f4, 7358, 1), (0x1d4f5, 7359, 1), (0x1d4f6, 7360, 1),
+    (0x1d4f7, 7361, 1), (0x1d4f8, 7362, 1), (0x1d4f9, 7363, 1), (0x1d4fa, 7364, 1), (0x1d4fb, 7365, 1),
+    (0x1d4fc, 7366, 1), (0x1d4fd, 7367, 1), (0x1d4fe, 7368, 1), (0x1d4ff, 7369, 1), (0x1d500, 7370, 1),
+    (0x1d501, 7371, 1), (0x1d502, 7372, 1), (0x1d503, 7373, 1), (0x1d504, 7374, 1), (0x1d505, 7375, 1),
+    (0x1d507, 7376, 1), (0x1d508, 7377, 1), (0x1d509, 7378, 1), (0x1d50a, 7379, 1), (0x1d50d, 7380, 1),
+    (0x1d50e, 7381, 1), (0x1d50f, 7382, 1), (0x1d510, 7383, 1), (0x1d511, 7384, 1), (0x1d512, 7385, 1),
+    (0x1d513, 7386, 1), (0x1d514, 7387, 1), (0x1d516, 7388, 1), (0x1d517, 7389, 1), (0x1d518, 7390, 1),
+    (0x1d519, 7391, 1), (0x1d51a, 7392, 1), (0x1d51b, 7393, 1), (0x1d51c, 7394, 1), (0x1d51e, 7395, 1),
+    (0x1d51f, 7396, 1), (0x1d520, 7397, 1), (0x1d521, 7398, 1), (0x1d522, 7399, 1), (0x1d523, 7400, 1),
+    (0x1d524, 7401, 1), (0x1d525, 7402, 1), (0x1d526, 7403, 1), (0x1d527, 7404, 1), (0x1d528, 7405, 1),
+    (0x1d529, 7406, 1), (0x1d52a, 7407, 1), (0x1d52b, 7408, 1), (0x1d52c, 7409, 1), (0x1d52d, 7410, 1),
+    (0x1d52e, 7411, 1), (0x1d52f, 7412, 1), (0x1d530, 7413, 1), (0x1d531, 7414, 1), (0x1d532, 7415, 1),
+    (0x1d533, 7416, 1), (0x1d534, 7417, 1), (0x1d535, 7418, 1), (0x1d536, 7419, 1), (0x1d537, 7420, 1),
+    (0x1d538, 7421, 1), (0x1d539, 7422, 1), (0x1d53b, 7423, 1), (0x1d53c, 7424, 1), (0x1d53d, 7425, 1),
+    (0x1d53e, 7426, 1), (0x1d540, 7427, 1), (0x1d541, 7428, 1), (0x1d542, 7429, 1), (0x1d543, 7430, 1),
+    (0x1d544, 7431, 1), (0x1d546, 7432, 1), (0x1d54a, 7433, 1), (0x1d54b, 7434, 1), (0x1d54c, 7435, 1),
+    (0x1d54d, 7436, 1), (0x1d54e, 7437, 1), (0x1d54f, 7438, 1), (0x1d550, 7439, 1), (0x1d552, 7440, 1),
+    (0x1d553, 7441, 1), (0x1d554, 7442, 1), (0x1d555, 7443, 1), (0x1d556, 7444, 1), (0x1d557, 7445, 1),
+    (0x1d558, 7446, 1), (0x1d559, 7447, 1), (0x1d55a, 7448, 1), (0x1d55b, 7449, 1), (0x1d55c, 7450, 1),
+    (0x1d55d, 7451, 1), (0x1d55e, 7452, 1), (0x1d55f, 7453, 1), (0x1d560, 7454, 1), (0x1d561, 7455, 1),
+    (0x1d562, 7456, 1), (0x1d563, 7457, 1), (0x1d564, 7458, 1), (0x1d565, 7459, 1), (0x1d566, 7460, 1),
+    (0x1d567, 7461, 1), (0x1d568, 7462, 1), (0x1d569, 7463, 1), (0x1d56a, 7464, 1), (0x1d56b, 7465, 1),
+    (0x1d56c, 7466, 1), (0x1d56d, 7467, 1), (0x1d56e, 7468, 1), (0x1d56f, 7469, 1), (0x1d570, 7470, 1),
+    (0x1d571, 7471, 1), (0x1d572, 7472, 1), (0x1d573, 7473, 1), (0x1d574, 7474, 1), (0x1d575, 7475, 1),
+    (0x1d576, 7476, 1), (0x1d577, 7477, 1), (0x1d578, 7478, 1), (0x1d579, 7479, 1), (0x1d57a, 7480, 1),
+    (0x1d57b, 7481, 1), (0x1d57c, 7482, 1), (0x1d57d, 7483, 1), (0x1d57e, 7484, 1), (0x1d57f, 7485, 1),
+    (0x1d580, 7486, 1), (0x1d581, 7487, 1), (0x1d582, 7488, 1), (0x1d583, 7489, 1), (0x1d584, 7490, 1),
+    (0x1d585, 7491, 1), (0x1d586, 7492, 1), (0x1d587, 7493, 1), (0x1d588, 7494, 1), (0x1d589, 7495, 1),
+    (0x1d58a, 7496, 1), (0x1d58b, 7497, 1), (0x1d58c, 7498, 1), (0x1d58d, 7499, 1), (0x1d58e, 7500, 1),
+    (0x1d58f, 7501, 1), (0x1d590, 7502, 1), (0x1d591, 7503, 1), (0x1d592, 7504, 1), (0x1d593, 7505, 1),
+    (0x1d594, 7506, 1), (0x1d595, 7507, 1), (0x1d596, 7508, 1), (0x1d597, 7509, 1), (0x1d598, 7510, 1),
+    (0x1d599, 7511, 1), (0x1d59a, 7512, 1), (0x1d59b, 7513, 1), (0x1d59c, 7514, 1), (0x1d59d, 7515, 1),
+    (0x1d59e, 7516, 1), (0x1d59f, 7517, 1), (0x1d5a0, 7518, 1), (0x1d5a1, 7519, 1), (0x1d5a2, 7520, 1),
+    (0x1d5a3, 7521, 1), (0x1d5a4, 7522, 1), (0x1d5a5, 7523, 1), (0x1d5a6, 7524, 1), (0x1d5a7, 7525, 1),
+    (0x1d5a8, 7526, 1), (0x1d5a9, 7527, 1), (0x1d5aa, 7528, 1), (0x1d5ab, 7529, 1), (0x1d5ac, 7530, 1),
+    (0x1d5ad, 7531, 1), (0x1d5ae, 7532, 1), (0x1d5af, 7533, 1), (0x1d5b0, 7534, 1), (0x1d5b1, 7535, 1),
+    (0x1d5b2, 7536, 1), (0x1d5b3, 7537, 1), (0x1d5b4, 7538, 1), (0x1d5b5, 7539, 1), (0x1d5b6, 7540, 1),
+    (0x1d5b7, 7541, 1), (0x1d5b8, 7542, 1), (0x1d5b9, 7543, 1), (0x1d5ba, 7544, 1), (0x1d5bb, 7545, 1),
+    (0x1d5bc, 7546, 1), (0x1d5bd, 7547, 1), (0x1d5be, 7548, 1), (0x1d5bf, 7549, 1), (0x1d5c0, 7550, 1),
+    (0x1d5c1, 7551, 1), (0x1d5c2, 7552, 1), (0x1d5c3, 7553, 1), (0x1d5c4, 7554, 1), (0x1d5c5, 7555, 1),
+    (0x1d5c6, 7556, 1), (0x1d5c7, 7557, 1), (0x1d5c8, 7558, 1), (0x1d5c9, 7559, 1), (0x1d5ca, 7560, 1),
+    (0x1d5cb, 7561, 1), (0x1d5cc, 7562, 1), (0x1d5cd, 7563, 1), (0x1d5ce, 7564, 1), (0x1d5cf, 7565, 1),
+    (0x1d5d0, 7566, 1), (0x1d5d1, 7567, 1), (0x1d5d2, 7568, 1), (0x1d5d3, 7569, 1), (0x1d5d4, 7570, 1),
+    (0x1d5d5, 7571, 1), (0x1d5d6, 7572, 1), (0x1d5d7, 7573, 1), (0x1d5d8, 7574, 1), (0x1d5d9, 7575, 1),
+    (0x1d5da, 7576, 1), (0x1d5db, 7577, 1), (0x1d5dc, 7578, 1), (0x1d5dd, 7579, 1), (0x1d5de, 7580, 1),
+    (0x1d5df, 7581, 1), (0x1d5e0, 7582, 1), (0x1d5e1, 7583, 1), (0x1d5e2, 7584, 1), (0x1d5e3, 7585, 1),
+    (0x1d5e4, 7586, 1), (0x1d5e5, 7587, 1), (0x1d5e6, 7588, 1), (0x1d5e7, 7589, 1), (0x1d5e8, 7590, 1),
+    (0x1d5e9, 7591, 1), (0x1d5ea, 7592, 1), (0x1d5eb, 7593, 1), (0x1d5ec, 7594, 1), (0x1d5ed, 7595, 1),
+    (0x1d5ee, 7596, 1), (0x1d5ef, 7597, 1), (0x1d5f0, 7598, 1), (0x1d5f1, 7599, 1), (0x1d5f2, 7600, 1),
+    (0x1d5f3, 7601, 1), (0x1d5f4, 7602, 1), (0x1d5f5, 7603, 1), (0x1d5f6, 7604, 1), (0x1d5f7, 7605, 1),
+    (0x1d5f8, 7606, 1), (0x1d5f9, 7607, 1), (0x1d5fa, 7608, 1), (0x1d5fb, 7609, 1), (0x1d5fc, 7610, 1),
+    (0x1d5fd, 7611, 1), (0x1d5fe, 7612, 1), (0x1d5ff, 7613, 1), (0x1d600, 7614, 1), (0x1d601, 7615, 1),
+    (0x1d602, 7616, 1), (0x1d603, 7617, 1), (0x1d604, 7618, 1), (0x1d605, 7619, 1), (0x1d606, 7620, 1),
+    (0x1d607, 7621, 1), (0x1d608, 7622, 1), (0x1d609, 7623, 1), (0x1d60a, 7624, 1), (0x1d60b, 7625, 1),
+    (0x1d60c, 7626, 1), (0x1d60d, 7627, 1), (0x1d60e, 7628, 1), (0x1d60f, 7629, 1), (0x1d610, 7630, 1),
+    (0x1d611, 7631, 1), (0x1d612, 7632, 1), (0x1d613, 7633, 1), (0x1d614, 7634, 1), (0x1d615, 7635, 1),
+    (0x1d616, 7636, 1), (0x1d617, 7637, 1), (0x1d618, 7638, 1), (0x1d619, 7639, 1), (0x1d61a, 7640, 1),
+    (0x1d61b, 7641, 1), (0x1d61c, 7642, 1), (0x1d61d, 7643, 1), (0x1d61e, 7644, 1), (0x1d61f, 7645, 1),
+    (0x1d620, 7646, 1), (0x1d621, 7647, 1), (0x1d622, 7648, 1), (0x1d623, 7649, 1), (0x1d624, 7650, 1),
+    (0x1d625, 7651, 1), (0x1d626, 7652, 1), (0x1d627, 7653, 1), (0x1d628, 7654, 1), (0x1d629, 7655, 1),
+    (0x1d62a, 7656, 1), (0x1d62b, 7657, 1), (0x1d62c, 7658, 1), (0x1d62d, 7659, 1), (0x1d62e, 7660, 1),
+    (0x1d62f, 7661, 1), (0x1d630, 7662, 1), (0x1d631, 7663, 1), (0x1d632, 7664, 1), (0x1d633, 7665, 1),
+    (0x1d634, 7666, 1), (0x1d635, 7667, 1), (0x1d636, 7668, 1), (0x1d637, 7669, 1), (0x1d638, 7670, 1),
+    (0x1d639, 7671, 1), (0x1d63a, 7672, 1), (0x1d63b, 7673, 1), (0x1d63c, 7674, 1), (0x1d63d, 7675, 1),
+    (0x1d63e, 7676, 1), (0x1d63f, 7677, 1), (0x1d640, 7678, 1), (0x1d641, 7679, 1), (0x1d642, 7680, 1),
+    (0x1d643, 7681, 1), (0x1d644, 7682, 1), (0x1d645, 7683, 1), (0x1d646, 7684, 1), (0x1d647, 7685, 1),
+    (0x1d648, 7686, 1), (0x1d649, 7687, 1), (0x1d64a, 7688, 1), (0x1d64b, 7689, 1), (0x1d64c, 7690, 1),
+    (0x1d64d, 7691, 1), (0x1d64e, 7692, 1), (0x1d64f, 7693, 1), (0x1d650, 7694, 1), (0x1d651, 7695, 1),
+    (0x1d652, 7696, 1), (0x1d653, 7697, 1), (0x1d654, 7698, 1), (0x1d655, 7699, 1), (0x1d656, 7700, 1),
+    (0x1d657, 7701, 1), (0x1d658, 7702, 1), (0x1d659, 7703, 1), (0x1d65a, 7704, 1), (0x1d65b, 7705, 1),
+    (0x1d65c, 7706, 1), (0x1d65d, 7707, 1), (0x1d65e, 7708, 1), (0x1d65f, 7709, 1), (0x1d660, 7710, 1),
+    (0x1d661, 7711, 1), (0x1d662, 7712, 1), (0x1d663, 7713, 1), (0x1d664, 7714, 1), (0x1d665, 7715, 1),
+    (0x1d666, 7716, 1), (0x1d667, 7717, 1), (0x1d668, 7718, 1), (0x1d669, 7719, 1), (0x1d66a, 7720, 1),
+    (0x1d66b, 7721, 1), (0x1d66c, 7722, 1), (0x1d66d, 7723, 1), (0x1d66e, 7724, 1), (0x1d66f, 7725, 1),
+    (0x1d670, 7726, 1), (0x1d671, 7727, 1), (0x1d672, 7728, 1), (0x1d673, 7729, 1), (0x1d674, 7730, 1),
+    (0x1d675, 7731, 1), (0x1d676, 7732, 1), (0x1d677, 7733, 1), (0x1d678, 7734, 1), (0x1d679, 7735, 1),
+    (0x1d67a, 7736, 1), (0x1d67b, 7737, 1), (0x1d67c, 7738, 1), (0x1d67d, 7739, 1), (0x1d67e, 7740, 1),
+    (0x1d67f, 7741, 1), (0x1d680, 7742, 1), (0x1d681, 7743, 1), (0x1d682, 7744, 1), (0x1d683, 7745, 1),
+    (0x1d684, 7746, 1), (0x1d685, 7747, 1), (0x1d686, 7748, 1), (0x1d687, 7749, 1), (0x1d688, 7750, 1),
+    (0x1d689, 7751, 1), (0x1d68a, 7752, 1), (0x1d68b, 7753, 1), (0x1d68c, 7754, 1), (0x1d68d, 7755, 1),
+    (0x1d68e, 7756, 1), (0x1d68f, 7757, 1), (0x1d690, 7758, 1), (0x1d691, 7759, 1), (0x1d692, 7760, 1),
+    (0x1d693, 7761, 1), (0x1d694, 7762, 1), (0x1d695, 7763, 1), (0x1d696, 7764, 1), (0x1d697, 7765, 1),
+    (0x1d698, 7766, 1), (0x1d699, 7767, 1), (0x1d69a, 7768, 1), (0x1d69b, 7769, 1), (0x1d69c, 7770, 1),
+    (0x1d69d, 7771, 1), (0x1d69e, 7772, 1), (0x1d69f, 7773, 1), (0x1d6a0, 7774, 1), (0x1d6a1, 7775, 1),
+    (0x1d6a2, 7776, 1), (0x1d6a3, 7777, 1), (0x1d6a4, 7778, 1), (0x1d6a5, 7779, 1), (0x1d6a8, 7780, 1),
+    (0x1d6a9, 7781, 1), (0x1d6aa, 7782, 1), (0x1d6ab, 7783, 1), (0x1d6ac, 7784, 1), (0x1d6ad, 7785, 1),
+    (0x1d6ae, 7786, 1), (0x1d6af, 7787, 1), (0x1d6b0, 7788, 1), (0x1d6b1, 7789, 1), (0x1d6b2, 7790, 1),
+    (0x1d6b3, 7791, 1), (0x1d6b4, 7792, 1), (0x1d6b5, 7793, 1), (0x1d6b6, 7794, 1), (0x1d6b7, 7795, 1),
+    (0x1d6b8, 7796, 1), (0x1d6b9, 7797, 1), (0x1d6ba, 7798, 1), (0x1d6bb, 7799, 1), (0x1d6bc, 7800, 1),
+    (0x1d6bd, 7801, 1), (0x1d6be, 7802, 1), (0x1d6bf, 7803, 1), (0x1d6c0, 7804, 1), (0x1d6c1, 7805, 1),
+    (0x1d6c2, 7806, 1), (0x1d6c3, 7807, 1), (0x1d6c4, 7808, 1), (0x1d6c5, 7809, 1), (0x1d6c6, 7810, 1),
+    (0x1d6c7, 7811, 1), (0x1d6c8, 7812, 1), (0x1d6c9, 7813, 1), (0x1d6ca, 7814, 1), (0x1d6cb, 7815, 1),
+    (0x1d6cc, 7816, 1), (0x1d6cd, 7817, 1), (0x1d6ce, 7818, 1), (0x1d6cf, 7819, 1), (0x1d6d0, 7820, 1),
+    (0x1d6d1, 7821, 1), (0x1d6d2, 7822, 1), (0x1d6d3, 7823, 1), (0x1d6d4, 7824, 1), (0x1d6d5, 7825, 1),
+    (0x1d6d6, 7826, 1), (0x1d6d7, 7827, 1), (0x1d6d8, 7828, 1), (0x1d6d9, 7829, 1), (0x1d6da, 7830, 1),
+    (0x1d6db, 7831, 1), (0x1d6dc, 7832, 1), (0x1d6dd, 7833, 1), (0x1d6de, 7834, 1), (0x1d6df, 7835, 1),
+    (0x1d6e0, 7836, 1), (0x1d6e1, 7837, 1), (0x1d6e2, 7838, 1), (0x1d6e3, 7839, 1), (0x1d6e4, 7840, 1),
+    (0x1d6e5, 7841, 1), (0x1d6e6, 7842, 1), (0x1d6e7, 7843, 1), (0x1d6e8, 7844, 1), (0x1d6e9, 7845, 1),
+    (0x1d6ea, 7846, 1), (0x1d6eb, 7847, 1), (0x1d6ec, 7848, 1), (0x1d6ed, 7849, 1), (0x1d6ee, 7850, 1),
+    (0x1d6ef, 7851, 1), (0x1d6f0, 7852, 1), (0x1d6f1, 7853, 1), (0x1d6f2, 7854, 1), (0x1d6f3, 7855, 1),
+    (0x1d6f4, 7856, 1), (0x1d6f5, 7857, 1), (0x1d6f6, 7858, 1), (0x1d6f7, 7859, 1), (0x1d6f8, 7860, 1),
+    (0x1d6f9, 7861, 1), (0x1d6fa, 7862, 1), (0x1d6fb, 7863, 1), (0x1d6fc, 7864, 1), (0x1d6fd, 7865, 1),
+    (0x1d6fe, 7866, 1), (0x1d6ff, 7867, 1), (0x1d700, 7868, 1), (0x1d701, 7869, 1), (0x1d702, 7870, 1),
+    (0x1d703, 7871, 1), (0x1d704, 7872, 1), (0x1d705, 7873, 1), (0x1d706, 7874, 1), (0x1d707, 7875, 1),
+    (0x1d708, 7876, 1), (0x1d709, 7877, 1), (0x1d70a, 7878, 1), (0x1d70b, 7879, 1), (0x1d70c, 7880, 1),
+    (0x1d70d, 7881, 1), (0x1d70e, 7882, 1), (0x1d70f, 7883, 1), (0x1d710, 7884, 1), (0x1d711, 7885, 1),
+    (0x1d712, 7886, 1), (0x1d713, 7887, 1), (0x1d714, 7888, 1), (0x1d715, 7889, 1), (0x1d716, 7890, 1),
+    (0x1d717, 7891, 1), (0x1d718, 7892, 1), (0x1d719, 7893, 1), (0x1d71a, 7894, 1), (0x1d71b, 7895, 1),
+    (0x1d71c, 7896, 1), (0x1d71d, 7897, 1), (0x1d71e, 7898, 1), (0x1d71f, 7899, 1), (0x1d720, 7900, 1),
+    (0x1d721, 7901, 1), (0x1d722, 7902, 1), (0x1d723, 7903, 1), (0x1d724, 7904, 1), (0x1d725, 7905, 1),
+    (0x1d726, 7906, 1), (0x1d727, 7907, 1), (0x1d728, 7908, 1), (0x1d729, 7909, 1), (0x1d72a, 7910, 1),
+    (0x1d72b, 7911, 1), (0x1d72c, 7912, 1), (0x1d72d, 7913, 1), (0x1d72e, 7914, 1), (0x1d72f, 7915, 1),
+    (0x1d730, 7916, 1), (0x1d731, 7917, 1), (0x1d732, 7918, 1), (0x1d733, 7919, 1), (0x1d734, 7920, 1),
+    (0x1d735, 7921, 1), (0x1d736, 7922, 1), (0x1d737, 7923, 1), (0x1d738, 7924, 1), (0x1d739, 7925, 1),
+    (0x1d73a, 7926, 1), (0x1d73b, 7927, 1), (0x1d73c, 7928, 1), (0x1d73d, 7929, 1), (0x1d73e, 7930, 1),
+    (0x1d73f, 7931, 1), (0x1d740, 7932, 1), (0x1d741, 7933, 1), (0x1d742, 7934, 1), (0x1d743, 7935, 1),
+    (0x1d744, 7936, 1), (0x1d745, 7937, 1), (0x1d746, 7938, 1), (0x1d747, 7939, 1), (0x1d748, 7940, 1),
+    (0x1d749, 7941, 1), (0x1d74a, 7942, 1), (0x1d74b, 7943, 1), (0x1d74c, 7944, 1), (0x1d74d, 7945, 1),
+    (0x1d74e, 7946, 1), (0x1d74f, 7947, 1), (0x1d750, 7948, 1), (0x1d751, 7949, 1), (0x1d752, 7950, 1),
+    (0x1d753, 7951, 1), (0x1d754, 7952, 1), (0x1d755, 7953, 1), (0x1d756, 7954, 1), (0x1d757, 7955, 1),
+    (0x1d758, 7956, 1), (0x1d759, 7957, 1), (0x1d75a, 7958, 1), (0x1d75b, 7959, 1), (0x1d75c, 7960, 1),
+    (0x1d75d, 7961, 1), (0x1d75e, 7962, 1), (0x1d75f, 7963, 1), (0x1d760, 7964, 1), (0x1d761, 7965, 1),
+    (0x1d762, 7966, 1), (0x1d763, 7967, 1), (0x1d764, 7968, 1), (0x1d765, 7969, 1), (0x1d766, 7970, 1),
+    (0x1d767, 7971, 1), (0x1d768, 7972, 1), (0x1d769, 7973, 1), (0x1d76a, 7974, 1), (0x1d76b, 7975, 1),
+    (0x1d76c, 7976, 1), (0x1d76d, 7977, 1), (0x1d76e, 7978, 1), (0x1d76f, 7979, 1), (0x1d770, 7980, 1),
+    (0x1d771, 7981, 1), (0x1d772, 7982, 1), (0x1d773, 7983, 1), (0x1d774, 7984, 1), (0x1d775, 7985, 1),
+    (0x1d776, 7986, 1), (0x1d777, 7987, 1), (0x1d778, 7988, 1), (0x1d779, 7989, 1), (0x1d77a, 7990, 1),
+    (0x1d77b, 7991, 1), (0x1d77c, 7992, 1), (0x1d77d, 7993, 1), (0x1d77e, 7994, 1), (0x1d77f, 7995, 1),
+    (0x1d780, 7996, 1), (0x1d781, 7997, 1), (0x1d782, 7998, 1), (0x1d783, 7999, 1), (0x1d784, 8000, 1),
+    (0x1d785, 8001, 1), (0x1d786, 8002, 1), (0x1d787, 8003, 1), (0x1d788, 8004, 1), (0x1d789, 8005, 1),
+    (0x1d78a, 8006, 1), (0x1d78b, 8007, 1), (0x1d78c, 8008, 1), (0x1d78d, 8009, 1), (0x1d78e, 8010, 1),
+    (0x1d78f, 8011, 1), (0x1d790, 8012, 1), (0x1d791, 8013, 1), (0x1d792, 8014, 1), (0x1d793, 8015, 1),
+    (0x1d794, 8016, 1), (0x1d795, 8017, 1), (0x1d796, 8018, 1), (0x1d797, 8019, 1), (0x1d798, 8020, 1),
+    (0x1d799, 8021, 1), (0x1d79a, 8022, 1), (0x1d79b, 8023, 1), (0x1d79c, 8024, 1), (0x1d79d, 8025, 1),
+    (0x1d79e, 8026, 1), (0x1d79f, 8027, 1), (0x1d7a0, 8028, 1), (0x1d7a1, 8029, 1), (0x1d7a2, 8030, 1),
+    (0x1d7a3, 8031, 1), (0x1d7a4, 8032, 1), (0x1d7a5, 8033, 1), (0x1d7a6, 8034, 1), (0x1d7a7, 8035, 1),
+    (0x1d7a8, 8036, 1), (0x1d7a9, 8037, 1), (0x1d7aa, 8038, 1), (0x1d7ab, 8039, 1), (0x1d7ac, 8040, 1),
+    (0x1d7ad, 8041, 1), (0x1d7ae, 8042, 1), (0x1d7af, 8043, 1), (0x1d7b0, 8044, 1), (0x1d7b1, 8045, 1),
+    (0x1d7b2, 8046, 1), (0x1d7b3, 8047, 1), (0x1d7b4, 8048, 1), (0x1d7b5, 8049, 1), (0x1d7b6, 8050, 1),
+    (0x1d7b7, 8051, 1), (0x1d7b8, 8052, 1), (0x1d7b9, 8053, 1), (0x1d7ba, 8054, 1), (0x1d7bb, 8055, 1),
+    (0x1d7bc, 8056, 1), (0x1d7bd, 8057, 1), (0x1d7be, 8058, 1), (0x1d7bf, 8059, 1), (0x1d7c0, 8060, 1),
+    (0x1d7c1, 8061, 1), (0x1d7c2, 8062, 1), (0x1d7c3, 8063, 1), (0x1d7c4, 8064, 1), (0x1d7c5, 8065, 1),
+    (0x1d7c6, 8066, 1), (0x1d7c7, 8067, 1), (0x1d7c8, 8068, 1), (0x1d7c9, 8069, 1), (0x1d7ca, 8070, 1),
+    (0x1d7cb, 8071, 1), (0x1d7ce, 8072, 1), (0x1d7cf, 8073, 1), (0x1d7d0, 8074, 1), (0x1d7d1, 8075, 1),
+    (0x1d7d2, 8076, 1), (0x1d7d3, 8077, 1), (0x1d7d4, 8078, 1), (0x1d7d5, 8079, 1), (0x1d7d6, 8080, 1),
+    (0x1d7d7, 8081, 1), (0x1d7d8, 8082, 1), (0x1d7d9, 8083, 1), (0x1d7da, 8084, 1), (0x1d7db, 8085, 1),
+    (0x1d7dc, 8086, 1), (0x1d7dd, 8087, 1), (0x1d7de, 8088, 1), (0x1d7df, 8089, 1), (0x1d7e0, 8090, 1),
+    (0x1d7e1, 8091, 1), (0x1d7e2, 8092, 1), (0x1d7e3, 8093, 1), (0x1d7e4, 8094, 1), (0x1d7e5, 8095, 1),
+    (0x1d7e6, 8096, 1), (0x1d7e7, 8097, 1), (0x1d7e8, 8098, 1), (0x1d7e9, 8099, 1), (0x1d7ea, 8100, 1),
+    (0x1d7eb, 8101, 1), (0x1d7ec, 8102, 1), (0x1d7ed, 8103, 1), (0x1d7ee, 8104, 1), (0x1d7ef, 8105, 1),
+    (0x1d7f0, 8106, 1), (0x1d7f1, 8107, 1), (0x1d7f2, 8108, 1), (0x1d7f3, 8109, 1), (0x1d7f4, 8110, 1),
+    (0x1d7f5, 8111, 1), (0x1d7f6, 8112, 1), (0x1d7f7, 8113, 1), (0x1d7f8, 8114, 1), (0x1d7f9, 8115, 1),
+    (0x1d7fa, 8116, 1), (0x1d7fb, 8117, 1), (0x1d7fc, 8118, 1), (0x1d7fd, 8119, 1), (0x1d7fe, 8120, 1),
+    (0x1d7ff, 8121, 1), (0x1ee00, 8122, 1), (0x1ee01, 8123, 1), (0x1ee02, 8124, 1), (0x1ee03, 8125, 1),
+    (0x1ee05, 8126, 1), (0x1ee06, 8127, 1), (0x1ee07, 8128, 1), (0x1ee08, 8129, 1), (0x1ee09, 8130, 1),
+    (0x1ee0a, 8131, 1), (0x1ee0b, 8132, 1), (0x1ee0c, 8133, 1), (0x1ee0d, 8134, 1), (0x1ee0e, 8135, 1),
+    (0x1ee0f, 8136, 1), (0x1ee10, 8137, 1), (0x1ee11, 8138, 1), (0x1ee12, 8139, 1), (0x1ee13, 8140, 1),
+    (0x1ee14, 8141, 1), (0x1ee15, 8142, 1), (0x1ee16, 8143, 1), (0x1ee17, 8144, 1), (0x1ee18, 8145, 1),
+    (0x1ee19, 8146, 1), (0x1ee1a, 8147, 1), (0x1ee1b, 8148, 1), (0x1ee1c, 8149, 1), (0x1ee1d, 8150, 1),
+    (0x1ee1e, 8151, 1), (0x1ee1f, 8152, 1), (0x1ee21, 8153, 1), (0x1ee22, 8154, 1), (0x1ee24, 8155, 1),
+    (0x1ee27, 8156, 1), (0x1ee29, 8157, 1), (0x1ee2a, 8158, 1), (0x1ee2b, 8159, 1), (0x1ee2c, 8160, 1),
+    (0x1ee2d, 8161, 1), (0x1ee2e, 8162, 1), (0x1ee2f, 8163, 1), (0x1ee30, 8164, 1), (0x1ee31, 8165, 1),
+    (0x1ee32, 8166, 1), (0x1ee34, 8167, 1), (0x1ee35, 8168, 1), (0x1ee36, 8169, 1), (0x1ee37, 8170, 1),
+    (0x1ee39, 8171, 1), (0x1ee3b, 8172, 1), (0x1ee42, 8173, 1), (0x1ee47, 8174, 1), (0x1ee49, 8175, 1),
+    (0x1ee4b, 8176, 1), (0x1ee4d, 8177, 1), (0x1ee4e, 8178, 1), (0x1ee4f, 8179, 1), (0x1ee51, 8180, 1),
+    (0x1ee52, 8181, 1), (0x1ee54, 8182, 1), (0x1ee57, 8183, 1), (0x1ee59, 8184, 1), (0x1ee5b, 8185, 1),
+    (0x1ee5d, 8186, 1), (0x1ee5f, 8187, 1), (0x1ee61, 8188, 1), (0x1ee62, 8189, 1), (0x1ee64, 8190, 1),
+    (0x1ee67, 8191, 1), (0x1ee68, 8192, 1), (0x1ee69, 8193, 1), (0x1ee6a, 8194, 1), (0x1ee6c, 8195, 1),
+    (0x1ee6d, 8196, 1), (0x1ee6e, 8197, 1), (0x1ee6f, 8198, 1), (0x1ee70, 8199, 1), (0x1ee71, 8200, 1),
+    (0x1ee72, 8201, 1), (0x1ee74, 8202, 1), (0x1ee75, 8203, 1), (0x1ee76, 8204, 1), (0x1ee77, 8205, 1),
+    (0x1ee79, 8206, 1), (0x1ee7a, 8207, 1), (0x1ee7b, 8208, 1), (0x1ee7c, 8209, 1), (0x1ee7e, 8210, 1),
+    (0x1ee80, 8211, 1), (0x1ee81, 8212, 1), (0x1ee82, 8213, 1), (0x1ee83, 8214, 1), (0x1ee84, 8215, 1),
+    (0x1ee85, 8216, 1), (0x1ee86, 8217, 1), (0x1ee87, 8218, 1), (0x1ee88, 8219, 1), (0x1ee89, 8220, 1),
+    (0x1ee8b, 8221, 1), (0x1ee8c, 8222, 1), (0x1ee8d, 8223, 1), (0x1ee8e, 8224, 1), (0x1ee8f, 8225, 1),
+    (0x1ee90, 8226, 1), (0x1ee91, 8227, 1), (0x1ee92, 8228, 1), (0x1ee93, 8229, 1), (0x1ee94, 8230, 1),
+    (0x1ee95, 8231, 1), (0x1ee96, 8232, 1), (0x1ee97, 8233, 1), (0x1ee98, 8234, 1), (0x1ee99, 8235, 1),
+    (0x1ee9a, 8236, 1), (0x1ee9b, 8237, 1), (0x1eea1, 8238, 1), (0x1eea2, 8239, 1), (0x1eea3, 8240, 1),
+    (0x1eea5, 8241, 1), (0x1eea6, 8242, 1), (0x1eea7, 8243, 1), (0x1eea8, 8244, 1), (0x1eea9, 8245, 1),
+    (0x1eeab, 8246, 1), (0x1eeac, 8247, 1), (0x1eead, 8248, 1), (0x1eeae, 8249, 1), (0x1eeaf, 8250, 1),
+    (0x1eeb0, 8251, 1), (0x1eeb1, 8252, 1), (0x1eeb2, 8253, 1), (0x1eeb3, 8254, 1), (0x1eeb4, 8255, 1),
+    (0x1eeb5, 8256, 1), (0x1eeb6, 8257, 1), (0x1eeb7, 8258, 1), (0x1eeb8, 8259, 1), (0x1eeb9, 8260, 1),
+    (0x1eeba, 8261, 1), (0x1eebb, 8262, 1), (0x1f100, 8263, 2), (0x1f101, 8265, 2), (0x1f102, 8267, 2),
+    (0x1f103, 8269, 2), (0x1f104, 8271, 2), (0x1f105, 8273, 2), (0x1f106, 8275, 2), (0x1f107, 8277, 2),
+    (0x1f108, 8279, 2), (0x1f109, 8281, 2), (0x1f10a, 8283, 2), (0x1f110, 8285, 3), (0x1f111, 8288, 3),
+    (0x1f112, 8291, 3), (0x1f113, 8294, 3), (0x1f114, 8297, 3), (0x1f115, 8300, 3), (0x1f116, 8303, 3),
+    (0x1f117, 8306, 3), (0x1f118, 8309, 3), (0x1f119, 8312, 3), (0x1f11a, 8315, 3), (0x1f11b, 8318, 3),
+    (0x1f11c, 8321, 3), (0x1f11d, 8324, 3), (0x1f11e, 8327, 3), (0x1f11f, 8330, 3), (0x1f120, 8333, 3),
+    (0x1f121, 8336, 3), (0x1f122, 8339, 3), (0x1f123, 8342, 3), (0x1f124, 8345, 3), (0x1f125, 8348, 3),
+    (0x1f126, 8351, 3), (0x1f127, 8354, 3), (0x1f128, 8357, 3), (0x1f129, 8360, 3), (0x1f12a, 8363, 3),
+    (0x1f12b, 8366, 1), (0x1f12c, 8367, 1), (0x1f12d, 8368, 2), (0x1f12e, 8370, 2), (0x1f130, 8372, 1),
+    (0x1f131, 8373, 1), (0x1f132, 8374, 1), (0x1f133, 8375, 1), (0x1f134, 8376, 1), (0x1f135, 8377, 1),
+    (0x1f136, 8378, 1), (0x1f137, 8379, 1), (0x1f138, 8380, 1), (0x1f139, 8381, 1), (0x1f13a, 8382, 1),
+    (0x1f13b, 8383, 1), (0x1f13c, 8384, 1), (0x1f13d, 8385, 1), (0x1f13e, 8386, 1), (0x1f13f, 8387, 1),
+    (0x1f140, 8388, 1), (0x1f141, 8389, 1), (0x1f142, 8390, 1), (0x1f143, 8391, 1), (0x1f144, 8392, 1),
+    (0x1f145, 8393, 1), (0x1f146, 8394, 1), (0x1f147, 8395, 1), (0x1f148, 8396, 1), (0x1f149, 8397, 1),
+    (0x1f14a, 8398, 2), (0x1f14b, 8400, 2), (0x1f14c, 8402, 2), (0x1f14d, 8404, 2), (0x1f14e, 8406, 3),
+    (0x1f14f, 8409, 2), (0x1f16a, 8411, 2), (0x1f16b, 8413, 2), (0x1f16c, 8415, 2), (0x1f190, 8417, 2),
+    (0x1f200, 8419, 2), (0x1f201, 8421, 2), (0x1f202, 8423, 1), (0x1f210, 8424, 1), (0x1f211, 8425, 1),
+    (0x1f212, 8426, 1), (0x1f213, 8427, 2), (0x1f214, 8429, 1), (0x1f215, 8430, 1), (0x1f216, 8431, 1),
+    (0x1f217, 8432, 1), (0x1f218, 8433, 1), (0x1f219, 8434, 1), (0x1f21a, 8435, 1), (0x1f21b, 8436, 1),
+    (0x1f21c, 8437, 1), (0x1f21d, 8438, 1), (0x1f21e, 8439, 1), (0x1f21f, 8440, 1), (0x1f220, 8441, 1),
+    (0x1f221, 8442, 1), (0x1f222, 8443, 1), (0x1f223, 8444, 1), (0x1f224, 8445, 1), (0x1f225, 8446, 1),
+    (0x1f226, 8447, 1), (0x1f227, 8448, 1), (0x1f228, 8449, 1), (0x1f229, 8450, 1), (0x1f22a, 8451, 1),
+    (0x1f22b, 8452, 1), (0x1f22c, 8453, 1), (0x1f22d, 8454, 1), (0x1f22e, 8455, 1), (0x1f22f, 8456, 1),
+    (0x1f230, 8457, 1), (0x1f231, 8458, 1), (0x1f232, 8459, 1), (0x1f233, 8460, 1), (0x1f234, 8461, 1),
+    (0x1f235, 8462, 1), (0x1f236, 8463, 1), (0x1f237, 8464, 1), (0x1f238, 8465, 1), (0x1f239, 8466, 1),
+    (0x1f23a, 8467, 1), (0x1f23b, 8468, 1), (0x1f240, 8469, 3), (0x1f241, 8472, 3), (0x1f242, 8475, 3),
+    (0x1f243, 8478, 3), (0x1f244, 8481, 3), (0x1f245, 8484, 3), (0x1f246, 8487, 3), (0x1f247, 8490, 3),
+    (0x1f248, 8493, 3), (0x1f250, 8496, 1), (0x1f251, 8497, 1), (0x1fbf0, 8498, 1), (0x1fbf1, 8499, 1),
+    (0x1fbf2, 8500, 1), (0x1fbf3, 8501, 1), (0x1fbf4, 8502, 1), (0x1fbf5, 8503, 1), (0x1fbf6, 8504, 1),
+    (0x1fbf7, 8505, 1), (0x1fbf8, 8506, 1), (0x1fbf9, 8507, 1), (0x2f800, 8508, 1), (0x2f801, 8509, 1),
+    (0x2f802, 8510, 1), (0x2f803, 8511, 1), (0x2f804, 8512, 1), (0x2f805, 8513, 1), (0x2f806, 8514, 1),
+    (0x2f807, 8515, 1), (0x2f808, 8516, 1), (0x2f809, 8517, 1), (0x2f80a, 8518, 1), (0x2f80b, 8519, 1),
+    (0x2f80c, 8520, 1), (0x2f80d, 8521, 1), (0x2f80e, 8522, 1), (0x2f80f, 8523, 1), (0x2f810, 8524, 1),
+    (0x2f811, 8525, 1), (0x2f812, 8526, 1), (0x2f813, 8527, 1), (0x2f814, 8528, 1), (0x2f815, 8529, 1),
+    (0x2f816, 8530, 1), (0x2f817, 8531, 1), (0x2f818, 8532, 1), (0x2f819, 8533, 1), (0x2f81a, 8534, 1),
+    (0x2f81b, 8535, 1), (0x2f81c, 8536, 1), (0x2f81d, 8537, 1), (0x2f81e, 8538, 1), (0x2f81f, 8539, 1),
+    (0x2f820, 8540, 1), (0x2f821, 8541, 1), (0x2f822, 8542, 1), (0x2f823, 8543, 1), (0x2f824, 8544, 1),
+    (0x2f825, 8545, 1), (0x2f826, 8546, 1), (0x2f827, 8547, 1), (0x2f828, 8548, 1), (0x2f829, 8549, 1),
+    (0x2f82a, 8550, 1), (0x2f82b, 8551, 1), (0x2f82c, 8552, 1), (0x2f82d, 8553, 1), (0x2f82e, 8554, 1),
+    (0x2f82f, 8555, 1), (0x2f830, 8556, 1), (0x2f831, 8557, 1), (0x2f832, 8558, 1), (0x2f833, 8559, 1),
+    (0x2f834, 8560, 1), (0x2f835, 8561, 1), (0x2f836, 8562, 1), (0x2f837, 8563, 1), (0x2f838, 8564, 1),
+    (0x2f839, 8565, 1), (0x2f83a, 8566, 1), (0x2f83b, 8567, 1), (0x2f83c, 8568, 1), (0x2f83d, 8569, 1),
+    (0x2f83e, 8570, 1), (0x2f83f, 8571, 1), (0x2f840, 8572, 1), (0x2f841, 8573, 1), (0x2f842, 8574, 1),
+    (0x2f843, 8575, 1), (0x2f844, 8576, 1), (0x2f845, 8577, 1), (0x2f846, 8578, 1), (0x2f847, 8579, 1),
+    (0x2f848, 8580, 1), (0x2f849, 8581, 1), (0x2f84a, 8582, 1), (0x2f84b, 8583, 1), (0x2f84c, 8584, 1),
+    (0x2f84d, 8585, 1), (0x2f84e, 8586, 1), (0x2f84f, 8587, 1), (0x2f850, 8588, 1), (0x2f851, 8589, 1),
+    (0x2f852, 8590, 1), (0x2f853, 8591, 1), (0x2f854, 8592, 1), (0x2f855, 8593, 1), (0x2f856, 8594, 1),
+    (0x2f857, 8595, 1), (0x2f858, 8596, 1), (0x2f859, 8597, 1), (0x2f85a, 8598, 1), (0x2f85b, 8599, 1),
+    (0x2f85c, 8600, 1), (0x2f85d, 8601, 1), (0x2f85e, 8602, 1), (0x2f85f, 8603, 1), (0x2f860, 8604, 1),
+    (0x2f861, 8605, 1), (0x2f862, 8606, 1), (0x2f863, 8607, 1), (0x2f864, 8608, 1), (0x2f865, 8609, 1),
+    (0x2f866, 8610, 1), (0x2f867, 8611, 1), (0x2f868, 8612, 1), (0x2f869, 8613, 1), (0x2f86a, 8614, 1),
+    (0x2f86b, 8615, 1), (0x2f86c, 8616, 1), (0x2f86d, 8617, 1), (0x2f86e, 8618, 1), (0x2f86f, 8619, 1),
+    (0x2f870, 8620, 1), (0x2f871, 8621, 1), (0x2f872, 8622, 1), (0x2f873, 8623, 1), (0x2f874, 8624, 1),
+    (0x2f875, 8625, 1), (0x2f876, 8626, 1), (0x2f877, 8627, 1), (0x2f878, 8628, 1), (0x2f879, 8629, 1),
+    (0x2f87a, 8630, 1), (0x2f87b, 8631, 1), (0x2f87c, 8632, 1), (0x2f87d, 8633, 1), (0x2f87e, 8634, 1),
+    (0x2f87f, 8635, 1), (0x2f880, 8636, 1), (0x2f881, 8637, 1), (0x2f882, 8638, 1), (0x2f883, 8639, 1),
+    (0x2f884, 8640, 1), (0x2f885, 8641, 1), (0x2f886, 8642, 1), (0x2f887, 8643, 1), (0x2f888, 8644, 1),
+    (0x2f889, 8645, 1), (0x2f88a, 8646, 1), (0x2f88b, 8647, 1), (0x2f88c, 8648, 1), (0x2f88d, 8649, 1),
+    (0x2f88e, 8650, 1), (0x2f88f, 8651, 1), (0x2f890, 8652, 1), (0x2f891, 8653, 1), (0x2f892, 8654, 1),
+    (0x2f893, 8655, 1), (0x2f894, 8656, 1), (0x2f895, 8657, 1), (0x2f896, 8658, 1), (0x2f897, 8659, 1),
+    (0x2f898, 8660, 1), (0x2f899, 8661, 1), (0x2f89a, 8662, 1), (0x2f89b, 8663, 1), (0x2f89c, 8664, 1),
+    (0x2f89d, 8665, 1), (0x2f89e, 8666, 1), (0x2f89f, 8667, 1), (0x2f8a0, 8668, 1), (0x2f8a1, 8669, 1),
+    (0x2f8a2, 8670, 1), (0x2f8a3, 8671, 1), (0x2f8a4, 8672, 1), (0x2f8a5, 8673, 1), (0x2f8a6, 8674, 1),
+    (0x2f8a7, 8675, 1), (0x2f8a8, 8676, 1), (0x2f8a9, 8677, 1), (0x2f8aa, 8678, 1), (0x2f8ab, 8679, 1),
+    (0x2f8ac, 8680, 1), (0x2f8ad, 8681, 1), (0x2f8ae, 8682, 1), (0x2f8af, 8683, 1), (0x2f8b0, 8684, 1),
+    (0x2f8b1, 8685, 1), (0x2f8b2, 8686, 1), (0x2f8b3, 8687, 1), (0x2f8b4, 8688, 1), (0x2f8b5, 8689, 1),
+    (0x2f8b6, 8690, 1), (0x2f8b7, 8691, 1), (0x2f8b8, 8692, 1), (0x2f8b9, 8693, 1), (0x2f8ba, 8694, 1),
+    (0x2f8bb, 8695, 1), (0x2f8bc, 8696, 1), (0x2f8bd, 8697, 1), (0x2f8be, 8698, 1), (0x2f8bf, 8699, 1),
+    (0x2f8c0, 8700, 1), (0x2f8c1, 8701, 1), (0x2f8c2, 8702, 1), (0x2f8c3, 8703, 1), (0x2f8c4, 8704, 1),
+    (0x2f8c5, 8705, 1), (0x2f8c6, 8706, 1), (0x2f8c7, 8707, 1), (0x2f8c8, 8708, 1), (0x2f8c9, 8709, 1),
+    (0x2f8ca, 8710, 1), (0x2f8cb, 8711, 1), (0x2f8cc, 8712, 1), (0x2f8cd, 8713, 1), (0x2f8ce, 8714, 1),
+    (0x2f8cf, 8715, 1), (0x2f8d0, 8716, 1), (0x2f8d1, 8717, 1), (0x2f8d2, 8718, 1), (0x2f8d3, 8719, 1),
+    (0x2f8d4, 8720, 1), (0x2f8d5, 8721, 1), (0x2f8d6, 8722, 1), (0x2f8d7, 8723, 1), (0x2f8d8, 8724, 1),
+    (0x2f8d9, 8725, 1), (0x2f8da, 8726, 1), (0x2f8db, 8727, 1), (0x2f8dc, 8728, 1), (0x2f8dd, 8729, 1),
+    (0x2f8de, 8730, 1), (0x2f8df, 8731, 1), (0x2f8e0, 8732, 1), (0x2f8e1, 8733, 1), (0x2f8e2, 8734, 1),
+    (0x2f8e3, 8735, 1), (0x2f8e4, 8736, 1), (0x2f8e5, 8737, 1), (0x2f8e6, 8738, 1), (0x2f8e7, 8739, 1),
+    (0x2f8e8, 8740, 1), (0x2f8e9, 8741, 1), (0x2f8ea, 8742, 1), (0x2f8eb, 8743, 1), (0x2f8ec, 8744, 1),
+    (0x2f8ed, 8745, 1), (0x2f8ee, 8746, 1), (0x2f8ef, 8747, 1), (0x2f8f0, 8748, 1), (0x2f8f1, 8749, 1),
+    (0x2f8f2, 8750, 1), (0x2f8f3, 8751, 1), (0x2f8f4, 8752, 1), (0x2f8f5, 8753, 1), (0x2f8f6, 8754, 1),
+    (0x2f8f7, 8755, 1), (0x2f8f8, 8756, 1), (0x2f8f9, 8757, 1), (0x2f8fa, 8758, 1), (0x2f8fb, 8759, 1),
+    (0x2f8fc, 8760, 1), (0x2f8fd, 8761, 1), (0x2f8fe, 8762, 1), (0x2f8ff, 8763, 1), (0x2f900, 8764, 1),
+    (0x2f901, 8765, 1), (0x2f902, 8766, 1), (0x2f903, 8767, 1), (0x2f904, 8768, 1), (0x2f905, 8769, 1),
+    (0x2f906, 8770, 1), (0x2f907, 8771, 1), (0x2f908, 8772, 1), (0x2f909, 8773, 1), (0x2f90a, 8774, 1),
+    (0x2f90b, 8775, 1), (0x2f90c, 8776, 1), (0x2f90d, 8777, 1), (0x2f90e, 8778, 1), (0x2f90f, 8779, 1),
+    (0x2f910, 8780, 1), (0x2f911, 8781, 1), (0x2f912, 8782, 1), (0x2f913, 8783, 1), (0x2f914, 8784, 1),
+    (0x2f915, 8785, 1), (0x2f916, 8786, 1), (0x2f917, 8787, 1), (0x2f918, 8788, 1), (0x2f919, 8789, 1),
+    (0x2f91a, 8790, 1), (0x2f91b, 8791, 1), (0x2f91c, 8792, 1), (0x2f91d, 8793, 1), (0x2f91e, 8794, 1),
+    (0x2f91f, 8795, 1), (0x2f920, 8796, 1), (0x2f921, 8797, 1), (0x2f922, 8798, 1), (0x2f923, 8799, 1),
+    (0x2f924, 8800, 1), (0x2f925, 8801, 1), (0x2f926, 8802, 1), (0x2f927, 8803, 1), (0x2f928, 8804, 1),
+    (0x2f929, 8805, 1), (0x2f92a, 8806, 1), (0x2f92b, 8807, 1), (0x2f92c, 8808, 1), (0x2f92d, 8809, 1),
+    (0x2f92e, 8810, 1), (0x2f92f, 8811, 1), (0x2f930, 8812, 1), (0x2f931, 8813, 1), (0x2f932, 8814, 1),
+    (0x2f933, 8815, 1), (0x2f934, 8816, 1), (0x2f935, 8817, 1), (0x2f936, 8818, 1), (0x2f937, 8819, 1),
+    (0x2f938, 8820, 1), (0x2f939, 8821, 1), (0x2f93a, 8822, 1), (0x2f93b, 8823, 1), (0x2f93c, 8824, 1),
+    (0x2f93d, 8825, 1), (0x2f93e, 8826, 1), (0x2f93f, 8827, 1), (0x2f940, 8828, 1), (0x2f941, 8829, 1),
+    (0x2f942, 8830, 1), (0x2f943, 8831, 1), (0x2f944, 8832, 1), (0x2f945, 8833, 1), (0x2f946, 8834, 1),
+    (0x2f947, 8835, 1), (0x2f948, 8836, 1), (0x2f949, 8837, 1), (0x2f94a, 8838, 1), (0x2f94b, 8839, 1),
+    (0x2f94c, 8840, 1), (0x2f94d, 8841, 1), (0x2f94e, 8842, 1), (0x2f94f, 8843, 1), (0x2f950, 8844, 1),
+    (0x2f951, 8845, 1), (0x2f952, 8846, 1), (0x2f953, 8847, 1), (0x2f954, 8848, 1), (0x2f955, 8849, 1),
+    (0x2f956, 8850, 1), (0x2f957, 8851, 1), (0x2f958, 8852, 1), (0x2f959, 8853, 1), (0x2f95a, 8854, 1),
+    (0x2f95b, 8855, 1), (0x2f95c, 8856, 1), (0x2f95d, 8857, 1), (0x2f95e, 8858, 1), (0x2f95f, 8859, 1),
+    (0x2f960, 8860, 1), (0x2f961, 8861, 1), (0x2f962, 8862, 1), (0x2f963, 8863, 1), (0x2f964, 8864, 1),
+    (0x2f965, 8865, 1), (0x2f966, 8866, 1), (0x2f967, 8867, 1), (0x2f968, 8868, 1), (0x2f969, 8869, 1),
+    (0x2f96a, 8870, 1), (0x2f96b, 8871, 1), (0x2f96c, 8872, 1), (0x2f96d, 8873, 1), (0x2f96e, 8874, 1),
+    (0x2f96f, 8875, 1), (0x2f970, 8876, 1), (0x2f971, 8877, 1), (0x2f972, 8878, 1), (0x2f973, 8879, 1),
+    (0x2f974, 8880, 1), (0x2f975, 8881, 1), (0x2f976, 8882, 1), (0x2f977, 8883, 1), (0x2f978, 8884, 1),
+    (0x2f979, 8885, 1), (0x2f97a, 8886, 1), (0x2f97b, 8887, 1), (0x2f97c, 8888, 1), (0x2f97d, 8889, 1),
+    (0x2f97e, 8890, 1), (0x2f97f, 8891, 1), (0x2f980, 8892, 1), (0x2f981, 8893, 1), (0x2f982, 8894, 1),
+    (0x2f983, 8895, 1), (0x2f984, 8896, 1), (0x2f985, 8897, 1), (0x2f986, 8898, 1), (0x2f987, 8899, 1),
+    (0x2f988, 8900, 1), (0x2f989, 8901, 1), (0x2f98a, 8902, 1), (0x2f98b, 8903, 1), (0x2f98c, 8904, 1),
+    (0x2f98d, 8905, 1), (0x2f98e, 8906, 1), (0x2f98f, 8907, 1), (0x2f990, 8908, 1), (0x2f991, 8909, 1),
+    (0x2f992, 8910, 1), (0x2f993, 8911, 1), (0x2f994, 8912, 1), (0x2f995, 8913, 1), (0x2f996, 8914, 1),
+    (0x2f997, 8915, 1), (0x2f998, 8916, 1), (0x2f999, 8917, 1), (0x2f99a, 8918, 1), (0x2f99b, 8919, 1),
+    (0x2f99c, 8920, 1), (0x2f99d, 8921, 1), (0x2f99e, 8922, 1), (0x2f99f, 8923, 1), (0x2f9a0, 8924, 1),
+    (0x2f9a1, 8925, 1), (0x2f9a2, 8926, 1), (0x2f9a3, 8927, 1), (0x2f9a4, 8928, 1), (0x2f9a5, 8929, 1),
+    (0x2f9a6, 8930, 1), (0x2f9a7, 8931, 1), (0x2f9a8, 8932, 1), (0x2f9a9, 8933, 1), (0x2f9aa, 8934, 1),
+    (0x2f9ab, 8935, 1), (0x2f9ac, 8936, 1), (0x2f9ad, 8937, 1), (0x2f9ae, 8938, 1), (0x2f9af, 8939, 1),
+    (0x2f9b0, 8940, 1), (0x2f9b1, 8941, 1), (0x2f9b2, 8942, 1), (0x2f9b3, 8943, 1), (0x2f9b4, 8944, 1),
+    (0x2f9b5, 8945, 1), (0x2f9b6, 8946, 1), (0x2f9b7, 8947, 1), (0x2f9b8, 8948, 1), (0x2f9b9, 8949, 1),
+    (0x2f9ba, 8950, 1), (0x2f9bb, 8951, 1), (0x2f9bc, 8952, 1), (0x2f9bd, 8953, 1), (0x2f9be, 8954, 1),
+    (0x2f9bf, 8955, 1), (0x2f9c0, 8956, 1), (0x2f9c1, 8957, 1), (0x2f9c2, 8958, 1), (0x2f9c3, 8959, 1),
+    (0x2f9c4, 8960, 1), (0x2f9c5, 8961, 1), (0x2f9c6, 8962, 1), (0x2f9c7, 8963, 1), (0x2f9c8, 8964, 1),
+    (0x2f9c9, 8965, 1), (0x2f9ca, 8966, 1), (0x2f9cb, 8967, 1), (0x2f9cc, 8968, 1), (0x2f9cd, 8969, 1),
+    (0x2f9ce, 8970, 1), (0x2f9cf, 8971, 1), (0x2f9d0, 8972, 1), (0x2f9d1, 8973, 1), (0x2f9d2, 8974, 1),
+    (0x2f9d3, 8975, 1), (0x2f9d4, 8976, 1), (0x2f9d5, 8977, 1), (0x2f9d6, 8978, 1), (0x2f9d7, 8979, 1),
+    (0x2f9d8, 8980, 1), (0x2f9d9, 8981, 1), (0x2f9da, 8982, 1), (0x2f9db, 8983, 1), (0x2f9dc, 8984, 1),
+    (0x2f9dd, 8985, 1), (0x2f9de, 8986, 1), (0x2f9df, 8987, 1), (0x2f9e0, 8988, 1), (0x2f9e1, 8989, 1),
+    (0x2f9e2, 8990, 1), (0x2f9e3, 8991, 1), (0x2f9e4, 8992, 1), (0x2f9e5, 8993, 1), (0x2f9e6, 8994, 1),
+    (0x2f9e7, 8995, 1), (0x2f9e8, 8996, 1), (0x2f9e9, 8997, 1), (0x2f9ea, 8998, 1), (0x2f9eb, 8999, 1),
+    (0x2f9ec, 9000, 1), (0x2f9ed, 9001, 1), (0x2f9ee, 9002, 1), (0x2f9ef, 9003, 1), (0x2f9f0, 9004, 1),
+    (0x2f9f1, 9005, 1), (0x2f9f2, 9006, 1), (0x2f9f3, 9007, 1), (0x2f9f4, 9008, 1), (0x2f9f5, 9009, 1),
+    (0x2f9f6, 9010, 1), (0x2f9f7, 9011, 1), (0x2f9f8, 9012, 1), (0x2f9f9, 9013, 1), (0x2f9fa, 9014, 1),
+    (0x2f9fb, 9015, 1), (0x2f9fc, 9016, 1), (0x2f9fd, 9017, 1), (0x2f9fe, 9018, 1), (0x2f9ff, 9019, 1),
+    (0x2fa00, 9020, 1), (0x2fa01, 9021, 1), (0x2fa02, 9022, 1), (0x2fa03, 9023, 1), (0x2fa04, 9024, 1),
+    (0x2fa05, 9025, 1), (0x2fa06, 9026, 1), (0x2fa07, 9027, 1), (0x2fa08, 9028, 1), (0x2fa09, 9029, 1),
+    (0x2fa0a, 9030, 1), (0x2fa0b, 9031, 1), (0x2fa0c, 9032, 1), (0x2fa0d, 9033, 1), (0x2fa0e, 9034, 1),
+    (0x2fa0f, 9035, 1), (0x2fa10, 9036, 1), (0x2fa11, 9037, 1), (0x2fa12, 9038, 1), (0x2fa13, 9039, 1),
+    (0x2fa14, 9040, 1), (0x2fa15, 9041, 1), (0x2fa16, 9042, 1), (0x2fa17, 9043, 1), (0x2fa18, 9044, 1),
+    (0x2fa19, 9045, 1), (0x2fa1a, 9046, 1), (0x2fa1b, 9047, 1), (0x2fa1c, 9048, 1), (0x2fa1d, 9049, 1),
+];
+
+pub(crate) const DECOMPOSITIONS_DATA: &[u32] = &[
+    0x20, 0x20, 0x308, 0x61, 0x20, 0x304, 0x32, 0x33, 0x20, 0x301,
+    0x3bc, 0x20, 0x327, 0x31, 0x6f, 0x31, 0x2044, 0x34, 0x31, 0x2044,
+    0x32, 0x33, 0x2044, 0x34, 0x41, 0x300, 0x41, 0x301, 0x41, 0x302,
+    0x41, 0x303, 0x41, 0x308, 0x41, 0x30a, 0x43, 0x327, 0x45, 0x300,
+    0x45, 0x301, 0x45, 0x302, 0x45, 0x308, 0x49, 0x300, 0x49, 0x301,
+    0x49, 0x302, 0x49, 0x308, 0x4e, 0x303, 0x4f, 0x300, 0x4f, 0x301,
+    0x4f, 0x302, 0x4f, 0x303, 0x4f, 0x308, 0x55, 0x300, 0x55, 0x301,
+    0x55, 0x302, 0x55, 0x308, 0x59, 0x301, 0x61, 0x300, 0x61, 0x301,
+    0x61, 0x302, 0x61, 0x303, 0x61, 0x308, 0x61, 0x30a, 0x63, 0x327,
+    0x65, 0x300, 0x65, 0x301, 0x65, 0x302, 0x65, 0x308, 0x69, 0x300,
+    0x69, 0x301, 0x69, 0x302, 0x69, 0x308, 0x6e, 0x303, 0x6f, 0x300,
+    0x6f, 0x301, 0x6f, 0x302, 0x6f, 0x303, 0x6f, 0x308, 0x75, 0x300,
+    0x75, 0x301, 0x75, 0x302, 0x75, 0x308, 0x79, 0x301, 0x79, 0x308,
+    0x41, 0x304, 0x61, 0x304, 0x41, 0x306, 0x61, 0x306, 0x41, 0x328,
+    0x61, 0x328, 0x43, 0x301, 0x63, 0x301, 0x43, 0x302, 0x63, 0x302,
+    0x43, 0x307, 0x63, 0x307, 0x43, 0x30c, 0x63, 0x30c, 0x44, 0x30c,
+    0x64, 0x30c, 0x45, 0x304, 0x65, 0x304, 0x45, 0x306, 0x65, 0x306,
+    0x45, 0x307, 0x65, 0x307, 0x45, 0x328, 0x65, 0x328, 0x45, 0x30c,
+    0x65, 0x30c, 0x47, 0x302, 0x67, 0x302, 0x47, 0x306, 0x67, 0x306,
+    0x47, 0x307, 0x67, 0x307, 0x47, 0x327, 0x67, 0x327, 0x48, 0x302,
+    0x68, 0x302, 0x49, 0x303, 0x69, 0x303, 0x49, 0x304, 0x69, 0x304,
+    0x49, 0x306, 0x69, 0x306, 0x49, 0x328, 0x69, 0x328, 0x49, 0x307,
+    0x49, 0x4a, 0x69, 0x6a, 0x4a, 0x302, 0x6a, 0x302, 0x4b, 0x327,
+    0x6b, 0x327, 0x4c, 0x301, 0x6c, 0x301, 0x4c, 0x327, 0x6c, 0x327,
+    0x4c, 0x30c, 0x6c, 0x30c, 0x4c, 0xb7, 0x6c, 0xb7, 0x4e, 0x301,
+    0x6e, 0x301, 0x4e, 0x327, 0x6e, 0x327, 0x4e, 0x30c, 0x6e, 0x30c,
+    0x2bc, 0x6e, 0x4f, 0x304, 0x6f, 0x304, 0x4f, 0x306, 0x6f, 0x306,
+    0x4f, 0x30b, 0x6f, 0x30b, 0x52, 0x301, 0x72, 0x301, 0x52, 0x327,
+    0x72, 0x327, 0x52, 0x30c, 0x72, 0x30c, 0x53, 0x301, 0x73, 0x301,
+    0x53, 0x302, 0x73, 0x302, 0x53, 0x327, 0x73, 0x327, 0x53, 0x30c,
+    0x73, 0x30c, 0x54, 0x327, 0x74, 0x327, 0x54, 0x30c, 0x74, 0x30c,
+    0x55, 0x303, 0x75, 0x303, 0x55, 0x304, 0x75, 0x304, 0x55, 0x306,
+    0x75, 0x306, 0x55, 0x30a, 0x75, 0x30a, 0x55, 0x30b, 0x75, 0x30b,
+    0x55, 0x328, 0x75, 0x328, 0x57, 0x302, 0x77, 0x302, 0x59, 0x302,
+    0x79, 0x302, 0x59, 0x308, 0x5a, 0x301, 0x7a, 0x301, 0x5a, 0x307,
+    0x7a, 0x307, 0x5a, 0x30c, 0x7a, 0x30c, 0x73, 0x4f, 0x31b, 0x6f,
+    0x31b, 0x55, 0x31b, 0x75, 0x31b, 0x44, 0x5a, 0x30c, 0x44, 0x7a,
+    0x30c, 0x64, 0x7a, 0x30c, 0x4c, 0x4a, 0x4c, 0x6a, 0x6c, 0x6a,
+    0x4e, 0x4a, 0x4e, 0x6a, 0x6e, 0x6a, 0x41, 0x30c, 0x61, 0x30c,
+    0x49, 0x30c, 0x69, 0x30c, 0x4f, 0x30c, 0x6f, 0x30c, 0x55, 0x30c,
+    0x75, 0x30c, 0x55, 0x308, 0x304, 0x75, 0x308, 0x304, 0x55, 0x308,
+    0x301, 0x75, 0x308, 0x301, 0x55, 0x308, 0x30c, 0x75, 0x308, 0x30c,
+    0x55, 0x308, 0x300, 0x75, 0x308, 0x300, 0x41, 0x308, 0x304, 0x61,
+    0x308, 0x304, 0x41, 0x307, 0x304, 0x61, 0x307, 0x304, 0xc6, 0x304,
+    0xe6, 0x304, 0x47, 0x30c, 0x67, 0x30c, 0x4b, 0x30c, 0x6b, 0x30c,
+    0x4f, 0x328, 0x6f, 0x328, 0x4f, 0x328, 0x304, 0x6f, 0x328, 0x304,
+    0x1b7, 0x30c, 0x292, 0x30c, 0x6a, 0x30c, 0x44, 0x5a, 0x44, 0x7a,
+    0x64, 0x7a, 0x47, 0x301, 0x67, 0x301, 0x4e, 0x300, 0x6e, 0x300,
+    0x41, 0x30a, 0x301, 0x61, 0x30a, 0x301, 0xc6, 0x301, 0xe6, 0x301,
+    0xd8, 0x301, 0xf8, 0x301, 0x41, 0x30f, 0x61, 0x30f, 0x41, 0x311,
+    0x61, 0x311, 0x45, 0x30f, 0x65, 0x30f, 0x45, 0x311, 0x65, 0x311,
+    0x49, 0x30f, 0x69, 0x30f, 0x49, 0x311, 0x69, 0x311, 0x4f, 0x30f,
+    0x6f, 0x30f, 0x4f, 0x311, 0x6f, 0x311, 0x52, 0x30f, 0x72, 0x30f,
+    0x52, 0x311, 0x72, 0x311, 0x55, 0x30f, 0x75, 0x30f, 0x55, 0x311,
+    0x75, 0x311, 0x53, 0x326, 0x73, 0x326, 0x54, 0x326, 0x74, 0x326,
+    0x48, 0x30c, 0x68, 0x30c, 0x41, 0x307, 0x61, 0x307, 0x45, 0x327,
+    0x65, 0x327, 0x4f, 0x308, 0x304, 0x6f, 0x308, 0x304, 0x4f, 0x303,
+    0x304, 0x6f, 0x303, 0x304, 0x4f, 0x307, 0x6f, 0x307, 0x4f, 0x307,
+    0x304, 0x6f, 0x307, 0x304, 0x59, 0x304, 0x79, 0x304, 0x68, 0x266,
+    0x6a, 0x72, 0x279, 0x27b, 0x281, 0x77, 0x79, 0x20, 0x306, 0x20,
+    0x307, 0x20, 0x30a, 0x20, 0x328, 0x20, 0x303, 0x20, 0x30b, 0x263,
+    0x6c, 0x73, 0x78, 0x295, 0x300, 0x301, 0x313, 0x308, 0x301, 0x2b9,
+    0x20, 0x345, 0x3b, 0x20, 0x301, 0x20, 0x308, 0x301, 0x391, 0x301,
+    0xb7, 0x395, 0x301, 0x397, 0x301, 0x399, 0x301, 0x39f, 0x301, 0x3a5,
+    0x301, 0x3a9, 0x301, 0x3b9, 0x308, 0x301, 0x399, 0x308, 0x3a5, 0x308,
+    0x3b1, 0x301, 0x3b5, 0x301, 0x3b7, 0x301, 0x3b9, 0x301, 0x3c5, 0x308,
+    0x301, 0x3b9, 0x308, 0x3c5, 0x308, 0x3bf, 0x301, 0x3c5, 0x301, 0x3c9,
+    0x301, 0x3b2, 0x3b8, 0x3a5, 0x3a5, 0x301, 0x3a5, 0x308, 0x3c6, 0x3c0,
+    0x3ba, 0x3c1, 0x3c2, 0x398, 0x3b5, 0x3a3, 0x415, 0x300, 0x415, 0x308,
+    0x413, 0x301, 0x406, 0x308, 0x41a, 0x301, 0x418, 0x300, 0x423, 0x306,
+    0x418, 0x306, 0x438, 0x306, 0x435, 0x300, 0x435, 0x308, 0x433, 0x301,
+    0x456, 0x308, 0x43a, 0x301, 0x438, 0x300, 0x443, 0x306, 0x474, 0x30f,
+    0x475, 0x30f, 0x416, 0x306, 0x436, 0x306, 0x410, 0x306, 0x430, 0x306,
+    0x410, 0x308, 0x430, 0x308, 0x415, 0x306, 0x435, 0x306, 0x4d8, 0x308,
+    0x4d9, 0x308, 0x416, 0x308, 0x436, 0x308, 0x417, 0x308, 0x437, 0x308,
+    0x418, 0x304, 0x438, 0x304, 0x418, 0x308, 0x438, 0x308, 0x41e, 0x308,
+    0x43e, 0x308, 0x4e8, 0x308, 0x4e9, 0x308, 0x42d, 0x308, 0x44d, 0x308,
+    0x423, 0x304, 0x443, 0x304, 0x423, 0x308, 0x443, 0x308, 0x423, 0x30b,
+    0x443, 0x30b, 0x427, 0x308, 0x447, 0x308, 0x42b, 0x308, 0x44b, 0x308,
+    0x565, 0x582, 0x627, 0x653, 0x627, 0x654, 0x648, 0x654, 0x627, 0x655,
+    0x64a, 0x654, 0x627, 0x674, 0x648, 0x674, 0x6c7, 0x674, 0x64a, 0x674,
+    0x6d5, 0x654, 0x6c1, 0x654, 0x6d2, 0x654, 0x928, 0x93c, 0x930, 0x93c,
+    0x933, 0x93c, 0x915, 0x93c, 0x916, 0x93c, 0x917, 0x93c, 0x91c, 0x93c,
+    0x921, 0x93c, 0x922, 0x93c, 0x92b, 0x93c, 0x92f, 0x93c, 0x9c7, 0x9be,
+    0x9c7, 0x9d7, 0x9a1, 0x9bc, 0x9a2, 0x9bc, 0x9af, 0x9bc, 0xa32, 0xa3c,
+    0xa38, 0xa3c, 0xa16, 0xa3c, 0xa17, 0xa3c, 0xa1c, 0xa3c, 0xa2b, 0xa3c,
+    0xb47, 0xb56, 0xb47, 0xb3e, 0xb47, 0xb57, 0xb21, 0xb3c, 0xb22, 0xb3c,
+    0xb92, 0xbd7, 0xbc6, 0xbbe, 0xbc7, 0xbbe, 0xbc6, 0xbd7, 0xc46, 0xc56,
+    0xcbf, 0xcd5, 0xcc6, 0xcd5, 0xcc6, 0xcd6, 0xcc6, 0xcc2, 0xcc6, 0xcc2,
+    0xcd5, 0xd46, 0xd3e, 0xd47, 0xd3e, 0xd46, 0xd57, 0xdd9, 0xdca, 0xdd9,
+    0xdcf, 0xdd9, 0xdcf, 0xdca, 0xdd9, 0xddf, 0xe4d, 0xe32, 0xecd, 0xeb2,
+    0xeab, 0xe99, 0xeab, 0xea1, 0xf0b, 0xf42, 0xfb7, 0xf4c, 0xfb7, 0xf51,
+    0xfb7, 0xf56, 0xfb7, 0xf5b, 0xfb7, 0xf40, 0xfb5, 0xf71, 0xf72, 0xf71,
+    0xf74, 0xfb2, 0xf80, 0xfb2, 0xf71, 0xf80, 0xfb3, 0xf80, 0xfb3, 0xf71,
+    0xf80, 0xf71, 0xf80, 0xf92, 0xfb7, 0xf9c, 0xfb7, 0xfa1, 0xfb7, 0xfa6,
+    0xfb7, 0xfab, 0xfb7, 0xf90, 0xfb5, 0x1025, 0x102e, 0x10dc, 0x1b05, 0x1b35,
+    0x1b07, 0x1b35, 0x1b09, 0x1b35, 0x1b0b, 0x1b35, 0x1b0d, 0x1b35, 0x1b11, 0x1b35,
+    0x1b3a, 0x1b35, 0x1b3c, 0x1b35, 0x1b3e, 0x1b35, 0x1b3f, 0x1b35, 0x1b42, 0x1b35,
+    0x41, 0xc6, 0x42, 0x44, 0x45, 0x18e, 0x47, 0x48, 0x49, 0x4a,
+    0x4b, 0x4c, 0x4d, 0x4e, 0x4f, 0x222, 0x50, 0x52, 0x54, 0x55,
+    0x57, 0x61, 0x250, 0x251, 0x1d02, 0x62, 0x64, 0x65, 0x259, 0x25b,
+    0x25c, 0x67, 0x6b, 0x6d, 0x14b, 0x6f, 0x254, 0x1d16, 0x1d17, 0x70,
+    0x74, 0x75, 0x1d1d, 0x26f, 0x76, 0x1d25, 0x3b2, 0x3b3, 0x3b4, 0x3c6,
+    0x3c7, 0x69, 0x72, 0x75, 0x76, 0x3b2, 0x3b3, 0x3c1, 0x3c6, 0x3c7,
+    0x43d, 0x252, 0x63, 0x255, 0xf0, 0x25c, 0x66, 0x25f, 0x261, 0x265,
+    0x268, 0x269, 0x26a, 0x1d7b, 0x29d, 0x26d, 0x1d85, 0x29f, 0x271, 0x270,
+    0x272, 0x273, 0x274, 0x275, 0x278, 0x282, 0x283, 0x1ab, 0x289, 0x28a,
+    0x1d1c, 0x28b, 0x28c, 0x7a, 0x290, 0x291, 0x292, 0x3b8, 0x41, 0x325,
+    0x61, 0x325, 0x42, 0x307, 0x62, 0x307, 0x42, 0x323, 0x62, 0x323,
+    0x42, 0x331, 0x62, 0x331, 0x43, 0x327, 0x301, 0x63, 0x327, 0x301,
+    0x44, 0x307, 0x64, 0x307, 0x44, 0x323, 0x64, 0x323, 0x44, 0x331,
+    0x64, 0x331, 0x44, 0x327, 0x64, 0x327, 0x44, 0x32d, 0x64, 0x32d,
+    0x45, 0x304, 0x300, 0x65, 0x304, 0x300, 0x45, 0x304, 0x301, 0x65,
+    0x304, 0x301, 0x45, 0x32d, 0x65, 0x32d, 0x45, 0x330, 0x65, 0x330,
+    0x45, 0x327, 0x306, 0x65, 0x327, 0x306, 0x46, 0x307, 0x66, 0x307,
+    0x47, 0x304, 0x67, 0x304, 0x48, 0x307, 0x68, 0x307, 0x48, 0x323,
+    0x68, 0x323, 0x48, 0x308, 0x68, 0x308, 0x48, 0x327, 0x68, 0x327,
+    0x48, 0x32e, 0x68, 0x32e, 0x49, 0x330, 0x69, 0x330, 0x49, 0x308,
+    0x301, 0x69, 0x308, 0x301, 0x4b, 0x301, 0x6b, 0x301, 0x4b, 0x323,
+    0x6b, 0x323, 0x4b, 0x331, 0x6b, 0x331, 0x4c, 0x323, 0x6c, 0x323,
+    0x4c, 0x323, 0x304, 0x6c, 0x323, 0x304, 0x4c, 0x331, 0x6c, 0x331,
+    0x4c, 0x32d, 0x6c, 0x32d, 0x4d, 0x301, 0x6d, 0x301, 0x4d, 0x307,
+    0x6d, 0x307, 0x4d, 0x323, 0x6d, 0x323, 0x4e, 0x307, 0x6e, 0x307,
+    0x4e, 0x323, 0x6e, 0x323, 0x4e, 0x331, 0x6e, 0x331, 0x4e, 0x32d,
+    0x6e, 0x32d, 0x4f, 0x303, 0x301, 0x6f, 0x303, 0x301, 0x4f, 0x303,
+    0x308, 0x6f, 0x303, 0x308, 0x4f, 0x304, 0x300, 0x6f, 0x304, 0x300,
+    0x4f, 0x304, 0x301, 0x6f, 0x304, 0x301, 0x50, 0x301, 0x70, 0x301,
+    0x50, 0x307, 0x70, 0x307, 0x52, 0x307, 0x72, 0x307, 0x52, 0x323,
+    0x72, 0x323, 0x52, 0x323, 0x304, 0x72, 0x323, 0x304, 0x52, 0x331,
+    0x72, 0x331, 0x53, 0x307, 0x73, 0x307, 0x53, 0x323, 0x73, 0x323,
+    0x53, 0x301, 0x307, 0x73, 0x301, 0x307, 0x53, 0x30c, 0x307, 0x73,
+    0x30c, 0x307, 0x53, 0x323, 0x307, 0x73, 0x323, 0x307, 0x54, 0x307,
+    0x74, 0x307, 0x54, 0x323, 0x74, 0x323, 0x54, 0x331, 0x74, 0x331,
+    0x54, 0x32d, 0x74, 0x32d, 0x55, 0x324, 0x75, 0x324, 0x55, 0x330,
+    0x75, 0x330, 0x55, 0x32d, 0x75, 0x32d, 0x55, 0x303, 0x301, 0x75,
+    0x303, 0x301, 0x55, 0x304, 0x308, 0x75, 0x304, 0x308, 0x56, 0x303,
+    0x76, 0x303, 0x56, 0x323, 0x76, 0x323, 0x57, 0x300, 0x77, 0x300,
+    0x57, 0x301, 0x77, 0x301, 0x57, 0x308, 0x77, 0x308, 0x57, 0x307,
+    0x77, 0x307, 0x57, 0x323, 0x77, 0x323, 0x58, 0x307, 0x78, 0x307,
+    0x58, 0x308, 0x78, 0x308, 0x59, 0x307, 0x79, 0x307, 0x5a, 0x302,
+    0x7a, 0x302, 0x5a, 0x323, 0x7a, 0x323, 0x5a, 0x331, 0x7a, 0x331,
+    0x68, 0x331, 0x74, 0x308, 0x77, 0x30a, 0x79, 0x30a, 0x61, 0x2be,
+    0x73, 0x307, 0x41, 0x323, 0x61, 0x323, 0x41, 0x309, 0x61, 0x309,
+    0x41, 0x302, 0x301, 0x61, 0x302, 0x301, 0x41, 0x302, 0x300, 0x61,
+    0x302, 0x300, 0x41, 0x302, 0x309, 0x61, 0x302, 0x309, 0x41, 0x302,
+    0x303, 0x61, 0x302, 0x303, 0x41, 0x323, 0x302, 0x61, 0x323, 0x302,
+    0x41, 0x306, 0x301, 0x61, 0x306, 0x301, 0x41, 0x306, 0x300, 0x61,
+    0x306, 0x300, 0x41, 0x306, 0x309, 0x61, 0x306, 0x309, 0x41, 0x306,
+    0x303, 0x61, 0x306, 0x303, 0x41, 0x323, 0x306, 0x61, 0x323, 0x306,
+    0x45, 0x323, 0x65, 0x323, 0x45, 0x309, 0x65, 0x309, 0x45, 0x303,
+    0x65, 0x303, 0x45, 0x302, 0x301, 0x65, 0x302, 0x301, 0x45, 0x302,
+    0x300, 0x65, 0x302, 0x300, 0x45, 0x302, 0x309, 0x65, 0x302, 0x309,
+    0x45, 0x302, 0x303, 0x65, 0x302, 0x303, 0x45, 0x323, 0x302, 0x65,
+    0x323, 0x302, 0x49, 0x309, 0x69, 0x309, 0x49, 0x323, 0x69, 0x323,
+    0x4f, 0x323, 0x6f, 0x323, 0x4f, 0x309, 0x6f, 0x309, 0x4f, 0x302,
+    0x301, 0x6f, 0x302, 0x301, 0x4f, 0x302, 0x300, 0x6f, 0x302, 0x300,
+    0x4f, 0x302, 0x309, 0x6f, 0x302, 0x309, 0x4f, 0x302, 0x303, 0x6f,
+    0x302, 0x303, 0x4f, 0x323, 0x302, 0x6f, 0x323, 0x302, 0x4f, 0x31b,
+    0x301, 0x6f, 0x31b, 0x301, 0x4f, 0x31b, 0x300, 0x6f, 0x31b, 0x300,
+    0x4f, 0x31b, 0x309, 0x6f, 0x31b, 0x309, 0x4f, 0x31b, 0x303, 0x6f,
+    0x31b, 0x303, 0x4f, 0x31b, 0x323, 0x6f, 0x31b, 0x323, 0x55, 0x323,
+    0x75, 0x323, 0x55, 0x309, 0x75, 0x309, 0x55, 0x31b, 0x301, 0x75,
+    0x31b, 0x301, 0x55, 0x31b, 0x300, 0x75, 0x31b, 0x300, 0x55, 0x31b,
+    0x309, 0x75, 0x31b, 0x309, 0x55, 0x31b, 0x303, 0x75, 0x31b, 0x303,
+    0x55, 0x31b, 0x323, 0x75, 0x31b, 0x323, 0x59, 0x300, 0x79, 0x300,
+    0x59, 0x323, 0x79, 0x323, 0x59, 0x309, 0x79, 0x309, 0x59, 0x303,
+    0x79, 0x303, 0x3b1, 0x313, 0x3b1, 0x314, 0x3b1, 0x313, 0x300, 0x3b1,
+    0x314, 0x300, 0x3b1, 0x313, 0x301, 0x3b1, 0x314, 0x301, 0x3b1, 0x313,
+    0x342, 0x3b1, 0x314, 0x342, 0x391, 0x313, 0x391, 0x314, 0x391, 0x313,
+    0x300, 0x391, 0x314, 0x300, 0x391, 0x313, 0x301, 0x391, 0x314, 0x301,
+    0x391, 0x313, 0x342, 0x391, 0x314, 0x342, 0x3b5, 0x313, 0x3b5, 0x314,
+    0x3b5, 0x313, 0x300, 0x3b5, 0x314, 0x300, 0x3b5, 0x313, 0x301, 0x3b5,
+    0x314, 0x301, 0x395, 0x313, 0x395, 0x314, 0x395, 0x313, 0x300, 0x395,
+    0x314, 0x300, 0x395, 0x313, 0x301, 0x395, 0x314, 0x301, 0x3b7, 0x313,
+    0x3b7, 0x314, 0x3b7, 0x313, 0x300, 0x3b7, 0x314, 0x300, 0x3b7, 0x313,
+    0x301, 0x3b7, 0x314, 0x301, 0x3b7, 0x313, 0x342, 0x3b7, 0x314, 0x342,
+    0x397, 0x313, 0x397, 0x314, 0x397, 0x313, 0x300, 0x397, 0x314, 0x300,
+    0x397, 0x313, 0x301, 0x397, 0x314, 0x301, 0x397, 0x313, 0x342, 0x397,
+    0x314, 0x342, 0x3b9, 0x313, 0x3b9, 0x314, 0x3b9, 0x313, 0x300, 0x3b9,
+    0x314, 0x300, 0x3b9, 0x313, 0x301, 0x3b9, 0x314, 0x301, 0x3b9, 0x313,
+    0x342, 0x3b9, 0x314, 0x342, 0x399, 0x313, 0x399, 0x314, 0x399, 0x313,
+    0x300, 0x399, 0x314, 0x300, 0x399, 0x313, 0x301, 0x399, 0x314, 0x301,
+    0x399, 0x313, 0x342, 0x399, 0x314, 0x342, 0x3bf, 0x313, 0x3bf, 0x314,
+    0x3bf, 0x313, 0x300, 0x3bf, 0x314, 0x300, 0x3bf, 0x313, 0x301, 0x3bf,
+    0x314, 0x301, 0x39f, 0x313, 0x39f, 0x314, 0x39f, 0x313, 0x300, 0x39f,
+    0x314, 0x300, 0x39f, 0x313, 0x301, 0x39f, 0x314, 0x301, 0x3c5, 0x313,
+    0x3c5, 0x314, 0x3c5, 0x313, 0x300, 0x3c5, 0x314, 0x300, 0x3c5, 0x313,
+    0x301, 0x3c5, 0x314, 0x301, 0x3c5, 0x313, 0x342, 0x3c5, 0x314, 0x342,
+    0x3a5, 0x314, 0x3a5, 0x314, 0x300, 0x3a5, 0x314, 0x301, 0x3a5, 0x314,
+    0x342, 0x3c9, 0x313, 0x3c9, 0x314, 0x3c9, 0x313, 0x300, 0x3c9, 0x314,
+    0x300, 0x3c9, 0x313, 0x301, 0x3c9, 0x314, 0x301, 0x3c9, 0x313, 0x342,
+    0x3c9, 0x314, 0x342, 0x3a9, 0x313, 0x3a9, 0x314, 0x3a9, 0x313, 0x300,
+    0x3a9, 0x314, 0x300, 0x3a9, 0x313, 0x301, 0x3a9, 0x314, 0x301, 0x3a9,
+    0x313, 0x342, 0x3a9, 0x314, 0x342, 0x3b1, 0x300, 0x3b1, 0x301, 0x3b5,
+    0x300, 0x3b5, 0x301, 0x3b7, 0x300, 0x3b7, 0x301, 0x3b9, 0x300, 0x3b9,
+    0x301, 0x3bf, 0x300, 0x3bf, 0x301, 0x3c5, 0x300, 0x3c5, 0x301, 0x3c9,
+    0x300, 0x3c9, 0x301, 0x3b1, 0x313, 0x345, 0x3b1, 0x314, 0x345, 0x3b1,
+    0x313, 0x300, 0x345, 0x3b1, 0x314, 0x300, 0x345, 0x3b1, 0x313, 0x301,
+    0x345, 0x3b1, 0x314, 0x301, 0x345, 0x3b1, 0x313, 0x342, 0x345, 0x3b1,
+    0x314, 0x342, 0x345, 0x391, 0x313, 0x345, 0x391, 0x314, 0x345, 0x391,
+    0x313, 0x300, 0x345, 0x391, 0x314, 0x300, 0x345, 0x391, 0x313, 0x301,
+    0x345, 0x391, 0x314, 0x301, 0x345, 0x391, 0x313, 0x342, 0x345, 0x391,
+    0x314, 0x342, 0x345, 0x3b7, 0x313, 0x345, 0x3b7, 0x314, 0x345, 0x3b7,
+    0x313, 0x300, 0x345, 0x3b7, 0x314, 0x300, 0x345, 0x3b7, 0x313, 0x301,
+    0x345, 0x3b7, 0x314, 0x301, 0x345, 0x3b7, 0x313, 0x342, 0x345, 0x3b7,
+    0x314, 0x342, 0x345, 0x397, 0x313, 0x345, 0x397, 0x314, 0x345, 0x397,
+    0x313, 0x300, 0x345, 0x397, 0x314, 0x300, 0x345, 0x397, 0x313, 0x301,
+    0x345, 0x397, 0x314, 0x301, 0x345, 0x397, 0x313, 0x342, 0x345, 0x397,
+    0x314, 0x342, 0x345, 0x3c9, 0x313, 0x345, 0x3c9, 0x314, 0x345, 0x3c9,
+    0x313, 0x300, 0x345, 0x3c9, 0x314, 0x300, 0x345, 0x3c9, 0x313, 0x301,
+    0x345, 0x3c9, 0x314, 0x301, 0x345, 0x3c9, 0x313, 0x342, 0x345, 0x3c9,
+    0x314, 0x342, 0x345, 0x3a9, 0x313, 0x345, 0x3a9, 0x314, 0x345, 0x3a9,
+    0x313, 0x300, 0x345, 0x3a9, 0x314, 0x300, 0x345, 0x3a9, 0x313, 0x301,
+    0x345, 0x3a9, 0x314, 0x301, 0x345, 0x3a9, 0x313, 0x342, 0x345, 0x3a9,
+    0x314, 0x342, 0x345, 0x3b1, 0x306, 0x3b1, 0x304, 0x3b1, 0x300, 0x345,
+    0x3b1, 0x345, 0x3b1, 0x301, 0x345, 0x3b1, 0x342, 0x3b1, 0x342, 0x345,
+    0x391, 0x306, 0x391, 0x304, 0x391, 0x300, 0x391, 0x301, 0x391, 0x345,
+    0x20, 0x313, 0x3b9, 0x20, 0x313, 0x20, 0x342, 0x20, 0x308, 0x342,
+    0x3b7, 0x300, 0x345, 0x3b7, 0x345, 0x3b7, 0x301, 0x345, 0x3b7, 0x342,
+    0x3b7, 0x342, 0x345, 0x395, 0x300, 0x395, 0x301, 0x397, 0x300, 0x397,
+    0x301, 0x397, 0x345, 0x20, 0x313, 0x300, 0x20, 0x313, 0x301, 0x20,
+    0x313, 0x342, 0x3b9, 0x306, 0x3b9, 0x304, 0x3b9, 0x308, 0x300, 0x3b9,
+    0x308, 0x301, 0x3b9, 0x342, 0x3b9, 0x308, 0x342, 0x399, 0x306, 0x399,
+    0x304, 0x399, 0x300, 0x399, 0x301, 0x20, 0x314, 0x300, 0x20, 0x314,
+    0x301, 0x20, 0x314, 0x342, 0x3c5, 0x306, 0x3c5, 0x304, 0x3c5, 0x308,
+    0x300, 0x3c5, 0x308, 0x301, 0x3c1, 0x313, 0x3c1, 0x314, 0x3c5, 0x342,
+    0x3c5, 0x308, 0x342, 0x3a5, 0x306, 0x3a5, 0x304, 0x3a5, 0x300, 0x3a5,
+    0x301, 0x3a1, 0x314, 0x20, 0x308, 0x300, 0x20, 0x308, 0x301, 0x60,
+    0x3c9, 0x300, 0x345, 0x3c9, 0x345, 0x3c9, 0x301, 0x345, 0x3c9, 0x342,
+    0x3c9, 0x342, 0x345, 0x39f, 0x300, 0x39f, 0x301, 0x3a9, 0x300, 0x3a9,
+    0x301, 0x3a9, 0x345, 0x20, 0x301, 0x20, 0x314, 0x20, 0x20, 0x20,
+    0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x2010, 0x20,
+    0x333, 0x2e, 0x2e, 0x2e, 0x2e, 0x2e, 0x2e, 0x20, 0x2032, 0x2032,
+    0x2032, 0x2032, 0x2032, 0x2035, 0x2035, 0x2035, 0x2035, 0x2035, 0x21, 0x21,
+    0x20, 0x305, 0x3f, 0x3f, 0x3f, 0x21, 0x21, 0x3f, 0x2032, 0x2032,
+    0x2032, 0x2032, 0x20, 0x30, 0x69, 0x34, 0x35, 0x36, 0x37, 0x38,
+    0x39, 0x2b, 0x2212, 0x3d, 0x28, 0x29, 0x6e, 0x30, 0x31, 0x32,
+    0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x2b, 0x2212, 0x3d,
+    0x28, 0x29, 0x61, 0x65, 0x6f, 0x78, 0x259, 0x68, 0x6b, 0x6c,
+    0x6d, 0x6e, 0x70, 0x73, 0x74, 0x52, 0x73, 0x61, 0x2f, 0x63,
+    0x61, 0x2f, 0x73, 0x43, 0xb0, 0x43, 0x63, 0x2f, 0x6f, 0x63,
+    0x2f, 0x75, 0x190, 0xb0, 0x46, 0x67, 0x48, 0x48, 0x48, 0x68,
+    0x127, 0x49, 0x49, 0x4c, 0x6c, 0x4e, 0x4e, 0x6f, 0x50, 0x51,
+    0x52, 0x52, 0x52, 0x53, 0x4d, 0x54, 0x45, 0x4c, 0x54, 0x4d,
+    0x5a, 0x3a9, 0x5a, 0x4b, 0x41, 0x30a, 0x42, 0x43, 0x65, 0x45,
+    0x46, 0x4d, 0x6f, 0x5d0, 0x5d1, 0x5d2, 0x5d3, 0x69, 0x46, 0x41,
+    0x58, 0x3c0, 0x3b3, 0x393, 0x3a0, 0x2211, 0x44, 0x64, 0x65, 0x69,
+    0x6a, 0x31, 0x2044, 0x37, 0x31, 0x2044, 0x39, 0x31, 0x2044, 0x31,
+    0x30, 0x31, 0x2044, 0x33, 0x32, 0x2044, 0x33, 0x31, 0x2044, 0x35,
+    0x32, 0x2044, 0x35, 0x33, 0x2044, 0x35, 0x34, 0x2044, 0x35, 0x31,
+    0x2044, 0x36, 0x35, 0x2044, 0x36, 0x31, 0x2044, 0x38, 0x33, 0x2044,
+    0x38, 0x35, 0x2044, 0x38, 0x37, 0x2044, 0x38, 0x31, 0x2044, 0x49,
+    0x49, 0x49, 0x49, 0x49, 0x49, 0x49, 0x56, 0x56, 0x56, 0x49,
+    0x56, 0x49, 0x49, 0x56, 0x49, 0x49, 0x49, 0x49, 0x58, 0x58,
+    0x58, 0x49, 0x58, 0x49, 0x49, 0x4c, 0x43, 0x44, 0x4d, 0x69,
+    0x69, 0x69, 0x69, 0x69, 0x69, 0x69, 0x76, 0x76, 0x76, 0x69,
+    0x76, 0x69, 0x69, 0x76, 0x69, 0x69, 0x69, 0x69, 0x78, 0x78,
+    0x78, 0x69, 0x78, 0x69, 0x69, 0x6c, 0x63, 0x64, 0x6d, 0x30,
+    0x2044, 0x33, 0x2190, 0x338, 0x2192, 0x338, 0x2194, 0x338, 0x21d0, 0x338,
+    0x21d4, 0x338, 0x21d2, 0x338, 0x2203, 0x338, 0x2208, 0x338, 0x220b, 0x338,
+    0x2223, 0x338, 0x2225, 0x338, 0x222b, 0x222b, 0x222b, 0x222b, 0x222b, 0x222e,
+    0x222e, 0x222e, 0x222e, 0x222e, 0x223c, 0x338, 0x2243, 0x338, 0x2245, 0x338,
+    0x2248, 0x338, 0x3d, 0x338, 0x2261, 0x338, 0x224d, 0x338, 0x3c, 0x338,
+    0x3e, 0x338, 0x2264, 0x338, 0x2265, 0x338, 0x2272, 0x338, 0x2273, 0x338,
+    0x2276, 0x338, 0x2277, 0x338, 0x227a, 0x338, 0x227b, 0x338, 0x2282, 0x338,
+    0x2283, 0x338, 0x2286, 0x338, 0x2287, 0x338, 0x22a2, 0x338, 0x22a8, 0x338,
+    0x22a9, 0x338, 0x22ab, 0x338, 0x227c, 0x338, 0x227d, 0x338, 0x2291, 0x338,
+    0x2292, 0x338, 0x22b2, 0x338, 0x22b3, 0x338, 0x22b4, 0x338, 0x22b5, 0x338,
+    0x3008, 0x3009, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38,
+    0x39, 0x31, 0x30, 0x31, 0x31, 0x31, 0x32, 0x31, 0x33, 0x31,
+    0x34, 0x31, 0x35, 0x31, 0x36, 0x31, 0x37, 0x31, 0x38, 0x31,
+    0x39, 0x32, 0x30, 0x28, 0x31, 0x29, 0x28, 0x32, 0x29, 0x28,
+    0x33, 0x29, 0x28, 0x34, 0x29, 0x28, 0x35, 0x29, 0x28, 0x36,
+    0x29, 0x28, 0x37, 0x29, 0x28, 0x38, 0x29, 0x28, 0x39, 0x29,
+    0x28, 0x31, 0x30, 0x29, 0x28, 0x31, 0x31, 0x29, 0x28, 0x31,
+    0x32, 0x29, 0x28, 0x31, 0x33, 0x29, 0x28, 0x31, 0x34, 0x29,
+    0x28, 0x31, 0x35, 0x29, 0x28, 0x31, 0x36, 0x29, 0x28, 0x31,
+    0x37, 0x29, 0x28, 0x31, 0x38, 0x29, 0x28, 0x31, 0x39, 0x29,
+    0x28, 0x32, 0x30, 0x29, 0x31, 0x2e, 0x32, 0x2e, 0x33, 0x2e,
+    0x34, 0x2e, 0x35, 0x2e, 0x36, 0x2e, 0x37, 0x2e, 0x38, 0x2e,
+    0x39, 0x2e, 0x31, 0x30, 0x2e, 0x31, 0x31, 0x2e, 0x31, 0x32,
+    0x2e, 0x31, 0x33, 0x2e, 0x31, 0x34, 0x2e, 0x31, 0x35, 0x2e,
+    0x31, 0x36, 0x2e, 0x31, 0x37, 0x2e, 0x31, 0x38, 0x2e, 0x31,
+    0x39, 0x2e, 0x32, 0x30, 0x2e, 0x28, 0x61, 0x29, 0x28, 0x62,
+    0x29, 0x28, 0x63, 0x29, 0x28, 0x64, 0x29, 0x28, 0x65, 0x29,
+    0x28, 0x66, 0x29, 0x28, 0x67, 0x29, 0x28, 0x68, 0x29, 0x28,
+    0x69, 0x29, 0x28, 0x6a, 0x29, 0x28, 0x6b, 0x29, 0x28, 0x6c,
+    0x29, 0x28, 0x6d, 0x29, 0x28, 0x6e, 0x29, 0x28, 0x6f, 0x29,
+    0x28, 0x70, 0x29, 0x28, 0x71, 0x29, 0x28, 0x72, 0x29, 0x28,
+    0x73, 0x29, 0x28, 0x74, 0x29, 0x28, 0x75, 0x29, 0x28, 0x76,
+    0x29, 0x28, 0x77, 0x29, 0x28, 0x78, 0x29, 0x28, 0x79, 0x29,
+    0x28, 0x7a, 0x29, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47,
+    0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f, 0x50, 0x51,
+    0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0x61,
+    0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6a, 0x6b,
+    0x6c, 0x6d, 0x6e, 0x6f, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75,
+    0x76, 0x77, 0x78, 0x79, 0x7a, 0x30, 0x222b, 0x222b, 0x222b, 0x222b,
+    0x3a, 0x3a, 0x3d, 0x3d, 0x3d, 0x3d, 0x3d, 0x3d, 0x2add, 0x338,
+    0x6a, 0x56, 0x2d61, 0x6bcd, 0x9f9f, 0x4e00, 0x4e28, 0x4e36, 0x4e3f, 0x4e59,
+    0x4e85, 0x4e8c, 0x4ea0, 0x4eba, 0x513f, 0x5165, 0x516b, 0x5182, 0x5196, 0x51ab,
+    0x51e0, 0x51f5, 0x5200, 0x529b, 0x52f9, 0x5315, 0x531a, 0x5338, 0x5341, 0x535c,
+    0x5369, 0x5382, 0x53b6, 0x53c8, 0x53e3, 0x56d7, 0x571f, 0x58eb, 0x5902, 0x590a,
+    0x5915, 0x5927, 0x5973, 0x5b50, 0x5b80, 0x5bf8, 0x5c0f, 0x5c22, 0x5c38, 0x5c6e,
+    0x5c71, 0x5ddb, 0x5de5, 0x5df1, 0x5dfe, 0x5e72, 0x5e7a, 0x5e7f, 0x5ef4, 0x5efe,
+    0x5f0b, 0x5f13, 0x5f50, 0x5f61, 0x5f73, 0x5fc3, 0x6208, 0x6236, 0x624b, 0x652f,
+    0x6534, 0x6587, 0x6597, 0x65a4, 0x65b9, 0x65e0, 0x65e5, 0x66f0, 0x6708, 0x6728,
+    0x6b20, 0x6b62, 0x6b79, 0x6bb3, 0x6bcb, 0x6bd4, 0x6bdb, 0x6c0f, 0x6c14, 0x6c34,
+    0x706b, 0x722a, 0x7236, 0x723b, 0x723f, 0x7247, 0x7259, 0x725b, 0x72ac, 0x7384,
+    0x7389, 0x74dc, 0x74e6, 0x7518, 0x751f, 0x7528, 0x7530, 0x758b, 0x7592, 0x7676,
+    0x767d, 0x76ae, 0x76bf, 0x76ee, 0x77db, 0x77e2, 0x77f3, 0x793a, 0x79b8, 0x79be,
+    0x7a74, 0x7acb, 0x7af9, 0x7c73, 0x7cf8, 0x7f36, 0x7f51, 0x7f8a, 0x7fbd, 0x8001,
+    0x800c, 0x8012, 0x8033, 0x807f, 0x8089, 0x81e3, 0x81ea, 0x81f3, 0x81fc, 0x820c,
+    0x821b, 0x821f, 0x826e, 0x8272, 0x8278, 0x864d, 0x866b, 0x8840, 0x884c, 0x8863,
+    0x897e, 0x898b, 0x89d2, 0x8a00, 0x8c37, 0x8c46, 0x8c55, 0x8c78, 0x8c9d, 0x8d64,
+    0x8d70, 0x8db3, 0x8eab, 0x8eca, 0x8f9b, 0x8fb0, 0x8fb5, 0x9091, 0x9149, 0x91c6,
+    0x91cc, 0x91d1, 0x9577, 0x9580, 0x961c, 0x96b6, 0x96b9, 0x96e8, 0x9751, 0x975e,
+    0x9762, 0x9769, 0x97cb, 0x97ed, 0x97f3, 0x9801, 0x98a8, 0x98db, 0x98df, 0x9996,
+    0x9999, 0x99ac, 0x9aa8, 0x9ad8, 0x9adf, 0x9b25, 0x9b2f, 0x9b32, 0x9b3c, 0x9b5a,
+    0x9ce5, 0x9e75, 0x9e7f, 0x9ea5, 0x9ebb, 0x9ec3, 0x9ecd, 0x9ed1, 0x9ef9, 0x9efd,
+    0x9f0e, 0x9f13, 0x9f20, 0x9f3b, 0x9f4a, 0x9f52, 0x9f8d, 0x9f9c, 0x9fa0, 0x20,
+    0x3012, 0x5341, 0x5344, 0x5345, 0x304b, 0x3099, 0x304d, 0x3099, 0x304f, 0x3099,
+    0x3051, 0x3099, 0x3053, 0x3099, 0x3055, 0x3099, 0x3057, 0x3099, 0x3059, 0x3099,
+    0x305b, 0x3099, 0x305d, 0x3099, 0x305f, 0x3099, 0x3061, 0x3099, 0x3064, 0x3099,
+    0x3066, 0x3099, 0x3068, 0x3099, 0x306f, 0x3099, 0x306f, 0x309a, 0x3072, 0x3099,
+    0x3072, 0x309a, 0x3075, 0x3099, 0x3075, 0x309a, 0x3078, 0x3099, 0x3078, 0x309a,
+    0x307b, 0x3099, 0x307b, 0x309a, 0x3046, 0x3099, 0x20, 0x3099, 0x20, 0x309a,
+    0x309d, 0x3099, 0x3088, 0x308a, 0x30ab, 0x3099, 0x30ad, 0x3099, 0x30af, 0x3099,
+    0x30b1, 0x3099, 0x30b3, 0x3099, 0x30b5, 0x3099, 0x30b7, 0x3099, 0x30b9, 0x3099,
+    0x30bb, 0x3099, 0x30bd, 0x3099, 0x30bf, 0x3099, 0x30c1, 0x3099, 0x30c4, 0x3099,
+    0x30c6, 0x3099, 0x30c8, 0x3099, 0x30cf, 0x3099, 0x30cf, 0x309a, 0x30d2, 0x3099,
+    0x30d2, 0x309a, 0x30d5, 0x3099, 0x30d5, 0x309a, 0x30d8, 0x3099, 0x30d8, 0x309a,
+    0x30db, 0x3099, 0x30db, 0x309a, 0x30a6, 0x3099, 0x30ef, 0x3099, 0x30f0, 0x3099,
+    0x30f1, 0x3099, 0x30f2, 0x3099, 0x30fd, 0x3099, 0x30b3, 0x30c8, 0x1100, 0x1101,
+    0x11aa, 0x1102, 0x11ac, 0x11ad, 0x1103, 0x1104, 0x1105, 0x11b0, 0x11b1, 0x11b2,
+    0x11b3, 0x11b4, 0x11b5, 0x111a, 0x1106, 0x1107, 0x1108, 0x1121, 0x1109, 0x110a,
+    0x110b, 0x110c, 0x110d, 0x110e, 0x110f, 0x1110, 0x1111, 0x1112, 0x1161, 0x1162,
+    0x1163, 0x1164, 0x1165, 0x1166, 0x1167, 0x1168, 0x1169, 0x116a, 0x116b, 0x116c,
+    0x116d, 0x116e, 0x116f, 0x1170, 0x1171, 0x1172, 0x1173, 0x1174, 0x1175, 0x1160,
+    0x1114, 0x1115, 0x11c7, 0x11c8, 0x11cc, 0x11ce, 0x11d3, 0x11d7, 0x11d9, 0x111c,
+    0x11dd, 0x11df, 0x111d, 0x111e, 0x1120, 0x1122, 0x1123, 0x1127, 0x1129, 0x112b,
+    0x112c, 0x112d, 0x112e, 0x112f, 0x1132, 0x1136, 0x1140, 0x1147, 0x114c, 0x11f1,
+    0x11f2, 0x1157, 0x1158, 0x1159, 0x1184, 0x1185, 0x1188, 0x1191, 0x1192, 0x1194,
+    0x119e, 0x11a1, 0x4e00, 0x4e8c, 0x4e09, 0x56db, 0x4e0a, 0x4e2d, 0x4e0b, 0x7532,
+    0x4e59, 0x4e19, 0x4e01, 0x5929, 0x5730, 0x4eba, 0x28, 0x1100, 0x29, 0x28,
+    0x1102, 0x29, 0x28, 0x1103, 0x29, 0x28, 0x1105, 0x29, 0x28, 0x1106,
+    0x29, 0x28, 0x1107, 0x29, 0x28, 0x1109, 0x29, 0x28, 0x110b, 0x29,
+    0x28, 0x110c, 0x29, 0x28, 0x110e, 0x29, 0x28, 0x110f, 0x29, 0x28,
+    0x1110, 0x29, 0x28, 0x1111, 0x29, 0x28, 0x1112, 0x29, 0x28, 0x1100,
+    0x1161, 0x29, 0x28, 0x1102, 0x1161, 0x29, 0x28, 0x1103, 0x1161, 0x29,
+    0x28, 0x1105, 0x1161, 0x29, 0x28, 0x1106, 0x1161, 0x29, 0x28, 0x1107,
+    0x1161, 0x29, 0x28, 0x1109, 0x1161, 0x29, 0x28, 0x110b, 0x1161, 0x29,
+    0x28, 0x110c, 0x1161, 0x29, 0x28, 0x110e, 0x1161, 0x29, 0x28, 0x110f,
+    0x1161, 0x29, 0x28, 0x1110, 0x1161, 0x29, 0x28, 0x1111, 0x1161, 0x29,
+    0x28, 0x1112, 0x1161, 0x29, 0x28, 0x110c, 0x116e, 0x29, 0x28, 0x110b,
+    0x1169, 0x110c, 0x1165, 0x11ab, 0x29, 0x28, 0x110b, 0x1169, 0x1112, 0x116e,
+    0x29, 0x28, 0x4e00, 0x29, 0x28, 0x4e8c, 0x29, 0x28, 0x4e09, 0x29,
+    0x28, 0x56db, 0x29, 0x28, 0x4e94, 0x29, 0x28, 0x516d, 0x29, 0x28,
+    0x4e03, 0x29, 0x28, 0x516b, 0x29, 0x28, 0x4e5d, 0x29, 0x28, 0x5341,
+    0x29, 0x28, 0x6708, 0x29, 0x28, 0x706b, 0x29, 0x28, 0x6c34, 0x29,
+    0x28, 0x6728, 0x29, 0x28, 0x91d1, 0x29, 0x28, 0x571f, 0x29, 0x28,
+    0x65e5, 0x29, 0x28, 0x682a, 0x29, 0x28, 0x6709, 0x29, 0x28, 0x793e,
+    0x29, 0x28, 0x540d, 0x29, 0x28, 0x7279, 0x29, 0x28, 0x8ca1, 0x29,
+    0x28, 0x795d, 0x29, 0x28, 0x52b4, 0x29, 0x28, 0x4ee3, 0x29, 0x28,
+    0x547c, 0x29, 0x28, 0x5b66, 0x29, 0x28, 0x76e3, 0x29, 0x28, 0x4f01,
+    0x29, 0x28, 0x8cc7, 0x29, 0x28, 0x5354, 0x29, 0x28, 0x796d, 0x29,
+    0x28, 0x4f11, 0x29, 0x28, 0x81ea, 0x29, 0x28, 0x81f3, 0x29, 0x554f,
+    0x5e7c, 0x6587, 0x7b8f, 0x50, 0x54, 0x45, 0x32, 0x31, 0x32, 0x32,
+    0x32, 0x33, 0x32, 0x34, 0x32, 0x35, 0x32, 0x36, 0x32, 0x37,
+    0x32, 0x38, 0x32, 0x39, 0x33, 0x30, 0x33, 0x31, 0x33, 0x32,
+    0x33, 0x33, 0x33, 0x34, 0x33, 0x35, 0x1100, 0x1102, 0x1103, 0x1105,
+    0x1106, 0x1107, 0x1109, 0x110b, 0x110c, 0x110e, 0x110f, 0x1110, 0x1111, 0x1112,
+    0x1100, 0x1161, 0x1102, 0x1161, 0x1103, 0x1161, 0x1105, 0x1161, 0x1106, 0x1161,
+    0x1107, 0x1161, 0x1109, 0x1161, 0x110b, 0x1161, 0x110c, 0x1161, 0x110e, 0x1161,
+    0x110f, 0x1161, 0x1110, 0x1161, 0x1111, 0x1161, 0x1112, 0x1161, 0x110e, 0x1161,
+    0x11b7, 0x1100, 0x1169, 0x110c, 0x116e, 0x110b, 0x1174, 0x110b, 0x116e, 0x4e00,
+    0x4e8c, 0x4e09, 0x56db, 0x4e94, 0x516d, 0x4e03, 0x516b, 0x4e5d, 0x5341, 0x6708,
+    0x706b, 0x6c34, 0x6728, 0x91d1, 0x571f, 0x65e5, 0x682a, 0x6709, 0x793e, 0x540d,
+    0x7279, 0x8ca1, 0x795d, 0x52b4, 0x79d8, 0x7537, 0x5973, 0x9069, 0x512a, 0x5370,
+    0x6ce8, 0x9805, 0x4f11, 0x5199, 0x6b63, 0x4e0a, 0x4e2d, 0x4e0b, 0x5de6, 0x53f3,
+    0x533b, 0x5b97, 0x5b66, 0x76e3, 0x4f01, 0x8cc7, 0x5354, 0x591c, 0x33, 0x36,
+    0x33, 0x37, 0x33, 0x38, 0x33, 0x39, 0x34, 0x30, 0x34, 0x31,
+    0x34, 0x32, 0x34, 0x33, 0x34, 0x34, 0x34, 0x35, 0x34, 0x36,
+    0x34, 0x37, 0x34, 0x38, 0x34, 0x39, 0x35, 0x30, 0x31, 0x6708,
+    0x32, 0x6708, 0x33, 0x6708, 0x34, 0x6708, 0x35, 0x6708, 0x36, 0x6708,
+    0x37, 0x6708, 0x38, 0x6708, 0x39, 0x6708, 0x31, 0x30, 0x6708, 0x31,
+    0x31, 0x6708, 0x31, 0x32, 0x6708, 0x48, 0x67, 0x65, 0x72, 0x67,
+    0x65, 0x56, 0x4c, 0x54, 0x44, 0x30a2, 0x30a4, 0x30a6, 0x30a8, 0x30aa,
+    0x30ab, 0x30ad, 0x30af, 0x30b1, 0x30b3, 0x30b5, 0x30b7, 0x30b9, 0x30bb, 0x30bd,
+    0x30bf, 0x30c1, 0x30c4, 0x30c6, 0x30c8, 0x30ca, 0x30cb, 0x30cc, 0x30cd, 0x30ce,
+    0x30cf, 0x30d2, 0x30d5, 0x30d8, 0x30db, 0x30de, 0x30df, 0x30e0, 0x30e1, 0x30e2,
+    0x30e4, 0x30e6, 0x30e8, 0x30e9, 0x30ea, 0x30eb, 0x30ec, 0x30ed, 0x30ef, 0x30f0,
+    0x30f1, 0x30f2, 0x4ee4, 0x548c, 0x30a2, 0x30cf, 0x309a, 0x30fc, 0x30c8, 0x30a2,
+    0x30eb, 0x30d5, 0x30a1, 0x30a2, 0x30f3, 0x30d8, 0x309a, 0x30a2, 0x30a2, 0x30fc,
+    0x30eb, 0x30a4, 0x30cb, 0x30f3, 0x30af, 0x3099, 0x30a4, 0x30f3, 0x30c1, 0x30a6,
+    0x30a9, 0x30f3, 0x30a8, 0x30b9, 0x30af, 0x30fc, 0x30c8, 0x3099, 0x30a8, 0x30fc,
+    0x30ab, 0x30fc, 0x30aa, 0x30f3, 0x30b9, 0x30aa, 0x30fc, 0x30e0, 0x30ab, 0x30a4,
+    0x30ea, 0x30ab, 0x30e9, 0x30c3, 0x30c8, 0x30ab, 0x30ed, 0x30ea, 0x30fc, 0x30ab,
+    0x3099, 0x30ed, 0x30f3, 0x30ab, 0x3099, 0x30f3, 0x30de, 0x30ad, 0x3099, 0x30ab,
+    0x3099, 0x30ad, 0x3099, 0x30cb, 0x30fc, 0x30ad, 0x30e5, 0x30ea, 0x30fc, 0x30ad,
+    0x3099, 0x30eb, 0x30bf, 0x3099, 0x30fc, 0x30ad, 0x30ed, 0x30ad, 0x30ed, 0x30af,
+    0x3099, 0x30e9, 0x30e0, 0x30ad, 0x30ed, 0x30e1, 0x30fc, 0x30c8, 0x30eb, 0x30ad,
+    0x30ed, 0x30ef, 0x30c3, 0x30c8, 0x30af, 0x3099, 0x30e9, 0x30e0, 0x30af, 0x3099,
+    0x30e9, 0x30e0, 0x30c8, 0x30f3, 0x30af, 0x30eb, 0x30bb, 0x3099, 0x30a4, 0x30ed,
+    0x30af, 0x30ed, 0x30fc, 0x30cd, 0x30b1, 0x30fc, 0x30b9, 0x30b3, 0x30eb, 0x30ca,
+    0x30b3, 0x30fc, 0x30db, 0x309a, 0x30b5, 0x30a4, 0x30af, 0x30eb, 0x30b5, 0x30f3,
+    0x30c1, 0x30fc, 0x30e0, 0x30b7, 0x30ea, 0x30f3, 0x30af, 0x3099, 0x30bb, 0x30f3,
+    0x30c1, 0x30bb, 0x30f3, 0x30c8, 0x30bf, 0x3099, 0x30fc, 0x30b9, 0x30c6, 0x3099,
+    0x30b7, 0x30c8, 0x3099, 0x30eb, 0x30c8, 0x30f3, 0x30ca, 0x30ce, 0x30ce, 0x30c3,
+    0x30c8, 0x30cf, 0x30a4, 0x30c4, 0x30cf, 0x309a, 0x30fc, 0x30bb, 0x30f3, 0x30c8,
+    0x30cf, 0x309a, 0x30fc, 0x30c4, 0x30cf, 0x3099, 0x30fc, 0x30ec, 0x30eb, 0x30d2,
+    0x309a, 0x30a2, 0x30b9, 0x30c8, 0x30eb, 0x30d2, 0x309a, 0x30af, 0x30eb, 0x30d2,
+    0x309a, 0x30b3, 0x30d2, 0x3099, 0x30eb, 0x30d5, 0x30a1, 0x30e9, 0x30c3, 0x30c8,
+    0x3099, 0x30d5, 0x30a3, 0x30fc, 0x30c8, 0x30d5, 0x3099, 0x30c3, 0x30b7, 0x30a7,
+    0x30eb, 0x30d5, 0x30e9, 0x30f3, 0x30d8, 0x30af, 0x30bf, 0x30fc, 0x30eb, 0x30d8,
+    0x309a, 0x30bd, 0x30d8, 0x309a, 0x30cb, 0x30d2, 0x30d8, 0x30eb, 0x30c4, 0x30d8,
+    0x309a, 0x30f3, 0x30b9, 0x30d8, 0x309a, 0x30fc, 0x30b7, 0x3099, 0x30d8, 0x3099,
+    0x30fc, 0x30bf, 0x30db, 0x309a, 0x30a4, 0x30f3, 0x30c8, 0x30db, 0x3099, 0x30eb,
+    0x30c8, 0x30db, 0x30f3, 0x30db, 0x309a, 0x30f3, 0x30c8, 0x3099, 0x30db, 0x30fc,
+    0x30eb, 0x30db, 0x30fc, 0x30f3, 0x30de, 0x30a4, 0x30af, 0x30ed, 0x30de, 0x30a4,
+    0x30eb, 0x30de, 0x30c3, 0x30cf, 0x30de, 0x30eb, 0x30af, 0x30de, 0x30f3, 0x30b7,
+    0x30e7, 0x30f3, 0x30df, 0x30af, 0x30ed, 0x30f3, 0x30df, 0x30ea, 0x30df, 0x30ea,
+    0x30cf, 0x3099, 0x30fc, 0x30eb, 0x30e1, 0x30ab, 0x3099, 0x30e1, 0x30ab, 0x3099,
+    0x30c8, 0x30f3, 0x30e1, 0x30fc, 0x30c8, 0x30eb, 0x30e4, 0x30fc, 0x30c8, 0x3099,
+    0x30e4, 0x30fc, 0x30eb, 0x30e6, 0x30a2, 0x30f3, 0x30ea, 0x30c3, 0x30c8, 0x30eb,
+    0x30ea, 0x30e9, 0x30eb, 0x30d2, 0x309a, 0x30fc, 0x30eb, 0x30fc, 0x30d5, 0x3099,
+    0x30eb, 0x30ec, 0x30e0, 0x30ec, 0x30f3, 0x30c8, 0x30b1, 0x3099, 0x30f3, 0x30ef,
+    0x30c3, 0x30c8, 0x30, 0x70b9, 0x31, 0x70b9, 0x32, 0x70b9, 0x33, 0x70b9,
+    0x34, 0x70b9, 0x35, 0x70b9, 0x36, 0x70b9, 0x37, 0x70b9, 0x38, 0x70b9,
+    0x39, 0x70b9, 0x31, 0x30, 0x70b9, 0x31, 0x31, 0x70b9, 0x31, 0x32,
+    0x70b9, 0x31, 0x33, 0x70b9, 0x31, 0x34, 0x70b9, 0x31, 0x35, 0x70b9,
+    0x31, 0x36, 0x70b9, 0x31, 0x37, 0x70b9, 0x31, 0x38, 0x70b9, 0x31,
+    0x39, 0x70b9, 0x32, 0x30, 0x70b9, 0x32, 0x31, 0x70b9, 0x32, 0x32,
+    0x70b9, 0x32, 0x33, 0x70b9, 0x32, 0x34, 0x70b9, 0x68, 0x50, 0x61,
+    0x64, 0x61, 0x41, 0x55, 0x62, 0x61, 0x72, 0x6f, 0x56, 0x70,
+    0x63, 0x64, 0x6d, 0x64, 0x6d, 0x32, 0x64, 0x6d, 0x33, 0x49,
+    0x55, 0x5e73, 0x6210, 0x662d, 0x548c, 0x5927, 0x6b63, 0x660e, 0x6cbb, 0x682a,
+    0x5f0f, 0x4f1a, 0x793e, 0x70, 0x41, 0x6e, 0x41, 0x3bc, 0x41, 0x6d,
+    0x41, 0x6b, 0x41, 0x4b, 0x42, 0x4d, 0x42, 0x47, 0x42, 0x63,
+    0x61, 0x6c, 0x6b, 0x63, 0x61, 0x6c, 0x70, 0x46, 0x6e, 0x46,
+    0x3bc, 0x46, 0x3bc, 0x67, 0x6d, 0x67, 0x6b, 0x67, 0x48, 0x7a,
+    0x6b, 0x48, 0x7a, 0x4d, 0x48, 0x7a, 0x47, 0x48, 0x7a, 0x54,
+    0x48, 0x7a, 0x3bc, 0x6c, 0x6d, 0x6c, 0x64, 0x6c, 0x6b, 0x6c,
+    0x66, 0x6d, 0x6e, 0x6d, 0x3bc, 0x6d, 0x6d, 0x6d, 0x63, 0x6d,
+    0x6b, 0x6d, 0x6d, 0x6d, 0x32, 0x63, 0x6d, 0x32, 0x6d, 0x32,
+    0x6b, 0x6d, 0x32, 0x6d, 0x6d, 0x33, 0x63, 0x6d, 0x33, 0x6d,
+    0x33, 0x6b, 0x6d, 0x33, 0x6d, 0x2215, 0x73, 0x6d, 0x2215, 0x73,
+    0x32, 0x50, 0x61, 0x6b, 0x50, 0x61, 0x4d, 0x50, 0x61, 0x47,
+    0x50, 0x61, 0x72, 0x61, 0x64, 0x72, 0x61, 0x64, 0x2215, 0x73,
+    0x72, 0x61, 0x64, 0x2215, 0x73, 0x32, 0x70, 0x73, 0x6e, 0x73,
+    0x3bc, 0x73, 0x6d, 0x73, 0x70, 0x56, 0x6e, 0x56, 0x3bc, 0x56,
+    0x6d, 0x56, 0x6b, 0x56, 0x4d, 0x56, 0x70, 0x57, 0x6e, 0x57,
+    0x3bc, 0x57, 0x6d, 0x57, 0x6b, 0x57, 0x4d, 0x57, 0x6b, 0x3a9,
+    0x4d, 0x3a9, 0x61, 0x2e, 0x6d, 0x2e, 0x42, 0x71, 0x63, 0x63,
+    0x63, 0x64, 0x43, 0x2215, 0x6b, 0x67, 0x43, 0x6f, 0x2e, 0x64,
+    0x42, 0x47, 0x79, 0x68, 0x61, 0x48, 0x50, 0x69, 0x6e, 0x4b,
+    0x4b, 0x4b, 0x4d, 0x6b, 0x74, 0x6c, 0x6d, 0x6c, 0x6e, 0x6c,
+    0x6f, 0x67, 0x6c, 0x78, 0x6d, 0x62, 0x6d, 0x69, 0x6c, 0x6d,
+    0x6f, 0x6c, 0x50, 0x48, 0x70, 0x2e, 0x6d, 0x2e, 0x50, 0x50,
+    0x4d, 0x50, 0x52, 0x73, 0x72, 0x53, 0x76, 0x57, 0x62, 0x56,
+    0x2215, 0x6d, 0x41, 0x2215, 0x6d, 0x31, 0x65e5, 0x32, 0x65e5, 0x33,
+    0x65e5, 0x34, 0x65e5, 0x35, 0x65e5, 0x36, 0x65e5, 0x37, 0x65e5, 0x38,
+    0x65e5, 0x39, 0x65e5, 0x31, 0x30, 0x65e5, 0x31, 0x31, 0x65e5, 0x31,
+    0x32, 0x65e5, 0x31, 0x33, 0x65e5, 0x31, 0x34, 0x65e5, 0x31, 0x35,
+    0x65e5, 0x31, 0x36, 0x65e5, 0x31, 0x37, 0x65e5, 0x31, 0x38, 0x65e5,
+    0x31, 0x39, 0x65e5, 0x32, 0x30, 0x65e5, 0x32, 0x31, 0x65e5, 0x32,
+    0x32, 0x65e5, 0x32, 0x33, 0x65e5, 0x32, 0x34, 0x65e5, 0x32, 0x35,
+    0x65e5, 0x32, 0x36, 0x65e5, 0x32, 0x37, 0x65e5, 0x32, 0x38, 0x65e5,
+    0x32, 0x39, 0x65e5, 0x33, 0x30, 0x65e5, 0x33, 0x31, 0x65e5, 0x67,
+    0x61, 0x6c, 0x44a, 0x44c, 0xa76f, 0x43, 0x46, 0x51, 0x126, 0x153,
+    0xa727, 0xab37, 0x26b, 0xab52, 0x28d, 0x8c48, 0x66f4, 0x8eca, 0x8cc8, 0x6ed1,
+    0x4e32, 0x53e5, 0x9f9c, 0x9f9c, 0x5951, 0x91d1, 0x5587, 0x5948, 0x61f6, 0x7669,
+    0x7f85, 0x863f, 0x87ba, 0x88f8, 0x908f, 0x6a02, 0x6d1b, 0x70d9, 0x73de, 0x843d,
+    0x916a, 0x99f1, 0x4e82, 0x5375, 0x6b04, 0x721b, 0x862d, 0x9e1e, 0x5d50, 0x6feb,
+    0x85cd, 0x8964, 0x62c9, 0x81d8, 0x881f, 0x5eca, 0x6717, 0x6d6a, 0x72fc, 0x90ce,
+    0x4f86, 0x51b7, 0x52de, 0x64c4, 0x6ad3, 0x7210, 0x76e7, 0x8001, 0x8606, 0x865c,
+    0x8def, 0x9732, 0x9b6f, 0x9dfa, 0x788c, 0x797f, 0x7da0, 0x83c9, 0x9304, 0x9e7f,
+    0x8ad6, 0x58df, 0x5f04, 0x7c60, 0x807e, 0x7262, 0x78ca, 0x8cc2, 0x96f7, 0x58d8,
+    0x5c62, 0x6a13, 0x6dda, 0x6f0f, 0x7d2f, 0x7e37, 0x964b, 0x52d2, 0x808b, 0x51dc,
+    0x51cc, 0x7a1c, 0x7dbe, 0x83f1, 0x9675, 0x8b80, 0x62cf, 0x6a02, 0x8afe, 0x4e39,
+    0x5be7, 0x6012, 0x7387, 0x7570, 0x5317, 0x78fb, 0x4fbf, 0x5fa9, 0x4e0d, 0x6ccc,
+    0x6578, 0x7d22, 0x53c3, 0x585e, 0x7701, 0x8449, 0x8aaa, 0x6bba, 0x8fb0, 0x6c88,
+    0x62fe, 0x82e5, 0x63a0, 0x7565, 0x4eae, 0x5169, 0x51c9, 0x6881, 0x7ce7, 0x826f,
+    0x8ad2, 0x91cf, 0x52f5, 0x5442, 0x5973, 0x5eec, 0x65c5, 0x6ffe, 0x792a, 0x95ad,
+    0x9a6a, 0x9e97, 0x9ece, 0x529b, 0x66c6, 0x6b77, 0x8f62, 0x5e74, 0x6190, 0x6200,
+    0x649a, 0x6f23, 0x7149, 0x7489, 0x79ca, 0x7df4, 0x806f, 0x8f26, 0x84ee, 0x9023,
+    0x934a, 0x5217, 0x52a3, 0x54bd, 0x70c8, 0x88c2, 0x8aaa, 0x5ec9, 0x5ff5, 0x637b,
+    0x6bae, 0x7c3e, 0x7375, 0x4ee4, 0x56f9, 0x5be7, 0x5dba, 0x601c, 0x73b2, 0x7469,
+    0x7f9a, 0x8046, 0x9234, 0x96f6, 0x9748, 0x9818, 0x4f8b, 0x79ae, 0x91b4, 0x96b8,
+    0x60e1, 0x4e86, 0x50da, 0x5bee, 0x5c3f, 0x6599, 0x6a02, 0x71ce, 0x7642, 0x84fc,
+    0x907c, 0x9f8d, 0x6688, 0x962e, 0x5289, 0x677b, 0x67f3, 0x6d41, 0x6e9c, 0x7409,
+    0x7559, 0x786b, 0x7d10, 0x985e, 0x516d, 0x622e, 0x9678, 0x502b, 0x5d19, 0x6dea,
+    0x8f2a, 0x5f8b, 0x6144, 0x6817, 0x7387, 0x9686, 0x5229, 0x540f, 0x5c65, 0x6613,
+    0x674e, 0x68a8, 0x6ce5, 0x7406, 0x75e2, 0x7f79, 0x88cf, 0x88e1, 0x91cc, 0x96e2,
+    0x533f, 0x6eba, 0x541d, 0x71d0, 0x7498, 0x85fa, 0x96a3, 0x9c57, 0x9e9f, 0x6797,
+    0x6dcb, 0x81e8, 0x7acb, 0x7b20, 0x7c92, 0x72c0, 0x7099, 0x8b58, 0x4ec0, 0x8336,
+    0x523a, 0x5207, 0x5ea6, 0x62d3, 0x7cd6, 0x5b85, 0x6d1e, 0x66b4, 0x8f3b, 0x884c,
+    0x964d, 0x898b, 0x5ed3, 0x5140, 0x55c0, 0x585a, 0x6674, 0x51de, 0x732a, 0x76ca,
+    0x793c, 0x795e, 0x7965, 0x798f, 0x9756, 0x7cbe, 0x7fbd, 0x8612, 0x8af8, 0x9038,
+    0x90fd, 0x98ef, 0x98fc, 0x9928, 0x9db4, 0x90de, 0x96b7, 0x4fae, 0x50e7, 0x514d,
+    0x52c9, 0x52e4, 0x5351, 0x559d, 0x5606, 0x5668, 0x5840, 0x58a8, 0x5c64, 0x5c6e,
+    0x6094, 0x6168, 0x618e, 0x61f2, 0x654f, 0x65e2, 0x6691, 0x6885, 0x6d77, 0x6e1a,
+    0x6f22, 0x716e, 0x722b, 0x7422, 0x7891, 0x793e, 0x7949, 0x7948, 0x7950, 0x7956,
+    0x795d, 0x798d, 0x798e, 0x7a40, 0x7a81, 0x7bc0, 0x7df4, 0x7e09, 0x7e41, 0x7f72,
+    0x8005, 0x81ed, 0x8279, 0x8279, 0x8457, 0x8910, 0x8996, 0x8b01, 0x8b39, 0x8cd3,
+    0x8d08, 0x8fb6, 0x9038, 0x96e3, 0x97ff, 0x983b, 0x6075, 0x242ee, 0x8218, 0x4e26,
+    0x51b5, 0x5168, 0x4f80, 0x5145, 0x5180, 0x52c7, 0x52fa, 0x559d, 0x5555, 0x5599,
+    0x55e2, 0x585a, 0x58b3, 0x5944, 0x5954, 0x5a62, 0x5b28, 0x5ed2, 0x5ed9, 0x5f69,
+    0x5fad, 0x60d8, 0x614e, 0x6108, 0x618e, 0x6160, 0x61f2, 0x6234, 0x63c4, 0x641c,
+    0x6452, 0x6556, 0x6674, 0x6717, 0x671b, 0x6756, 0x6b79, 0x6bba, 0x6d41, 0x6edb,
+    0x6ecb, 0x6f22, 0x701e, 0x716e, 0x77a7, 0x7235, 0x72af, 0x732a, 0x7471, 0x7506,
+    0x753b, 0x761d, 0x761f, 0x76ca, 0x76db, 0x76f4, 0x774a, 0x7740, 0x78cc, 0x7ab1,
+    0x7bc0, 0x7c7b, 0x7d5b, 0x7df4, 0x7f3e, 0x8005, 0x8352, 0x83ef, 0x8779, 0x8941,
+    0x8986, 0x8996, 0x8abf, 0x8af8, 0x8acb, 0x8b01, 0x8afe, 0x8aed, 0x8b39, 0x8b8a,
+    0x8d08, 0x8f38, 0x9072, 0x9199, 0x9276, 0x967c, 0x96e3, 0x9756, 0x97db, 0x97ff,
+    0x980b, 0x983b, 0x9b12, 0x9f9c, 0x2284a, 0x22844, 0x233d5, 0x3b9d, 0x4018, 0x4039,
+    0x25249, 0x25cd0, 0x27ed3, 0x9f43, 0x9f8e, 0x66, 0x66, 0x66, 0x69, 0x66,
+    0x6c, 0x66, 0x66, 0x69, 0x66, 0x66, 0x6c, 0x73, 0x74, 0x73,
+    0x74, 0x574, 0x576, 0x574, 0x565, 0x574, 0x56b, 0x57e, 0x576, 0x574,
+    0x56d, 0x5d9, 0x5b4, 0x5f2, 0x5b7, 0x5e2, 0x5d0, 0x5d3, 0x5d4, 0x5db,
+    0x5dc, 0x5dd, 0x5e8, 0x5ea, 0x2b, 0x5e9, 0x5c1, 0x5e9, 0x5c2, 0x5e9,
+    0x5bc, 0x5c1, 0x5e9, 0x5bc, 0x5c2, 0x5d0, 0x5b7, 0x5d0, 0x5b8, 0x5d0,
+    0x5bc, 0x5d1, 0x5bc, 0x5d2, 0x5bc, 0x5d3, 0x5bc, 0x5d4, 0x5bc, 0x5d5,
+    0x5bc, 0x5d6, 0x5bc, 0x5d8, 0x5bc, 0x5d9, 0x5bc, 0x5da, 0x5bc, 0x5db,
+    0x5bc, 0x5dc, 0x5bc, 0x5de, 0x5bc, 0x5e0, 0x5bc, 0x5e1, 0x5bc, 0x5e3,
+    0x5bc, 0x5e4, 0x5bc, 0x5e6, 0x5bc, 0x5e7, 0x5bc, 0x5e8, 0x5bc, 0x5e9,
+    0x5bc, 0x5ea, 0x5bc, 0x5d5, 0x5b9, 0x5d1, 0x5bf, 0x5db, 0x5bf, 0x5e4,
+    0x5bf, 0x5d0, 0x5dc, 0x671, 0x671, 0x67b, 0x67b, 0x67b, 0x67b, 0x67e,
+    0x67e, 0x67e, 0x67e, 0x680, 0x680, 0x680, 0x680, 0x67a, 0x67a, 0x67a,
+    0x67a, 0x67f, 0x67f, 0x67f, 0x67f, 0x679, 0x679, 0x679, 0x679, 0x6a4,
+    0x6a4, 0x6a4, 0x6a4, 0x6a6, 0x6a6, 0x6a6, 0x6a6, 0x684, 0x684, 0x684,
+    0x684, 0x683, 0x683, 0x683, 0x683, 0x686, 0x686, 0x686, 0x686, 0x687,
+    0x687, 0x687, 0x687, 0x68d, 0x68d, 0x68c, 0x68c, 0x68e, 0x68e, 0x688,
+    0x688, 0x698, 0x698, 0x691, 0x691, 0x6a9, 0x6a9, 0x6a9, 0x6a9, 0x6af,
+    0x6af, 0x6af, 0x6af, 0x6b3, 0x6b3, 0x6b3, 0x6b3, 0x6b1, 0x6b1, 0x6b1,
+    0x6b1, 0x6ba, 0x6ba, 0x6bb, 0x6bb, 0x6bb, 0x6bb, 0x6d5, 0x654, 0x6d5,
+    0x654, 0x6c1, 0x6c1, 0x6c1, 0x6c1, 0x6be, 0x6be, 0x6be, 0x6be, 0x6d2,
+    0x6d2, 0x6d2, 0x654, 0x6d2, 0x654, 0x6ad, 0x6ad, 0x6ad, 0x6ad, 0x6c7,
+    0x6c7, 0x6c6, 0x6c6, 0x6c8, 0x6c8, 0x6c7, 0x674, 0x6cb, 0x6cb, 0x6c5,
+    0x6c5, 0x6c9, 0x6c9, 0x6d0, 0x6d0, 0x6d0, 0x6d0, 0x649, 0x649, 0x64a,
+    0x654, 0x627, 0x64a, 0x654, 0x627, 0x64a, 0x654, 0x6d5, 0x64a, 0x654,
+    0x6d5, 0x64a, 0x654, 0x648, 0x64a, 0x654, 0x648, 0x64a, 0x654, 0x6c7,
+    0x64a, 0x654, 0x6c7, 0x64a, 0x654, 0x6c6, 0x64a, 0x654, 0x6c6, 0x64a,
+    0x654, 0x6c8, 0x64a, 0x654, 0x6c8, 0x64a, 0x654, 0x6d0, 0x64a, 0x654,
+    0x6d0, 0x64a, 0x654, 0x6d0, 0x64a, 0x654, 0x649, 0x64a, 0x654, 0x649,
+    0x64a, 0x654, 0x649, 0x6cc, 0x6cc, 0x6cc, 0x6cc, 0x64a, 0x654, 0x62c,
+    0x64a, 0x654, 0x62d, 0x64a, 0x654, 0x645, 0x64a, 0x654, 0x649, 0x64a,
+    0x654, 0x64a, 0x628, 0x62c, 0x628, 0x62d, 0x628, 0x62e, 0x628, 0x645,
+    0x628, 0x649, 0x628, 0x64a, 0x62a, 0x62c, 0x62a, 0x62d, 0x62a, 0x62e,
+    0x62a, 0x645, 0x62a, 0x649, 0x62a, 0x64a, 0x62b, 0x62c, 0x62b, 0x645,
+    0x62b, 0x649, 0x62b, 0x64a, 0x62c, 0x62d, 0x62c, 0x645, 0x62d, 0x62c,
+    0x62d, 0x645, 0x62e, 0x62c, 0x62e, 0x62d, 0x62e, 0x645, 0x633, 0x62c,
+    0x633, 0x62d, 0x633, 0x62e, 0x633, 0x645, 0x635, 0x62d, 0x635, 0x645,
+    0x636, 0x62c, 0x636, 0x62d, 0x636, 0x62e, 0x636, 0x645, 0x637, 0x62d,
+    0x637, 0x645, 0x638, 0x645, 0x639, 0x62c, 0x639, 0x645, 0x63a, 0x62c,
+    0x63a, 0x645, 0x641, 0x62c, 0x641, 0x62d, 0x641, 0x62e, 0x641, 0x645,
+    0x641, 0x649, 0x641, 0x64a, 0x642, 0x62d, 0x642, 0x645, 0x642, 0x649,
+    0x642, 0x64a, 0x643, 0x627, 0x643, 0x62c, 0x643, 0x62d, 0x643, 0x62e,
+    0x643, 0x644, 0x643, 0x645, 0x643, 0x649, 0x643, 0x64a, 0x644, 0x62c,
+    0x644, 0x62d, 0x644, 0x62e, 0x644, 0x645, 0x644, 0x649, 0x644, 0x64a,
+    0x645, 0x62c, 0x645, 0x62d, 0x645, 0x62e, 0x645, 0x645, 0x645, 0x649,
+    0x645, 0x64a, 0x646, 0x62c, 0x646, 0x62d, 0x646, 0x62e, 0x646, 0x645,
+    0x646, 0x649, 0x646, 0x64a, 0x647, 0x62c, 0x647, 0x645, 0x647, 0x649,
+    0x647, 0x64a, 0x64a, 0x62c, 0x64a, 0x62d, 0x64a, 0x62e, 0x64a, 0x645,
+    0x64a, 0x649, 0x64a, 0x64a, 0x630, 0x670, 0x631, 0x670, 0x649, 0x670,
+    0x20, 0x64c, 0x651, 0x20, 0x64d, 0x651, 0x20, 0x64e, 0x651, 0x20,
+    0x64f, 0x651, 0x20, 0x650, 0x651, 0x20, 0x651, 0x670, 0x64a, 0x654,
+    0x631, 0x64a, 0x654, 0x632, 0x64a, 0x654, 0x645, 0x64a, 0x654, 0x646,
+    0x64a, 0x654, 0x649, 0x64a, 0x654, 0x64a, 0x628, 0x631, 0x628, 0x632,
+    0x628, 0x645, 0x628, 0x646, 0x628, 0x649, 0x628, 0x64a, 0x62a, 0x631,
+    0x62a, 0x632, 0x62a, 0x645, 0x62a, 0x646, 0x62a, 0x649, 0x62a, 0x64a,
+    0x62b, 0x631, 0x62b, 0x632, 0x62b, 0x645, 0x62b, 0x646, 0x62b, 0x649,
+    0x62b, 0x64a, 0x641, 0x649, 0x641, 0x64a, 0x642, 0x649, 0x642, 0x64a,
+    0x643, 0x627, 0x643, 0x644, 0x643, 0x645, 0x643, 0x649, 0x643, 0x64a,
+    0x644, 0x645, 0x644, 0x649, 0x644, 0x64a, 0x645, 0x627, 0x645, 0x645,
+    0x646, 0x631, 0x646, 0x632, 0x646, 0x645, 0x646, 0x646, 0x646, 0x649,
+    0x646, 0x64a, 0x649, 0x670, 0x64a, 0x631, 0x64a, 0x632, 0x64a, 0x645,
+    0x64a, 0x646, 0x64a, 0x649, 0x64a, 0x64a, 0x64a, 0x654, 0x62c, 0x64a,
+    0x654, 0x62d, 0x64a, 0x654, 0x62e, 0x64a, 0x654, 0x645, 0x64a, 0x654,
+    0x647, 0x628, 0x62c, 0x628, 0x62d, 0x628, 0x62e, 0x628, 0x645, 0x628,
+    0x647, 0x62a, 0x62c, 0x62a, 0x62d, 0x62a, 0x62e, 0x62a, 0x645, 0x62a,
+    0x647, 0x62b, 0x645, 0x62c, 0x62d, 0x62c, 0x645, 0x62d, 0x62c, 0x62d,
+    0x645, 0x62e, 0x62c, 0x62e, 0x645, 0x633, 0x62c, 0x633, 0x62d, 0x633,
+    0x62e, 0x633, 0x645, 0x635, 0x62d, 0x635, 0x62e, 0x635, 0x645, 0x636,
+    0x62c, 0x636, 0x62d, 0x636, 0x62e, 0x636, 0x645, 0x637, 0x62d, 0x638,
+    0x645, 0x639, 0x62c, 0x639, 0x645, 0x63a, 0x62c, 0x63a, 0x645, 0x641,
+    0x62c, 0x641, 0x62d, 0x641, 0x62e, 0x641, 0x645, 0x642, 0x62d, 0x642,
+    0x645, 0x643, 0x62c, 0x643, 0x62d, 0x643, 0x62e, 0x643, 0x644, 0x643,
+    0x645, 0x644, 0x62c, 0x644, 0x62d, 0x644, 0x62e, 0x644, 0x645, 0x644,
+    0x647, 0x645, 0x62c, 0x645, 0x62d, 0x645, 0x62e, 0x645, 0x645, 0x646,
+    0x62c, 0x646, 0x62d, 0x646, 0x62e, 0x646, 0x645, 0x646, 0x647, 0x647,
+    0x62c, 0x647, 0x645, 0x647, 0x670, 0x64a, 0x62c, 0x64a, 0x62d, 0x64a,
+    0x62e, 0x64a, 0x645, 0x64a, 0x647, 0x64a, 0x654, 0x645, 0x64a, 0x654,
+    0x647, 0x628, 0x645, 0x628, 0x647, 0x62a, 0x645, 0x62a, 0x647, 0x62b,
+    0x645, 0x62b, 0x647, 0x633, 0x645, 0x633, 0x647, 0x634, 0x645, 0x634,
+    0x647, 0x643, 0x644, 0x643, 0x645, 0x644, 0x645, 0x646, 0x645, 0x646,
+    0x647, 0x64a, 0x645, 0x64a, 0x647, 0x640, 0x64e, 0x651, 0x640, 0x64f,
+    0x651, 0x640, 0x650, 0x651, 0x637, 0x649, 0x637, 0x64a, 0x639, 0x649,
+    0x639, 0x64a, 0x63a, 0x649, 0x63a, 0x64a, 0x633, 0x649, 0x633, 0x64a,
+    0x634, 0x649, 0x634, 0x64a, 0x62d, 0x649, 0x62d, 0x64a, 0x62c, 0x649,
+    0x62c, 0x64a, 0x62e, 0x649, 0x62e, 0x64a, 0x635, 0x649, 0x635, 0x64a,
+    0x636, 0x649, 0x636, 0x64a, 0x634, 0x62c, 0x634, 0x62d, 0x634, 0x62e,
+    0x634, 0x645, 0x634, 0x631, 0x633, 0x631, 0x635, 0x631, 0x636, 0x631,
+    0x637, 0x649, 0x637, 0x64a, 0x639, 0x649, 0x639, 0x64a, 0x63a, 0x649,
+    0x63a, 0x64a, 0x633, 0x649, 0x633, 0x64a, 0x634, 0x649, 0x634, 0x64a,
+    0x62d, 0x649, 0x62d, 0x64a, 0x62c, 0x649, 0x62c, 0x64a, 0x62e, 0x649,
+    0x62e, 0x64a, 0x635, 0x649, 0x635, 0x64a, 0x636, 0x649, 0x636, 0x64a,
+    0x634, 0x62c, 0x634, 0x62d, 0x634, 0x62e, 0x634, 0x645, 0x634, 0x631,
+    0x633, 0x631, 0x635, 0x631, 0x636, 0x631, 0x634, 0x62c, 0x634, 0x62d,
+    0x634, 0x62e, 0x634, 0x645, 0x633, 0x647, 0x634, 0x647, 0x637, 0x645,
+    0x633, 0x62c, 0x633, 0x62d, 0x633, 0x62e, 0x634, 0x62c, 0x634, 0x62d,
+    0x634, 0x62e, 0x637, 0x645, 0x638, 0x645, 0x627, 0x64b, 0x627, 0x64b,
+    0x62a, 0x62c, 0x645, 0x62a, 0x62d, 0x62c, 0x62a, 0x62d, 0x62c, 0x62a,
+    0x62d, 0x645, 0x62a, 0x62e, 0x645, 0x62a, 0x645, 0x62c, 0x62a, 0x645,
+    0x62d, 0x62a, 0x645, 0x62e, 0x62c, 0x645, 0x62d, 0x62c, 0x645, 0x62d,
+    0x62d, 0x645, 0x64a, 0x62d, 0x645, 0x649, 0x633, 0x62d, 0x62c, 0x633,
+    0x62c, 0x62d, 0x633, 0x62c, 0x649, 0x633, 0x645, 0x62d, 0x633, 0x645,
+    0x62d, 0x633, 0x645, 0x62c, 0x633, 0x645, 0x645, 0x633, 0x645, 0x645,
+    0x635, 0x62d, 0x62d, 0x635, 0x62d, 0x62d, 0x635, 0x645, 0x645, 0x634,
+    0x62d, 0x645, 0x634, 0x62d, 0x645, 0x634, 0x62c, 0x64a, 0x634, 0x645,
+    0x62e, 0x634, 0x645, 0x62e, 0x634, 0x645, 0x645, 0x634, 0x645, 0x645,
+    0x636, 0x62d, 0x649, 0x636, 0x62e, 0x645, 0x636, 0x62e, 0x645, 0x637,
+    0x645, 0x62d, 0x637, 0x645, 0x62d, 0x637, 0x645, 0x645, 0x637, 0x645,
+    0x64a, 0x639, 0x62c, 0x645, 0x639, 0x645, 0x645, 0x639, 0x645, 0x645,
+    0x639, 0x645, 0x649, 0x63a, 0x645, 0x645, 0x63a, 0x645, 0x64a, 0x63a,
+    0x645, 0x649, 0x641, 0x62e, 0x645, 0x641, 0x62e, 0x645, 0x642, 0x645,
+    0x62d, 0x642, 0x645, 0x645, 0x644, 0x62d, 0x645, 0x644, 0x62d, 0x64a,
+    0x644, 0x62d, 0x649, 0x644, 0x62c, 0x62c, 0x644, 0x62c, 0x62c, 0x644,
+    0x62e, 0x645, 0x644, 0x62e, 0x645, 0x644, 0x645, 0x62d, 0x644, 0x645,
+    0x62d, 0x645, 0x62d, 0x62c, 0x645, 0x62d, 0x645, 0x645, 0x62d, 0x64a,
+    0x645, 0x62c, 0x62d, 0x645, 0x62c, 0x645, 0x645, 0x62e, 0x62c, 0x645,
+    0x62e, 0x645, 0x645, 0x62c, 0x62e, 0x647, 0x645, 0x62c, 0x647, 0x645,
+    0x645, 0x646, 0x62d, 0x645, 0x646, 0x62d, 0x649, 0x646, 0x62c, 0x645,
+    0x646, 0x62c, 0x645, 0x646, 0x62c, 0x649, 0x646, 0x645, 0x64a, 0x646,
+    0x645, 0x649, 0x64a, 0x645, 0x645, 0x64a, 0x645, 0x645, 0x628, 0x62e,
+    0x64a, 0x62a, 0x62c, 0x64a, 0x62a, 0x62c, 0x649, 0x62a, 0x62e, 0x64a,
+    0x62a, 0x62e, 0x649, 0x62a, 0x645, 0x64a, 0x62a, 0x645, 0x649, 0x62c,
+    0x645, 0x64a, 0x62c, 0x62d, 0x649, 0x62c, 0x645, 0x649, 0x633, 0x62e,
+    0x649, 0x635, 0x62d, 0x64a, 0x634, 0x62d, 0x64a, 0x636, 0x62d, 0x64a,
+    0x644, 0x62c, 0x64a, 0x644, 0x645, 0x64a, 0x64a, 0x62d, 0x64a, 0x64a,
+    0x62c, 0x64a, 0x64a, 0x645, 0x64a, 0x645, 0x645, 0x64a, 0x642, 0x645,
+    0x64a, 0x646, 0x62d, 0x64a, 0x642, 0x645, 0x62d, 0x644, 0x62d, 0x645,
+    0x639, 0x645, 0x64a, 0x643, 0x645, 0x64a, 0x646, 0x62c, 0x62d, 0x645,
+    0x62e, 0x64a, 0x644, 0x62c, 0x645, 0x643, 0x645, 0x645, 0x644, 0x62c,
+    0x645, 0x646, 0x62c, 0x62d, 0x62c, 0x62d, 0x64a, 0x62d, 0x62c, 0x64a,
+    0x645, 0x62c, 0x64a, 0x641, 0x645, 0x64a, 0x628, 0x62d, 0x64a, 0x643,
+    0x645, 0x645, 0x639, 0x62c, 0x645, 0x635, 0x645, 0x645, 0x633, 0x62e,
+    0x64a, 0x646, 0x62c, 0x64a, 0x635, 0x644, 0x6d2, 0x642, 0x644, 0x6d2,
+    0x627, 0x644, 0x644, 0x647, 0x627, 0x643, 0x628, 0x631, 0x645, 0x62d,
+    0x645, 0x62f, 0x635, 0x644, 0x639, 0x645, 0x631, 0x633, 0x648, 0x644,
+    0x639, 0x644, 0x64a, 0x647, 0x648, 0x633, 0x644, 0x645, 0x635, 0x644,
+    0x649, 0x635, 0x644, 0x649, 0x20, 0x627, 0x644, 0x644, 0x647, 0x20,
+    0x639, 0x644, 0x64a, 0x647, 0x20, 0x648, 0x633, 0x644, 0x645, 0x62c,
+    0x644, 0x20, 0x62c, 0x644, 0x627, 0x644, 0x647, 0x631, 0x6cc, 0x627,
+    0x644, 0x2c, 0x3001, 0x3002, 0x3a, 0x3b, 0x21, 0x3f, 0x3016, 0x3017,
+    0x2e, 0x2e, 0x2e, 0x2e, 0x2e, 0x2014, 0x2013, 0x5f, 0x5f, 0x28,
+    0x29, 0x7b, 0x7d, 0x3014, 0x3015, 0x3010, 0x3011, 0x300a, 0x300b, 0x3008,
+    0x3009, 0x300c, 0x300d, 0x300e, 0x300f, 0x5b, 0x5d, 0x20, 0x305, 0x20,
+    0x305, 0x20, 0x305, 0x20, 0x305, 0x5f, 0x5f, 0x5f, 0x2c, 0x3001,
+    0x2e, 0x3b, 0x3a, 0x3f, 0x21, 0x2014, 0x28, 0x29, 0x7b, 0x7d,
+    0x3014, 0x3015, 0x23, 0x26, 0x2a, 0x2b, 0x2d, 0x3c, 0x3e, 0x3d,
+    0x5c, 0x24, 0x25, 0x40, 0x20, 0x64b, 0x640, 0x64b, 0x20, 0x64c,
+    0x20, 0x64d, 0x20, 0x64e, 0x640, 0x64e, 0x20, 0x64f, 0x640, 0x64f,
+    0x20, 0x650, 0x640, 0x650, 0x20, 0x651, 0x640, 0x651, 0x20, 0x652,
+    0x640, 0x652, 0x621, 0x627, 0x653, 0x627, 0x653, 0x627, 0x654, 0x627,
+    0x654, 0x648, 0x654, 0x648, 0x654, 0x627, 0x655, 0x627, 0x655, 0x64a,
+    0x654, 0x64a, 0x654, 0x64a, 0x654, 0x64a, 0x654, 0x627, 0x627, 0x628,
+    0x628, 0x628, 0x628, 0x629, 0x629, 0x62a, 0x62a, 0x62a, 0x62a, 0x62b,
+    0x62b, 0x62b, 0x62b, 0x62c, 0x62c, 0x62c, 0x62c, 0x62d, 0x62d, 0x62d,
+    0x62d, 0x62e, 0x62e, 0x62e, 0x62e, 0x62f, 0x62f, 0x630, 0x630, 0x631,
+    0x631, 0x632, 0x632, 0x633, 0x633, 0x633, 0x633, 0x634, 0x634, 0x634,
+    0x634, 0x635, 0x635, 0x635, 0x635, 0x636, 0x636, 0x636, 0x636, 0x637,
+    0x637, 0x637, 0x637, 0x638, 0x638, 0x638, 0x638, 0x639, 0x639, 0x639,
+    0x639, 0x63a, 0x63a, 0x63a, 0x63a, 0x641, 0x641, 0x641, 0x641, 0x642,
+    0x642, 0x642, 0x642, 0x643, 0x643, 0x643, 0x643, 0x644, 0x644, 0x644,
+    0x644, 0x645, 0x645, 0x645, 0x645, 0x646, 0x646, 0x646, 0x646, 0x647,
+    0x647, 0x647, 0x647, 0x648, 0x648, 0x649, 0x649, 0x64a, 0x64a, 0x64a,
+    0x64a, 0x644, 0x627, 0x653, 0x644, 0x627, 0x653, 0x644, 0x627, 0x654,
+    0x644, 0x627, 0x654, 0x644, 0x627, 0x655, 0x644, 0x627, 0x655, 0x644,
+    0x627, 0x644, 0x627, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27,
+    0x28, 0x29, 0x2a, 0x2b, 0x2c, 0x2d, 0x2e, 0x2f, 0x30, 0x31,
+    0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x3a, 0x3b,
+    0x3c, 0x3d, 0x3e, 0x3f, 0x40, 0x41, 0x42, 0x43, 0x44, 0x45,
+    0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f,
+    0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59,
+    0x5a, 0x5b, 0x5c, 0x5d, 0x5e, 0x5f, 0x60, 0x61, 0x62, 0x63,
+    0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6a, 0x6b, 0x6c, 0x6d,
+    0x6e, 0x6f, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77,
+    0x78, 0x79, 0x7a, 0x7b, 0x7c, 0x7d, 0x7e, 0x2985, 0x2986, 0x3002,
+    0x300c, 0x300d, 0x3001, 0x30fb, 0x30f2, 0x30a1, 0x30a3, 0x30a5, 0x30a7, 0x30a9,
+    0x30e3, 0x30e5, 0x30e7, 0x30c3, 0x30fc, 0x30a2, 0x30a4, 0x30a6, 0x30a8, 0x30aa,
+    0x30ab, 0x30ad, 0x30af, 0x30b1, 0x30b3, 0x30b5, 0x30b7, 0x30b9, 0x30bb, 0x30bd,
+    0x30bf, 0x30c1, 0x30c4, 0x30c6, 0x30c8, 0x30ca, 0x30cb, 0x30cc, 0x30cd, 0x30ce,
+    0x30cf, 0x30d2, 0x30d5, 0x30d8, 0x30db, 0x30de, 0x30df, 0x30e0, 0x30e1, 0x30e2,
+    0x30e4, 0x30e6, 0x30e8, 0x30e9, 0x30ea, 0x30eb, 0x30ec, 0x30ed, 0x30ef, 0x30f3,
+    0x3099, 0x309a, 0x1160, 0x1100, 0x1101, 0x11aa, 0x1102, 0x11ac, 0x11ad, 0x1103,
+    0x1104, 0x1105, 0x11b0, 0x11b1, 0x11b2, 0x11b3, 0x11b4, 0x11b5, 0x111a, 0x1106,
+    0x1107, 0x1108, 0x1121, 0x1109, 0x110a, 0x110b, 0x110c, 0x110d, 0x110e, 0x110f,
+    0x1110, 0x1111, 0x1112, 0x1161, 0x1162, 0x1163, 0x1164, 0x1165, 0x1166, 0x1167,
+    0x1168, 0x1169, 0x116a, 0x116b, 0x116c, 0x116d, 0x116e, 0x116f, 0x1170, 0x1171,
+    0x1172, 0x1173, 0x1174, 0x1175, 0xa2, 0xa3, 0xac, 0x20, 0x304, 0xa6,
+    0xa5, 0x20a9, 0x2502, 0x2190, 0x2191, 0x2192, 0x2193, 0x25a0, 0x25cb, 0x2d0,
+    0x2d1, 0xe6, 0x299, 0x253, 0x2a3, 0xab66, 0x2a5, 0x2a4, 0x256, 0x257,
+    0x1d91, 0x258, 0x25e, 0x2a9, 0x264, 0x262, 0x260, 0x29b, 0x127, 0x29c,
+    0x267, 0x284, 0x2aa, 0x2ab, 0x26c, 0x1df04, 0xa78e, 0x26e, 0x1df05, 0x28e,
+    0x1df06, 0xf8, 0x276, 0x277, 0x71, 0x27a, 0x1df08, 0x27d, 0x27e, 0x280,
+    0x2a8, 0x2a6, 0xab67, 0x2a7, 0x288, 0x2c71, 0x28f, 0x2a1, 0x2a2, 0x298,
+    0x1c0, 0x1c1, 0x1c2, 0x1df0a, 0x1df1e, 0x11099, 0x110ba, 0x1109b, 0x110ba, 0x110a5,
+    0x110ba, 0x11131, 0x11127, 0x11132, 0x11127, 0x11347, 0x1133e, 0x11347, 0x11357, 0x114b9,
+    0x114ba, 0x114b9, 0x114b0, 0x114b9, 0x114bd, 0x115b8, 0x115af, 0x115b9, 0x115af, 0x11935,
+    0x11930, 0x1d157, 0x1d165, 0x1d158, 0x1d165, 0x1d158, 0x1d165, 0x1d16e, 0x1d158, 0x1d165,
+    0x1d16f, 0x1d158, 0x1d165, 0x1d170, 0x1d158, 0x1d165, 0x1d171, 0x1d158, 0x1d165, 0x1d172,
+    0x1d1b9, 0x1d165, 0x1d1ba, 0x1d165, 0x1d1b9, 0x1d165, 0x1d16e, 0x1d1ba, 0x1d165, 0x1d16e,
+    0x1d1b9, 0x1d165, 0x1d16f, 0x1d1ba, 0x1d165, 0x1d16f, 0x41, 0x42, 0x43, 0x44,
+    0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e,
+    0x4f, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58,
+    0x59, 0x5a, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68,
+    0x69, 0x6a, 0x6b, 0x6c, 0x6d, 0x6e, 0x6f, 0x70, 0x71, 0x72,
+    0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7a, 0x41, 0x42,
+    0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c,
+    0x4d, 0x4e, 0x4f, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56,
+    0x57, 0x58, 0x59, 0x5a, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66,
+    0x67, 0x69, 0x6a, 0x6b, 0x6c, 0x6d, 0x6e, 0x6f, 0x70, 0x71,
+    0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7a, 0x41,
+    0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b,
+    0x4c, 0x4d, 0x4e, 0x4f, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55,
+    0x56, 0x57, 0x58, 0x59, 0x5a, 0x61, 0x62, 0x63, 0x64, 0x65,
+    0x66, 0x67, 0x68, 0x69, 0x6a, 0x6b, 0x6c, 0x6d, 0x6e, 0x6f,
+    0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79,
+    0x7a, 0x41, 0x43, 0x44, 0x47, 0x4a, 0x4b, 0x4e, 0x4f, 0x50,
+    0x51, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0x61,
+    0x62, 0x63, 0x64, 0x66, 0x68, 0x69, 0x6a, 0x6b, 0x6c, 0x6d,
+    0x6e, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78,
+    0x79, 0x7a, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48,
+    0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f, 0x50, 0x51, 0x52,
+    0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0x61, 0x62,
+    0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6a, 0x6b, 0x6c,
+    0x6d, 0x6e, 0x6f, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76,
+    0x77, 0x78, 0x79, 0x7a, 0x41, 0x42, 0x44, 0x45, 0x46, 0x47,
+    0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f, 0x50, 0x51, 0x53, 0x54,
+    0x55, 0x56, 0x57, 0x58, 0x59, 0x61, 0x62, 0x63, 0x64, 0x65,
+    0x66, 0x67, 0x68, 0x69, 0x6a, 0x6b, 0x6c, 0x6d, 0x6e, 0x6f,
+    0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79,
+    0x7a, 0x41, 0x42, 0x44, 0x45, 0x46, 0x47, 0x49, 0x4a, 0x4b,
+    0x4c, 0x4d, 0x4f, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59,
+    0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6a,
+    0x6b, 0x6c, 0x6d, 0x6e, 0x6f, 0x70, 0x71, 0x72, 0x73, 0x74,
+    0x75, 0x76, 0x77, 0x78, 0x79, 0x7a, 0x41, 0x42, 0x43, 0x44,
+    0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e,
+    0x4f, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58,
+    0x59, 0x5a, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68,
+    0x69, 0x6a, 0x6b, 0x6c, 0x6d, 0x6e, 0x6f, 0x70, 0x71, 0x72,
+    0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7a, 0x41, 0x42,
+    0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c,
+    0x4d, 0x4e, 0x4f, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56,
+    0x57, 0x58, 0x59, 0x5a, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66,
+    0x67, 0x68, 0x69, 0x6a, 0x6b, 0x6c, 0x6d, 0x6e, 0x6f, 0x70,
+    0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7a,
+    0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4a,
+    0x4b, 0x4c, 0x4d, 0x4e, 0x4f, 0x50, 0x51, 0x52, 0x53, 0x54,
+    0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0x61, 0x62, 0x63, 0x64,
+    0x65, 0x66, 0x67, 0x68, 0x69, 0x6a, 0x6b, 0x6c, 0x6d, 0x6e,
+    0x6f, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78,
+    0x79, 0x7a, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48,
+    0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f, 0x50, 0x51, 0x52,
+    0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0x61, 0x62,
+    0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6a, 0x6b, 0x6c,
+    0x6d, 0x6e, 0x6f, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76,
+    0x77, 0x78, 0x79, 0x7a, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46,
+    0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f, 0x50,
+    0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5a,
+    0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6a,
+    0x6b, 0x6c, 0x6d, 0x6e, 0x6f, 0x70, 0x71, 0x72, 0x73, 0x74,
+    0x75, 0x76, 0x77, 0x78, 0x79, 0x7a, 0x41, 0x42, 0x43, 0x44,
+    0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e,
+    0x4f, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58,
+    0x59, 0x5a, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68,
+    0x69, 0x6a, 0x6b, 0x6c, 0x6d, 0x6e, 0x6f, 0x70, 0x71, 0x72,
+    0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7a, 0x131, 0x237,
+    0x391, 0x392, 0x393, 0x394, 0x395, 0x396, 0x397, 0x398, 0x399, 0x39a,
+    0x39b, 0x39c, 0x39d, 0x39e, 0x39f, 0x3a0, 0x3a1, 0x398, 0x3a3, 0x3a4,
+    0x3a5, 0x3a6, 0x3a7, 0x3a8, 0x3a9, 0x2207, 0x3b1, 0x3b2, 0x3b3, 0x3b4,
+    0x3b5, 0x3b6, 0x3b7, 0x3b8, 0x3b9, 0x3ba, 0x3bb, 0x3bc, 0x3bd, 0x3be,
+    0x3bf, 0x3c0, 0x3c1, 0x3c2, 0x3c3, 0x3c4, 0x3c5, 0x3c6, 0x3c7, 0x3c8,
+    0x3c9, 0x2202, 0x3b5, 0x3b8, 0x3ba, 0x3c6, 0x3c1, 0x3c0, 0x391, 0x392,
+    0x393, 0x394, 0x395, 0x396, 0x397, 0x398, 0x399, 0x39a, 0x39b, 0x39c,
+    0x39d, 0x39e, 0x39f, 0x3a0, 0x3a1, 0x398, 0x3a3, 0x3a4, 0x3a5, 0x3a6,
+    0x3a7, 0x3a8, 0x3a9, 0x2207, 0x3b1, 0x3b2, 0x3b3, 0x3b4, 0x3b5, 0x3b6,
+    0x3b7, 0x3b8, 0x3b9, 0x3ba, 0x3bb, 0x3bc, 0x3bd, 0x3be, 0x3bf, 0x3c0,
+    0x3c1, 0x3c2, 0x3c3, 0x3c4, 0x3c5, 0x3c6, 0x3c7, 0x3c8, 0x3c9, 0x2202,
+    0x3b5, 0x3b8, 0x3ba, 0x3c6, 0x3c1, 0x3c0, 0x391, 0x392, 0x393, 0x394,
+    0x395, 0x396, 0x397, 0x398, 0x399, 0x39a, 0x39b, 0x39c, 0x39d, 0x39e,
+    0x39f, 0x3a0, 0x3a1, 0x398, 0x3a3, 0x3a4, 0x3a5, 0x3a6, 0x3a7, 0x3a8,
+    0x3a9, 0x2207, 0x3b1, 0x3b2, 0x3b3, 0x3b4, 0x3b5, 0x3b6, 0x3b7, 0x3b8,
+    0x3b9, 0x3ba, 0x3bb, 0x3bc, 0x3bd, 0x3be, 0x3bf, 0x3c0, 0x3c1, 0x3c2,
+    0x3c3, 0x3c4, 0x3c5, 0x3c6, 0x3c7, 0x3c8, 0x3c9, 0x2202, 0x3b5, 0x3b8,
+    0x3ba, 0x3c6, 0x3c1, 0x3c0, 0x391, 0x392, 0x393, 0x394, 0x395, 0x396,
+    0x397, 0x398, 0x399, 0x39a, 0x39b, 0x39c, 0x39d, 0x39e, 0x39f, 0x3a0,
+    0x3a1, 0x398, 0x3a3, 0x3a4, 0x3a5, 0x3a6, 0x3a7, 0x3a8, 0x3a9, 0x2207,
+    0x3b1, 0x3b2, 0x3b3, 0x3b4, 0x3b5, 0x3b6, 0x3b7, 0x3b8, 0x3b9, 0x3ba,
+    0x3bb, 0x3bc, 0x3bd, 0x3be, 0x3bf, 0x3c0, 0x3c1, 0x3c2, 0x3c3, 0x3c4,
+    0x3c5, 0x3c6, 0x3c7, 0x3c8, 0x3c9, 0x2202, 0x3b5, 0x3b8, 0x3ba, 0x3c6,
+    0x3c1, 0x3c0, 0x391, 0x392, 0x393, 0x394, 0x395, 0x396, 0x397, 0x398,
+    0x399, 0x39a, 0x39b, 0x39c, 0x39d, 0x39e, 0x39f, 0x3a0, 0x3a1, 0x398,
+    0x3a3, 0x3a4, 0x3a5, 0x3a6, 0x3a7, 0x3a8, 0x3a9, 0x2207, 0x3b1, 0x3b2,
+    0x3b3, 0x3b4, 0x3b5, 0x3b6, 0x3b7, 0x3b8, 0x3b9, 0x3ba, 0x3bb, 0x3bc,
+    0x3bd, 0x3be, 0x3bf, 0x3c0, 0x3c1, 0x3c2, 0x3c3, 0x3c4, 0x3c5, 0x3c6,
+    0x3c7, 0x3c8, 0x3c9, 0x2202, 0x3b5, 0x3b8, 0x3ba, 0x3c6, 0x3c1, 0x3c0,
+    0x3dc, 0x3dd, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37,
+    0x38, 0x39, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37,
+    0x38, 0x39, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37,
+    0x38, 0x39, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37,
+    0x38, 0x39, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37,
+    0x38, 0x39, 0x627, 0x628, 0x62c, 0x62f, 0x648, 0x632, 0x62d, 0x637,
+    0x64a, 0x643, 0x644, 0x645, 0x646, 0x633, 0x639, 0x641, 0x635, 0x642,
+    0x631, 0x634, 0x62a, 0x62b, 0x62e, 0x630, 0x636, 0x638, 0x63a, 0x66e,
+    0x6ba, 0x6a1, 0x66f, 0x628, 0x62c, 0x647, 0x62d, 0x64a, 0x643, 0x644,
+    0x645, 0x646, 0x633, 0x639, 0x641, 0x635, 0x642, 0x634, 0x62a, 0x62b,
+    0x62e, 0x636, 0x63a, 0x62c, 0x62d, 0x64a, 0x644, 0x646, 0x633, 0x639,
+    0x635, 0x642, 0x634, 0x62e, 0x636, 0x63a, 0x6ba, 0x66f, 0x628, 0x62c,
+    0x647, 0x62d, 0x637, 0x64a, 0x643, 0x645, 0x646, 0x633, 0x639, 0x641,
+    0x635, 0x642, 0x634, 0x62a, 0x62b, 0x62e, 0x636, 0x638, 0x63a, 0x66e,
+    0x6a1, 0x627, 0x628, 0x62c, 0x62f, 0x647, 0x648, 0x632, 0x62d, 0x637,
+    0x64a, 0x644, 0x645, 0x646, 0x633, 0x639, 0x641, 0x635, 0x642, 0x631,
+    0x634, 0x62a, 0x62b, 0x62e, 0x630, 0x636, 0x638, 0x63a, 0x628, 0x62c,
+    0x62f, 0x648, 0x632, 0x62d, 0x637, 0x64a, 0x644, 0x645, 0x646, 0x633,
+    0x639, 0x641, 0x635, 0x642, 0x631, 0x634, 0x62a, 0x62b, 0x62e, 0x630,
+    0x636, 0x638, 0x63a, 0x30, 0x2e, 0x30, 0x2c, 0x31, 0x2c, 0x32,
+    0x2c, 0x33, 0x2c, 0x34, 0x2c, 0x35, 0x2c, 0x36, 0x2c, 0x37,
+    0x2c, 0x38, 0x2c, 0x39, 0x2c, 0x28, 0x41, 0x29, 0x28, 0x42,
+    0x29, 0x28, 0x43, 0x29, 0x28, 0x44, 0x29, 0x28, 0x45, 0x29,
+    0x28, 0x46, 0x29, 0x28, 0x47, 0x29, 0x28, 0x48, 0x29, 0x28,
+    0x49, 0x29, 0x28, 0x4a, 0x29, 0x28, 0x4b, 0x29, 0x28, 0x4c,
+    0x29, 0x28, 0x4d, 0x29, 0x28, 0x4e, 0x29, 0x28, 0x4f, 0x29,
+    0x28, 0x50, 0x29, 0x28, 0x51, 0x29, 0x28, 0x52, 0x29, 0x28,
+    0x53, 0x29, 0x28, 0x54, 0x29, 0x28, 0x55, 0x29, 0x28, 0x56,
+    0x29, 0x28, 0x57, 0x29, 0x28, 0x58, 0x29, 0x28, 0x59, 0x29,
+    0x28, 0x5a, 0x29, 0x3014, 0x53, 0x3015, 0x43, 0x52, 0x43, 0x44,
+    0x57, 0x5a, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48,
+    0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f, 0x50, 0x51, 0x52,
+    0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0x48, 0x56,
+    0x4d, 0x56, 0x53, 0x44, 0x53, 0x53, 0x50, 0x50, 0x56, 0x57,
+    0x43, 0x4d, 0x43, 0x4d, 0x44, 0x4d, 0x52, 0x44, 0x4a, 0x307b,
+    0x304b, 0x30b3, 0x30b3, 0x30b5, 0x624b, 0x5b57, 0x53cc, 0x30c6, 0x3099, 0x4e8c,
+    0x591a, 0x89e3, 0x5929, 0x4ea4, 0x6620, 0x7121, 0x6599, 0x524d, 0x5f8c, 0x518d,
+    0x65b0, 0x521d, 0x7d42, 0x751f, 0x8ca9, 0x58f0, 0x5439, 0x6f14, 0x6295, 0x6355,
+    0x4e00, 0x4e09, 0x904a, 0x5de6, 0x4e2d, 0x53f3, 0x6307, 0x8d70, 0x6253, 0x7981,
+    0x7a7a, 0x5408, 0x6e80, 0x6709, 0x6708, 0x7533, 0x5272, 0x55b6, 0x914d, 0x3014,
+    0x672c, 0x3015, 0x3014, 0x4e09, 0x3015, 0x3014, 0x4e8c, 0x3015, 0x3014, 0x5b89,
+    0x3015, 0x3014, 0x70b9, 0x3015, 0x3014, 0x6253, 0x3015, 0x3014, 0x76d7, 0x3015,
+    0x3014, 0x52dd, 0x3015, 0x3014, 0x6557, 0x3015, 0x5f97, 0x53ef, 0x30, 0x31,
+    0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x4e3d, 0x4e38,
+    0x4e41, 0x20122, 0x4f60, 0x4fae, 0x4fbb, 0x5002, 0x507a, 0x5099, 0x50e7, 0x50cf,
+    0x349e, 0x2063a, 0x514d, 0x5154, 0x5164, 0x5177, 0x2051c, 0x34b9, 0x5167, 0x518d,
+    0x2054b, 0x5197, 0x51a4, 0x4ecc, 0x51ac, 0x51b5, 0x291df, 0x51f5, 0x5203, 0x34df,
+    0x523b, 0x5246, 0x5272, 0x5277, 0x3515, 0x52c7, 0x52c9, 0x52e4, 0x52fa, 0x5305,
+    0x5306, 0x5317, 0x5349, 0x5351, 0x535a, 0x5373, 0x537d, 0x537f, 0x537f, 0x537f,
+    0x20a2c, 0x7070, 0x53ca, 0x53df, 0x20b63, 0x53eb, 0x53f1, 0x5406, 0x549e, 0x5438,
+    0x5448, 0x5468, 0x54a2, 0x54f6, 0x5510, 0x5553, 0x5563, 0x5584, 0x5584, 0x5599,
+    0x55ab, 0x55b3, 0x55c2, 0x5716, 0x5606, 0x5717, 0x5651, 0x5674, 0x5207, 0x58ee,
+    0x57ce, 0x57f4, 0x580d, 0x578b, 0x5832, 0x5831, 0x58ac, 0x214e4, 0x58f2, 0x58f7,
+    0x5906, 0x591a, 0x5922, 0x5962, 0x216a8, 0x216ea, 0x59ec, 0x5a1b, 0x5a27, 0x59d8,
+    0x5a66, 0x36ee, 0x36fc, 0x5b08, 0x5b3e, 0x5b3e, 0x219c8, 0x5bc3, 0x5bd8, 0x5be7,
+    0x5bf3, 0x21b18, 0x5bff, 0x5c06, 0x5f53, 0x5c22, 0x3781, 0x5c60, 0x5c6e, 0x5cc0,
+    0x5c8d, 0x21de4, 0x5d43, 0x21de6, 0x5d6e, 0x5d6b, 0x5d7c, 0x5de1, 0x5de2, 0x382f,
+    0x5dfd, 0x5e28, 0x5e3d, 0x5e69, 0x3862, 0x22183, 0x387c, 0x5eb0, 0x5eb3, 0x5eb6,
+    0x5eca, 0x2a392, 0x5efe, 0x22331, 0x22331, 0x8201, 0x5f22, 0x5f22, 0x38c7, 0x232b8,
+    0x261da, 0x5f62, 0x5f6b, 0x38e3, 0x5f9a, 0x5fcd, 0x5fd7, 0x5ff9, 0x6081, 0x393a,
+    0x391c, 0x6094, 0x226d4, 0x60c7, 0x6148, 0x614c, 0x614e, 0x614c, 0x617a, 0x618e,
+    0x61b2, 0x61a4, 0x61af, 0x61de, 0x61f2, 0x61f6, 0x6210, 0x621b, 0x625d, 0x62b1,
+    0x62d4, 0x6350, 0x22b0c, 0x633d, 0x62fc, 0x6368, 0x6383, 0x63e4, 0x22bf1, 0x6422,
+    0x63c5, 0x63a9, 0x3a2e, 0x6469, 0x647e, 0x649d, 0x6477, 0x3a6c, 0x654f, 0x656c,
+    0x2300a, 0x65e3, 0x66f8, 0x6649, 0x3b19, 0x6691, 0x3b08, 0x3ae4, 0x5192, 0x5195,
+    0x6700, 0x669c, 0x80ad, 0x43d9, 0x6717, 0x671b, 0x6721, 0x675e, 0x6753, 0x233c3,
+    0x3b49, 0x67fa, 0x6785, 0x6852, 0x6885, 0x2346d, 0x688e, 0x681f, 0x6914, 0x3b9d,
+    0x6942, 0x69a3, 0x69ea, 0x6aa8, 0x236a3, 0x6adb, 0x3c18, 0x6b21, 0x238a7, 0x6b54,
+    0x3c4e, 0x6b72, 0x6b9f, 0x6bba, 0x6bbb, 0x23a8d, 0x21d0b, 0x23afa, 0x6c4e, 0x23cbc,
+    0x6cbf, 0x6ccd, 0x6c67, 0x6d16, 0x6d3e, 0x6d77, 0x6d41, 0x6d69, 0x6d78, 0x6d85,
+    0x23d1e, 0x6d34, 0x6e2f, 0x6e6e, 0x3d33, 0x6ecb, 0x6ec7, 0x23ed1, 0x6df9, 0x6f6e,
+    0x23f5e, 0x23f8e, 0x6fc6, 0x7039, 0x701e, 0x701b, 0x3d96, 0x704a, 0x707d, 0x7077,
+    0x70ad, 0x20525, 0x7145, 0x24263, 0x719c, 0x243ab, 0x7228, 0x7235, 0x7250, 0x24608,
+    0x7280, 0x7295, 0x24735, 0x24814, 0x737a, 0x738b, 0x3eac, 0x73a5, 0x3eb8, 0x3eb8,
+    0x7447, 0x745c, 0x7471, 0x7485, 0x74ca, 0x3f1b, 0x7524, 0x24c36, 0x753e, 0x24c92,
+    0x7570, 0x2219f, 0x7610, 0x24fa1, 0x24fb8, 0x25044, 0x3ffc, 0x4008, 0x76f4, 0x250f3,
+    0x250f2, 0x25119, 0x25133, 0x771e, 0x771f, 0x771f, 0x774a, 0x4039, 0x778b, 0x4046,
+    0x4096, 0x2541d, 0x784e, 0x788c, 0x78cc, 0x40e3, 0x25626, 0x7956, 0x2569a, 0x256c5,
+    0x798f, 0x79eb, 0x412f, 0x7a40, 0x7a4a, 0x7a4f, 0x2597c, 0x25aa7, 0x25aa7, 0x7aee,
+    0x4202, 0x25bab, 0x7bc6, 0x7bc9, 0x4227, 0x25c80, 0x7cd2, 0x42a0, 0x7ce8, 0x7ce3,
+    0x7d00, 0x25f86, 0x7d63, 0x4301, 0x7dc7, 0x7e02, 0x7e45, 0x4334, 0x26228, 0x26247,
+    0x4359, 0x262d9, 0x7f7a, 0x2633e, 0x7f95, 0x7ffa, 0x8005, 0x264da, 0x26523, 0x8060,
+    0x265a8, 0x8070, 0x2335f, 0x43d5, 0x80b2, 0x8103, 0x440b, 0x813e, 0x5ab5, 0x267a7,
+    0x267b5, 0x23393, 0x2339c, 0x8201, 0x8204, 0x8f9e, 0x446b, 0x8291, 0x828b, 0x829d,
+    0x52b3, 0x82b1, 0x82b3, 0x82bd, 0x82e6, 0x26b3c, 0x82e5, 0x831d, 0x8363, 0x83ad,
+    0x8323, 0x83bd, 0x83e7, 0x8457, 0x8353, 0x83ca, 0x83cc, 0x83dc, 0x26c36, 0x26d6b,
+    0x26cd5, 0x452b, 0x84f1, 0x84f3, 0x8516, 0x273ca, 0x8564, 0x26f2c, 0x455d, 0x4561,
+    0x26fb1, 0x270d2, 0x456b, 0x8650, 0x865c, 0x8667, 0x8669, 0x86a9, 0x8688, 0x870e,
+    0x86e2, 0x8779, 0x8728, 0x876b, 0x8786, 0x45d7, 0x87e1, 0x8801, 0x45f9, 0x8860,
+    0x8863, 0x27667, 0x88d7, 0x88de, 0x4635, 0x88fa, 0x34bb, 0x278ae, 0x27966, 0x46be,
+    0x46c7, 0x8aa0, 0x8aed, 0x8b8a, 0x8c55, 0x27ca8, 0x8cab, 0x8cc1, 0x8d1b, 0x8d77,
+    0x27f2f, 0x20804, 0x8dcb, 0x8dbc, 0x8df0, 0x208de, 0x8ed4, 0x8f38, 0x285d2, 0x285ed,
+    0x9094, 0x90f1, 0x9111, 0x2872e, 0x911b, 0x9238, 0x92d7, 0x92d8, 0x927c, 0x93f9,
+    0x9415, 0x28bfa, 0x958b, 0x4995, 0x95b7, 0x28d77, 0x49e6, 0x96c3, 0x5db2, 0x9723,
+    0x29145, 0x2921a, 0x4a6e, 0x4a76, 0x97e0, 0x2940a, 0x4ab2, 0x29496, 0x980b, 0x980b,
+    0x9829, 0x295b6, 0x98e2, 0x4b33, 0x9929, 0x99a7, 0x99c2, 0x99fe, 0x4bce, 0x29b30,
+    0x9b12, 0x9c40, 0x9cfd, 0x4cce, 0x4ced, 0x9d67, 0x2a0ce, 0x4cf8, 0x2a105, 0x2a20e,
+    0x2a291, 0x9ebb, 0x4d56, 0x9ef9, 0x9efe, 0x9f05, 0x9f0f, 0x9f16, 0x9f3b, 0x2a600,
+];
+
+/// Full case foldings
+///
+/// Each entry is a code point with the offset and length of its mapping in `CASE_FOLDINGS_DATA`.
+pub(crate) const CASE_FOLDINGS: &[(u32, u16, u8)] = &[
+    (0x41, 0, 1), (0x42, 1, 1), (0x43, 2, 1), (0x44, 3, 1), (0x45, 4, 1),
+    (0x46, 5, 1), (0x47, 6, 1), (0x48, 7, 1), (0x49, 8, 1), (0x4a, 9, 1),
+    (0x4b, 10, 1), (0x4c, 11, 1), (0x4d, 12, 1), (0x4e, 13, 1), (0x4f, 14, 1),
+    (0x50, 15, 1), (0x51, 16, 1), (0x52, 17, 1), (0x53, 18, 1), (0x54, 19, 1),
+    (0x55, 20, 1), (0x56, 21, 1), (0x57, 22, 1), (0x58, 23, 1), (0x59, 24, 1),
+    (0x5a, 25, 1), (0xb5, 26, 1), (0xc0, 27, 1), (0xc1, 28, 1), (0xc2, 29, 1),
+    (0xc3, 30, 1), (0xc4, 31, 1), (0xc5, 32, 1), (0xc6, 33, 1), (0xc7, 34, 1),
+    (0xc8, 35, 1), (0xc9, 36, 1), (0xca, 37, 1), (0xcb, 38, 1), (0xcc, 39, 1),
+    (0xcd, 40, 1), (0xce, 41, 1), (0xcf, 42, 1), (0xd0, 43, 1), (0xd1, 44, 1),
+    (0xd2, 45, 1), (0xd3, 46, 1), (0xd4, 47, 1), (0xd5, 48, 1), (0xd6, 49, 1),
+    (0xd8, 50, 1), (0xd9, 51, 1), (0xda, 52, 1), (0xdb, 53, 1), (0xdc, 54, 1),
+    (0xdd, 55, 1), (0xde, 56, 1), (0xdf, 57, 2), (0x100, 59, 1), (0x102, 60, 1),
+    (0x104, 61, 1), (0x106, 62, 1), (0x108, 63, 1), (0x10a, 64, 1), (0x10c, 65, 1),
+    (0x10e, 66, 1), (0x110, 67, 1), (0x112, 68, 1), (0x114, 69, 1), (0x116, 70, 1),
+    (0x118, 71, 1), (0x11a, 72, 1), (0x11c, 73, 1), (0x11e, 74, 1), (0x120, 75, 1),
+    (0x122, 76, 1), (0x124, 77, 1), (0x126, 78, 1), (0x128, 79, 1), (0x12a, 80, 1),
+    (0x12c, 81, 1), (0x12e, 82, 1), (0x130, 83, 2), (0x132, 85, 1), (0x134, 86, 1),
+    (0x136, 87, 1), (0x139, 88, 1), (0x13b, 89, 1), (0x13d, 90, 1), (0x13f, 91, 1),
+    (0x141, 92, 1), (0x143, 93, 1), (0x145, 94, 1), (0x147, 95, 1), (0x149, 96, 2),
+    (0x14a, 98, 1), (0x14c, 99, 1), (0x14e, 100, 1), (0x150, 101, 1), (0x152, 102, 1),
+    (0x154, 103, 1), (0x156, 104, 1), (0x158, 105, 1), (0x15a, 106, 1), (0x15c, 107, 1),
+    (0x15e, 108, 1), (0x160, 109, 1), (0x162, 110, 1), (0x164, 111, 1), (0x166, 112, 1),
+    (0x168, 113, 1), (0x16a, 114, 1), (0x16c, 115, 1), (0x16e, 116, 1), (0x170, 117, 1),
+    (0x172, 118, 1), (0x174, 119, 1), (0x176, 120, 1), (0x178, 121, 1), (0x179, 122, 1),
+    (0x17b, 123, 1), (0x17d, 124, 1), (0x17f, 125, 1), (0x181, 126, 1), (0x182, 127, 1),
+    (0x184, 128, 1), (0x186, 129, 1), (0x187, 130, 1), (0x189, 131, 1), (0x18a, 132, 1),
+    (0x18b, 133, 1), (0x18e, 134, 1), (0x18f, 135, 1), (0x190, 136, 1), (0x191, 137, 1),
+    (0x193, 138, 1), (0x194, 139, 1), (0x196, 140, 1), (0x197, 141, 1), (0x198, 142, 1),
+    (0x19c, 143, 1), (0x19d, 144, 1), (0x19f, 145, 1), (0x1a0, 146, 1), (0x1a2, 147, 1),
+    (0x1a4, 148, 1), (0x1a6, 149, 1), (0x1a7, 150, 1), (0x1a9, 151, 1), (0x1ac, 152, 1),
+    (0x1ae, 153, 1), (0x1af, 154, 1), (0x1b1, 155, 1), (0x1b2, 156, 1), (0x1b3, 157, 1),
+    (0x1b5, 158, 1), (0x1b7, 159, 1), (0x1b8, 160, 1), (0x1bc, 161, 1), (0x1c4, 162, 1),
+    (0x1c5, 163, 1), (0x1c7, 164, 1), (0x1c8, 165, 1), (0x1ca, 166, 1), (0x1cb, 167, 1),
+    (0x1cd, 168, 1), (0x1cf, 169, 1), (0x1d1, 170, 1), (0x1d3, 171, 1), (0x1d5, 172, 1),
+    (0x1d7, 173, 1), (0x1d9, 174, 1), (0x1db, 175, 1), (0x1de, 176, 1), (0x1e0, 177, 1),
+    (0x1e2, 178, 1), (0x1e4, 179, 1), (0x1e6, 180, 1), (0x1e8, 181, 1), (0x1ea, 182, 1),
+    (0x1ec, 183, 1), (0x1ee, 184, 1), (0x1f0, 185, 2), (0x1f1, 187, 1), (0x1f2, 188, 1),
+    (0x1f4, 189, 1), (0x1f6, 190, 1), (0x1f7, 191, 1), (0x1f8, 192, 1), (0x1fa, 193, 1),
+    (0x1fc, 194, 1), (0x1fe, 195, 1), (0x200, 196, 1), (0x202, 197, 1), (0x204, 198, 1),
+    (0x206, 199, 1), (0x208, 200, 1), (0x20a, 201, 1), (0x20c, 202, 1), (0x20e, 203, 1),
+    (0x210, 204, 1), (0x212, 205, 1), (0x214, 206, 1), (0x216, 207, 1), (0x218, 208, 1),
+    (0x21a, 209, 1), (0x21c, 210, 1), (0x21e, 211, 1), (0x220, 212, 1), (0x222, 213, 1),
+    (0x224, 214, 1), (0x226, 215, 1), (0x228, 216, 1), (0x22a, 217, 1), (0x22c, 218, 1),
+    (0x22e, 219, 1), (0x230, 220, 1), (0x232, 221, 1), (0x23a, 222, 1), (0x23b, 223, 1),
+    (0x23d, 224, 1), (0x23e, 225, 1), (0x241, 226, 1), (0x243, 227, 1), (0x244, 228, 1),
+    (0x245, 229, 1), (0x246, 230, 1), (0x248, 231, 1), (0x24a, 232, 1), (0x24c, 233, 1),
+    (0x24e, 234, 1), (0x345, 235, 1), (0x370, 236, 1), (0x372, 237, 1), (0x376, 238, 1),
+    (0x37f, 239, 1), (0x386, 240, 1), (0x388, 241, 1), (0x389, 242, 1), (0x38a, 243, 1),
+    (0x38c, 244, 1), (0x38e, 245, 1), (0x38f, 246, 1), (0x390, 247, 3), (0x391, 250, 1),
+    (0x392, 251, 1), (0x393, 252, 1), (0x394, 253, 1), (0x395, 254, 1), (0x396, 255, 1),
+    (0x397, 256, 1), (0x398, 257, 1), (0x399, 258, 1), (0x39a, 259, 1), (0x39b, 260, 1),
+    (0x39c, 261, 1), (0x39d, 262, 1), (0x39e, 263, 1), (0x39f, 264, 1), (0x3a0, 265, 1),
+    (0x3a1, 266, 1), (0x3a3, 267, 1), (0x3a4, 268, 1), (0x3a5, 269, 1), (0x3a6, 270, 1),
+    (0x3a7, 271, 1), (0x3a8, 272, 1), (0x3a9, 273, 1), (0x3aa, 274, 1), (0x3ab, 275, 1),
+    (0x3b0, 276, 3), (0x3c2, 279, 1), (0x3cf, 280, 1), (0x3d0, 281, 1), (0x3d1, 282, 1),
+    (0x3d5, 283, 1), (0x3d6, 284, 1), (0x3d8, 285, 1), (0x3da, 286, 1), (0x3dc, 287, 1),
+    (0x3de, 288, 1), (0x3e0, 289, 1), (0x3e2, 290, 1), (0x3e4, 291, 1), (0x3e6, 292, 1),
+    (0x3e8, 293, 1), (0x3ea, 294, 1), (0x3ec, 295, 1), (0x3ee, 296, 1), (0x3f0, 297, 1),
+    (0x3f1, 298, 1), (0x3f4, 299, 1), (0x3f5, 300, 1), (0x3f7, 301, 1), (0x3f9, 302, 1),
+    (0x3fa, 303, 1), (0x3fd, 304, 1), (0x3fe, 305, 1), (0x3ff, 306, 1), (0x400, 307, 1),
+    (0x401, 308, 1), (0x402, 309, 1), (0x403, 310, 1), (0x404, 311, 1), (0x405, 312, 1),
+    (0x406, 313, 1), (0x407, 314, 1), (0x408, 315, 1), (0x409, 316, 1), (0x40a, 317, 1),
+    (0x40b, 318, 1), (0x40c, 319, 1), (0x40d, 320, 1), (0x40e, 321, 1), (0x40f, 322, 1),
+    (0x410, 323, 1), (0x411, 324, 1), (0x412, 325, 1), (0x413, 326, 1), (0x414, 327, 1),
+    (0x415, 328, 1), (0x416, 329, 1), (0x417, 330, 1), (0x418, 331, 1), (0x419, 332, 1),
+    (0x41a, 333, 1), (0x41b, 334, 1), (0x41c, 335, 1), (0x41d, 336, 1), (0x41e, 337, 1),
+    (0x41f, 338, 1), (0x420, 339, 1), (0x421, 340, 1), (0x422, 341, 1), (0x423, 342, 1),
+    (0x424, 343, 1), (0x425, 344, 1), (0x426, 345, 1), (0x427, 346, 1), (0x428, 347, 1),
+    (0x429, 348, 1), (0x42a, 349, 1), (0x42b, 350, 1), (0x42c, 351, 1), (0x42d, 352, 1),
+    (0x42e, 353, 1), (0x42f, 354, 1), (0x460, 355, 1), (0x462, 356, 1), (0x464, 357, 1),
+    (0x466, 358, 1), (0x468, 359, 1), (0x46a, 360, 1), (0x46c, 361, 1), (0x46e, 362, 1),
+    (0x470, 363, 1), (0x472, 364, 1), (0x474, 365, 1), (0x476, 366, 1), (0x478, 367, 1),
+    (0x47a, 368, 1), (0x47c, 369, 1), (0x47e, 370, 1), (0x480, 371, 1), (0x48a, 372, 1),
+    (0x48c, 373, 1), (0x48e, 374, 1), (0x490, 375, 1), (0x492, 376, 1), (0x494, 377, 1),
+    (0x496, 378, 1), (0x498, 379, 1), (0x49a, 380, 1), (0x49c, 381, 1), (0x49e, 382, 1),
+    (0x4a0, 383, 1), (0x4a2, 384, 1), (0x4a4, 385, 1), (0x4a6, 386, 1), (0x4a8, 387, 1),
+    (0x4aa, 388, 1), (0x4ac, 389, 1), (0x4ae, 390, 1), (0x4b0, 391, 1), (0x4b2, 392, 1),
+    (0x4b4, 393, 1), (0x4b6, 394, 1), (0x4b8, 395, 1), (0x4ba, 396, 1), (0x4bc, 397, 1),
+    (0x4be, 398, 1), (0x4c0, 399, 1), (0x4c1, 400, 1), (0x4c3, 401, 1), (0x4c5, 402, 1),
+    (0x4c7, 403, 1), (0x4c9, 404, 1), (0x4cb, 405, 1), (0x4cd, 406, 1), (0x4d0, 407, 1),
+    (0x4d2, 408, 1), (0x4d4, 409, 1), (0x4d6, 410, 1), (0x4d8, 411, 1), (0x4da, 412, 1),
+    (0x4dc, 413, 1), (0x4de, 414, 1), (0x4e0, 415, 1), (0x4e2, 416, 1), (0x4e4, 417, 1),
+    (0x4e6, 418, 1), (0x4e8, 419, 1), (0x4ea, 420, 1), (0x4ec, 421, 1), (0x4ee, 422, 1),
+    (0x4f0, 423, 1), (0x4f2, 424, 1), (0x4f4, 425, 1), (0x4f6, 426, 1), (0x4f8, 427, 1),
+    (0x4fa, 428, 1), (0x4fc, 429, 1), (0x4fe, 430, 1), (0x500, 431, 1), (0x502, 432, 1),
+    (0x504, 433, 1), (0x506, 434, 1), (0x508, 435, 1), (0x50a, 436, 1), (0x50c, 437, 1),
+    (0x50e, 438, 1), (0x510, 439, 1), (0x512, 440, 1), (0x514, 441, 1), (0x516, 442, 1),
+    (0x518, 443, 1), (0x51a, 444, 1), (0x51c, 445, 1), (0x51e, 446, 1), (0x520, 447, 1),
+    (0x522, 448, 1), (0x524, 449, 1), (0x526, 450, 1), (0x528, 451, 1), (0x52a, 452, 1),
+    (0x52c, 453, 1), (0x52e, 454, 1), (0x531, 455, 1), (0x532, 456, 1), (0x533, 457, 1),
+    (0x534, 458, 1), (0x535, 459, 1), (0x536, 460, 1), (0x537, 461, 1), (0x538, 462, 1),
+    (0x539, 463, 1), (0x53a, 464, 1), (0x53b, 465, 1), (0x53c, 466, 1), (0x53d, 467, 1),
+    (0x53e, 468, 1), (0x53f, 469, 1), (0x540, 470, 1), (0x541, 471, 1), (0x542, 472, 1),
+    (0x543, 473, 1), (0x544, 474, 1), (0x545, 475, 1), (0x546, 476, 1), (0x547, 477, 1),
+    (0x548, 478, 1), (0x549, 479, 1), (0x54a, 480, 1), (0x54b, 481, 1), (0x54c, 482, 1),
+    (0x54d, 483, 1), (0x54e, 484, 1), (0x54f, 485, 1), (0x550, 486, 1), (0x551, 487, 1),
+    (0x552, 488, 1), (0x553, 489, 1), (0x554, 490, 1), (0x555, 491, 1), (0x556, 492, 1),
+    (0x587, 493, 2), (0x10a0, 495, 1), (0x10a1, 496, 1), (0x10a2, 497, 1), (0x10a3, 498, 1),
+    (0x10a4, 499, 1), (0x10a5, 500, 1), (0x10a6, 501, 1), (0x10a7, 502, 1), (0x10a8, 503, 1),
+    (0x10a9, 504, 1), (0x10aa, 505, 1), (0x10ab, 506, 1), (0x10ac, 507, 1), (0x10ad, 508, 1),
+    (0x10ae, 509, 1), (0x10af, 510, 1), (0x10b0, 511, 1), (0x10b1, 512, 1), (0x10b2, 513, 1),
+    (0x10b3, 514, 1), (0x10b4, 515, 1), (0x10b5, 516, 1), (0x10b6, 517, 1), (0x10b7, 518, 1),
+    (0x10b8, 519, 1), (0x10b9, 520, 1), (0x10ba, 521, 1), (0x10bb, 522, 1), (0x10bc, 523, 1),
+    (0x10bd, 524, 1), (0x10be, 525, 1), (0x10bf, 526, 1), (0x10c0, 527, 1), (0x10c1, 528, 1),
+    (0x10c2, 529, 1), (0x10c3, 530, 1), (0x10c4, 531, 1), (0x10c5, 532, 1), (0x10c7, 533, 1),
+    (0x10cd, 534, 1), (0x13f8, 535, 1), (0x13f9, 536, 1), (0x13fa, 537, 1), (0x13fb, 538, 1),
+    (0x13fc, 539, 1), (0x13fd, 540, 1), (0x1c80, 541, 1), (0x1c81, 542, 1), (0x1c82, 543, 1),
+    (0x1c83, 544, 1), (0x1c84, 545, 1), (0x1c85, 546, 1), (0x1c86, 547, 1), (0x1c87, 548, 1),
+    (0x1c88, 549, 1), (0x1c90, 550, 1), (0x1c91, 551, 1), (0x1c92, 552, 1), (0x1c93, 553, 1),
+    (0x1c94, 554, 1), (0x1c95, 555, 1), (0x1c96, 556, 1), (0x1c97, 557, 1), (0x1c98, 558, 1),
+    (0x1c99, 559, 1), (0x1c9a, 560, 1), (0x1c9b, 561, 1), (0x1c9c, 562, 1), (0x1c9d, 563, 1),
+    (0x1c9e, 564, 1), (0x1c9f, 565, 1), (0x1ca0, 566, 1), (0x1ca1, 567, 1), (0x1ca2, 568, 1),
+    (0x1ca3, 569, 1), (0x1ca4, 570, 1), (0x1ca5, 571, 1), (0x1ca6, 572, 1), (0x1ca7, 573, 1),
+    (0x1ca8, 574, 1), (0x1ca9, 575, 1), (0x1caa, 576, 1), (0x1cab, 577, 1), (0x1cac, 578, 1),
+    (0x1cad, 579, 1), (0x1cae, 580, 1), (0x1caf, 581, 1), (0x1cb0, 582, 1), (0x1cb1, 583, 1),
+    (0x1cb2, 584, 1), (0x1cb3, 585, 1), (0x1cb4, 586, 1), (0x1cb5, 587, 1), (0x1cb6, 588, 1),
+    (0x1cb7, 589, 1), (0x1cb8, 590, 1), (0x1cb9, 591, 1), (0x1cba, 592, 1), (0x1cbd, 593, 1),
+    (0x1cbe, 594, 1), (0x1cbf, 595, 1), (0x1e00, 596, 1), (0x1e02, 597, 1), (0x1e04, 598, 1),
+    (0x1e06, 599, 1), (0x1e08, 600, 1), (0x1e0a, 601, 1), (0x1e0c, 602, 1), (0x1e0e, 603, 1),
+    (0x1e10, 604, 1), (0x1e12, 605, 1), (0x1e14, 606, 1), (0x1e16, 607, 1), (0x1e18, 608, 1),
+    (0x1e1a, 609, 1), (0x1e1c, 610, 1), (0x1e1e, 611, 1), (0x1e20, 612, 1), (0x1e22, 613, 1),
+    (0x1e24, 614, 1), (0x1e26, 615, 1), (0x1e28, 616, 1), (0x1e2a, 617, 1), (0x1e2c, 618, 1),
+    (0x1e2e, 619, 1), (0x1e30, 620, 1), (0x1e32, 621, 1), (0x1e34, 622, 1), (0x1e36, 623, 1),
+    (0x1e38, 624, 1), (0x1e3a, 625, 1), (0x1e3c, 626, 1), (0x1e3e, 627, 1), (0x1e40, 628, 1),
+    (0x1e42, 629, 1), (0x1e44, 630, 1), (0x1e46, 631, 1), (0x1e48, 632, 1), (0x1e4a, 633, 1),
+    (0x1e4c, 634, 1), (0x1e4e, 635, 1), (0x1e50, 636, 1), (0x1e52, 637, 1), (0x1e54, 638, 1),
+    (0x1e56, 639, 1), (0x1e58, 640, 1), (0x1e5a, 641, 1), (0x1e5c, 642, 1), (0x1e5e, 643, 1),
+    (0x1e60, 644, 1), (0x1e62, 645, 1), (0x1e64, 646, 1), (0x1e66, 647, 1), (0x1e68, 648, 1),
+    (0x1e6a, 649, 1), (0x1e6c, 650, 1), (0x1e6e, 651, 1), (0x1e70, 652, 1), (0x1e72, 653, 1),
+    (0x1e74, 654, 1), (0x1e76, 655, 1), (0x1e78, 656, 1), (0x1e7a, 657, 1), (0x1e7c, 658, 1),
+    (0x1e7e, 659, 1), (0x1e80, 660, 1), (0x1e82, 661, 1), (0x1e84, 662, 1), (0x1e86, 663, 1),
+    (0x1e88, 664, 1), (0x1e8a, 665, 1), (0x1e8c, 666, 1), (0x1e8e, 667, 1), (0x1e90, 668, 1),
+    (0x1e92, 669, 1), (0x1e94, 670, 1), (0x1e96, 671, 2), (0x1e97, 673, 2), (0x1e98, 675, 2),
+    (0x1e99, 677, 2), (0x1e9a, 679, 2), (0x1e9b, 681, 1), (0x1e9e, 682, 2), (0x1ea0, 684, 1),
+    (0x1ea2, 685, 1), (0x1ea4, 686, 1), (0x1ea6, 687, 1), (0x1ea8, 688, 1), (0x1eaa, 689, 1),
+    (0x1eac, 690, 1), (0x1eae, 691, 1), (0x1eb0, 692, 1), (0x1eb2, 693, 1), (0x1eb4, 694, 1),
+    (0x1eb6, 695, 1), (0x1eb8, 696, 1), (0x1eba, 697, 1), (0x1ebc, 698, 1), (0x1ebe, 699, 1),
+    (0x1ec0, 700, 1), (0x1ec2, 701, 1), (0x1ec4, 702, 1), (0x1ec6, 703, 1), (0x1ec8, 704, 1),
+    (0x1eca, 705, 1), (0x1ecc, 706, 1), (0x1ece, 707, 1), (0x1ed0, 708, 1), (0x1ed2, 709, 1),
+    (0x1ed4, 710, 1), (0x1ed6, 711, 1), (0x1ed8, 712, 1), (0x1eda, 713, 1), (0x1edc, 714, 1),
+    (0x1ede, 715, 1), (0x1ee0, 716, 1), (0x1ee2, 717, 1), (0x1ee4, 718, 1), (0x1ee6, 719, 1),
+    (0x1ee8, 720, 1), (0x1eea, 721, 1), (0x1eec, 722, 1), (0x1eee, 723, 1), (0x1ef0, 724, 1),
+    (0x1ef2, 725, 1), (0x1ef4, 726, 1), (0x1ef6, 727, 1), (0x1ef8, 728, 1), (0x1efa, 729, 1),
+    (0x1efc, 730, 1), (0x1efe, 731, 1), (0x1f08, 732, 1), (0x1f09, 733, 1), (0x1f0a, 734, 1),
+    (0x1f0b, 735, 1), (0x1f0c, 736, 1), (0x1f0d, 737, 1), (0x1f0e, 738, 1), (0x1f0f, 739, 1),
+    (0x1f18, 740, 1), (0x1f19, 741, 1), (0x1f1a, 742, 1), (0x1f1b, 743, 1), (0x1f1c, 744, 1),
+    (0x1f1d, 745, 1), (0x1f28, 746, 1), (0x1f29, 747, 1), (0x1f2a, 748, 1), (0x1f2b, 749, 1),
+    (0x1f2c, 750, 1), (0x1f2d, 751, 1), (0x1f2e, 752, 1), (0x1f2f, 753, 1), (0x1f38, 754, 1),
+    (0x1f39, 755, 1), (0x1f3a, 756, 1), (0x1f3b, 757, 1), (0x1f3c, 758, 1), (0x1f3d, 759, 1),
+    (0x1f3e, 760, 1), (0x1f3f, 761, 1), (0x1f48, 762, 1), (0x1f49, 763, 1), (0x1f4a, 764, 1),
+    (0x1f4b, 765, 1), (0x1f4c, 766, 1), (0x1f4d, 767, 1), (0x1f50, 768, 2), (0x1f52, 770, 3),
+    (0x1f54, 773, 3), (0x1f56, 776, 3), (0x1f59, 779, 1), (0x1f5b, 780, 1), (0x1f5d, 781, 1),
+    (0x1f5f, 782, 1), (0x1f68, 783, 1), (0x1f69, 784, 1), (0x1f6a, 785, 1), (0x1f6b, 786, 1),
+    (0x1f6c, 787, 1), (0x1f6d, 788, 1), (0x1f6e, 789, 1), (0x1f6f, 790, 1), (0x1f80, 791, 2),
+    (0x1f81, 793, 2), (0x1f82, 795, 2), (0x1f83, 797, 2), (0x1f84, 799, 2), (0x1f85, 801, 2),
+    (0x1f86, 803, 2), (0x1f87, 805, 2), (0x1f88, 807, 2), (0x1f89, 809, 2), (0x1f8a, 811, 2),
+    (0x1f8b, 813, 2), (0x1f8c, 815, 2), (0x1f8d, 817, 2), (0x1f8e, 819, 2), (0x1f8f, 821, 2),
+    (0x1f90, 823, 2), (0x1f91, 825, 2), (0x1f92, 827, 2), (0x1f93, 829, 2), (0x1f94, 831, 2),
+    (0x1f95, 833, 2), (0x1f96, 835, 2), (0x1f97, 837, 2), (0x1f98, 839, 2), (0x1f99, 841, 2),
+    (0x1f9a, 843, 2), (0x1f9b, 845, 2), (0x1f9c, 847, 2), (0x1f9d, 849, 2), (0x1f9e, 851, 2),
+    (0x1f9f, 853, 2), (0x1fa0, 855, 2), (0x1fa1, 857, 2), (0x1fa2, 859, 2), (0x1fa3, 861, 2),
+    (0x1fa4, 863, 2), (0x1fa5, 865, 2), (0x1fa6, 867, 2), (0x1fa7, 869, 2), (0x1fa8, 871, 2),
+    (0x1fa9, 873, 2), (0x1faa, 875, 2), (0x1fab, 877, 2), (0x1fac, 879, 2), (0x1fad, 881, 2),
+    (0x1fae, 883, 2), (0x1faf, 885, 2), (0x1fb2, 887, 2), (0x1fb3, 889, 2), (0x1fb4, 891, 2),
+    (0x1fb6, 893, 2), (0x1fb7, 895, 3), (0x1fb8, 898, 1), (0x1fb9, 899, 1), (0x1fba, 900, 1),
+    (0x1fbb, 901, 1), (0x1fbc, 902, 2), (0x1fbe, 904, 1), (0x1fc2, 905, 2), (0x1fc3, 907, 2),
+    (0x1fc4, 909, 2), (0x1fc6, 911, 2), (0x1fc7, 913, 3), (0x1fc8, 916, 1), (0x1fc9, 917, 1),
+    (0x1fca, 918, 1), (0x1fcb, 919, 1), (0x1fcc, 920, 2), (0x1fd2, 922, 3), (0x1fd3, 925, 3),
+    (0x1fd6, 928, 2), (0x1fd7, 930, 3), (0x1fd8, 933, 1), (0x1fd9, 934, 1), (0x1fda, 935, 1),
+    (0x1fdb, 936, 1), (0x1fe2, 937, 3), (0x1fe3, 940, 3), (0x1fe4, 943, 2), (0x1fe6, 945, 2),
+    (0x1fe7, 947, 3), (0x1fe8, 950, 1), (0x1fe9, 951, 1), (0x1fea, 952, 1), (0x1feb, 953, 1),
+    (0x1fec, 954, 1), (0x1ff2, 955, 2), (0x1ff3, 957, 2), (0x1ff4, 959, 2), (0x1ff6, 961, 2),
+    (0x1ff7, 963, 3), (0x1ff8, 966, 1), (0x1ff9, 967, 1), (0x1ffa, 968, 1), (0x1ffb, 969, 1),
+    (0x1ffc, 970, 2), (0x2126, 972, 1), (0x212a, 973, 1), (0x212b, 974, 1), (0x2132, 975, 1),
+    (0x2160, 976, 1), (0x2161, 977, 1), (0x2162, 978, 1), (0x2163, 979, 1), (0x2164, 980, 1),
+    (0x2165, 981, 1), (0x2166, 982, 1), (0x2167, 983, 1), (0x2168, 984, 1), (0x2169, 985, 1),
+    (0x216a, 986, 1), (0x216b, 987, 1), (0x216c, 988, 1), (0x216d, 989, 1), (0x216e, 990, 1),
+    (0x216f, 991, 1), (0x2183, 992, 1), (0x24b6, 993, 1), (0x24b7, 994, 1), (0x24b8, 995, 1),
+    (0x24b9, 996, 1), (0x24ba, 997, 1), (0x24bb, 998, 1), (0x24bc, 999, 1), (0x24bd, 1000, 1),
+    (0x24be, 1001, 1), (0x24bf, 1002, 1), (0x24c0, 1003, 1), (0x24c1, 1004, 1), (0x24c2, 1005, 1),
+    (0x24c3, 1006, 1), (0x24c4, 1007, 1), (0x24c5, 1008, 1), (0x24c6, 1009, 1), (0x24c7, 1010, 1),
+    (0x24c8, 1011, 1), (0x24c9, 1012, 1), (0x24ca, 1013, 1), (0x24cb, 1014, 1), (0x24cc, 1015, 1),
+    (0x24cd, 1016, 1), (0x24ce, 1017, 1), (0x24cf, 1018, 1), (0x2c00, 1019, 1), (0x2c01, 1020, 1),
+    (0x2c02, 1021, 1), (0x2c03, 1022, 1), (0x2c04, 1023, 1), (0x2c05, 1024, 1), (0x2c06, 1025, 1),
+    (0x2c07, 1026, 1), (0x2c08, 1027, 1), (0x2c09, 1028, 1), (0x2c0a, 1029, 1), (0x2c0b, 1030, 1),
+    (0x2c0c, 1031, 1), (0x2c0d, 1032, 1), (0x2c0e, 1033, 1), (0x2c0f, 1034, 1), (0x2c10, 1035, 1),
+    (0x2c11, 1036, 1), (0x2c12, 1037, 1), (0x2c13, 1038, 1), (0x2c14, 1039, 1), (0x2c15, 1040, 1),
+    (0x2c16, 1041, 1), (0x2c17, 1042, 1), (0x2c18, 1043, 1), (0x2c19, 1044, 1), (0x2c1a, 1045, 1),
+    (0x2c1b, 1046, 1), (0x2c1c, 1047, 1), (0x2c1d, 1048, 1), (0x2c1e, 1049, 1), (0x2c1f, 1050, 1),
+    (0x2c20, 1051, 1), (0x2c21, 1052, 1), (0x2c22, 1053, 1), (0x2c23, 1054, 1), (0x2c24, 1055, 1),
+    (0x2c25, 1056, 1), (0x2c26, 1057, 1), (0x2c27, 1058, 1), (0x2c28, 1059, 1), (0x2c29, 1060, 1),
+    (0x2c2a, 1061, 1), (0x2c2b, 1062, 1), (0x2c2c, 1063, 1), (0x2c2d, 1064, 1), (0x2c2e, 1065, 1),
+    (0x2c2f, 1066, 1), (0x2c60, 1067, 1), (0x2c62, 1068, 1), (0x2c63, 1069, 1), (0x2c64, 1070, 1),
+    (0x2c67, 1071, 1), (0x2c69, 1072, 1), (0x2c6b, 1073, 1), (0x2c6d, 1074, 1), (0x2c6e, 1075, 1),
+    (0x2c6f, 1076, 1), (0x2c70, 1077, 1), (0x2c72, 1078, 1), (0x2c75, 1079, 1), (0x2c7e, 1080, 1),
+    (0x2c7f, 1081, 1), (0x2c80, 1082, 1), (0x2c82, 1083, 1), (0x2c84, 1084, 1), (0x2c86, 1085, 1),
+    (0x2c88, 1086, 1), (0x2c8a, 1087, 1), (0x2c8c, 1088, 1), (0x2c8e, 1089, 1), (0x2c90, 1090, 1),
+    (0x2c92, 1091, 1), (0x2c94, 1092, 1), (0x2c96, 1093, 1), (0x2c98, 1094, 1), (0x2c9a, 1095, 1),
+    (0x2c9c, 1096, 1), (0x2c9e, 1097, 1), (0x2ca0, 1098, 1), (0x2ca2, 1099, 1), (0x2ca4, 1100, 1),
+    (0x2ca6, 1101, 1), (0x2ca8, 1102, 1), (0x2caa, 1103, 1), (0x2cac, 1104, 1), (0x2cae, 1105, 1),
+    (0x2cb0, 1106, 1), (0x2cb2, 1107, 1), (0x2cb4, 1108, 1), (0x2cb6, 1109, 1), (0x2cb8, 1110, 1),
+    (0x2cba, 1111, 1), (0x2cbc, 1112, 1), (0x2cbe, 1113, 1), (0x2cc0, 1114, 1), (0x2cc2, 1115, 1),
+    (0x2cc4, 1116, 1), (0x2cc6, 1117, 1), (0x2cc8, 1118, 1), (0x2cca, 1119, 1), (0x2ccc, 1120, 1),
+    (0x2cce, 1121, 1), (0x2cd0, 1122, 1), (0x2cd2, 1123, 1), (0x2cd4, 1124, 1), (0x2cd6, 1125, 1),
+    (0x2cd8, 1126, 1), (0x2cda, 1127, 1), (0x2cdc, 1128, 1), (0x2cde, 1129, 1), (0x2ce0, 1130, 1),
+    (0x2ce2, 1131, 1), (0x2ceb, 1132, 1), (0x2ced, 1133, 1), (0x2cf2, 1134, 1), (0xa640, 1135, 1),
+    (0xa642, 1136, 1), (0xa644, 1137, 1), (0xa646, 1138, 1), (0xa648, 1139, 1), (0xa64a, 1140, 1),
+    (0xa64c, 1141, 1), (0xa64e, 1142, 1), (0xa650, 1143, 1), (0xa652, 1144, 1), (0xa654, 1145, 1),
+    (0xa656, 1146, 1), (0xa658, 1147, 1), (0xa65a, 1148, 1), (0xa65c, 1149, 1), (0xa65e, 1150, 1),
+    (0xa660, 1151, 1), (0xa662, 1152, 1), (0xa664, 1153, 1), (0xa666, 1154, 1), (0xa668, 1155, 1),
+    (0xa66a, 1156, 1), (0xa66c, 1157, 1), (0xa680, 1158, 1), (0xa682, 1159, 1), (0xa684, 1160, 1),
+    (0xa686, 1161, 1), (0xa688, 1162, 1), (0xa68a, 1163, 1), (0xa68c, 1164, 1), (0xa68e, 1165, 1),
+    (0xa690, 1166, 1), (0xa692, 1167, 1), (0xa694, 1168, 1), (0xa696, 1169, 1), (0xa698, 1170, 1),
+    (0xa69a, 1171, 1), (0xa722, 1172, 1), (0xa724, 1173, 1), (0xa726, 1174, 1), (0xa728, 1175, 1),
+    (0xa72a, 1176, 1), (0xa72c, 1177, 1), (0xa72e, 1178, 1), (0xa732, 1179, 1), (0xa734, 1180, 1),
+    (0xa736, 1181, 1), (0xa738, 1182, 1), (0xa73a, 1183, 1), (0xa73c, 1184, 1), (0xa73e, 1185, 1),
+    (0xa740, 1186, 1), (0xa742, 1187, 1), (0xa744, 1188, 1), (0xa746, 1189, 1), (0xa748, 1190, 1),
+    (0xa74a, 1191, 1), (0xa74c, 1192, 1), (0xa74e, 1193, 1), (0xa750, 1194, 1), (0xa752, 1195, 1),
+    (0xa754, 1196, 1), (0xa756, 1197, 1), (0xa758, 1198, 1), (0xa75a, 1199, 1), (0xa75c, 1200, 1),
+    (0xa75e, 1201, 1), (0xa760, 1202, 1), (0xa762, 1203, 1), (0xa764, 1204, 1), (0xa766, 1205, 1),
+    (0xa768, 1206, 1), (0xa76a, 1207, 1), (0xa76c, 1208, 1), (0xa76e, 1209, 1), (0xa779, 1210, 1),
+    (0xa77b, 1211, 1), (0xa77d, 1212, 1), (0xa77e, 1213, 1), (0xa780, 1214, 1), (0xa782, 1215, 1),
+    (0xa784, 1216, 1), (0xa786, 1217, 1), (0xa78b, 1218, 1), (0xa78d, 1219, 1), (0xa790, 1220, 1),
+    (0xa792, 1221, 1), (0xa796, 1222, 1), (0xa798, 1223, 1), (0xa79a, 1224, 1), (0xa79c, 1225, 1),
+    (0xa79e, 1226, 1), (0xa7a0, 1227, 1), (0xa7a2, 1228, 1), (0xa7a4, 1229, 1), (0xa7a6, 1230, 1),
+    (0xa7a8, 1231, 1), (0xa7aa, 1232, 1), (0xa7ab, 1233, 1), (0xa7ac, 1234, 1), (0xa7ad, 1235, 1),
+    (0xa7ae, 1236, 1), (0xa7b0, 1237, 1), (0xa7b1, 1238, 1), (0xa7b2, 1239, 1), (0xa7b3, 1240, 1),
+    (0xa7b4, 1241, 1), (0xa7b6, 1242, 1), (0xa7b8, 1243, 1), (0xa7ba, 1244, 1), (0xa7bc, 1245, 1),
+    (0xa7be, 1246, 1), (0xa7c0, 1247, 1), (0xa7c2, 1248, 1), (0xa7c4, 1249, 1), (0xa7c5, 1250, 1),
+    (0xa7c6, 1251, 1), (0xa7c7, 1252, 1), (0xa7c9, 1253, 1), (0xa7d0, 1254, 1), (0xa7d6, 1255, 1),
+    (0xa7d8, 1256, 1), (0xa7f5, 1257, 1), (0xab70, 1258, 1), (0xab71, 1259, 1), (0xab72, 1260, 1),
+    (0xab73, 1261, 1), (0xab74, 1262, 1), (0xab75, 1263, 1), (0xab76, 1264, 1), (0xab77, 1265, 1),
+    (0xab78, 1266, 1), (0xab79, 1267, 1), (0xab7a, 1268, 1), (0xab7b, 1269, 1), (0xab7c, 1270, 1),
+    (0xab7d, 1271, 1), (0xab7e, 1272, 1), (0xab7f, 1273, 1), (0xab80, 1274, 1), (0xab81, 1275, 1),
+    (0xab82, 1276, 1), (0xab83, 1277, 1), (0xab84, 1278, 1), (0xab85, 1279, 1), (0xab86, 1280, 1),
+    (0xab87, 1281, 1), (0xab88, 1282, 1), (0xab89, 1283, 1), (0xab8a, 1284, 1), (0xab8b, 1285, 1),
+    (0xab8c, 1286, 1), (0xab8d, 1287, 1), (0xab8e, 1288, 1), (0xab8f, 1289, 1), (0xab90, 1290, 1),
+    (0xab91, 1291, 1), (0xab92, 1292, 1), (0xab93, 1293, 1), (0xab94, 1294, 1), (0xab95, 1295, 1),
+    (0xab96, 1296, 1), (0xab97, 1297, 1), (0xab98, 1298, 1), (0xab99, 1299, 1), (0xab9a, 1300, 1),
+    (0xab9b, 1301, 1), (0xab9c, 1302, 1), (0xab9d, 1303, 1), (0xab9e, 1304, 1), (0xab9f, 1305, 1),
+    (0xaba0, 1306, 1), (0xaba1, 1307, 1), (0xaba2, 1308, 1), (0xaba3, 1309, 1), (0xaba4, 1310, 1),
+    (0xaba5, 1311, 1), (0xaba6, 1312, 1), (0xaba7, 1313, 1), (0xaba8, 1314, 1), (0xaba9, 1315, 1),
+    (0xabaa, 1316, 1), (0xabab, 1317, 1), (0xabac, 1318, 1), (0xabad, 1319, 1), (0xabae, 1320, 1),
+    (0xabaf, 1321, 1), (0xabb0, 1322, 1), (0xabb1, 1323, 1), (0xabb2, 1324, 1), (0xabb3, 1325, 1),
+    (0xabb4, 1326, 1), (0xabb5, 1327, 1), (0xabb6, 1328, 1), (0xabb7, 1329, 1), (0xabb8, 1330, 1),
+    (0xabb9, 1331, 1), (0xabba, 1332, 1), (0xabbb, 1333, 1), (0xabbc, 1334, 1), (0xabbd, 1335, 1),
+    (0xabbe, 1336, 1), (0xabbf, 1337, 1), (0xfb00, 1338, 2), (0xfb01, 1340, 2), (0xfb02, 1342, 2),
+    (0xfb03, 1344, 3), (0xfb04, 1347, 3), (0xfb05, 1350, 2), (0xfb06, 1352, 2), (0xfb13, 1354, 2),
+    (0xfb14, 1356, 2), (0xfb15, 1358, 2), (0xfb16, 1360, 2), (0xfb17, 1362, 2), (0xff21, 1364, 1),
+    (0xff22, 1365, 1), (0xff23, 1366, 1), (0xff24, 1367, 1), (0xff25, 1368, 1), (0xff26, 1369, 1),
+    (0xff27, 1370, 1), (0xff28, 1371, 1), (0xff29, 1372, 1), (0xff2a, 1373, 1), (0xff2b, 1374, 1),
+    (0xff2c, 1375, 1), (0xff2d, 1376, 1), (0xff2e, 1377, 1), (0xff2f, 1378, 1), (0xff30, 1379, 1),
+    (0xff31, 1380, 1), (0xff32, 1381, 1), (0xff33, 1382, 1), (0xff34, 1383, 1), (0xff35, 1384, 1),
+    (0xff36, 1385, 1), (0xff37, 1386, 1), (0xff38, 1387, 1), (0xff39, 1388, 1), (0xff3a, 1389, 1),
+    (0x10400, 1390, 1), (0x10401, 1391, 1), (0x10402, 1392, 1), (0x10403, 1393, 1), (0x10404, 1394, 1),
+    (0x10405, 1395, 1), (0x10406, 1396, 1), (0x10407, 1397, 1), (0x10408, 1398, 1), (0x10409, 1399, 1),
+    (0x1040a, 1400, 1), (0x1040b, 1401, 1), (0x1040c, 1402, 1), (0x1040d, 1403, 1), (0x1040e, 1404, 1),
+    (0x1040f, 1405, 1), (0x10410, 1406, 1), (0x10411, 1407, 1), (0x10412, 1408, 1), (0x10413, 1409, 1),
+    (0x10414, 1410, 1), (0x10415, 1411, 1), (0x10416, 1412, 1), (0x10417, 1413, 1), (0x10418, 1414, 1),
+    (0x10419, 1415, 1), (0x1041a, 1416, 1), (0x1041b, 1417, 1), (0x1041c, 1418, 1), (0x1041d, 1419, 1),
+    (0x1041e, 1420, 1), (0x1041f, 1421, 1), (0x10420, 1422, 1), (0x10421, 1423, 1), (0x10422, 1424, 1),
+    (0x10423, 1425, 1), (0x10424, 1426, 1), (0x10425, 1427, 1), (0x10426, 1428, 1), (0x10427, 1429, 1),
+    (0x104b0, 1430, 1), (0x104b1, 1431, 1), (0x104b2, 1432, 1), (0x104b3, 1433, 1), (0x104b4, 1434, 1),
+    (0x104b5, 1435, 1), (0x104b6, 1436, 1), (0x104b7, 1437, 1), (0x104b8, 1438, 1), (0x104b9, 1439, 1),
+    (0x104ba, 1440, 1), (0x104bb, 1441, 1), (0x104bc, 1442, 1), (0x104bd, 1443, 1), (0x104be, 1444, 1),
+    (0x104bf, 1445, 1), (0x104c0, 1446, 1), (0x104c1, 1447, 1), (0x104c2, 1448, 1), (0x104c3, 1449, 1),
+    (0x104c4, 1450, 1), (0x104c5, 1451, 1), (0x104c6, 1452, 1), (0x104c7, 1453, 1), (0x104c8, 1454, 1),
+    (0x104c9, 1455, 1), (0x104ca, 1456, 1), (0x104cb, 1457, 1), (0x104cc, 1458, 1), (0x104cd, 1459, 1),
+    (0x104ce, 1460, 1), (0x104cf, 1461, 1), (0x104d0, 1462, 1), (0x104d1, 1463, 1), (0x104d2, 1464, 1),
+    (0x104d3, 1465, 1), (0x10570, 1466, 1), (0x10571, 1467, 1), (0x10572, 1468, 1), (0x10573, 1469, 1),
+    (0x10574, 1470, 1), (0x10575, 1471, 1), (0x10576, 1472, 1), (0x10577, 1473, 1), (0x10578, 1474, 1),
+    (0x10579, 1475, 1), (0x1057a, 1476, 1), (0x1057c, 1477, 1), (0x1057d, 1478, 1), (0x1057e, 1479, 1),
+    (0x1057f, 1480, 1), (0x10580, 1481, 1), (0x10581, 1482, 1), (0x10582, 1483, 1), (0x10583, 1484, 1),
+    (0x10584, 1485, 1), (0x10585, 1486, 1), (0x10586, 1487, 1), (0x10587, 1488, 1), (0x10588, 1489, 1),
+    (0x10589, 1490, 1), (0x1058a, 1491, 1), (0x1058c, 1492, 1), (0x1058d, 1493, 1), (0x1058e, 1494, 1),
+    (0x1058f, 1495, 1), (0x10590, 1496, 1), (0x10591, 1497, 1), (0x10592, 1498, 1), (0x10594, 1499, 1),
+    (0x10595, 1500, 1), (0x10c80, 1501, 1), (0x10c81, 1502, 1), (0x10c82, 1503, 1), (0x10c83, 1504, 1),
+    (0x10c84, 1505, 1), (0x10c85, 1506, 1), (0x10c86, 1507, 1), (0x10c87, 1508, 1), (0x10c88, 1509, 1),
+    (0x10c89, 1510, 1), (0x10c8a, 1511, 1), (0x10c8b, 1512, 1), (0x10c8c, 1513, 1), (0x10c8d, 1514, 1),
+    (0x10c8e, 1515, 1), (0x10c8f, 1516, 1), (0x10c90, 1517, 1), (0x10c91, 1518, 1), (0x10c92, 1519, 1),
+    (0x10c93, 1520, 1), (0x10c94, 1521, 1), (0x10c95, 1522, 1), (0x10c96, 1523, 1), (0x10c97, 1524, 1),
+    (0x10c98, 1525, 1), (0x10c99, 1526, 1), (0x10c9a, 1527, 1), (0x10c9b, 1528, 1), (0x10c9c, 1529, 1),
+    (0x10c9d, 1530, 1), (0x10c9e, 1531, 1), (0x10c9f, 1532, 1), (0x10ca0, 1533, 1), (0x10ca1, 1534, 1),
+    (0x10ca2, 1535, 1), (0x10ca3, 1536, 1), (0x10ca4, 1537, 1), (0x10ca5, 1538, 1), (0x10ca6, 1539, 1),
+    (0x10ca7, 1540, 1), (0x10ca8, 1541, 1), (0x10ca9, 1542, 1), (0x10caa, 1543, 1), (0x10cab, 1544, 1),
+    (0x10cac, 1545, 1), (0x10cad, 1546, 1), (0x10cae, 1547, 1), (0x10caf, 1548, 1), (0x10cb0, 1549, 1),
+    (0x10cb1, 1550, 1), (0x10cb2, 1551, 1), (0x118a0, 1552, 1), (0x118a1, 1553, 1), (0x118a2, 1554, 1),
+    (0x118a3, 1555, 1), (0x118a4, 1556, 1), (0x118a5, 1557, 1), (0x118a6, 1558, 1), (0x118a7, 1559, 1),
+    (0x118a8, 1560, 1), (0x118a9, 1561, 1), (0x118aa, 1562, 1), (0x118ab, 1563, 1), (0x118ac, 1564, 1),
+    (0x118ad, 1565, 1), (0x118ae, 1566, 1), (0x118af, 1567, 1), (0x118b0, 1568, 1), (0x118b1, 1569, 1),
+    (0x118b2, 1570, 1), (0x118b3, 1571, 1), (0x118b4, 1572, 1), (0x118b5, 1573, 1), (0x118b6, 1574, 1),
+    (0x118b7, 1575, 1), (0x118b8, 1576, 1), (0x118b9, 1577, 1), (0x118ba, 1578, 1), (0x118bb, 1579, 1),
+    (0x118bc, 1580, 1), (0x118bd, 1581, 1), (0x118be, 1582, 1), (0x118bf, 1583, 1), (0x16e40, 1584, 1),
+    (0x16e41, 1585, 1), (0x16e42, 1586, 1), (0x16e43, 1587, 1), (0x16e44, 1588, 1), (0x16e45, 1589, 1),
+    (0x16e46, 1590, 1), (0x16e47, 1591, 1), (0x16e48, 1592, 1), (0x16e49, 1593, 1), (0x16e4a, 1594, 1),
+    (0x16e4b, 1595, 1), (0x16e4c, 1596, 1), (0x16e4d, 1597, 1), (0x16e4e, 1598, 1), (0x16e4f, 1599, 1),
+    (0x16e50, 1600, 1), (0x16e51, 1601, 1), (0x16e52, 1602, 1), (0x16e53, 1603, 1), (0x16e54, 1604, 1),
+    (0x16e55, 1605, 1), (0x16e56, 1606, 1), (0x16e57, 1607, 1), (0x16e58, 1608, 1), (0x16e59, 1609, 1),
+    (0x16e5a, 1610, 1), (0x16e5b, 1611, 1), (0x16e5c, 1612, 1), (0x16e5d, 1613, 1), (0x16e5e, 1614, 1),
+    (0x16e5f, 1615, 1), (0x1e900, 1616, 1), (0x1e901, 1617, 1), (0x1e902, 1618, 1), (0x1e903, 1619, 1),
+    (0x1e904, 1620, 1), (0x1e905, 1621, 1), (0x1e906, 1622, 1), (0x1e907, 1623, 1), (0x1e908, 1624, 1),
+    (0x1e909, 1625, 1), (0x1e90a, 1626, 1), (0x1e90b, 1627, 1), (0x1e90c, 1628, 1), (0x1e90d, 1629, 1),
+    (0x1e90e, 1630, 1), (0x1e90f, 1631, 1), (0x1e910, 1632, 1), (0x1e911, 1633, 1), (0x1e912, 1634, 1),
+    (0x1e913, 1635, 1), (0x1e914, 1636, 1), (0x1e915, 1637, 1), (0x1e916, 1638, 1), (0x1e917, 1639, 1),
+    (0x1e918, 1640, 1), (0x1e919, 1641, 1), (0x1e91a, 1642, 1), (0x1e91b, 1643, 1), (0x1e91c, 1644, 1),
+    (0x1e91d, 1645, 1), (0x1e91e, 1646, 1), (0x1e91f, 1647, 1), (0x1e920, 1648, 1), (0x1e921, 1649, 1),
+];
+
+pub(crate) const CASE_FOLDINGS_DATA: &[u32] = &[
+    0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6a,
+    0x6b, 0x6c, 0x6d, 0x6e, 0x6f, 0x70, 0x71, 0x72, 0x73, 0x74,
+    0x75, 0x76, 0x77, 0x78, 0x79, 0x7a, 0x3bc, 0xe0, 0xe1, 0xe2,
+    0xe3, 0xe4, 0xe5, 0xe6, 0xe7, 0xe8, 0xe9, 0xea, 0xeb, 0xec,
+    0xed, 0xee, 0xef, 0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6,
+    0xf8, 0xf9, 0xfa, 0xfb, 0xfc, 0xfd, 0xfe, 0x73, 0x73, 0x101,
+    0x103, 0x105, 0x107, 0x109, 0x10b, 0x10d, 0x10f, 0x111, 0x113, 0x115,
+    0x117, 0x119, 0x11b, 0x11d, 0x11f, 0x121, 0x123, 0x125, 0x127, 0x129,
+    0x12b, 0x12d, 0x12f, 0x69, 0x307, 0x133, 0x135, 0x137, 0x13a, 0x13c,
+    0x13e, 0x140, 0x142, 0x144, 0x146, 0x148, 0x2bc, 0x6e, 0x14b, 0x14d,
+    0x14f, 0x151, 0x153, 0x155, 0x157, 0x159, 0x15b, 0x15d, 0x15f, 0x161,
+    0x163, 0x165, 0x167, 0x169, 0x16b, 0x16d, 0x16f, 0x171, 0x173, 0x175,
+    0x177, 0xff, 0x17a, 0x17c, 0x17e, 0x73, 0x253, 0x183, 0x185, 0x254,
+    0x188, 0x256, 0x257, 0x18c, 0x1dd, 0x259, 0x25b, 0x192, 0x260, 0x263,
+    0x269, 0x268, 0x199, 0x26f, 0x272, 0x275, 0x1a1, 0x1a3, 0x1a5, 0x280,
+    0x1a8, 0x283, 0x1ad, 0x288, 0x1b0, 0x28a, 0x28b, 0x1b4, 0x1b6, 0x292,
+    0x1b9, 0x1bd, 0x1c6, 0x1c6, 0x1c9, 0x1c9, 0x1cc, 0x1cc, 0x1ce, 0x1d0,
+    0x1d2, 0x1d4, 0x1d6, 0x1d8, 0x1da, 0x1dc, 0x1df, 0x1e1, 0x1e3, 0x1e5,
+    0x1e7, 0x1e9, 0x1eb, 0x1ed, 0x1ef, 0x6a, 0x30c, 0x1f3, 0x1f3, 0x1f5,
+    0x195, 0x1bf, 0x1f9, 0x1fb, 0x1fd, 0x1ff, 0x201, 0x203, 0x205, 0x207,
+    0x209, 0x20b, 0x20d, 0x20f, 0x211, 0x213, 0x215, 0x217, 0x219, 0x21b,
+    0x21d, 0x21f, 0x19e, 0x223, 0x225, 0x227, 0x229, 0x22b, 0x22d, 0x22f,
+    0x231, 0x233, 0x2c65, 0x23c, 0x19a, 0x2c66, 0x242, 0x180, 0x289, 0x28c,
+    0x247, 0x249, 0x24b, 0x24d, 0x24f, 0x3b9, 0x371, 0x373, 0x377, 0x3f3,
+    0x3ac, 0x3ad, 0x3ae, 0x3af, 0x3cc, 0x3cd, 0x3ce, 0x3b9, 0x308, 0x301,
+    0x3b1, 0x3b2, 0x3b3, 0x3b4, 0x3b5, 0x3b6, 0x3b7, 0x3b8, 0x3b9, 0x3ba,
+    0x3bb, 0x3bc, 0x3bd, 0x3be, 0x3bf, 0x3c0, 0x3c1, 0x3c3, 0x3c4, 0x3c5,
+    0x3c6, 0x3c7, 0x3c8, 0x3c9, 0x3ca, 0x3cb, 0x3c5, 0x308, 0x301, 0x3c3,
+    0x3d7, 0x3b2, 0x3b8, 0x3c6, 0x3c0, 0x3d9, 0x3db, 0x3dd, 0x3df, 0x3e1,
+    0x3e3, 0x3e5, 0x3e7, 0x3e9, 0x3eb, 0x3ed, 0x3ef, 0x3ba, 0x3c1, 0x3b8,
+    0x3b5, 0x3f8, 0x3f2, 0x3fb, 0x37b, 0x37c, 0x37d, 0x450, 0x451, 0x452,
+    0x453, 0x454, 0x455, 0x456, 0x457, 0x458, 0x459, 0x45a, 0x45b, 0x45c,
+    0x45d, 0x45e, 0x45f, 0x430, 0x431, 0x432, 0x433, 0x434, 0x435, 0x436,
+    0x437, 0x438, 0x439, 0x43a, 0x43b, 0x43c, 0x43d, 0x43e, 0x43f, 0x440,
+    0x441, 0x442, 0x443, 0x444, 0x445, 0x446, 0x447, 0x448, 0x449, 0x44a,
+    0x44b, 0x44c, 0x44d, 0x44e, 0x44f, 0x461, 0x463, 0x465, 0x467, 0x469,
+    0x46b, 0x46d, 0x46f, 0x471, 0x473, 0x475, 0x477, 0x479, 0x47b, 0x47d,
+    0x47f, 0x481, 0x48b, 0x48d, 0x48f, 0x491, 0x493, 0x495, 0x497, 0x499,
+    0x49b, 0x49d, 0x49f, 0x4a1, 0x4a3, 0x4a5, 0x4a7, 0x4a9, 0x4ab, 0x4ad,
+    0x4af, 0x4b1, 0x4b3, 0x4b5, 0x4b7, 0x4b9, 0x4bb, 0x4bd, 0x4bf, 0x4cf,
+    0x4c2, 0x4c4, 0x4c6, 0x4c8, 0x4ca, 0x4cc, 0x4ce, 0x4d1, 0x4d3, 0x4d5,
+    0x4d7, 0x4d9, 0x4db, 0x4dd, 0x4df, 0x4e1, 0x4e3, 0x4e5, 0x4e7, 0x4e9,
+    0x4eb, 0x4ed, 0x4ef, 0x4f1, 0x4f3, 0x4f5, 0x4f7, 0x4f9, 0x4fb, 0x4fd,
+    0x4ff, 0x501, 0x503, 0x505, 0x507, 0x509, 0x50b, 0x50d, 0x50f, 0x511,
+    0x513, 0x515, 0x517, 0x519, 0x51b, 0x51d, 0x51f, 0x521, 0x523, 0x525,
+    0x527, 0x529, 0x52b, 0x52d, 0x52f, 0x561, 0x562, 0x563, 0x564, 0x565,
+    0x566, 0x567, 0x568, 0x569, 0x56a, 0x56b, 0x56c, 0x56d, 0x56e, 0x56f,
+    0x570, 0x571, 0x572, 0x573, 0x574, 0x575, 0x576, 0x577, 0x578, 0x579,
+    0x57a, 0x57b, 0x57c, 0x57d, 0x57e, 0x57f, 0x580, 0x581, 0x582, 0x583,
+    0x584, 0x585, 0x586, 0x565, 0x582, 0x2d00, 0x2d01, 0x2d02, 0x2d03, 0x2d04,
+    0x2d05, 0x2d06, 0x2d07, 0x2d08, 0x2d09, 0x2d0a, 0x2d0b, 0x2d0c, 0x2d0d, 0x2d0e,
+    0x2d0f, 0x2d10, 0x2d11, 0x2d12, 0x2d13, 0x2d14, 0x2d15, 0x2d16, 0x2d17, 0x2d18,
+    0x2d19, 0x2d1a, 0x2d1b, 0x2d1c, 0x2d1d, 0x2d1e, 0x2d1f, 0x2d20, 0x2d21, 0x2d22,
+    0x2d23, 0x2d24, 0x2d25, 0x2d27, 0x2d2d, 0x13f0, 0x13f1, 0x13f2, 0x13f3, 0x13f4,
+    0x13f5, 0x432, 0x434, 0x43e, 0x441, 0x442, 0x442, 0x44a, 0x463, 0xa64b,
+    0x10d0, 0x10d1, 0x10d2, 0x10d3, 0x10d4, 0x10d5, 0x10d6, 0x10d7, 0x10d8, 0x10d9,
+    0x10da, 0x10db, 0x10dc, 0x10dd, 0x10de, 0x10df, 0x10e0, 0x10e1, 0x10e2, 0x10e3,
+    0x10e4, 0x10e5, 0x10e6, 0x10e7, 0x10e8, 0x10e9, 0x10ea, 0x10eb, 0x10ec, 0x10ed,
+    0x10ee, 0x10ef, 0x10f0, 0x10f1, 0x10f2, 0x10f3, 0x10f4, 0x10f5, 0x10f6, 0x10f7,
+    0x10f8, 0x10f9, 0x10fa, 0x10fd, 0x10fe, 0x10ff, 0x1e01, 0x1e03, 0x1e05, 0x1e07,
+    0x1e09, 0x1e0b, 0x1e0d, 0x1e0f, 0x1e11, 0x1e13, 0x1e15, 0x1e17, 0x1e19, 0x1e1b,
+    0x1e1d, 0x1e1f, 0x1e21, 0x1e23, 0x1e25, 0x1e27, 0x1e29, 0x1e2b, 0x1e2d, 0x1e2f,
+    0x1e31, 0x1e33, 0x1e35, 0x1e37, 0x1e39, 0x1e3b, 0x1e3d, 0x1e3f, 0x1e41, 0x1e43,
+    0x1e45, 0x1e47, 0x1e49, 0x1e4b, 0x1e4d, 0x1e4f, 0x1e51, 0x1e53, 0x1e55, 0x1e57,
+    0x1e59, 0x1e5b, 0x1e5d, 0x1e5f, 0x1e61, 0x1e63, 0x1e65, 0x1e67, 0x1e69, 0x1e6b,
+    0x1e6d, 0x1e6f, 0x1e71, 0x1e73, 0x1e75, 0x1e77, 0x1e79, 0x1e7b, 0x1e7d, 0x1e7f,
+    0x1e81, 0x1e83, 0x1e85, 0x1e87, 0x1e89, 0x1e8b, 0x1e8d, 0x1e8f, 0x1e91, 0x1e93,
+    0x1e95, 0x68, 0x331, 0x74, 0x308, 0x77, 0x30a, 0x79, 0x30a, 0x61,
+    0x2be, 0x1e61, 0x73, 0x73, 0x1ea1, 0x1ea3, 0x1ea5, 0x1ea7, 0x1ea9, 0x1eab,
+    0x1ead, 0x1eaf, 0x1eb1, 0x1eb3, 0x1eb5, 0x1eb7, 0x1eb9, 0x1ebb, 0x1ebd, 0x1ebf,
+    0x1ec1, 0x1ec3, 0x1ec5, 0x1ec7, 0x1ec9, 0x1ecb, 0x1ecd, 0x1ecf, 0x1ed1, 0x1ed3,
+    0x1ed5, 0x1ed7, 0x1ed9, 0x1edb, 0x1edd, 0x1edf, 0x1ee1, 0x1ee3, 0x1ee5, 0x1ee7,
+    0x1ee9, 0x1eeb, 0x1eed, 0x1eef, 0x1ef1, 0x1ef3, 0x1ef5, 0x1ef7, 0x1ef9, 0x1efb,
+    0x1efd, 0x1eff, 0x1f00, 0x1f01, 0x1f02, 0x1f03, 0x1f04, 0x1f05, 0x1f06, 0x1f07,
+    0x1f10, 0x1f11, 0x1f12, 0x1f13, 0x1f14, 0x1f15, 0x1f20, 0x1f21, 0x1f22, 0x1f23,
+    0x1f24, 0x1f25, 0x1f26, 0x1f27, 0x1f30, 0x1f31, 0x1f32, 0x1f33, 0x1f34, 0x1f35,
+    0x1f36, 0x1f37, 0x1f40, 0x1f41, 0x1f42, 0x1f43, 0x1f44, 0x1f45, 0x3c5, 0x313,
+    0x3c5, 0x313, 0x300, 0x3c5, 0x313, 0x301, 0x3c5, 0x313, 0x342, 0x1f51,
+    0x1f53, 0x1f55, 0x1f57, 0x1f60, 0x1f61, 0x1f62, 0x1f63, 0x1f64, 0x1f65, 0x1f66,
+    0x1f67, 0x1f00, 0x3b9, 0x1f01, 0x3b9, 0x1f02, 0x3b9, 0x1f03, 0x3b9, 0x1f04,
+    0x3b9, 0x1f05, 0x3b9, 0x1f06, 0x3b9, 0x1f07, 0x3b9, 0x1f00, 0x3b9, 0x1f01,
+    0x3b9, 0x1f02, 0x3b9, 0x1f03, 0x3b9, 0x1f04, 0x3b9, 0x1f05, 0x3b9, 0x1f06,
+    0x3b9, 0x1f07, 0x3b9, 0x1f20, 0x3b9, 0x1f21, 0x3b9, 0x1f22, 0x3b9, 0x1f23,
+    0x3b9, 0x1f24, 0x3b9, 0x1f25, 0x3b9, 0x1f26, 0x3b9, 0x1f27, 0x3b9, 0x1f20,
+    0x3b9, 0x1f21, 0x3b9, 0x1f22, 0x3b9, 0x1f23, 0x3b9, 0x1f24, 0x3b9, 0x1f25,
+    0x3b9, 0x1f26, 0x3b9, 0x1f27, 0x3b9, 0x1f60, 0x3b9, 0x1f61, 0x3b9, 0x1f62,
+    0x3b9, 0x1f63, 0x3b9, 0x1f64, 0x3b9, 0x1f65, 0x3b9, 0x1f66, 0x3b9, 0x1f67,
+    0x3b9, 0x1f60, 0x3b9, 0x1f61, 0x3b9, 0x1f62, 0x3b9, 0x1f63, 0x3b9, 0x1f64,
+    0x3b9, 0x1f65, 0x3b9, 0x1f66, 0x3b9, 0x1f67, 0x3b9, 0x1f70, 0x3b9, 0x3b1,
+    0x3b9, 0x3ac, 0x3b9, 0x3b1, 0x342, 0x3b1, 0x342, 0x3b9, 0x1fb0, 0x1fb1,
+    0x1f70, 0x1f71, 0x3b1, 0x3b9, 0x3b9, 0x1f74, 0x3b9, 0x3b7, 0x3b9, 0x3ae,
+    0x3b9, 0x3b7, 0x342, 0x3b7, 0x342, 0x3b9, 0x1f72, 0x1f73, 0x1f74, 0x1f75,
+    0x3b7, 0x3b9, 0x3b9, 0x308, 0x300, 0x3b9, 0x308, 0x301, 0x3b9, 0x342,
+    0x3b9, 0x308, 0x342, 0x1fd0, 0x1fd1, 0x1f76, 0x1f77, 0x3c5, 0x308, 0x300,
+    0x3c5, 0x308, 0x301, 0x3c1, 0x313, 0x3c5, 0x342, 0x3c5, 0x308, 0x342,
+    0x1fe0, 0x1fe1, 0x1f7a, 0x1f7b, 0x1fe5, 0x1f7c, 0x3b9, 0x3c9, 0x3b9, 0x3ce,
+    0x3b9, 0x3c9, 0x342, 0x3c9, 0x342, 0x3b9, 0x1f78, 0x1f79, 0x1f7c, 0x1f7d,
+    0x3c9, 0x3b9, 0x3c9, 0x6b, 0xe5, 0x214e, 0x2170, 0x2171, 0x2172, 0x2173,
+    0x2174, 0x2175, 0x2176, 0x2177, 0x2178, 0x2179, 0x217a, 0x217b, 0x217c, 0x217d,
+    0x217e, 0x217f, 0x2184, 0x24d0, 0x24d1, 0x24d2, 0x24d3, 0x24d4, 0x24d5, 0x24d6,
+    0x24d7, 0x24d8, 0x24d9, 0x24da, 0x24db, 0x24dc, 0x24dd, 0x24de, 0x24df, 0x24e0,
+    0x24e1, 0x24e2, 0x24e3, 0x24e4, 0x24e5, 0x24e6, 0x24e7, 0x24e8, 0x24e9, 0x2c30,
+    0x2c31, 0x2c32, 0x2c33, 0x2c34, 0x2c35, 0x2c36, 0x2c37, 0x2c38, 0x2c39, 0x2c3a,
+    0x2c3b, 0x2c3c, 0x2c3d, 0x2c3e, 0x2c3f, 0x2c40, 0x2c41, 0x2c42, 0x2c43, 0x2c44,
+    0x2c45, 0x2c46, 0x2c47, 0x2c48, 0x2c49, 0x2c4a, 0x2c4b, 0x2c4c, 0x2c4d, 0x2c4e,
+    0x2c4f, 0x2c50, 0x2c51, 0x2c52, 0x2c53, 0x2c54, 0x2c55, 0x2c56, 0x2c57, 0x2c58,
+    0x2c59, 0x2c5a, 0x2c5b, 0x2c5c, 0x2c5d, 0x2c5e, 0x2c5f, 0x2c61, 0x26b, 0x1d7d,
+    0x27d, 0x2c68, 0x2c6a, 0x2c6c, 0x251, 0x271, 0x250, 0x252, 0x2c73, 0x2c76,
+    0x23f, 0x240, 0x2c81, 0x2c83, 0x2c85, 0x2c87, 0x2c89, 0x2c8b, 0x2c8d, 0x2c8f,
+    0x2c91, 0x2c93, 0x2c95, 0x2c97, 0x2c99, 0x2c9b, 0x2c9d, 0x2c9f, 0x2ca1, 0x2ca3,
+    0x2ca5, 0x2ca7, 0x2ca9, 0x2cab, 0x2cad, 0x2caf, 0x2cb1, 0x2cb3, 0x2cb5, 0x2cb7,
+    0x2cb9, 0x2cbb, 0x2cbd, 0x2cbf, 0x2cc1, 0x2cc3, 0x2cc5, 0x2cc7, 0x2cc9, 0x2ccb,
+    0x2ccd, 0x2ccf, 0x2cd1, 0x2cd3, 0x2cd5, 0x2cd7, 0x2cd9, 0x2cdb, 0x2cdd, 0x2cdf,
+    0x2ce1, 0x2ce3, 0x2cec, 0x2cee, 0x2cf3, 0xa641, 0xa643, 0xa645, 0xa647, 0xa649,
+    0xa64b, 0xa64d, 0xa64f, 0xa651, 0xa653, 0xa655, 0xa657, 0xa659, 0xa65b, 0xa65d,
+    0xa65f, 0xa661, 0xa663, 0xa665, 0xa667, 0xa669, 0xa66b, 0xa66d, 0xa681, 0xa683,
+    0xa685, 0xa687, 0xa689, 0xa68b, 0xa68d, 0xa68f, 0xa691, 0xa693, 0xa695, 0xa697,
+    0xa699, 0xa69b, 0xa723, 0xa725, 0xa727, 0xa729, 0xa72b, 0xa72d, 0xa72f, 0xa733,
+    0xa735, 0xa737, 0xa739, 0xa73b, 0xa73d, 0xa73f, 0xa741, 0xa743, 0xa745, 0xa747,
+    0xa749, 0xa74b, 0xa74d, 0xa74f, 0xa751, 0xa753, 0xa755, 0xa757, 0xa759, 0xa75b,
+    0xa75d, 0xa75f, 0xa761, 0xa763, 0xa765, 0xa767, 0xa769, 0xa76b, 0xa76d, 0xa76f,
+    0xa77a, 0xa77c, 0x1d79, 0xa77f, 0xa781, 0xa783, 0xa785, 0xa787, 0xa78c, 0x265,
+    0xa791, 0xa793, 0xa797, 0xa799, 0xa79b, 0xa79d, 0xa79f, 0xa7a1, 0xa7a3, 0xa7a5,
+    0xa7a7, 0xa7a9, 0x266, 0x25c, 0x261, 0x26c, 0x26a, 0x29e, 0x287, 0x29d,
+    0xab53, 0xa7b5, 0xa7b7, 0xa7b9, 0xa7bb, 0xa7bd, 0xa7bf, 0xa7c1, 0xa7c3, 0xa794,
+    0x282, 0x1d8e, 0xa7c8, 0xa7ca, 0xa7d1, 0xa7d7, 0xa7d9, 0xa7f6, 0x13a0, 0x13a1,
+    0x13a2, 0x13a3, 0x13a4, 0x13a5, 0x13a6, 0x13a7, 0x13a8, 0x13a9, 0x13aa, 0x13ab,
+    0x13ac, 0x13ad, 0x13ae, 0x13af, 0x13b0, 0x13b1, 0x13b2, 0x13b3, 0x13b4, 0x13b5,
+    0x13b6, 0x13b7, 0x13b8, 0x13b9, 0x13ba, 0x13bb, 0x13bc, 0x13bd, 0x13be, 0x13bf,
+    0x13c0, 0x13c1, 0x13c2, 0x13c3, 0x13c4, 0x13c5, 0x13c6, 0x13c7, 0x13c8, 0x13c9,
+    0x13ca, 0x13cb, 0x13cc, 0x13cd, 0x13ce, 0x13cf, 0x13d0, 0x13d1, 0x13d2, 0x13d3,
+    0x13d4, 0x13d5, 0x13d6, 0x13d7, 0x13d8, 0x13d9, 0x13da, 0x13db, 0x13dc, 0x13dd,
+    0x13de, 0x13df, 0x13e0, 0x13e1, 0x13e2, 0x13e3, 0x13e4, 0x13e5, 0x13e6, 0x13e7,
+    0x13e8, 0x13e9, 0x13ea, 0x13eb, 0x13ec, 0x13ed, 0x13ee, 0x13ef, 0x66, 0x66,
+    0x66, 0x69, 0x66, 0x6c, 0x66, 0x66, 0x69, 0x66, 0x66, 0x6c,
+    0x73, 0x74, 0x73, 0x74, 0x574, 0x576, 0x574, 0x565, 0x574, 0x56b,
+    0x57e, 0x576, 0x574, 0x56d, 0xff41, 0xff42, 0xff43, 0xff44, 0xff45, 0xff46,
+    0xff47, 0xff48, 0xff49, 0xff4a, 0xff4b, 0xff4c, 0xff4d, 0xff4e, 0xff4f, 0xff50,
+    0xff51, 0xff52, 0xff53, 0xff54, 0xff55, 0xff56, 0xff57, 0xff58, 0xff59, 0xff5a,
+    0x10428, 0x10429, 0x1042a, 0x1042b, 0x1042c, 0x1042d, 0x1042e, 0x1042f, 0x10430, 0x10431,
+    0x10432, 0x10433, 0x10434, 0x10435, 0x10436, 0x10437, 0x10438, 0x10439, 0x1043a, 0x1043b,
+    0x1043c, 0x1043d, 0x1043e, 0x1043f, 0x10440, 0x10441, 0x10442, 0x10443, 0x10444, 0x10445,
+    0x10446, 0x10447, 0x10448, 0x10449, 0x1044a, 0x1044b, 0x1044c, 0x1044d, 0x1044e, 0x1044f,
+    0x104d8, 0x104d9, 0x104da, 0x104db, 0x104dc, 0x104dd, 0x104de, 0x104df, 0x104e0, 0x104e1,
+    0x104e2, 0x104e3, 0x104e4, 0x104e5, 0x104e6, 0x104e7, 0x104e8, 0x104e9, 0x104ea, 0x104eb,
+    0x104ec, 0x104ed, 0x104ee, 0x104ef, 0x104f0, 0x104f1, 0x104f2, 0x104f3, 0x104f4, 0x104f5,
+    0x104f6, 0x104f7, 0x104f8, 0x104f9, 0x104fa, 0x104fb, 0x10597, 0x10598, 0x10599, 0x1059a,
+    0x1059b, 0x1059c, 0x1059d, 0x1059e, 0x1059f, 0x105a0, 0x105a1, 0x105a3, 0x105a4, 0x105a5,
+    0x105a6, 0x105a7, 0x105a8, 0x105a9, 0x105aa, 0x105ab, 0x105ac, 0x105ad, 0x105ae, 0x105af,
+    0x105b0, 0x105b1, 0x105b3, 0x105b4, 0x105b5, 0x105b6, 0x105b7, 0x105b8, 0x105b9, 0x105bb,
+    0x105bc, 0x10cc0, 0x10cc1, 0x10cc2, 0x10cc3, 0x10cc4, 0x10cc5, 0x10cc6, 0x10cc7, 0x10cc8,
+    0x10cc9, 0x10cca, 0x10ccb, 0x10ccc, 0x10ccd, 0x10cce, 0x10ccf, 0x10cd0, 0x10cd1, 0x10cd2,
+    0x10cd3, 0x10cd4, 0x10cd5, 0x10cd6, 0x10cd7, 0x10cd8, 0x10cd9, 0x10cda, 0x10cdb, 0x10cdc,
+    0x10cdd, 0x10cde, 0x10cdf, 0x10ce0, 0x10ce1, 0x10ce2, 0x10ce3, 0x10ce4, 0x10ce5, 0x10ce6,
+    0x10ce7, 0x10ce8, 0x10ce9, 0x10cea, 0x10ceb, 0x10cec, 0x10ced, 0x10cee, 0x10cef, 0x10cf0,
+    0x10cf1, 0x10cf2, 0x118c0, 0x118c1, 0x118c2, 0x118c3, 0x118c4, 0x118c5, 0x118c6, 0x118c7,
+    0x118c8, 0x118c9, 0x118ca, 0x118cb, 0x118cc, 0x118cd, 0x118ce, 0x118cf, 0x118d0, 0x118d1,
+    0x118d2, 0x118d3, 0x118d4, 0x118d5, 0x118d6, 0x118d7, 0x118d8, 0x118d9, 0x118da, 0x118db,
+    0x118dc, 0x118dd, 0x118de, 0x118df, 0x16e60, 0x16e61, 0x16e62, 0x16e63, 0x16e64, 0x16e65,
+    0x16e66, 0x16e67, 0x16e68, 0x16e69, 0x16e6a, 0x16e6b, 0x16e6c, 0x16e6d, 0x16e6e, 0x16e6f,
+    0x16e70, 0x16e71, 0x16e72, 0x16e73, 0x16e74, 0x16e75, 0x16e76, 0x16e77, 0x16e78, 0x16e79,
+    0x16e7a, 0x16e7b, 0x16e7c, 0x16e7d, 0x16e7e, 0x16e7f, 0x1e922, 0x1e923, 0x1e924, 0x1e925,
+    0x1e926, 0x1e927, 0x1e928, 0x1e929, 0x1e92a, 0x1e92b, 0x1e92c, 0x1e92d, 0x1e92e, 0x1e92f,
+    0x1e930, 0x1e931, 0x1e932, 0x1e933, 0x1e934, 0x1e935, 0x1e936, 0x1e937, 0x1e938, 0x1e939,
+    0x1e93a, 0x1e93b, 0x1e93c, 0x1e93d, 0x1e93e, 0x1e93f, 0x1e940, 0x1e941, 0x1e942, 0x1e943,
+];
+
+/// Prototypes of confusable characters in NFD
+///
+/// Each entry is a code point with the offset and length of its mapping in `CONFUSABLES_DATA`.
+pub(crate) const CONFUSABLES: &[(u32, u16, u8)] = &[
+    (0x22, 0, 2), (0x25, 2, 3), (0x30, 5, 1), (0x31, 6, 1), (0x49, 7, 1),
+    (0x60, 8, 1), (0x6d, 9, 2), (0x7c, 11, 1), (0xa0, 12, 1), (0xa2, 13, 2),
+    (0xa5, 15, 2), (0xaf, 17, 1), (0xb4, 18, 1), (0xb5, 19, 1), (0xb8, 20, 1),
+    (0xc6, 21, 2), (0xc7, 23, 2), (0xd0, 25, 2), (0xd7, 27, 1), (0xd8, 28, 2),
+    (0xe6, 30, 2), (0xe7, 32, 2), (0xf0, 34, 2), (0xf6, 36, 1), (0xf8, 37, 2),
+    (0x110, 39, 2), (0x111, 41, 2), (0x11a, 43, 2), (0x11b, 45, 2), (0x126, 47, 2),
+    (0x127, 49, 2), (0x131, 51, 1), (0x132, 52, 2), (0x133, 54, 2), (0x13f, 56, 2),
+    (0x140, 58, 2), (0x141, 60, 2), (0x142, 62, 2), (0x146, 64, 1), (0x149, 65, 2),
+    (0x150, 67, 2), (0x152, 69, 2), (0x153, 71, 2), (0x163, 73, 1), (0x166, 74, 2),
+    (0x167, 76, 2), (0x17f, 78, 1), (0x180, 79, 2), (0x181, 81, 2), (0x182, 83, 2),
+    (0x183, 85, 2), (0x184, 87, 1), (0x187, 88, 2), (0x189, 90, 2), (0x18a, 92, 2),
+    (0x18c, 94, 2), (0x18d, 96, 1), (0x191, 97, 2), (0x192, 99, 2), (0x193, 101, 2),
+    (0x196, 103, 1), (0x197, 104, 2), (0x198, 106, 2), (0x199, 108, 2), (0x19a, 110, 2),
+    (0x19d, 112, 2), (0x19e, 114, 2), (0x19f, 116, 2), (0x1a0, 118, 2), (0x1a1, 120, 2),
+    (0x1a4, 122, 2), (0x1a5, 124, 2), (0x1a6, 126, 1), (0x1a7, 127, 1), (0x1ac, 128, 2),
+    (0x1ad, 130, 2), (0x1ae, 132, 2), (0x1b3, 134, 2), (0x1b4, 136, 2), (0x1b5, 138, 2),
+    (0x1b6, 140, 2), (0x1b7, 142, 1), (0x1bb, 143, 2), (0x1bc, 145, 1), (0x1bd, 146, 1),
+    (0x1bf, 147, 1), (0x1c0, 148, 1), (0x1c1, 149, 2), (0x1c3, 151, 1), (0x1c4, 152, 3),
+    (0x1c5, 155, 3), (0x1c6, 158, 3), (0x1c7, 161, 2), (0x1c8, 163, 2), (0x1c9, 165, 2),
+    (0x1ca, 167, 2), (0x1cb, 169, 2), (0x1cc, 171, 2), (0x1cd, 173, 2), (0x1ce, 175, 2),
+    (0x1cf, 177, 2), (0x1d0, 179, 2), (0x1d1, 181, 2), (0x1d2, 183, 2), (0x1d3, 185, 2),
+    (0x1d4, 187, 2), (0x1e4, 189, 2), (0x1e5, 191, 2), (0x1e6, 193, 2), (0x1e7, 195, 2),
+    (0x1f1, 197, 2), (0x1f2, 199, 2), (0x1f3, 201, 2), (0x1f5, 203, 2), (0x1fe, 205, 3),
+    (0x21a, 208, 2), (0x21b, 210, 1), (0x21c, 211, 1), (0x222, 212, 1), (0x223, 213, 1),
+    (0x224, 214, 2), (0x225, 216, 2), (0x226, 218, 2), (0x227, 220, 2), (0x23c, 222, 2),
+    (0x23e, 224, 2), (0x241, 226, 1), (0x244, 227, 2), (0x246, 229, 2), (0x247, 231, 2),
+    (0x248, 233, 2), (0x249, 235, 2), (0x24d, 237, 2), (0x24e, 239, 2), (0x24f, 241, 2),
+    (0x251, 243, 1), (0x253, 244, 2), (0x256, 246, 2), (0x257, 248, 2), (0x259, 250, 1),
+    (0x25a, 251, 2), (0x25b, 253, 1), (0x260, 254, 2), (0x261, 256, 1), (0x263, 257, 1),
+    (0x266, 258, 2), (0x268, 260, 2), (0x269, 262, 1), (0x26a, 263, 1), (0x26b, 264, 2),
+    (0x26d, 266, 2), (0x26e, 268, 2), (0x26f, 270, 1), (0x271, 271, 3), (0x273, 274, 2),
+    (0x275, 276, 2), (0x276, 278, 2), (0x27c, 280, 2), (0x27d, 282, 2), (0x282, 284, 2),
+    (0x28b, 286, 1), (0x28f, 287, 1), (0x290, 288, 2), (0x292, 290, 1), (0x294, 291, 1),
+    (0x2a0, 292, 2), (0x2a3, 294, 2), (0x2a4, 296, 2), (0x2a5, 298, 2), (0x2a6, 300, 2),
+    (0x2a7, 302, 2), (0x2a8, 304, 2), (0x2a9, 306, 2), (0x2aa, 308, 2), (0x2ab, 310, 2),
+    (0x2b3, 312, 1), (0x2b9, 313, 1), (0x2ba, 314, 2), (0x2bb, 316, 1), (0x2bc, 317, 1),
+    (0x2bd, 318, 1), (0x2be, 319, 1), (0x2bf, 320, 1), (0x2c2, 321, 1), (0x2c3, 322, 1),
+    (0x2c4, 323, 1), (0x2c6, 324, 1), (0x2c8, 325, 1), (0x2ca, 326, 1), (0x2cb, 327, 1),
+    (0x2d0, 328, 1), (0x2d3, 329, 1), (0x2d7, 330, 1), (0x2d8, 331, 1), (0x2d9, 332, 1),
+    (0x2da, 333, 1), (0x2db, 334, 1), (0x2dc, 335, 1), (0x2dd, 336, 2), (0x2e1, 338, 1),
+    (0x2e2, 339, 1), (0x2e4, 340, 1), (0x2ee, 341, 2), (0x2f4, 343, 1), (0x2f6, 344, 2),
+    (0x2f8, 346, 1), (0x2fb, 347, 1), (0x305, 348, 1), (0x30c, 349, 1), (0x30d, 350, 1),
+    (0x310, 351, 2), (0x311, 353, 1), (0x315, 354, 1), (0x317, 355, 1), (0x320, 356, 1),
+    (0x321, 357, 1), (0x322, 358, 1), (0x327, 359, 1), (0x336, 360, 1), (0x337, 361, 1),
+    (0x339, 362, 1), (0x340, 363, 1), (0x341, 364, 1), (0x342, 365, 1), (0x343, 366, 1),
+    (0x345, 367, 1), (0x347, 368, 1), (0x357, 369, 1), (0x358, 370, 1), (0x366, 371, 1),
+    (0x36e, 372, 1), (0x370, 373, 1), (0x374, 374, 1), (0x375, 375, 1), (0x376, 376, 1),
+    (0x377, 377, 1), (0x37a, 378, 1), (0x37b, 379, 1), (0x37d, 380, 1), (0x37e, 381, 1),
+    (0x37f, 382, 1), (0x384, 383, 1), (0x387, 384, 1), (0x391, 385, 1), (0x392, 386, 1),
+    (0x395, 387, 1), (0x396, 388, 1), (0x397, 389, 1), (0x398, 390, 2), (0x399, 392, 1),
+    (0x39a, 393, 1), (0x39b, 394, 1), (0x39c, 395, 1), (0x39d, 396, 1), (0x39f, 397, 1),
+    (0x3a1, 398, 1), (0x3a3, 399, 1), (0x3a4, 400, 1), (0x3a5, 401, 1), (0x3a7, 402, 1),
+    (0x3b1, 403, 1), (0x3b2, 404, 1), (0x3b3, 405, 1), (0x3b4, 406, 1), (0x3b5, 407, 1),
+    (0x3b7, 408, 2), (0x3b8, 410, 2), (0x3b9, 412, 1), (0x3ba, 413, 1), (0x3bd, 414, 1),
+    (0x3bf, 415, 1), (0x3c1, 416, 1), (0x3c3, 417, 1), (0x3c4, 418, 1), (0x3c5, 419, 1),
+    (0x3c6, 420, 1), (0x3d0, 421, 1), (0x3d1, 422, 2), (0x3d2, 424, 1), (0x3d5, 425, 1),
+    (0x3d6, 426, 1), (0x3db, 427, 1), (0x3dc, 428, 1), (0x3e8, 429, 1), (0x3e9, 430, 1),
+    (0x3f0, 431, 1), (0x3f1, 432, 1), (0x3f2, 433, 1), (0x3f3, 434, 1), (0x3f4, 435, 2),
+    (0x3f5, 437, 1), (0x3f7, 438, 1), (0x3f8, 439, 1), (0x3f9, 440, 1), (0x3fa, 441, 1),
+    (0x3fd, 442, 1), (0x3ff, 443, 1), (0x404, 444, 1), (0x405, 445, 1), (0x406, 446, 1),
+    (0x408, 447, 1), (0x410, 448, 1), (0x411, 449, 2), (0x412, 451, 1), (0x413, 452, 1),
+    (0x415, 453, 1), (0x417, 454, 1), (0x419, 455, 2), (0x41a, 457, 1), (0x41b, 458, 1),
+    (0x41c, 459, 1), (0x41d, 460, 1), (0x41e, 461, 1), (0x41f, 462, 1), (0x420, 463, 1),
+    (0x421, 464, 1), (0x422, 465, 1), (0x423, 466, 1), (0x424, 467, 1), (0x425, 468, 1),
+    (0x42b, 469, 2), (0x42c, 471, 1), (0x42e, 472, 2), (0x430, 474, 1), (0x431, 475, 1),
+    (0x432, 476, 1), (0x433, 477, 1), (0x435, 478, 1), (0x437, 479, 1), (0x438, 480, 1),
+    (0x43a, 481, 1), (0x43c, 482, 1), (0x43d, 483, 1), (0x43e, 484, 1), (0x43f, 485, 1),
+    (0x440, 486, 1), (0x441, 487, 1), (0x442, 488, 1), (0x443, 489, 1), (0x444, 490, 1),
+    (0x445, 491, 1), (0x44a, 492, 2), (0x44b, 494, 2), (0x44c, 496, 1), (0x44f, 497, 1),
+    (0x454, 498, 1), (0x455, 499, 1), (0x456, 500, 1), (0x458, 501, 1), (0x45b, 502, 2),
+    (0x45d, 504, 2), (0x461, 506, 1), (0x462, 507, 2), (0x463, 509, 2), (0x470, 511, 1),
+    (0x471, 512, 1), (0x472, 513, 2), (0x473, 515, 2), (0x474, 517, 1), (0x475, 518, 1),
+    (0x47c, 519, 3), (0x47d, 522, 3), (0x48a, 525, 3), (0x48b, 528, 3), (0x48c, 531, 2),
+    (0x48d, 533, 2), (0x490, 535, 2), (0x491, 537, 2), (0x492, 539, 2), (0x493, 541, 2),
+    (0x496, 543, 2), (0x497, 545, 2), (0x498, 547, 2), (0x499, 549, 2), (0x49a, 551, 2),
+    (0x49b, 553, 2), (0x49e, 555, 2), (0x49f, 557, 2), (0x4a2, 559, 2), (0x4a3, 561, 2),
+    (0x4aa, 563, 2), (0x4ab, 565, 2), (0x4ac, 567, 2), (0x4ad, 569, 2), (0x4ae, 571, 1),
+    (0x4af, 572, 1), (0x4b0, 573, 2), (0x4b1, 575, 2), (0x4b2, 577, 2), (0x4bb, 579, 1),
+    (0x4bd, 580, 1), (0x4be, 581, 2), (0x4bf, 583, 2), (0x4c0, 585, 1), (0x4c5, 586, 2),
+    (0x4c6, 588, 2), (0x4c7, 590, 2), (0x4c8, 592, 2), (0x4c9, 594, 2), (0x4ca, 596, 2),
+    (0x4cb, 598, 1), (0x4cc, 599, 1), (0x4cd, 600, 2), (0x4ce, 602, 2), (0x4cf, 604, 1),
+    (0x4d4, 605, 2), (0x4d5, 607, 2), (0x4d8, 609, 1), (0x4d9, 610, 1), (0x4e0, 611, 1),
+    (0x4e1, 612, 1), (0x4e8, 613, 2), (0x4e9, 615, 2), (0x501, 617, 1), (0x50a, 618, 1),
+    (0x50c, 619, 1), (0x50d, 620, 1), (0x510, 621, 1), (0x511, 622, 1), (0x51b, 623, 1),
+    (0x51c, 624, 1), (0x51d, 625, 1), (0x53b, 626, 1), (0x544, 627, 1), (0x54a, 628, 1),
+    (0x54c, 629, 1), (0x54d, 630, 1), (0x54f, 631, 1), (0x553, 632, 1), (0x555, 633, 1),
+    (0x55a, 634, 1), (0x55d, 635, 1), (0x561, 636, 1), (0x563, 637, 1), (0x566, 638, 1),
+    (0x56e, 639, 1), (0x570, 640, 1), (0x575, 641, 1), (0x578, 642, 1), (0x57a, 643, 1),
+    (0x57c, 644, 1), (0x57d, 645, 1), (0x581, 646, 1), (0x584, 647, 1), (0x585, 648, 1),
+    (0x587, 649, 2), (0x589, 651, 1), (0x59c, 652, 1), (0x59d, 653, 1), (0x5a4, 654, 1),
+    (0x5a8, 655, 1), (0x5ad, 656, 1), (0x5ae, 657, 1), (0x5af, 658, 1), (0x5b4, 659, 1),
+    (0x5b9, 660, 1), (0x5ba, 661, 1), (0x5c0, 662, 1), (0x5c1, 663, 1), (0x5c2, 664, 1),
+    (0x5c3, 665, 1), (0x5c4, 666, 1), (0x5c5, 667, 1), (0x5d5, 668, 1), (0x5d8, 669, 1),
+    (0x5d9, 670, 1), (0x5df, 671, 1), (0x5e1, 672, 1), (0x5f0, 673, 2), (0x5f1, 675, 2),
+    (0x5f2, 677, 2), (0x5f3, 679, 1), (0x5f4, 680, 2), (0x609, 682, 4), (0x60a, 686, 5),
+    (0x60d, 691, 1), (0x60f, 692, 1), (0x618, 693, 1), (0x619, 694, 1), (0x61a, 695, 1),
+    (0x623, 696, 2), (0x624, 698, 2), (0x625, 700, 2), (0x626, 702, 2), (0x627, 704, 1),
+    (0x62b, 705, 2), (0x634, 707, 2), (0x63d, 709, 2), (0x63f, 711, 2), (0x647, 713, 1),
+    (0x64a, 714, 1), (0x64b, 715, 1), (0x64e, 716, 1), (0x64f, 717, 1), (0x652, 718, 1),
+    (0x653, 719, 1), (0x656, 720, 1), (0x657, 721, 1), (0x658, 722, 1), (0x659, 723, 1),
+    (0x65a, 724, 1), (0x65b, 725, 1), (0x65c, 726, 1), (0x65d, 727, 1), (0x65f, 728, 1),
+    (0x660, 729, 1), (0x661, 730, 1), (0x665, 731, 1), (0x667, 732, 1), (0x668, 733, 1),
+    (0x66a, 734, 3), (0x66b, 737, 1), (0x66c, 738, 1), (0x66d, 739, 1), (0x66e, 740, 1),
+    (0x66f, 741, 1), (0x672, 742, 2), (0x673, 744, 2), (0x675, 746, 2), (0x676, 748, 2),
+    (0x677, 750, 3), (0x678, 753, 2), (0x679, 755, 2), (0x67e, 757, 2), (0x681, 759, 2),
+    (0x685, 761, 2), (0x688, 763, 2), (0x68b, 765, 2), (0x68e, 767, 2), (0x691, 769, 2),
+    (0x692, 771, 2), (0x698, 773, 2), (0x69e, 775, 2), (0x69f, 777, 2), (0x6a4, 779, 2),
+    (0x6a7, 781, 1), (0x6a8, 782, 2), (0x6a9, 784, 1), (0x6aa, 785, 1), (0x6ad, 786, 2),
+    (0x6b4, 788, 2), (0x6b5, 790, 2), (0x6b7, 792, 2), (0x6ba, 794, 1), (0x6bb, 795, 2),
+    (0x6bd, 797, 2), (0x6be, 799, 1), (0x6c1, 800, 1), (0x6c2, 801, 2), (0x6c3, 803, 1),
+    (0x6c6, 804, 2), (0x6c7, 806, 2), (0x6c8, 808, 2), (0x6c9, 810, 2), (0x6cb, 812, 2),
+    (0x6cc, 814, 1), (0x6ce, 815, 2), (0x6d0, 817, 1), (0x6d1, 818, 2), (0x6d2, 820, 1),
+    (0x6d4, 821, 1), (0x6d5, 822, 1), (0x6df, 823, 1), (0x6e8, 824, 2), (0x6ec, 826, 1),
+    (0x6ee, 827, 2), (0x6ef, 829, 2), (0x6f0, 831, 1), (0x6f1, 832, 1), (0x6f2, 833, 1),
+    (0x6f3, 834, 1), (0x6f4, 835, 1), (0x6f5, 836, 1), (0x6f6, 837, 1), (0x6f7, 838, 1),
+    (0x6f8, 839, 1), (0x6f9, 840, 1), (0x6fd, 841, 2), (0x6fe, 843, 2), (0x6ff, 845, 2),
+    (0x701, 847, 1), (0x702, 848, 1), (0x703, 849, 1), (0x704, 850, 1), (0x740, 851, 1),
+    (0x741, 852, 1), (0x742, 853, 1), (0x747, 854, 1), (0x751, 855, 2), (0x756, 857, 2),
+    (0x762, 859, 1), (0x763, 860, 2), (0x767, 862, 1), (0x768, 863, 2), (0x769, 865, 2),
+    (0x76c, 867, 2), (0x771, 869, 2), (0x772, 871, 2), (0x77e, 873, 2), (0x7c0, 875, 1),
+    (0x7ca, 876, 1), (0x7eb, 877, 1), (0x7ed, 878, 1), (0x7ee, 879, 1), (0x7f3, 880, 1),
+    (0x7f4, 881, 1), (0x7f5, 882, 1), (0x7fa, 883, 1), (0x8a1, 884, 2), (0x8a4, 886, 2),
+    (0x8a7, 888, 2), (0x8a8, 890, 2), (0x8a9, 892, 1), (0x8ae, 893, 3), (0x8af, 896, 3),
+    (0x8b0, 899, 1), (0x8b1, 900, 1), (0x8b2, 901, 2), (0x8b6, 903, 2), (0x8b7, 905, 3),
+    (0x8b9, 908, 3), (0x8ba, 911, 3), (0x8bb, 914, 1), (0x8bc, 915, 1), (0x8bd, 916, 1),
+    (0x8e5, 917, 1), (0x8e8, 918, 1), (0x8ea, 919, 1), (0x8eb, 920, 1), (0x8ed, 921, 1),
+    (0x8ee, 922, 1), (0x8f0, 923, 1), (0x8f1, 924, 1), (0x8f2, 925, 1), (0x8f3, 926, 1),
+    (0x8f8, 927, 1), (0x8f9, 928, 1), (0x8fa, 929, 1), (0x8ff, 930, 1), (0x900, 931, 1),
+    (0x901, 932, 2), (0x902, 934, 1), (0x903, 935, 1), (0x904, 936, 2), (0x906, 938, 2),
+    (0x908, 940, 3), (0x90d, 943, 2), (0x90e, 945, 2), (0x910, 947, 2), (0x911, 949, 2),
+    (0x912, 951, 3), (0x913, 954, 3), (0x914, 957, 3), (0x93c, 960, 1), (0x952, 961, 1),
+    (0x953, 962, 1), (0x954, 963, 1), (0x965, 964, 2), (0x966, 966, 1), (0x967, 967, 1),
+    (0x97d, 968, 1), (0x981, 969, 2), (0x986, 971, 2), (0x9bc, 973, 1), (0x9e0, 974, 2),
+    (0x9e1, 976, 2), (0x9e6, 978, 1), (0x9ea, 979, 1), (0x9ed, 980, 1), (0xa02, 981, 1),
+    (0xa03, 982, 1), (0xa06, 983, 2), (0xa07, 985, 2), (0xa08, 987, 2), (0xa09, 989, 2),
+    (0xa0a, 991, 2), (0xa0f, 993, 2), (0xa10, 995, 2), (0xa14, 997, 2), (0xa3c, 999, 1),
+    (0xa4b, 1000, 1), (0xa4d, 1001, 1), (0xa66, 1002, 1), (0xa67, 1003, 1), (0xa6a, 1004, 1),
+    (0xa81, 1005, 2), (0xa82, 1007, 1), (0xa83, 1008, 1), (0xa86, 1009, 2), (0xa8d, 1011, 2),
+    (0xa8f, 1013, 2), (0xa90, 1015, 2), (0xa91, 1017, 3), (0xa93, 1020, 3), (0xa94, 1023, 3),
+    (0xabc, 1026, 1), (0xabd, 1027, 1), (0xac1, 1028, 1), (0xac2, 1029, 1), (0xacd, 1030, 1),
+    (0xae6, 1031, 1), (0xae8, 1032, 1), (0xae9, 1033, 1), (0xaea, 1034, 1), (0xaee, 1035, 1),
+    (0xaf0, 1036, 1), (0xb01, 1037, 2), (0xb03, 1039, 1), (0xb06, 1040, 2), (0xb20, 1042, 1),
+    (0xb3c, 1043, 1), (0xb66, 1044, 1), (0xb68, 1045, 1), (0xb82, 1046, 1), (0xb8a, 1047, 2),
+    (0xb9c, 1049, 1), (0xbb0, 1050, 1), (0xbbe, 1051, 1), (0xbc8, 1052, 1), (0xbca, 1053, 2),
+    (0xbcb, 1055, 2), (0xbcc, 1057, 2), (0xbcd, 1059, 1), (0xbd7, 1060, 1), (0xbe6, 1061, 1),
+    (0xbe7, 1062, 1), (0xbe8, 1063, 1), (0xbea, 1064, 1), (0xbeb, 1065, 2), (0xbec, 1067, 2),
+    (0xbed, 1069, 1), (0xbee, 1070, 1), (0xbf0, 1071, 1), (0xbf2, 1072, 2), (0xbf4, 1074, 2),
+    (0xbf5, 1076, 1), (0xbf7, 1077, 2), (0xbf8, 1079, 1), (0xbfa, 1080, 2), (0xc00, 1082, 2),
+    (0xc02, 1084, 1), (0xc03, 1085, 1), (0xc13, 1086, 2), (0xc14, 1088, 2), (0xc20, 1090, 2),
+    (0xc22, 1092, 2), (0xc25, 1094, 2), (0xc2d, 1096, 2), (0xc2e, 1098, 2), (0xc37, 1100, 2),
+    (0xc39, 1102, 2), (0xc42, 1104, 2), (0xc44, 1106, 2), (0xc60, 1108, 2), (0xc61, 1110, 2),
+    (0xc66, 1112, 1), (0xc81, 1113, 2), (0xc82, 1115, 1), (0xc83, 1116, 1), (0xc85, 1117, 1),
+    (0xc86, 1118, 1), (0xc87, 1119, 1), (0xc92, 1120, 1), (0xc93, 1121, 2), (0xc94, 1123, 2),
+    (0xc9c, 1125, 1), (0xc9e, 1126, 1), (0xca3, 1127, 1), (0xcaf, 1128, 1), (0xcb1, 1129, 1),
+    (0xcb2, 1130, 1), (0xce1, 1131, 2), (0xce6, 1133, 1), (0xce7, 1134, 1), (0xce8, 1135, 1),
+    (0xcef, 1136, 1), (0xd01, 1137, 2), (0xd02, 1139, 1), (0xd03, 1140, 1), (0xd08, 1141, 2),
+    (0xd09, 1143, 1), (0xd0a, 1144, 2), (0xd0c, 1146, 2), (0xd10, 1148, 2), (0xd13, 1150, 2),
+    (0xd14, 1152, 2), (0xd19, 1154, 2), (0xd1c, 1156, 1), (0xd20, 1157, 1), (0xd23, 1158, 1),
+    (0xd31, 1159, 1), (0xd34, 1160, 1), (0xd36, 1161, 1), (0xd3a, 1162, 2), (0xd3f, 1164, 1),
+    (0xd40, 1165, 1), (0xd42, 1166, 1), (0xd43, 1167, 1), (0xd48, 1168, 2), (0xd4e, 1170, 1),
+    (0xd5a, 1171, 3), (0xd5f, 1174, 3), (0xd61, 1177, 1), (0xd66, 1178, 1), (0xd6a, 1179, 2),
+    (0xd6b, 1181, 3), (0xd6c, 1184, 3), (0xd6d, 1187, 1), (0xd6e, 1188, 3), (0xd6f, 1191, 2),
+    (0xd76, 1193, 3), (0xd79, 1196, 2), (0xd7b, 1198, 2), (0xd7c, 1200, 2), (0xd82, 1202, 1),
+    (0xd83, 1203, 1), (0xde9, 1204, 2), (0xdea, 1206, 1), (0xdeb, 1207, 1), (0xdef, 1208, 2),
+    (0xe03, 1210, 1), (0xe0b, 1211, 1), (0xe0f, 1212, 1), (0xe14, 1213, 1), (0xe15, 1214, 1),
+    (0xe17, 1215, 1), (0xe21, 1216, 1), (0xe26, 1217, 1), (0xe33, 1218, 2), (0xe41, 1220, 2),
+    (0xe45, 1222, 1), (0xe4d, 1223, 1), (0xe50, 1224, 1), (0xe88, 1225, 1), (0xe8d, 1226, 1),
+    (0xe9a, 1227, 1), (0xe9b, 1228, 1), (0xe9d, 1229, 1), (0xe9e, 1230, 1), (0xe9f, 1231, 1),
+    (0xeb3, 1232, 2), (0xeb8, 1234, 1), (0xeb9, 1235, 1), (0xec8, 1236, 1), (0xec9, 1237, 1),
+    (0xeca, 1238, 1), (0xecb, 1239, 1), (0xecd, 1240, 1), (0xed0, 1241, 1), (0xedc, 1242, 2),
+    (0xedd, 1244, 2), (0xf00, 1246, 3), (0xf02, 1249, 4), (0xf03, 1253, 4), (0xf0c, 1257, 1),
+    (0xf0e, 1258, 2), (0xf1b, 1260, 2), (0xf1e, 1262, 2), (0xf1f, 1264, 2), (0xf37, 1266, 1),
+    (0xf6a, 1267, 1), (0xf77, 1268, 3), (0xf79, 1271, 3), (0xfce, 1274, 2), (0xfd5, 1276, 1),
+    (0xfd6, 1277, 1), (0x1000, 1278, 2), (0x1010, 1280, 2), (0x101d, 1282, 1), (0x101f, 1283, 2),
+    (0x1029, 1285, 2), (0x102a, 1287, 5), (0x1036, 1292, 1), (0x1038, 1293, 1), (0x1040, 1294, 1),
+    (0x104b, 1295, 2), (0x1065, 1297, 1), (0x1066, 1298, 2), (0x106f, 1300, 3), (0x1070, 1303, 2),
+    (0x107e, 1305, 2), (0x1081, 1307, 2), (0x109e, 1309, 2), (0x10a0, 1311, 1), (0x10e7, 1312, 1),
+    (0x10f3, 1313, 1), (0x10ff, 1314, 1), (0x1101, 1315, 2), (0x1104, 1317, 2), (0x1108, 1319, 2),
+    (0x110a, 1321, 2), (0x110d, 1323, 2), (0x1113, 1325, 2), (0x1114, 1327, 2), (0x1115, 1329, 2),
+    (0x1116, 1331, 2), (0x1117, 1333, 2), (0x1118, 1335, 2), (0x1119, 1337, 2), (0x111a, 1339, 2),
+    (0x111b, 1341, 2), (0x111c, 1343, 2), (0x111d, 1345, 2), (0x111e, 1347, 2), (0x111f, 1349, 2),
+    (0x1120, 1351, 2), (0x1121, 1353, 2), (0x1122, 1355, 3), (0x1123, 1358, 3), (0x1124, 1361, 3),
+    (0x1125, 1364, 3), (0x1126, 1367, 3), (0x1127, 1370, 2), (0x1128, 1372, 2), (0x1129, 1374, 2),
+    (0x112a, 1376, 2), (0x112b, 1378, 2), (0x112c, 1380, 3), (0x112d, 1383, 2), (0x112e, 1385, 2),
+    (0x112f, 1387, 2), (0x1130, 1389, 2), (0x1131, 1391, 2), (0x1132, 1393, 2), (0x1133, 1395, 3),
+    (0x1134, 1398, 3), (0x1135, 1401, 2), (0x1136, 1403, 2), (0x1137, 1405, 2), (0x1138, 1407, 2),
+    (0x1139, 1409, 2), (0x113a, 1411, 2), (0x113b, 1413, 2), (0x113d, 1415, 2), (0x113f, 1417, 2),
+    (0x1141, 1419, 2), (0x1142, 1421, 2), (0x1143, 1423, 2), (0x1144, 1425, 2), (0x1145, 1427, 2),
+    (0x1146, 1429, 2), (0x1147, 1431, 2), (0x1148, 1433, 2), (0x1149, 1435, 2), (0x114a, 1437, 2),
+    (0x114b, 1439, 2), (0x114d, 1441, 2), (0x114f, 1443, 2), (0x1151, 1445, 2), (0x1152, 1447, 2),
+    (0x1153, 1449, 2), (0x1156, 1451, 2), (0x1157, 1453, 2), (0x1158, 1455, 2), (0x115a, 1457, 2),
+    (0x115b, 1459, 2), (0x115c, 1461, 2), (0x115d, 1463, 2), (0x115e, 1465, 2), (0x1162, 1467, 2),
+    (0x1164, 1469, 2), (0x1166, 1471, 2), (0x1168, 1473, 2), (0x116a, 1475, 2), (0x116b, 1477, 3),
+    (0x116c, 1480, 2), (0x116f, 1482, 2), (0x1170, 1484, 3), (0x1171, 1487, 2), (0x1173, 1489, 1),
+    (0x1174, 1490, 2), (0x1175, 1492, 1), (0x1176, 1493, 2), (0x1177, 1495, 2), (0x1178, 1497, 2),
+    (0x1179, 1499, 2), (0x117a, 1501, 2), (0x117b, 1503, 2), (0x117c, 1505, 2), (0x117d, 1507, 2),
+    (0x117e, 1509, 2), (0x117f, 1511, 2), (0x1180, 1513, 3), (0x1181, 1516, 3), (0x1182, 1519, 2),
+    (0x1183, 1521, 2), (0x1184, 1523, 2), (0x1185, 1525, 3), (0x1186, 1528, 2), (0x1187, 1530, 2),
+    (0x1188, 1532, 2), (0x1189, 1534, 2), (0x118a, 1536, 3), (0x118b, 1539, 3), (0x118c, 1542, 3),
+    (0x118d, 1545, 2), (0x118e, 1547, 2), (0x118f, 1549, 2), (0x1190, 1551, 3), (0x1191, 1554, 2),
+    (0x1192, 1556, 3), (0x1193, 1559, 2), (0x1194, 1561, 2), (0x1195, 1563, 2), (0x1196, 1565, 2),
+    (0x1197, 1567, 3), (0x1198, 1570, 2), (0x1199, 1572, 2), (0x119a, 1574, 2), (0x119b, 1576, 2),
+    (0x119c, 1578, 2), (0x119d, 1580, 2), (0x119f, 1582, 2), (0x11a0, 1584, 2), (0x11a1, 1586, 2),
+    (0x11a2, 1588, 2), (0x11a3, 1590, 2), (0x11a4, 1592, 2), (0x11a5, 1594, 2), (0x11a6, 1596, 2),
+    (0x11a7, 1598, 3), (0x11a8, 1601, 1), (0x11a9, 1602, 2), (0x11aa, 1604, 2), (0x11ab, 1606, 1),
+    (0x11ac, 1607, 2), (0x11ad, 1609, 2), (0x11ae, 1611, 1), (0x11af, 1612, 1), (0x11b0, 1613, 2),
+    (0x11b1, 1615, 2), (0x11b2, 1617, 2), (0x11b3, 1619, 2), (0x11b4, 1621, 2), (0x11b5, 1623, 2),
+    (0x11b6, 1625, 2), (0x11b7, 1627, 1), (0x11b8, 1628, 1), (0x11b9, 1629, 2), (0x11ba, 1631, 1),
+    (0x11bb, 1632, 2), (0x11bc, 1634, 1), (0x11bd, 1635, 1), (0x11be, 1636, 1), (0x11bf, 1637, 1),
+    (0x11c0, 1638, 1), (0x11c1, 1639, 1), (0x11c2, 1640, 1), (0x11c3, 1641, 2), (0x11c4, 1643, 3),
+    (0x11c5, 1646, 2), (0x11c6, 1648, 2), (0x11c7, 1650, 2), (0x11c8, 1652, 2), (0x11c9, 1654, 2),
+    (0x11ca, 1656, 2), (0x11cb, 1658, 2), (0x11cc, 1660, 3), (0x11cd, 1663, 2), (0x11ce, 1665, 2),
+    (0x11cf, 1667, 3), (0x11d0, 1670, 2), (0x11d1, 1672, 3), (0x11d2, 1675, 3), (0x11d3, 1678, 3),
+    (0x11d4, 1681, 3), (0x11d5, 1684, 3), (0x11d6, 1687, 3), (0x11d7, 1690, 2), (0x11d8, 1692, 2),
+    (0x11d9, 1694, 2), (0x11da, 1696, 2), (0x11db, 1698, 2), (0x11dc, 1700, 2), (0x11dd, 1702, 2),
+    (0x11de, 1704, 3), (0x11df, 1707, 2), (0x11e0, 1709, 2), (0x11e1, 1711, 2), (0x11e2, 1713, 2),
+    (0x11e3, 1715, 2), (0x11e4, 1717, 2), (0x11e5, 1719, 2), (0x11e6, 1721, 2), (0x11e7, 1723, 2),
+    (0x11e8, 1725, 2), (0x11e9, 1727, 2), (0x11ea, 1729, 2), (0x11eb, 1731, 1), (0x11ec, 1732, 2),
+    (0x11ed, 1734, 3), (0x11ee, 1737, 2), (0x11ef, 1739, 2), (0x11f0, 1741, 1), (0x11f1, 1742, 2),
+    (0x11f2, 1744, 2), (0x11f3, 1746, 2), (0x11f4, 1748, 2), (0x11f5, 1750, 2), (0x11f6, 1752, 2),
+    (0x11f7, 1754, 2), (0x11f8, 1756, 2), (0x11f9, 1758, 1), (0x11fa, 1759, 2), (0x11fb, 1761, 2),
+    (0x11fc, 1763, 2), (0x11fd, 1765, 2), (0x11fe, 1767, 2), (0x11ff, 1769, 2), (0x1200, 1771, 1),
+    (0x1223, 1772, 1), (0x1240, 1773, 1), (0x1260, 1774, 1), (0x1294, 1775, 1), (0x12d0, 1776, 1),
+    (0x13a0, 1777, 1), (0x13a1, 1778, 1), (0x13a2, 1779, 1), (0x13a4, 1780, 2), (0x13a5, 1782, 1),
+    (0x13a8, 1783, 1), (0x13a9, 1784, 1), (0x13aa, 1785, 1), (0x13ab, 1786, 1), (0x13ac, 1787, 1),
+    (0x13ae, 1788, 1), (0x13b0, 1789, 1), (0x13b1, 1790, 1), (0x13b3, 1791, 1), (0x13b7, 1792, 1),
+    (0x13bb, 1793, 1), (0x13bd, 1794, 1), (0x13be, 1795, 2), (0x13bf, 1797, 1), (0x13c0, 1798, 1),
+    (0x13c2, 1799, 1), (0x13c3, 1800, 1), (0x13c7, 1801, 1), (0x13cb, 1802, 1), (0x13cc, 1803, 2),
+    (0x13ce, 1805, 1), (0x13cf, 1806, 1), (0x13d2, 1807, 1), (0x13d4, 1808, 1), (0x13d5, 1809, 1),
+    (0x13d9, 1810, 1), (0x13da, 1811, 1), (0x13de, 1812, 1), (0x13df, 1813, 1), (0x13e2, 1814, 1),
+    (0x13e6, 1815, 1), (0x13e7, 1816, 1), (0x13eb, 1817, 2), (0x13ee, 1819, 1), (0x13f0, 1820, 1),
+    (0x13f2, 1821, 2), (0x13f3, 1823, 1), (0x13f4, 1824, 1), (0x13fb, 1825, 1), (0x13fc, 1826, 1),
+    (0x1400, 1827, 1), (0x1403, 1828, 1), (0x140c, 1829, 2), (0x140d, 1831, 2), (0x140e, 1833, 2),
+    (0x140f, 1835, 2), (0x1410, 1837, 2), (0x1411, 1839, 2), (0x1412, 1841, 2), (0x1413, 1843, 2),
+    (0x1414, 1845, 2), (0x1415, 1847, 2), (0x1417, 1849, 2), (0x1418, 1851, 2), (0x1419, 1853, 2),
+    (0x141a, 1855, 2), (0x1427, 1857, 1), (0x142b, 1858, 2), (0x142c, 1860, 2), (0x142d, 1862, 2),
+    (0x142e, 1864, 2), (0x142f, 1866, 1), (0x1431, 1867, 1), (0x1433, 1868, 1), (0x1437, 1869, 2),
+    (0x1438, 1871, 1), (0x143a, 1872, 2), (0x143b, 1874, 2), (0x143c, 1876, 2), (0x143d, 1878, 2),
+    (0x143e, 1880, 2), (0x143f, 1882, 2), (0x1440, 1884, 2), (0x1441, 1886, 2), (0x1442, 1888, 2),
+    (0x1443, 1890, 2), (0x1444, 1892, 2), (0x1445, 1894, 2), (0x1446, 1896, 2), (0x1447, 1898, 2),
+    (0x144a, 1900, 1), (0x144c, 1901, 1), (0x144e, 1902, 1), (0x1454, 1903, 2), (0x1457, 1905, 2),
+    (0x1458, 1907, 2), (0x1459, 1909, 2), (0x145a, 1911, 2), (0x145b, 1913, 2), (0x145c, 1915, 2),
+    (0x145d, 1917, 2), (0x145e, 1919, 2), (0x145f, 1921, 2), (0x1460, 1923, 2), (0x1461, 1925, 2),
+    (0x1462, 1927, 2), (0x1463, 1929, 2), (0x1464, 1931, 2), (0x1467, 1933, 2), (0x1468, 1935, 2),
+    (0x1469, 1937, 2), (0x146a, 1939, 2), (0x146d, 1941, 1), (0x146f, 1942, 1), (0x1472, 1943, 1),
+    (0x1473, 1944, 2), (0x1474, 1946, 2), (0x1475, 1948, 2), (0x1476, 1950, 2), (0x1477, 1952, 2),
+    (0x1478, 1954, 2), (0x1479, 1956, 2), (0x147a, 1958, 2), (0x147b, 1960, 2), (0x147c, 1962, 2),
+    (0x147d, 1964, 2), (0x147e, 1966, 2), (0x147f, 1968, 2), (0x1480, 1970, 3), (0x1481, 1973, 3),
+    (0x1485, 1976, 2), (0x1486, 1978, 2), (0x1487, 1980, 2), (0x1488, 1982, 2), (0x148d, 1984, 1),
+    (0x1492, 1985, 2), (0x1493, 1987, 2), (0x1494, 1989, 2), (0x1495, 1991, 2), (0x1496, 1993, 2),
+    (0x1497, 1995, 2), (0x1498, 1997, 2), (0x1499, 1999, 2), (0x149a, 2001, 2), (0x149b, 2003, 2),
+    (0x149c, 2005, 2), (0x149d, 2007, 2), (0x149e, 2009, 2), (0x149f, 2011, 2), (0x14a5, 2013, 1),
+    (0x14aa, 2014, 1), (0x14ac, 2015, 2), (0x14ad, 2017, 2), (0x14ae, 2019, 2), (0x14af, 2021, 2),
+    (0x14b0, 2023, 2), (0x14b1, 2025, 2), (0x14b2, 2027, 2), (0x14b3, 2029, 2), (0x14b4, 2031, 2),
+    (0x14b5, 2033, 2), (0x14b6, 2035, 2), (0x14b7, 2037, 2), (0x14b8, 2039, 2), (0x14b9, 2041, 2),
+    (0x14bf, 2043, 1), (0x14c9, 2044, 2), (0x14ca, 2046, 2), (0x14cb, 2048, 2), (0x14cc, 2050, 2),
+    (0x14cd, 2052, 2), (0x14ce, 2054, 2), (0x14d1, 2056, 1), (0x14dc, 2057, 2), (0x14dd, 2059, 2),
+    (0x14de, 2061, 2), (0x14df, 2063, 2), (0x14e0, 2065, 2), (0x14e1, 2067, 2), (0x14e2, 2069, 2),
+    (0x14e3, 2071, 2), (0x14e4, 2073, 2), (0x14e5, 2075, 2), (0x14e6, 2077, 2), (0x14e7, 2079, 2),
+    (0x14e8, 2081, 2), (0x14e9, 2083, 2), (0x14f6, 2085, 2), (0x14f7, 2087, 2), (0x14f8, 2089, 2),
+    (0x14f9, 2091, 2), (0x14fa, 2093, 2), (0x14fb, 2095, 2), (0x14fc, 2097, 2), (0x14fd, 2099, 2),
+    (0x14fe, 2101, 2), (0x14ff, 2103, 2), (0x1500, 2105, 2), (0x1501, 2107, 2), (0x1502, 2109, 2),
+    (0x1503, 2111, 2), (0x150c, 2113, 2), (0x150d, 2115, 2), (0x150e, 2117, 2), (0x150f, 2119, 2),
+    (0x1517, 2121, 2), (0x1518, 2123, 2), (0x1519, 2125, 2), (0x151a, 2127, 2), (0x151b, 2129, 2),
+    (0x151c, 2131, 2), (0x151d, 2133, 2), (0x151e, 2135, 2), (0x151f, 2137, 2), (0x1520, 2139, 2),
+    (0x1521, 2141, 2), (0x1522, 2143, 2), (0x1523, 2145, 2), (0x1524, 2147, 2), (0x152f, 2149, 2),
+    (0x1530, 2151, 2), (0x1531, 2153, 2), (0x1532, 2155, 2), (0x1533, 2157, 2), (0x1534, 2159, 2),
+    (0x1535, 2161, 2), (0x1536, 2163, 2), (0x1537, 2165, 2), (0x1538, 2167, 2), (0x1539, 2169, 2),
+    (0x153a, 2171, 2), (0x153b, 2173, 2), (0x153c, 2175, 2), (0x1540, 2177, 1), (0x1541, 2178, 1),
+    (0x154e, 2179, 2), (0x154f, 2181, 2), (0x155b, 2183, 2), (0x155c, 2185, 2), (0x1568, 2187, 2),
+    (0x1569, 2189, 2), (0x1577, 2191, 1), (0x157c, 2192, 1), (0x157d, 2193, 1), (0x157e, 2194, 2),
+    (0x157f, 2196, 2), (0x1580, 2198, 2), (0x1581, 2200, 2), (0x1582, 2202, 2), (0x1583, 2204, 2),
+    (0x1584, 2206, 3), (0x1585, 2209, 2), (0x1587, 2211, 1), (0x158e, 2212, 2), (0x158f, 2214, 2),
+    (0x1590, 2216, 2), (0x1591, 2218, 2), (0x1592, 2220, 2), (0x1593, 2222, 2), (0x1594, 2224, 2),
+    (0x15af, 2226, 1), (0x15b4, 2227, 1), (0x15b5, 2228, 1), (0x15b7, 2229, 1), (0x15c4, 2230, 1),
+    (0x15c5, 2231, 1), (0x15de, 2232, 1), (0x15ea, 2233, 1), (0x15ef, 2234, 1), (0x15f0, 2235, 1),
+    (0x15f7, 2236, 1), (0x1602, 2237, 1), (0x1603, 2238, 1), (0x1604, 2239, 1), (0x1607, 2240, 1),
+    (0x1622, 2241, 1), (0x1623, 2242, 1), (0x1624, 2243, 1), (0x162e, 2244, 1), (0x162f, 2245, 1),
+    (0x1634, 2246, 1), (0x1635, 2247, 1), (0x166d, 2248, 1), (0x166e, 2249, 1), (0x166f, 2250, 2),
+    (0x1670, 2252, 2), (0x1671, 2254, 2), (0x1672, 2256, 2), (0x1673, 2258, 2), (0x1674, 2260, 2),
+    (0x1675, 2262, 2), (0x1676, 2264, 2), (0x1677, 2266, 2), (0x1678, 2268, 2), (0x1679, 2270, 2),
+    (0x167a, 2272, 2), (0x167b, 2274, 2), (0x167c, 2276, 2), (0x167d, 2278, 2), (0x1680, 2280, 1),
+    (0x16b2, 2281, 1), (0x16b7, 2282, 1), (0x16c1, 2283, 1), (0x16c2, 2284, 1), (0x16cc, 2285, 1),
+    (0x16d5, 2286, 1), (0x16d6, 2287, 1), (0x16d8, 2288, 1), (0x16e1, 2289, 1), (0x16eb, 2290, 1),
+    (0x16ec, 2291, 1), (0x16ed, 2292, 1), (0x16f0, 2293, 1), (0x1735, 2294, 1), (0x17a3, 2295, 1),
+    (0x17b7, 2296, 1), (0x17b8, 2297, 1), (0x17b9, 2298, 1), (0x17ba, 2299, 1), (0x17c6, 2300, 1),
+    (0x17cb, 2301, 1), (0x17d3, 2302, 1), (0x17d4, 2303, 1), (0x17d5, 2304, 1), (0x17d9, 2305, 1),
+    (0x17da, 2306, 1), (0x1803, 2307, 1), (0x1809, 2308, 1), (0x1855, 2309, 1), (0x1896, 2310, 1),
+    (0x18b3, 2311, 2), (0x18b6, 2313, 2), (0x18b9, 2315, 2), (0x18c2, 2317, 2), (0x18c6, 2319, 2),
+    (0x18c7, 2321, 2), (0x18c8, 2323, 2), (0x18c9, 2325, 2), (0x18ca, 2327, 2), (0x18cb, 2329, 2),
+    (0x18cc, 2331, 2), (0x18cd, 2333, 2), (0x18ce, 2335, 2), (0x18cf, 2337, 2), (0x18d0, 2339, 2),
+    (0x18d1, 2341, 2), (0x18d2, 2343, 2), (0x18d3, 2345, 2), (0x18db, 2347, 1), (0x18dc, 2348, 2),
+    (0x18dd, 2350, 2), (0x18e0, 2352, 2), (0x18e3, 2354, 2), (0x18e4, 2356, 2), (0x18e5, 2358, 2),
+    (0x18e8, 2360, 2), (0x18ea, 2362, 2), (0x18ed, 2364, 2), (0x18f0, 2366, 2), (0x18f2, 2368, 2),
+    (0x19d0, 2370, 1), (0x19d1, 2371, 1), (0x1a80, 2372, 1), (0x1a90, 2373, 1), (0x1aa9, 2374, 2),
+    (0x1aab, 2376, 2), (0x1ab4, 2378, 1), (0x1ab7, 2379, 1), (0x1b52, 2380, 1), (0x1b53, 2381, 1),
+    (0x1b58, 2382, 1), (0x1b5c, 2383, 1), (0x1b5f, 2384, 2), (0x1c3c, 2386, 2), (0x1c7f, 2388, 2),
+    (0x1cd0, 2390, 1), (0x1cd2, 2391, 1), (0x1cd3, 2392, 2), (0x1cd5, 2394, 1), (0x1cd8, 2395, 1),
+    (0x1cd9, 2396, 1), (0x1cda, 2397, 1), (0x1cdc, 2398, 1), (0x1cdd, 2399, 1), (0x1cde, 2400, 1),
+    (0x1ced, 2401, 1), (0x1d04, 2402, 1), (0x1d08, 2403, 1), (0x1d0b, 2404, 1), (0x1d0d, 2405, 1),
+    (0x1d0f, 2406, 1), (0x1d10, 2407, 1), (0x1d11, 2408, 1), (0x1d14, 2409, 2), (0x1d1c, 2411, 1),
+    (0x1d20, 2412, 1), (0x1d21, 2413, 1), (0x1d22, 2414, 1), (0x1d24, 2415, 1), (0x1d26, 2416, 1),
+    (0x1d27, 2417, 1), (0x1d28, 2418, 1), (0x1d29, 2419, 1), (0x1d2b, 2420, 1), (0x1d3e, 2421, 1),
+    (0x1d52, 2422, 1), (0x1d6b, 2423, 2), (0x1d6e, 2425, 2), (0x1d6f, 2427, 3), (0x1d70, 2430, 2),
+    (0x1d72, 2432, 2), (0x1d73, 2434, 2), (0x1d74, 2436, 2), (0x1d75, 2438, 2), (0x1d76, 2440, 2),
+    (0x1d78, 2442, 1), (0x1d7b, 2443, 2), (0x1d7c, 2445, 2), (0x1d7d, 2447, 2), (0x1d7e, 2449, 2),
+    (0x1d7f, 2451, 2), (0x1d83, 2453, 1), (0x1d8c, 2454, 1), (0x1d90, 2455, 1), (0x1d9f, 2456, 1),
+    (0x1da2, 2457, 1), (0x1dba, 2458, 1), (0x1dbb, 2459, 1), (0x1dee, 2460, 1), (0x1e43, 2461, 1),
+    (0x1e9a, 2462, 2), (0x1e9d, 2464, 1), (0x1eff, 2465, 1), (0x1f7d, 2466, 3), (0x1fbd, 2469, 1),
+    (0x1fbe, 2470, 1), (0x1fbf, 2471, 1), (0x1fc0, 2472, 1), (0x1fef, 2473, 1), (0x1ff6, 2474, 1),
+    (0x1ffd, 2475, 1), (0x1ffe, 2476, 1), (0x2000, 2477, 1), (0x2001, 2478, 1), (0x2002, 2479, 1),
+    (0x2003, 2480, 1), (0x2004, 2481, 1), (0x2005, 2482, 1), (0x2006, 2483, 1), (0x2007, 2484, 1),
+    (0x2008, 2485, 1), (0x2009, 2486, 1), (0x200a, 2487, 1), (0x2010, 2488, 1), (0x2011, 2489, 1),
+    (0x2012, 2490, 1), (0x2013, 2491, 1), (0x2014, 2492, 1), (0x2015, 2493, 1), (0x2016, 2494, 2),
+    (0x2018, 2496, 1), (0x2019, 2497, 1), (0x201a, 2498, 1), (0x201b, 2499, 1), (0x201c, 2500, 2),
+    (0x201d, 2502, 2), (0x201f, 2504, 2), (0x2022, 2506, 1), (0x2024, 2507, 1), (0x2025, 2508, 2),
+    (0x2026, 2510, 3), (0x2027, 2513, 1), (0x2028, 2514, 1), (0x2029, 2515, 1), (0x202f, 2516, 1),
+    (0x2030, 2517, 4), (0x2031, 2521, 5), (0x2032, 2526, 1), (0x2033, 2527, 2), (0x2034, 2529, 3),
+    (0x2035, 2532, 1), (0x2036, 2533, 2), (0x2037, 2535, 3), (0x2039, 2538, 1), (0x203a, 2539, 1),
+    (0x203c, 2540, 2), (0x203e, 2542, 1), (0x2041, 2543, 1), (0x2043, 2544, 1), (0x2044, 2545, 1),
+    (0x2047, 2546, 2), (0x2048, 2548, 2), (0x2049, 2550, 2), (0x204e, 2552, 1), (0x2052, 2553, 3),
+    (0x2053, 2556, 1), (0x2057, 2557, 4), (0x205a, 2561, 1), (0x205d, 2562, 1), (0x205e, 2563, 1),
+    (0x205f, 2564, 1), (0x2070, 2565, 1), (0x2079, 2566, 1), (0x20a1, 2567, 2), (0x20a4, 2569, 1),
+    (0x20a5, 2570, 3), (0x20a8, 2573, 2), (0x20a9, 2575, 2), (0x20ab, 2577, 3), (0x20ac, 2580, 1),
+    (0x20ad, 2581, 2), (0x20ae, 2583, 2), (0x20b6, 2585, 2), (0x20bd, 2587, 1), (0x20db, 2588, 1),
+    (0x2100, 2589, 3), (0x2101, 2592, 3), (0x2102, 2595, 1), (0x2103, 2596, 2), (0x2105, 2598, 3),
+    (0x2106, 2601, 3), (0x2107, 2604, 1), (0x2108, 2605, 1), (0x2109, 2606, 2), (0x210a, 2608, 1),
+    (0x210b, 2609, 1), (0x210c, 2610, 1), (0x210d, 2611, 1), (0x210e, 2612, 1), (0x210f, 2613, 2),
+    (0x2110, 2615, 1), (0x2111, 2616, 1), (0x2112, 2617, 1), (0x2113, 2618, 1), (0x2115, 2619, 1),
+    (0x2116, 2620, 2), (0x2119, 2622, 1), (0x211a, 2623, 1), (0x211b, 2624, 1), (0x211c, 2625, 1),
+    (0x211d, 2626, 1), (0x2121, 2627, 3), (0x2124, 2630, 1), (0x2126, 2631, 1), (0x2127, 2632, 1),
+    (0x2128, 2633, 1), (0x2129, 2634, 1), (0x212a, 2635, 1), (0x212c, 2636, 1), (0x212d, 2637, 1),
+    (0x212e, 2638, 1), (0x212f, 2639, 1), (0x2130, 2640, 1), (0x2131, 2641, 1), (0x2133, 2642, 1),
+    (0x2134, 2643, 1), (0x2135, 2644, 1), (0x2136, 2645, 1), (0x2137, 2646, 1), (0x2138, 2647, 1),
+    (0x2139, 2648, 1), (0x213b, 2649, 3), (0x213c, 2652, 1), (0x213d, 2653, 1), (0x213e, 2654, 1),
+    (0x213f, 2655, 1), (0x2140, 2656, 1), (0x2141, 2657, 1), (0x2142, 2658, 1), (0x2143, 2659, 1),
+    (0x2145, 2660, 1), (0x2146, 2661, 1), (0x2147, 2662, 1), (0x2148, 2663, 1), (0x2149, 2664, 1),
+    (0x2160, 2665, 1), (0x2161, 2666, 2), (0x2162, 2668, 3), (0x2163, 2671, 2), (0x2164, 2673, 1),
+    (0x2165, 2674, 2), (0x2166, 2676, 3), (0x2167, 2679, 4), (0x2168, 2683, 2), (0x2169, 2685, 1),
+    (0x216a, 2686, 2), (0x216b, 2688, 3), (0x216c, 2691, 1), (0x216d, 2692, 1), (0x216e, 2693, 1),
+    (0x216f, 2694, 1), (0x2170, 2695, 1), (0x2171, 2696, 2), (0x2172, 2698, 3), (0x2173, 2701, 2),
+    (0x2174, 2703, 1), (0x2175, 2704, 2), (0x2176, 2706, 3), (0x2177, 2709, 4), (0x2178, 2713, 2),
+    (0x2179, 2715, 1), (0x217a, 2716, 2), (0x217b, 2718, 3), (0x217c, 2721, 1), (0x217d, 2722, 1),
+    (0x217e, 2723, 1), (0x217f, 2724, 2), (0x2183, 2726, 1), (0x2184, 2727, 1), (0x2191, 2728, 1),
+    (0x2195, 2729, 1), (0x21b5, 2730, 1), (0x21ba, 2731, 1), (0x21be, 2732, 1), (0x21bf, 2733, 1),
+    (0x2200, 2734, 1), (0x2203, 2735, 1), (0x2206, 2736, 1), (0x220f, 2737, 1), (0x2211, 2738, 1),
+    (0x2212, 2739, 1), (0x2214, 2740, 2), (0x2215, 2742, 1), (0x2216, 2743, 1), (0x2217, 2744, 1),
+    (0x2218, 2745, 1), (0x2219, 2746, 1), (0x221e, 2747, 2), (0x2223, 2749, 1), (0x2225, 2750, 2),
+    (0x2228, 2752, 1), (0x2229, 2753, 1), (0x222a, 2754, 1), (0x222b, 2755, 1), (0x222c, 2756, 2),
+    (0x222d, 2758, 3), (0x222f, 2761, 2), (0x2230, 2763, 3), (0x2236, 2766, 1), (0x2238, 2767, 2),
+    (0x223c, 2769, 1), (0x2250, 2770, 2), (0x2251, 2772, 3), (0x2257, 2775, 2), (0x2259, 2777, 2),
+    (0x225a, 2779, 2), (0x225e, 2781, 2), (0x2263, 2783, 1), (0x226a, 2784, 2), (0x226b, 2786, 2),
+    (0x2282, 2788, 1), (0x2283, 2789, 1), (0x2295, 2790, 1), (0x2296, 2791, 2), (0x2299, 2793, 1),
+    (0x229d, 2794, 2), (0x22a4, 2796, 1), (0x22a5, 2797, 1), (0x22c0, 2798, 1), (0x22c1, 2799, 1),
+    (0x22c2, 2800, 1), (0x22c3, 2801, 1), (0x22c4, 2802, 1), (0x22c5, 2803, 1), (0x22c8, 2804, 1),
+    (0x22d6, 2805, 2), (0x22d7, 2807, 2), (0x22d8, 2809, 3), (0x22d9, 2812, 3), (0x22ee, 2815, 1),
+    (0x22ef, 2816, 3), (0x22f4, 2819, 1), (0x22ff, 2820, 1), (0x2300, 2821, 1), (0x2325, 2822, 1),
+    (0x2329, 2823, 1), (0x232a, 2824, 1), (0x2341, 2825, 1), (0x2359, 2826, 2), (0x235a, 2828, 2),
+    (0x235c, 2830, 2), (0x235f, 2832, 1), (0x2361, 2833, 2), (0x2362, 2835, 2), (0x2363, 2837, 2),
+    (0x2364, 2839, 2), (0x2365, 2841, 1), (0x2368, 2842, 2), (0x2369, 2844, 1), (0x236b, 2845, 2),
+    (0x236c, 2847, 2), (0x2373, 2849, 1), (0x2374, 2850, 1), (0x2375, 2851, 1), (0x2376, 2852, 2),
+    (0x2377, 2854, 2), (0x2378, 2856, 2), (0x2379, 2858, 2), (0x237a, 2860, 1), (0x237f, 2861, 1),
+    (0x239c, 2862, 1), (0x239f, 2863, 1), (0x23a2, 2864, 1), (0x23a5, 2865, 1), (0x23aa, 2866, 1),
+    (0x23ae, 2867, 1), (0x23c1, 2868, 1), (0x23c2, 2869, 1), (0x23c3, 2870, 1), (0x23c6, 2871, 1),
+    (0x23e8, 2872, 2), (0x23fc, 2874, 1), (0x23fd, 2875, 1), (0x23fe, 2876, 1), (0x244a, 2877, 2),
+    (0x2460, 2879, 1), (0x2461, 2880, 1), (0x2462, 2881, 1), (0x2463, 2882, 1), (0x2464, 2883, 1),
+    (0x2465, 2884, 1), (0x2466, 2885, 1), (0x2467, 2886, 1), (0x2468, 2887, 1), (0x2469, 2888, 1),
+    (0x2474, 2889, 3), (0x2475, 2892, 3), (0x2476, 2895, 3), (0x2477, 2898, 3), (0x2478, 2901, 3),
+    (0x2479, 2904, 3), (0x247a, 2907, 3), (0x247b, 2910, 3), (0x247c, 2913, 3), (0x247d, 2916, 4),
+    (0x247e, 2920, 4), (0x247f, 2924, 4), (0x2480, 2928, 4), (0x2481, 2932, 4), (0x2482, 2936, 4),
+    (0x2483, 2940, 4), (0x2484, 2944, 4), (0x2485, 2948, 4), (0x2486, 2952, 4), (0x2487, 2956, 4),
+    (0x2488, 2960, 2), (0x2489, 2962, 2), (0x248a, 2964, 2), (0x248b, 2966, 2), (0x248c, 2968, 2),
+    (0x248d, 2970, 2), (0x248e, 2972, 2), (0x248f, 2974, 2), (0x2490, 2976, 2), (0x2491, 2978, 3),
+    (0x2492, 2981, 3), (0x2493, 2984, 3), (0x2494, 2987, 3), (0x2495, 2990, 3), (0x2496, 2993, 3),
+    (0x2497, 2996, 3), (0x2498, 2999, 3), (0x2499, 3002, 3), (0x249a, 3005, 3), (0x249b, 3008, 3),
+    (0x249c, 3011, 3), (0x249d, 3014, 3), (0x249e, 3017, 3), (0x249f, 3020, 3), (0x24a0, 3023, 3),
+    (0x24a1, 3026, 3), (0x24a2, 3029, 3), (0x24a3, 3032, 3), (0x24a4, 3035, 3), (0x24a5, 3038, 3),
+    (0x24a6, 3041, 3), (0x24a7, 3044, 3), (0x24a8, 3047, 4), (0x24a9, 3051, 3), (0x24aa, 3054, 3),
+    (0x24ab, 3057, 3), (0x24ac, 3060, 3), (0x24ad, 3063, 3), (0x24ae, 3066, 3), (0x24af, 3069, 3),
+    (0x24b0, 3072, 3), (0x24b1, 3075, 3), (0x24b2, 3078, 3), (0x24b3, 3081, 3), (0x24b4, 3084, 3),
+    (0x24b5, 3087, 3), (0x24b8, 3090, 1), (0x24c5, 3091, 1), (0x24c7, 3092, 1), (0x24db, 3093, 1),
+    (0x24ea, 3094, 1), (0x2500, 3095, 1), (0x2501, 3096, 1), (0x2503, 3097, 1), (0x250f, 3098, 1),
+    (0x2523, 3099, 1), (0x2571, 3100, 1), (0x2573, 3101, 1), (0x2588, 3102, 1), (0x2590, 3103, 1),
+    (0x2594, 3104, 1), (0x2597, 3105, 1), (0x259d, 3106, 1), (0x25a0, 3107, 1), (0x25b1, 3108, 1),
+    (0x25b3, 3109, 1), (0x25b7, 3110, 1), (0x25b8, 3111, 1), (0x25ba, 3112, 1), (0x25bd, 3113, 1),
+    (0x25c1, 3114, 1), (0x25c7, 3115, 1), (0x25ca, 3116, 1), (0x25cb, 3117, 1), (0x25ce, 3118, 1),
+    (0x25e0, 3119, 1), (0x25e6, 3120, 1), (0x2609, 3121, 1), (0x2610, 3122, 1), (0x2625, 3123, 1),
+    (0x2630, 3124, 1), (0x2638, 3125, 1), (0x264e, 3126, 1), (0x2662, 3127, 1), (0x2669, 3128, 2),
+    (0x266a, 3130, 3), (0x26ac, 3133, 1), (0x2768, 3134, 1), (0x2769, 3135, 1), (0x276e, 3136, 1),
+    (0x276f, 3137, 1), (0x2772, 3138, 1), (0x2773, 3139, 1), (0x2774, 3140, 1), (0x2775, 3141, 1),
+    (0x2795, 3142, 1), (0x2796, 3143, 1), (0x2797, 3144, 1), (0x27c2, 3145, 1), (0x27c8, 3146, 2),
+    (0x27c9, 3148, 2), (0x27cb, 3150, 1), (0x27cd, 3151, 1), (0x27d9, 3152, 1), (0x27e8, 3153, 1),
+    (0x27e9, 3154, 1), (0x292b, 3155, 1), (0x292c, 3156, 1), (0x2963, 3157, 2), (0x2965, 3159, 2),
+    (0x296e, 3161, 2), (0x296f, 3163, 2), (0x2999, 3165, 1), (0x29b0, 3166, 1), (0x29be, 3167, 1),
+    (0x29c4, 3168, 1), (0x29c5, 3169, 1), (0x29c7, 3170, 1), (0x29d6, 3171, 1), (0x29d9, 3172, 1),
+    (0x29f4, 3173, 2), (0x29f5, 3175, 1), (0x29f6, 3176, 2), (0x29f8, 3178, 1), (0x29f9, 3179, 1),
+    (0x2a00, 3180, 1), (0x2a01, 3181, 1), (0x2a02, 3182, 1), (0x2a03, 3183, 1), (0x2a04, 3184, 1),
+    (0x2a05, 3185, 1), (0x2a06, 3186, 1), (0x2a0c, 3187, 4), (0x2a1d, 3191, 1), (0x2a20, 3192, 2),
+    (0x2a21, 3194, 1), (0x2a22, 3195, 2), (0x2a23, 3197, 2), (0x2a24, 3199, 2), (0x2a25, 3201, 2),
+    (0x2a26, 3203, 2), (0x2a27, 3205, 2), (0x2a29, 3207, 2), (0x2a2a, 3209, 2), (0x2a2f, 3211, 1),
+    (0x2a30, 3212, 2), (0x2a3d, 3214, 1), (0x2a3e, 3215, 1), (0x2a3f, 3216, 1), (0x2a6a, 3217, 2),
+    (0x2a6e, 3219, 2), (0x2a74, 3221, 3), (0x2a75, 3224, 2), (0x2a76, 3226, 3), (0x2aa5, 3229, 2),
+    (0x2aaa, 3231, 1), (0x2aab, 3232, 1), (0x2ad7, 3233, 2), (0x2afb, 3235, 3), (0x2afd, 3238, 2),
+    (0x2bec, 3240, 1), (0x2bed, 3241, 1), (0x2bee, 3242, 1), (0x2bef, 3243, 1), (0x2c67, 3244, 2),
+    (0x2c69, 3246, 2), (0x2c84, 3248, 1), (0x2c85, 3249, 1), (0x2c86, 3250, 1), (0x2c88, 3251, 1),
+    (0x2c89, 3252, 1), (0x2c8e, 3253, 1), (0x2c92, 3254, 1), (0x2c94, 3255, 1), (0x2c95, 3256, 1),
+    (0x2c96, 3257, 1), (0x2c98, 3258, 1), (0x2c9a, 3259, 1), (0x2c9e, 3260, 1), (0x2c9f, 3261, 1),
+    (0x2ca0, 3262, 1), (0x2ca2, 3263, 1), (0x2ca3, 3264, 1), (0x2ca4, 3265, 1), (0x2ca5, 3266, 1),
+    (0x2ca6, 3267, 1), (0x2ca8, 3268, 1), (0x2caa, 3269, 1), (0x2cab, 3270, 1), (0x2cac, 3271, 1),
+    (0x2cad, 3272, 1), (0x2cae, 3273, 1), (0x2cb1, 3274, 1), (0x2cb4, 3275, 2), (0x2cba, 3277, 1),
+    (0x2cbc, 3278, 1), (0x2cbd, 3279, 1), (0x2cc6, 3280, 1), (0x2cca, 3281, 1), (0x2ccc, 3282, 1),
+    (0x2ccd, 3283, 1), (0x2cd0, 3284, 1), (0x2cd1, 3285, 1), (0x2cd2, 3286, 1), (0x2cdc, 3287, 1),
+    (0x2ce4, 3288, 1), (0x2ce9, 3289, 1), (0x2cf9, 3290, 2), (0x2d31, 3292, 2), (0x2d37, 3294, 1),
+    (0x2d38, 3295, 1), (0x2d39, 3296, 1), (0x2d3a, 3297, 1), (0x2d41, 3298, 2), (0x2d48, 3300, 3),
+    (0x2d49, 3303, 1), (0x2d4f, 3304, 1), (0x2d51, 3305, 1), (0x2d54, 3306, 1), (0x2d55, 3307, 1),
+    (0x2d59, 3308, 1), (0x2d5d, 3309, 1), (0x2d60, 3310, 1), (0x2d63, 3311, 1), (0x2de8, 3312, 1),
+    (0x2dea, 3313, 1), (0x2ded, 3314, 1), (0x2def, 3315, 1), (0x2df6, 3316, 1), (0x2df7, 3317, 1),
+    (0x2e1a, 3318, 2), (0x2e1e, 3320, 2), (0x2e1f, 3322, 2), (0x2e26, 3324, 1), (0x2e27, 3325, 1),
+    (0x2e28, 3326, 2), (0x2e29, 3328, 2), (0x2e2a, 3330, 1), (0x2e2b, 3331, 1), (0x2e2c, 3332, 1),
+    (0x2e2e, 3333, 1), (0x2e30, 3334, 1), (0x2e31, 3335, 1), (0x2e32, 3336, 1), (0x2e35, 3337, 1),
+    (0x2e39, 3338, 1), (0x2e3d, 3339, 1), (0x2e3f, 3340, 1), (0x2e40, 3341, 1), (0x2e82, 3342, 1),
+    (0x2e83, 3343, 1), (0x2e85, 3344, 1), (0x2e89, 3345, 1), (0x2e8b, 3346, 1), (0x2e8e, 3347, 1),
+    (0x2e8f, 3348, 1), (0x2e90, 3349, 1), (0x2e92, 3350, 1), (0x2e93, 3351, 1), (0x2e94, 3352, 1),
+    (0x2e96, 3353, 1), (0x2e97, 3354, 1), (0x2e98, 3355, 1), (0x2e99, 3356, 1), (0x2e9b, 3357, 1),
+    (0x2e9e, 3358, 1), (0x2e9f, 3359, 1), (0x2ea0, 3360, 1), (0x2ea1, 3361, 1), (0x2ea2, 3362, 1),
+    (0x2ea3, 3363, 1), (0x2ea4, 3364, 1), (0x2ea6, 3365, 1), (0x2ea8, 3366, 1), (0x2eab, 3367, 1),
+    (0x2ead, 3368, 1), (0x2eaf, 3369, 1), (0x2eb1, 3370, 1), (0x2eb2, 3371, 1), (0x2eb9, 3372, 1),
+    (0x2eba, 3373, 1), (0x2ebe, 3374, 1), (0x2ebf, 3375, 1), (0x2ec0, 3376, 1), (0x2ec1, 3377, 1),
+    (0x2ec2, 3378, 1), (0x2ec3, 3379, 1), (0x2ec4, 3380, 1), (0x2ec5, 3381, 1), (0x2ec8, 3382, 1),
+    (0x2ec9, 3383, 1), (0x2ecb, 3384, 1), (0x2ecc, 3385, 1), (0x2ecd, 3386, 1), (0x2ecf, 3387, 1),
+    (0x2ed0, 3388, 1), (0x2ed1, 3389, 1), (0x2ed2, 3390, 1), (0x2ed3, 3391, 1), (0x2ed4, 3392, 1),
+    (0x2ed6, 3393, 1), (0x2ed8, 3394, 1), (0x2ed9, 3395, 1), (0x2eda, 3396, 1), (0x2edb, 3397, 1),
+    (0x2edc, 3398, 1), (0x2edd, 3399, 1), (0x2edf, 3400, 1), (0x2ee0, 3401, 1), (0x2ee2, 3402, 1),
+    (0x2ee4, 3403, 1), (0x2ee5, 3404, 1), (0x2ee8, 3405, 1), (0x2ee9, 3406, 1), (0x2eeb, 3407, 1),
+    (0x2eec, 3408, 1), (0x2eed, 3409, 1), (0x2eee, 3410, 1), (0x2eef, 3411, 1), (0x2ef0, 3412, 1),
+    (0x2ef2, 3413, 1), (0x2ef3, 3414, 1), (0x2f00, 3415, 1), (0x2f01, 3416, 1), (0x2f02, 3417, 1),
+    (0x2f03, 3418, 1), (0x2f04, 3419, 1), (0x2f05, 3420, 1), (0x2f06, 3421, 1), (0x2f07, 3422, 1),
+    (0x2f08, 3423, 1), (0x2f09, 3424, 1), (0x2f0a, 3425, 1), (0x2f0b, 3426, 1), (0x2f0c, 3427, 1),
+    (0x2f0d, 3428, 1), (0x2f0e, 3429, 1), (0x2f0f, 3430, 1), (0x2f10, 3431, 1), (0x2f11, 3432, 1),
+    (0x2f12, 3433, 1), (0x2f13, 3434, 1), (0x2f14, 3435, 1), (0x2f15, 3436, 1), (0x2f16, 3437, 1),
+    (0x2f17, 3438, 1), (0x2f18, 3439, 1), (0x2f19, 3440, 1), (0x2f1a, 3441, 1), (0x2f1b, 3442, 1),
+    (0x2f1c, 3443, 1), (0x2f1d, 3444, 1), (0x2f1e, 3445, 1), (0x2f1f, 3446, 1), (0x2f20, 3447, 1),
+    (0x2f21, 3448, 1), (0x2f22, 3449, 1), (0x2f23, 3450, 1), (0x2f24, 3451, 1), (0x2f25, 3452, 1),
+    (0x2f26, 3453, 1), (0x2f27, 3454, 1), (0x2f28, 3455, 1), (0x2f29, 3456, 1), (0x2f2a, 3457, 1),
+    (0x2f2b, 3458, 1), (0x2f2c, 3459, 1), (0x2f2d, 3460, 1), (0x2f2e, 3461, 1), (0x2f2f, 3462, 1),
+    (0x2f30, 3463, 1), (0x2f31, 3464, 1), (0x2f32, 3465, 1), (0x2f33, 3466, 1), (0x2f34, 3467, 1),
+    (0x2f35, 3468, 1), (0x2f36, 3469, 1), (0x2f37, 3470, 1), (0x2f38, 3471, 1), (0x2f39, 3472, 1),
+    (0x2f3a, 3473, 1), (0x2f3b, 3474, 1), (0x2f3c, 3475, 1), (0x2f3d, 3476, 1), (0x2f3e, 3477, 1),
+    (0x2f3f, 3478, 1), (0x2f40, 3479, 1), (0x2f41, 3480, 1), (0x2f42, 3481, 1), (0x2f43, 3482, 1),
+    (0x2f44, 3483, 1), (0x2f45, 3484, 1), (0x2f46, 3485, 1), (0x2f47, 3486, 1), (0x2f48, 3487, 1),
+    (0x2f49, 3488, 1), (0x2f4a, 3489, 1), (0x2f4b, 3490, 1), (0x2f4c, 3491, 1), (0x2f4d, 3492, 1),
+    (0x2f4e, 3493, 1), (0x2f4f, 3494, 1), (0x2f50, 3495, 1), (0x2f51, 3496, 1), (0x2f52, 3497, 1),
+    (0x2f53, 3498, 1), (0x2f54, 3499, 1), (0x2f55, 3500, 1), (0x2f56, 3501, 1), (0x2f57, 3502, 1),
+    (0x2f58, 3503, 1), (0x2f59, 3504, 1), (0x2f5a, 3505, 1), (0x2f5b, 3506, 1), (0x2f5c, 3507, 1),
+    (0x2f5d, 3508, 1), (0x2f5e, 3509, 1), (0x2f5f, 3510, 1), (0x2f60, 3511, 1), (0x2f61, 3512, 1),
+    (0x2f62, 3513, 1), (0x2f63, 3514, 1), (0x2f64, 3515, 1), (0x2f65, 3516, 1), (0x2f66, 3517, 1),
+    (0x2f67, 3518, 1), (0x2f68, 3519, 1), (0x2f69, 3520, 1), (0x2f6a, 3521, 1), (0x2f6b, 3522, 1),
+    (0x2f6c, 3523, 1), (0x2f6d, 3524, 1), (0x2f6e, 3525, 1), (0x2f6f, 3526, 1), (0x2f70, 3527, 1),
+    (0x2f71, 3528, 1), (0x2f72, 3529, 1), (0x2f73, 3530, 1), (0x2f74, 3531, 1), (0x2f75, 3532, 1),
+    (0x2f76, 3533, 1), (0x2f77, 3534, 1), (0x2f78, 3535, 1), (0x2f79, 3536, 1), (0x2f7a, 3537, 1),
+    (0x2f7b, 3538, 1), (0x2f7c, 3539, 1), (0x2f7d, 3540, 1), (0x2f7e, 3541, 1), (0x2f7f, 3542, 1),
+    (0x2f80, 3543, 1), (0x2f81, 3544, 1), (0x2f82, 3545, 1), (0x2f83, 3546, 1), (0x2f84, 3547, 1),
+    (0x2f85, 3548, 1), (0x2f86, 3549, 1), (0x2f87, 3550, 1), (0x2f88, 3551, 1), (0x2f89, 3552, 1),
+    (0x2f8a, 3553, 1), (0x2f8b, 3554, 1), (0x2f8c, 3555, 1), (0x2f8d, 3556, 1), (0x2f8e, 3557, 1),
+    (0x2f8f, 3558, 1), (0x2f90, 3559, 1), (0x2f91, 3560, 1), (0x2f92, 3561, 1), (0x2f93, 3562, 1),
+    (0x2f94, 3563, 1), (0x2f95, 3564, 1), (0x2f96, 3565, 1), (0x2f97, 3566, 1), (0x2f98, 3567, 1),
+    (0x2f99, 3568, 1), (0x2f9a, 3569, 1), (0x2f9b, 3570, 1), (0x2f9c, 3571, 1), (0x2f9d, 3572, 1),
+    (0x2f9e, 3573, 1), (0x2f9f, 3574, 1), (0x2fa0, 3575, 1), (0x2fa1, 3576, 1), (0x2fa2, 3577, 1),
+    (0x2fa3, 3578, 1), (0x2fa4, 3579, 1), (0x2fa5, 3580, 1), (0x2fa6, 3581, 1), (0x2fa7, 3582, 1),
+    (0x2fa8, 3583, 1), (0x2fa9, 3584, 1), (0x2faa, 3585, 1), (0x2fab, 3586, 1), (0x2fac, 3587, 1),
+    (0x2fad, 3588, 1), (0x2fae, 3589, 1), (0x2faf, 3590, 1), (0x2fb0, 3591, 1), (0x2fb1, 3592, 1),
+    (0x2fb2, 3593, 1), (0x2fb3, 3594, 1), (0x2fb4, 3595, 1), (0x2fb5, 3596, 1), (0x2fb6, 3597, 1),
+    (0x2fb7, 3598, 1), (0x2fb8, 3599, 1), (0x2fb9, 3600, 1), (0x2fba, 3601, 1), (0x2fbb, 3602, 1),
+    (0x2fbc, 3603, 1), (0x2fbd, 3604, 1), (0x2fbe, 3605, 1), (0x2fbf, 3606, 1), (0x2fc0, 3607, 1),
+    (0x2fc1, 3608, 1), (0x2fc2, 3609, 1), (0x2fc3, 3610, 1), (0x2fc4, 3611, 1), (0x2fc5, 3612, 1),
+    (0x2fc6, 3613, 1), (0x2fc7, 3614, 1), (0x2fc8, 3615, 1), (0x2fc9, 3616, 1), (0x2fca, 3617, 1),
+    (0x2fcb, 3618, 1), (0x2fcc, 3619, 1), (0x2fcd, 3620, 1), (0x2fce, 3621, 1), (0x2fcf, 3622, 1),
+    (0x2fd0, 3623, 1), (0x2fd1, 3624, 1), (0x2fd2, 3625, 1), (0x2fd3, 3626, 1), (0x2fd4, 3627, 1),
+    (0x2fd5, 3628, 1), (0x3002, 3629, 1), (0x3003, 3630, 2), (0x3007, 3632, 1), (0x3008, 3633, 1),
+    (0x3009, 3634, 1), (0x3012, 3635, 1), (0x3014, 3636, 1), (0x3015, 3637, 1), (0x301a, 3638, 1),
+    (0x301b, 3639, 1), (0x302c, 3640, 1), (0x302d, 3641, 1), (0x3033, 3642, 1), (0x3036, 3643, 1),
+    (0x3038, 3644, 1), (0x3039, 3645, 1), (0x303a, 3646, 1), (0x304f, 3647, 1), (0x309a, 3648, 1),
+    (0x309b, 3649, 1), (0x309c, 3650, 1), (0x30a0, 3651, 1), (0x30a4, 3652, 1), (0x30a8, 3653, 1),
+    (0x30ab, 3654, 1), (0x30bf, 3655, 1), (0x30c8, 3656, 1), (0x30cb, 3657, 1), (0x30ce, 3658, 1),
+    (0x30cf, 3659, 1), (0x30d8, 3660, 1), (0x30ed, 3661, 1), (0x30fb, 3662, 1), (0x3131, 3663, 1),
+    (0x3132, 3664, 2), (0x3133, 3666, 2), (0x3134, 3668, 1), (0x3135, 3669, 2), (0x3136, 3671, 2),
+    (0x3137, 3673, 1), (0x3138, 3674, 2), (0x3139, 3676, 1), (0x313a, 3677, 2), (0x313b, 3679, 2),
+    (0x313c, 3681, 2), (0x313d, 3683, 2), (0x313e, 3685, 2), (0x313f, 3687, 2), (0x3140, 3689, 2),
+    (0x3141, 3691, 1), (0x3142, 3692, 1), (0x3143, 3693, 2), (0x3144, 3695, 2), (0x3145, 3697, 1),
+    (0x3146, 3698, 2), (0x3147, 3700, 1), (0x3148, 3701, 1), (0x3149, 3702, 2), (0x314a, 3704, 1),
+    (0x314b, 3705, 1), (0x314c, 3706, 1), (0x314d, 3707, 1), (0x314e, 3708, 1), (0x314f, 3709, 1),
+    (0x3150, 3710, 2), (0x3151, 3712, 1), (0x3152, 3713, 2), (0x3153, 3715, 1), (0x3154, 3716, 2),
+    (0x3155, 3718, 1), (0x3156, 3719, 2), (0x3157, 3721, 1), (0x3158, 3722, 2), (0x3159, 3724, 3),
+    (0x315a, 3727, 2), (0x315b, 3729, 1), (0x315c, 3730, 1), (0x315d, 3731, 2), (0x315e, 3733, 3),
+    (0x315f, 3736, 2), (0x3160, 3738, 1), (0x3161, 3739, 1), (0x3162, 3740, 2), (0x3163, 3742, 1),
+    (0x3164, 3743, 1), (0x3165, 3744, 2), (0x3166, 3746, 2), (0x3167, 3748, 2), (0x3168, 3750, 2),
+    (0x3169, 3752, 3), (0x316a, 3755, 2), (0x316b, 3757, 3), (0x316c, 3760, 2), (0x316d, 3762, 2),
+    (0x316e, 3764, 2), (0x316f, 3766, 2), (0x3170, 3768, 2), (0x3171, 3770, 2), (0x3172, 3772, 2),
+    (0x3173, 3774, 2), (0x3174, 3776, 3), (0x3175, 3779, 3), (0x3176, 3782, 2), (0x3177, 3784, 2),
+    (0x3178, 3786, 2), (0x3179, 3788, 3), (0x317a, 3791, 2), (0x317b, 3793, 2), (0x317c, 3795, 2),
+    (0x317d, 3797, 2), (0x317e, 3799, 2), (0x317f, 3801, 1), (0x3180, 3802, 2), (0x3181, 3804, 1),
+    (0x3182, 3805, 2), (0x3183, 3807, 2), (0x3184, 3809, 2), (0x3185, 3811, 2), (0x3186, 3813, 1),
+    (0x3187, 3814, 2), (0x3188, 3816, 3), (0x3189, 3819, 2), (0x318a, 3821, 2), (0x318b, 3823, 3),
+    (0x318c, 3826, 2), (0x318d, 3828, 1), (0x318e, 3829, 2), (0x31d0, 3831, 1), (0x31d1, 3832, 1),
+    (0x31d3, 3833, 1), (0x31d4, 3834, 1), (0x31d6, 3835, 1), (0x31da, 3836, 1), (0x31db, 3837, 1),
+    (0x31df, 3838, 1), (0x31e0, 3839, 1), (0x3200, 3840, 3), (0x3201, 3843, 3), (0x3202, 3846, 3),
+    (0x3203, 3849, 3), (0x3204, 3852, 3), (0x3205, 3855, 3), (0x3206, 3858, 3), (0x3207, 3861, 3),
+    (0x3208, 3864, 3), (0x3209, 3867, 3), (0x320a, 3870, 3), (0x320b, 3873, 3), (0x320c, 3876, 3),
+    (0x320d, 3879, 3), (0x320e, 3882, 4), (0x320f, 3886, 4), (0x3210, 3890, 4), (0x3211, 3894, 4),
+    (0x3212, 3898, 4), (0x3213, 3902, 4), (0x3214, 3906, 4), (0x3215, 3910, 4), (0x3216, 3914, 4),
+    (0x3217, 3918, 4), (0x3218, 3922, 4), (0x3219, 3926, 4), (0x321a, 3930, 4), (0x321b, 3934, 4),
+    (0x321c, 3938, 4), (0x321d, 3942, 7), (0x321e, 3949, 6), (0x3220, 3955, 3), (0x3221, 3958, 3),
+    (0x3222, 3961, 3), (0x3223, 3964, 3), (0x3224, 3967, 3), (0x3225, 3970, 3), (0x3226, 3973, 3),
+    (0x3227, 3976, 3), (0x3228, 3979, 3), (0x3229, 3982, 3), (0x322a, 3985, 3), (0x322b, 3988, 3),
+    (0x322c, 3991, 3), (0x322d, 3994, 3), (0x322e, 3997, 3), (0x322f, 4000, 3), (0x3230, 4003, 3),
+    (0x3231, 4006, 3), (0x3232, 4009, 3), (0x3233, 4012, 3), (0x3234, 4015, 3), (0x3235, 4018, 3),
+    (0x3236, 4021, 3), (0x3237, 4024, 3), (0x3238, 4027, 3), (0x3239, 4030, 3), (0x323a, 4033, 3),
+    (0x323b, 4036, 3), (0x323c, 4039, 3), (0x323d, 4042, 3), (0x323e, 4045, 3), (0x323f, 4048, 3),
+    (0x3240, 4051, 3), (0x3241, 4054, 3), (0x3242, 4057, 3), (0x3243, 4060, 3), (0x32c0, 4063, 2),
+    (0x32c1, 4065, 2), (0x32c2, 4067, 2), (0x32c3, 4069, 2), (0x32c4, 4071, 2), (0x32c5, 4073, 2),
+    (0x32c6, 4075, 2), (0x32c7, 4077, 2), (0x32c8, 4079, 2), (0x32c9, 4081, 3), (0x32ca, 4084, 3),
+    (0x32cb, 4087, 3), (0x3358, 4090, 2), (0x3359, 4092, 2), (0x335a, 4094, 2), (0x335b, 4096, 2),
+    (0x335c, 4098, 2), (0x335d, 4100, 2), (0x335e, 4102, 2), (0x335f, 4104, 2), (0x3360, 4106, 2),
+    (0x3361, 4108, 2), (0x3362, 4110, 3), (0x3363, 4113, 3), (0x3364, 4116, 3), (0x3365, 4119, 3),
+    (0x3366, 4122, 3), (0x3367, 4125, 3), (0x3368, 4128, 3), (0x3369, 4131, 3), (0x336a, 4134, 3),
+    (0x336b, 4137, 3), (0x336c, 4140, 3), (0x336d, 4143, 3), (0x336e, 4146, 3), (0x336f, 4149, 3),
+    (0x3370, 4152, 3), (0x33e0, 4155, 2), (0x33e1, 4157, 2), (0x33e2, 4159, 2), (0x33e3, 4161, 2),
+    (0x33e4, 4163, 2), (0x33e5, 4165, 2), (0x33e6, 4167, 2), (0x33e7, 4169, 2), (0x33e8, 4171, 2),
+    (0x33e9, 4173, 3), (0x33ea, 4176, 3), (0x33eb, 4179, 3), (0x33ec, 4182, 3), (0x33ed, 4185, 3),
+    (0x33ee, 4188, 3), (0x33ef, 4191, 3), (0x33f0, 4194, 3), (0x33f1, 4197, 3), (0x33f2, 4200, 3),
+    (0x33f3, 4203, 3), (0x33f4, 4206, 3), (0x33f5, 4209, 3), (0x33f6, 4212, 3), (0x33f7, 4215, 3),
+    (0x33f8, 4218, 3), (0x33f9, 4221, 3), (0x33fa, 4224, 3), (0x33fb, 4227, 3), (0x33fc, 4230, 3),
+    (0x33fd, 4233, 3), (0x33fe, 4236, 3), (0x39b3, 4239, 1), (0x439b, 4240, 1), (0x4420, 4241, 1),
+    (0x4e00, 4242, 1), (0x4e36, 4243, 1), (0x4e3f, 4244, 1), (0x5002, 4245, 1), (0x503c, 4246, 1),
+    (0x555f, 4247, 1), (0x56d7, 4248, 1), (0x586b, 4249, 1), (0x58eb, 4250, 1), (0x58ff, 4251, 1),
+    (0x5b00, 4252, 1), (0x5e32, 4253, 1), (0x5e50, 4254, 1), (0x6238, 4255, 1), (0x6409, 4256, 1),
+    (0x6663, 4257, 1), (0x6669, 4258, 1), (0x66f6, 4259, 1), (0x6726, 4260, 1), (0x67ff, 4261, 1),
+    (0x69e9, 4262, 1), (0x6a27, 4263, 1), (0x6f59, 4264, 1), (0x784f, 4265, 1), (0x7d76, 4266, 1),
+    (0x80a6, 4267, 1), (0x80ca, 4268, 1), (0x80d0, 4269, 1), (0x80f6, 4270, 1), (0x8101, 4271, 1),
+    (0x8127, 4272, 1), (0x8141, 4273, 1), (0x81a7, 4274, 1), (0x853f, 4275, 1), (0x8641, 4276, 1),
+    (0x8a1e, 4277, 1), (0x8a7d, 4278, 1), (0x8b8f, 4279, 1), (0x8c63, 4280, 1), (0x8d86, 4281, 1),
+    (0x8dfa, 4282, 1), (0x8e9b, 4283, 1), (0x8f27, 4284, 1), (0x90de, 4285, 1), (0x93ae, 4286, 1),
+    (0x96b8, 4287, 1), (0x9e43, 4288, 1), (0x9ed2, 4289, 1), (0x9fc3, 4290, 1), (0xa494, 4291, 1),
+    (0xa49c, 4292, 1), (0xa49e, 4293, 1), (0xa4a7, 4294, 1), (0xa4a8, 4295, 1), (0xa4ac, 4296, 1),
+    (0xa4b0, 4297, 1), (0xa4ba, 4298, 1), (0xa4be, 4299, 1), (0xa4bf, 4300, 1), (0xa4c0, 4301, 1),
+    (0xa4c2, 4302, 1), (0xa4d0, 4303, 1), (0xa4d1, 4304, 1), (0xa4d2, 4305, 1), (0xa4d3, 4306, 1),
+    (0xa4d4, 4307, 1), (0xa4d6, 4308, 1), (0xa4d7, 4309, 1), (0xa4d9, 4310, 1), (0xa4da, 4311, 1),
+    (0xa4db, 4312, 1), (0xa4dc, 4313, 1), (0xa4dd, 4314, 1), (0xa4de, 4315, 1), (0xa4df, 4316, 1),
+    (0xa4e0, 4317, 1), (0xa4e1, 4318, 1), (0xa4e2, 4319, 1), (0xa4e3, 4320, 1), (0xa4e5, 4321, 1),
+    (0xa4e6, 4322, 1), (0xa4e7, 4323, 1), (0xa4ea, 4324, 1), (0xa4eb, 4325, 1), (0xa4ec, 4326, 1),
+    (0xa4ed, 4327, 1), (0xa4ee, 4328, 1), (0xa4ef, 4329, 1), (0xa4f0, 4330, 1), (0xa4f1, 4331, 1),
+    (0xa4f2, 4332, 1), (0xa4f3, 4333, 1), (0xa4f4, 4334, 1), (0xa4f5, 4335, 1), (0xa4f7, 4336, 1),
+    (0xa4f8, 4337, 1), (0xa4f9, 4338, 1), (0xa4fa, 4339, 2), (0xa4fb, 4341, 2), (0xa4fd, 4343, 1),
+    (0xa4fe, 4344, 2), (0xa4ff, 4346, 1), (0xa60e, 4347, 1), (0xa644, 4348, 1), (0xa645, 4349, 1),
+    (0xa647, 4350, 1), (0xa64d, 4351, 1), (0xa650, 4352, 2), (0xa651, 4354, 3), (0xa668, 4357, 1),
+    (0xa66f, 4358, 1), (0xa67c, 4359, 1), (0xa67e, 4360, 1), (0xa695, 4361, 2), (0xa698, 4363, 2),
+    (0xa699, 4365, 2), (0xa69a, 4367, 1), (0xa6a1, 4368, 1), (0xa6b0, 4369, 1), (0xa6b1, 4370, 1),
+    (0xa6cd, 4371, 1), (0xa6ce, 4372, 1), (0xa6db, 4373, 1), (0xa6df, 4374, 1), (0xa6eb, 4375, 1),
+    (0xa6ef, 4376, 1), (0xa6f0, 4377, 1), (0xa6f1, 4378, 1), (0xa6f4, 4379, 2), (0xa714, 4381, 1),
+    (0xa716, 4382, 1), (0xa728, 4383, 2), (0xa729, 4385, 2), (0xa731, 4387, 1), (0xa732, 4388, 2),
+    (0xa733, 4390, 2), (0xa734, 4392, 2), (0xa735, 4394, 2), (0xa736, 4396, 2), (0xa737, 4398, 2),
+    (0xa738, 4400, 2), (0xa739, 4402, 2), (0xa73a, 4404, 2), (0xa73b, 4406, 2), (0xa73c, 4408, 2),
+    (0xa73d, 4410, 2), (0xa740, 4412, 2), (0xa74a, 4414, 2), (0xa74b, 4416, 2), (0xa74e, 4418, 2),
+    (0xa74f, 4420, 2), (0xa75a, 4422, 1), (0xa761, 4423, 2), (0xa76a, 4425, 1), (0xa76b, 4426, 1),
+    (0xa76e, 4427, 1), (0xa777, 4428, 2), (0xa778, 4430, 1), (0xa77a, 4431, 1), (0xa789, 4432, 1),
+    (0xa78c, 4433, 1), (0xa78f, 4434, 1), (0xa795, 4435, 1), (0xa798, 4436, 1), (0xa799, 4437, 1),
+    (0xa79a, 4438, 1), (0xa79b, 4439, 1), (0xa79d, 4440, 1), (0xa79e, 4441, 1), (0xa79f, 4442, 1),
+    (0xa7ab, 4443, 1), (0xa7b1, 4444, 1), (0xa7b2, 4445, 1), (0xa7b3, 4446, 1), (0xa7b4, 4447, 1),
+    (0xa7b5, 4448, 1), (0xa7b6, 4449, 1), (0xa7b7, 4450, 1), (0xa7f7, 4451, 1), (0xa830, 4452, 1),
+    (0xa960, 4453, 2), (0xa961, 4455, 2), (0xa962, 4457, 2), (0xa963, 4459, 2), (0xa964, 4461, 2),
+    (0xa965, 4463, 3), (0xa966, 4466, 2), (0xa967, 4468, 3), (0xa968, 4471, 2), (0xa969, 4473, 2),
+    (0xa96a, 4475, 3), (0xa96b, 4478, 3), (0xa96c, 4481, 2), (0xa96d, 4483, 2), (0xa96e, 4485, 2),
+    (0xa96f, 4487, 2), (0xa970, 4489, 2), (0xa971, 4491, 2), (0xa972, 4493, 3), (0xa973, 4496, 2),
+    (0xa974, 4498, 2), (0xa975, 4500, 3), (0xa976, 4503, 2), (0xa977, 4505, 2), (0xa978, 4507, 3),
+    (0xa979, 4510, 2), (0xa97a, 4512, 2), (0xa97b, 4514, 2), (0xa97c, 4516, 2), (0xa992, 4518, 1),
+    (0xa9a3, 4519, 1), (0xa9c6, 4520, 1), (0xa9cf, 4521, 1), (0xaa53, 4522, 1), (0xaa56, 4523, 1),
+    (0xab32, 4524, 1), (0xab35, 4525, 1), (0xab3d, 4526, 1), (0xab3e, 4527, 2), (0xab3f, 4529, 2),
+    (0xab41, 4531, 3), (0xab42, 4534, 3), (0xab47, 4537, 1), (0xab48, 4538, 1), (0xab4d, 4539, 1),
+    (0xab4e, 4540, 1), (0xab52, 4541, 1), (0xab53, 4542, 1), (0xab55, 4543, 1), (0xab5a, 4544, 1),
+    (0xab60, 4545, 1), (0xab62, 4546, 2), (0xab63, 4548, 2), (0xab70, 4550, 1), (0xab71, 4551, 1),
+    (0xab72, 4552, 1), (0xab74, 4553, 2), (0xab75, 4555, 1), (0xab7a, 4556, 1), (0xab7b, 4557, 1),
+    (0xab7c, 4558, 1), (0xab7e, 4559, 1), (0xab80, 4560, 1), (0xab81, 4561, 1), (0xab83, 4562, 1),
+    (0xab87, 4563, 1), (0xab8b, 4564, 1), (0xab8e, 4565, 2), (0xab90, 4567, 1), (0xab93, 4568, 1),
+    (0xab9b, 4569, 1), (0xab9c, 4570, 2), (0xab9f, 4572, 1), (0xaba2, 4573, 1), (0xaba9, 4574, 1),
+    (0xabaa, 4575, 1), (0xabae, 4576, 1), (0xabaf, 4577, 1), (0xabb2, 4578, 1), (0xabb6, 4579, 1),
+    (0xabbb, 4580, 2), (0xd7b0, 4582, 2), (0xd7b1, 4584, 3), (0xd7b2, 4587, 2), (0xd7b3, 4589, 3),
+    (0xd7b4, 4592, 2), (0xd7b5, 4594, 2), (0xd7b6, 4596, 3), (0xd7b7, 4599, 3), (0xd7b8, 4602, 2),
+    (0xd7b9, 4604, 2), (0xd7ba, 4606, 2), (0xd7bb, 4608, 3), (0xd7bc, 4611, 2), (0xd7bd, 4613, 3),
+    (0xd7be, 4616, 3), (0xd7bf, 4619, 2), (0xd7c0, 4621, 3), (0xd7c1, 4624, 3), (0xd7c2, 4627, 2),
+    (0xd7c3, 4629, 2), (0xd7c4, 4631, 2), (0xd7c5, 4633, 2), (0xd7c6, 4635, 3), (0xd7cb, 4638, 2),
+    (0xd7cc, 4640, 2), (0xd7cd, 4642, 2), (0xd7ce, 4644, 3), (0xd7cf, 4647, 2), (0xd7d0, 4649, 2),
+    (0xd7d1, 4651, 3), (0xd7d2, 4654, 2), (0xd7d3, 4656, 2), (0xd7d4, 4658, 2), (0xd7d5, 4660, 3),
+    (0xd7d6, 4663, 3), (0xd7d7, 4666, 3), (0xd7d8, 4669, 3), (0xd7d9, 4672, 3), (0xd7da, 4675, 3),
+    (0xd7db, 4678, 2), (0xd7dc, 4680, 3), (0xd7dd, 4683, 2), (0xd7de, 4685, 2), (0xd7df, 4687, 3),
+    (0xd7e0, 4690, 2), (0xd7e1, 4692, 3), (0xd7e2, 4695, 2), (0xd7e3, 4697, 2), (0xd7e4, 4699, 3),
+    (0xd7e5, 4702, 2), (0xd7e6, 4704, 2), (0xd7e7, 4706, 3), (0xd7e8, 4709, 2), (0xd7e9, 4711, 2),
+    (0xd7ea, 4713, 2), (0xd7eb, 4715, 3), (0xd7ec, 4718, 3), (0xd7ed, 4721, 3), (0xd7ee, 4724, 2),
+    (0xd7ef, 4726, 2), (0xd7f0, 4728, 2), (0xd7f1, 4730, 2), (0xd7f2, 4732, 2), (0xd7f3, 4734, 2),
+    (0xd7f4, 4736, 3), (0xd7f5, 4739, 2), (0xd7f6, 4741, 2), (0xd7f7, 4743, 2), (0xd7f8, 4745, 3),
+    (0xd7f9, 4748, 2), (0xd7fa, 4750, 2), (0xd7fb, 4752, 2), (0xf900, 4754, 1), (0xf901, 4755, 1),
+    (0xf902, 4756, 1), (0xf903, 4757, 1), (0xf904, 4758, 1), (0xf905, 4759, 1), (0xf906, 4760, 1),
+    (0xf907, 4761, 1), (0xf908, 4762, 1), (0xf909, 4763, 1), (0xf90a, 4764, 1), (0xf90b, 4765, 1),
+    (0xf90c, 4766, 1), (0xf90d, 4767, 1), (0xf90e, 4768, 1), (0xf90f, 4769, 1), (0xf910, 4770, 1),
+    (0xf911, 4771, 1), (0xf912, 4772, 1), (0xf913, 4773, 1), (0xf914, 4774, 1), (0xf915, 4775, 1),
+    (0xf916, 4776, 1), (0xf917, 4777, 1), (0xf918, 4778, 1), (0xf919, 4779, 1), (0xf91a, 4780, 1),
+    (0xf91b, 4781, 1), (0xf91c, 4782, 1), (0xf91d, 4783, 1), (0xf91e, 4784, 1), (0xf91f, 4785, 1),
+    (0xf920, 4786, 1), (0xf921, 4787, 1), (0xf922, 4788, 1), (0xf923, 4789, 1), (0xf924, 4790, 1),
+    (0xf925, 4791, 1), (0xf926, 4792, 1), (0xf927, 4793, 1), (0xf928, 4794, 1), (0xf929, 4795, 1),
+    (0xf92a, 4796, 1), (0xf92b, 4797, 1), (0xf92c, 4798, 1), (0xf92d, 4799, 1), (0xf92e, 4800, 1),
+    (0xf92f, 4801, 1), (0xf930, 4802, 1), (0xf931, 4803, 1), (0xf932, 4804, 1), (0xf933, 4805, 1),
+    (0xf934, 4806, 1), (0xf935, 4807, 1), (0xf936, 4808, 1), (0xf937, 4809, 1), (0xf938, 4810, 1),
+    (0xf939, 4811, 1), (0xf93a, 4812, 1), (0xf93b, 4813, 1), (0xf93c, 4814, 1), (0xf93d, 4815, 1),
+    (0xf93e, 4816, 1), (0xf93f, 4817, 1), (0xf940, 4818, 1), (0xf941, 4819, 1), (0xf942, 4820, 1),
+    (0xf943, 4821, 1), (0xf944, 4822, 1), (0xf945, 4823, 1), (0xf946, 4824, 1), (0xf947, 4825, 1),
+    (0xf948, 4826, 1), (0xf949, 4827, 1), (0xf94a, 4828, 1), (0xf94b, 4829, 1), (0xf94c, 4830, 1),
+    (0xf94d, 4831, 1), (0xf94e, 4832, 1), (0xf94f, 4833, 1), (0xf950, 4834, 1), (0xf951, 4835, 1),
+    (0xf952, 4836, 1), (0xf953, 4837, 1), (0xf954, 4838, 1), (0xf955, 4839, 1), (0xf956, 4840, 1),
+    (0xf957, 4841, 1), (0xf958, 4842, 1), (0xf959, 4843, 1), (0xf95a, 4844, 1), (0xf95b, 4845, 1),
+    (0xf95c, 4846, 1), (0xf95d, 4847, 1), (0xf95e, 4848, 1), (0xf95f, 4849, 1), (0xf960, 4850, 1),
+    (0xf961, 4851, 1), (0xf962, 4852, 1), (0xf963, 4853, 1), (0xf964, 4854, 1), (0xf965, 4855, 1),
+    (0xf966, 4856, 1), (0xf967, 4857, 1), (0xf968, 4858, 1), (0xf969, 4859, 1), (0xf96a, 4860, 1),
+    (0xf96b, 4861, 1), (0xf96c, 4862, 1), (0xf96d, 4863, 1), (0xf96e, 4864, 1), (0xf96f, 4865, 1),
+    (0xf970, 4866, 1), (0xf971, 4867, 1), (0xf972, 4868, 1), (0xf973, 4869, 1), (0xf974, 4870, 1),
+    (0xf975, 4871, 1), (0xf976, 4872, 1), (0xf977, 4873, 1), (0xf978, 4874, 1), (0xf979, 4875, 1),
+    (0xf97a, 4876, 1), (0xf97b, 4877, 1), (0xf97c, 4878, 1), (0xf97d, 4879, 1), (0xf97e, 4880, 1),
+    (0xf97f, 4881, 1), (0xf980, 4882, 1), (0xf981, 4883, 1), (0xf982, 4884, 1), (0xf983, 4885, 1),
+    (0xf984, 4886, 1), (0xf985, 4887, 1), (0xf986, 4888, 1), (0xf987, 4889, 1), (0xf988, 4890, 1),
+    (0xf989, 4891, 1), (0xf98a, 4892, 1), (0xf98b, 4893, 1), (0xf98c, 4894, 1), (0xf98d, 4895, 1),
+    (0xf98e, 4896, 1), (0xf98f, 4897, 1), (0xf990, 4898, 1), (0xf991, 4899, 1), (0xf992, 4900, 1),
+    (0xf993, 4901, 1), (0xf994, 4902, 1), (0xf995, 4903, 1), (0xf996, 4904, 1), (0xf997, 4905, 1),
+    (0xf998, 4906, 1), (0xf999, 4907, 1), (0xf99a, 4908, 1), (0xf99b, 4909, 1), (0xf99c, 4910, 1),
+    (0xf99d, 4911, 1), (0xf99e, 4912, 1), (0xf99f, 4913, 1), (0xf9a0, 4914, 1), (0xf9a1, 4915, 1),
+    (0xf9a2, 4916, 1), (0xf9a3, 4917, 1), (0xf9a4, 4918, 1), (0xf9a5, 4919, 1), (0xf9a6, 4920, 1),
+    (0xf9a7, 4921, 1), (0xf9a8, 4922, 1), (0xf9a9, 4923, 1), (0xf9aa, 4924, 1), (0xf9ab, 4925, 1),
+    (0xf9ac, 4926, 1), (0xf9ad, 4927, 1), (0xf9ae, 4928, 1), (0xf9af, 4929, 1), (0xf9b0, 4930, 1),
+    (0xf9b1, 4931, 1), (0xf9b2, 4932, 1), (0xf9b3, 4933, 1), (0xf9b4, 4934, 1), (0xf9b5, 4935, 1),
+    (0xf9b6, 4936, 1), (0xf9b7, 4937, 1), (0xf9b8, 4938, 1), (0xf9b9, 4939, 1), (0xf9ba, 4940, 1),
+    (0xf9bb, 4941, 1), (0xf9bc, 4942, 1), (0xf9bd, 4943, 1), (0xf9be, 4944, 1), (0xf9bf, 4945, 1),
+    (0xf9c0, 4946, 1), (0xf9c1, 4947, 1), (0xf9c2, 4948, 1), (0xf9c3, 4949, 1), (0xf9c4, 4950, 1),
+    (0xf9c5, 4951, 1), (0xf9c6, 4952, 1), (0xf9c7, 4953, 1), (0xf9c8, 4954, 1), (0xf9c9, 4955, 1),
+    (0xf9ca, 4956, 1), (0xf9cb, 4957, 1), (0xf9cc, 4958, 1), (0xf9cd, 4959, 1), (0xf9ce, 4960, 1),
+    (0xf9cf, 4961, 1), (0xf9d0, 4962, 1), (0xf9d1, 4963, 1), (0xf9d2, 4964, 1), (0xf9d3, 4965, 1),
+    (0xf9d4, 4966, 1), (0xf9d5, 4967, 1), (0xf9d6, 4968, 1), (0xf9d7, 4969, 1), (0xf9d8, 4970, 1),
+    (0xf9d9, 4971, 1), (0xf9da, 4972, 1), (0xf9db, 4973, 1), (0xf9dc, 4974, 1), (0xf9dd, 4975, 1),
+    (0xf9de, 4976, 1), (0xf9df, 4977, 1), (0xf9e0, 4978, 1), (0xf9e1, 4979, 1), (0xf9e2, 4980, 1),
+    (0xf9e3, 4981, 1), (0xf9e4, 4982, 1), (0xf9e5, 4983, 1), (0xf9e6, 4984, 1), (0xf9e7, 4985, 1),
+    (0xf9e8, 4986, 1), (0xf9e9, 4987, 1), (0xf9ea, 4988, 1), (0xf9eb, 4989, 1), (0xf9ec, 4990, 1),
+    (0xf9ed, 4991, 1), (0xf9ee, 4992, 1), (0xf9ef, 4993, 1), (0xf9f0, 4994, 1), (0xf9f1, 4995, 1),
+    (0xf9f2, 4996, 1), (0xf9f3, 4997, 1), (0xf9f4, 4998, 1), (0xf9f5, 4999, 1), (0xf9f6, 5000, 1),
+    (0xf9f7, 5001, 1), (0xf9f8, 5002, 1), (0xf9f9, 5003, 1), (0xf9fa, 5004, 1), (0xf9fb, 5005, 1),
+    (0xf9fc, 5006, 1), (0xf9fd, 5007, 1), (0xf9fe, 5008, 1), (0xf9ff, 5009, 1), (0xfa00, 5010, 1),
+    (0xfa01, 5011, 1), (0xfa02, 5012, 1), (0xfa03, 5013, 1), (0xfa04, 5014, 1), (0xfa05, 5015, 1),
+    (0xfa06, 5016, 1), (0xfa07, 5017, 1), (0xfa08, 5018, 1), (0xfa09, 5019, 1), (0xfa0a, 5020, 1),
+    (0xfa0b, 5021, 1), (0xfa0c, 5022, 1), (0xfa0d, 5023, 1), (0xfa10, 5024, 1), (0xfa12, 5025, 1),
+    (0xfa15, 5026, 1), (0xfa16, 5027, 1), (0xfa17, 5028, 1), (0xfa18, 5029, 1), (0xfa19, 5030, 1),
+    (0xfa1a, 5031, 1), (0xfa1b, 5032, 1), (0xfa1c, 5033, 1), (0xfa1d, 5034, 1), (0xfa1e, 5035, 1),
+    (0xfa20, 5036, 1), (0xfa22, 5037, 1), (0xfa25, 5038, 1), (0xfa26, 5039, 1), (0xfa2a, 5040, 1),
+    (0xfa2b, 5041, 1), (0xfa2c, 5042, 1), (0xfa2d, 5043, 1), (0xfa2e, 5044, 1), (0xfa2f, 5045, 1),
+    (0xfa30, 5046, 1), (0xfa31, 5047, 1), (0xfa32, 5048, 1), (0xfa33, 5049, 1), (0xfa34, 5050, 1),
+    (0xfa35, 5051, 1), (0xfa36, 5052, 1), (0xfa37, 5053, 1), (0xfa38, 5054, 1), (0xfa39, 5055, 1),
+    (0xfa3a, 5056, 1), (0xfa3b, 5057, 1), (0xfa3c, 5058, 1), (0xfa3d, 5059, 1), (0xfa3e, 5060, 1),
+    (0xfa3f, 5061, 1), (0xfa40, 5062, 1), (0xfa41, 5063, 1), (0xfa42, 5064, 1), (0xfa43, 5065, 1),
+    (0xfa44, 5066, 1), (0xfa45, 5067, 1), (0xfa46, 5068, 1), (0xfa47, 5069, 1), (0xfa48, 5070, 1),
+    (0xfa49, 5071, 1), (0xfa4a, 5072, 1), (0xfa4b, 5073, 1), (0xfa4c, 5074, 1), (0xfa4d, 5075, 1),
+    (0xfa4e, 5076, 1), (0xfa4f, 5077, 1), (0xfa50, 5078, 1), (0xfa51, 5079, 1), (0xfa52, 5080, 1),
+    (0xfa53, 5081, 1), (0xfa54, 5082, 1), (0xfa55, 5083, 1), (0xfa56, 5084, 1), (0xfa57, 5085, 1),
+    (0xfa58, 5086, 1), (0xfa59, 5087, 1), (0xfa5a, 5088, 1), (0xfa5b, 5089, 1), (0xfa5c, 5090, 1),
+    (0xfa5d, 5091, 1), (0xfa5e, 5092, 1), (0xfa5f, 5093, 1), (0xfa60, 5094, 1), (0xfa61, 5095, 1),
+    (0xfa62, 5096, 1), (0xfa63, 5097, 1), (0xfa64, 5098, 1), (0xfa65, 5099, 1), (0xfa66, 5100, 1),
+    (0xfa67, 5101, 1), (0xfa68, 5102, 1), (0xfa69, 5103, 1), (0xfa6a, 5104, 1), (0xfa6b, 5105, 1),
+    (0xfa6c, 5106, 1), (0xfa6d, 5107, 1), (0xfa70, 5108, 1), (0xfa71, 5109, 1), (0xfa72, 5110, 1),
+    (0xfa73, 5111, 1), (0xfa74, 5112, 1), (0xfa75, 5113, 1), (0xfa76, 5114, 1), (0xfa77, 5115, 1),
+    (0xfa78, 5116, 1), (0xfa79, 5117, 1), (0xfa7a, 5118, 1), (0xfa7b, 5119, 1), (0xfa7c, 5120, 1),
+    (0xfa7d, 5121, 1), (0xfa7e, 5122, 1), (0xfa7f, 5123, 1), (0xfa80, 5124, 1), (0xfa81, 5125, 1),
+    (0xfa82, 5126, 1), (0xfa83, 5127, 1), (0xfa84, 5128, 1), (0xfa85, 5129, 1), (0xfa86, 5130, 1),
+    (0xfa87, 5131, 1), (0xfa88, 5132, 1), (0xfa89, 5133, 1), (0xfa8a, 5134, 1), (0xfa8b, 5135, 1),
+    (0xfa8c, 5136, 1), (0xfa8d, 5137, 1), (0xfa8e, 5138, 1), (0xfa8f, 5139, 1), (0xfa90, 5140, 1),
+    (0xfa91, 5141, 1), (0xfa92, 5142, 1), (0xfa93, 5143, 1), (0xfa94, 5144, 1), (0xfa95, 5145, 1),
+    (0xfa96, 5146, 1), (0xfa97, 5147, 1), (0xfa98, 5148, 1), (0xfa99, 5149, 1), (0xfa9a, 5150, 1),
+    (0xfa9b, 5151, 1), (0xfa9c, 5152, 1), (0xfa9d, 5153, 1), (0xfa9e, 5154, 1), (0xfa9f, 5155, 1),
+    (0xfaa0, 5156, 1), (0xfaa1, 5157, 1), (0xfaa2, 5158, 1), (0xfaa3, 5159, 1), (0xfaa4, 5160, 1),
+    (0xfaa5, 5161, 1), (0xfaa6, 5162, 1), (0xfaa7, 5163, 1), (0xfaa8, 5164, 1), (0xfaa9, 5165, 1),
+    (0xfaaa, 5166, 1), (0xfaab, 5167, 1), (0xfaac, 5168, 1), (0xfaad, 5169, 1), (0xfaae, 5170, 1),
+    (0xfaaf, 5171, 1), (0xfab0, 5172, 1), (0xfab1, 5173, 1), (0xfab2, 5174, 1), (0xfab3, 5175, 1),
+    (0xfab4, 5176, 1), (0xfab5, 5177, 1), (0xfab6, 5178, 1), (0xfab7, 5179, 1), (0xfab8, 5180, 1),
+    (0xfab9, 5181, 1), (0xfaba, 5182, 1), (0xfabb, 5183, 1), (0xfabc, 5184, 1), (0xfabd, 5185, 1),
+    (0xfabe, 5186, 1), (0xfabf, 5187, 1), (0xfac0, 5188, 1), (0xfac1, 5189, 1), (0xfac2, 5190, 1),
+    (0xfac3, 5191, 1), (0xfac4, 5192, 1), (0xfac5, 5193, 1), (0xfac6, 5194, 1), (0xfac7, 5195, 1),
+    (0xfac8, 5196, 1), (0xfac9, 5197, 1), (0xfaca, 5198, 1), (0xfacb, 5199, 1), (0xfacc, 5200, 1),
+    (0xfacd, 5201, 1), (0xface, 5202, 1), (0xfacf, 5203, 1), (0xfad0, 5204, 1), (0xfad1, 5205, 1),
+    (0xfad2, 5206, 1), (0xfad3, 5207, 1), (0xfad4, 5208, 1), (0xfad5, 5209, 1), (0xfad6, 5210, 1),
+    (0xfad7, 5211, 1), (0xfad8, 5212, 1), (0xfad9, 5213, 1), (0xfb00, 5214, 2), (0xfb01, 5216, 2),
+    (0xfb02, 5218, 2), (0xfb03, 5220, 3), (0xfb04, 5223, 3), (0xfb06, 5226, 2), (0xfb13, 5228, 2),
+    (0xfb14, 5230, 2), (0xfb15, 5232, 2), (0xfb16, 5234, 2), (0xfb17, 5236, 2), (0xfb20, 5238, 1),
+    (0xfb21, 5239, 1), (0xfb22, 5240, 1), (0xfb23, 5241, 1), (0xfb24, 5242, 1), (0xfb25, 5243, 1),
+    (0xfb26, 5244, 1), (0xfb27, 5245, 1), (0xfb28, 5246, 1), (0xfb29, 5247, 2), (0xfb2b, 5249, 2),
+    (0xfb2d, 5251, 3), (0xfb2f, 5254, 2), (0xfb30, 5256, 2), (0xfb39, 5258, 2), (0xfb49, 5260, 2),
+    (0xfb4f, 5262, 2), (0xfb50, 5264, 1), (0xfb51, 5265, 1), (0xfb52, 5266, 1), (0xfb53, 5267, 1),
+    (0xfb54, 5268, 1), (0xfb55, 5269, 1), (0xfb56, 5270, 2), (0xfb57, 5272, 2), (0xfb58, 5274, 2),
+    (0xfb59, 5276, 2), (0xfb5a, 5278, 1), (0xfb5b, 5279, 1), (0xfb5c, 5280, 1), (0xfb5d, 5281, 1),
+    (0xfb5e, 5282, 1), (0xfb5f, 5283, 1), (0xfb60, 5284, 1), (0xfb61, 5285, 1), (0xfb62, 5286, 1),
+    (0xfb63, 5287, 1), (0xfb64, 5288, 1), (0xfb65, 5289, 1), (0xfb66, 5290, 2), (0xfb67, 5292, 2),
+    (0xfb68, 5294, 2), (0xfb69, 5296, 2), (0xfb6a, 5298, 2), (0xfb6b, 5300, 2), (0xfb6c, 5302, 2),
+    (0xfb6d, 5304, 2), (0xfb6e, 5306, 1), (0xfb6f, 5307, 1), (0xfb70, 5308, 1), (0xfb71, 5309, 1),
+    (0xfb72, 5310, 1), (0xfb73, 5311, 1), (0xfb74, 5312, 1), (0xfb75, 5313, 1), (0xfb76, 5314, 1),
+    (0xfb77, 5315, 1), (0xfb78, 5316, 1), (0xfb79, 5317, 1), (0xfb7a, 5318, 1), (0xfb7b, 5319, 1),
+    (0xfb7c, 5320, 1), (0xfb7d, 5321, 1), (0xfb7e, 5322, 1), (0xfb7f, 5323, 1), (0xfb80, 5324, 1),
+    (0xfb81, 5325, 1), (0xfb82, 5326, 1), (0xfb83, 5327, 1), (0xfb84, 5328, 1), (0xfb85, 5329, 1),
+    (0xfb86, 5330, 2), (0xfb87, 5332, 2), (0xfb88, 5334, 2), (0xfb89, 5336, 2), (0xfb8a, 5338, 2),
+    (0xfb8b, 5340, 2), (0xfb8c, 5342, 2), (0xfb8d, 5344, 2), (0xfb8e, 5346, 1), (0xfb8f, 5347, 1),
+    (0xfb90, 5348, 1), (0xfb91, 5349, 1), (0xfb92, 5350, 1), (0xfb93, 5351, 1), (0xfb94, 5352, 1),
+    (0xfb95, 5353, 1), (0xfb96, 5354, 1), (0xfb97, 5355, 1), (0xfb98, 5356, 1), (0xfb99, 5357, 1),
+    (0xfb9a, 5358, 1), (0xfb9b, 5359, 1), (0xfb9c, 5360, 1), (0xfb9d, 5361, 1), (0xfb9e, 5362, 1),
+    (0xfb9f, 5363, 1), (0xfba0, 5364, 2), (0xfba1, 5366, 2), (0xfba2, 5368, 2), (0xfba3, 5370, 2),
+    (0xfba4, 5372, 2), (0xfba5, 5374, 2), (0xfba6, 5376, 1), (0xfba7, 5377, 1), (0xfba8, 5378, 1),
+    (0xfba9, 5379, 1), (0xfbaa, 5380, 1), (0xfbab, 5381, 1), (0xfbac, 5382, 1), (0xfbad, 5383, 1),
+    (0xfbae, 5384, 1), (0xfbaf, 5385, 1), (0xfbb0, 5386, 2), (0xfbb1, 5388, 2), (0xfbd3, 5390, 2),
+    (0xfbd4, 5392, 2), (0xfbd5, 5394, 2), (0xfbd6, 5396, 2), (0xfbd7, 5398, 2), (0xfbd8, 5400, 2),
+    (0xfbd9, 5402, 2), (0xfbda, 5404, 2), (0xfbdb, 5406, 2), (0xfbdc, 5408, 2), (0xfbdd, 5410, 3),
+    (0xfbde, 5413, 2), (0xfbdf, 5415, 2), (0xfbe0, 5417, 1), (0xfbe1, 5418, 1), (0xfbe2, 5419, 2),
+    (0xfbe3, 5421, 2), (0xfbe4, 5423, 1), (0xfbe5, 5424, 1), (0xfbe6, 5425, 1), (0xfbe7, 5426, 1),
+    (0xfbe8, 5427, 1), (0xfbe9, 5428, 1), (0xfbea, 5429, 3), (0xfbeb, 5432, 3), (0xfbec, 5435, 3),
+    (0xfbed, 5438, 3), (0xfbee, 5441, 3), (0xfbef, 5444, 3), (0xfbf0, 5447, 4), (0xfbf1, 5451, 4),
+    (0xfbf2, 5455, 4), (0xfbf3, 5459, 4), (0xfbf4, 5463, 4), (0xfbf5, 5467, 4), (0xfbf6, 5471, 3),
+    (0xfbf7, 5474, 3), (0xfbf8, 5477, 3), (0xfbf9, 5480, 3), (0xfbfa, 5483, 3), (0xfbfb, 5486, 3),
+    (0xfbfc, 5489, 1), (0xfbfd, 5490, 1), (0xfbfe, 5491, 1), (0xfbff, 5492, 1), (0xfc00, 5493, 3),
+    (0xfc01, 5496, 3), (0xfc02, 5499, 3), (0xfc03, 5502, 3), (0xfc04, 5505, 3), (0xfc05, 5508, 2),
+    (0xfc06, 5510, 2), (0xfc07, 5512, 2), (0xfc08, 5514, 2), (0xfc09, 5516, 2), (0xfc0a, 5518, 2),
+    (0xfc0b, 5520, 2), (0xfc0c, 5522, 2), (0xfc0d, 5524, 2), (0xfc0e, 5526, 2), (0xfc0f, 5528, 2),
+    (0xfc10, 5530, 2), (0xfc11, 5532, 3), (0xfc12, 5535, 3), (0xfc13, 5538, 3), (0xfc14, 5541, 3),
+    (0xfc15, 5544, 2), (0xfc16, 5546, 2), (0xfc17, 5548, 2), (0xfc18, 5550, 2), (0xfc19, 5552, 2),
+    (0xfc1a, 5554, 2), (0xfc1b, 5556, 2), (0xfc1c, 5558, 2), (0xfc1d, 5560, 2), (0xfc1e, 5562, 2),
+    (0xfc1f, 5564, 2), (0xfc20, 5566, 2), (0xfc21, 5568, 2), (0xfc22, 5570, 2), (0xfc23, 5572, 2),
+    (0xfc24, 5574, 2), (0xfc25, 5576, 2), (0xfc26, 5578, 2), (0xfc27, 5580, 2), (0xfc28, 5582, 2),
+    (0xfc29, 5584, 2), (0xfc2a, 5586, 2), (0xfc2b, 5588, 2), (0xfc2c, 5590, 2), (0xfc2d, 5592, 2),
+    (0xfc2e, 5594, 2), (0xfc2f, 5596, 2), (0xfc30, 5598, 2), (0xfc31, 5600, 2), (0xfc32, 5602, 2),
+    (0xfc33, 5604, 2), (0xfc34, 5606, 2), (0xfc35, 5608, 2), (0xfc36, 5610, 2), (0xfc37, 5612, 2),
+    (0xfc38, 5614, 2), (0xfc39, 5616, 2), (0xfc3a, 5618, 2), (0xfc3b, 5620, 2), (0xfc3c, 5622, 2),
+    (0xfc3d, 5624, 2), (0xfc3e, 5626, 2), (0xfc3f, 5628, 2), (0xfc40, 5630, 2), (0xfc41, 5632, 2),
+    (0xfc42, 5634, 2), (0xfc43, 5636, 2), (0xfc44, 5638, 2), (0xfc45, 5640, 2), (0xfc46, 5642, 2),
+    (0xfc47, 5644, 2), (0xfc48, 5646, 2), (0xfc49, 5648, 2), (0xfc4a, 5650, 2), (0xfc4b, 5652, 2),
+    (0xfc4c, 5654, 2), (0xfc4d, 5656, 2), (0xfc4e, 5658, 2), (0xfc4f, 5660, 2), (0xfc50, 5662, 2),
+    (0xfc51, 5664, 2), (0xfc52, 5666, 2), (0xfc53, 5668, 2), (0xfc54, 5670, 2), (0xfc55, 5672, 2),
+    (0xfc56, 5674, 2), (0xfc57, 5676, 2), (0xfc58, 5678, 2), (0xfc59, 5680, 2), (0xfc5a, 5682, 2),
+    (0xfc5b, 5684, 2), (0xfc5c, 5686, 2), (0xfc5d, 5688, 2), (0xfc5e, 5690, 2), (0xfc5f, 5692, 2),
+    (0xfc60, 5694, 2), (0xfc61, 5696, 2), (0xfc62, 5698, 2), (0xfc63, 5700, 2), (0xfc64, 5702, 3),
+    (0xfc65, 5705, 3), (0xfc66, 5708, 3), (0xfc67, 5711, 3), (0xfc68, 5714, 3), (0xfc69, 5717, 3),
+    (0xfc6a, 5720, 2), (0xfc6b, 5722, 2), (0xfc6c, 5724, 2), (0xfc6d, 5726, 2), (0xfc6e, 5728, 2),
+    (0xfc6f, 5730, 2), (0xfc70, 5732, 2), (0xfc71, 5734, 2), (0xfc72, 5736, 2), (0xfc73, 5738, 2),
+    (0xfc74, 5740, 2), (0xfc75, 5742, 2), (0xfc76, 5744, 3), (0xfc77, 5747, 3), (0xfc78, 5750, 3),
+    (0xfc79, 5753, 3), (0xfc7a, 5756, 3), (0xfc7b, 5759, 3), (0xfc7c, 5762, 2), (0xfc7d, 5764, 2),
+    (0xfc7e, 5766, 2), (0xfc7f, 5768, 2), (0xfc80, 5770, 2), (0xfc81, 5772, 2), (0xfc82, 5774, 2),
+    (0xfc83, 5776, 2), (0xfc84, 5778, 2), (0xfc85, 5780, 2), (0xfc86, 5782, 2), (0xfc87, 5784, 2),
+    (0xfc88, 5786, 2), (0xfc89, 5788, 2), (0xfc8a, 5790, 2), (0xfc8b, 5792, 2), (0xfc8c, 5794, 2),
+    (0xfc8d, 5796, 2), (0xfc8e, 5798, 2), (0xfc8f, 5800, 2), (0xfc90, 5802, 2), (0xfc91, 5804, 2),
+    (0xfc92, 5806, 2), (0xfc93, 5808, 2), (0xfc94, 5810, 2), (0xfc95, 5812, 2), (0xfc96, 5814, 2),
+    (0xfc97, 5816, 3), (0xfc98, 5819, 3), (0xfc99, 5822, 3), (0xfc9a, 5825, 3), (0xfc9b, 5828, 3),
+    (0xfc9c, 5831, 2), (0xfc9d, 5833, 2), (0xfc9e, 5835, 2), (0xfc9f, 5837, 2), (0xfca0, 5839, 2),
+    (0xfca1, 5841, 2), (0xfca2, 5843, 2), (0xfca3, 5845, 2), (0xfca4, 5847, 2), (0xfca5, 5849, 2),
+    (0xfca6, 5851, 3), (0xfca7, 5854, 2), (0xfca8, 5856, 2), (0xfca9, 5858, 2), (0xfcaa, 5860, 2),
+    (0xfcab, 5862, 2), (0xfcac, 5864, 2), (0xfcad, 5866, 2), (0xfcae, 5868, 2), (0xfcaf, 5870, 2),
+    (0xfcb0, 5872, 2), (0xfcb1, 5874, 2), (0xfcb2, 5876, 2), (0xfcb3, 5878, 2), (0xfcb4, 5880, 2),
+    (0xfcb5, 5882, 2), (0xfcb6, 5884, 2), (0xfcb7, 5886, 2), (0xfcb8, 5888, 2), (0xfcb9, 5890, 2),
+    (0xfcba, 5892, 2), (0xfcbb, 5894, 2), (0xfcbc, 5896, 2), (0xfcbd, 5898, 2), (0xfcbe, 5900, 2),
+    (0xfcbf, 5902, 2), (0xfcc0, 5904, 2), (0xfcc1, 5906, 2), (0xfcc2, 5908, 2), (0xfcc3, 5910, 2),
+    (0xfcc4, 5912, 2), (0xfcc5, 5914, 2), (0xfcc6, 5916, 2), (0xfcc7, 5918, 2), (0xfcc8, 5920, 2),
+    (0xfcc9, 5922, 2), (0xfcca, 5924, 2), (0xfccb, 5926, 2), (0xfccc, 5928, 2), (0xfccd, 5930, 2),
+    (0xfcce, 5932, 2), (0xfccf, 5934, 2), (0xfcd0, 5936, 2), (0xfcd1, 5938, 2), (0xfcd2, 5940, 2),
+    (0xfcd3, 5942, 2), (0xfcd4, 5944, 2), (0xfcd5, 5946, 2), (0xfcd6, 5948, 2), (0xfcd7, 5950, 2),
+    (0xfcd8, 5952, 2), (0xfcd9, 5954, 2), (0xfcda, 5956, 2), (0xfcdb, 5958, 2), (0xfcdc, 5960, 2),
+    (0xfcdd, 5962, 2), (0xfcde, 5964, 2), (0xfcdf, 5966, 3), (0xfce0, 5969, 3), (0xfce1, 5972, 2),
+    (0xfce2, 5974, 2), (0xfce3, 5976, 2), (0xfce4, 5978, 2), (0xfce5, 5980, 3), (0xfce6, 5983, 3),
+    (0xfce7, 5986, 2), (0xfce8, 5988, 2), (0xfce9, 5990, 3), (0xfcea, 5993, 3), (0xfceb, 5996, 2),
+    (0xfcec, 5998, 2), (0xfced, 6000, 2), (0xfcee, 6002, 2), (0xfcef, 6004, 2), (0xfcf0, 6006, 2),
+    (0xfcf1, 6008, 2), (0xfcf2, 6010, 2), (0xfcf3, 6012, 2), (0xfcf4, 6014, 2), (0xfcf5, 6016, 2),
+    (0xfcf6, 6018, 2), (0xfcf7, 6020, 2), (0xfcf8, 6022, 2), (0xfcf9, 6024, 2), (0xfcfa, 6026, 2),
+    (0xfcfb, 6028, 2), (0xfcfc, 6030, 2), (0xfcfd, 6032, 3), (0xfcfe, 6035, 3), (0xfcff, 6038, 2),
+    (0xfd00, 6040, 2), (0xfd01, 6042, 2), (0xfd02, 6044, 2), (0xfd03, 6046, 2), (0xfd04, 6048, 2),
+    (0xfd05, 6050, 2), (0xfd06, 6052, 2), (0xfd07, 6054, 2), (0xfd08, 6056, 2), (0xfd09, 6058, 3),
+    (0xfd0a, 6061, 3), (0xfd0b, 6064, 3), (0xfd0c, 6067, 3), (0xfd0d, 6070, 3), (0xfd0e, 6073, 2),
+    (0xfd0f, 6075, 2), (0xfd10, 6077, 2), (0xfd11, 6079, 2), (0xfd12, 6081, 2), (0xfd13, 6083, 2),
+    (0xfd14, 6085, 2), (0xfd15, 6087, 2), (0xfd16, 6089, 2), (0xfd17, 6091, 2), (0xfd18, 6093, 2),
+    (0xfd19, 6095, 3), (0xfd1a, 6098, 3), (0xfd1b, 6101, 2), (0xfd1c, 6103, 2), (0xfd1d, 6105, 2),
+    (0xfd1e, 6107, 2), (0xfd1f, 6109, 2), (0xfd20, 6111, 2), (0xfd21, 6113, 2), (0xfd22, 6115, 2),
+    (0xfd23, 6117, 2), (0xfd24, 6119, 2), (0xfd25, 6121, 3), (0xfd26, 6124, 3), (0xfd27, 6127, 3),
+    (0xfd28, 6130, 3), (0xfd29, 6133, 3), (0xfd2a, 6136, 2), (0xfd2b, 6138, 2), (0xfd2c, 6140, 2),
+    (0xfd2d, 6142, 3), (0xfd2e, 6145, 3), (0xfd2f, 6148, 3), (0xfd30, 6151, 3), (0xfd31, 6154, 2),
+    (0xfd32, 6156, 3), (0xfd33, 6159, 2), (0xfd34, 6161, 2), (0xfd35, 6163, 2), (0xfd36, 6165, 2),
+    (0xfd37, 6167, 3), (0xfd38, 6170, 3), (0xfd39, 6173, 3), (0xfd3a, 6176, 2), (0xfd3b, 6178, 2),
+    (0xfd3c, 6180, 2), (0xfd3d, 6182, 2), (0xfd3e, 6184, 1), (0xfd3f, 6185, 1), (0xfd50, 6186, 3),
+    (0xfd51, 6189, 3), (0xfd52, 6192, 3), (0xfd53, 6195, 3), (0xfd54, 6198, 3), (0xfd55, 6201, 3),
+    (0xfd56, 6204, 3), (0xfd57, 6207, 3), (0xfd58, 6210, 3), (0xfd59, 6213, 3), (0xfd5a, 6216, 3),
+    (0xfd5b, 6219, 3), (0xfd5c, 6222, 3), (0xfd5d, 6225, 3), (0xfd5e, 6228, 3), (0xfd5f, 6231, 3),
+    (0xfd60, 6234, 3), (0xfd61, 6237, 3), (0xfd62, 6240, 3), (0xfd63, 6243, 3), (0xfd64, 6246, 3),
+    (0xfd65, 6249, 3), (0xfd66, 6252, 3), (0xfd67, 6255, 4), (0xfd68, 6259, 4), (0xfd69, 6263, 4),
+    (0xfd6a, 6267, 4), (0xfd6b, 6271, 4), (0xfd6c, 6275, 4), (0xfd6d, 6279, 4), (0xfd6e, 6283, 3),
+    (0xfd6f, 6286, 3), (0xfd70, 6289, 3), (0xfd71, 6292, 3), (0xfd72, 6295, 3), (0xfd73, 6298, 3),
+    (0xfd74, 6301, 3), (0xfd75, 6304, 3), (0xfd76, 6307, 3), (0xfd77, 6310, 3), (0xfd78, 6313, 3),
+    (0xfd79, 6316, 3), (0xfd7a, 6319, 3), (0xfd7b, 6322, 3), (0xfd7c, 6325, 3), (0xfd7d, 6328, 3),
+    (0xfd7e, 6331, 3), (0xfd7f, 6334, 3), (0xfd80, 6337, 3), (0xfd81, 6340, 3), (0xfd82, 6343, 3),
+    (0xfd83, 6346, 3), (0xfd84, 6349, 3), (0xfd85, 6352, 3), (0xfd86, 6355, 3), (0xfd87, 6358, 3),
+    (0xfd88, 6361, 3), (0xfd89, 6364, 3), (0xfd8a, 6367, 3), (0xfd8b, 6370, 3), (0xfd8c, 6373, 3),
+    (0xfd8d, 6376, 3), (0xfd8e, 6379, 3), (0xfd8f, 6382, 3), (0xfd92, 6385, 3), (0xfd93, 6388, 3),
+    (0xfd94, 6391, 3), (0xfd95, 6394, 3), (0xfd96, 6397, 3), (0xfd97, 6400, 3), (0xfd98, 6403, 3),
+    (0xfd99, 6406, 3), (0xfd9a, 6409, 3), (0xfd9b, 6412, 3), (0xfd9c, 6415, 3), (0xfd9d, 6418, 3),
+    (0xfd9e, 6421, 3), (0xfd9f, 6424, 3), (0xfda0, 6427, 3), (0xfda1, 6430, 3), (0xfda2, 6433, 3),
+    (0xfda3, 6436, 3), (0xfda4, 6439, 3), (0xfda5, 6442, 3), (0xfda6, 6445, 3), (0xfda7, 6448, 3),
+    (0xfda8, 6451, 3), (0xfda9, 6454, 3), (0xfdaa, 6457, 4), (0xfdab, 6461, 3), (0xfdac, 6464, 3),
+    (0xfdad, 6467, 3), (0xfdae, 6470, 3), (0xfdaf, 6473, 3), (0xfdb0, 6476, 3), (0xfdb1, 6479, 3),
+    (0xfdb2, 6482, 3), (0xfdb3, 6485, 3), (0xfdb4, 6488, 3), (0xfdb5, 6491, 3), (0xfdb6, 6494, 3),
+    (0xfdb7, 6497, 3), (0xfdb8, 6500, 3), (0xfdb9, 6503, 3), (0xfdba, 6506, 3), (0xfdbb, 6509, 3),
+    (0xfdbc, 6512, 3), (0xfdbd, 6515, 3), (0xfdbe, 6518, 3), (0xfdbf, 6521, 3), (0xfdc0, 6524, 3),
+    (0xfdc1, 6527, 3), (0xfdc2, 6530, 3), (0xfdc3, 6533, 3), (0xfdc4, 6536, 3), (0xfdc5, 6539, 3),
+    (0xfdc6, 6542, 3), (0xfdc7, 6545, 3), (0xfdf0, 6548, 3), (0xfdf1, 6551, 3), (0xfdf2, 6554, 6),
+    (0xfdf3, 6560, 4), (0xfdf4, 6564, 4), (0xfdf5, 6568, 4), (0xfdf6, 6572, 4), (0xfdf7, 6576, 4),
+    (0xfdf8, 6580, 4), (0xfdf9, 6584, 3), (0xfdfa, 6587, 18), (0xfdfb, 6605, 8), (0xfdfc, 6613, 4),
+    (0xfe19, 6617, 1), (0xfe30, 6618, 1), (0xfe31, 6619, 1), (0xfe34, 6620, 1), (0xfe35, 6621, 1),
+    (0xfe36, 6622, 1), (0xfe37, 6623, 1), (0xfe38, 6624, 1), (0xfe39, 6625, 1), (0xfe3a, 6626, 1),
+    (0xfe49, 6627, 1), (0xfe4a, 6628, 1), (0xfe4b, 6629, 1), (0xfe4c, 6630, 1), (0xfe4d, 6631, 1),
+    (0xfe4e, 6632, 1), (0xfe4f, 6633, 1), (0xfe58, 6634, 1), (0xfe68, 6635, 1), (0xfe80, 6636, 1),
+    (0xfe81, 6637, 2), (0xfe82, 6639, 2), (0xfe83, 6641, 2), (0xfe84, 6643, 2), (0xfe85, 6645, 2),
+    (0xfe86, 6647, 2), (0xfe87, 6649, 2), (0xfe88, 6651, 2), (0xfe89, 6653, 2), (0xfe8a, 6655, 2),
+    (0xfe8b, 6657, 2), (0xfe8c, 6659, 2), (0xfe8d, 6661, 1), (0xfe8e, 6662, 1), (0xfe8f, 6663, 1),
+    (0xfe90, 6664, 1), (0xfe91, 6665, 1), (0xfe92, 6666, 1), (0xfe93, 6667, 1), (0xfe94, 6668, 1),
+    (0xfe95, 6669, 1), (0xfe96, 6670, 1), (0xfe97, 6671, 1), (0xfe98, 6672, 1), (0xfe99, 6673, 2),
+    (0xfe9a, 6675, 2), (0xfe9b, 6677, 2), (0xfe9c, 6679, 2), (0xfe9d, 6681, 1), (0xfe9e, 6682, 1),
+    (0xfe9f, 6683, 1), (0xfea0, 6684, 1), (0xfea1, 6685, 1), (0xfea2, 6686, 1), (0xfea3, 6687, 1),
+    (0xfea4, 6688, 1), (0xfea5, 6689, 1), (0xfea6, 6690, 1), (0xfea7, 6691, 1), (0xfea8, 6692, 1),
+    (0xfea9, 6693, 1), (0xfeaa, 6694, 1), (0xfeab, 6695, 1), (0xfeac, 6696, 1), (0xfead, 6697, 1),
+    (0xfeae, 6698, 1), (0xfeaf, 6699, 1), (0xfeb0, 6700, 1), (0xfeb1, 6701, 1), (0xfeb2, 6702, 1),
+    (0xfeb3, 6703, 1), (0xfeb4, 6704, 1), (0xfeb5, 6705, 2), (0xfeb6, 6707, 2), (0xfeb7, 6709, 2),
+    (0xfeb8, 6711, 2), (0xfeb9, 6713, 1), (0xfeba, 6714, 1), (0xfebb, 6715, 1), (0xfebc, 6716, 1),
+    (0xfebd, 6717, 1), (0xfebe, 6718, 1), (0xfebf, 6719, 1), (0xfec0, 6720, 1), (0xfec1, 6721, 1),
+    (0xfec2, 6722, 1), (0xfec3, 6723, 1), (0xfec4, 6724, 1), (0xfec5, 6725, 1), (0xfec6, 6726, 1),
+    (0xfec7, 6727, 1), (0xfec8, 6728, 1), (0xfec9, 6729, 1), (0xfeca, 6730, 1), (0xfecb, 6731, 1),
+    (0xfecc, 6732, 1), (0xfecd, 6733, 1), (0xfece, 6734, 1), (0xfecf, 6735, 1), (0xfed0, 6736, 1),
+    (0xfed1, 6737, 1), (0xfed2, 6738, 1), (0xfed3, 6739, 1), (0xfed4, 6740, 1), (0xfed5, 6741, 1),
+    (0xfed6, 6742, 1), (0xfed7, 6743, 1), (0xfed8, 6744, 1), (0xfed9, 6745, 1), (0xfeda, 6746, 1),
+    (0xfedb, 6747, 1), (0xfedc, 6748, 1), (0xfedd, 6749, 1), (0xfede, 6750, 1), (0xfedf, 6751, 1),
+    (0xfee0, 6752, 1), (0xfee1, 6753, 1), (0xfee2, 6754, 1), (0xfee3, 6755, 1), (0xfee4, 6756, 1),
+    (0xfee5, 6757, 1), (0xfee6, 6758, 1), (0xfee7, 6759, 1), (0xfee8, 6760, 1), (0xfee9, 6761, 1),
+    (0xfeea, 6762, 1), (0xfeeb, 6763, 1), (0xfeec, 6764, 1), (0xfeed, 6765, 1), (0xfeee, 6766, 1),
+    (0xfeef, 6767, 1), (0xfef0, 6768, 1), (0xfef1, 6769, 1), (0xfef2, 6770, 1), (0xfef3, 6771, 1),
+    (0xfef4, 6772, 1), (0xfef5, 6773, 3), (0xfef6, 6776, 3), (0xfef7, 6779, 3), (0xfef8, 6782, 3),
+    (0xfef9, 6785, 3), (0xfefa, 6788, 3), (0xfefb, 6791, 2), (0xfefc, 6793, 2), (0xff01, 6795, 1),
+    (0xff02, 6796, 2), (0xff07, 6798, 1), (0xff0d, 6799, 1), (0xff1a, 6800, 1), (0xff21, 6801, 1),
+    (0xff22, 6802, 1), (0xff23, 6803, 1), (0xff25, 6804, 1), (0xff28, 6805, 1), (0xff29, 6806, 1),
+    (0xff2a, 6807, 1), (0xff2b, 6808, 1), (0xff2d, 6809, 1), (0xff2e, 6810, 1), (0xff2f, 6811, 1),
+    (0xff30, 6812, 1), (0xff33, 6813, 1), (0xff34, 6814, 1), (0xff38, 6815, 1), (0xff39, 6816, 1),
+    (0xff3a, 6817, 1), (0xff3b, 6818, 1), (0xff3c, 6819, 1), (0xff3d, 6820, 1), (0xff3e, 6821, 1),
+    (0xff40, 6822, 1), (0xff41, 6823, 1), (0xff43, 6824, 1), (0xff45, 6825, 1), (0xff47, 6826, 1),
+    (0xff48, 6827, 1), (0xff49, 6828, 1), (0xff4a, 6829, 1), (0xff4c, 6830, 1), (0xff4f, 6831, 1),
+    (0xff50, 6832, 1), (0xff53, 6833, 1), (0xff56, 6834, 1), (0xff58, 6835, 1), (0xff59, 6836, 1),
+    (0xff5c, 6837, 1), (0xff5e, 6838, 1), (0xff65, 6839, 1), (0xffe3, 6840, 1), (0xffe8, 6841, 1),
+    (0xffed, 6842, 1), (0x10101, 6843, 1), (0x1018e, 6844, 2), (0x10196, 6846, 2), (0x10197, 6848, 2),
+    (0x10198, 6850, 6), (0x10199, 6856, 4), (0x101a0, 6860, 1), (0x10282, 6861, 1), (0x10285, 6862, 1),
+    (0x10286, 6863, 1), (0x10287, 6864, 1), (0x1028a, 6865, 1), (0x1028d, 6866, 1), (0x10290, 6867, 1),
+    (0x10292, 6868, 1), (0x10294, 6869, 1), (0x10295, 6870, 1), (0x10296, 6871, 1), (0x10297, 6872, 1),
+    (0x1029b, 6873, 1), (0x102a0, 6874, 1), (0x102a1, 6875, 1), (0x102a2, 6876, 1), (0x102a3, 6877, 1),
+    (0x102a5, 6878, 1), (0x102ab, 6879, 1), (0x102ad, 6880, 1), (0x102b0, 6881, 1), (0x102b1, 6882, 1),
+    (0x102b2, 6883, 1), (0x102b3, 6884, 1), (0x102b4, 6885, 1), (0x102b5, 6886, 1), (0x102b6, 6887, 1),
+    (0x102b8, 6888, 1), (0x102cf, 6889, 1), (0x102e1, 6890, 1), (0x102e4, 6891, 1), (0x102e8, 6892, 1),
+    (0x102f2, 6893, 1), (0x102f5, 6894, 1), (0x10301, 6895, 1), (0x10302, 6896, 1), (0x10309, 6897, 1),
+    (0x10311, 6898, 1), (0x10312, 6899, 1), (0x10315, 6900, 1), (0x10317, 6901, 1), (0x1031a, 6902, 1),
+    (0x1031f, 6903, 1), (0x10320, 6904, 1), (0x10322, 6905, 1), (0x103d1, 6906, 1), (0x103d3, 6907, 1),
+    (0x10401, 6908, 1), (0x10404, 6909, 1), (0x10411, 6910, 1), (0x10415, 6911, 1), (0x1041b, 6912, 1),
+    (0x1041f, 6913, 1), (0x10420, 6914, 1), (0x10423, 6915, 1), (0x10425, 6916, 1), (0x10429, 6917, 1),
+    (0x1042a, 6918, 1), (0x1042c, 6919, 1), (0x1043d, 6920, 1), (0x1043f, 6921, 1), (0x10442, 6922, 1),
+    (0x10443, 6923, 1), (0x10448, 6924, 1), (0x1044b, 6925, 1), (0x1044d, 6926, 1), (0x104a0, 6927, 1),
+    (0x104b0, 6928, 1), (0x104b4, 6929, 1), (0x104bc, 6930, 1), (0x104c2, 6931, 1), (0x104c3, 6932, 1),
+    (0x104c4, 6933, 1), (0x104cd, 6934, 1), (0x104ce, 6935, 1), (0x104d0, 6936, 1), (0x104d1, 6937, 1),
+    (0x104d2, 6938, 1), (0x104d8, 6939, 1), (0x104db, 6940, 1), (0x104ea, 6941, 1), (0x104eb, 6942, 1),
+    (0x104f6, 6943, 1), (0x104f9, 6944, 1), (0x10513, 6945, 1), (0x10516, 6946, 1), (0x10518, 6947, 1),
+    (0x1051c, 6948, 1), (0x1051d, 6949, 1), (0x10525, 6950, 1), (0x10526, 6951, 1), (0x10527, 6952, 1),
+    (0x10a3a, 6953, 1), (0x10a50, 6954, 1), (0x10a57, 6955, 2), (0x10cfa, 6957, 1), (0x10cfc, 6958, 1),
+    (0x110bb, 6959, 1), (0x111c7, 6960, 1), (0x111ca, 6961, 1), (0x111cb, 6962, 1), (0x111db, 6963, 1),
+    (0x111dc, 6964, 1), (0x111de, 6965, 1), (0x11300, 6966, 1), (0x11413, 6967, 3), (0x11419, 6970, 3),
+    (0x11424, 6973, 3), (0x1142a, 6976, 3), (0x1142d, 6979, 3), (0x1142f, 6982, 3), (0x1144c, 6985, 2),
+    (0x11492, 6987, 1), (0x11494, 6988, 1), (0x11496, 6989, 1), (0x11498, 6990, 1), (0x11499, 6991, 1),
+    (0x1149b, 6992, 1), (0x1149d, 6993, 1), (0x1149e, 6994, 1), (0x1149f, 6995, 1), (0x114a0, 6996, 1),
+    (0x114a1, 6997, 1), (0x114a2, 6998, 1), (0x114a3, 6999, 1), (0x114a7, 7000, 1), (0x114a8, 7001, 1),
+    (0x114a9, 7002, 1), (0x114aa, 7003, 1), (0x114ab, 7004, 1), (0x114ad, 7005, 1), (0x114ae, 7006, 1),
+    (0x114b0, 7007, 1), (0x114b1, 7008, 1), (0x114b9, 7009, 1), (0x114bc, 7010, 2), (0x114bd, 7012, 1),
+    (0x114be, 7013, 2), (0x114bf, 7015, 2), (0x114c1, 7017, 1), (0x114c2, 7018, 1), (0x114c3, 7019, 1),
+    (0x114c4, 7020, 1), (0x114c5, 7021, 2), (0x114d0, 7023, 1), (0x114d1, 7024, 1), (0x114d2, 7025, 1),
+    (0x114d6, 7026, 1), (0x115d8, 7027, 1), (0x115d9, 7028, 1), (0x115da, 7029, 1), (0x115db, 7030, 1),
+    (0x115dc, 7031, 1), (0x115dd, 7032, 1), (0x11642, 7033, 2), (0x11700, 7035, 2), (0x11706, 7037, 1),
+    (0x1170a, 7038, 1), (0x1170e, 7039, 1), (0x1170f, 7040, 1), (0x118a0, 7041, 1), (0x118a2, 7042, 1),
+    (0x118a3, 7043, 1), (0x118a4, 7044, 1), (0x118a6, 7045, 1), (0x118a8, 7046, 1), (0x118a9, 7047, 1),
+    (0x118ac, 7048, 1), (0x118ae, 7049, 1), (0x118af, 7050, 1), (0x118b2, 7051, 1), (0x118b5, 7052, 1),
+    (0x118b7, 7053, 1), (0x118b8, 7054, 1), (0x118bb, 7055, 1), (0x118bc, 7056, 1), (0x118c0, 7057, 1),
+    (0x118c1, 7058, 1), (0x118c2, 7059, 1), (0x118c3, 7060, 1), (0x118c4, 7061, 1), (0x118c6, 7062, 1),
+    (0x118c8, 7063, 1), (0x118ca, 7064, 1), (0x118cc, 7065, 1), (0x118ce, 7066, 1), (0x118d5, 7067, 1),
+    (0x118d6, 7068, 1), (0x118d7, 7069, 1), (0x118d8, 7070, 1), (0x118dc, 7071, 1), (0x118e0, 7072, 1),
+    (0x118e3, 7073, 2), (0x118e4, 7075, 1), (0x118e5, 7076, 1), (0x118e6, 7077, 1), (0x118e9, 7078, 1),
+    (0x118ec, 7079, 1), (0x118ef, 7080, 1), (0x118f2, 7081, 1), (0x11ae6, 7082, 2), (0x11ae7, 7084, 2),
+    (0x11ae8, 7086, 2), (0x11ae9, 7088, 3), (0x11aea, 7091, 3), (0x11aec, 7094, 2), (0x11aed, 7096, 2),
+    (0x11aee, 7098, 3), (0x11af4, 7101, 2), (0x11af5, 7103, 2), (0x11af6, 7105, 2), (0x11af7, 7107, 3),
+    (0x11af8, 7110, 3), (0x11c42, 7113, 2), (0x11cb2, 7115, 1), (0x12038, 7116, 1), (0x132f9, 7117, 1),
+    (0x16f07, 7118, 1), (0x16f08, 7119, 1), (0x16f0a, 7120, 1), (0x16f16, 7121, 1), (0x16f1a, 7122, 1),
+    (0x16f1c, 7123, 1), (0x16f26, 7124, 1), (0x16f28, 7125, 1), (0x16f2d, 7126, 1), (0x16f35, 7127, 1),
+    (0x16f3a, 7128, 1), (0x16f3b, 7129, 1), (0x16f3d, 7130, 1), (0x16f3f, 7131, 1), (0x16f40, 7132, 1),
+    (0x16f42, 7133, 1), (0x16f43, 7134, 1), (0x16f51, 7135, 1), (0x16f52, 7136, 1), (0x1d114, 7137, 1),
+    (0x1d16d, 7138, 1), (0x1d202, 7139, 1), (0x1d206, 7140, 1), (0x1d20b, 7141, 1), (0x1d20d, 7142, 1),
+    (0x1d20f, 7143, 1), (0x1d212, 7144, 1), (0x1d213, 7145, 1), (0x1d214, 7146, 1), (0x1d215, 7147, 1),
+    (0x1d216, 7148, 1), (0x1d217, 7149, 1), (0x1d21a, 7150, 2), (0x1d21b, 7152, 1), (0x1d21c, 7153, 1),
+    (0x1d221, 7154, 1), (0x1d222, 7155, 1), (0x1d22a, 7156, 1), (0x1d22b, 7157, 1), (0x1d230, 7158, 1),
+    (0x1d236, 7159, 1), (0x1d237, 7160, 1), (0x1d238, 7161, 1), (0x1d239, 7162, 1), (0x1d23a, 7163, 1),
+    (0x1d23b, 7164, 1), (0x1d23f, 7165, 1), (0x1d245, 7166, 1), (0x1d400, 7167, 1), (0x1d401, 7168, 1),
+    (0x1d402, 7169, 1), (0x1d403, 7170, 1), (0x1d404, 7171, 1), (0x1d405, 7172, 1), (0x1d406, 7173, 1),
+    (0x1d407, 7174, 1), (0x1d408, 7175, 1), (0x1d409, 7176, 1), (0x1d40a, 7177, 1), (0x1d40b, 7178, 1),
+    (0x1d40c, 7179, 1), (0x1d40d, 7180, 1), (0x1d40e, 7181, 1), (0x1d40f, 7182, 1), (0x1d410, 7183, 1),
+    (0x1d411, 7184, 1), (0x1d412, 7185, 1), (0x1d413, 7186, 1), (0x1d414, 7187, 1), (0x1d415, 7188, 1),
+    (0x1d416, 7189, 1), (0x1d417, 7190, 1), (0x1d418, 7191, 1), (0x1d419, 7192, 1), (0x1d41a, 7193, 1),
+    (0x1d41b, 7194, 1), (0x1d41c, 7195, 1), (0x1d41d, 7196, 1), (0x1d41e, 7197, 1), (0x1d41f, 7198, 1),
+    (0x1d420, 7199, 1), (0x1d421, 7200, 1), (0x1d422, 7201, 1), (0x1d423, 7202, 1), (0x1d424, 7203, 1),
+    (0x1d425, 7204, 1), (0x1d426, 7205, 2), (0x1d427, 7207, 1), (0x1d428, 7208, 1), (0x1d429, 7209, 1),
+    (0x1d42a, 7210, 1), (0x1d42b, 7211, 1), (0x1d42c, 7212, 1), (0x1d42d, 7213, 1), (0x1d42e, 7214, 1),
+    (0x1d42f, 7215, 1), (0x1d430, 7216, 1), (0x1d431, 7217, 1), (0x1d432, 7218, 1), (0x1d433, 7219, 1),
+    (0x1d434, 7220, 1), (0x1d435, 7221, 1), (0x1d436, 7222, 1), (0x1d437, 7223, 1), (0x1d438, 7224, 1),
+    (0x1d439, 7225, 1), (0x1d43a, 7226, 1), (0x1d43b, 7227, 1), (0x1d43c, 7228, 1), (0x1d43d, 7229, 1),
+    (0x1d43e, 7230, 1), (0x1d43f, 7231, 1), (0x1d440, 7232, 1), (0x1d441, 7233, 1), (0x1d442, 7234, 1),
+    (0x1d443, 7235, 1), (0x1d444, 7236, 1), (0x1d445, 7237, 1), (0x1d446, 7238, 1), (0x1d447, 7239, 1),
+    (0x1d448, 7240, 1), (0x1d449, 7241, 1), (0x1d44a, 7242, 1), (0x1d44b, 7243, 1), (0x1d44c, 7244, 1),
+    (0x1d44d, 7245, 1), (0x1d44e, 7246, 1), (0x1d44f, 7247, 1), (0x1d450, 7248, 1), (0x1d451, 7249, 1),
+    (0x1d452, 7250, 1), (0x1d453, 7251, 1), (0x1d454, 7252, 1), (0x1d456, 7253, 1), (0x1d457, 7254, 1),
+    (0x1d458, 7255, 1), (0x1d459, 7256, 1), (0x1d45a, 7257, 2), (0x1d45b, 7259, 1), (0x1d45c, 7260, 1),
+    (0x1d45d, 7261, 1), (0x1d45e, 7262, 1), (0x1d45f, 7263, 1), (0x1d460, 7264, 1), (0x1d461, 7265, 1),
+    (0x1d462, 7266, 1), (0x1d463, 7267, 1), (0x1d464, 7268, 1), (0x1d465, 7269, 1), (0x1d466, 7270, 1),
+    (0x1d467, 7271, 1), (0x1d468, 7272, 1), (0x1d469, 7273, 1), (0x1d46a, 7274, 1), (0x1d46b, 7275, 1),
+    (0x1d46c, 7276, 1), (0x1d46d, 7277, 1), (0x1d46e, 7278, 1), (0x1d46f, 7279, 1), (0x1d470, 7280, 1),
+    (0x1d471, 7281, 1), (0x1d472, 7282, 1), (0x1d473, 7283, 1), (0x1d474, 7284, 1), (0x1d475, 7285, 1),
+    (0x1d476, 7286, 1), (0x1d477, 7287, 1), (0x1d478, 7288, 1), (0x1d479, 7289, 1), (0x1d47a, 7290, 1),
+    (0x1d47b, 7291, 1), (0x1d47c, 7292, 1), (0x1d47d, 7293, 1), (0x1d47e, 7294, 1), (0x1d47f, 7295, 1),
+    (0x1d480, 7296, 1), (0x1d481, 7297, 1), (0x1d482, 7298, 1), (0x1d483, 7299, 1), (0x1d484, 7300, 1),
+    (0x1d485, 7301, 1), (0x1d486, 7302, 1), (0x1d487, 7303, 1), (0x1d488, 7304, 1), (0x1d489, 7305, 1),
+    (0x1d48a, 7306, 1), (0x1d48b, 7307, 1), (0x1d48c, 7308, 1), (0x1d48d, 7309, 1), (0x1d48e, 7310, 2),
+    (0x1d48f, 7312, 1), (0x1d490, 7313, 1), (0x1d491, 7314, 1), (0x1d492, 7315, 1), (0x1d493, 7316, 1),
+    (0x1d494, 7317, 1), (0x1d495, 7318, 1), (0x1d496, 7319, 1), (0x1d497, 7320, 1), (0x1d498, 7321, 1),
+    (0x1d499, 7322, 1), (0x1d49a, 7323, 1), (0x1d49b, 7324, 1), (0x1d49c, 7325, 1), (0x1d49e, 7326, 1),
+    (0x1d49f, 7327, 1), (0x1d4a2, 7328, 1), (0x1d4a5, 7329, 1), (0x1d4a6, 7330, 1), (0x1d4a9, 7331, 1),
+    (0x1d4aa, 7332, 1), (0x1d4ab, 7333, 1), (0x1d4ac, 7334, 1), (0x1d4ae, 7335, 1), (0x1d4af, 7336, 1),
+    (0x1d4b0, 7337, 1), (0x1d4b1, 7338, 1), (0x1d4b2, 7339, 1), (0x1d4b3, 7340, 1), (0x1d4b4, 7341, 1),
+    (0x1d4b5, 7342, 1), (0x1d4b6, 7343, 1), (0x1d4b7, 7344, 1), (0x1d4b8, 7345, 1), (0x1d4b9, 7346, 1),
+    (0x1d4bb, 7347, 1), (0x1d4bd, 7348, 1), (0x1d4be, 7349, 1), (0x1d4bf, 7350, 1), (0x1d4c0, 7351, 1),
+    (0x1d4c1, 7352, 1), (0x1d4c2, 7353, 2), (0x1d4c3, 7355, 1), (0x1d4c5, 7356, 1), (0x1d4c6, 7357, 1),
+    (0x1d4c7, 7358, 1), (0x1d4c8, 7359, 1), (0x1d4c9, 7360, 1), (0x1d4ca, 7361, 1), (0x1d4cb, 7362, 1),
+    (0x1d4cc, 7363, 1), (0x1d4cd, 7364, 1), (0x1d4ce, 7365, 1), (0x1d4cf, 7366, 1), (0x1d4d0, 7367, 1),
+    (0x1d4d1, 7368, 1), (0x1d4d2, 7369, 1), (0x1d4d3, 7370, 1), (0x1d4d4, 7371, 1), (0x1d4d5, 7372, 1),
+    (0x1d4d6, 7373, 1), (0x1d4d7, 7374, 1), (0x1d4d8, 7375, 1), (0x1d4d9, 7376, 1), (0x1d4da, 7377, 1),
+    (0x1d4db, 7378, 1), (0x1d4dc, 7379, 1), (0x1d4dd, 7380, 1), (0x1d4de, 7381, 1), (0x1d4df, 7382, 1),
+    (0x1d4e0, 7383, 1), (0x1d4e1, 7384, 1), (0x1d4e2, 7385, 1), (0x1d4e3, 7386, 1), (0x1d4e4, 7387, 1),
+    (0x1d4e5, 7388, 1), (0x1d4e6, 7389, 1), (0x1d4e7, 7390, 1), (0x1d4e8, 7391, 1), (0x1d4e9, 7392, 1),
+    (0x1d4ea, 7393, 1), (0x1d4eb, 7394, 1), (0x1d4ec, 7395, 1), (0x1d4ed, 7396, 1), (0x1d4ee, 7397, 1),
+    (0x1d4ef, 7398, 1), (0x1d4f0, 7399, 1), (0x1d4f1, 7400, 1), (0x1d4f2, 7401, 1), (0x1d4f3, 7402, 1),
+    (0x1d4f4, 7403, 1), (0x1d4f5, 7404, 1), (0x1d4f6, 7405, 2), (0x1d4f7, 7407, 1), (0x1d4f8, 7408, 1),
+    (0x1d4f9, 7409, 1), (0x1d4fa, 7410, 1), (0x1d4fb, 7411, 1), (0x1d4fc, 7412, 1), (0x1d4fd, 7413, 1),
+    (0x1d4fe, 7414, 1), (0x1d4ff, 7415, 1), (0x1d500, 7416, 1), (0x1d501, 7417, 1), (0x1d502, 7418, 1),
+    (0x1d503, 7419, 1), (0x1d504, 7420, 1), (0x1d505, 7421, 1), (0x1d507, 7422, 1), (0x1d508, 7423, 1),
+    (0x1d509, 7424, 1), (0x1d50a, 7425, 1), (0x1d50d, 7426, 1), (0x1d50e, 7427, 1), (0x1d50f, 7428, 1),
+    (0x1d510, 7429, 1), (0x1d511, 7430, 1), (0x1d512, 7431, 1), (0x1d513, 7432, 1), (0x1d514, 7433, 1),
+    (0x1d516, 7434, 1), (0x1d517, 7435, 1), (0x1d518, 7436, 1), (0x1d519, 7437, 1), (0x1d51a, 7438, 1),
+    (0x1d51b, 7439, 1), (0x1d51c, 7440, 1), (0x1d51e, 7441, 1), (0x1d51f, 7442, 1), (0x1d520, 7443, 1),
+    (0x1d521, 7444, 1), (0x1d522, 7445, 1), (0x1d523, 7446, 1), (0x1d524, 7447, 1), (0x1d525, 7448, 1),
+    (0x1d526, 7449, 1), (0x1d527, 7450, 1), (0x1d528, 7451, 1), (0x1d529, 7452, 1), (0x1d52a, 7453, 2),
+    (0x1d52b, 7455, 1), (0x1d52c, 7456, 1), (0x1d52d, 7457, 1), (0x1d52e, 7458, 1), (0x1d52f, 7459, 1),
+    (0x1d530, 7460, 1), (0x1d531, 7461, 1), (0x1d532, 7462, 1), (0x1d533, 7463, 1), (0x1d534, 7464, 1),
+    (0x1d535, 7465, 1), (0x1d536, 7466, 1), (0x1d537, 7467, 1), (0x1d538, 7468, 1), (0x1d539, 7469, 1),
+    (0x1d53b, 7470, 1), (0x1d53c, 7471, 1), (0x1d53d, 7472, 1), (0x1d53e, 7473, 1), (0x1d540, 7474, 1),
+    (0x1d541, 7475, 1), (0x1d542, 7476, 1), (0x1d543, 7477, 1), (0x1d544, 7478, 1), (0x1d546, 7479, 1),
+    (0x1d54a, 7480, 1), (0x1d54b, 7481, 1), (0x1d54c, 7482, 1), (0x1d54d, 7483, 1), (0x1d54e, 7484, 1),
+    (0x1d54f, 7485, 1), (0x1d550, 7486, 1), (0x1d552, 7487, 1), (0x1d553, 7488, 1), (0x1d554, 7489, 1),
+    (0x1d555, 7490, 1), (0x1d556, 7491, 1), (0x1d557, 7492, 1), (0x1d558, 7493, 1), (0x1d559, 7494, 1),
+    (0x1d55a, 7495, 1), (0x1d55b, 7496, 1), (0x1d55c, 7497, 1), (0x1d55d, 7498, 1), (0x1d55e, 7499, 2),
+    (0x1d55f, 7501, 1), (0x1d560, 7502, 1), (0x1d561, 7503, 1), (0x1d562, 7504, 1), (0x1d563, 7505, 1),
+    (0x1d564, 7506, 1), (0x1d565, 7507, 1), (0x1d566, 7508, 1), (0x1d567, 7509, 1), (0x1d568, 7510, 1),
+    (0x1d569, 7511, 1), (0x1d56a, 7512, 1), (0x1d56b, 7513, 1), (0x1d56c, 7514, 1), (0x1d56d, 7515, 1),
+    (0x1d56e, 7516, 1), (0x1d56f, 7517, 1), (0x1d570, 7518, 1), (0x1d571, 7519, 1), (0x1d572, 7520, 1),
+    (0x1d573, 7521, 1), (0x1d574, 7522, 1), (0x1d575, 7523, 1), (0x1d576, 7524, 1), (0x1d577, 7525, 1),
+    (0x1d578, 7526, 1), (0x1d579, 7527, 1), (0x1d57a, 7528, 1), (0x1d57b, 7529, 1), (0x1d57c, 7530, 1),
+    (0x1d57d, 7531, 1), (0x1d57e, 7532, 1), (0x1d57f, 7533, 1), (0x1d580, 7534, 1), (0x1d581, 7535, 1),
+    (0x1d582, 7536, 1), (0x1d583, 7537, 1), (0x1d584, 7538, 1), (0x1d585, 7539, 1), (0x1d586, 7540, 1),
+    (0x1d587, 7541, 1), (0x1d588, 7542, 1), (0x1d589, 7543, 1), (0x1d58a, 7544, 1), (0x1d58b, 7545, 1),
+    (0x1d58c, 7546, 1), (0x1d58d, 7547, 1), (0x1d58e, 7548, 1), (0x1d58f, 7549, 1), (0x1d590, 7550, 1),
+    (0x1d591, 7551, 1), (0x1d592, 7552, 2), (0x1d593, 7554, 1), (0x1d594, 7555, 1), (0x1d595, 7556, 1),
+    (0x1d596, 7557, 1), (0x1d597, 7558, 1), (0x1d598, 7559, 1), (0x1d599, 7560, 1), (0x1d59a, 7561, 1),
+    (0x1d59b, 7562, 1), (0x1d59c, 7563, 1), (0x1d59d, 7564, 1), (0x1d59e, 7565, 1), (0x1d59f, 7566, 1),
+    (0x1d5a0, 7567, 1), (0x1d5a1, 7568, 1), (0x1d5a2, 7569, 1), (0x1d5a3, 7570, 1), (0x1d5a4, 7571, 1),
+    (0x1d5a5, 7572, 1), (0x1d5a6, 7573, 1), (0x1d5a7, 7574, 1), (0x1d5a8, 7575, 1), (0x1d5a9, 7576, 1),
+    (0x1d5aa, 7577, 1), (0x1d5ab, 7578, 1), (0x1d5ac, 7579, 1), (0x1d5ad, 7580, 1), (0x1d5ae, 7581, 1),
+    (0x1d5af, 7582, 1), (0x1d5b0, 7583, 1), (0x1d5b1, 7584, 1), (0x1d5b2, 7585, 1), (0x1d5b3, 7586, 1),
+    (0x1d5b4, 7587, 1), (0x1d5b5, 7588, 1), (0x1d5b6, 7589, 1), (0x1d5b7, 7590, 1), (0x1d5b8, 7591, 1),
+    (0x1d5b9, 7592, 1), (0x1d5ba, 7593, 1), (0x1d5bb, 7594, 1), (0x1d5bc, 7595, 1), (0x1d5bd, 7596, 1),
+    (0x1d5be, 7597, 1), (0x1d5bf, 7598, 1), (0x1d5c0, 7599, 1), (0x1d5c1, 7600, 1), (0x1d5c2, 7601, 1),
+    (0x1d5c3, 7602, 1), (0x1d5c4, 7603, 1), (0x1d5c5, 7604, 1), (0x1d5c6, 7605, 2), (0x1d5c7, 7607, 1),
+    (0x1d5c8, 7608, 1), (0x1d5c9, 7609, 1), (0x1d5ca, 7610, 1), (0x1d5cb, 7611, 1), (0x1d5cc, 7612, 1),
+    (0x1d5cd, 7613, 1), (0x1d5ce, 7614, 1), (0x1d5cf, 7615, 1), (0x1d5d0, 7616, 1), (0x1d5d1, 7617, 1),
+    (0x1d5d2, 7618, 1), (0x1d5d3, 7619, 1), (0x1d5d4, 7620, 1), (0x1d5d5, 7621, 1), (0x1d5d6, 7622, 1),
+    (0x1d5d7, 7623, 1), (0x1d5d8, 7624, 1), (0x1d5d9, 7625, 1), (0x1d5da, 7626, 1), (0x1d5db, 7627, 1),
+    (0x1d5dc, 7628, 1), (0x1d5dd, 7629, 1), (0x1d5de, 7630, 1), (0x1d5df, 7631, 1), (0x1d5e0, 7632, 1),
+    (0x1d5e1, 7633, 1), (0x1d5e2, 7634, 1), (0x1d5e3, 7635, 1), (0x1d5e4, 7636, 1), (0x1d5e5, 7637, 1),
+    (0x1d5e6, 7638, 1), (0x1d5e7, 7639, 1), (0x1d5e8, 7640, 1), (0x1d5e9, 7641, 1), (0x1d5ea, 7642, 1),
+    (0x1d5eb, 7643, 1), (0x1d5ec, 7644, 1), (0x1d5ed, 7645, 1), (0x1d5ee, 7646, 1), (0x1d5ef, 7647, 1),
+    (0x1d5f0, 7648, 1), (0x1d5f1, 7649, 1), (0x1d5f2, 7650, 1), (0x1d5f3, 7651, 1), (0x1d5f4, 7652, 1),
+    (0x1d5f5, 7653, 1), (0x1d5f6, 7654, 1), (0x1d5f7, 7655, 1), (0x1d5f8, 7656, 1), (0x1d5f9, 7657, 1),
+    (0x1d5fa, 7658, 2), (0x1d5fb, 7660, 1), (0x1d5fc, 7661, 1), (0x1d5fd, 7662, 1), (0x1d5fe, 7663, 1),
+    (0x1d5ff, 7664, 1), (0x1d600, 7665, 1), (0x1d601, 7666, 1), (0x1d602, 7667, 1), (0x1d603, 7668, 1),
+    (0x1d604, 7669, 1), (0x1d605, 7670, 1), (0x1d606, 7671, 1), (0x1d607, 7672, 1), (0x1d608, 7673, 1),
+    (0x1d609, 7674, 1), (0x1d60a, 7675, 1), (0x1d60b, 7676, 1), (0x1d60c, 7677, 1), (0x1d60d, 7678, 1),
+    (0x1d60e, 7679, 1), (0x1d60f, 7680, 1), (0x1d610, 7681, 1), (0x1d611, 7682, 1), (0x1d612, 7683, 1),
+    (0x1d613, 7684, 1), (0x1d614, 7685, 1), (0x1d615, 7686, 1), (0x1d616, 7687, 1), (0x1d617, 7688, 1),
+    (0x1d618, 7689, 1), (0x1d619, 7690, 1), (0x1d61a, 7691, 1), (0x1d61b, 7692, 1), (0x1d61c, 7693, 1),
+    (0x1d61d, 7694, 1), (0x1d61e, 7695, 1), (0x1d61f, 7696, 1), (0x1d620, 7697, 1), (0x1d621, 7698, 1),
+    (0x1d622, 7699, 1), (0x1d623, 7700, 1), (0x1d624, 7701, 1), (0x1d625, 7702, 1), (0x1d626, 7703, 1),
+    (0x1d627, 7704, 1), (0x1d628, 7705, 1), (0x1d629, 7706, 1), (0x1d62a, 7707, 1), (0x1d62b, 7708, 1),
+    (0x1d62c, 7709, 1), (0x1d62d, 7710, 1), (0x1d62e, 7711, 2), (0x1d62f, 7713, 1), (0x1d630, 7714, 1),
+    (0x1d631, 7715, 1), (0x1d632, 7716, 1), (0x1d633, 7717, 1), (0x1d634, 7718, 1), (0x1d635, 7719, 1),
+    (0x1d636, 7720, 1), (0x1d637, 7721, 1), (0x1d638, 7722, 1), (0x1d639, 7723, 1), (0x1d63a, 7724, 1),
+    (0x1d63b, 7725, 1), (0x1d63c, 7726, 1), (0x1d63d, 7727, 1), (0x1d63e, 7728, 1), (0x1d63f, 7729, 1),
+    (0x1d640, 7730, 1), (0x1d641, 7731, 1), (0x1d642, 7732, 1), (0x1d643, 7733, 1), (0x1d644, 7734, 1),
+    (0x1d645, 7735, 1), (0x1d646, 7736, 1), (0x1d647, 7737, 1), (0x1d648, 7738, 1), (0x1d649, 7739, 1),
+    (0x1d64a, 7740, 1), (0x1d64b, 7741, 1), (0x1d64c, 7742, 1), (0x1d64d, 7743, 1), (0x1d64e, 7744, 1),
+    (0x1d64f, 7745, 1), (0x1d650, 7746, 1), (0x1d651, 7747, 1), (0x1d652, 7748, 1), (0x1d653, 7749, 1),
+    (0x1d654, 7750, 1), (0x1d655, 7751, 1), (0x1d656, 7752, 1), (0x1d657, 7753, 1), (0x1d658, 7754, 1),
+    (0x1d659, 7755, 1), (0x1d65a, 7756, 1), (0x1d65b, 7757, 1), (0x1d65c, 7758, 1), (0x1d65d, 7759, 1),
+    (0x1d65e, 7760, 1), (0x1d65f, 7761, 1), (0x1d660, 7762, 1), (0x1d661, 7763, 1), (0x1d662, 7764, 2),
+    (0x1d663, 7766, 1), (0x1d664, 7767, 1), (0x1d665, 7768, 1), (0x1d666, 7769, 1), (0x1d667, 7770, 1),
+    (0x1d668, 7771, 1), (0x1d669, 7772, 1), (0x1d66a, 7773, 1), (0x1d66b, 7774, 1), (0x1d66c, 7775, 1),
+    (0x1d66d, 7776, 1), (0x1d66e, 7777, 1), (0x1d66f, 7778, 1), (0x1d670, 7779, 1), (0x1d671, 7780, 1),
+    (0x1d672, 7781, 1), (0x1d673, 7782, 1), (0x1d674, 7783, 1), (0x1d675, 7784, 1), (0x1d676, 7785, 1),
+    (0x1d677, 7786, 1), (0x1d678, 7787, 1), (0x1d679, 7788, 1), (0x1d67a, 7789, 1), (0x1d67b, 7790, 1),
+    (0x1d67c, 7791, 1), (0x1d67d, 7792, 1), (0x1d67e, 7793, 1), (0x1d67f, 7794, 1), (0x1d680, 7795, 1),
+    (0x1d681, 7796, 1), (0x1d682, 7797, 1), (0x1d683, 7798, 1), (0x1d684, 7799, 1), (0x1d685, 7800, 1),
+    (0x1d686, 7801, 1), (0x1d687, 7802, 1), (0x1d688, 7803, 1), (0x1d689, 7804, 1), (0x1d68a, 7805, 1),
+    (0x1d68b, 7806, 1), (0x1d68c, 7807, 1), (0x1d68d, 7808, 1), (0x1d68e, 7809, 1), (0x1d68f, 7810, 1),
+    (0x1d690, 7811, 1), (0x1d691, 7812, 1), (0x1d692, 7813, 1), (0x1d693, 7814, 1), (0x1d694, 7815, 1),
+    (0x1d695, 7816, 1), (0x1d696, 7817, 2), (0x1d697, 7819, 1), (0x1d698, 7820, 1), (0x1d699, 7821, 1),
+    (0x1d69a, 7822, 1), (0x1d69b, 7823, 1), (0x1d69c, 7824, 1), (0x1d69d, 7825, 1), (0x1d69e, 7826, 1),
+    (0x1d69f, 7827, 1), (0x1d6a0, 7828, 1), (0x1d6a1, 7829, 1), (0x1d6a2, 7830, 1), (0x1d6a3, 7831, 1),
+    (0x1d6a4, 7832, 1), (0x1d6a5, 7833, 1), (0x1d6a8, 7834, 1), (0x1d6a9, 7835, 1), (0x1d6aa, 7836, 1),
+    (0x1d6ab, 7837, 1), (0x1d6ac, 7838, 1), (0x1d6ad, 7839, 1), (0x1d6ae, 7840, 1), (0x1d6af, 7841, 2),
+    (0x1d6b0, 7843, 1), (0x1d6b1, 7844, 1), (0x1d6b2, 7845, 1), (0x1d6b3, 7846, 1), (0x1d6b4, 7847, 1),
+    (0x1d6b5, 7848, 1), (0x1d6b6, 7849, 1), (0x1d6b7, 7850, 1), (0x1d6b8, 7851, 1), (0x1d6b9, 7852, 2),
+    (0x1d6ba, 7854, 1), (0x1d6bb, 7855, 1), (0x1d6bc, 7856, 1), (0x1d6bd, 7857, 1), (0x1d6be, 7858, 1),
+    (0x1d6bf, 7859, 1), (0x1d6c0, 7860, 1), (0x1d6c1, 7861, 1), (0x1d6c2, 7862, 1), (0x1d6c3, 7863, 1),
+    (0x1d6c4, 7864, 1), (0x1d6c5, 7865, 1), (0x1d6c6, 7866, 1), (0x1d6c7, 7867, 1), (0x1d6c8, 7868, 2),
+    (0x1d6c9, 7870, 2), (0x1d6ca, 7872, 1), (0x1d6cb, 7873, 1), (0x1d6cc, 7874, 1), (0x1d6cd, 7875, 1),
+    (0x1d6ce, 7876, 1), (0x1d6cf, 7877, 1), (0x1d6d0, 7878, 1), (0x1d6d1, 7879, 1), (0x1d6d2, 7880, 1),
+    (0x1d6d3, 7881, 1), (0x1d6d4, 7882, 1), (0x1d6d5, 7883, 1), (0x1d6d6, 7884, 1), (0x1d6d7, 7885, 1),
+    (0x1d6d8, 7886, 1), (0x1d6d9, 7887, 1), (0x1d6da, 7888, 1), (0x1d6db, 7889, 1), (0x1d6dc, 7890, 1),
+    (0x1d6dd, 7891, 2), (0x1d6de, 7893, 1), (0x1d6df, 7894, 1), (0x1d6e0, 7895, 1), (0x1d6e1, 7896, 1),
+    (0x1d6e2, 7897, 1), (0x1d6e3, 7898, 1), (0x1d6e4, 7899, 1), (0x1d6e5, 7900, 1), (0x1d6e6, 7901, 1),
+    (0x1d6e7, 7902, 1), (0x1d6e8, 7903, 1), (0x1d6e9, 7904, 2), (0x1d6ea, 7906, 1), (0x1d6eb, 7907, 1),
+    (0x1d6ec, 7908, 1), (0x1d6ed, 7909, 1), (0x1d6ee, 7910, 1), (0x1d6ef, 7911, 1), (0x1d6f0, 7912, 1),
+    (0x1d6f1, 7913, 1), (0x1d6f2, 7914, 1), (0x1d6f3, 7915, 2), (0x1d6f4, 7917, 1), (0x1d6f5, 7918, 1),
+    (0x1d6f6, 7919, 1), (0x1d6f7, 7920, 1), (0x1d6f8, 7921, 1), (0x1d6f9, 7922, 1), (0x1d6fa, 7923, 1),
+    (0x1d6fb, 7924, 1), (0x1d6fc, 7925, 1), (0x1d6fd, 7926, 1), (0x1d6fe, 7927, 1), (0x1d6ff, 7928, 1),
+    (0x1d700, 7929, 1), (0x1d701, 7930, 1), (0x1d702, 7931, 2), (0x1d703, 7933, 2), (0x1d704, 7935, 1),
+    (0x1d705, 7936, 1), (0x1d706, 7937, 1), (0x1d707, 7938, 1), (0x1d708, 7939, 1), (0x1d709, 7940, 1),
+    (0x1d70a, 7941, 1), (0x1d70b, 7942, 1), (0x1d70c, 7943, 1), (0x1d70d, 7944, 1), (0x1d70e, 7945, 1),
+    (0x1d70f, 7946, 1), (0x1d710, 7947, 1), (0x1d711, 7948, 1), (0x1d712, 7949, 1), (0x1d713, 7950, 1),
+    (0x1d714, 7951, 1), (0x1d715, 7952, 1), (0x1d716, 7953, 1), (0x1d717, 7954, 2), (0x1d718, 7956, 1),
+    (0x1d719, 7957, 1), (0x1d71a, 7958, 1), (0x1d71b, 7959, 1), (0x1d71c, 7960, 1), (0x1d71d, 7961, 1),
+    (0x1d71e, 7962, 1), (0x1d71f, 7963, 1), (0x1d720, 7964, 1), (0x1d721, 7965, 1), (0x1d722, 7966, 1),
+    (0x1d723, 7967, 2), (0x1d724, 7969, 1), (0x1d725, 7970, 1), (0x1d726, 7971, 1), (0x1d727, 7972, 1),
+    (0x1d728, 7973, 1), (0x1d729, 7974, 1), (0x1d72a, 7975, 1), (0x1d72b, 7976, 1), (0x1d72c, 7977, 1),
+    (0x1d72d, 7978, 2), (0x1d72e, 7980, 1), (0x1d72f, 7981, 1), (0x1d730, 7982, 1), (0x1d731, 7983, 1),
+    (0x1d732, 7984, 1), (0x1d733, 7985, 1), (0x1d734, 7986, 1), (0x1d735, 7987, 1), (0x1d736, 7988, 1),
+    (0x1d737, 7989, 1), (0x1d738, 7990, 1), (0x1d739, 7991, 1), (0x1d73a, 7992, 1), (0x1d73b, 7993, 1),
+    (0x1d73c, 7994, 2), (0x1d73d, 7996, 2), (0x1d73e, 7998, 1), (0x1d73f, 7999, 1), (0x1d740, 8000, 1),
+    (0x1d741, 8001, 1), (0x1d742, 8002, 1), (0x1d743, 8003, 1), (0x1d744, 8004, 1), (0x1d745, 8005, 1),
+    (0x1d746, 8006, 1), (0x1d747, 8007, 1), (0x1d748, 8008, 1), (0x1d749, 8009, 1), (0x1d74a, 8010, 1),
+    (0x1d74b, 8011, 1), (0x1d74c, 8012, 1), (0x1d74d, 8013, 1), (0x1d74e, 8014, 1), (0x1d74f, 8015, 1),
+    (0x1d750, 8016, 1), (0x1d751, 8017, 2), (0x1d752, 8019, 1), (0x1d753, 8020, 1), (0x1d754, 8021, 1),
+    (0x1d755, 8022, 1), (0x1d756, 8023, 1), (0x1d757, 8024, 1), (0x1d758, 8025, 1), (0x1d759, 8026, 1),
+    (0x1d75a, 8027, 1), (0x1d75b, 8028, 1), (0x1d75c, 8029, 1), (0x1d75d, 8030, 2), (0x1d75e, 8032, 1),
+    (0x1d75f, 8033, 1), (0x1d760, 8034, 1), (0x1d761, 8035, 1), (0x1d762, 8036, 1), (0x1d763, 8037, 1),
+    (0x1d764, 8038, 1), (0x1d765, 8039, 1), (0x1d766, 8040, 1), (0x1d767, 8041, 2), (0x1d768, 8043, 1),
+    (0x1d769, 8044, 1), (0x1d76a, 8045, 1), (0x1d76b, 8046, 1), (0x1d76c, 8047, 1), (0x1d76d, 8048, 1),
+    (0x1d76e, 8049, 1), (0x1d76f, 8050, 1), (0x1d770, 8051, 1), (0x1d771, 8052, 1), (0x1d772, 8053, 1),
+    (0x1d773, 8054, 1), (0x1d774, 8055, 1), (0x1d775, 8056, 1), (0x1d776, 8057, 2), (0x1d777, 8059, 2),
+    (0x1d778, 8061, 1), (0x1d779, 8062, 1), (0x1d77a, 8063, 1), (0x1d77b, 8064, 1), (0x1d77c, 8065, 1),
+    (0x1d77d, 8066, 1), (0x1d77e, 8067, 1), (0x1d77f, 8068, 1), (0x1d780, 8069, 1), (0x1d781, 8070, 1),
+    (0x1d782, 8071, 1), (0x1d783, 8072, 1), (0x1d784, 8073, 1), (0x1d785, 8074, 1), (0x1d786, 8075, 1),
+    (0x1d787, 8076, 1), (0x1d788, 8077, 1), (0x1d789, 8078, 1), (0x1d78a, 8079, 1), (0x1d78b, 8080, 2),
+    (0x1d78c, 8082, 1), (0x1d78d, 8083, 1), (0x1d78e, 8084, 1), (0x1d78f, 8085, 1), (0x1d790, 8086, 1),
+    (0x1d791, 8087, 1), (0x1d792, 8088, 1), (0x1d793, 8089, 1), (0x1d794, 8090, 1), (0x1d795, 8091, 1),
+    (0x1d796, 8092, 1), (0x1d797, 8093, 2), (0x1d798, 8095, 1), (0x1d799, 8096, 1), (0x1d79a, 8097, 1),
+    (0x1d79b, 8098, 1), (0x1d79c, 8099, 1), (0x1d79d, 8100, 1), (0x1d79e, 8101, 1), (0x1d79f, 8102, 1),
+    (0x1d7a0, 8103, 1), (0x1d7a1, 8104, 2), (0x1d7a2, 8106, 1), (0x1d7a3, 8107, 1), (0x1d7a4, 8108, 1),
+    (0x1d7a5, 8109, 1), (0x1d7a6, 8110, 1), (0x1d7a7, 8111, 1), (0x1d7a8, 8112, 1), (0x1d7a9, 8113, 1),
+    (0x1d7aa, 8114, 1), (0x1d7ab, 8115, 1), (0x1d7ac, 8116, 1), (0x1d7ad, 8117, 1), (0x1d7ae, 8118, 1),
+    (0x1d7af, 8119, 1), (0x1d7b0, 8120, 2), (0x1d7b1, 8122, 2), (0x1d7b2, 8124, 1), (0x1d7b3, 8125, 1),
+    (0x1d7b4, 8126, 1), (0x1d7b5, 8127, 1), (0x1d7b6, 8128, 1), (0x1d7b7, 8129, 1), (0x1d7b8, 8130, 1),
+    (0x1d7b9, 8131, 1), (0x1d7ba, 8132, 1), (0x1d7bb, 8133, 1), (0x1d7bc, 8134, 1), (0x1d7bd, 8135, 1),
+    (0x1d7be, 8136, 1), (0x1d7bf, 8137, 1), (0x1d7c0, 8138, 1), (0x1d7c1, 8139, 1), (0x1d7c2, 8140, 1),
+    (0x1d7c3, 8141, 1), (0x1d7c4, 8142, 1), (0x1d7c5, 8143, 2), (0x1d7c6, 8145, 1), (0x1d7c7, 8146, 1),
+    (0x1d7c8, 8147, 1), (0x1d7c9, 8148, 1), (0x1d7ca, 8149, 1), (0x1d7cb, 8150, 1), (0x1d7ce, 8151, 1),
+    (0x1d7cf, 8152, 1), (0x1d7d0, 8153, 1), (0x1d7d1, 8154, 1), (0x1d7d2, 8155, 1), (0x1d7d3, 8156, 1),
+    (0x1d7d4, 8157, 1), (0x1d7d5, 8158, 1), (0x1d7d6, 8159, 1), (0x1d7d7, 8160, 1), (0x1d7d8, 8161, 1),
+    (0x1d7d9, 8162, 1), (0x1d7da, 8163, 1), (0x1d7db, 8164, 1), (0x1d7dc, 8165, 1), (0x1d7dd, 8166, 1),
+    (0x1d7de, 8167, 1), (0x1d7df, 8168, 1), (0x1d7e0, 8169, 1), (0x1d7e1, 8170, 1), (0x1d7e2, 8171, 1),
+    (0x1d7e3, 8172, 1), (0x1d7e4, 8173, 1), (0x1d7e5, 8174, 1), (0x1d7e6, 8175, 1), (0x1d7e7, 8176, 1),
+    (0x1d7e8, 8177, 1), (0x1d7e9, 8178, 1), (0x1d7ea, 8179, 1), (0x1d7eb, 8180, 1), (0x1d7ec, 8181, 1),
+    (0x1d7ed, 8182, 1), (0x1d7ee, 8183, 1), (0x1d7ef, 8184, 1), (0x1d7f0, 8185, 1), (0x1d7f1, 8186, 1),
+    (0x1d7f2, 8187, 1), (0x1d7f3, 8188, 1), (0x1d7f4, 8189, 1), (0x1d7f5, 8190, 1), (0x1d7f6, 8191, 1),
+    (0x1d7f7, 8192, 1), (0x1d7f8, 8193, 1), (0x1d7f9, 8194, 1), (0x1d7fa, 8195, 1), (0x1d7fb, 8196, 1),
+    (0x1d7fc, 8197, 1), (0x1d7fd, 8198, 1), (0x1d7fe, 8199, 1), (0x1d7ff, 8200, 1), (0x1e8c7, 8201, 1),
+    (0x1e8c8, 8202, 1), (0x1e8c9, 8203, 1), (0x1e8cb, 8204, 1), (0x1e8cc, 8205, 1), (0x1e8cd, 8206, 2),
+    (0x1ee00, 8208, 1), (0x1ee01, 8209, 1), (0x1ee02, 8210, 1), (0x1ee03, 8211, 1), (0x1ee05, 8212, 1),
+    (0x1ee06, 8213, 1), (0x1ee07, 8214, 1), (0x1ee08, 8215, 1), (0x1ee09, 8216, 1), (0x1ee0a, 8217, 1),
+    (0x1ee0b, 8218, 1), (0x1ee0c, 8219, 1), (0x1ee0d, 8220, 1), (0x1ee0e, 8221, 1), (0x1ee0f, 8222, 1),
+    (0x1ee10, 8223, 1), (0x1ee11, 8224, 1), (0x1ee12, 8225, 1), (0x1ee13, 8226, 1), (0x1ee14, 8227, 2),
+    (0x1ee15, 8229, 1), (0x1ee16, 8230, 2), (0x1ee17, 8232, 1), (0x1ee18, 8233, 1), (0x1ee19, 8234, 1),
+    (0x1ee1a, 8235, 1), (0x1ee1b, 8236, 1), (0x1ee1c, 8237, 1), (0x1ee1d, 8238, 1), (0x1ee1e, 8239, 1),
+    (0x1ee1f, 8240, 1), (0x1ee21, 8241, 1), (0x1ee22, 8242, 1), (0x1ee24, 8243, 1), (0x1ee27, 8244, 1),
+    (0x1ee29, 8245, 1), (0x1ee2a, 8246, 1), (0x1ee2b, 8247, 1), (0x1ee2c, 8248, 1), (0x1ee2d, 8249, 1),
+    (0x1ee2e, 8250, 1), (0x1ee2f, 8251, 1), (0x1ee30, 8252, 1), (0x1ee31, 8253, 1), (0x1ee32, 8254, 1),
+    (0x1ee34, 8255, 2), (0x1ee35, 8257, 1), (0x1ee36, 8258, 2), (0x1ee37, 8260, 1), (0x1ee39, 8261, 1),
+    (0x1ee3b, 8262, 1), (0x1ee42, 8263, 1), (0x1ee47, 8264, 1), (0x1ee49, 8265, 1), (0x1ee4b, 8266, 1),
+    (0x1ee4d, 8267, 1), (0x1ee4e, 8268, 1), (0x1ee4f, 8269, 1), (0x1ee51, 8270, 1), (0x1ee52, 8271, 1),
+    (0x1ee54, 8272, 2), (0x1ee57, 8274, 1), (0x1ee59, 8275, 1), (0x1ee5b, 8276, 1), (0x1ee5d, 8277, 1),
+    (0x1ee5f, 8278, 1), (0x1ee61, 8279, 1), (0x1ee62, 8280, 1), (0x1ee64, 8281, 1), (0x1ee67, 8282, 1),
+    (0x1ee68, 8283, 1), (0x1ee69, 8284, 1), (0x1ee6a, 8285, 1), (0x1ee6c, 8286, 1), (0x1ee6d, 8287, 1),
+    (0x1ee6e, 8288, 1), (0x1ee6f, 8289, 1), (0x1ee70, 8290, 1), (0x1ee71, 8291, 1), (0x1ee72, 8292, 1),
+    (0x1ee74, 8293, 2), (0x1ee75, 8295, 1), (0x1ee76, 8296, 2), (0x1ee77, 8298, 1), (0x1ee79, 8299, 1),
+    (0x1ee7a, 8300, 1), (0x1ee7b, 8301, 1), (0x1ee7c, 8302, 1), (0x1ee7e, 8303, 1), (0x1ee80, 8304, 1),
+    (0x1ee81, 8305, 1), (0x1ee82, 8306, 1), (0x1ee83, 8307, 1), (0x1ee84, 8308, 1), (0x1ee85, 8309, 1),
+    (0x1ee86, 8310, 1), (0x1ee87, 8311, 1), (0x1ee88, 8312, 1), (0x1ee89, 8313, 1), (0x1ee8b, 8314, 1),
+    (0x1ee8c, 8315, 1), (0x1ee8d, 8316, 1), (0x1ee8e, 8317, 1), (0x1ee8f, 8318, 1), (0x1ee90, 8319, 1),
+    (0x1ee91, 8320, 1), (0x1ee92, 8321, 1), (0x1ee93, 8322, 1), (0x1ee94, 8323, 2), (0x1ee95, 8325, 1),
+    (0x1ee96, 8326, 2), (0x1ee97, 8328, 1), (0x1ee98, 8329, 1), (0x1ee99, 8330, 1), (0x1ee9a, 8331, 1),
+    (0x1ee9b, 8332, 1), (0x1eea1, 8333, 1), (0x1eea2, 8334, 1), (0x1eea3, 8335, 1), (0x1eea5, 8336, 1),
+    (0x1eea6, 8337, 1), (0x1eea7, 8338, 1), (0x1eea8, 8339, 1), (0x1eea9, 8340, 1), (0x1eeab, 8341, 1),
+    (0x1eeac, 8342, 1), (0x1eead, 8343, 1), (0x1eeae, 8344, 1), (0x1eeaf, 8345, 1), (0x1eeb0, 8346, 1),
+    (0x1eeb1, 8347, 1), (0x1eeb2, 8348, 1), (0x1eeb3, 8349, 1), (0x1eeb4, 8350, 2), (0x1eeb5, 8352, 1),
+    (0x1eeb6, 8353, 2), (0x1eeb7, 8355, 1), (0x1eeb8, 8356, 1), (0x1eeb9, 8357, 1), (0x1eeba, 8358, 1),
+    (0x1eebb, 8359, 1), (0x1f100, 8360, 2), (0x1f101, 8362, 2), (0x1f102, 8364, 2), (0x1f103, 8366, 2),
+    (0x1f104, 8368, 2), (0x1f105, 8370, 2), (0x1f106, 8372, 2), (0x1f107, 8374, 2), (0x1f108, 8376, 2),
+    (0x1f109, 8378, 2), (0x1f10a, 8380, 2), (0x1f10f, 8382, 2), (0x1f110, 8384, 3), (0x1f111, 8387, 3),
+    (0x1f112, 8390, 3), (0x1f113, 8393, 3), (0x1f114, 8396, 3), (0x1f115, 8399, 3), (0x1f116, 8402, 3),
+    (0x1f117, 8405, 3), (0x1f118, 8408, 3), (0x1f119, 8411, 3), (0x1f11a, 8414, 3), (0x1f11b, 8417, 3),
+    (0x1f11c, 8420, 3), (0x1f11d, 8423, 3), (0x1f11e, 8426, 3), (0x1f11f, 8429, 3), (0x1f120, 8432, 3),
+    (0x1f121, 8435, 3), (0x1f122, 8438, 3), (0x1f123, 8441, 3), (0x1f124, 8444, 3), (0x1f125, 8447, 3),
+    (0x1f126, 8450, 3), (0x1f127, 8453, 3), (0x1f128, 8456, 3), (0x1f129, 8459, 3), (0x1f12a, 8462, 3),
+    (0x1f16d, 8465, 3), (0x1f16e, 8468, 2), (0x1f240, 8470, 3), (0x1f241, 8473, 3), (0x1f242, 8476, 3),
+    (0x1f243, 8479, 3), (0x1f244, 8482, 3), (0x1f245, 8485, 3), (0x1f246, 8488, 3), (0x1f247, 8491, 3),
+    (0x1f248, 8494, 3), (0x1f312, 8497, 1), (0x1f318, 8498, 1), (0x1f319, 8499, 1), (0x1f700, 8500, 2),
+    (0x1f701, 8502, 1), (0x1f702, 8503, 1), (0x1f704, 8504, 1), (0x1f707, 8505, 2), (0x1f708, 8507, 2),
+    (0x1f70a, 8509, 1), (0x1f714, 8510, 2), (0x1f728, 8512, 1), (0x1f73a, 8513, 1), (0x1f74c, 8514, 1),
+    (0x1f754, 8515, 1), (0x1f755, 8516, 1), (0x1f75c, 8517, 3), (0x1f75e, 8520, 1), (0x1f768, 8521, 1),
+    (0x1f76b, 8522, 2), (0x1f76c, 8524, 2), (0x1f771, 8526, 1), (0x1fbf0, 8527, 1), (0x1fbf1, 8528, 1),
+    (0x1fbf2, 8529, 1), (0x1fbf3, 8530, 1), (0x1fbf4, 8531, 1), (0x1fbf5, 8532, 1), (0x1fbf6, 8533, 1),
+    (0x1fbf7, 8534, 1), (0x1fbf8, 8535, 1), (0x1fbf9, 8536, 1), (0x21fe8, 8537, 1), (0x2f800, 8538, 1),
+    (0x2f801, 8539, 1), (0x2f802, 8540, 1), (0x2f803, 8541, 1), (0x2f804, 8542, 1), (0x2f805, 8543, 1),
+    (0x2f806, 8544, 1), (0x2f807, 8545, 1), (0x2f808, 8546, 1), (0x2f809, 8547, 1), (0x2f80a, 8548, 1),
+    (0x2f80b, 8549, 1), (0x2f80c, 8550, 1), (0x2f80d, 8551, 1), (0x2f80e, 8552, 1), (0x2f80f, 8553, 1),
+    (0x2f810, 8554, 1), (0x2f811, 8555, 1), (0x2f812, 8556, 1), (0x2f813, 8557, 1), (0x2f814, 8558, 1),
+    (0x2f815, 8559, 1), (0x2f816, 8560, 1), (0x2f817, 8561, 1), (0x2f818, 8562, 1), (0x2f819, 8563, 1),
+    (0x2f81a, 8564, 1), (0x2f81b, 8565, 1), (0x2f81c, 8566, 1), (0x2f81d, 8567, 1), (0x2f81e, 8568, 1),
+    (0x2f81f, 8569, 1), (0x2f820, 8570, 1), (0x2f821, 8571, 1), (0x2f822, 8572, 1), (0x2f823, 8573, 1),
+    (0x2f824, 8574, 1), (0x2f825, 8575, 1), (0x2f826, 8576, 1), (0x2f827, 8577, 1), (0x2f828, 8578, 1),
+    (0x2f829, 8579, 1), (0x2f82a, 8580, 1), (0x2f82b, 8581, 1), (0x2f82c, 8582, 1), (0x2f82d, 8583, 1),
+    (0x2f82e, 8584, 1), (0x2f82f, 8585, 1), (0x2f830, 8586, 1), (0x2f831, 8587, 1), (0x2f832, 8588, 1),
+    (0x2f833, 8589, 1), (0x2f834, 8590, 1), (0x2f835, 8591, 1), (0x2f836, 8592, 1), (0x2f837, 8593, 1),
+    (0x2f838, 8594, 1), (0x2f839, 8595, 1), (0x2f83a, 8596, 1), (0x2f83b, 8597, 1), (0x2f83c, 8598, 1),
+    (0x2f83d, 8599, 1), (0x2f83e, 8600, 1), (0x2f83f, 8601, 1), (0x2f840, 8602, 1), (0x2f841, 8603, 1),
+    (0x2f842, 8604, 1), (0x2f843, 8605, 1), (0x2f844, 8606, 1), (0x2f845, 8607, 1), (0x2f846, 8608, 1),
+    (0x2f847, 8609, 1), (0x2f848, 8610, 1), (0x2f849, 8611, 1), (0x2f84a, 8612, 1), (0x2f84b, 8613, 1),
+    (0x2f84c, 8614, 1), (0x2f84d, 8615, 1), (0x2f84e, 8616, 1), (0x2f84f, 8617, 1), (0x2f850, 8618, 1),
+    (0x2f851, 8619, 1), (0x2f852, 8620, 1), (0x2f853, 8621, 1), (0x2f854, 8622, 1), (0x2f855, 8623, 1),
+    (0x2f856, 8624, 1), (0x2f857, 8625, 1), (0x2f858, 8626, 1), (0x2f859, 8627, 1), (0x2f85a, 8628, 1),
+    (0x2f85b, 8629, 1), (0x2f85c, 8630, 1), (0x2f85d, 8631, 1), (0x2f85e, 8632, 1), (0x2f85f, 8633, 1),
+    (0x2f860, 8634, 1), (0x2f861, 8635, 1), (0x2f862, 8636, 1), (0x2f863, 8637, 1), (0x2f864, 8638, 1),
+    (0x2f865, 8639, 1), (0x2f866, 8640, 1), (0x2f867, 8641, 1), (0x2f868, 8642, 1), (0x2f869, 8643, 1),
+    (0x2f86a, 8644, 1), (0x2f86b, 8645, 1), (0x2f86c, 8646, 1), (0x2f86d, 8647, 1), (0x2f86e, 8648, 1),
+    (0x2f86f, 8649, 1), (0x2f870, 8650, 1), (0x2f871, 8651, 1), (0x2f872, 8652, 1), (0x2f873, 8653, 1),
+    (0x2f874, 8654, 1), (0x2f875, 8655, 1), (0x2f876, 8656, 1), (0x2f877, 8657, 1), (0x2f878, 8658, 1),
+    (0x2f879, 8659, 1), (0x2f87a, 8660, 1), (0x2f87b, 8661, 1), (0x2f87c, 8662, 1), (0x2f87d, 8663, 1),
+    (0x2f87e, 8664, 1), (0x2f87f, 8665, 1), (0x2f880, 8666, 1), (0x2f881, 8667, 1), (0x2f882, 8668, 1),
+    (0x2f883, 8669, 1), (0x2f884, 8670, 1), (0x2f885, 8671, 1), (0x2f886, 8672, 1), (0x2f887, 8673, 1),
+    (0x2f888, 8674, 1), (0x2f889, 8675, 1), (0x2f88a, 8676, 1), (0x2f88b, 8677, 1), (0x2f88c, 8678, 1),
+    (0x2f88d, 8679, 1), (0x2f88e, 8680, 1), (0x2f88f, 8681, 1), (0x2f890, 8682, 1), (0x2f891, 8683, 1),
+    (0x2f892, 8684, 1), (0x2f893, 8685, 1), (0x2f894, 8686, 1), (0x2f895, 8687, 1), (0x2f896, 8688, 1),
+    (0x2f897, 8689, 1), (0x2f898, 8690, 1), (0x2f899, 8691, 1), (0x2f89a, 8692, 1), (0x2f89b, 8693, 1),
+    (0x2f89c, 8694, 1), (0x2f89d, 8695, 1), (0x2f89e, 8696, 1), (0x2f89f, 8697, 1), (0x2f8a0, 8698, 1),
+    (0x2f8a1, 8699, 1), (0x2f8a2, 8700, 1), (0x2f8a3, 8701, 1), (0x2f8a4, 8702, 1), (0x2f8a5, 8703, 1),
+    (0x2f8a6, 8704, 1), (0x2f8a7, 8705, 1), (0x2f8a8, 8706, 1), (0x2f8a9, 8707, 1), (0x2f8aa, 8708, 1),
+    (0x2f8ab, 8709, 1), (0x2f8ac, 8710, 1), (0x2f8ad, 8711, 1), (0x2f8ae, 8712, 1), (0x2f8af, 8713, 1),
+    (0x2f8b0, 8714, 1), (0x2f8b1, 8715, 1), (0x2f8b2, 8716, 1), (0x2f8b3, 8717, 1), (0x2f8b4, 8718, 1),
+    (0x2f8b5, 8719, 1), (0x2f8b6, 8720, 1), (0x2f8b7, 8721, 1), (0x2f8b8, 8722, 1), (0x2f8b9, 8723, 1),
+    (0x2f8ba, 8724, 1), (0x2f8bb, 8725, 1), (0x2f8bc, 8726, 1), (0x2f8bd, 8727, 1), (0x2f8be, 8728, 1),
+    (0x2f8bf, 8729, 1), (0x2f8c0, 8730, 1), (0x2f8c1, 8731, 1), (0x2f8c2, 8732, 1), (0x2f8c3, 8733, 1),
+    (0x2f8c4, 8734, 1), (0x2f8c5, 8735, 1), (0x2f8c6, 8736, 1), (0x2f8c7, 8737, 1), (0x2f8c8, 8738, 1),
+    (0x2f8c9, 8739, 1), (0x2f8ca, 8740, 1), (0x2f8cb, 8741, 1), (0x2f8cc, 8742, 1), (0x2f8cd, 8743, 1),
+    (0x2f8ce, 8744, 1), (0x2f8cf, 8745, 1), (0x2f8d0, 8746, 1), (0x2f8d1, 8747, 1), (0x2f8d2, 8748, 1),
+    (0x2f8d3, 8749, 1), (0x2f8d4, 8750, 1), (0x2f8d5, 8751, 1), (0x2f8d6, 8752, 1), (0x2f8d7, 8753, 1),
+    (0x2f8d8, 8754, 1), (0x2f8d9, 8755, 1), (0x2f8da, 8756, 1), (0x2f8db, 8757, 1), (0x2f8dc, 8758, 1),
+    (0x2f8dd, 8759, 1), (0x2f8de, 8760, 1), (0x2f8df, 8761, 1), (0x2f8e0, 8762, 1), (0x2f8e1, 8763, 1),
+    (0x2f8e2, 8764, 1), (0x2f8e3, 8765, 1), (0x2f8e4, 8766, 1), (0x2f8e5, 8767, 1), (0x2f8e6, 8768, 1),
+    (0x2f8e7, 8769, 1), (0x2f8e8, 8770, 1), (0x2f8e9, 8771, 1), (0x2f8ea, 8772, 1), (0x2f8eb, 8773, 1),
+    (0x2f8ec, 8774, 1), (0x2f8ed, 8775, 1), (0x2f8ee, 8776, 1), (0x2f8ef, 8777, 1), (0x2f8f0, 8778, 1),
+    (0x2f8f1, 8779, 1), (0x2f8f2, 8780, 1), (0x2f8f3, 8781, 1), (0x2f8f4, 8782, 1), (0x2f8f5, 8783, 1),
+    (0x2f8f6, 8784, 1), (0x2f8f7, 8785, 1), (0x2f8f8, 8786, 1), (0x2f8f9, 8787, 1), (0x2f8fa, 8788, 1),
+    (0x2f8fb, 8789, 1), (0x2f8fc, 8790, 1), (0x2f8fd, 8791, 1), (0x2f8fe, 8792, 1), (0x2f8ff, 8793, 1),
+    (0x2f900, 8794, 1), (0x2f901, 8795, 1), (0x2f902, 8796, 1), (0x2f903, 8797, 1), (0x2f904, 8798, 1),
+    (0x2f905, 8799, 1), (0x2f906, 8800, 1), (0x2f907, 8801, 1), (0x2f908, 8802, 1), (0x2f909, 8803, 1),
+    (0x2f90a, 8804, 1), (0x2f90b, 8805, 1), (0x2f90c, 8806, 1), (0x2f90d, 8807, 1), (0x2f90e, 8808, 1),
+    (0x2f90f, 8809, 1), (0x2f910, 8810, 1), (0x2f911, 8811, 1), (0x2f912, 8812, 1), (0x2f913, 8813, 1),
+    (0x2f914, 8814, 1), (0x2f915, 8815, 1), (0x2f916, 8816, 1), (0x2f917, 8817, 1), (0x2f918, 8818, 1),
+    (0x2f919, 8819, 1), (0x2f91a, 8820, 1), (0x2f91b, 8821, 1), (0x2f91c, 8822, 1), (0x2f91d, 8823, 1),
+    (0x2f91e, 8824, 1), (0x2f91f, 8825, 1), (0x2f920, 8826, 1), (0x2f921, 8827, 1), (0x2f922, 8828, 1),
+    (0x2f923, 8829, 1), (0x2f924, 8830, 1), (0x2f925, 8831, 1), (0x2f926, 8832, 1), (0x2f927, 8833, 1),
+    (0x2f928, 8834, 1), (0x2f929, 8835, 1), (0x2f92a, 8836, 1), (0x2f92b, 8837, 1), (0x2f92c, 8838, 1),
+    (0x2f92d, 8839, 1), (0x2f92e, 8840, 1), (0x2f92f, 8841, 1), (0x2f930, 8842, 1), (0x2f931, 8843, 1),
+    (0x2f932, 8844, 1), (0x2f933, 8845, 1), (0x2f934, 8846, 1), (0x2f935, 8847, 1), (0x2f936, 8848, 1),
+    (0x2f937, 8849, 1), (0x2f938, 8850, 1), (0x2f939, 8851, 1), (0x2f93a, 8852, 1), (0x2f93b, 8853, 1),
+    (0x2f93c, 8854, 1), (0x2f93d, 8855, 1), (0x2f93e, 8856, 1), (0x2f93f, 8857, 1), (0x2f940, 8858, 1),
+    (0x2f941, 8859, 1), (0x2f942, 8860, 1), (0x2f943, 8861, 1), (0x2f944, 8862, 1), (0x2f945, 8863, 1),
+    (0x2f946, 8864, 1), (0x2f947, 8865, 1), (0x2f948, 8866, 1), (0x2f949, 8867, 1), (0x2f94a, 8868, 1),
+    (0x2f94b, 8869, 1), (0x2f94c, 8870, 1), (0x2f94d, 8871, 1), (0x2f94e, 8872, 1), (0x2f94f, 8873, 1),
+    (0x2f950, 8874, 1), (0x2f951, 8875, 1), (0x2f952, 8876, 1), (0x2f953, 8877, 1), (0x2f954, 8878, 1),
+    (0x2f955, 8879, 1), (0x2f956, 8880, 1), (0x2f957, 8881, 1), (0x2f958, 8882, 1), (0x2f959, 8883, 1),
+    (0x2f95a, 8884, 1), (0x2f95b, 8885, 1), (0x2f95c, 8886, 1), (0x2f95d, 8887, 1), (0x2f95e, 8888, 1),
+    (0x2f95f, 8889, 1), (0x2f960, 8890, 1), (0x2f961, 8891, 1), (0x2f962, 8892, 1), (0x2f963, 8893, 1),
+    (0x2f964, 8894, 1), (0x2f965, 8895, 1), (0x2f966, 8896, 1), (0x2f967, 8897, 1), (0x2f968, 8898, 1),
+    (0x2f969, 8899, 1), (0x2f96a, 8900, 1), (0x2f96b, 8901, 1), (0x2f96c, 8902, 1), (0x2f96d, 8903, 1),
+    (0x2f96e, 8904, 1), (0x2f96f, 8905, 1), (0x2f970, 8906, 1), (0x2f971, 8907, 1), (0x2f972, 8908, 1),
+    (0x2f973, 8909, 1), (0x2f974, 8910, 1), (0x2f975, 8911, 1), (0x2f976, 8912, 1), (0x2f977, 8913, 1),
+    (0x2f978, 8914, 1), (0x2f979, 8915, 1), (0x2f97a, 8916, 1), (0x2f97b, 8917, 1), (0x2f97c, 8918, 1),
+    (0x2f97d, 8919, 1), (0x2f97e, 8920, 1), (0x2f97f, 8921, 1), (0x2f980, 8922, 1), (0x2f981, 8923, 1),
+    (0x2f982, 8924, 1), (0x2f983, 8925, 1), (0x2f984, 8926, 1), (0x2f985, 8927, 1), (0x2f986, 8928, 1),
+    (0x2f987, 8929, 1), (0x2f988, 8930, 1), (0x2f989, 8931, 1), (0x2f98a, 8932, 1), (0x2f98b, 8933, 1),
+    (0x2f98c, 8934, 1), (0x2f98d, 8935, 1), (0x2f98e, 8936, 1), (0x2f98f, 8937, 1), (0x2f990, 8938, 1),
+    (0x2f991, 8939, 1), (0x2f992, 8940, 1), (0x2f993, 8941, 1), (0x2f994, 8942, 1), (0x2f995, 8943, 1),
+    (0x2f996, 8944, 1), (0x2f997, 8945, 1), (0x2f998, 8946, 1), (0x2f999, 8947, 1), (0x2f99a, 8948, 1),
+    (0x2f99b, 8949, 1), (0x2f99c, 8950, 1), (0x2f99d, 8951, 1), (0x2f99e, 8952, 1), (0x2f99f, 8953, 1),
+    (0x2f9a0, 8954, 1), (0x2f9a1, 8955, 1), (0x2f9a2, 8956, 1), (0x2f9a3, 8957, 1), (0x2f9a4, 8958, 1),
+    (0x2f9a5, 8959, 1), (0x2f9a6, 8960, 1), (0x2f9a7, 8961, 1), (0x2f9a8, 8962, 1), (0x2f9a9, 8963, 1),
+    (0x2f9aa, 8964, 1), (0x2f9ab, 8965, 1), (0x2f9ac, 8966, 1), (0x2f9ad, 8967, 1), (0x2f9ae, 8968, 1),
+    (0x2f9af, 8969, 1), (0x2f9b0, 8970, 1), (0x2f9b1, 8971, 1), (0x2f9b2, 8972, 1), (0x2f9b3, 8973, 1),
+    (0x2f9b4, 8974, 1), (0x2f9b5, 8975, 1), (0x2f9b6, 8976, 1), (0x2f9b7, 8977, 1), (0x2f9b8, 8978, 1),
+    (0x2f9b9, 8979, 1), (0x2f9ba, 8980, 1), (0x2f9bb, 8981, 1), (0x2f9bc, 8982, 1), (0x2f9bd, 8983, 1),
+    (0x2f9be, 8984, 1), (0x2f9bf, 8985, 1), (0x2f9c0, 8986, 1), (0x2f9c1, 8987, 1), (0x2f9c2, 8988, 1),
+    (0x2f9c3, 8989, 1), (0x2f9c4, 8990, 1), (0x2f9c5, 8991, 1), (0x2f9c6, 8992, 1), (0x2f9c7, 8993, 1),
+    (0x2f9c8, 8994, 1), (0x2f9c9, 8995, 1), (0x2f9ca, 8996, 1), (0x2f9cb, 8997, 1), (0x2f9cc, 8998, 1),
+    (0x2f9cd, 8999, 1), (0x2f9ce, 9000, 1), (0x2f9cf, 9001, 1), (0x2f9d0, 9002, 1), (0x2f9d1, 9003, 1),
+    (0x2f9d2, 9004, 1), (0x2f9d3, 9005, 1), (0x2f9d4, 9006, 1), (0x2f9d5, 9007, 1), (0x2f9d6, 9008, 1),
+    (0x2f9d7, 9009, 1), (0x2f9d8, 9010, 1), (0x2f9d9, 9011, 1), (0x2f9da, 9012, 1), (0x2f9db, 9013, 1),
+    (0x2f9dc, 9014, 1), (0x2f9dd, 9015, 1), (0x2f9de, 9016, 1), (0x2f9df, 9017, 1), (0x2f9e0, 9018, 1),
+    (0x2f9e1, 9019, 1), (0x2f9e2, 9020, 1), (0x2f9e3, 9021, 1), (0x2f9e4, 9022, 1), (0x2f9e5, 9023, 1),
+    (0x2f9e6, 9024, 1), (0x2f9e7, 9025, 1), (0x2f9e8, 9026, 1), (0x2f9e9, 9027, 1), (0x2f9ea, 9028, 1),
+    (0x2f9eb, 9029, 1), (0x2f9ec, 9030, 1), (0x2f9ed, 9031, 1), (0x2f9ee, 9032, 1), (0x2f9ef, 9033, 1),
+    (0x2f9f0, 9034, 1), (0x2f9f1, 9035, 1), (0x2f9f2, 9036, 1), (0x2f9f3, 9037, 1), (0x2f9f4, 9038, 1),
+    (0x2f9f5, 9039, 1), (0x2f9f6, 9040, 1), (0x2f9f7, 9041, 1), (0x2f9f8, 9042, 1), (0x2f9f9, 9043, 1),
+    (0x2f9fa, 9044, 1), (0x2f9fb, 9045, 1), (0x2f9fc, 9046, 1), (0x2f9fd, 9047, 1), (0x2f9fe, 9048, 1),
+    (0x2f9ff, 9049, 1), (0x2fa00, 9050, 1), (0x2fa01, 9051, 1), (0x2fa02, 9052, 1), (0x2fa03, 9053, 1),
+    (0x2fa04, 9054, 1), (0x2fa05, 9055, 1), (0x2fa06, 9056, 1), (0x2fa07, 9057, 1), (0x2fa08, 9058, 1),
+    (0x2fa09, 9059, 1), (0x2fa0a, 9060, 1), (0x2fa0b, 9061, 1), (0x2fa0c, 9062, 1), (0x2fa0d, 9063, 1),
+    (0x2fa0e, 9064, 1), (0x2fa0f, 9065, 1), (0x2fa10, 9066, 1), (0x2fa11, 9067, 1), (0x2fa12, 9068, 1),
+    (0x2fa13, 9069, 1), (0x2fa14, 9070, 1), (0x2fa15, 9071, 1), (0x2fa16, 9072, 1), (0x2fa17, 9073, 1),
+    (0x2fa18, 9074, 1), (0x2fa19, 9075, 1), (0x2fa1a, 9076, 1), (0x2fa1b, 9077, 1), (0x2fa1c, 9078, 1),
+    (0x2fa1d, 9079, 1),
+];
+
+pub(crate) const CONFUSABLES_DATA: &[u32] = &[
+    0x27, 0x27, 0xba, 0x2f, 0x2080, 0x4f, 0x6c, 0x6c, 0x27, 0x72,
+    0x6e, 0x6c, 0x20, 0x63, 0x338, 0x59, 0x335, 0x2c9, 0x27, 0x3bc,
+    0x2c, 0x41, 0x45, 0x43, 0x326, 0x44, 0x335, 0x78, 0x4f, 0x338,
+    0x61, 0x65, 0x63, 0x326, 0x2202, 0x335, 0x629, 0x6f, 0x338, 0x44,
+    0x335, 0x64, 0x335, 0x45, 0x306, 0x65, 0x306, 0x48, 0x335, 0x68,
+    0x335, 0x69, 0x6c, 0x4a, 0x69, 0x6a, 0x6c, 0xb7, 0x6c, 0xb7,
+    0x4c, 0x338, 0x6c, 0x338, 0x272, 0x27, 0x6e, 0x4f, 0x308, 0x4f,
+    0x45, 0x6f, 0x65, 0x1ab, 0x54, 0x335, 0x74, 0x335, 0x66, 0x62,
+    0x335, 0x27, 0x42, 0x62, 0x304, 0x62, 0x304, 0x62, 0x43, 0x27,
+    0x44, 0x335, 0x27, 0x44, 0x64, 0x304, 0x67, 0x46, 0x326, 0x66,
+    0x326, 0x47, 0x27, 0x6c, 0x6c, 0x335, 0x4b, 0x27, 0x6b, 0x314,
+    0x6c, 0x335, 0x4e, 0x326, 0x6e, 0x329, 0x4f, 0x335, 0x4f, 0x27,
+    0x6f, 0x27, 0x27, 0x50, 0x70, 0x314, 0x52, 0x32, 0x27, 0x54,
+    0x74, 0x314, 0x54, 0x328, 0x27, 0x59, 0x79, 0x314, 0x5a, 0x335,
+    0x7a, 0x335, 0x33, 0x32, 0x335, 0x35, 0x73, 0xfe, 0x6c, 0x6c,
+    0x6c, 0x21, 0x44, 0x5a, 0x30c, 0x44, 0x7a, 0x30c, 0x64, 0x7a,
+    0x30c, 0x4c, 0x4a, 0x4c, 0x6a, 0x6c, 0x6a, 0x4e, 0x4a, 0x4e,
+    0x6a, 0x6e, 0x6a, 0x41, 0x306, 0x61, 0x306, 0x49, 0x306, 0x69,
+    0x306, 0x4f, 0x306, 0x6f, 0x306, 0x55, 0x306, 0x75, 0x306, 0x47,
+    0x335, 0x67, 0x335, 0x47, 0x306, 0x67, 0x306, 0x44, 0x5a, 0x44,
+    0x7a, 0x64, 0x7a, 0x67, 0x327, 0x4f, 0x338, 0x301, 0x54, 0x327,
+    0x1ab, 0x33, 0x38, 0x38, 0x5a, 0x326, 0x7a, 0x326, 0x41, 0x30a,
+    0x61, 0x30a, 0x63, 0x338, 0x54, 0x338, 0x3f, 0x55, 0x335, 0x45,
+    0x338, 0x65, 0x338, 0x4a, 0x335, 0x6a, 0x335, 0x72, 0x335, 0x59,
+    0x335, 0x79, 0x335, 0x61, 0x62, 0x314, 0x64, 0x328, 0x64, 0x314,
+    0x1dd, 0x1dd, 0x2de, 0xa793, 0x67, 0x314, 0x67, 0x79, 0x68, 0x314,
+    0x69, 0x335, 0x69, 0x69, 0x6c, 0x334, 0x6c, 0x328, 0x6c, 0x21d,
+    0x77, 0x72, 0x6e, 0x326, 0x6e, 0x328, 0x6f, 0x335, 0x6f, 0x1d07,
+    0x72, 0x329, 0x72, 0x328, 0x73, 0x328, 0x75, 0x79, 0x7a, 0x328,
+    0x21d, 0x3f, 0x71, 0x314, 0x64, 0x7a, 0x64, 0x21d, 0x64, 0x291,
+    0x74, 0x73, 0x74, 0x283, 0x74, 0x255, 0x66, 0x14b, 0x6c, 0x73,
+    0x6c, 0x7a, 0x18f4, 0x27, 0x27, 0x27, 0x27, 0x27, 0x27, 0x27,
+    0x559, 0x3c, 0x3e, 0x5e, 0x5e, 0x27, 0x27, 0x27, 0x3a, 0x559,
+    0x2d, 0x2c7, 0x971, 0xb0, 0x69, 0x7e, 0x27, 0x27, 0x18f3, 0x18f5,
+    0x2c1, 0x27, 0x27, 0x27, 0x27, 0x27, 0x3a, 0x2ea, 0x304, 0x306,
+    0x670, 0x306, 0x307, 0x302, 0x313, 0x650, 0x331, 0x326, 0x328, 0x326,
+    0x335, 0x338, 0x326, 0x300, 0x301, 0x303, 0x313, 0x328, 0x333, 0x350,
+    0x307, 0x30a, 0x306, 0x2c75, 0x27, 0x2cf, 0x418, 0x1d0e, 0x69, 0x254,
+    0xa73f, 0x3b, 0x4a, 0x27, 0xb7, 0x41, 0x42, 0x45, 0x5a, 0x48,
+    0x4f, 0x335, 0x6c, 0x4b, 0x245, 0x4d, 0x4e, 0x4f, 0x50, 0x1a9,
+    0x54, 0x59, 0x58, 0x61, 0xdf, 0x79, 0x1e9f, 0xa793, 0x6e, 0x329,
+    0x4f, 0x335, 0x69, 0x138, 0x76, 0x6f, 0x70, 0x6f, 0x1d1b, 0x75,
+    0x278, 0xdf, 0x4f, 0x335, 0x59, 0x278, 0x3c0, 0x3c2, 0x46, 0x32,
+    0x1a8, 0x138, 0x70, 0x63, 0x6a, 0x4f, 0x335, 0xa793, 0xde, 0xfe,
+    0x43, 0x4d, 0x186, 0xa73e, 0xa792, 0x53, 0x6c, 0x4a, 0x41, 0x62,
+    0x304, 0x42, 0x393, 0x45, 0x33, 0x418, 0x300, 0x4b, 0x245, 0x4d,
+    0x48, 0x4f, 0x3a0, 0x50, 0x43, 0x54, 0x59, 0x3a6, 0x58, 0x62,
+    0x6c, 0x62, 0x6c, 0x4f, 0x61, 0x36, 0x299, 0x72, 0x65, 0x25c,
+    0x1d0e, 0x138, 0x28d, 0x29c, 0x6f, 0x3c0, 0x70, 0x63, 0x1d1b, 0x79,
+    0x278, 0x78, 0x2c9, 0x62, 0x185, 0x69, 0x185, 0x1d19, 0xa793, 0x73,
+    0x69, 0x6a, 0x68, 0x335, 0x438, 0x306, 0x77, 0x62, 0x335, 0x62,
+    0x335, 0x3a8, 0x3c8, 0x4f, 0x335, 0x6f, 0x335, 0x56, 0x76, 0x460,
+    0x486, 0x487, 0x77, 0x486, 0x487, 0x418, 0x326, 0x300, 0x438, 0x326,
+    0x306, 0x62, 0x335, 0x62, 0x335, 0x393, 0x27, 0x72, 0x27, 0x393,
+    0x335, 0x72, 0x335, 0x416, 0x329, 0x436, 0x329, 0x33, 0x326, 0x25c,
+    0x326, 0x4b, 0x329, 0x138, 0x329, 0x4b, 0x335, 0x138, 0x335, 0x48,
+    0x329, 0x29c, 0x329, 0x43, 0x326, 0x63, 0x326, 0x54, 0x329, 0x1d1b,
+    0x329, 0x59, 0x79, 0x59, 0x335, 0x79, 0x335, 0x58, 0x329, 0x68,
+    0x65, 0x4bc, 0x328, 0x65, 0x328, 0x6c, 0x245, 0x326, 0x43b, 0x326,
+    0x48, 0x326, 0x29c, 0x326, 0x48, 0x326, 0x29c, 0x326, 0x4b6, 0x4b7,
+    0x4d, 0x326, 0x28d, 0x326, 0x69, 0x41, 0x45, 0x61, 0x65, 0x18f,
+    0x1dd, 0x33, 0x21d, 0x4f, 0x335, 0x6f, 0x335, 0x64, 0x1f6, 0x47,
+    0x262, 0x190, 0xa793, 0x71, 0x57, 0x77, 0x12ae, 0x1206, 0x1323, 0x1261,
+    0x55, 0x53, 0x3a6, 0x4f, 0x27, 0x27, 0x77, 0x71, 0x71, 0x1e9f,
+    0x68, 0x237, 0x6e, 0x270, 0x6e, 0x75, 0x67, 0x66, 0x6f, 0x565,
+    0x582, 0x3a, 0x301, 0x301, 0x59a, 0x599, 0x596, 0x598, 0x30a, 0x323,
+    0x307, 0x307, 0x6c, 0x307, 0x307, 0x3a, 0x307, 0x323, 0x6c, 0x76,
+    0x27, 0x6c, 0x6f, 0x6c, 0x6c, 0x6c, 0x27, 0x27, 0x27, 0x27,
+    0x27, 0x27, 0xba, 0x2f, 0x2080, 0x2080, 0xba, 0x2f, 0x2080, 0x2080,
+    0x2080, 0x2c, 0x639, 0x301, 0x313, 0x650, 0x6c, 0x674, 0x648, 0x674,
+    0x6c, 0x655, 0x649, 0x674, 0x6c, 0x649, 0x6db, 0x633, 0x6db, 0x649,
+    0x302, 0x649, 0x6db, 0x6f, 0x649, 0x30b, 0x301, 0x313, 0x30a, 0x303,
+    0x329, 0x312, 0x306, 0x304, 0x306, 0x302, 0x323, 0x314, 0x655, 0x2e,
+    0x6c, 0x6f, 0x56, 0x245, 0xba, 0x2f, 0x2080, 0x2c, 0x60c, 0x2a,
+    0x649, 0x6a1, 0x6c, 0x674, 0x6c, 0x655, 0x6c, 0x674, 0x648, 0x674,
+    0x648, 0x313, 0x674, 0x649, 0x674, 0x649, 0x615, 0x649, 0x6db, 0x62d,
+    0x654, 0x62d, 0x6db, 0x62f, 0x615, 0x68a, 0x615, 0x62f, 0x6db, 0x631,
+    0x615, 0x631, 0x306, 0x631, 0x6db, 0x635, 0x6db, 0x637, 0x6db, 0x6a1,
+    0x6db, 0x641, 0x6a1, 0x6db, 0x643, 0x643, 0x643, 0x6db, 0x6af, 0x6db,
+    0x644, 0x306, 0x644, 0x6db, 0x649, 0x649, 0x615, 0x649, 0x6db, 0x6f,
+    0x6f, 0x6d5, 0x654, 0x629, 0x648, 0x306, 0x648, 0x313, 0x648, 0x670,
+    0x648, 0x302, 0x648, 0x6db, 0x649, 0x649, 0x306, 0x67b, 0x649, 0x6db,
+    0x649, 0x2d, 0x6f, 0x30a, 0x306, 0x307, 0x307, 0x62f, 0x302, 0x631,
+    0x302, 0x2e, 0x6c, 0x662, 0x663, 0x664, 0x6f, 0x666, 0x56, 0x245,
+    0x669, 0x621, 0x348, 0x645, 0x348, 0x6f, 0x302, 0x2e, 0x2e, 0x3a,
+    0x3a, 0x307, 0x307, 0x73c, 0x301, 0x628, 0x6db, 0x649, 0x306, 0x6ac,
+    0x643, 0x6db, 0x754, 0x646, 0x615, 0x646, 0x306, 0x631, 0x654, 0x697,
+    0x615, 0x62d, 0x654, 0x633, 0x302, 0x4f, 0x6c, 0x304, 0x307, 0x302,
+    0x308, 0x27, 0x27, 0x5f, 0x628, 0x654, 0x6a2, 0x6db, 0x645, 0x6db,
+    0x649, 0x654, 0x754, 0x62f, 0x324, 0x323, 0x635, 0x324, 0x323, 0x6af,
+    0x648, 0x632, 0x302, 0x628, 0x6e2, 0x649, 0x6db, 0x6e2, 0x631, 0x306,
+    0x307, 0x649, 0x306, 0x307, 0x6a1, 0x6a1, 0x649, 0x64c, 0x64c, 0x307,
+    0x308, 0x323, 0x324, 0x30b, 0x64c, 0x64d, 0x313, 0x350, 0x354, 0x355,
+    0x350, 0x352, 0x306, 0x307, 0x307, 0x3a, 0x905, 0x946, 0x905, 0x93e,
+    0x930, 0x94d, 0x907, 0x90f, 0x945, 0x90f, 0x946, 0x90f, 0x947, 0x905,
+    0x949, 0x905, 0x93e, 0x946, 0x905, 0x93e, 0x947, 0x905, 0x93e, 0x948,
+    0x323, 0x331, 0x300, 0x301, 0x964, 0x964, 0x6f, 0x669, 0x3f, 0x306,
+    0x307, 0x985, 0x9be, 0x323, 0x98b, 0x9c3, 0x98b, 0x9c3, 0x4f, 0x38,
+    0x39, 0x307, 0x983, 0xa05, 0xa3e, 0xa72, 0xa3f, 0xa72, 0xa40, 0xa73,
+    0xa41, 0xa73, 0xa42, 0xa72, 0xa47, 0xa05, 0xa48, 0xa05, 0xa4c, 0x323,
+    0x946, 0x94d, 0x6f, 0x39, 0x38, 0x306, 0x307, 0x307, 0x3a, 0xa85,
+    0xabe, 0xa85, 0xac5, 0xa85, 0xac7, 0xa85, 0xac8, 0xa85, 0xabe, 0xac5,
+    0xa85, 0xabe, 0xac7, 0xa85, 0xabe, 0xac8, 0x323, 0x93d, 0x941, 0x942,
+    0x94d, 0x6f, 0x968, 0x969, 0x96a, 0x96e, 0x970, 0x306, 0x307, 0x38,
+    0xb05, 0xb3e, 0x4f, 0x323, 0x4f, 0x39, 0x30a, 0xb89, 0xbb3, 0xb90,
+    0xb88, 0xb88, 0xba9, 0xbc6, 0xb88, 0xbc7, 0xb88, 0xbc6, 0xbb3, 0x307,
+    0xbb3, 0x6f, 0xb95, 0xb89, 0xb9a, 0xb88, 0xbc1, 0xb9a, 0xbc1, 0xb8e,
+    0xb85, 0xbaf, 0xb9a, 0xbc2, 0xbae, 0xbc0, 0xbf3, 0xb8e, 0xbb5, 0xbb7,
+    0xba8, 0xbc0, 0x306, 0x307, 0x6f, 0x983, 0xc12, 0xc55, 0xc12, 0xc4c,
+    0xc30, 0x5bc, 0xc21, 0x323, 0xc27, 0x5bc, 0xc2c, 0x323, 0xc35, 0xc41,
+    0xc35, 0x323, 0xc35, 0xc3e, 0xc41, 0xc3e, 0xc43, 0xc3e, 0xc0b, 0xc3e,
+    0xc0c, 0xc3e, 0x6f, 0x306, 0x307, 0x6f, 0x983, 0xc05, 0xc06, 0xc07,
+    0xc12, 0xc12, 0xc55, 0xc12, 0xc4c, 0xc1c, 0xc1e, 0xc23, 0xc2f, 0xc31,
+    0xc32, 0xc8c, 0xcbe, 0x6f, 0xc67, 0xc68, 0xc6f, 0x306, 0x307, 0x6f,
+    0x983, 0xd07, 0xd57, 0xb89, 0xb89, 0xd57, 0xd28, 0xd41, 0xd0e, 0xd46,
+    0xd12, 0xd3e, 0xd12, 0xd57, 0xd28, 0xd41, 0xb90, 0x6f, 0xba3, 0xd30,
+    0xbb4, 0xbb6, 0xb9f, 0xbbf, 0xbbf, 0xbbf, 0xd41, 0xd41, 0xd46, 0xd46,
+    0x971, 0xd28, 0xd4d, 0xd2e, 0x6f, 0xd30, 0x6f, 0xd1e, 0x6f, 0xd30,
+    0xd4d, 0xd26, 0xd4d, 0xd30, 0xd28, 0xd4d, 0xd28, 0x39, 0xd35, 0xd4d,
+    0xd30, 0xd28, 0xd4d, 0xd39, 0xd4d, 0xd2e, 0xd28, 0xd41, 0xd28, 0xd4d,
+    0xd30, 0xd4d, 0x6f, 0x983, 0xde8, 0xdcf, 0xda2, 0xdaf, 0xde8, 0xdd3,
+    0xe02, 0xe0a, 0xe0e, 0xe04, 0xe04, 0xe11, 0xe06, 0xe20, 0x30a, 0xe32,
+    0xe40, 0xe40, 0xe32, 0x30a, 0x6f, 0xe08, 0xe22, 0xe1a, 0xe1b, 0xe1d,
+    0xe1e, 0xe1f, 0x30a, 0xeb2, 0xe38, 0xe39, 0xe48, 0xe49, 0xe4a, 0xe4b,
+    0x30a, 0x6f, 0xeab, 0xe99, 0xeab, 0xea1, 0xf68, 0xf7c, 0xf7e, 0xf60,
+    0xf74, 0xf82, 0xf7f, 0xf60, 0xf74, 0xf82, 0xf14, 0xf0b, 0xf0d, 0xf0d,
+    0xf1a, 0xf1a, 0xf1d, 0xf1d, 0xf1a, 0xf1d, 0x325, 0xf62, 0xfb2, 0xf71,
+    0xf80, 0xfb3, 0xf71, 0xf80, 0xf1d, 0xf1a, 0x5350, 0x534d, 0x1002, 0x102c,
+    0x6f, 0x102c, 0x6f, 0x1015, 0x102c, 0x101e, 0x103c, 0x101e, 0x103c, 0x1031,
+    0x102c, 0x103a, 0x30a, 0x983, 0x6f, 0x104a, 0x104a, 0x1041, 0x1015, 0x103e,
+    0x1015, 0x102c, 0x103e, 0x1003, 0x103e, 0x107d, 0x103e, 0x1002, 0x103e, 0x1083,
+    0x30a, 0xa786, 0x79, 0x21d, 0x6f, 0x1100, 0x1100, 0x1103, 0x1103, 0x1107,
+    0x1107, 0x1109, 0x1109, 0x110c, 0x110c, 0x1102, 0x1100, 0x1102, 0x1102, 0x1102,
+    0x1103, 0x1102, 0x1107, 0x1103, 0x1100, 0x1105, 0x1102, 0x1105, 0x1105, 0x1105,
+    0x1112, 0x1105, 0x110b, 0x1106, 0x1107, 0x1106, 0x110b, 0x1107, 0x1100, 0x1107,
+    0x1102, 0x1107, 0x1103, 0x1107, 0x1109, 0x1107, 0x1109, 0x1100, 0x1107, 0x1109,
+    0x1103, 0x1107, 0x1109, 0x1107, 0x1107, 0x1109, 0x1109, 0x1107, 0x1109, 0x110c,
+    0x1107, 0x110c, 0x1107, 0x110e, 0x1107, 0x1110, 0x1107, 0x1111, 0x1107, 0x110b,
+    0x1107, 0x1107, 0x110b, 0x1109, 0x1100, 0x1109, 0x1102, 0x1109, 0x1103, 0x1109,
+    0x1105, 0x1109, 0x1106, 0x1109, 0x1107, 0x1109, 0x1107, 0x1100, 0x1109, 0x1109,
+    0x1109, 0x1109, 0x110b, 0x1109, 0x110c, 0x1109, 0x110e, 0x1109, 0x110f, 0x1109,
+    0x1110, 0x1109, 0x1111, 0x1105, 0x1112, 0x113c, 0x113c, 0x113e, 0x113e, 0x110b,
+    0x1100, 0x110b, 0x1103, 0x110b, 0x1106, 0x110b, 0x1107, 0x110b, 0x1109, 0x110b,
+    0x1140, 0x110b, 0x110b, 0x110b, 0x110c, 0x110b, 0x110e, 0x110b, 0x1110, 0x110b,
+    0x1111, 0x110c, 0x110b, 0x114e, 0x114e, 0x1150, 0x1150, 0x110e, 0x110f, 0x110e,
+    0x1112, 0x1111, 0x1107, 0x1111, 0x110b, 0x1112, 0x1112, 0x1100, 0x1103, 0x1102,
+    0x1109, 0x1102, 0x110c, 0x1102, 0x1112, 0x1103, 0x1105, 0x1161, 0x4e28, 0x1163,
+    0x4e28, 0x1165, 0x4e28, 0x1167, 0x4e28, 0x1169, 0x1161, 0x1169, 0x1161, 0x4e28,
+    0x1169, 0x4e28, 0x116e, 0x1165, 0x116e, 0x1165, 0x4e28, 0x116e, 0x4e28, 0x30fc,
+    0x30fc, 0x4e28, 0x4e28, 0x1161, 0x1169, 0x1161, 0x116e, 0x1163, 0x1169, 0x1163,
+    0x116d, 0x1165, 0x1169, 0x1165, 0x116e, 0x1165, 0x30fc, 0x1167, 0x1169, 0x1167,
+    0x116e, 0x1169, 0x1165, 0x1169, 0x1165, 0x4e28, 0x1169, 0x1167, 0x4e28, 0x1169,
+    0x1169, 0x1169, 0x116e, 0x116d, 0x1163, 0x116d, 0x1163, 0x4e28, 0x116d, 0x1163,
+    0x116d, 0x1169, 0x116d, 0x4e28, 0x116e, 0x1161, 0x116e, 0x1161, 0x4e28, 0x116e,
+    0x1165, 0x30fc, 0x116e, 0x1167, 0x4e28, 0x116e, 0x116e, 0x1172, 0x1161, 0x1172,
+    0x1165, 0x1172, 0x1165, 0x4e28, 0x1172, 0x1167, 0x1172, 0x1167, 0x4e28, 0x1172,
+    0x116e, 0x1172, 0x4e28, 0x30fc, 0x116e, 0x30fc, 0x30fc, 0x30fc, 0x4e28, 0x116e,
+    0x4e28, 0x1161, 0x4e28, 0x1163, 0x4e28, 0x1169, 0x4e28, 0x116e, 0x4e28, 0x30fc,
+    0x4e28, 0x119e, 0x119e, 0x1165, 0x119e, 0x116e, 0x119e, 0x4e28, 0x119e, 0x119e,
+    0x1161, 0x30fc, 0x1163, 0x116e, 0x1167, 0x1163, 0x1169, 0x1163, 0x1169, 0x1163,
+    0x4e28, 0x1100, 0x1100, 0x1100, 0x1100, 0x1109, 0x1102, 0x1102, 0x110c, 0x1102,
+    0x1112, 0x1103, 0x1105, 0x1105, 0x1100, 0x1105, 0x1106, 0x1105, 0x1107, 0x1105,
+    0x1109, 0x1105, 0x1110, 0x1105, 0x1111, 0x1105, 0x1112, 0x1106, 0x1107, 0x1107,
+    0x1109, 0x1109, 0x1109, 0x1109, 0x110b, 0x110c, 0x110e, 0x110f, 0x1110, 0x1111,
+    0x1112, 0x1100, 0x1105, 0x1100, 0x1109, 0x1100, 0x1102, 0x1100, 0x1102, 0x1103,
+    0x1102, 0x1109, 0x1102, 0x1140, 0x1102, 0x1110, 0x1103, 0x1100, 0x1103, 0x1105,
+    0x1105, 0x1100, 0x1109, 0x1105, 0x1102, 0x1105, 0x1103, 0x1105, 0x1103, 0x1112,
+    0x1105, 0x1105, 0x1105, 0x1106, 0x1100, 0x1105, 0x1106, 0x1109, 0x1105, 0x1107,
+    0x1109, 0x1105, 0x1107, 0x1112, 0x1105, 0x1107, 0x110b, 0x1105, 0x1109, 0x1109,
+    0x1105, 0x1140, 0x1105, 0x110f, 0x1105, 0x1159, 0x1106, 0x1100, 0x1106, 0x1105,
+    0x1106, 0x1107, 0x1106, 0x1109, 0x1106, 0x1109, 0x1109, 0x1106, 0x1140, 0x1106,
+    0x110e, 0x1106, 0x1112, 0x1106, 0x110b, 0x1107, 0x1105, 0x1107, 0x1111, 0x1107,
+    0x1112, 0x1107, 0x110b, 0x1109, 0x1100, 0x1109, 0x1103, 0x1109, 0x1105, 0x1109,
+    0x1107, 0x1140, 0x110b, 0x1100, 0x110b, 0x1100, 0x1100, 0x110b, 0x110b, 0x110b,
+    0x110f, 0x114c, 0x110b, 0x1109, 0x110b, 0x1140, 0x1111, 0x1107, 0x1111, 0x110b,
+    0x1112, 0x1102, 0x1112, 0x1105, 0x1112, 0x1106, 0x1112, 0x1107, 0x1159, 0x1100,
+    0x1102, 0x1100, 0x1107, 0x1100, 0x110e, 0x1100, 0x110f, 0x1100, 0x1112, 0x1102,
+    0x1102, 0x55, 0x270, 0x3a6, 0x548, 0x571, 0x4f, 0x44, 0x52, 0x54,
+    0x4f, 0x27, 0x69, 0x2c75, 0x59, 0x41, 0x4a, 0x45, 0x3f, 0x2c75,
+    0x393, 0x57, 0x4d, 0x48, 0x59, 0x4f, 0x335, 0x1ab, 0x47, 0x68,
+    0x5a, 0x460, 0x190, 0x55, 0x335, 0x34, 0x62, 0x52, 0x57, 0x53,
+    0x56, 0x53, 0x4c, 0x43, 0x50, 0x4b, 0x64, 0x4f, 0x335, 0x36,
+    0xdf, 0x68, 0x314, 0x47, 0x42, 0x262, 0x299, 0x3d, 0x394, 0xb7,
+    0x1401, 0x1401, 0xb7, 0xb7, 0x394, 0x394, 0xb7, 0xb7, 0x1404, 0x1404,
+    0xb7, 0xb7, 0x1405, 0x1405, 0xb7, 0xb7, 0x1406, 0x1406, 0xb7, 0xb7,
+    0x140a, 0x140a, 0xb7, 0xb7, 0x140b, 0x140b, 0xb7, 0xb7, 0x1401, 0x1420,
+    0x394, 0x1420, 0x1405, 0x1420, 0x140a, 0x1420, 0x56, 0x245, 0x3e, 0xb7,
+    0x3e, 0x3c, 0xb7, 0x56, 0x56, 0xb7, 0xb7, 0x245, 0x245, 0xb7,
+    0xb7, 0x1432, 0x1432, 0xb7, 0xb7, 0x3e, 0x3e, 0xb7, 0xb7, 0x1434,
+    0x1434, 0xb7, 0xb7, 0x3c, 0x3c, 0xb7, 0xb7, 0x1439, 0x1439, 0xb7,
+    0x27, 0x55, 0x548, 0xb7, 0x1450, 0xb7, 0x55, 0x55, 0xb7, 0xb7,
+    0x548, 0x548, 0xb7, 0xb7, 0x144f, 0x144f, 0xb7, 0xb7, 0x1450, 0x1450,
+    0xb7, 0xb7, 0x1451, 0x1451, 0xb7, 0xb7, 0x1455, 0x1455, 0xb7, 0xb7,
+    0x1456, 0x1456, 0xb7, 0x55, 0x27, 0x548, 0x27, 0x1450, 0x27, 0x1455,
+    0x27, 0x50, 0x64, 0x62, 0x62, 0x307, 0xb7, 0x146b, 0x146b, 0xb7,
+    0xb7, 0x50, 0x70, 0xb7, 0xb7, 0x146e, 0x146e, 0xb7, 0xb7, 0x64,
+    0x64, 0xb7, 0xb7, 0x1470, 0x1470, 0xb7, 0xb7, 0x62, 0x62, 0xb7,
+    0xb7, 0x62, 0x307, 0x62, 0x307, 0xb7, 0x146b, 0x27, 0x50, 0x27,
+    0x64, 0x27, 0x62, 0x27, 0x4a, 0xb7, 0x1489, 0x1489, 0xb7, 0xb7,
+    0x148b, 0x148b, 0xb7, 0xb7, 0x148c, 0x148c, 0xb7, 0xb7, 0x4a, 0x4a,
+    0xb7, 0xb7, 0x148e, 0x148e, 0xb7, 0xb7, 0x1490, 0x1490, 0xb7, 0xb7,
+    0x1491, 0x1491, 0xb7, 0x393, 0x4c, 0xb7, 0x14a3, 0x14a3, 0xb7, 0xb7,
+    0x393, 0x393, 0xb7, 0xb7, 0x14a6, 0x14a6, 0xb7, 0xb7, 0x14a7, 0x14a7,
+    0xb7, 0xb7, 0x14a8, 0x14a8, 0xb7, 0xb7, 0x4c, 0x6c, 0xb7, 0xb7,
+    0x14ab, 0x14ab, 0xb7, 0x32, 0xb7, 0x14c0, 0x14c0, 0xb7, 0xb7, 0x14c7,
+    0x14c7, 0xb7, 0xb7, 0x14c8, 0x14c8, 0xb7, 0x1421, 0xb7, 0x14d3, 0x14d3,
+    0xb7, 0xb7, 0x14d5, 0x14d5, 0xb7, 0xb7, 0x14d6, 0x14d6, 0xb7, 0xb7,
+    0x14d7, 0x14d7, 0xb7, 0xb7, 0x14d8, 0x14d8, 0xb7, 0xb7, 0x14da, 0x14da,
+    0xb7, 0xb7, 0x14db, 0x14db, 0xb7, 0xb7, 0x14ed, 0x14ed, 0xb7, 0xb7,
+    0x14ef, 0x14ef, 0xb7, 0xb7, 0x14f0, 0x14f0, 0xb7, 0xb7, 0x14f1, 0x14f1,
+    0xb7, 0xb7, 0x14f2, 0x14f2, 0xb7, 0xb7, 0x14f4, 0x14f4, 0xb7, 0xb7,
+    0x14f5, 0x14f5, 0xb7, 0x150b, 0x3c, 0x150b, 0x1455, 0x150b, 0x62, 0x150b,
+    0x1490, 0xb7, 0x1510, 0x1510, 0xb7, 0xb7, 0x1511, 0x1511, 0xb7, 0xb7,
+    0x1512, 0x1512, 0xb7, 0xb7, 0x1513, 0x1513, 0xb7, 0xb7, 0x1514, 0x1514,
+    0xb7, 0xb7, 0x1515, 0x1515, 0xb7, 0xb7, 0x1516, 0x1516, 0xb7, 0xb7,
+    0x34, 0x34, 0xb7, 0xb7, 0x1528, 0x1528, 0xb7, 0xb7, 0x1529, 0x1529,
+    0xb7, 0xb7, 0x152a, 0x152a, 0xb7, 0xb7, 0x152b, 0x152b, 0xb7, 0xb7,
+    0x152d, 0x152d, 0xb7, 0xb7, 0x152e, 0x152e, 0xb7, 0x1429, 0x78, 0xb7,
+    0x154c, 0x154c, 0xb7, 0xb7, 0x155a, 0x155a, 0xb7, 0xb7, 0x1567, 0x1567,
+    0xb7, 0x1e9f, 0x48, 0x78, 0x1550, 0x146c, 0x1550, 0x50, 0x1550, 0x146e,
+    0x1550, 0x64, 0x1550, 0x1470, 0x1550, 0x62, 0x1550, 0x62, 0x307, 0x1550,
+    0x1483, 0x52, 0x1595, 0x148a, 0x1595, 0x148b, 0x1595, 0x148c, 0x1595, 0x4a,
+    0x1595, 0x148e, 0x1595, 0x1490, 0x1595, 0x1491, 0x62, 0x46, 0x2132, 0xa7fb,
+    0x2c6f, 0x41, 0x44, 0x44, 0x460, 0x4d, 0x42, 0x1490, 0x1489, 0x14d3,
+    0x14da, 0x1543, 0x1546, 0x154a, 0x1b1, 0x3a9, 0x1b1, 0x3a9, 0x58, 0x78,
+    0x1550, 0x146b, 0x1595, 0x1489, 0x1596, 0x148b, 0x1596, 0x148c, 0x1596, 0x4a,
+    0x1596, 0x148e, 0x1596, 0x1490, 0x1596, 0x1491, 0x15a7, 0xb7, 0x15a8, 0xb7,
+    0x15a9, 0xb7, 0x15aa, 0xb7, 0x15ab, 0xb7, 0x15ac, 0xb7, 0x15ad, 0xb7,
+    0x20, 0x3c, 0x58, 0x6c, 0x16bd, 0x27, 0x4b, 0x4d, 0x3a8, 0x16bc,
+    0xb7, 0x3a, 0x2b, 0x3a6, 0x2f, 0x17a2, 0xe34, 0xe35, 0xe36, 0xe37,
+    0x30a, 0xe48, 0x30a, 0xe2f, 0xe5a, 0xe4f, 0xe5b, 0x3a, 0x3a, 0x1835,
+    0x185c, 0xb7, 0x18b1, 0xb7, 0x18b4, 0xb7, 0x18b8, 0xb7, 0x18c0, 0xb7,
+    0x14c2, 0x14c2, 0xb7, 0xb7, 0x14c3, 0x14c3, 0xb7, 0xb7, 0x14c4, 0x14c4,
+    0xb7, 0xb7, 0x14c5, 0x14c5, 0xb7, 0xb7, 0x1543, 0xb7, 0x1546, 0xb7,
+    0x1547, 0xb7, 0x1548, 0xb7, 0x1549, 0xb7, 0x154b, 0x18f5, 0x18df, 0x141e,
+    0x141e, 0x18df, 0x1543, 0xb7, 0x155e, 0xb7, 0x1566, 0xb7, 0x156b, 0xb7,
+    0x1586, 0xb7, 0x1597, 0xb7, 0x460, 0xb7, 0x15f4, 0xb7, 0x161b, 0xb7,
+    0x199e, 0x19b1, 0x1a45, 0x1a45, 0x1aa8, 0x1aa8, 0x1aaa, 0x1aa8, 0x6db, 0x328,
+    0x1b0d, 0x1b11, 0x1b28, 0x1b50, 0x1b5e, 0x1b5e, 0x1c3b, 0x1c3b, 0x1c7e, 0x1c7e,
+    0x302, 0x304, 0x27, 0x27, 0x32b, 0x32e, 0x32d, 0x30e, 0x329, 0x323,
+    0x324, 0x316, 0x63, 0x25c, 0x138, 0x28d, 0x6f, 0x254, 0x6f, 0x1dd,
+    0x6f, 0x75, 0x76, 0x77, 0x7a, 0x1a8, 0x72, 0x28c, 0x3c0, 0x1d18,
+    0x43b, 0x18d6, 0xba, 0x75, 0x65, 0x66, 0x334, 0x72, 0x6e, 0x334,
+    0x6e, 0x334, 0x72, 0x334, 0x27e, 0x334, 0x73, 0x334, 0x74, 0x334,
+    0x7a, 0x334, 0x1d34, 0x69, 0x335, 0x69, 0x335, 0x70, 0x335, 0x75,
+    0x335, 0x28a, 0x335, 0x67, 0x79, 0x24b, 0x1d4b, 0x1d4d, 0x18d4, 0x1646,
+    0x2dec, 0xab51, 0x61, 0x309, 0x66, 0x79, 0x3c9, 0x301, 0x345, 0x27,
+    0x69, 0x27, 0x7e, 0x27, 0x13ef, 0x27, 0x27, 0x20, 0x20, 0x20,
+    0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x2d, 0x2d,
+    0x2d, 0x2d, 0x30fc, 0x30fc, 0x6c, 0x6c, 0x27, 0x27, 0x2c, 0x27,
+    0x27, 0x27, 0x27, 0x27, 0x27, 0x27, 0xb7, 0x2e, 0x2e, 0x2e,
+    0x2e, 0x2e, 0x2e, 0xb7, 0x20, 0x20, 0x20, 0xba, 0x2f, 0x2080,
+    0x2080, 0xba, 0x2f, 0x2080, 0x2080, 0x2080, 0x27, 0x27, 0x27, 0x27,
+    0x27, 0x27, 0x27, 0x27, 0x27, 0x27, 0x27, 0x27, 0x3c, 0x3e,
+    0x21, 0x21, 0x2c9, 0x2f, 0x2d, 0x2f, 0x3f, 0x3f, 0x3f, 0x21,
+    0x21, 0x3f, 0x2a, 0xba, 0x2f, 0x2080, 0x7e, 0x27, 0x27, 0x27,
+    0x27, 0x3a, 0x2d57, 0x2d42, 0x20, 0xba, 0xa770, 0x43, 0x20eb, 0xa3,
+    0x72, 0x6e, 0x338, 0x52, 0x73, 0x57, 0x335, 0x64, 0x335, 0x331,
+    0xa792, 0x4b, 0x335, 0x54, 0x20eb, 0x6c, 0x74, 0x554, 0x6db, 0x61,
+    0x2f, 0x63, 0x61, 0x2f, 0x73, 0x43, 0xb0, 0x43, 0x63, 0x2f,
+    0x6f, 0x63, 0x2f, 0x75, 0x190, 0x42d, 0xb0, 0x46, 0x67, 0x48,
+    0x48, 0x48, 0x68, 0x68, 0x335, 0x6c, 0x6c, 0x4c, 0x6c, 0x4e,
+    0x4e, 0x6f, 0x50, 0x51, 0x52, 0x52, 0x52, 0x54, 0x45, 0x4c,
+    0x5a, 0x3a9, 0x1b1, 0x5a, 0x27f, 0x4b, 0x42, 0x43, 0x65, 0x65,
+    0x45, 0x46, 0x4d, 0x6f, 0x5d0, 0x5d1, 0x5d2, 0x5d3, 0x69, 0x46,
+    0x41, 0x58, 0x3c0, 0x79, 0x393, 0x3a0, 0x1a9, 0xa4e8, 0xa4f6, 0x16f00,
+    0x44, 0x64, 0x65, 0x69, 0x6a, 0x6c, 0x6c, 0x6c, 0x6c, 0x6c,
+    0x6c, 0x6c, 0x56, 0x56, 0x56, 0x6c, 0x56, 0x6c, 0x6c, 0x56,
+    0x6c, 0x6c, 0x6c, 0x6c, 0x58, 0x58, 0x58, 0x6c, 0x58, 0x6c,
+    0x6c, 0x4c, 0x43, 0x44, 0x4d, 0x69, 0x69, 0x69, 0x69, 0x69,
+    0x69, 0x69, 0x76, 0x76, 0x76, 0x69, 0x76, 0x69, 0x69, 0x76,
+    0x69, 0x69, 0x69, 0x69, 0x78, 0x78, 0x78, 0x69, 0x78, 0x69,
+    0x69, 0x6c, 0x63, 0x64, 0x72, 0x6e, 0x186, 0x254, 0x16cf, 0x16e8,
+    0x21b2, 0x1f10e, 0x16da, 0x16d0, 0x2c6f, 0x18e, 0x394, 0x3a0, 0x1a9, 0x2d,
+    0x2b, 0x307, 0x2f, 0x5c, 0x2a, 0xb0, 0xb7, 0x6f, 0x6f, 0x6c,
+    0x6c, 0x6c, 0x76, 0x548, 0x55, 0x283, 0x283, 0x283, 0x283, 0x283,
+    0x283, 0x222e, 0x222e, 0x222e, 0x222e, 0x222e, 0x3a, 0x2d, 0x307, 0x7e,
+    0x3d, 0x307, 0x3d, 0x323, 0x307, 0x3d, 0x30a, 0x3d, 0x302, 0x3d,
+    0x306, 0x3d, 0x36b, 0x2261, 0x3c, 0x3c, 0x3e, 0x3e, 0x1455, 0x1450,
+    0x102a8, 0x4f, 0x335, 0x298, 0x4f, 0x335, 0x54, 0xa4d5, 0x2227, 0x76,
+    0x548, 0x55, 0x16dc, 0xb7, 0x16de, 0x3c, 0xb7, 0xb7, 0x3e, 0x3c,
+    0x3c, 0x3c, 0x3e, 0x3e, 0x3e, 0x2d57, 0xb7, 0xb7, 0xb7, 0xa793,
+    0x45, 0x2205, 0x2324, 0x276c, 0x276d, 0x303c, 0x394, 0x332, 0x16dc, 0x332,
+    0xb0, 0x332, 0x229b, 0x54, 0x308, 0x2207, 0x308, 0x22c6, 0x308, 0xb0,
+    0x308, 0x629, 0x7e, 0x308, 0x1435, 0x2207, 0x334, 0x4f, 0x335, 0x69,
+    0x70, 0x3c9, 0x61, 0x332, 0xa793, 0x332, 0x69, 0x332, 0x3c9, 0x332,
+    0x61, 0x16bd, 0x4e28, 0x4e28, 0x4e28, 0x4e28, 0x4e28, 0x4e28, 0x2355, 0x234e,
+    0x234b, 0x236d, 0x2081, 0x2080, 0x23fb, 0x6c, 0x263e, 0x5c, 0x5c, 0x2780,
+    0x2781, 0x2782, 0x2783, 0x2784, 0x2785, 0x2786, 0x2787, 0x2788, 0x2789, 0x28,
+    0x6c, 0x29, 0x28, 0x32, 0x29, 0x28, 0x33, 0x29, 0x28, 0x34,
+    0x29, 0x28, 0x35, 0x29, 0x28, 0x36, 0x29, 0x28, 0x37, 0x29,
+    0x28, 0x38, 0x29, 0x28, 0x39, 0x29, 0x28, 0x6c, 0x4f, 0x29,
+    0x28, 0x6c, 0x6c, 0x29, 0x28, 0x6c, 0x32, 0x29, 0x28, 0x6c,
+    0x33, 0x29, 0x28, 0x6c, 0x34, 0x29, 0x28, 0x6c, 0x35, 0x29,
+    0x28, 0x6c, 0x36, 0x29, 0x28, 0x6c, 0x37, 0x29, 0x28, 0x6c,
+    0x38, 0x29, 0x28, 0x6c, 0x39, 0x29, 0x28, 0x32, 0x4f, 0x29,
+    0x6c, 0x2e, 0x32, 0x2e, 0x33, 0x2e, 0x34, 0x2e, 0x35, 0x2e,
+    0x36, 0x2e, 0x37, 0x2e, 0x38, 0x2e, 0x39, 0x2e, 0x6c, 0x4f,
+    0x2e, 0x6c, 0x6c, 0x2e, 0x6c, 0x32, 0x2e, 0x6c, 0x33, 0x2e,
+    0x6c, 0x34, 0x2e, 0x6c, 0x35, 0x2e, 0x6c, 0x36, 0x2e, 0x6c,
+    0x37, 0x2e, 0x6c, 0x38, 0x2e, 0x6c, 0x39, 0x2e, 0x32, 0x4f,
+    0x2e, 0x28, 0x61, 0x29, 0x28, 0x62, 0x29, 0x28, 0x63, 0x29,
+    0x28, 0x64, 0x29, 0x28, 0x65, 0x29, 0x28, 0x66, 0x29, 0x28,
+    0x67, 0x29, 0x28, 0x68, 0x29, 0x28, 0x69, 0x29, 0x28, 0x6a,
+    0x29, 0x28, 0x6b, 0x29, 0x28, 0x6c, 0x29, 0x28, 0x72, 0x6e,
+    0x29, 0x28, 0x6e, 0x29, 0x28, 0x6f, 0x29, 0x28, 0x70, 0x29,
+    0x28, 0x71, 0x29, 0x28, 0x72, 0x29, 0x28, 0x73, 0x29, 0x28,
+    0x74, 0x29, 0x28, 0x75, 0x29, 0x28, 0x76, 0x29, 0x28, 0x77,
+    0x29, 0x28, 0x78, 0x29, 0x28, 0x79, 0x29, 0x28, 0x7a, 0x29,
+    0xa9, 0x2117, 0xae, 0x24be, 0x1f10d, 0x30fc, 0x30fc, 0x2502, 0x250c, 0x251c,
+    0x2f, 0x58, 0x220e, 0x258c, 0x2c9, 0x2596, 0x2598, 0x220e, 0x23e5, 0x394,
+    0x22b3, 0x25b6, 0x25b6, 0x102bc, 0x22b2, 0x16dc, 0x16dc, 0xb0, 0x233e, 0x2312,
+    0xb0, 0x298, 0x25a1, 0x1099e, 0x2cb6, 0x2388, 0x224f, 0x16dc, 0x1d158, 0x1d165,
+    0x1d158, 0x1d165, 0x1d16e, 0x970, 0x28, 0x29, 0x3c, 0x3e, 0x28, 0x29,
+    0x7b, 0x7d, 0x2b, 0x2d, 0xf7, 0xa4d5, 0x5c, 0x1455, 0x1450, 0x2f,
+    0x2f, 0x5c, 0x54, 0x276c, 0x276d, 0x78, 0x78, 0x16d0, 0x16da, 0x21c3,
+    0x21c2, 0x16d0, 0x21c2, 0x21c3, 0x16da, 0x2d42, 0x2349, 0x233e, 0x303c, 0x2342,
+    0x233b, 0x102c0, 0x299a, 0x3a, 0x2192, 0x5c, 0x2f, 0x304, 0x2f, 0x5c,
+    0x298, 0x102a8, 0x2297, 0x228d, 0x228e, 0x2293, 0x2294, 0x283, 0x283, 0x283,
+    0x283, 0x16de, 0x3e, 0x3e, 0x16da, 0x2b, 0x30a, 0x2b, 0x302, 0x2b,
+    0x303, 0x2b, 0x323, 0x2b, 0x330, 0x2b, 0x2082, 0x2d, 0x313, 0x2d,
+    0x323, 0x78, 0x78, 0x307, 0x2319, 0x2a1f, 0x2210, 0x7e, 0x307, 0x3d,
+    0x20f0, 0x3a, 0x3a, 0x3d, 0x3d, 0x3d, 0x3d, 0x3d, 0x3d, 0x3e,
+    0x3c, 0x15d5, 0x15d2, 0x1450, 0x1455, 0x2f, 0x2f, 0x2f, 0x2f, 0x2f,
+    0x219e, 0x219f, 0x21a0, 0x21a1, 0x48, 0x329, 0x4b, 0x329, 0x393, 0x72,
+    0x394, 0xa792, 0xa793, 0x48, 0x6c, 0x4b, 0x138, 0x3bb, 0x4d, 0x4e,
+    0x4f, 0x6f, 0x3a0, 0x50, 0x70, 0x43, 0x63, 0x54, 0x59, 0x3a6,
+    0x278, 0x58, 0x3c7, 0x3a8, 0x3c9, 0x3c, 0xb7, 0x2d, 0x428, 0x448,
+    0x2f, 0x39, 0x33, 0x21d, 0x4c, 0x29f, 0x36, 0x3ec, 0x3d7, 0x2627,
+    0x5c, 0x5c, 0x4f, 0x335, 0x245, 0x56, 0x45, 0x18e, 0x4f, 0x338,
+    0xb7, 0xb7, 0xb7, 0x1a9, 0x6c, 0x21, 0x4f, 0x51, 0x298, 0x58,
+    0x394, 0x16ef, 0x1ddf, 0x30a, 0x368, 0x36f, 0x363, 0x364, 0x2d, 0x308,
+    0x7e, 0x307, 0x7e, 0x323, 0x1455, 0x1450, 0x28, 0x28, 0x29, 0x29,
+    0x2235, 0x2234, 0x2237, 0x61f, 0xb0, 0xb7, 0x60c, 0x61b, 0x1e9f, 0x2d42,
+    0xb6, 0x3d, 0x4e5b, 0x4e5a, 0x4ebb, 0x5202, 0x353e, 0x5140, 0x5c23, 0x5c22,
+    0x5df3, 0x5e7a, 0x5f51, 0x5fc4, 0x38fa, 0x624c, 0x6535, 0x65e1, 0x6b7a, 0x6bcd,
+    0x6c11, 0x6c35, 0x6c3a, 0x706c, 0x722b, 0x4e2c, 0x72ad, 0x7f52, 0x793b, 0x7cf9,
+    0x7f53, 0x7f52, 0x8002, 0x8080, 0x8279, 0x8279, 0x8279, 0x864e, 0x8864, 0x8980,
+    0x897f, 0x89c1, 0x8ba0, 0x8d1d, 0x8f66, 0x8fb6, 0x8fb6, 0x961d, 0x9485, 0x9577,
+    0x9578, 0x957f, 0x95e8, 0x961d, 0x9752, 0x97e6, 0x9875, 0x98ce, 0x98de, 0x98df,
+    0x98e0, 0x9963, 0x9a6c, 0x9b3c, 0x9c7c, 0x9ea6, 0x9ec4, 0x6589, 0x9f50, 0x6b6f,
+    0x9f7f, 0x7adc, 0x9f99, 0x4e80, 0x9f9f, 0x30fc, 0x4e28, 0x5c, 0x2f, 0x4e59,
+    0x4e85, 0x4e8c, 0x4ea0, 0x4eba, 0x513f, 0x5165, 0x516b, 0x5182, 0x5196, 0x51ab,
+    0x51e0, 0x51f5, 0x5200, 0x529b, 0x52f9, 0x5315, 0x531a, 0x5338, 0x5341, 0x535c,
+    0x5369, 0x5382, 0x53b6, 0x53c8, 0x53e3, 0x53e3, 0x571f, 0x571f, 0x5902, 0x590a,
+    0x5915, 0x5927, 0x5973, 0x5b50, 0x5b80, 0x5bf8, 0x5c0f, 0x5c22, 0x5c38, 0x5c6e,
+    0x5c71, 0x5ddb, 0x5de5, 0x5df1, 0x5dfe, 0x5e72, 0x5e7a, 0x5e7f, 0x5ef4, 0x5efe,
+    0x5f0b, 0x5f13, 0x5f50, 0x5f61, 0x5f73, 0x5fc3, 0x6208, 0x6236, 0x624b, 0x652f,
+    0x6534, 0x6587, 0x6597, 0x65a4, 0x65b9, 0x65e0, 0x65e5, 0x66f0, 0x6708, 0x6728,
+    0x6b20, 0x6b62, 0x6b79, 0x6bb3, 0x6bcb, 0x6bd4, 0x6bdb, 0x6c0f, 0x6c14, 0x6c34,
+    0x706b, 0x722a, 0x7236, 0x723b, 0x723f, 0x7247, 0x7259, 0x725b, 0x72ac, 0x7384,
+    0x7389, 0x74dc, 0x74e6, 0x7518, 0x751f, 0x7528, 0x7530, 0x758b, 0x7592, 0x7676,
+    0x767d, 0x76ae, 0x76bf, 0x76ee, 0x77db, 0x77e2, 0x77f3, 0x793a, 0x79b8, 0x79be,
+    0x7a74, 0x7acb, 0x7af9, 0x7c73, 0x7cf8, 0x7f36, 0x7f51, 0x7f8a, 0x7fbd, 0x8001,
+    0x800c, 0x8012, 0x8033, 0x807f, 0x8089, 0x81e3, 0x81ea, 0x81f3, 0x81fc, 0x820c,
+    0x821b, 0x821f, 0x826e, 0x8272, 0x8278, 0x864d, 0x866b, 0x8840, 0x884c, 0x8863,
+    0x897e, 0x898b, 0x89d2, 0x8a00, 0x8c37, 0x8c46, 0x8c55, 0x8c78, 0x8c9d, 0x8d64,
+    0x8d70, 0x8db3, 0x8eab, 0x8eca, 0x8f9b, 0x8fb0, 0x8fb5, 0x9091, 0x9149, 0x91c6,
+    0x91cc, 0x91d1, 0x9577, 0x9580, 0x961c, 0x96b6, 0x96b9, 0x96e8, 0x9751, 0x975e,
+    0x9762, 0x9769, 0x97cb, 0x97ed, 0x97f3, 0x9801, 0x98a8, 0x98db, 0x98df, 0x9996,
+    0x9999, 0x99ac, 0x9aa8, 0x9ad8, 0x9adf, 0x9b25, 0x9b2f, 0x9b32, 0x9b3c, 0x9b5a,
+    0x9ce5, 0x9e75, 0x9e7f, 0x9ea5, 0x9ebb, 0x9ec3, 0x9ecd, 0x9ed1, 0x9ef9, 0x9efd,
+    0x9f0e, 0x9f13, 0x9f20, 0x9f3b, 0x9f4a, 0x9f52, 0x9f8d, 0x9f9c, 0x9fa0, 0x2f3,
+    0x27, 0x27, 0x4f, 0x276c, 0x276d, 0x20b8, 0x28, 0x29, 0x27e6, 0x27e7,
+    0x309, 0x325, 0x2f, 0x20b8, 0x5341, 0x5344, 0x5345, 0x276c, 0x30a, 0xff9e,
+    0xff9f, 0x3d, 0x4ebb, 0x5de5, 0x529b, 0x5915, 0x535c, 0x4e8c, 0x2f, 0x516b,
+    0x3078, 0x53e3, 0xb7, 0x1100, 0x1100, 0x1100, 0x1100, 0x1109, 0x1102, 0x1102,
+    0x110c, 0x1102, 0x1112, 0x1103, 0x1103, 0x1103, 0x1105, 0x1105, 0x1100, 0x1105,
+    0x1106, 0x1105, 0x1107, 0x1105, 0x1109, 0x1105, 0x1110, 0x1105, 0x1111, 0x1105,
+    0x1112, 0x1106, 0x1107, 0x1107, 0x1107, 0x1107, 0x1109, 0x1109, 0x1109, 0x1109,
+    0x110b, 0x110c, 0x110c, 0x110c, 0x110e, 0x110f, 0x1110, 0x1111, 0x1112, 0x1161,
+    0x1161, 0x4e28, 0x1163, 0x1163, 0x4e28, 0x1165, 0x1165, 0x4e28, 0x1167, 0x1167,
+    0x4e28, 0x1169, 0x1169, 0x1161, 0x1169, 0x1161, 0x4e28, 0x1169, 0x4e28, 0x116d,
+    0x116e, 0x116e, 0x1165, 0x116e, 0x1165, 0x4e28, 0x116e, 0x4e28, 0x1172, 0x30fc,
+    0x30fc, 0x4e28, 0x4e28, 0x1160, 0x1102, 0x1102, 0x1102, 0x1103, 0x1102, 0x1109,
+    0x1102, 0x1140, 0x1105, 0x1100, 0x1109, 0x1105, 0x1103, 0x1105, 0x1107, 0x1109,
+    0x1105, 0x1140, 0x1105, 0x1159, 0x1106, 0x1107, 0x1106, 0x1109, 0x1106, 0x1140,
+    0x1106, 0x110b, 0x1107, 0x1100, 0x1107, 0x1103, 0x1107, 0x1109, 0x1100, 0x1107,
+    0x1109, 0x1103, 0x1107, 0x110c, 0x1107, 0x1110, 0x1107, 0x110b, 0x1107, 0x1107,
+    0x110b, 0x1109, 0x1100, 0x1109, 0x1102, 0x1109, 0x1103, 0x1109, 0x1107, 0x1109,
+    0x110c, 0x1140, 0x110b, 0x110b, 0x114c, 0x110b, 0x1109, 0x110b, 0x1140, 0x1111,
+    0x110b, 0x1112, 0x1112, 0x1159, 0x116d, 0x1163, 0x116d, 0x1163, 0x4e28, 0x116d,
+    0x4e28, 0x1172, 0x1167, 0x1172, 0x1167, 0x4e28, 0x1172, 0x4e28, 0x119e, 0x119e,
+    0x4e28, 0x30fc, 0x4e28, 0x2f, 0x5c, 0x4e5b, 0x4e85, 0x276c, 0x4e5a, 0x4e59,
+    0x28, 0x1100, 0x29, 0x28, 0x1102, 0x29, 0x28, 0x1103, 0x29, 0x28,
+    0x1105, 0x29, 0x28, 0x1106, 0x29, 0x28, 0x1107, 0x29, 0x28, 0x1109,
+    0x29, 0x28, 0x110b, 0x29, 0x28, 0x110c, 0x29, 0x28, 0x110e, 0x29,
+    0x28, 0x110f, 0x29, 0x28, 0x1110, 0x29, 0x28, 0x1111, 0x29, 0x28,
+    0x1112, 0x29, 0x28, 0x1100, 0x1161, 0x29, 0x28, 0x1102, 0x1161, 0x29,
+    0x28, 0x1103, 0x1161, 0x29, 0x28, 0x1105, 0x1161, 0x29, 0x28, 0x1106,
+    0x1161, 0x29, 0x28, 0x1107, 0x1161, 0x29, 0x28, 0x1109, 0x1161, 0x29,
+    0x28, 0x110b, 0x1161, 0x29, 0x28, 0x110c, 0x1161, 0x29, 0x28, 0x110e,
+    0x1161, 0x29, 0x28, 0x110f, 0x1161, 0x29, 0x28, 0x1110, 0x1161, 0x29,
+    0x28, 0x1111, 0x1161, 0x29, 0x28, 0x1112, 0x1161, 0x29, 0x28, 0x110c,
+    0x116e, 0x29, 0x28, 0x110b, 0x1169, 0x110c, 0x1165, 0x11ab, 0x29, 0x28,
+    0x110b, 0x1169, 0x1112, 0x116e, 0x29, 0x28, 0x30fc, 0x29, 0x28, 0x4e8c,
+    0x29, 0x28, 0x4e09, 0x29, 0x28, 0x56db, 0x29, 0x28, 0x4e94, 0x29,
+    0x28, 0x516d, 0x29, 0x28, 0x4e03, 0x29, 0x28, 0x516b, 0x29, 0x28,
+    0x4e5d, 0x29, 0x28, 0x5341, 0x29, 0x28, 0x6708, 0x29, 0x28, 0x706b,
+    0x29, 0x28, 0x6c34, 0x29, 0x28, 0x6728, 0x29, 0x28, 0x91d1, 0x29,
+    0x28, 0x571f, 0x29, 0x28, 0x65e5, 0x29, 0x28, 0x682a, 0x29, 0x28,
+    0x6709, 0x29, 0x28, 0x793e, 0x29, 0x28, 0x540d, 0x29, 0x28, 0x7279,
+    0x29, 0x28, 0x8ca1, 0x29, 0x28, 0x795d, 0x29, 0x28, 0x52b4, 0x29,
+    0x28, 0x4ee3, 0x29, 0x28, 0x547c, 0x29, 0x28, 0x5b66, 0x29, 0x28,
+    0x76e3, 0x29, 0x28, 0x4f01, 0x29, 0x28, 0x8cc7, 0x29, 0x28, 0x5354,
+    0x29, 0x28, 0x796d, 0x29, 0x28, 0x4f11, 0x29, 0x28, 0x81ea, 0x29,
+    0x28, 0x81f3, 0x29, 0x6c, 0x6708, 0x32, 0x6708, 0x33, 0x6708, 0x34,
+    0x6708, 0x35, 0x6708, 0x36, 0x6708, 0x37, 0x6708, 0x38, 0x6708, 0x39,
+    0x6708, 0x6c, 0x4f, 0x6708, 0x6c, 0x6c, 0x6708, 0x6c, 0x32, 0x6708,
+    0x4f, 0x70b9, 0x6c, 0x70b9, 0x32, 0x70b9, 0x33, 0x70b9, 0x34, 0x70b9,
+    0x35, 0x70b9, 0x36, 0x70b9, 0x37, 0x70b9, 0x38, 0x70b9, 0x39, 0x70b9,
+    0x6c, 0x4f, 0x70b9, 0x6c, 0x6c, 0x70b9, 0x6c, 0x32, 0x70b9, 0x6c,
+    0x33, 0x70b9, 0x6c, 0x34, 0x70b9, 0x6c, 0x35, 0x70b9, 0x6c, 0x36,
+    0x70b9, 0x6c, 0x37, 0x70b9, 0x6c, 0x38, 0x70b9, 0x6c, 0x39, 0x70b9,
+    0x32, 0x4f, 0x70b9, 0x32, 0x6c, 0x70b9, 0x32, 0x32, 0x70b9, 0x32,
+    0x33, 0x70b9, 0x32, 0x34, 0x70b9, 0x6c, 0x65e5, 0x32, 0x65e5, 0x33,
+    0x65e5, 0x34, 0x65e5, 0x35, 0x65e5, 0x36, 0x65e5, 0x37, 0x65e5, 0x38,
+    0x65e5, 0x39, 0x65e5, 0x6c, 0x4f, 0x65e5, 0x6c, 0x6c, 0x65e5, 0x6c,
+    0x32, 0x65e5, 0x6c, 0x33, 0x65e5, 0x6c, 0x34, 0x65e5, 0x6c, 0x35,
+    0x65e5, 0x6c, 0x36, 0x65e5, 0x6c, 0x37, 0x65e5, 0x6c, 0x38, 0x65e5,
+    0x6c, 0x39, 0x65e5, 0x32, 0x4f, 0x65e5, 0x32, 0x6c, 0x65e5, 0x32,
+    0x32, 0x65e5, 0x32, 0x33, 0x65e5, 0x32, 0x34, 0x65e5, 0x32, 0x35,
+    0x65e5, 0x32, 0x36, 0x65e5, 0x32, 0x37, 0x65e5, 0x32, 0x38, 0x65e5,
+    0x32, 0x39, 0x65e5, 0x33, 0x4f, 0x65e5, 0x33, 0x6c, 0x65e5, 0x363d,
+    0x3588, 0x3b3b, 0x30fc, 0x5c, 0x2f, 0x4f75, 0x5024, 0x5553, 0x53e3, 0x5861,
+    0x571f, 0x58ab, 0x5aaf, 0x5e21, 0x3b3a, 0x6236, 0x3a41, 0x403f, 0x665a, 0x3ada,
+    0x4443, 0x676e, 0x3ba3, 0x699d, 0x6e88, 0x7814, 0x7d55, 0x670c, 0x6710, 0x670f,
+    0x3b35, 0x6713, 0x6718, 0x80fc, 0x6723, 0x848d, 0x8637, 0x46b6, 0x8a2e, 0x8b86,
+    0x8c5c, 0x8d7f, 0x8de5, 0x8e97, 0x8eff, 0x90ce, 0x93ad, 0x96b7, 0x9e42, 0x9ed1,
+    0x4039, 0xa2cd, 0xa0c0, 0xa04a, 0xa458, 0xa132, 0xa050, 0xa3c2, 0xa3bf, 0xa2b1,
+    0xa259, 0xa3ab, 0xa3b5, 0x42, 0x50, 0x64, 0x44, 0x54, 0x47, 0x4b,
+    0x4a, 0x43, 0x186, 0x5a, 0x46, 0x2132, 0x4d, 0x4e, 0x4c, 0x53,
+    0x52, 0x245, 0x56, 0x48, 0x57, 0x58, 0x59, 0x1660, 0x41, 0x2c6f,
+    0x45, 0x18e, 0x6c, 0x4f, 0x55, 0x548, 0x15e1, 0x2e, 0x2c, 0x2e,
+    0x2e, 0x2e, 0x2c, 0x3a, 0x2d, 0x2e, 0x3d, 0x2e, 0x32, 0x1a8,
+    0x69, 0x3c9, 0x42a, 0x6c, 0x2c9, 0x62, 0x69, 0x298, 0x20e9, 0x306,
+    0x2c7, 0x68, 0x314, 0x4f, 0x4f, 0x6f, 0x6f, 0x102a8, 0x418, 0x16b9,
+    0x2c75, 0x2a1, 0x245, 0x3a0, 0x56, 0x3f, 0x32, 0x302, 0x304, 0xa6f3,
+    0xa6f3, 0x2eb, 0x2ea, 0x54, 0x33, 0x74, 0x21d, 0x73, 0x41, 0x41,
+    0x61, 0x61, 0x41, 0x4f, 0x61, 0x6f, 0x41, 0x55, 0x61, 0x75,
+    0x41, 0x56, 0x61, 0x76, 0x41, 0x56, 0x61, 0x76, 0x41, 0x59,
+    0x61, 0x79, 0x4b, 0x335, 0x4f, 0x335, 0x6f, 0x335, 0x4f, 0x4f,
+    0x6f, 0x6f, 0x32, 0x77, 0x326, 0x33, 0x21d, 0x39, 0x74, 0x66,
+    0x26, 0xa779, 0x3a, 0x27, 0xb7, 0xa727, 0x46, 0x66, 0x10412, 0x1043a,
+    0x29a, 0xa4e4, 0x75, 0x33, 0xa4d5, 0x4a, 0x58, 0x42, 0xdf, 0xa64c,
+    0x3c9, 0x30fc, 0x964, 0x1103, 0x1106, 0x1103, 0x1107, 0x1103, 0x1109, 0x1103,
+    0x110c, 0x1105, 0x1100, 0x1105, 0x1100, 0x1100, 0x1105, 0x1103, 0x1105, 0x1103,
+    0x1103, 0x1105, 0x1106, 0x1105, 0x1107, 0x1105, 0x1107, 0x1107, 0x1105, 0x1107,
+    0x110b, 0x1105, 0x1109, 0x1105, 0x110c, 0x1105, 0x110f, 0x1106, 0x1100, 0x1106,
+    0x1103, 0x1106, 0x1109, 0x1107, 0x1109, 0x1110, 0x1107, 0x110f, 0x1107, 0x1112,
+    0x1109, 0x1109, 0x1107, 0x110b, 0x1105, 0x110b, 0x1112, 0x110c, 0x110c, 0x1112,
+    0x1110, 0x1110, 0x1111, 0x1112, 0x1112, 0x1109, 0x1159, 0x1159, 0x2c3f, 0xa99d,
+    0xa9d0, 0x662, 0xaa01, 0xaa23, 0x65, 0x66, 0x6f, 0x6f, 0x338, 0x254,
+    0x338, 0x1dd, 0x6f, 0x338, 0x1dd, 0x6f, 0x335, 0x72, 0x72, 0x283,
+    0x75, 0x75, 0x3c7, 0x3c7, 0x79, 0x459, 0x254, 0x65, 0x75, 0x6f,
+    0x1d05, 0x280, 0x1d1b, 0x6f, 0x31b, 0x69, 0x1d00, 0x1d0a, 0x1d07, 0x242,
+    0x2c76, 0x72, 0x77, 0x28d, 0x29c, 0x6f, 0x335, 0x262, 0x7a, 0xa793,
+    0x75, 0x335, 0x185, 0x280, 0x76, 0x73, 0x29f, 0x63, 0x1d18, 0x138,
+    0x6f, 0x335, 0x1169, 0x1167, 0x1169, 0x1169, 0x4e28, 0x116d, 0x1161, 0x116d,
+    0x1161, 0x4e28, 0x116d, 0x1165, 0x116e, 0x1167, 0x116e, 0x4e28, 0x4e28, 0x1172,
+    0x1161, 0x4e28, 0x1172, 0x1169, 0x30fc, 0x1161, 0x30fc, 0x1165, 0x30fc, 0x1165,
+    0x4e28, 0x30fc, 0x1169, 0x4e28, 0x1163, 0x1169, 0x4e28, 0x1163, 0x4e28, 0x4e28,
+    0x1167, 0x4e28, 0x1167, 0x4e28, 0x4e28, 0x1169, 0x4e28, 0x4e28, 0x116d, 0x4e28,
+    0x1172, 0x4e28, 0x4e28, 0x119e, 0x1161, 0x119e, 0x1165, 0x4e28, 0x1102, 0x1105,
+    0x1102, 0x110e, 0x1103, 0x1103, 0x1103, 0x1103, 0x1107, 0x1103, 0x1107, 0x1103,
+    0x1109, 0x1103, 0x1109, 0x1100, 0x1103, 0x110c, 0x1103, 0x110e, 0x1103, 0x1110,
+    0x1105, 0x1100, 0x1100, 0x1105, 0x1100, 0x1112, 0x1105, 0x1105, 0x110f, 0x1105,
+    0x1106, 0x1112, 0x1105, 0x1107, 0x1103, 0x1105, 0x1107, 0x1111, 0x1105, 0x114c,
+    0x1105, 0x1159, 0x1112, 0x1105, 0x110b, 0x1106, 0x1102, 0x1106, 0x1102, 0x1102,
+    0x1106, 0x1106, 0x1106, 0x1107, 0x1109, 0x1106, 0x110c, 0x1107, 0x1103, 0x1107,
+    0x1105, 0x1111, 0x1107, 0x1106, 0x1107, 0x1107, 0x1107, 0x1109, 0x1103, 0x1107,
+    0x110c, 0x1107, 0x110e, 0x1109, 0x1106, 0x1109, 0x1107, 0x110b, 0x1109, 0x1109,
+    0x1100, 0x1109, 0x1109, 0x1103, 0x1109, 0x1140, 0x1109, 0x110c, 0x1109, 0x110e,
+    0x1109, 0x1110, 0x1105, 0x1112, 0x1140, 0x1107, 0x1140, 0x1107, 0x110b, 0x114c,
+    0x1106, 0x114c, 0x1112, 0x110c, 0x1107, 0x110c, 0x1107, 0x1107, 0x110c, 0x110c,
+    0x1111, 0x1109, 0x1111, 0x1110, 0x8c48, 0x66f4, 0x8eca, 0x8cc8, 0x6ed1, 0x4e32,
+    0x53e5, 0x9f9c, 0x9f9c, 0x5951, 0x91d1, 0x5587, 0x5948, 0x61f6, 0x7669, 0x7f85,
+    0x863f, 0x87ba, 0x88f8, 0x908f, 0x6a02, 0x6d1b, 0x70d9, 0x73de, 0x843d, 0x916a,
+    0x99f1, 0x4e82, 0x5375, 0x6b04, 0x721b, 0x862d, 0x9e1e, 0x5d50, 0x6feb, 0x85cd,
+    0x8964, 0x62c9, 0x81d8, 0x881f, 0x5eca, 0x6717, 0x6d6a, 0x72fc, 0x90ce, 0x4f86,
+    0x51b7, 0x52de, 0x64c4, 0x6ad3, 0x7210, 0x76e7, 0x8001, 0x8606, 0x865c, 0x8def,
+    0x9732, 0x9b6f, 0x9dfa, 0x788c, 0x797f, 0x7da0, 0x83c9, 0x9304, 0x9e7f, 0x8ad6,
+    0x58df, 0x5f04, 0x7c60, 0x807e, 0x7262, 0x78ca, 0x8cc2, 0x96f7, 0x58d8, 0x5c62,
+    0x6a13, 0x6dda, 0x6f0f, 0x7d2f, 0x7e37, 0x964b, 0x52d2, 0x808b, 0x51dc, 0x51cc,
+    0x7a1c, 0x7dbe, 0x83f1, 0x9675, 0x8b80, 0x62cf, 0x6a02, 0x8afe, 0x4e39, 0x5be7,
+    0x6012, 0x7387, 0x7570, 0x5317, 0x78fb, 0x4fbf, 0x5fa9, 0x4e0d, 0x6ccc, 0x6578,
+    0x7d22, 0x53c3, 0x585e, 0x7701, 0x8449, 0x8aaa, 0x6bba, 0x8fb0, 0x6c88, 0x62fe,
+    0x82e5, 0x63a0, 0x7565, 0x4eae, 0x5169, 0x51c9, 0x6881, 0x7ce7, 0x826f, 0x8ad2,
+    0x91cf, 0x52f5, 0x5442, 0x5973, 0x5eec, 0x65c5, 0x6ffe, 0x792a, 0x95ad, 0x9a6a,
+    0x9e97, 0x9ece, 0x529b, 0x66c6, 0x6b77, 0x8f62, 0x5e74, 0x6190, 0x6200, 0x649a,
+    0x6f23, 0x7149, 0x7489, 0x79ca, 0x7df4, 0x806f, 0x8f26, 0x84ee, 0x9023, 0x934a,
+    0x5217, 0x52a3, 0x54bd, 0x70c8, 0x88c2, 0x8aaa, 0x5ec9, 0x5ff5, 0x637b, 0x6bae,
+    0x7c3e, 0x7375, 0x4ee4, 0x56f9, 0x5be7, 0x5dba, 0x601c, 0x73b2, 0x7469, 0x7f9a,
+    0x8046, 0x9234, 0x96f6, 0x9748, 0x9818, 0x4f8b, 0x79ae, 0x91b4, 0x96b7, 0x60e1,
+    0x4e86, 0x50da, 0x5bee, 0x5c3f, 0x6599, 0x6a02, 0x71ce, 0x7642, 0x84fc, 0x907c,
+    0x9f8d, 0x6688, 0x962e, 0x5289, 0x677b, 0x67f3, 0x6d41, 0x6e9c, 0x7409, 0x7559,
+    0x786b, 0x7d10, 0x985e, 0x516d, 0x622e, 0x9678, 0x502b, 0x5d19, 0x6dea, 0x8f2a,
+    0x5f8b, 0x6144, 0x6817, 0x7387, 0x9686, 0x5229, 0x540f, 0x5c65, 0x6613, 0x674e,
+    0x68a8, 0x6ce5, 0x7406, 0x75e2, 0x7f79, 0x88cf, 0x88e1, 0x91cc, 0x96e2, 0x533f,
+    0x6eba, 0x541d, 0x71d0, 0x7498, 0x85fa, 0x96a3, 0x9c57, 0x9e9f, 0x6797, 0x6dcb,
+    0x81e8, 0x7acb, 0x7b20, 0x7c92, 0x72c0, 0x7099, 0x8b58, 0x4ec0, 0x8336, 0x523a,
+    0x5207, 0x5ea6, 0x62d3, 0x7cd6, 0x5b85, 0x6d1e, 0x66b4, 0x8f3b, 0x884c, 0x964d,
+    0x898b, 0x5ed3, 0x5140, 0x55c0, 0x585a, 0x6674, 0x51de, 0x732a, 0x76ca, 0x793c,
+    0x795e, 0x7965, 0x798f, 0x9756, 0x7cbe, 0x7fbd, 0x8612, 0x8af8, 0x9038, 0x90fd,
+    0x98ef, 0x98fc, 0x9928, 0x9db4, 0x90ce, 0x96b7, 0x4fae, 0x50e7, 0x514d, 0x52c9,
+    0x52e4, 0x5351, 0x559d, 0x5606, 0x5668, 0x5840, 0x58a8, 0x5c64, 0x5c6e, 0x6094,
+    0x6168, 0x618e, 0x61f2, 0x654f, 0x65e2, 0x6691, 0x6885, 0x6d77, 0x6e1a, 0x6f22,
+    0x716e, 0x722b, 0x7422, 0x7891, 0x793e, 0x7949, 0x7948, 0x7950, 0x7956, 0x795d,
+    0x798d, 0x798e, 0x7a40, 0x7a81, 0x7bc0, 0x7df4, 0x7e09, 0x7e41, 0x7f72, 0x8005,
+    0x81ed, 0x8279, 0x8279, 0x8457, 0x8910, 0x8996, 0x8b01, 0x8b39, 0x8cd3, 0x8d08,
+    0x8fb6, 0x9038, 0x96e3, 0x97ff, 0x983b, 0x6075, 0x242ee, 0x8218, 0x4e26, 0x51b5,
+    0x5168, 0x4f80, 0x5145, 0x5180, 0x52c7, 0x52fa, 0x559d, 0x5555, 0x5599, 0x55e2,
+    0x585a, 0x58b3, 0x5944, 0x5954, 0x5a62, 0x5b28, 0x5ed2, 0x5ed9, 0x5f69, 0x5fad,
+    0x60d8, 0x614e, 0x6108, 0x618e, 0x6160, 0x61f2, 0x6234, 0x63c4, 0x641c, 0x6452,
+    0x6556, 0x6674, 0x6717, 0x671b, 0x6756, 0x6b79, 0x6bba, 0x6d41, 0x6edb, 0x6ecb,
+    0x6f22, 0x701e, 0x716e, 0x77a7, 0x7235, 0x72af, 0x732a, 0x7471, 0x7506, 0x753b,
+    0x761d, 0x761f, 0x76ca, 0x76db, 0x76f4, 0x774a, 0x7740, 0x78cc, 0x7ab1, 0x7bc0,
+    0x7c7b, 0x7d5b, 0x7df4, 0x7f3e, 0x8005, 0x8352, 0x83ef, 0x8779, 0x8941, 0x8986,
+    0x8996, 0x8abf, 0x8af8, 0x8acb, 0x8b01, 0x8afe, 0x8aed, 0x8b39, 0x8b8a, 0x8d08,
+    0x8f38, 0x9072, 0x9199, 0x9276, 0x967c, 0x96e3, 0x9756, 0x97db, 0x97ff, 0x980b,
+    0x983b, 0x9b12, 0x9f9c, 0x2284a, 0x22844, 0x233d5, 0x3b9d, 0x4018, 0x4039, 0x25249,
+    0x25cd0, 0x27ed3, 0x9f43, 0x9f8e, 0x66, 0x66, 0x66, 0x69, 0x66, 0x6c,
+    0x66, 0x66, 0x69, 0x66, 0x66, 0x6c, 0x73, 0x74, 0x574, 0x576,
+    0x574, 0x565, 0x574, 0x56b, 0x57e, 0x576, 0x574, 0x56d, 0x5e2, 0x5d0,
+    0x5d3, 0x5d4, 0x5db, 0x5dc, 0x5dd, 0x5e8, 0x5ea, 0x2d, 0x307, 0x5e9,
+    0x5c1, 0x5e9, 0x5bc, 0x5c1, 0x5d0, 0x5b7, 0x5d0, 0x5b7, 0x5d9, 0x5b4,
+    0x5e9, 0x5c1, 0x5d0, 0x5dc, 0x671, 0x671, 0x67b, 0x67b, 0x67b, 0x67b,
+    0x649, 0x6db, 0x649, 0x6db, 0x649, 0x6db, 0x649, 0x6db, 0x680, 0x680,
+    0x680, 0x680, 0x67a, 0x67a, 0x67a, 0x67a, 0x67f, 0x67f, 0x67f, 0x67f,
+    0x649, 0x615, 0x649, 0x615, 0x649, 0x615, 0x649, 0x615, 0x6a1, 0x6db,
+    0x6a1, 0x6db, 0x6a1, 0x6db, 0x6a1, 0x6db, 0x6a6, 0x6a6, 0x6a6, 0x6a6,
+    0x684, 0x684, 0x684, 0x684, 0x683, 0x683, 0x683, 0x683, 0x686, 0x686,
+    0x686, 0x686, 0x687, 0x687, 0x687, 0x687, 0x68d, 0x68d, 0x68c, 0x68c,
+    0x62f, 0x6db, 0x62f, 0x6db, 0x62f, 0x615, 0x62f, 0x615, 0x631, 0x6db,
+    0x631, 0x6db, 0x631, 0x615, 0x631, 0x615, 0x643, 0x643, 0x643, 0x643,
+    0x6af, 0x6af, 0x6af, 0x6af, 0x6b3, 0x6b3, 0x6b3, 0x6b3, 0x6b1, 0x6b1,
+    0x6b1, 0x6b1, 0x649, 0x649, 0x649, 0x615, 0x649, 0x615, 0x649, 0x615,
+    0x649, 0x615, 0x6d5, 0x654, 0x6d5, 0x654, 0x6f, 0x6f, 0x6f, 0x6f,
+    0x6f, 0x6f, 0x6f, 0x6f, 0x649, 0x649, 0x6d2, 0x654, 0x6d2, 0x654,
+    0x643, 0x6db, 0x643, 0x6db, 0x643, 0x6db, 0x643, 0x6db, 0x648, 0x313,
+    0x648, 0x313, 0x648, 0x306, 0x648, 0x306, 0x648, 0x670, 0x648, 0x670,
+    0x648, 0x313, 0x674, 0x648, 0x6db, 0x648, 0x6db, 0x6c5, 0x6c5, 0x648,
+    0x302, 0x648, 0x302, 0x67b, 0x67b, 0x67b, 0x67b, 0x649, 0x649, 0x649,
+    0x674, 0x6c, 0x649, 0x674, 0x6c, 0x649, 0x674, 0x6f, 0x649, 0x674,
+    0x6f, 0x649, 0x674, 0x648, 0x649, 0x674, 0x648, 0x649, 0x674, 0x648,
+    0x313, 0x649, 0x674, 0x648, 0x313, 0x649, 0x674, 0x648, 0x306, 0x649,
+    0x674, 0x648, 0x306, 0x649, 0x674, 0x648, 0x670, 0x649, 0x674, 0x648,
+    0x670, 0x649, 0x674, 0x67b, 0x649, 0x674, 0x67b, 0x649, 0x674, 0x67b,
+    0x649, 0x674, 0x649, 0x649, 0x674, 0x649, 0x649, 0x674, 0x649, 0x649,
+    0x649, 0x649, 0x649, 0x649, 0x674, 0x62c, 0x649, 0x674, 0x62d, 0x649,
+    0x674, 0x645, 0x649, 0x674, 0x649, 0x649, 0x674, 0x649, 0x628, 0x62c,
+    0x628, 0x62d, 0x628, 0x62e, 0x628, 0x645, 0x628, 0x649, 0x628, 0x649,
+    0x62a, 0x62c, 0x62a, 0x62d, 0x62a, 0x62e, 0x62a, 0x645, 0x62a, 0x649,
+    0x62a, 0x649, 0x649, 0x6db, 0x62c, 0x649, 0x6db, 0x645, 0x649, 0x6db,
+    0x649, 0x649, 0x6db, 0x649, 0x62c, 0x62d, 0x62c, 0x645, 0x62d, 0x62c,
+    0x62d, 0x645, 0x62e, 0x62c, 0x62e, 0x62d, 0x62e, 0x645, 0x633, 0x62c,
+    0x633, 0x62d, 0x633, 0x62e, 0x633, 0x645, 0x635, 0x62d, 0x635, 0x645,
+    0x636, 0x62c, 0x636, 0x62d, 0x636, 0x62e, 0x636, 0x645, 0x637, 0x62d,
+    0x637, 0x645, 0x638, 0x645, 0x639, 0x62c, 0x639, 0x645, 0x63a, 0x62c,
+    0x63a, 0x645, 0x641, 0x62c, 0x641, 0x62d, 0x641, 0x62e, 0x641, 0x645,
+    0x641, 0x649, 0x641, 0x649, 0x642, 0x62d, 0x642, 0x645, 0x642, 0x649,
+    0x642, 0x649, 0x643, 0x6c, 0x643, 0x62c, 0x643, 0x62d, 0x643, 0x62e,
+    0x643, 0x644, 0x643, 0x645, 0x643, 0x649, 0x643, 0x649, 0x644, 0x62c,
+    0x644, 0x62d, 0x644, 0x62e, 0x644, 0x645, 0x644, 0x649, 0x644, 0x649,
+    0x645, 0x62c, 0x645, 0x62d, 0x645, 0x62e, 0x645, 0x645, 0x645, 0x649,
+    0x645, 0x649, 0x628, 0x62e, 0x646, 0x62d, 0x646, 0x62e, 0x646, 0x645,
+    0x646, 0x649, 0x646, 0x649, 0x6f, 0x62c, 0x6f, 0x645, 0x6f, 0x649,
+    0x6f, 0x649, 0x649, 0x62c, 0x649, 0x62d, 0x649, 0x62e, 0x649, 0x645,
+    0x649, 0x649, 0x649, 0x649, 0x630, 0x670, 0x631, 0x670, 0x649, 0x670,
+    0xfe72, 0x651, 0xfe74, 0x651, 0xfe76, 0x651, 0xfe78, 0x651, 0xfe7a, 0x651,
+    0xfe7c, 0x670, 0x649, 0x674, 0x631, 0x649, 0x674, 0x632, 0x649, 0x674,
+    0x645, 0x649, 0x674, 0x646, 0x649, 0x674, 0x649, 0x649, 0x674, 0x649,
+    0x628, 0x631, 0x628, 0x632, 0x628, 0x645, 0x628, 0x646, 0x628, 0x649,
+    0x628, 0x649, 0x62a, 0x631, 0x62a, 0x632, 0x62a, 0x645, 0x62a, 0x646,
+    0x62a, 0x649, 0x62a, 0x649, 0x649, 0x6db, 0x631, 0x649, 0x6db, 0x632,
+    0x649, 0x6db, 0x645, 0x649, 0x6db, 0x646, 0x649, 0x6db, 0x649, 0x649,
+    0x6db, 0x649, 0x641, 0x649, 0x641, 0x649, 0x642, 0x649, 0x642, 0x649,
+    0x643, 0x6c, 0x643, 0x644, 0x643, 0x645, 0x643, 0x649, 0x643, 0x649,
+    0x644, 0x645, 0x644, 0x649, 0x644, 0x649, 0x645, 0x6c, 0x645, 0x645,
+    0x646, 0x631, 0x646, 0x632, 0x646, 0x645, 0x646, 0x646, 0x646, 0x649,
+    0x646, 0x649, 0x649, 0x670, 0x649, 0x631, 0x649, 0x632, 0x649, 0x645,
+    0x649, 0x646, 0x649, 0x649, 0x649, 0x649, 0x649, 0x674, 0x62c, 0x649,
+    0x674, 0x62d, 0x649, 0x674, 0x62e, 0x649, 0x674, 0x645, 0x649, 0x674,
+    0x6f, 0x628, 0x62c, 0x628, 0x62d, 0x628, 0x62e, 0x628, 0x645, 0x628,
+    0x6f, 0x62a, 0x62c, 0x62a, 0x62d, 0x62a, 0x62e, 0x62a, 0x645, 0x62a,
+    0x6f, 0x649, 0x6db, 0x645, 0x62c, 0x62d, 0x62c, 0x645, 0x62d, 0x62c,
+    0x62d, 0x645, 0x62e, 0x62c, 0x62e, 0x645, 0x633, 0x62c, 0x633, 0x62d,
+    0x633, 0x62e, 0x633, 0x645, 0x635, 0x62d, 0x635, 0x62e, 0x635, 0x645,
+    0x636, 0x62c, 0x636, 0x62d, 0x636, 0x62e, 0x636, 0x645, 0x637, 0x62d,
+    0x638, 0x645, 0x639, 0x62c, 0x639, 0x645, 0x63a, 0x62c, 0x63a, 0x645,
+    0x641, 0x62c, 0x641, 0x62d, 0x641, 0x62e, 0x641, 0x645, 0x642, 0x62d,
+    0x642, 0x645, 0x643, 0x62c, 0x643, 0x62d, 0x643, 0x62e, 0x643, 0x644,
+    0x643, 0x645, 0x644, 0x62c, 0x644, 0x62d, 0x644, 0x62e, 0x644, 0x645,
+    0x644, 0x6f, 0x645, 0x62c, 0x645, 0x62d, 0x645, 0x62e, 0x645, 0x645,
+    0x628, 0x62e, 0x646, 0x62d, 0x646, 0x62e, 0x646, 0x645, 0x646, 0x6f,
+    0x6f, 0x62c, 0x6f, 0x645, 0x6f, 0x670, 0x649, 0x62c, 0x649, 0x62d,
+    0x649, 0x62e, 0x649, 0x645, 0x649, 0x6f, 0x649, 0x674, 0x645, 0x649,
+    0x674, 0x6f, 0x628, 0x645, 0x628, 0x6f, 0x62a, 0x645, 0x62a, 0x6f,
+    0x649, 0x6db, 0x645, 0x649, 0x6db, 0x6f, 0x633, 0x645, 0x633, 0x6f,
+    0x633, 0x6db, 0x645, 0x633, 0x6db, 0x6f, 0x643, 0x644, 0x643, 0x645,
+    0x644, 0x645, 0x646, 0x645, 0x646, 0x6f, 0x649, 0x645, 0x649, 0x6f,
+    0xfe77, 0x651, 0xfe79, 0x651, 0xfe7b, 0x651, 0x637, 0x649, 0x637, 0x649,
+    0x639, 0x649, 0x639, 0x649, 0x63a, 0x649, 0x63a, 0x649, 0x633, 0x649,
+    0x633, 0x649, 0x633, 0x6db, 0x649, 0x633, 0x6db, 0x649, 0x62d, 0x649,
+    0x62d, 0x649, 0x62c, 0x649, 0x62c, 0x649, 0x62e, 0x649, 0x62e, 0x649,
+    0x635, 0x649, 0x635, 0x649, 0x636, 0x649, 0x636, 0x649, 0x633, 0x6db,
+    0x62c, 0x633, 0x6db, 0x62d, 0x633, 0x6db, 0x62e, 0x633, 0x6db, 0x645,
+    0x633, 0x6db, 0x631, 0x633, 0x631, 0x635, 0x631, 0x636, 0x631, 0x637,
+    0x649, 0x637, 0x649, 0x639, 0x649, 0x639, 0x649, 0x63a, 0x649, 0x63a,
+    0x649, 0x633, 0x649, 0x633, 0x649, 0x633, 0x6db, 0x649, 0x633, 0x6db,
+    0x649, 0x62d, 0x649, 0x62d, 0x649, 0x62c, 0x649, 0x62c, 0x649, 0x62e,
+    0x649, 0x62e, 0x649, 0x635, 0x649, 0x635, 0x649, 0x636, 0x649, 0x636,
+    0x649, 0x633, 0x6db, 0x62c, 0x633, 0x6db, 0x62d, 0x633, 0x6db, 0x62e,
+    0x633, 0x6db, 0x645, 0x633, 0x6db, 0x631, 0x633, 0x631, 0x635, 0x631,
+    0x636, 0x631, 0x633, 0x6db, 0x62c, 0x633, 0x6db, 0x62d, 0x633, 0x6db,
+    0x62e, 0x633, 0x6db, 0x645, 0x633, 0x6f, 0x633, 0x6db, 0x6f, 0x637,
+    0x645, 0x633, 0x62c, 0x633, 0x62d, 0x633, 0x62e, 0x633, 0x6db, 0x62c,
+    0x633, 0x6db, 0x62d, 0x633, 0x6db, 0x62e, 0x637, 0x645, 0x638, 0x645,
+    0x6c, 0x30b, 0x6c, 0x30b, 0x28, 0x29, 0x62a, 0x62c, 0x645, 0x62a,
+    0x62d, 0x62c, 0x62a, 0x62d, 0x62c, 0x62a, 0x62d, 0x645, 0x62a, 0x62e,
+    0x645, 0x62a, 0x645, 0x62c, 0x62a, 0x645, 0x62d, 0x62a, 0x645, 0x62e,
+    0x62c, 0x645, 0x62d, 0x62c, 0x645, 0x62d, 0x62d, 0x645, 0x649, 0x62d,
+    0x645, 0x649, 0x633, 0x62d, 0x62c, 0x633, 0x62c, 0x62d, 0x633, 0x62c,
+    0x649, 0x633, 0x645, 0x62d, 0x633, 0x645, 0x62d, 0x633, 0x645, 0x62c,
+    0x633, 0x645, 0x645, 0x633, 0x645, 0x645, 0x635, 0x62d, 0x62d, 0x635,
+    0x62d, 0x62d, 0x635, 0x645, 0x645, 0x633, 0x6db, 0x62d, 0x645, 0x633,
+    0x6db, 0x62d, 0x645, 0x633, 0x6db, 0x62c, 0x649, 0x633, 0x6db, 0x645,
+    0x62e, 0x633, 0x6db, 0x645, 0x62e, 0x633, 0x6db, 0x645, 0x645, 0x633,
+    0x6db, 0x645, 0x645, 0x636, 0x62d, 0x649, 0x636, 0x62e, 0x645, 0x636,
+    0x62e, 0x645, 0x637, 0x645, 0x62d, 0x637, 0x645, 0x62d, 0x637, 0x645,
+    0x645, 0x637, 0x645, 0x649, 0x639, 0x62c, 0x645, 0x639, 0x645, 0x645,
+    0x639, 0x645, 0x645, 0x639, 0x645, 0x649, 0x63a, 0x645, 0x645, 0x63a,
+    0x645, 0x649, 0x63a, 0x645, 0x649, 0x641, 0x62e, 0x645, 0x641, 0x62e,
+    0x645, 0x642, 0x645, 0x62d, 0x642, 0x645, 0x645, 0x644, 0x62d, 0x645,
+    0x644, 0x62d, 0x649, 0x644, 0x62d, 0x649, 0x644, 0x62c, 0x62c, 0x644,
+    0x62c, 0x62c, 0x644, 0x62e, 0x645, 0x644, 0x62e, 0x645, 0x644, 0x645,
+    0x62d, 0x644, 0x645, 0x62d, 0x645, 0x62d, 0x62c, 0x645, 0x62d, 0x645,
+    0x645, 0x62d, 0x649, 0x645, 0x62c, 0x62d, 0x645, 0x62c, 0x645, 0x645,
+    0x62e, 0x62c, 0x645, 0x62e, 0x645, 0x645, 0x62c, 0x62e, 0x6f, 0x645,
+    0x62c, 0x6f, 0x645, 0x645, 0x646, 0x62d, 0x645, 0x646, 0x62d, 0x649,
+    0x646, 0x62c, 0x645, 0x646, 0x62c, 0x645, 0x646, 0x62c, 0x649, 0x646,
+    0x645, 0x649, 0x646, 0x645, 0x649, 0x649, 0x645, 0x645, 0x649, 0x645,
+    0x645, 0x628, 0x62e, 0x649, 0x62a, 0x62c, 0x649, 0x62a, 0x62c, 0x649,
+    0x62a, 0x62e, 0x649, 0x62a, 0x62e, 0x649, 0x62a, 0x645, 0x649, 0x62a,
+    0x645, 0x649, 0x62c, 0x645, 0x649, 0x62c, 0x62d, 0x649, 0x62c, 0x645,
+    0x649, 0x633, 0x62e, 0x649, 0x635, 0x62d, 0x649, 0x633, 0x6db, 0x62d,
+    0x649, 0x636, 0x62d, 0x649, 0x644, 0x62c, 0x649, 0x644, 0x645, 0x649,
+    0x649, 0x62d, 0x649, 0x649, 0x62c, 0x649, 0x649, 0x645, 0x649, 0x645,
+    0x645, 0x649, 0x642, 0x645, 0x649, 0x646, 0x62d, 0x649, 0x642, 0x645,
+    0x62d, 0x644, 0x62d, 0x645, 0x639, 0x645, 0x649, 0x643, 0x645, 0x649,
+    0x646, 0x62c, 0x62d, 0x645, 0x62e, 0x649, 0x644, 0x62c, 0x645, 0x643,
+    0x645, 0x645, 0x644, 0x62c, 0x645, 0x646, 0x62c, 0x62d, 0x62c, 0x62d,
+    0x649, 0x62d, 0x62c, 0x649, 0x645, 0x62c, 0x649, 0x641, 0x645, 0x649,
+    0x628, 0x62d, 0x649, 0x643, 0x645, 0x645, 0x639, 0x62c, 0x645, 0x635,
+    0x645, 0x645, 0x633, 0x62e, 0x649, 0x646, 0x62c, 0x649, 0x635, 0x644,
+    0x649, 0x642, 0x644, 0x649, 0x6c, 0x644, 0x644, 0x651, 0x670, 0x6f,
+    0x6c, 0x643, 0x628, 0x631, 0x645, 0x62d, 0x645, 0x62f, 0x635, 0x644,
+    0x639, 0x645, 0x631, 0x633, 0x648, 0x644, 0x639, 0x644, 0x649, 0x6f,
+    0x648, 0x633, 0x644, 0x645, 0x635, 0x644, 0x649, 0x635, 0x644, 0x649,
+    0x20, 0x6c, 0x644, 0x644, 0x6f, 0x20, 0x639, 0x644, 0x649, 0x6f,
+    0x20, 0x648, 0x633, 0x644, 0x645, 0x62c, 0x644, 0x20, 0x62c, 0x644,
+    0x6c, 0x644, 0x6f, 0x631, 0x649, 0x6c, 0x644, 0x2d57, 0x3a, 0x2502,
+    0x2307, 0x23dc, 0x23dd, 0x23de, 0x23df, 0x23e0, 0x23e1, 0x2c9, 0x2c9, 0x2c9,
+    0x2c9, 0x5f, 0x5f, 0x5f, 0x2d, 0x5c, 0x621, 0x627, 0x653, 0x627,
+    0x653, 0x6c, 0x674, 0x6c, 0x674, 0x648, 0x674, 0x648, 0x674, 0x6c,
+    0x655, 0x6c, 0x655, 0x649, 0x674, 0x649, 0x674, 0x649, 0x674, 0x649,
+    0x674, 0x6c, 0x6c, 0x628, 0x628, 0x628, 0x628, 0x629, 0x629, 0x62a,
+    0x62a, 0x62a, 0x62a, 0x649, 0x6db, 0x649, 0x6db, 0x649, 0x6db, 0x649,
+    0x6db, 0x62c, 0x62c, 0x62c, 0x62c, 0x62d, 0x62d, 0x62d, 0x62d, 0x62e,
+    0x62e, 0x62e, 0x62e, 0x62f, 0x62f, 0x630, 0x630, 0x631, 0x631, 0x632,
+    0x632, 0x633, 0x633, 0x633, 0x633, 0x633, 0x6db, 0x633, 0x6db, 0x633,
+    0x6db, 0x633, 0x6db, 0x635, 0x635, 0x635, 0x635, 0x636, 0x636, 0x636,
+    0x636, 0x637, 0x637, 0x637, 0x637, 0x638, 0x638, 0x638, 0x638, 0x639,
+    0x639, 0x639, 0x639, 0x63a, 0x63a, 0x63a, 0x63a, 0x641, 0x641, 0x641,
+    0x641, 0x642, 0x642, 0x642, 0x642, 0x643, 0x643, 0x643, 0x643, 0x644,
+    0x644, 0x644, 0x644, 0x645, 0x645, 0x645, 0x645, 0x646, 0x646, 0x646,
+    0x646, 0x6f, 0x6f, 0x6f, 0x6f, 0x648, 0x648, 0x649, 0x649, 0x649,
+    0x649, 0x649, 0x649, 0x644, 0x627, 0x653, 0x644, 0x627, 0x653, 0x644,
+    0x6c, 0x674, 0x644, 0x6c, 0x674, 0x644, 0x6c, 0x655, 0x644, 0x6c,
+    0x655, 0x644, 0x6c, 0x644, 0x6c, 0x21, 0x27, 0x27, 0x27, 0x30fc,
+    0x3a, 0x41, 0x42, 0x43, 0x45, 0x48, 0x6c, 0x4a, 0x4b, 0x4d,
+    0x4e, 0x4f, 0x50, 0x53, 0x54, 0x58, 0x59, 0x5a, 0x28, 0x5c,
+    0x29, 0xfe3f, 0x27, 0x61, 0x63, 0x65, 0x67, 0x68, 0x69, 0x6a,
+    0x6c, 0x6f, 0x70, 0x73, 0x76, 0x78, 0x79, 0x2502, 0x301c, 0xb7,
+    0x2c9, 0x6c, 0x25aa, 0xb7, 0x4e, 0x30a, 0x58, 0x335, 0x56, 0x335,
+    0x6c, 0x335, 0x6c, 0x335, 0x53, 0x335, 0x6c, 0x335, 0x6c, 0x335,
+    0x2ce8, 0x42, 0x394, 0x45, 0x46, 0x6c, 0x245, 0x58, 0x4f, 0x16dc,
+    0x50, 0x53, 0x54, 0x2b, 0x41, 0x42, 0x43, 0x394, 0x46, 0x4f,
+    0x3d8, 0x4d, 0x54, 0x59, 0x3a6, 0x58, 0x3a8, 0x3a9, 0x2d40, 0x48,
+    0x62f, 0x648, 0x637, 0x635, 0x5a, 0x42, 0x43, 0x6c, 0x4d, 0x3d8,
+    0x54, 0x58, 0x38, 0x2a, 0x6c, 0x58, 0x10382, 0x10393, 0x190, 0x4f,
+    0xa4f6, 0x43, 0x4c, 0x2c70, 0x53, 0x186, 0x418, 0xa793, 0x29a, 0x6f,
+    0x63, 0x277, 0x25e, 0x29f, 0x73, 0x254, 0x1d0e, 0x10486, 0x245, 0x52,
+    0x4c3, 0x4f, 0x298, 0xde, 0x40b, 0x55, 0x16e6, 0x3a8, 0x37, 0x28c,
+    0x3bb, 0x6f, 0xa669, 0x75, 0x3c8, 0x4e, 0x4f, 0x4b, 0x43, 0x56,
+    0x46, 0x4c, 0x58, 0x323, 0x2e, 0x10a56, 0x10a56, 0x10ca5, 0x10c82, 0x970,
+    0x970, 0x323, 0x93a, 0xa8fc, 0xa8fb, 0x2248, 0x30a, 0x11434, 0x11442, 0x11412,
+    0x11434, 0x11442, 0x11418, 0x11434, 0x11442, 0x11423, 0x11434, 0x11442, 0x11429, 0x11434,
+    0x11442, 0x1142c, 0x11434, 0x11442, 0x1142e, 0x1144b, 0x1144b, 0x998, 0x99a, 0x99c,
+    0x99e, 0x99f, 0x9a1, 0x9b2, 0x9a4, 0x9a5, 0x9a6, 0x9a7, 0x9a8, 0x9aa,
+    0x9ae, 0x9af, 0x9ac, 0x9a3, 0x9b0, 0x9b7, 0x9b8, 0x9be, 0x9bf, 0x9c7,
+    0x9c7, 0x9be, 0x9d7, 0x9c7, 0x9d7, 0x306, 0x307, 0x983, 0x9cd, 0x323,
+    0x9bd, 0x77, 0x307, 0x4f, 0x9e7, 0x9e8, 0x9ec, 0x11582, 0x11582, 0x11583,
+    0x11584, 0x115b2, 0x115b3, 0x11641, 0x11641, 0x72, 0x6e, 0x76, 0x77, 0x77,
+    0x77, 0x56, 0x46, 0x4c, 0x59, 0x45, 0x2207, 0x5a, 0x39, 0x45,
+    0x34, 0x4c, 0x4f, 0x16dc, 0x55, 0x35, 0x54, 0x76, 0x73, 0x46,
+    0x69, 0x7a, 0x37, 0x6f, 0x33, 0x39, 0xa793, 0x36, 0x39, 0x6f,
+    0x75, 0x79, 0x4f, 0x72, 0x6e, 0x669, 0x5a, 0x57, 0x43, 0x58,
+    0x57, 0x43, 0x11ae5, 0x11aef, 0x11ae5, 0x11af0, 0x11ae5, 0x11ae5, 0x11ae5, 0x11ae5,
+    0x11aef, 0x11ae5, 0x11ae5, 0x11af0, 0x11aeb, 0x11aef, 0x11aeb, 0x11aeb, 0x11aeb, 0x11aeb,
+    0x11aef, 0x11af3, 0x11aef, 0x11af3, 0x11af0, 0x11af3, 0x11af3, 0x11af3, 0x11af3, 0x11aef,
+    0x11af3, 0x11af3, 0x11af0, 0x11c41, 0x11c41, 0x11caa, 0x1039a, 0x1099e, 0x393, 0x56,
+    0x54, 0x4c, 0x394, 0xa658, 0xa4f6, 0x6c, 0x190, 0x52, 0x53, 0x33,
+    0x245, 0x3e, 0x41, 0x55, 0x59, 0x27, 0x27, 0x7b, 0x2e, 0x4fe,
+    0x33, 0x418, 0x56, 0x5c, 0x37, 0x46, 0x102bc, 0xa4f6, 0x52, 0x2c6f,
+    0x4f, 0x335, 0x2144, 0xa4d5, 0x190, 0x460, 0x4c, 0xa4f6, 0xa7fb, 0x3c,
+    0x3e, 0x228f, 0x2290, 0x2f, 0x5c, 0x16cb, 0x548, 0x41, 0x42, 0x43,
+    0x44, 0x45, 0x46, 0x47, 0x48, 0x6c, 0x4a, 0x4b, 0x4c, 0x4d,
+    0x4e, 0x4f, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57,
+    0x58, 0x59, 0x5a, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67,
+    0x68, 0x69, 0x6a, 0x6b, 0x6c, 0x72, 0x6e, 0x6e, 0x6f, 0x70,
+    0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7a,
+    0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x6c, 0x4a,
+    0x4b, 0x4c, 0x4d, 0x4e, 0x4f, 0x50, 0x51, 0x52, 0x53, 0x54,
+    0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0x61, 0x62, 0x63, 0x64,
+    0x65, 0x66, 0x67, 0x69, 0x6a, 0x6b, 0x6c, 0x72, 0x6e, 0x6e,
+    0x6f, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78,
+    0x79, 0x7a, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48,
+    0x6c, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f, 0x50, 0x51, 0x52,
+    0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0x61, 0x62,
+    0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6a, 0x6b, 0x6c,
+    0x72, 0x6e, 0x6e, 0x6f, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75,
+    0x76, 0x77, 0x78, 0x79, 0x7a, 0x41, 0x43, 0x44, 0x47, 0x4a,
+    0x4b, 0x4e, 0x4f, 0x50, 0x51, 0x53, 0x54, 0x55, 0x56, 0x57,
+    0x58, 0x59, 0x5a, 0x61, 0x62, 0x63, 0x64, 0x66, 0x68, 0x69,
+    0x6a, 0x6b, 0x6c, 0x72, 0x6e, 0x6e, 0x70, 0x71, 0x72, 0x73,
+    0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7a, 0x41, 0x42, 0x43,
+    0x44, 0x45, 0x46, 0x47, 0x48, 0x6c, 0x4a, 0x4b, 0x4c, 0x4d,
+    0x4e, 0x4f, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57,
+    0x58, 0x59, 0x5a, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67,
+    0x68, 0x69, 0x6a, 0x6b, 0x6c, 0x72, 0x6e, 0x6e, 0x6f, 0x70,
+    0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7a,
+    0x41, 0x42, 0x44, 0x45, 0x46, 0x47, 0x4a, 0x4b, 0x4c, 0x4d,
+    0x4e, 0x4f, 0x50, 0x51, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58,
+    0x59, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69,
+    0x6a, 0x6b, 0x6c, 0x72, 0x6e, 0x6e, 0x6f, 0x70, 0x71, 0x72,
+    0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7a, 0x41, 0x42,
+    0x44, 0x45, 0x46, 0x47, 0x6c, 0x4a, 0x4b, 0x4c, 0x4d, 0x4f,
+    0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x61, 0x62, 0x63,
+    0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6a, 0x6b, 0x6c, 0x72,
+    0x6e, 0x6e, 0x6f, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76,
+    0x77, 0x78, 0x79, 0x7a, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46,
+    0x47, 0x48, 0x6c, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f, 0x50,
+    0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5a,
+    0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6a,
+    0x6b, 0x6c, 0x72, 0x6e, 0x6e, 0x6f, 0x70, 0x71, 0x72, 0x73,
+    0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7a, 0x41, 0x42, 0x43,
+    0x44, 0x45, 0x46, 0x47, 0x48, 0x6c, 0x4a, 0x4b, 0x4c, 0x4d,
+    0x4e, 0x4f, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57,
+    0x58, 0x59, 0x5a, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67,
+    0x68, 0x69, 0x6a, 0x6b, 0x6c, 0x72, 0x6e, 0x6e, 0x6f, 0x70,
+    0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7a,
+    0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x6c, 0x4a,
+    0x4b, 0x4c, 0x4d, 0x4e, 0x4f, 0x50, 0x51, 0x52, 0x53, 0x54,
+    0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0x61, 0x62, 0x63, 0x64,
+    0x65, 0x66, 0x67, 0x68, 0x69, 0x6a, 0x6b, 0x6c, 0x72, 0x6e,
+    0x6e, 0x6f, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77,
+    0x78, 0x79, 0x7a, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47,
+    0x48, 0x6c, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f, 0x50, 0x51,
+    0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0x61,
+    0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6a, 0x6b,
+    0x6c, 0x72, 0x6e, 0x6e, 0x6f, 0x70, 0x71, 0x72, 0x73, 0x74,
+    0x75, 0x76, 0x77, 0x78, 0x79, 0x7a, 0x41, 0x42, 0x43, 0x44,
+    0x45, 0x46, 0x47, 0x48, 0x6c, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e,
+    0x4f, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58,
+    0x59, 0x5a, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68,
+    0x69, 0x6a, 0x6b, 0x6c, 0x72, 0x6e, 0x6e, 0x6f, 0x70, 0x71,
+    0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7a, 0x41,
+    0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x6c, 0x4a, 0x4b,
+    0x4c, 0x4d, 0x4e, 0x4f, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55,
+    0x56, 0x57, 0x58, 0x59, 0x5a, 0x61, 0x62, 0x63, 0x64, 0x65,
+    0x66, 0x67, 0x68, 0x69, 0x6a, 0x6b, 0x6c, 0x72, 0x6e, 0x6e,
+    0x6f, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78,
+    0x79, 0x7a, 0x69, 0x237, 0x41, 0x42, 0x393, 0x394, 0x45, 0x5a,
+    0x48, 0x4f, 0x335, 0x6c, 0x4b, 0x245, 0x4d, 0x4e, 0x39e, 0x4f,
+    0x3a0, 0x50, 0x4f, 0x335, 0x1a9, 0x54, 0x59, 0x3a6, 0x58, 0x3a8,
+    0x3a9, 0x2207, 0x61, 0xdf, 0x79, 0x1e9f, 0xa793, 0x3b6, 0x6e, 0x329,
+    0x4f, 0x335, 0x69, 0x138, 0x3bb, 0x3bc, 0x76, 0x3be, 0x6f, 0x3c0,
+    0x70, 0x3c2, 0x6f, 0x1d1b, 0x75, 0x278, 0x3c7, 0x3c8, 0x3c9, 0x2202,
+    0xa793, 0x4f, 0x335, 0x138, 0x278, 0x70, 0x3c0, 0x41, 0x42, 0x393,
+    0x394, 0x45, 0x5a, 0x48, 0x4f, 0x335, 0x6c, 0x4b, 0x245, 0x4d,
+    0x4e, 0x39e, 0x4f, 0x3a0, 0x50, 0x4f, 0x335, 0x1a9, 0x54, 0x59,
+    0x3a6, 0x58, 0x3a8, 0x3a9, 0x2207, 0x61, 0xdf, 0x79, 0x1e9f, 0xa793,
+    0x3b6, 0x6e, 0x329, 0x4f, 0x335, 0x69, 0x138, 0x3bb, 0x3bc, 0x76,
+    0x3be, 0x6f, 0x3c0, 0x70, 0x3c2, 0x6f, 0x1d1b, 0x75, 0x278, 0x3c7,
+    0x3c8, 0x3c9, 0x2202, 0xa793, 0x4f, 0x335, 0x138, 0x278, 0x70, 0x3c0,
+    0x41, 0x42, 0x393, 0x394, 0x45, 0x5a, 0x48, 0x4f, 0x335, 0x6c,
+    0x4b, 0x245, 0x4d, 0x4e, 0x39e, 0x4f, 0x3a0, 0x50, 0x4f, 0x335,
+    0x1a9, 0x54, 0x59, 0x3a6, 0x58, 0x3a8, 0x3a9, 0x2207, 0x61, 0xdf,
+    0x79, 0x1e9f, 0xa793, 0x3b6, 0x6e, 0x329, 0x4f, 0x335, 0x69, 0x138,
+    0x3bb, 0x3bc, 0x76, 0x3be, 0x6f, 0x3c0, 0x70, 0x3c2, 0x6f, 0x1d1b,
+    0x75, 0x278, 0x3c7, 0x3c8, 0x3c9, 0x2202, 0xa793, 0x4f, 0x335, 0x138,
+    0x278, 0x70, 0x3c0, 0x41, 0x42, 0x393, 0x394, 0x45, 0x5a, 0x48,
+    0x4f, 0x335, 0x6c, 0x4b, 0x245, 0x4d, 0x4e, 0x39e, 0x4f, 0x3a0,
+    0x50, 0x4f, 0x335, 0x1a9, 0x54, 0x59, 0x3a6, 0x58, 0x3a8, 0x3a9,
+    0x2207, 0x61, 0xdf, 0x79, 0x1e9f, 0xa793, 0x3b6, 0x6e, 0x329, 0x4f,
+    0x335, 0x69, 0x138, 0x3bb, 0x3bc, 0x76, 0x3be, 0x6f, 0x3c0, 0x70,
+    0x3c2, 0x6f, 0x1d1b, 0x75, 0x278, 0x3c7, 0x3c8, 0x3c9, 0x2202, 0xa793,
+    0x4f, 0x335, 0x138, 0x278, 0x70, 0x3c0, 0x41, 0x42, 0x393, 0x394,
+    0x45, 0x5a, 0x48, 0x4f, 0x335, 0x6c, 0x4b, 0x245, 0x4d, 0x4e,
+    0x39e, 0x4f, 0x3a0, 0x50, 0x4f, 0x335, 0x1a9, 0x54, 0x59, 0x3a6,
+    0x58, 0x3a8, 0x3a9, 0x2207, 0x61, 0xdf, 0x79, 0x1e9f, 0xa793, 0x3b6,
+    0x6e, 0x329, 0x4f, 0x335, 0x69, 0x138, 0x3bb, 0x3bc, 0x76, 0x3be,
+    0x6f, 0x3c0, 0x70, 0x3c2, 0x6f, 0x1d1b, 0x75, 0x278, 0x3c7, 0x3c8,
+    0x3c9, 0x2202, 0xa793, 0x4f, 0x335, 0x138, 0x278, 0x70, 0x3c0, 0x46,
+    0x3dd, 0x4f, 0x6c, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38,
+    0x39, 0x4f, 0x6c, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38,
+    0x39, 0x4f, 0x6c, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38,
+    0x39, 0x4f, 0x6c, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38,
+    0x39, 0x4f, 0x6c, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38,
+    0x39, 0x6c, 0x2220, 0x663, 0x38, 0x2202, 0x2202, 0x335, 0x6c, 0x628,
+    0x62c, 0x62f, 0x648, 0x632, 0x62d, 0x637, 0x649, 0x643, 0x644, 0x645,
+    0x646, 0x633, 0x639, 0x641, 0x635, 0x642, 0x631, 0x633, 0x6db, 0x62a,
+    0x649, 0x6db, 0x62e, 0x630, 0x636, 0x638, 0x63a, 0x649, 0x649, 0x6a1,
+    0x6a1, 0x628, 0x62c, 0x6f, 0x62d, 0x649, 0x643, 0x644, 0x645, 0x646,
+    0x633, 0x639, 0x641, 0x635, 0x642, 0x633, 0x6db, 0x62a, 0x649, 0x6db,
+    0x62e, 0x636, 0x63a, 0x62c, 0x62d, 0x649, 0x644, 0x646, 0x633, 0x639,
+    0x635, 0x642, 0x633, 0x6db, 0x62e, 0x636, 0x63a, 0x649, 0x6a1, 0x628,
+    0x62c, 0x6f, 0x62d, 0x637, 0x649, 0x643, 0x645, 0x646, 0x633, 0x639,
+    0x641, 0x635, 0x642, 0x633, 0x6db, 0x62a, 0x649, 0x6db, 0x62e, 0x636,
+    0x638, 0x63a, 0x649, 0x6a1, 0x6c, 0x628, 0x62c, 0x62f, 0x6f, 0x648,
+    0x632, 0x62d, 0x637, 0x649, 0x644, 0x645, 0x646, 0x633, 0x639, 0x641,
+    0x635, 0x642, 0x631, 0x633, 0x6db, 0x62a, 0x649, 0x6db, 0x62e, 0x630,
+    0x636, 0x638, 0x63a, 0x628, 0x62c, 0x62f, 0x648, 0x632, 0x62d, 0x637,
+    0x649, 0x644, 0x645, 0x646, 0x633, 0x639, 0x641, 0x635, 0x642, 0x631,
+    0x633, 0x6db, 0x62a, 0x649, 0x6db, 0x62e, 0x630, 0x636, 0x638, 0x63a,
+    0x4f, 0x2e, 0x4f, 0x2c, 0x6c, 0x2c, 0x32, 0x2c, 0x33, 0x2c,
+    0x34, 0x2c, 0x35, 0x2c, 0x36, 0x2c, 0x37, 0x2c, 0x38, 0x2c,
+    0x39, 0x2c, 0x24, 0x20e0, 0x28, 0x41, 0x29, 0x28, 0x42, 0x29,
+    0x28, 0x43, 0x29, 0x28, 0x44, 0x29, 0x28, 0x45, 0x29, 0x28,
+    0x46, 0x29, 0x28, 0x47, 0x29, 0x28, 0x48, 0x29, 0x28, 0x6c,
+    0x29, 0x28, 0x4a, 0x29, 0x28, 0x4b, 0x29, 0x28, 0x4c, 0x29,
+    0x28, 0x4d, 0x29, 0x28, 0x4e, 0x29, 0x28, 0x4f, 0x29, 0x28,
+    0x50, 0x29, 0x28, 0x51, 0x29, 0x28, 0x52, 0x29, 0x28, 0x53,
+    0x29, 0x28, 0x54, 0x29, 0x28, 0x55, 0x29, 0x28, 0x56, 0x29,
+    0x28, 0x57, 0x29, 0x28, 0x58, 0x29, 0x28, 0x59, 0x29, 0x28,
+    0x5a, 0x29, 0x28, 0x53, 0x29, 0x33c4, 0x9, 0x20dd, 0x43, 0x20e0,
+    0x28, 0x672c, 0x29, 0x28, 0x4e09, 0x29, 0x28, 0x4e8c, 0x29, 0x28,
+    0x5b89, 0x29, 0x28, 0x70b9, 0x29, 0x28, 0x6253, 0x29, 0x28, 0x76d7,
+    0x29, 0x28, 0x52dd, 0x29, 0x28, 0x6557, 0x29, 0x263d, 0x263e, 0x263d,
+    0x51, 0x45, 0xa658, 0x394, 0x102bc, 0x41, 0x52, 0x56, 0x1de4, 0x2629,
+    0x4f, 0x335, 0x102a8, 0x29df, 0x43, 0x16dc, 0x22a1, 0x73, 0x73, 0x73,
+    0x224f, 0x54, 0x4d, 0x42, 0x56, 0x42, 0x22a0, 0x4f, 0x6c, 0x32,
+    0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x276c, 0x4e3d, 0x4e38,
+    0x4e41, 0x20122, 0x4f60, 0x4fae, 0x4fbb, 0x4f75, 0x507a, 0x5099, 0x50e7, 0x50cf,
+    0x349e, 0x2063a, 0x514d, 0x5154, 0x5164, 0x5177, 0x2051c, 0x34b9, 0x5167, 0x518d,
+    0x2054b, 0x5197, 0x51a4, 0x4ecc, 0x51ac, 0x51b5, 0x291df, 0x51f5, 0x5203, 0x34df,
+    0x523b, 0x5246, 0x5272, 0x5277, 0x3515, 0x52c7, 0x52c9, 0x52e4, 0x52fa, 0x5305,
+    0x5306, 0x5317, 0x5349, 0x5351, 0x535a, 0x5373, 0x537d, 0x537f, 0x537f, 0x537f,
+    0x20a2c, 0x7070, 0x53ca, 0x53df, 0x20b63, 0x53eb, 0x53f1, 0x5406, 0x549e, 0x5438,
+    0x5448, 0x5468, 0x54a2, 0x54f6, 0x5510, 0x5553, 0x5563, 0x5584, 0x5584, 0x5599,
+    0x55ab, 0x55b3, 0x55c2, 0x5716, 0x5606, 0x5717, 0x5651, 0x5674, 0x5207, 0x58ee,
+    0x57ce, 0x57f4, 0x580d, 0x578b, 0x5832, 0x5831, 0x58ac, 0x214e4, 0x58f2, 0x58f7,
+    0x5906, 0x591a, 0x5922, 0x5962, 0x216a8, 0x216ea, 0x59ec, 0x5a1b, 0x5a27, 0x59d8,
+    0x5a66, 0x36ee, 0x36fc, 0x5b08, 0x5b3e, 0x5b3e, 0x219c8, 0x5bc3, 0x5bd8, 0x5be7,
+    0x5bf3, 0x21b18, 0x5bff, 0x5c06, 0x5f53, 0x5c22, 0x3781, 0x5c60, 0x5c6e, 0x5cc0,
+    0x5c8d, 0x21de4, 0x5d43, 0x21de6, 0x5d6e, 0x5d6b, 0x5d7c, 0x5de1, 0x5de2, 0x382f,
+    0x5dfd, 0x5e28, 0x5e3d, 0x5e69, 0x3862, 0x22183, 0x387c, 0x5eb0, 0x5eb3, 0x5eb6,
+    0x5eca, 0x2a392, 0x5efe, 0x22331, 0x22331, 0x8201, 0x5f22, 0x5f22, 0x38c7, 0x232b8,
+    0x261da, 0x5f62, 0x5f6b, 0x38e3, 0x5f9a, 0x5fcd, 0x5fd7, 0x5ff9, 0x6081, 0x393a,
+    0x391c, 0x6094, 0x226d4, 0x60c7, 0x6148, 0x614c, 0x614e, 0x614c, 0x617a, 0x618e,
+    0x61b2, 0x61a4, 0x61af, 0x61de, 0x61f2, 0x61f6, 0x6210, 0x621b, 0x625d, 0x62b1,
+    0x62d4, 0x6350, 0x22b0c, 0x633d, 0x62fc, 0x6368, 0x6383, 0x63e4, 0x22bf1, 0x6422,
+    0x63c5, 0x63a9, 0x3a2e, 0x6469, 0x647e, 0x649d, 0x6477, 0x3a6c, 0x654f, 0x656c,
+    0x2300a, 0x65e3, 0x66f8, 0x6649, 0x3b19, 0x6691, 0x3b08, 0x3ae4, 0x5192, 0x5195,
+    0x6700, 0x669c, 0x80ad, 0x43d9, 0x6717, 0x671b, 0x6721, 0x675e, 0x6753, 0x233c3,
+    0x3b49, 0x67fa, 0x6785, 0x6852, 0x6885, 0x2346d, 0x688e, 0x681f, 0x6914, 0x3b9d,
+    0x6942, 0x69a3, 0x69ea, 0x6aa8, 0x236a3, 0x6adb, 0x3c18, 0x6b21, 0x238a7, 0x6b54,
+    0x3c4e, 0x6b72, 0x6b9f, 0x6bba, 0x6bbb, 0x23a8d, 0x21d0b, 0x23afa, 0x6c4e, 0x23cbc,
+    0x6cbf, 0x6ccd, 0x6c67, 0x6d16, 0x6d3e, 0x6d77, 0x6d41, 0x6d69, 0x6d78, 0x6d85,
+    0x23d1e, 0x6d34, 0x6e2f, 0x6e6e, 0x3d33, 0x6ecb, 0x6ec7, 0x23ed1, 0x6df9, 0x6f6e,
+    0x23f5e, 0x23f8e, 0x6fc6, 0x7039, 0x701e, 0x701b, 0x3d96, 0x704a, 0x707d, 0x7077,
+    0x70ad, 0x20525, 0x7145, 0x24263, 0x719c, 0x243ab, 0x7228, 0x7235, 0x7250, 0x24608,
+    0x7280, 0x7295, 0x24735, 0x24814, 0x737a, 0x738b, 0x3eac, 0x73a5, 0x3eb8, 0x3eb8,
+    0x7447, 0x745c, 0x7471, 0x7485, 0x74ca, 0x3f1b, 0x7524, 0x24c36, 0x753e, 0x24c92,
+    0x7570, 0x2219f, 0x7610, 0x24fa1, 0x24fb8, 0x25044, 0x3ffc, 0x4008, 0x76f4, 0x250f3,
+    0x250f2, 0x25119, 0x25133, 0x771e, 0x771f, 0x771f, 0x774a, 0x4039, 0x778b, 0x4046,
+    0x4096, 0x2541d, 0x784e, 0x788c, 0x78cc, 0x40e3, 0x25626, 0x7956, 0x2569a, 0x256c5,
+    0x798f, 0x79eb, 0x412f, 0x7a40, 0x7a4a, 0x7a4f, 0x2597c, 0x25aa7, 0x25aa7, 0x7aee,
+    0x4202, 0x25bab, 0x7bc6, 0x7bc9, 0x4227, 0x25c80, 0x7cd2, 0x42a0, 0x7ce8, 0x7ce3,
+    0x7d00, 0x25f86, 0x7d63, 0x4301, 0x7dc7, 0x7e02, 0x7e45, 0x4334, 0x26228, 0x26247,
+    0x4359, 0x262d9, 0x7f7a, 0x2633e, 0x7f95, 0x7ffa, 0x8005, 0x264da, 0x26523, 0x8060,
+    0x265a8, 0x8070, 0x2335f, 0x43d5, 0x80b2, 0x8103, 0x440b, 0x813e, 0x5ab5, 0x267a7,
+    0x267b5, 0x23393, 0x2339c, 0x8201, 0x8204, 0x8f9e, 0x446b, 0x8291, 0x828b, 0x829d,
+    0x52b3, 0x82b1, 0x82b3, 0x82bd, 0x82e6, 0x26b3c, 0x82e5, 0x831d, 0x8363, 0x83ad,
+    0x8323, 0x83bd, 0x83e7, 0x8457, 0x8353, 0x83ca, 0x83cc, 0x83dc, 0x26c36, 0x26d6b,
+    0x26cd5, 0x452b, 0x84f1, 0x84f3, 0x8516, 0x273ca, 0x8564, 0x26f2c, 0x455d, 0x4561,
+    0x26fb1, 0x270d2, 0x456b, 0x8650, 0x865c, 0x8667, 0x8669, 0x86a9, 0x8688, 0x870e,
+    0x86e2, 0x8779, 0x8728, 0x876b, 0x8786, 0x45d7, 0x87e1, 0x8801, 0x45f9, 0x8860,
+    0x8863, 0x27667, 0x88d7, 0x88de, 0x4635, 0x88fa, 0x34bb, 0x278ae, 0x27966, 0x46be,
+    0x46c7, 0x8aa0, 0x8aed, 0x8b8a, 0x8c55, 0x27ca8, 0x8cab, 0x8cc1, 0x8d1b, 0x8d77,
+    0x27f2f, 0x20804, 0x8dcb, 0x8dbc, 0x8df0, 0x208de, 0x8ed4, 0x8f38, 0x285d2, 0x285ed,
+    0x9094, 0x90f1, 0x9111, 0x2872e, 0x911b, 0x9238, 0x92d7, 0x92d8, 0x927c, 0x93f9,
+    0x9415, 0x28bfa, 0x958b, 0x4995, 0x95b7, 0x28d77, 0x49e6, 0x96c3, 0x5db2, 0x9723,
+    0x29145, 0x2921a, 0x4a6e, 0x4a76, 0x97e0, 0x2940a, 0x4ab2, 0x29496, 0x980b, 0x980b,
+    0x9829, 0x295b6, 0x98e2, 0x4b33, 0x9929, 0x99a7, 0x99c2, 0x99fe, 0x4bce, 0x29b30,
+    0x9b12, 0x9c40, 0x9cfd, 0x4cce, 0x4ced, 0x9d67, 0x2a0ce, 0x4cf8, 0x2a105, 0x2a20e,
+    0x2a291, 0x9ebb, 0x4d56, 0x9ef9, 0x9efe, 0x9f05, 0x9f0f, 0x9f16, 0x9f3b, 0x2a600,
+];
+
+/// Characters that extend the preceding grapheme cluster as inclusive code point ranges
+pub(crate) const GRAPHEME_EXTEND: &[(u32, u32)] = &[
+    (0x300, 0x36f), (0x483, 0x489), (0x591, 0x5bd), (0x5bf, 0x5bf), (0x5c1, 0x5c2),
+    (0x5c4, 0x5c5), (0x5c7, 0x5c7), (0x610, 0x61a), (0x64b, 0x65f), (0x670, 0x670),
+    (0x6d6, 0x6dc), (0x6df, 0x6e4), (0x6e7, 0x6e8), (0x6ea, 0x6ed), (0x711, 0x711),
+    (0x730, 0x74a), (0x7a6, 0x7b0), (0x7eb, 0x7f3), (0x7fd, 0x7fd), (0x816, 0x819),
+    (0x81b, 0x823), (0x825, 0x827), (0x829, 0x82d), (0x859, 0x85b), (0x898, 0x89f),
+    (0x8ca, 0x8e1), (0x8e3, 0x903), (0x93a, 0x93c), (0x93e, 0x94f), (0x951, 0x957),
+    (0x962, 0x963), (0x981, 0x983), (0x9bc, 0x9bc), (0x9be, 0x9c4), (0x9c7, 0x9c8),
+    (0x9cb, 0x9cd), (0x9d7, 0x9d7), (0x9e2, 0x9e3), (0x9fe, 0x9fe), (0xa01, 0xa03),
+    (0xa3c, 0xa3c), (0xa3e, 0xa42), (0xa47, 0xa48), (0xa4b, 0xa4d), (0xa51, 0xa51),
+    (0xa70, 0xa71), (0xa75, 0xa75), (0xa81, 0xa83), (0xabc, 0xabc), (0xabe, 0xac5),
+    (0xac7, 0xac9), (0xacb, 0xacd), (0xae2, 0xae3), (0xafa, 0xaff), (0xb01, 0xb03),
+    (0xb3c, 0xb3c), (0xb3e, 0xb44), (0xb47, 0xb48), (0xb4b, 0xb4d), (0xb55, 0xb57),
+    (0xb62, 0xb63), (0xb82, 0xb82), (0xbbe, 0xbc2), (0xbc6, 0xbc8), (0xbca, 0xbcd),
+    (0xbd7, 0xbd7), (0xc00, 0xc04), (0xc3c, 0xc3c), (0xc3e, 0xc44), (0xc46, 0xc48),
+    (0xc4a, 0xc4d), (0xc55, 0xc56), (0xc62, 0xc63), (0xc81, 0xc83), (0xcbc, 0xcbc),
+    (0xcbe, 0xcc4), (0xcc6, 0xcc8), (0xcca, 0xccd), (0xcd5, 0xcd6), (0xce2, 0xce3),
+    (0xd00, 0xd03), (0xd3b, 0xd3c), (0xd3e, 0xd44), (0xd46, 0xd48), (0xd4a, 0xd4d),
+    (0xd57, 0xd57), (0xd62, 0xd63), (0xd81, 0xd83), (0xdca, 0xdca), (0xdcf, 0xdd4),
+    (0xdd6, 0xdd6), (0xdd8, 0xddf), (0xdf2, 0xdf3), (0xe31, 0xe31), (0xe34, 0xe3a),
+    (0xe47, 0xe4e), (0xeb1, 0xeb1), (0xeb4, 0xebc), (0xec8, 0xecd), (0xf18, 0xf19),
+    (0xf35, 0xf35), (0xf37, 0xf37), (0xf39, 0xf39), (0xf3e, 0xf3f), (0xf71, 0xf84),
+    (0xf86, 0xf87), (0xf8d, 0xf97), (0xf99, 0xfbc), (0xfc6, 0xfc6), (0x102b, 0x103e),
+    (0x1056, 0x1059), (0x105e, 0x1060), (0x1062, 0x1064), (0x1067, 0x106d), (0x1071, 0x1074),
+    (0x1082, 0x108d), (0x108f, 0x108f), (0x109a, 0x109d), (0x135d, 0x135f), (0x1712, 0x1715),
+    (0x1732, 0x1734), (0x1752, 0x1753), (0x1772, 0x1773), (0x17b4, 0x17d3), (0x17dd, 0x17dd),
+    (0x180b, 0x180d), (0x180f, 0x180f), (0x1885, 0x1886), (0x18a9, 0x18a9), (0x1920, 0x192b),
+    (0x1930, 0x193b), (0x1a17, 0x1a1b), (0x1a55, 0x1a5e), (0x1a60, 0x1a7c), (0x1a7f, 0x1a7f),
+    (0x1ab0, 0x1ace), (0x1b00, 0x1b04), (0x1b34, 0x1b44), (0x1b6b, 0x1b73), (0x1b80, 0x1b82),
+    (0x1ba1, 0x1bad), (0x1be6, 0x1bf3), (0x1c24, 0x1c37), (0x1cd0, 0x1cd2), (0x1cd4, 0x1ce8),
+    (0x1ced, 0x1ced), (0x1cf4, 0x1cf4), (0x1cf7, 0x1cf9), (0x1dc0, 0x1dff), (0x200c, 0x200d),
+    (0x20d0, 0x20f0), (0x2cef, 0x2cf1), (0x2d7f, 0x2d7f), (0x2de0, 0x2dff), (0x302a, 0x302f),
+    (0x3099, 0x309a), (0xa66f, 0xa672), (0xa674, 0xa67d), (0xa69e, 0xa69f), (0xa6f0, 0xa6f1),
+    (0xa802, 0xa802), (0xa806, 0xa806), (0xa80b, 0xa80b), (0xa823, 0xa827), (0xa82c, 0xa82c),
+    (0xa880, 0xa881), (0xa8b4, 0xa8c5), (0xa8e0, 0xa8f1), (0xa8ff, 0xa8ff), (0xa926, 0xa92d),
+    (0xa947, 0xa953), (0xa980, 0xa983), (0xa9b3, 0xa9c0), (0xa9e5, 0xa9e5), (0xaa29, 0xaa36),
+    (0xaa43, 0xaa43), (0xaa4c, 0xaa4d), (0xaa7b, 0xaa7d), (0xaab0, 0xaab0), (0xaab2, 0xaab4),
+    (0xaab7, 0xaab8), (0xaabe, 0xaabf), (0xaac1, 0xaac1), (0xaaeb, 0xaaef), (0xaaf5, 0xaaf6),
+    (0xabe3, 0xabea), (0xabec, 0xabed), (0xfb1e, 0xfb1e), (0xfe00, 0xfe0f), (0xfe20, 0xfe2f),
+    (0xff9e, 0xff9f), (0x101fd, 0x101fd), (0x102e0, 0x102e0), (0x10376, 0x1037a), (0x10a01, 0x10a03),
+    (0x10a05, 0x10a06), (0x10a0c, 0x10a0f), (0x10a38, 0x10a3a), (0x10a3f, 0x10a3f), (0x10ae5, 0x10ae6),
+    (0x10d24, 0x10d27), (0x10eab, 0x10eac), (0x10f46, 0x10f50), (0x10f82, 0x10f85), (0x11000, 0x11002),
+    (0x11038, 0x11046), (0x11070, 0x11070), (0x11073, 0x11074), (0x1107f, 0x11082), (0x110b0, 0x110ba),
+    (0x110c2, 0x110c2), (0x11100, 0x11102), (0x11127, 0x11134), (0x11145, 0x11146), (0x11173, 0x11173),
+    (0x11180, 0x11182), (0x111b3, 0x111c0), (0x111c9, 0x111cc), (0x111ce, 0x111cf), (0x1122c, 0x11237),
+    (0x1123e, 0x1123e), (0x112df, 0x112ea), (0x11300, 0x11303), (0x1133b, 0x1133c), (0x1133e, 0x11344),
+    (0x11347, 0x11348), (0x1134b, 0x1134d), (0x11357, 0x11357), (0x11362, 0x11363), (0x11366, 0x1136c),
+    (0x11370, 0x11374), (0x11435, 0x11446), (0x1145e, 0x1145e), (0x114b0, 0x114c3), (0x115af, 0x115b5),
+    (0x115b8, 0x115c0), (0x115dc, 0x115dd), (0x11630, 0x11640), (0x116ab, 0x116b7), (0x1171d, 0x1172b),
+    (0x1182c, 0x1183a), (0x11930, 0x11935), (0x11937, 0x11938), (0x1193b, 0x1193e), (0x11940, 0x11940),
+    (0x11942, 0x11943), (0x119d1, 0x119d7), (0x119da, 0x119e0), (0x119e4, 0x119e4), (0x11a01, 0x11a0a),
+    (0x11a33, 0x11a39), (0x11a3b, 0x11a3e), (0x11a47, 0x11a47), (0x11a51, 0x11a5b), (0x11a8a, 0x11a99),
+    (0x11c2f, 0x11c36), (0x11c38, 0x11c3f), (0x11c92, 0x11ca7), (0x11ca9, 0x11cb6), (0x11d31, 0x11d36),
+    (0x11d3a, 0x11d3a), (0x11d3c, 0x11d3d), (0x11d3f, 0x11d45), (0x11d47, 0x11d47), (0x11d8a, 0x11d8e),
+    (0x11d90, 0x11d91), (0x11d93, 0x11d97), (0x11ef3, 0x11ef6), (0x16af0, 0x16af4), (0x16b30, 0x16b36),
+    (0x16f4f, 0x16f4f), (0x16f51, 0x16f87), (0x16f8f, 0x16f92), (0x16fe4, 0x16fe4), (0x16ff0, 0x16ff1),
+    (0x1bc9d, 0x1bc9e), (0x1cf00, 0x1cf2d), (0x1cf30, 0x1cf46), (0x1d165, 0x1d169), (0x1d16d, 0x1d172),
+    (0x1d17b, 0x1d182), (0x1d185, 0x1d18b), (0x1d1aa, 0x1d1ad), (0x1d242, 0x1d244), (0x1da00, 0x1da36),
+    (0x1da3b, 0x1da6c), (0x1da75, 0x1da75), (0x1da84, 0x1da84), (0x1da9b, 0x1da9f), (0x1daa1, 0x1daaf),
+    (0x1e000, 0x1e006), (0x1e008, 0x1e018), (0x1e01b, 0x1e021), (0x1e023, 0x1e024), (0x1e026, 0x1e02a),
+    (0x1e130, 0x1e136), (0x1e2ae, 0x1e2ae), (0x1e2ec, 0x1e2ef), (0x1e8d0, 0x1e8d6), (0x1e944, 0x1e94a),
+    (0x1f3fb, 0x1f3ff), (0xe0020, 0xe007f), (0xe0100, 0xe01ef),
+];
+
+/// Primary composites by their canonical decomposition pair
+pub(crate) const COMPOSITIONS: &[(u32, u32, u32)] = &[
+    (0x3c, 0x338, 0x226e), (0x3d, 0x338, 0x2260), (0x3e, 0x338, 0x226f), (0x41, 0x300, 0xc0),
+    (0x41, 0x301, 0xc1), (0x41, 0x302, 0xc2), (0x41, 0x303, 0xc3), (0x41, 0x304, 0x100),
+    (0x41, 0x306, 0x102), (0x41, 0x307, 0x226), (0x41, 0x308, 0xc4), (0x41, 0x309, 0x1ea2),
+    (0x41, 0x30a, 0xc5), (0x41, 0x30c, 0x1cd), (0x41, 0x30f, 0x200), (0x41, 0x311, 0x202),
+    (0x41, 0x323, 0x1ea0), (0x41, 0x325, 0x1e00), (0x41, 0x328, 0x104), (0x42, 0x307, 0x1e02),
+    (0x42, 0x323, 0x1e04), (0x42, 0x331, 0x1e06), (0x43, 0x301, 0x106), (0x43, 0x302, 0x108),
+    (0x43, 0x307, 0x10a), (0x43, 0x30c, 0x10c), (0x43, 0x327, 0xc7), (0x44, 0x307, 0x1e0a),
+    (0x44, 0x30c, 0x10e), (0x44, 0x323, 0x1e0c), (0x44, 0x327, 0x1e10), (0x44, 0x32d, 0x1e12),
+    (0x44, 0x331, 0x1e0e), (0x45, 0x300, 0xc8), (0x45, 0x301, 0xc9), (0x45, 0x302, 0xca),
+    (0x45, 0x303, 0x1ebc), (0x45, 0x304, 0x112), (0x45, 0x306, 0x114), (0x45, 0x307, 0x116),
+    (0x45, 0x308, 0xcb), (0x45, 0x309, 0x1eba), (0x45, 0x30c, 0x11a), (0x45, 0x30f, 0x204),
+    (0x45, 0x311, 0x206), (0x45, 0x323, 0x1eb8), (0x45, 0x327, 0x228), (0x45, 0x328, 0x118),
+    (0x45, 0x32d, 0x1e18), (0x45, 0x330, 0x1e1a), (0x46, 0x307, 0x1e1e), (0x47, 0x301, 0x1f4),
+    (0x47, 0x302, 0x11c), (0x47, 0x304, 0x1e20), (0x47, 0x306, 0x11e), (0x47, 0x307, 0x120),
+    (0x47, 0x30c, 0x1e6), (0x47, 0x327, 0x122), (0x48, 0x302, 0x124), (0x48, 0x307, 0x1e22),
+    (0x48, 0x308, 0x1e26), (0x48, 0x30c, 0x21e), (0x48, 0x323, 0x1e24), (0x48, 0x327, 0x1e28),
+    (0x48, 0x32e, 0x1e2a), (0x49, 0x300, 0xcc), (0x49, 0x301, 0xcd), (0x49, 0x302, 0xce),
+    (0x49, 0x303, 0x128), (0x49, 0x304, 0x12a), (0x49, 0x306, 0x12c), (0x49, 0x307, 0x130),
+    (0x49, 0x308, 0xcf), (0x49, 0x309, 0x1ec8), (0x49, 0x30c, 0x1cf), (0x49, 0x30f, 0x208),
+    (0x49, 0x311, 0x20a), (0x49, 0x323, 0x1eca), (0x49, 0x328, 0x12e), (0x49, 0x330, 0x1e2c),
+    (0x4a, 0x302, 0x134), (0x4b, 0x301, 0x1e30), (0x4b, 0x30c, 0x1e8), (0x4b, 0x323, 0x1e32),
+    (0x4b, 0x327, 0x136), (0x4b, 0x331, 0x1e34), (0x4c, 0x301, 0x139), (0x4c, 0x30c, 0x13d),
+    (0x4c, 0x323, 0x1e36), (0x4c, 0x327, 0x13b), (0x4c, 0x32d, 0x1e3c), (0x4c, 0x331, 0x1e3a),
+    (0x4d, 0x301, 0x1e3e), (0x4d, 0x307, 0x1e40), (0x4d, 0x323, 0x1e42), (0x4e, 0x300, 0x1f8),
+    (0x4e, 0x301, 0x143), (0x4e, 0x303, 0xd1), (0x4e, 0x307, 0x1e44), (0x4e, 0x30c, 0x147),
+    (0x4e, 0x323, 0x1e46), (0x4e, 0x327, 0x145), (0x4e, 0x32d, 0x1e4a), (0x4e, 0x331, 0x1e48),
+    (0x4f, 0x300, 0xd2), (0x4f, 0x301, 0xd3), (0x4f, 0x302, 0xd4), (0x4f, 0x303, 0xd5),
+    (0x4f, 0x304, 0x14c), (0x4f, 0x306, 0x14e), (0x4f, 0x307, 0x22e), (0x4f, 0x308, 0xd6),
+    (0x4f, 0x309, 0x1ece), (0x4f, 0x30b, 0x150), (0x4f, 0x30c, 0x1d1), (0x4f, 0x30f, 0x20c),
+    (0x4f, 0x311, 0x20e), (0x4f, 0x31b, 0x1a0), (0x4f, 0x323, 0x1ecc), (0x4f, 0x328, 0x1ea),
+    (0x50, 0x301, 0x1e54), (0x50, 0x307, 0x1e56), (0x52, 0x301, 0x154), (0x52, 0x307, 0x1e58),
+    (0x52, 0x30c, 0x158), (0x52, 0x30f, 0x210), (0x52, 0x311, 0x212), (0x52, 0x323, 0x1e5a),
+    (0x52, 0x327, 0x156), (0x52, 0x331, 0x1e5e), (0x53, 0x301, 0x15a), (0x53, 0x302, 0x15c),
+    (0x53, 0x307, 0x1e60), (0x53, 0x30c, 0x160), (0x53, 0x323, 0x1e62), (0x53, 0x326, 0x218),
+    (0x53, 0x327, 0x15e), (0x54, 0x307, 0x1e6a), (0x54, 0x30c, 0x164), (0x54, 0x323, 0x1e6c),
+    (0x54, 0x326, 0x21a), (0x54, 0x327, 0x162), (0x54, 0x32d, 0x1e70), (0x54, 0x331, 0x1e6e),
+    (0x55, 0x300, 0xd9), (0x55, 0x301, 0xda), (0x55, 0x302, 0xdb), (0x55, 0x303, 0x168),
+    (0x55, 0x304, 0x16a), (0x55, 0x306, 0x16c), (0x55, 0x308, 0xdc), (0x55, 0x309, 0x1ee6),
+    (0x55, 0x30a, 0x16e), (0x55, 0x30b, 0x170), (0x55, 0x30c, 0x1d3), (0x55, 0x30f, 0x214),
+    (0x55, 0x311, 0x216), (0x55, 0x31b, 0x1af), (0x55, 0x323, 0x1ee4), (0x55, 0x324, 0x1e72),
+    (0x55, 0x328, 0x172), (0x55, 0x32d, 0x1e76), (0x55, 0x330, 0x1e74), (0x56, 0x303, 0x1e7c),
+    (0x56, 0x323, 0x1e7e), (0x57, 0x300, 0x1e80), (0x57, 0x301, 0x1e82), (0x57, 0x302, 0x174),
+    (0x57, 0x307, 0x1e86), (0x57, 0x308, 0x1e84), (0x57, 0x323, 0x1e88), (0x58, 0x307, 0x1e8a),
+    (0x58, 0x308, 0x1e8c), (0x59, 0x300, 0x1ef2), (0x59, 0x301, 0xdd), (0x59, 0x302, 0x176),
+    (0x59, 0x303, 0x1ef8), (0x59, 0x304, 0x232), (0x59, 0x307, 0x1e8e), (0x59, 0x308, 0x178),
+    (0x59, 0x309, 0x1ef6), (0x59, 0x323, 0x1ef4), (0x5a, 0x301, 0x179), (0x5a, 0x302, 0x1e90),
+    (0x5a, 0x307, 0x17b), (0x5a, 0x30c, 0x17d), (0x5a, 0x323, 0x1e92), (0x5a, 0x331, 0x1e94),
+    (0x61, 0x300, 0xe0), (0x61, 0x301, 0xe1), (0x61, 0x302, 0xe2), (0x61, 0x303, 0xe3),
+    (0x61, 0x304, 0x101), (0x61, 0x306, 0x103), (0x61, 0x307, 0x227), (0x61, 0x308, 0xe4),
+    (0x61, 0x309, 0x1ea3), (0x61, 0x30a, 0xe5), (0x61, 0x30c, 0x1ce), (0x61, 0x30f, 0x201),
+    (0x61, 0x311, 0x203), (0x61, 0x323, 0x1ea1), (0x61, 0x325, 0x1e01), (0x61, 0x328, 0x105),
+    (0x62, 0x307, 0x1e03), (0x62, 0x323, 0x1e05), (0x62, 0x331, 0x1e07), (0x63, 0x301, 0x107),
+    (0x63, 0x302, 0x109), (0x63, 0x307, 0x10b), (0x63, 0x30c, 0x10d), (0x63, 0x327, 0xe7),
+    (0x64, 0x307, 0x1e0b), (0x64, 0x30c, 0x10f), (0x64, 0x323, 0x1e0d), (0x64, 0x327, 0x1e11),
+    (0x64, 0x32d, 0x1e13), (0x64, 0x331, 0x1e0f), (0x65, 0x300, 0xe8), (0x65, 0x301, 0xe9),
+    (0x65, 0x302, 0xea), (0x65, 0x303, 0x1ebd), (0x65, 0x304, 0x113), (0x65, 0x306, 0x115),
+    (0x65, 0x307, 0x117), (0x65, 0x308, 0xeb), (0x65, 0x309, 0x1ebb), (0x65, 0x30c, 0x11b),
+    (0x65, 0x30f, 0x205), (0x65, 0x311, 0x207), (0x65, 0x323, 0x1eb9), (0x65, 0x327, 0x229),
+    (0x65, 0x328, 0x119), (0x65, 0x32d, 0x1e19), (0x65, 0x330, 0x1e1b), (0x66, 0x307, 0x1e1f),
+    (0x67, 0x301, 0x1f5), (0x67, 0x302, 0x11d), (0x67, 0x304, 0x1e21), (0x67, 0x306, 0x11f),
+    (0x67, 0x307, 0x121), (0x67, 0x30c, 0x1e7), (0x67, 0x327, 0x123), (0x68, 0x302, 0x125),
+    (0x68, 0x307, 0x1e23), (0x68, 0x308, 0x1e27), (0x68, 0x30c, 0x21f), (0x68, 0x323, 0x1e25),
+    (0x68, 0x327, 0x1e29), (0x68, 0x32e, 0x1e2b), (0x68, 0x331, 0x1e96), (0x69, 0x300, 0xec),
+    (0x69, 0x301, 0xed), (0x69, 0x302, 0xee), (0x69, 0x303, 0x129), (0x69, 0x304, 0x12b),
+    (0x69, 0x306, 0x12d), (0x69, 0x308, 0xef), (0x69, 0x309, 0x1ec9), (0x69, 0x30c, 0x1d0),
+    (0x69, 0x30f, 0x209), (0x69, 0x311, 0x20b), (0x69, 0x323, 0x1ecb), (0x69, 0x328, 0x12f),
+    (0x69, 0x330, 0x1e2d), (0x6a, 0x302, 0x135), (0x6a, 0x30c, 0x1f0), (0x6b, 0x301, 0x1e31),
+    (0x6b, 0x30c, 0x1e9), (0x6b, 0x323, 0x1e33), (0x6b, 0x327, 0x137), (0x6b, 0x331, 0x1e35),
+    (0x6c, 0x301, 0x13a), (0x6c, 0x30c, 0x13e), (0x6c, 0x323, 0x1e37), (0x6c, 0x327, 0x13c),
+    (0x6c, 0x32d, 0x1e3d), (0x6c, 0x331, 0x1e3b), (0x6d, 0x301, 0x1e3f), (0x6d, 0x307, 0x1e41),
+    (0x6d, 0x323, 0x1e43), (0x6e, 0x300, 0x1f9), (0x6e, 0x301, 0x144), (0x6e, 0x303, 0xf1),
+    (0x6e, 0x307, 0x1e45), (0x6e, 0x30c, 0x148), (0x6e, 0x323, 0x1e47), (0x6e, 0x327, 0x146),
+    (0x6e, 0x32d, 0x1e4b), (0x6e, 0x331, 0x1e49), (0x6f, 0x300, 0xf2), (0x6f, 0x301, 0xf3),
+    (0x6f, 0x302, 0xf4), (0x6f, 0x303, 0xf5), (0x6f, 0x304, 0x14d), (0x6f, 0x306, 0x14f),
+    (0x6f, 0x307, 0x22f), (0x6f, 0x308, 0xf6), (0x6f, 0x309, 0x1ecf), (0x6f, 0x30b, 0x151),
+    (0x6f, 0x30c, 0x1d2), (0x6f, 0x30f, 0x20d), (0x6f, 0x311, 0x20f), (0x6f, 0x31b, 0x1a1),
+    (0x6f, 0x323, 0x1ecd), (0x6f, 0x328, 0x1eb), (0x70, 0x301, 0x1e55), (0x70, 0x307, 0x1e57),
+    (0x72, 0x301, 0x155), (0x72, 0x307, 0x1e59), (0x72, 0x30c, 0x159), (0x72, 0x30f, 0x211),
+    (0x72, 0x311, 0x213), (0x72, 0x323, 0x1e5b), (0x72, 0x327, 0x157), (0x72, 0x331, 0x1e5f),
+    (0x73, 0x301, 0x15b), (0x73, 0x302, 0x15d), (0x73, 0x307, 0x1e61), (0x73, 0x30c, 0x161),
+    (0x73, 0x323, 0x1e63), (0x73, 0x326, 0x219), (0x73, 0x327, 0x15f), (0x74, 0x307, 0x1e6b),
+    (0x74, 0x308, 0x1e97), (0x74, 0x30c, 0x165), (0x74, 0x323, 0x1e6d), (0x74, 0x326, 0x21b),
+    (0x74, 0x327, 0x163), (0x74, 0x32d, 0x1e71), (0x74, 0x331, 0x1e6f), (0x75, 0x300, 0xf9),
+    (0x75, 0x301, 0xfa), (0x75, 0x302, 0xfb), (0x75, 0x303, 0x169), (0x75, 0x304, 0x16b),
+    (0x75, 0x306, 0x16d), (0x75, 0x308, 0xfc), (0x75, 0x309, 0x1ee7), (0x75, 0x30a, 0x16f),
+    (0x75, 0x30b, 0x171), (0x75, 0x30c, 0x1d4), (0x75, 0x30f, 0x215), (0x75, 0x311, 0x217),
+    (0x75, 0x31b, 0x1b0), (0x75, 0x323, 0x1ee5), (0x75, 0x324, 0x1e73), (0x75, 0x328, 0x173),
+    (0x75, 0x32d, 0x1e77), (0x75, 0x330, 0x1e75), (0x76, 0x303, 0x1e7d), (0x76, 0x323, 0x1e7f),
+    (0x77, 0x300, 0x1e81), (0x77, 0x301, 0x1e83), (0x77, 0x302, 0x175), (0x77, 0x307, 0x1e87),
+    (0x77, 0x308, 0x1e85), (0x77, 0x30a, 0x1e98), (0x77, 0x323, 0x1e89), (0x78, 0x307, 0x1e8b),
+    (0x78, 0x308, 0x1e8d), (0x79, 0x300, 0x1ef3), (0x79, 0x301, 0xfd), (0x79, 0x302, 0x177),
+    (0x79, 0x303, 0x1ef9), (0x79, 0x304, 0x233), (0x79, 0x307, 0x1e8f), (0x79, 0x308, 0xff),
+    (0x79, 0x309, 0x1ef7), (0x79, 0x30a, 0x1e99), (0x79, 0x323, 0x1ef5), (0x7a, 0x301, 0x17a),
+    (0x7a, 0x302, 0x1e91), (0x7a, 0x307, 0x17c), (0x7a, 0x30c, 0x17e), (0x7a, 0x323, 0x1e93),
+    (0x7a, 0x331, 0x1e95), (0xa8, 0x300, 0x1fed), (0xa8, 0x301, 0x385), (0xa8, 0x342, 0x1fc1),
+    (0xc2, 0x300, 0x1ea6), (0xc2, 0x301, 0x1ea4), (0xc2, 0x303, 0x1eaa), (0xc2, 0x309, 0x1ea8),
+    (0xc4, 0x304, 0x1de), (0xc5, 0x301, 0x1fa), (0xc6, 0x301, 0x1fc), (0xc6, 0x304, 0x1e2),
+    (0xc7, 0x301, 0x1e08), (0xca, 0x300, 0x1ec0), (0xca, 0x301, 0x1ebe), (0xca, 0x303, 0x1ec4),
+    (0xca, 0x309, 0x1ec2), (0xcf, 0x301, 0x1e2e), (0xd4, 0x300, 0x1ed2), (0xd4, 0x301, 0x1ed0),
+    (0xd4, 0x303, 0x1ed6), (0xd4, 0x309, 0x1ed4), (0xd5, 0x301, 0x1e4c), (0xd5, 0x304, 0x22c),
+    (0xd5, 0x308, 0x1e4e), (0xd6, 0x304, 0x22a), (0xd8, 0x301, 0x1fe), (0xdc, 0x300, 0x1db),
+    (0xdc, 0x301, 0x1d7), (0xdc, 0x304, 0x1d5), (0xdc, 0x30c, 0x1d9), (0xe2, 0x300, 0x1ea7),
+    (0xe2, 0x301, 0x1ea5), (0xe2, 0x303, 0x1eab), (0xe2, 0x309, 0x1ea9), (0xe4, 0x304, 0x1df),
+    (0xe5, 0x301, 0x1fb), (0xe6, 0x301, 0x1fd), (0xe6, 0x304, 0x1e3), (0xe7, 0x301, 0x1e09),
+    (0xea, 0x300, 0x1ec1), (0xea, 0x301, 0x1ebf), (0xea, 0x303, 0x1ec5), (0xea, 0x309, 0x1ec3),
+    (0xef, 0x301, 0x1e2f), (0xf4, 0x300, 0x1ed3), (0xf4, 0x301, 0x1ed1), (0xf4, 0x303, 0x1ed7),
+    (0xf4, 0x309, 0x1ed5), (0xf5, 0x301, 0x1e4d), (0xf5, 0x304, 0x22d), (0xf5, 0x308, 0x1e4f),
+    (0xf6, 0x304, 0x22b), (0xf8, 0x301, 0x1ff), (0xfc, 0x300, 0x1dc), (0xfc, 0x301, 0x1d8),
+    (0xfc, 0x304, 0x1d6), (0xfc, 0x30c, 0x1da), (0x102, 0x300, 0x1eb0), (0x102, 0x301, 0x1eae),
+    (0x102, 0x303, 0x1eb4), (0x102, 0x309, 0x1eb2), (0x103, 0x300, 0x1eb1), (0x103, 0x301, 0x1eaf),
+    (0x103, 0x303, 0x1eb5), (0x103, 0x309, 0x1eb3), (0x112, 0x300, 0x1e14), (0x112, 0x301, 0x1e16),
+    (0x113, 0x300, 0x1e15), (0x113, 0x301, 0x1e17), (0x14c, 0x300, 0x1e50), (0x14c, 0x301, 0x1e52),
+    (0x14d, 0x300, 0x1e51), (0x14d, 0x301, 0x1e53), (0x15a, 0x307, 0x1e64), (0x15b, 0x307, 0x1e65),
+    (0x160, 0x307, 0x1e66), (0x161, 0x307, 0x1e67), (0x168, 0x301, 0x1e78), (0x169, 0x301, 0x1e79),
+    (0x16a, 0x308, 0x1e7a), (0x16b, 0x308, 0x1e7b), (0x17f, 0x307, 0x1e9b), (0x1a0, 0x300, 0x1edc),
+    (0x1a0, 0x301, 0x1eda), (0x1a0, 0x303, 0x1ee0), (0x1a0, 0x309, 0x1ede), (0x1a0, 0x323, 0x1ee2),
+    (0x1a1, 0x300, 0x1edd), (0x1a1, 0x301, 0x1edb), (0x1a1, 0x303, 0x1ee1), (0x1a1, 0x309, 0x1edf),
+    (0x1a1, 0x323, 0x1ee3), (0x1af, 0x300, 0x1eea), (0x1af, 0x301, 0x1ee8), (0x1af, 0x303, 0x1eee),
+    (0x1af, 0x309, 0x1eec), (0x1af, 0x323, 0x1ef0), (0x1b0, 0x300, 0x1eeb), (0x1b0, 0x301, 0x1ee9),
+    (0x1b0, 0x303, 0x1eef), (0x1b0, 0x309, 0x1eed), (0x1b0, 0x323, 0x1ef1), (0x1b7, 0x30c, 0x1ee),
+    (0x1ea, 0x304, 0x1ec), (0x1eb, 0x304, 0x1ed), (0x226, 0x304, 0x1e0), (0x227, 0x304, 0x1e1),
+    (0x228, 0x306, 0x1e1c), (0x229, 0x306, 0x1e1d), (0x22e, 0x304, 0x230), (0x22f, 0x304, 0x231),
+    (0x292, 0x30c, 0x1ef), (0x391, 0x300, 0x1fba), (0x391, 0x301, 0x386), (0x391, 0x304, 0x1fb9),
+    (0x391, 0x306, 0x1fb8), (0x391, 0x313, 0x1f08), (0x391, 0x314, 0x1f09), (0x391, 0x345, 0x1fbc),
+    (0x395, 0x300, 0x1fc8), (0x395, 0x301, 0x388), (0x395, 0x313, 0x1f18), (0x395, 0x314, 0x1f19),
+    (0x397, 0x300, 0x1fca), (0x397, 0x301, 0x389), (0x397, 0x313, 0x1f28), (0x397, 0x314, 0x1f29),
+    (0x397, 0x345, 0x1fcc), (0x399, 0x300, 0x1fda), (0x399, 0x301, 0x38a), (0x399, 0x304, 0x1fd9),
+    (0x399, 0x306, 0x1fd8), (0x399, 0x308, 0x3aa), (0x399, 0x313, 0x1f38), (0x399, 0x314, 0x1f39),
+    (0x39f, 0x300, 0x1ff8), (0x39f, 0x301, 0x38c), (0x39f, 0x313, 0x1f48), (0x39f, 0x314, 0x1f49),
+    (0x3a1, 0x314, 0x1fec), (0x3a5, 0x300, 0x1fea), (0x3a5, 0x301, 0x38e), (0x3a5, 0x304, 0x1fe9),
+    (0x3a5, 0x306, 0x1fe8), (0x3a5, 0x308, 0x3ab), (0x3a5, 0x314, 0x1f59), (0x3a9, 0x300, 0x1ffa),
+    (0x3a9, 0x301, 0x38f), (0x3a9, 0x313, 0x1f68), (0x3a9, 0x314, 0x1f69), (0x3a9, 0x345, 0x1ffc),
+    (0x3ac, 0x345, 0x1fb4), (0x3ae, 0x345, 0x1fc4), (0x3b1, 0x300, 0x1f70), (0x3b1, 0x301, 0x3ac),
+    (0x3b1, 0x304, 0x1fb1), (0x3b1, 0x306, 0x1fb0), (0x3b1, 0x313, 0x1f00), (0x3b1, 0x314, 0x1f01),
+    (0x3b1, 0x342, 0x1fb6), (0x3b1, 0x345, 0x1fb3), (0x3b5, 0x300, 0x1f72), (0x3b5, 0x301, 0x3ad),
+    (0x3b5, 0x313, 0x1f10), (0x3b5, 0x314, 0x1f11), (0x3b7, 0x300, 0x1f74), (0x3b7, 0x301, 0x3ae),
+    (0x3b7, 0x313, 0x1f20), (0x3b7, 0x314, 0x1f21), (0x3b7, 0x342, 0x1fc6), (0x3b7, 0x345, 0x1fc3),
+    (0x3b9, 0x300, 0x1f76), (0x3b9, 0x301, 0x3af), (0x3b9, 0x304, 0x1fd1), (0x3b9, 0x306, 0x1fd0),
+    (0x3b9, 0x308, 0x3ca), (0x3b9, 0x313, 0x1f30), (0x3b9, 0x314, 0x1f31), (0x3b9, 0x342, 0x1fd6),
+    (0x3bf, 0x300, 0x1f78), (0x3bf, 0x301, 0x3cc), (0x3bf, 0x313, 0x1f40), (0x3bf, 0x314, 0x1f41),
+    (0x3c1, 0x313, 0x1fe4), (0x3c1, 0x314, 0x1fe5), (0x3c5, 0x300, 0x1f7a), (0x3c5, 0x301, 0x3cd),
+    (0x3c5, 0x304, 0x1fe1), (0x3c5, 0x306, 0x1fe0), (0x3c5, 0x308, 0x3cb), (0x3c5, 0x313, 0x1f50),
+    (0x3c5, 0x314, 0x1f51), (0x3c5, 0x342, 0x1fe6), (0x3c9, 0x300, 0x1f7c), (0x3c9, 0x301, 0x3ce),
+    (0x3c9, 0x313, 0x1f60), (0x3c9, 0x314, 0x1f61), (0x3c9, 0x342, 0x1ff6), (0x3c9, 0x345, 0x1ff3),
+    (0x3ca, 0x300, 0x1fd2), (0x3ca, 0x301, 0x390), (0x3ca, 0x342, 0x1fd7), (0x3cb, 0x300, 0x1fe2),
+    (0x3cb, 0x301, 0x3b0), (0x3cb, 0x342, 0x1fe7), (0x3ce, 0x345, 0x1ff4), (0x3d2, 0x301, 0x3d3),
+    (0x3d2, 0x308, 0x3d4), (0x406, 0x308, 0x407), (0x410, 0x306, 0x4d0), (0x410, 0x308, 0x4d2),
+    (0x413, 0x301, 0x403), (0x415, 0x300, 0x400), (0x415, 0x306, 0x4d6), (0x415, 0x308, 0x401),
+    (0x416, 0x306, 0x4c1), (0x416, 0x308, 0x4dc), (0x417, 0x308, 0x4de), (0x418, 0x300, 0x40d),
+    (0x418, 0x304, 0x4e2), (0x418, 0x306, 0x419), (0x418, 0x308, 0x4e4), (0x41a, 0x301, 0x40c),
+    (0x41e, 0x308, 0x4e6), (0x423, 0x304, 0x4ee), (0x423, 0x306, 0x40e), (0x423, 0x308, 0x4f0),
+    (0x423, 0x30b, 0x4f2), (0x427, 0x308, 0x4f4), (0x42b, 0x308, 0x4f8), (0x42d, 0x308, 0x4ec),
+    (0x430, 0x306, 0x4d1), (0x430, 0x308, 0x4d3), (0x433, 0x301, 0x453), (0x435, 0x300, 0x450),
+    (0x435, 0x306, 0x4d7), (0x435, 0x308, 0x451), (0x436, 0x306, 0x4c2), (0x436, 0x308, 0x4dd),
+    (0x437, 0x308, 0x4df), (0x438, 0x300, 0x45d), (0x438, 0x304, 0x4e3), (0x438, 0x306, 0x439),
+    (0x438, 0x308, 0x4e5), (0x43a, 0x301, 0x45c), (0x43e, 0x308, 0x4e7), (0x443, 0x304, 0x4ef),
+    (0x443, 0x306, 0x45e), (0x443, 0x308, 0x4f1), (0x443, 0x30b, 0x4f3), (0x447, 0x308, 0x4f5),
+    (0x44b, 0x308, 0x4f9), (0x44d, 0x308, 0x4ed), (0x456, 0x308, 0x457), (0x474, 0x30f, 0x476),
+    (0x475, 0x30f, 0x477), (0x4d8, 0x308, 0x4da), (0x4d9, 0x308, 0x4db), (0x4e8, 0x308, 0x4ea),
+    (0x4e9, 0x308, 0x4eb), (0x627, 0x653, 0x622), (0x627, 0x654, 0x623), (0x627, 0x655, 0x625),
+    (0x648, 0x654, 0x624), (0x64a, 0x654, 0x626), (0x6c1, 0x654, 0x6c2), (0x6d2, 0x654, 0x6d3),
+    (0x6d5, 0x654, 0x6c0), (0x928, 0x93c, 0x929), (0x930, 0x93c, 0x931), (0x933, 0x93c, 0x934),
+    (0x9c7, 0x9be, 0x9cb), (0x9c7, 0x9d7, 0x9cc), (0xb47, 0xb3e, 0xb4b), (0xb47, 0xb56, 0xb48),
+    (0xb47, 0xb57, 0xb4c), (0xb92, 0xbd7, 0xb94), (0xbc6, 0xbbe, 0xbca), (0xbc6, 0xbd7, 0xbcc),
+    (0xbc7, 0xbbe, 0xbcb), (0xc46, 0xc56, 0xc48), (0xcbf, 0xcd5, 0xcc0), (0xcc6, 0xcc2, 0xcca),
+    (0xcc6, 0xcd5, 0xcc7), (0xcc6, 0xcd6, 0xcc8), (0xcca, 0xcd5, 0xccb), (0xd46, 0xd3e, 0xd4a),
+    (0xd46, 0xd57, 0xd4c), (0xd47, 0xd3e, 0xd4b), (0xdd9, 0xdca, 0xdda), (0xdd9, 0xdcf, 0xddc),
+    (0xdd9, 0xddf, 0xdde), (0xddc, 0xdca, 0xddd), (0x1025, 0x102e, 0x1026), (0x1b05, 0x1b35, 0x1b06),
+    (0x1b07, 0x1b35, 0x1b08), (0x1b09, 0x1b35, 0x1b0a), (0x1b0b, 0x1b35, 0x1b0c), (0x1b0d, 0x1b35, 0x1b0e),
+    (0x1b11, 0x1b35, 0x1b12), (0x1b3a, 0x1b35, 0x1b3b), (0x1b3c, 0x1b35, 0x1b3d), (0x1b3e, 0x1b35, 0x1b40),
+    (0x1b3f, 0x1b35, 0x1b41), (0x1b42, 0x1b35, 0x1b43), (0x1e36, 0x304, 0x1e38), (0x1e37, 0x304, 0x1e39),
+    (0x1e5a, 0x304, 0x1e5c), (0x1e5b, 0x304, 0x1e5d), (0x1e62, 0x307, 0x1e68), (0x1e63, 0x307, 0x1e69),
+    (0x1ea0, 0x302, 0x1eac), (0x1ea0, 0x306, 0x1eb6), (0x1ea1, 0x302, 0x1ead), (0x1ea1, 0x306, 0x1eb7),
+    (0x1eb8, 0x302, 0x1ec6), (0x1eb9, 0x302, 0x1ec7), (0x1ecc, 0x302, 0x1ed8), (0x1ecd, 0x302, 0x1ed9),
+    (0x1f00, 0x300, 0x1f02), (0x1f00, 0x301, 0x1f04), (0x1f00, 0x342, 0x1f06), (0x1f00, 0x345, 0x1f80),
+    (0x1f01, 0x300, 0x1f03), (0x1f01, 0x301, 0x1f05), (0x1f01, 0x342, 0x1f07), (0x1f01, 0x345, 0x1f81),
+    (0x1f02, 0x345, 0x1f82), (0x1f03, 0x345, 0x1f83), (0x1f04, 0x345, 0x1f84), (0x1f05, 0x345, 0x1f85),
+    (0x1f06, 0x345, 0x1f86), (0x1f07, 0x345, 0x1f87), (0x1f08, 0x300, 0x1f0a), (0x1f08, 0x301, 0x1f0c),
+    (0x1f08, 0x342, 0x1f0e), (0x1f08, 0x345, 0x1f88), (0x1f09, 0x300, 0x1f0b), (0x1f09, 0x301, 0x1f0d),
+    (0x1f09, 0x342, 0x1f0f), (0x1f09, 0x345, 0x1f89), (0x1f0a, 0x345, 0x1f8a), (0x1f0b, 0x345, 0x1f8b),
+    (0x1f0c, 0x345, 0x1f8c), (0x1f0d, 0x345, 0x1f8d), (0x1f0e, 0x345, 0x1f8e), (0x1f0f, 0x345, 0x1f8f),
+    (0x1f10, 0x300, 0x1f12), (0x1f10, 0x301, 0x1f14), (0x1f11, 0x300, 0x1f13), (0x1f11, 0x301, 0x1f15),
+    (0x1f18, 0x300, 0x1f1a), (0x1f18, 0x301, 0x1f1c), (0x1f19, 0x300, 0x1f1b), (0x1f19, 0x301, 0x1f1d),
+    (0x1f20, 0x300, 0x1f22), (0x1f20, 0x301, 0x1f24), (0x1f20, 0x342, 0x1f26), (0x1f20, 0x345, 0x1f90),
+    (0x1f21, 0x300, 0x1f23), (0x1f21, 0x301, 0x1f25), (0x1f21, 0x342, 0x1f27), (0x1f21, 0x345, 0x1f91),
+    (0x1f22, 0x345, 0x1f92), (0x1f23, 0x345, 0x1f93), (0x1f24, 0x345, 0x1f94), (0x1f25, 0x345, 0x1f95),
+    (0x1f26, 0x345, 0x1f96), (0x1f27, 0x345, 0x1f97), (0x1f28, 0x300, 0x1f2a), (0x1f28, 0x301, 0x1f2c),
+    (0x1f28, 0x342, 0x1f2e), (0x1f28, 0x345, 0x1f98), (0x1f29, 0x300, 0x1f2b), (0x1f29, 0x301, 0x1f2d),
+    (0x1f29, 0x342, 0x1f2f), (0x1f29, 0x345, 0x1f99), (0x1f2a, 0x345, 0x1f9a), (0x1f2b, 0x345, 0x1f9b),
+    (0x1f2c, 0x345, 0x1f9c), (0x1f2d, 0x345, 0x1f9d), (0x1f2e, 0x345, 0x1f9e), (0x1f2f, 0x345, 0x1f9f),
+    (0x1f30, 0x300, 0x1f32), (0x1f30, 0x301, 0x1f34), (0x1f30, 0x342, 0x1f36), (0x1f31, 0x300, 0x1f33),
+    (0x1f31, 0x301, 0x1f35), (0x1f31, 0x342, 0x1f37), (0x1f38, 0x300, 0x1f3a), (0x1f38, 0x301, 0x1f3c),
+    (0x1f38, 0x342, 0x1f3e), (0x1f39, 0x300, 0x1f3b), (0x1f39, 0x301, 0x1f3d), (0x1f39, 0x342, 0x1f3f),
+    (0x1f40, 0x300, 0x1f42), (0x1f40, 0x301, 0x1f44), (0x1f41, 0x300, 0x1f43), (0x1f41, 0x301, 0x1f45),
+    (0x1f48, 0x300, 0x1f4a), (0x1f48, 0x301, 0x1f4c), (0x1f49, 0x300, 0x1f4b), (0x1f49, 0x301, 0x1f4d),
+    (0x1f50, 0x300, 0x1f52), (0x1f50, 0x301, 0x1f54), (0x1f50, 0x342, 0x1f56), (0x1f51, 0x300, 0x1f53),
+    (0x1f51, 0x301, 0x1f55), (0x1f51, 0x342, 0x1f57), (0x1f59, 0x300, 0x1f5b), (0x1f59, 0x301, 0x1f5d),
+    (0x1f59, 0x342, 0x1f5f), (0x1f60, 0x300, 0x1f62), (0x1f60, 0x301, 0x1f64), (0x1f60, 0x342, 0x1f66),
+    (0x1f60, 0x345, 0x1fa0), (0x1f61, 0x300, 0x1f63), (0x1f61, 0x301, 0x1f65), (0x1f61, 0x342, 0x1f67),
+    (0x1f61, 0x345, 0x1fa1), (0x1f62, 0x345, 0x1fa2), (0x1f63, 0x345, 0x1fa3), (0x1f64, 0x345, 0x1fa4),
+    (0x1f65, 0x345, 0x1fa5), (0x1f66, 0x345, 0x1fa6), (0x1f67, 0x345, 0x1fa7), (0x1f68, 0x300, 0x1f6a),
+    (0x1f68, 0x301, 0x1f6c), (0x1f68, 0x342, 0x1f6e), (0x1f68, 0x345, 0x1fa8), (0x1f69, 0x300, 0x1f6b),
+    (0x1f69, 0x301, 0x1f6d), (0x1f69, 0x342, 0x1f6f), (0x1f69, 0x345, 0x1fa9), (0x1f6a, 0x345, 0x1faa),
+    (0x1f6b, 0x345, 0x1fab), (0x1f6c, 0x345, 0x1fac), (0x1f6d, 0x345, 0x1fad), (0x1f6e, 0x345, 0x1fae),
+    (0x1f6f, 0x345, 0x1faf), (0x1f70, 0x345, 0x1fb2), (0x1f74, 0x345, 0x1fc2), (0x1f7c, 0x345, 0x1ff2),
+    (0x1fb6, 0x345, 0x1fb7), (0x1fbf, 0x300, 0x1fcd), (0x1fbf, 0x301, 0x1fce), (0x1fbf, 0x342, 0x1fcf),
+    (0x1fc6, 0x345, 0x1fc7), (0x1ff6, 0x345, 0x1ff7), (0x1ffe, 0x300, 0x1fdd), (0x1ffe, 0x301, 0x1fde),
+    (0x1ffe, 0x342, 0x1fdf), (0x2190, 0x338, 0x219a), (0x2192, 0x338, 0x219b), (0x2194, 0x338, 0x21ae),
+    (0x21d0, 0x338, 0x21cd), (0x21d2, 0x338, 0x21cf), (0x21d4, 0x338, 0x21ce), (0x2203, 0x338, 0x2204),
+    (0x2208, 0x338, 0x2209), (0x220b, 0x338, 0x220c), (0x2223, 0x338, 0x2224), (0x2225, 0x338, 0x2226),
+    (0x223c, 0x338, 0x2241), (0x2243, 0x338, 0x2244), (0x2245, 0x338, 0x2247), (0x2248, 0x338, 0x2249),
+    (0x224d, 0x338, 0x226d), (0x2261, 0x338, 0x2262), (0x2264, 0x338, 0x2270), (0x2265, 0x338, 0x2271),
+    (0x2272, 0x338, 0x2274), (0x2273, 0x338, 0x2275), (0x2276, 0x338, 0x2278), (0x2277, 0x338, 0x2279),
+    (0x227a, 0x338, 0x2280), (0x227b, 0x338, 0x2281), (0x227c, 0x338, 0x22e0), (0x227d, 0x338, 0x22e1),
+    (0x2282, 0x338, 0x2284), (0x2283, 0x338, 0x2285), (0x2286, 0x338, 0x2288), (0x2287, 0x338, 0x2289),
+    (0x2291, 0x338, 0x22e2), (0x2292, 0x338, 0x22e3), (0x22a2, 0x338, 0x22ac), (0x22a8, 0x338, 0x22ad),
+    (0x22a9, 0x338, 0x22ae), (0x22ab, 0x338, 0x22af), (0x22b2, 0x338, 0x22ea), (0x22b3, 0x338, 0x22eb),
+    (0x22b4, 0x338, 0x22ec), (0x22b5, 0x338, 0x22ed), (0x3046, 0x3099, 0x3094), (0x304b, 0x3099, 0x304c),
+    (0x304d, 0x3099, 0x304e), (0x304f, 0x3099, 0x3050), (0x3051, 0x3099, 0x3052), (0x3053, 0x3099, 0x3054),
+    (0x3055, 0x3099, 0x3056), (0x3057, 0x3099, 0x3058), (0x3059, 0x3099, 0x305a), (0x305b, 0x3099, 0x305c),
+    (0x305d, 0x3099, 0x305e), (0x305f, 0x3099, 0x3060), (0x3061, 0x3099, 0x3062), (0x3064, 0x3099, 0x3065),
+    (0x3066, 0x3099, 0x3067), (0x3068, 0x3099, 0x3069), (0x306f, 0x3099, 0x3070), (0x306f, 0x309a, 0x3071),
+    (0x3072, 0x3099, 0x3073), (0x3072, 0x309a, 0x3074), (0x3075, 0x3099, 0x3076), (0x3075, 0x309a, 0x3077),
+    (0x3078, 0x3099, 0x3079), (0x3078, 0x309a, 0x307a), (0x307b, 0x3099, 0x307c), (0x307b, 0x309a, 0x307d),
+    (0x309d, 0x3099, 0x309e), (0x30a6, 0x3099, 0x30f4), (0x30ab, 0x3099, 0x30ac), (0x30ad, 0x3099, 0x30ae),
+    (0x30af, 0x3099, 0x30b0), (0x30b1, 0x3099, 0x30b2), (0x30b3, 0x3099, 0x30b4), (0x30b5, 0x3099, 0x30b6),
+    (0x30b7, 0x3099, 0x30b8), (0x30b9, 0x3099, 0x30ba), (0x30bb, 0x3099, 0x30bc), (0x30bd, 0x3099, 0x30be),
+    (0x30bf, 0x3099, 0x30c0), (0x30c1, 0x3099, 0x30c2), (0x30c4, 0x3099, 0x30c5), (0x30c6, 0x3099, 0x30c7),
+    (0x30c8, 0x3099, 0x30c9), (0x30cf, 0x3099, 0x30d0), (0x30cf, 0x309a, 0x30d1), (0x30d2, 0x3099, 0x30d3),
+    (0x30d2, 0x309a, 0x30d4), (0x30d5, 0x3099, 0x30d6), (0x30d5, 0x309a, 0x30d7), (0x30d8, 0x3099, 0x30d9),
+    (0x30d8, 0x309a, 0x30da), (0x30db, 0x3099, 0x30dc), (0x30db, 0x309a, 0x30dd), (0x30ef, 0x3099, 0x30f7),
+    (0x30f0, 0x3099, 0x30f8), (0x30f1, 0x3099, 0x30f9), (0x30f2, 0x3099, 0x30fa), (0x30fd, 0x3099, 0x30fe),
+    (0x11099, 0x110ba, 0x1109a), (0x1109b, 0x110ba, 0x1109c), (0x110a5, 0x110ba, 0x110ab), (0x11131, 0x11127, 0x1112e),
+    (0x11132, 0x11127, 0x1112f), (0x11347, 0x1133e, 0x1134b), (0x11347, 0x11357, 0x1134c), (0x114b9, 0x114b0, 0x114bc),
+    (0x114b9, 0x114ba, 0x114bb), (0x114b9, 0x114bd, 0x114be), (0x115b8, 0x115af, 0x115ba), (0x115b9, 0x115af, 0x115bb),
+    (0x11935, 0x11930, 0x11938),
+];
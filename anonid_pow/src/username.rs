@@ -0,0 +1,259 @@
+use crate::{canonicalize_username, username_skeleton, UserData, UsernameError};
+
+/// Group of characters a `UsernamePolicy` can allow
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CharacterClass {
+    /// a-z
+    AsciiLowercase,
+    /// A-Z
+    AsciiUppercase,
+    /// 0-9
+    AsciiDigit,
+    /// Any Unicode letter, including the ASCII ones
+    Alphabetic,
+    /// Any Unicode number, including the ASCII digits
+    Numeric,
+}
+
+impl CharacterClass {
+    fn contains(&self, character: char) -> bool {
+        match self {
+            CharacterClass::AsciiLowercase => character.is_ascii_lowercase(),
+            CharacterClass::AsciiUppercase => character.is_ascii_uppercase(),
+            CharacterClass::AsciiDigit => character.is_ascii_digit(),
+            CharacterClass::Alphabetic => character.is_alphabetic(),
+            CharacterClass::Numeric => character.is_numeric(),
+        }
+    }
+}
+
+/// Rules a username has to follow, shared by every node so they reject the same names
+///
+/// The length is counted in characters. ':' separates the fields of the merged userdata and
+/// is rejected even if `allowed_symbols` contains it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UsernamePolicy {
+    pub min_length: usize,
+    pub max_length: usize,
+    /// Classes of characters allowed anywhere in the username
+    pub allowed_classes: Vec<CharacterClass>,
+    /// Characters allowed in addition to `allowed_classes`
+    pub allowed_symbols: String,
+    /// Characters the username can't start or end with
+    pub forbidden_edges: String,
+    /// Names that can't be registered, compared ignoring ASCII case, or by their skeleton
+    /// if `canonicalize` is set
+    pub reserved_names: Vec<String>,
+    /// Requires usernames in the canonical form returned by `canonicalize_username`
+    pub canonicalize: bool,
+}
+
+impl Default for UsernamePolicy {
+    /// 1 to 64 ASCII letters, digits, '_', '-', and '.', with the symbols not allowed at
+    /// either end
+    fn default() -> UsernamePolicy {
+        UsernamePolicy {
+            min_length: 1,
+            max_length: 64,
+            allowed_classes: vec![
+                CharacterClass::AsciiLowercase,
+                CharacterClass::AsciiUppercase,
+                CharacterClass::AsciiDigit,
+            ],
+            allowed_symbols: "_-.".to_string(),
+            forbidden_edges: "_-.".to_string(),
+            reserved_names: Vec::new(),
+            canonicalize: false,
+        }
+    }
+}
+
+impl UsernamePolicy {
+    /// Checks the username against the policy
+    pub fn validate(&self, username: &str) -> Result<(), UsernameError> {
+        if self.canonicalize && canonicalize_username(username) != username {
+            return Err(UsernameError::NotCanonical);
+        }
+
+        let length = username.chars().count();
+        if length < self.min_length {
+            return Err(UsernameError::TooShort {
+                length,
+                min: self.min_length,
+            });
+        }
+        if length > self.max_length {
+            return Err(UsernameError::TooLong {
+                length,
+                max: self.max_length,
+            });
+        }
+
+        let forbidden = username.chars().find(|character| {
+            *character == ':'
+                || !(self.allowed_symbols.contains(*character)
+                    || self
+                        .allowed_classes
+                        .iter()
+                        .any(|class| class.contains(*character)))
+        });
+        if let Some(character) = forbidden {
+            return Err(UsernameError::ForbiddenCharacter(character));
+        }
+
+        for edge in [username.chars().next(), username.chars().next_back()]
+            .into_iter()
+            .flatten()
+        {
+            if self.forbidden_edges.contains(edge) {
+                return Err(UsernameError::ForbiddenEdge(edge));
+            }
+        }
+
+        let skeleton = self.canonicalize.then(|| username_skeleton(username));
+        if let Some(reserved) = self.reserved_names.iter().find(|reserved| match &skeleton {
+            Some(skeleton) => username_skeleton(reserved) == *skeleton,
+            None => reserved.eq_ignore_ascii_case(username),
+        }) {
+            return Err(UsernameError::Reserved(reserved.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+impl UserData {
+    /// Initializes a UserData struct with a username checked against the policy
+    ///
+    /// If the policy requires canonical usernames the username is canonicalized first.
+    pub fn new_validated(
+        username: String,
+        auth_address: String,
+        policy: &UsernamePolicy,
+    ) -> Result<UserData, UsernameError> {
+        let userdata = if policy.canonicalize {
+            UserData::new_canonical(username, auth_address)
+        } else {
+            UserData::new(username, auth_address)
+        };
+
+        policy.validate(userdata.username())?;
+
+        Ok(userdata)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CharacterClass, UsernamePolicy};
+    use crate::{UserData, UsernameError};
+
+    #[test]
+    fn test_username_policy() {
+        let policy = UsernamePolicy {
+            reserved_names: vec!["admin".to_string()],
+            ..UsernamePolicy::default()
+        };
+
+        for username in ["zeronet_user", "a", "Zero.Net-1", &"x".repeat(64)] {
+            assert_eq!(Ok(()), policy.validate(username), "{username}");
+        }
+
+        for (username, error) in [
+            ("", UsernameError::TooShort { length: 0, min: 1 }),
+            (
+                &"x".repeat(65),
+                UsernameError::TooLong {
+                    length: 65,
+                    max: 64,
+                },
+            ),
+            ("zero net", UsernameError::ForbiddenCharacter(' ')),
+            ("zero:net", UsernameError::ForbiddenCharacter(':')),
+            ("zerönet", UsernameError::ForbiddenCharacter('ö')),
+            ("_zeronet", UsernameError::ForbiddenEdge('_')),
+            ("zeronet.", UsernameError::ForbiddenEdge('.')),
+            ("Admin", UsernameError::Reserved("admin".to_string())),
+        ] {
+            assert_eq!(Err(error), policy.validate(username), "{username}");
+        }
+    }
+
+    #[test]
+    fn test_username_policy_unicode() {
+        let policy = UsernamePolicy {
+            min_length: 3,
+            max_length: 5,
+            allowed_classes: vec![CharacterClass::Alphabetic, CharacterClass::Numeric],
+            allowed_symbols: ":".to_string(),
+            forbidden_edges: String::new(),
+            reserved_names: Vec::new(),
+            canonicalize: false,
+        };
+
+        assert_eq!(Ok(()), policy.validate("zerö٣"));
+        assert_eq!(
+            Err(UsernameError::TooShort { length: 2, min: 3 }),
+            policy.validate("ßö")
+        );
+        assert_eq!(
+            Err(UsernameError::ForbiddenCharacter(':')),
+            policy.validate("ze:ro")
+        );
+    }
+
+    #[test]
+    fn test_username_policy_canonical() {
+        let policy = UsernamePolicy {
+            allowed_classes: vec![CharacterClass::Alphabetic],
+            reserved_names: vec!["PayPal".to_string()],
+            canonicalize: true,
+            ..UsernamePolicy::default()
+        };
+
+        assert_eq!(Ok(()), policy.validate("zeronet"));
+        assert_eq!(Err(UsernameError::NotCanonical), policy.validate("ZeroNet"));
+        assert_eq!(
+            Err(UsernameError::Reserved("PayPal".to_string())),
+            policy.validate("раураl")
+        );
+
+        let userdata = UserData::new_validated(
+            "ＺｅｒｏＮｅｔ".to_string(),
+            "1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX".to_string(),
+            &policy,
+        )
+        .unwrap();
+        assert_eq!(
+            "1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet",
+            userdata.merge()
+        );
+        assert!(userdata.is_canonical());
+        assert!(!UserData::new("ZeroNet".to_string(), String::new()).is_canonical());
+    }
+
+    #[test]
+    fn test_userdata_new_validated() {
+        let policy = UsernamePolicy::default();
+
+        let userdata = UserData::new_validated(
+            "zeronet_user".to_string(),
+            "1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX".to_string(),
+            &policy,
+        )
+        .unwrap();
+        assert_eq!(
+            "1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user",
+            userdata.merge()
+        );
+
+        assert_eq!(
+            Err(UsernameError::ForbiddenCharacter(':')),
+            UserData::new_validated(
+                "zero:net".to_string(),
+                "1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX".to_string(),
+                &policy
+            )
+        );
+    }
+}
@@ -0,0 +1,630 @@
+use crate::algo::to_hex;
+#[cfg(feature = "cbor")]
+use crate::cbor::{self, CborValue};
+use crate::{
+    DifficultyCurve, LengthMetric, MergeFormat, NumericTarget, PoWAlgo, Proof, ProofError,
+    TargetMode, UserData, PROOF_VERSION,
+};
+
+/// Version of the binary format written by `Proof::to_bytes`
+pub const WIRE_VERSION: u8 = 1;
+
+const SHA256_ID: u8 = 0;
+#[cfg(feature = "blake3")]
+const BLAKE3_ID: u8 = 1;
+#[cfg(feature = "sha3")]
+const SHA3_256_ID: u8 = 2;
+const ARGON2ID_ID: u8 = 3;
+
+const HEX_PREFIX_ID: u8 = 0;
+const LEADING_ZERO_BITS_ID: u8 = 1;
+const NUMERIC_ID: u8 = 2;
+
+const BYTES_ID: u8 = 0;
+const SCALARS_ID: u8 = 1;
+const GRAPHEMES_ID: u8 = 2;
+
+/// Set in the modes byte when the userdata is merged with `MergeFormat::V1`
+const MERGE_V1_FLAG: u8 = 0x80;
+/// Set in the modes byte when a length-prefixed namespace follows the auth_address
+const NAMESPACE_FLAG: u8 = 0x40;
+/// Set in the modes byte when the length-prefixed name of a difficulty policy other than
+/// the legacy curve follows the userdata
+const DIFFICULTY_POLICY_FLAG: u8 = 0x08;
+
+/// Decodes a 64 character hex hash into its digest
+fn digest_from_hex(hash: &str) -> Option<[u8; 32]> {
+    if hash.len() != 64 || !hash.is_ascii() {
+        return None;
+    }
+
+    let mut digest = [0; 32];
+    for (index, byte) in digest.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hash[index * 2..index * 2 + 2], 16).ok()?;
+    }
+
+    Some(digest)
+}
+
+/// Writes an unsigned LEB128 varint
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+struct Reader<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl Reader<'_> {
+    fn bytes(&mut self, length: usize) -> Result<&[u8], ProofError> {
+        let end = self
+            .position
+            .checked_add(length)
+            .ok_or(ProofError::Malformed)?;
+        let bytes = self
+            .input
+            .get(self.position..end)
+            .ok_or(ProofError::Malformed)?;
+        self.position = end;
+
+        Ok(bytes)
+    }
+
+    fn byte(&mut self) -> Result<u8, ProofError> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    /// Reads an unsigned LEB128 varint, rejecting overlong encodings
+    fn varint(&mut self) -> Result<u64, ProofError> {
+        let mut value = 0u64;
+
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            let bits = (byte & 0x7f) as u64;
+
+            if bits << shift >> shift != bits {
+                return Err(ProofError::Malformed);
+            }
+            value |= bits << shift;
+
+            if byte & 0x80 == 0 {
+                if byte == 0 && shift > 0 {
+                    return Err(ProofError::Malformed);
+                }
+                return Ok(value);
+            }
+        }
+
+        Err(ProofError::Malformed)
+    }
+
+    fn usize(&mut self, name: &'static str) -> Result<usize, ProofError> {
+        usize::try_from(self.varint()?).map_err(|_| ProofError::InvalidField(name))
+    }
+
+    fn u32(&mut self, name: &'static str) -> Result<u32, ProofError> {
+        u32::try_from(self.varint()?).map_err(|_| ProofError::InvalidField(name))
+    }
+
+    fn string(&mut self, name: &'static str) -> Result<String, ProofError> {
+        let length = self.usize(name)?;
+        let bytes = self.bytes(length)?.to_vec();
+
+        String::from_utf8(bytes).map_err(|_| ProofError::InvalidField(name))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position == self.input.len()
+    }
+}
+
+impl Proof {
+    /// Encodes the proof in the compact binary format
+    ///
+    /// The format version byte is followed by the algorithm id with its parameters, a modes
+    /// byte followed by the target, the difficulty and nonce as LEB128 varints, the
+    /// length-prefixed username, auth_address, namespace and difficulty policy name if there
+    /// are ones, and, if `with_digest` is set, the raw 32 byte digest. The low 3 bits of the
+    /// modes byte hold the target mode id, followed by a flag for the difficulty policy, 2
+    /// bits for the length metric id, a flag for the namespace, and one for
+    /// `MergeFormat::V1`. Without the digest the decoded hash is left empty and recalculated
+    /// by `verify`.
+    ///
+    /// None is returned if the digest is requested but the hash is not 64 hex characters.
+    pub fn to_bytes(&self, with_digest: bool) -> Option<Vec<u8>> {
+        let mut bytes = vec![WIRE_VERSION];
+
+        match self.algo {
+            PoWAlgo::Sha256 => bytes.push(SHA256_ID),
+            #[cfg(feature = "blake3")]
+            PoWAlgo::Blake3 => bytes.push(BLAKE3_ID),
+            #[cfg(feature = "sha3")]
+            PoWAlgo::Sha3_256 => bytes.push(SHA3_256_ID),
+            PoWAlgo::Argon2id {
+                memory_cost,
+                time_cost,
+                parallelism,
+            } => {
+                bytes.push(ARGON2ID_ID);
+                write_varint(&mut bytes, memory_cost as u64);
+                write_varint(&mut bytes, time_cost as u64);
+                write_varint(&mut bytes, parallelism as u64);
+            }
+        }
+
+        let length_metric_id = match self.length_metric {
+            LengthMetric::Bytes => BYTES_ID,
+            LengthMetric::Scalars => SCALARS_ID,
+            LengthMetric::Graphemes => GRAPHEMES_ID,
+        };
+        let mut flags = 0;
+        if self.userdata.merge_format() == MergeFormat::V1 {
+            flags |= MERGE_V1_FLAG;
+        }
+        if self.userdata.namespace().is_some() {
+            flags |= NAMESPACE_FLAG;
+        }
+        let difficulty_policy = match self.difficulty_policy {
+            DifficultyCurve::Legacy => None,
+            ref difficulty_policy => {
+                flags |= DIFFICULTY_POLICY_FLAG;
+                Some(difficulty_policy.to_string())
+            }
+        };
+        let modes = flags | length_metric_id << 4;
+        match self.target_mode {
+            TargetMode::HexPrefix => bytes.push(modes | HEX_PREFIX_ID),
+            TargetMode::LeadingZeroBits => bytes.push(modes | LEADING_ZERO_BITS_ID),
+            TargetMode::Numeric(target) => {
+                bytes.push(modes | NUMERIC_ID);
+                bytes.extend(target.to_bytes());
+            }
+        }
+
+        write_varint(&mut bytes, self.difficulty as u64);
+        write_varint(&mut bytes, self.nonce as u64);
+
+        for field in [
+            Some(self.userdata.username()),
+            Some(self.userdata.auth_address()),
+            self.userdata.namespace(),
+            difficulty_policy.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            write_varint(&mut bytes, field.len() as u64);
+            bytes.extend(field.as_bytes());
+        }
+
+        if with_digest {
+            bytes.extend(digest_from_hex(&self.hash)?);
+        }
+
+        Some(bytes)
+    }
+
+    /// Initializes a Proof struct from the compact binary format
+    ///
+    /// Truncated input, overlong varints, unknown ids, and trailing bytes other than a
+    /// 32 byte digest are rejected. The proof still has to be checked with `verify`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Proof, ProofError> {
+        let mut reader = Reader {
+            input: bytes,
+            position: 0,
+        };
+
+        let version = reader.byte()?;
+        if version != WIRE_VERSION {
+            return Err(ProofError::UnsupportedVersion(version as u64));
+        }
+
+        let algo = match reader.byte()? {
+            SHA256_ID => PoWAlgo::Sha256,
+            #[cfg(feature = "blake3")]
+            BLAKE3_ID => PoWAlgo::Blake3,
+            #[cfg(feature = "sha3")]
+            SHA3_256_ID => PoWAlgo::Sha3_256,
+            ARGON2ID_ID => PoWAlgo::Argon2id {
+                memory_cost: reader.u32("algo")?,
+                time_cost: reader.u32("algo")?,
+                parallelism: reader.u32("algo")?,
+            },
+            _ => return Err(ProofError::InvalidField("algo")),
+        };
+        if algo.validate().is_err() {
+            return Err(ProofError::InvalidField("algo"));
+        }
+
+        let modes = reader.byte()?;
+        let target_mode = match modes & 0x07 {
+            HEX_PREFIX_ID => TargetMode::HexPrefix,
+            LEADING_ZERO_BITS_ID => TargetMode::LeadingZeroBits,
+            NUMERIC_ID => TargetMode::Numeric(NumericTarget::from_bytes(
+                reader.bytes(32)?.try_into().unwrap(),
+            )),
+            _ => return Err(ProofError::InvalidField("target_mode")),
+        };
+        let length_metric = match modes >> 4 & 0x03 {
+            BYTES_ID => LengthMetric::Bytes,
+            SCALARS_ID => LengthMetric::Scalars,
+            GRAPHEMES_ID => LengthMetric::Graphemes,
+            _ => return Err(ProofError::InvalidField("length_metric")),
+        };
+
+        let difficulty = reader.usize("difficulty")?;
+        let nonce = reader.usize("nonce")?;
+        let mut userdata =
+            UserData::new(reader.string("username")?, reader.string("auth_address")?);
+        if modes & MERGE_V1_FLAG != 0 {
+            userdata = userdata.with_merge_format(MergeFormat::V1);
+        }
+        if modes & NAMESPACE_FLAG != 0 {
+            if modes & MERGE_V1_FLAG == 0 {
+                return Err(ProofError::InvalidField("namespace"));
+            }
+            userdata = userdata.with_namespace(reader.string("namespace")?);
+        }
+        let difficulty_policy = if modes & DIFFICULTY_POLICY_FLAG != 0 {
+            DifficultyCurve::from_name(&reader.string("difficulty_policy")?)
+                .filter(|difficulty_policy| *difficulty_policy != DifficultyCurve::Legacy)
+                .ok_or(ProofError::InvalidField("difficulty_policy"))?
+        } else {
+            DifficultyCurve::Legacy
+        };
+
+        let hash = if reader.is_empty() {
+            String::new()
+        } else {
+            let digest = to_hex(reader.bytes(32)?);
+            if !reader.is_empty() {
+                return Err(ProofError::Malformed);
+            }
+            digest
+        };
+
+        Ok(Proof {
+            version: PROOF_VERSION,
+            userdata,
+            difficulty,
+            algo,
+            target_mode,
+            length_metric,
+            difficulty_policy,
+            hash,
+            nonce,
+        })
+    }
+
+    /// Encodes the proof as a CBOR map with the same fields as `to_json`, including the
+    /// optional ones
+    ///
+    /// The hash is stored as the raw digest under "digest" if `with_digest` is set, otherwise
+    /// the decoded hash is left empty and recalculated by `verify`. None is returned if the
+    /// digest is requested but the hash is not 64 hex characters.
+    #[cfg(feature = "cbor")]
+    pub fn to_cbor(&self, with_digest: bool) -> Option<Vec<u8>> {
+        let mut fields = vec![
+            ("version", CborValue::Unsigned(self.version)),
+            ("algo", CborValue::Text(self.algo.to_string())),
+            ("target_mode", CborValue::Text(self.target_mode.to_string())),
+            ("difficulty", CborValue::Unsigned(self.difficulty as u64)),
+            (
+                "auth_address",
+                CborValue::Text(self.userdata.auth_address().to_string()),
+            ),
+            (
+                "username",
+                CborValue::Text(self.userdata.username().to_string()),
+            ),
+            ("nonce", CborValue::Unsigned(self.nonce as u64)),
+        ];
+
+        if self.length_metric != LengthMetric::Bytes {
+            fields.push((
+                "length_metric",
+                CborValue::Text(self.length_metric.to_string()),
+            ));
+        }
+        if self.difficulty_policy != DifficultyCurve::Legacy {
+            fields.push((
+                "difficulty_policy",
+                CborValue::Text(self.difficulty_policy.to_string()),
+            ));
+        }
+        if self.userdata.merge_format() != MergeFormat::Legacy {
+            fields.push((
+                "merge_format",
+                CborValue::Text(self.userdata.merge_format().to_string()),
+            ));
+        }
+        if let Some(namespace) = self.userdata.namespace() {
+            fields.push(("namespace", CborValue::Text(namespace.to_string())));
+        }
+        if with_digest {
+            fields.push((
+                "digest",
+                CborValue::Bytes(digest_from_hex(&self.hash)?.to_vec()),
+            ));
+        }
+
+        Some(cbor::write_map(&fields))
+    }
+
+    /// Initializes a Proof struct from its CBOR form
+    ///
+    /// Only canonical CBOR is accepted and unknown fields are ignored. The proof still has to
+    /// be checked with `verify`.
+    #[cfg(feature = "cbor")]
+    pub fn from_cbor(proof: &[u8]) -> Result<Proof, ProofError> {
+        let fields = cbor::read_map(proof).ok_or(ProofError::Malformed)?;
+
+        let field = |name: &'static str| {
+            fields
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value)
+        };
+        let text = |name: &'static str| match field(name) {
+            Some(CborValue::Text(value)) => Ok(value.as_str()),
+            Some(_) => Err(ProofError::InvalidField(name)),
+            None => Err(ProofError::MissingField(name)),
+        };
+        let unsigned = |name: &'static str| match field(name) {
+            Some(CborValue::Unsigned(value)) => {
+                usize::try_from(*value).map_err(|_| ProofError::InvalidField(name))
+            }
+            Some(_) => Err(ProofError::InvalidField(name)),
+            None => Err(ProofError::MissingField(name)),
+        };
+
+        let version = unsigned("version")? as u64;
+        if version != PROOF_VERSION {
+            return Err(ProofError::UnsupportedVersion(version));
+        }
+
+        let algo = PoWAlgo::from_name(text("algo")?)
+            .filter(|algo| algo.validate().is_ok())
+            .ok_or(ProofError::InvalidField("algo"))?;
+        let merge_format = match field("merge_format") {
+            Some(CborValue::Text(name)) => {
+                MergeFormat::from_name(name).ok_or(ProofError::InvalidField("merge_format"))?
+            }
+            Some(_) => return Err(ProofError::InvalidField("merge_format")),
+            None => MergeFormat::Legacy,
+        };
+        let mut userdata = UserData::new(
+            text("username")?.to_string(),
+            text("auth_address")?.to_string(),
+        )
+        .with_merge_format(merge_format);
+        match field("namespace") {
+            Some(CborValue::Text(namespace)) if merge_format != MergeFormat::Legacy => {
+                userdata = userdata.with_namespace(namespace.clone());
+            }
+            Some(_) => return Err(ProofError::InvalidField("namespace")),
+            None => {}
+        }
+        let nonce = unsigned("nonce")?;
+
+        let hash = match field("digest") {
+            Some(CborValue::Bytes(digest)) if digest.len() == 32 => to_hex(digest),
+            Some(_) => return Err(ProofError::InvalidField("digest")),
+            None => String::new(),
+        };
+
+        let length_metric = match field("length_metric") {
+            Some(CborValue::Text(name)) => {
+                LengthMetric::from_name(name).ok_or(ProofError::InvalidField("length_metric"))?
+            }
+            Some(_) => return Err(ProofError::InvalidField("length_metric")),
+            None => LengthMetric::Bytes,
+        };
+        let difficulty_policy = match field("difficulty_policy") {
+            Some(CborValue::Text(name)) => DifficultyCurve::from_name(name)
+                .ok_or(ProofError::InvalidField("difficulty_policy"))?,
+            Some(_) => return Err(ProofError::InvalidField("difficulty_policy")),
+            None => DifficultyCurve::Legacy,
+        };
+
+        Ok(Proof {
+            version,
+            userdata,
+            difficulty: unsigned("difficulty")?,
+            algo,
+            target_mode: TargetMode::from_name(text("target_mode")?)
+                .ok_or(ProofError::InvalidField("target_mode"))?,
+            length_metric,
+            difficulty_policy,
+            hash,
+            nonce,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        DifficultyCurve, LengthMetric, NumericTarget, PoW, PoWAlgo, Proof, ProofError, TargetMode,
+        UserData,
+    };
+
+    fn proof() -> Proof {
+        let userdata =
+            UserData::from_merged("1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX:zeronet_user".to_string())
+                .unwrap();
+
+        PoW::new(userdata, 24, PoWAlgo::Sha256).unwrap().proof((
+            "ffffff419e9de8f5a3b958da92eb19ed8b6cc6da591de7fec0a2e7250c804047".to_string(),
+            6589658,
+        ))
+    }
+
+    #[test]
+    fn test_wire_round_trip() {
+        let proof = proof();
+
+        let bytes = proof.to_bytes(false).unwrap();
+        assert_eq!(
+            [
+                &[1, 0, 0, 24, 0xda, 0x99, 0x92, 0x03, 12][..],
+                b"zeronet_user",
+                &[34],
+                b"1FBbx487PoajzgnA4yY6TnoLFhQQteT8UX",
+            ]
+            .concat(),
+            bytes
+        );
+        let decoded = Proof::from_bytes(&bytes).unwrap();
+        assert_eq!(
+            Proof {
+                hash: String::new(),
+                ..proof.clone()
+            },
+            decoded
+        );
+        assert!(decoded.verify());
+
+        let bytes_with_digest = proof.to_bytes(true).unwrap();
+        assert_eq!(bytes.len() + 32, bytes_with_digest.len());
+        assert_eq!(Ok(proof.clone()), Proof::from_bytes(&bytes_with_digest));
+
+        let mut other = proof.clone();
+        other.algo = PoWAlgo::Argon2id {
+            memory_cost: 64,
+            time_cost: 2,
+            parallelism: 2,
+        };
+        other.target_mode = TargetMode::Numeric(NumericTarget::from_compact(0x1f00b504).unwrap());
+        other.length_metric = LengthMetric::Graphemes;
+        other.difficulty_policy = DifficultyCurve::Exponential {
+            half_life: 4,
+            min: 8,
+        };
+        other.nonce = usize::MAX;
+        other.userdata = UserData::new("zero:net ü".to_string(), String::new())
+            .with_namespace("zeronet".to_string());
+        assert_eq!(
+            Ok(other.clone()),
+            Proof::from_bytes(&other.to_bytes(true).unwrap())
+        );
+
+        other.hash = "not a hash".to_string();
+        assert_eq!(None, other.to_bytes(true));
+    }
+
+    #[test]
+    fn test_wire_malformed() {
+        let bytes = proof().to_bytes(true).unwrap();
+
+        for length in 0..bytes.len() {
+            if length != bytes.len() - 32 {
+                assert!(Proof::from_bytes(&bytes[..length]).is_err(), "{length}");
+            }
+        }
+
+        let with_trailing_byte = [&bytes[..], &[0]].concat();
+        assert_eq!(
+            Err(ProofError::Malformed),
+            Proof::from_bytes(&with_trailing_byte)
+        );
+
+        for (index, byte, error) in [
+            (0, 2, ProofError::UnsupportedVersion(2)),
+            (1, 0xff, ProofError::InvalidField("algo")),
+            (2, 0xff, ProofError::InvalidField("target_mode")),
+            (2, 0x30, ProofError::InvalidField("length_metric")),
+            (2, 0x40, ProofError::InvalidField("namespace")),
+            // Overlong encoding of the difficulty
+            (3, 0x98, ProofError::Malformed),
+        ] {
+            let mut bytes = bytes.clone();
+            bytes[index] = byte;
+            if index == 3 {
+                bytes.insert(4, 0);
+            }
+            assert_eq!(Err(error), Proof::from_bytes(&bytes));
+        }
+
+        // The legacy curve is only ever encoded by leaving the flag unset
+        let mut legacy_policy = [&proof().to_bytes(false).unwrap()[..], &[6], b"legacy"].concat();
+        legacy_policy[2] |= 0x08;
+        assert_eq!(
+            Err(ProofError::InvalidField("difficulty_policy")),
+            Proof::from_bytes(&legacy_policy)
+        );
+
+        let invalid_utf8 = [1, 0, 0, 24, 0, 1, 0xff, 0];
+        assert_eq!(
+            Err(ProofError::InvalidField("username")),
+            Proof::from_bytes(&invalid_utf8)
+        );
+
+        let invalid_argon2 = [1, 3, 1, 1, 1, 0, 24, 0, 0, 0];
+        assert_eq!(
+            Err(ProofError::InvalidField("algo")),
+            Proof::from_bytes(&invalid_argon2)
+        );
+    }
+
+    #[cfg(feature = "cbor")]
+    #[test]
+    fn test_cbor_round_trip() {
+        let proof = proof();
+
+        let cbor = proof.to_cbor(true).unwrap();
+        assert_eq!(Ok(proof.clone()), Proof::from_cbor(&cbor));
+
+        let without_hash = Proof {
+            hash: String::new(),
+            ..proof.clone()
+        };
+        let cbor = proof.to_cbor(false).unwrap();
+        assert_eq!(Ok(without_hash.clone()), Proof::from_cbor(&cbor));
+
+        let scalar_proof = Proof {
+            length_metric: LengthMetric::Scalars,
+            difficulty_policy: DifficultyCurve::Linear { step: 2, min: 8 },
+            ..without_hash
+        };
+        let cbor = scalar_proof.to_cbor(false).unwrap();
+        assert_eq!(Ok(scalar_proof), Proof::from_cbor(&cbor));
+
+        let namespaced_proof = Proof {
+            userdata: proof.userdata.clone().with_namespace("zeronet".to_string()),
+            ..proof.clone()
+        };
+        let cbor = namespaced_proof.to_cbor(true).unwrap();
+        assert_eq!(Ok(namespaced_proof), Proof::from_cbor(&cbor));
+
+        let cbor = proof.to_cbor(true).unwrap();
+        assert_eq!(Err(ProofError::Malformed), Proof::from_cbor(&cbor[1..]));
+        let digest = cbor
+            .windows(7)
+            .position(|key| key == b"\x66digest")
+            .unwrap()
+            + 7;
+        assert_eq!(
+            Err(ProofError::InvalidField("digest")),
+            Proof::from_cbor(&[&cbor[..digest], &[0x41, 0], &cbor[digest + 34..]].concat())
+        );
+
+        // Unknown fields are skipped whatever their type, {"x": true, "y": [-1, null]} sorts
+        // before the proof's fields
+        let with_unknown = [
+            &[cbor[0] + 2, 0x61, b'x', 0xf5, 0x61, b'y', 0x82, 0x20, 0xf6],
+            &cbor[1..],
+        ]
+        .concat();
+        assert_eq!(Ok(proof.clone()), Proof::from_cbor(&with_unknown));
+
+        // Keys out of order aren't canonical
+        let unsorted = [&[cbor[0] + 1], &cbor[1..], &[0x61, b'x', 0xf5]].concat();
+        assert_eq!(Err(ProofError::Malformed), Proof::from_cbor(&unsorted));
+    }
+}